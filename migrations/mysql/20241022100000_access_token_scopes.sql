@@ -0,0 +1,7 @@
+/* ------------------------------ */
+
+ALTER TABLE access_tokens
+   ADD COLUMN expires_at TIMESTAMP(6),
+   ADD COLUMN scopes TEXT;
+
+/* ------------------------------ */
@@ -0,0 +1,5 @@
+/* ------------------------------ */
+
+ALTER TYPE dataset_flow_type ADD VALUE 'retention';
+
+/* ------------------------------ */
@@ -0,0 +1,33 @@
+/* ------------------------------ */
+
+CREATE TABLE webhook_subscriptions(
+    id UUID PRIMARY KEY,
+    account_id VARCHAR(100) NOT NULL,
+    target_url VARCHAR(2048) NOT NULL,
+    event_types VARCHAR(200) NOT NULL,
+    dataset_id VARCHAR(100),
+    secret VARCHAR(100) NOT NULL,
+    enabled BOOLEAN NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_webhook_subscriptions_account_id ON webhook_subscriptions(account_id);
+
+CREATE INDEX idx_webhook_subscriptions_dataset_id ON webhook_subscriptions(dataset_id);
+
+/* ------------------------------ */
+
+CREATE TABLE webhook_deliveries(
+    id UUID PRIMARY KEY,
+    subscription_id UUID NOT NULL REFERENCES webhook_subscriptions(id),
+    event_id UUID NOT NULL,
+    event_type VARCHAR(50) NOT NULL,
+    attempt INTEGER NOT NULL,
+    requested_at TIMESTAMPTZ NOT NULL,
+    response_status INTEGER,
+    error TEXT
+);
+
+CREATE INDEX idx_webhook_deliveries_subscription_id ON webhook_deliveries(subscription_id, requested_at);
+
+/* ------------------------------ */
@@ -0,0 +1,13 @@
+/* ------------------------------ */
+
+CREATE TABLE webhook_delivery_retries(
+    id UUID PRIMARY KEY,
+    subscription_id UUID NOT NULL REFERENCES webhook_subscriptions(id),
+    event TEXT NOT NULL,
+    attempt INTEGER NOT NULL,
+    due_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_webhook_delivery_retries_due_at ON webhook_delivery_retries(due_at);
+
+/* ------------------------------ */
@@ -0,0 +1,95 @@
+/* ------------------------------ */
+
+/*
+ SQLite can't alter CHECK constraints, so tables that restrict dataset flow types
+ are re-created. Foreign keys pointing to "flows" are re-validated on commit.
+*/
+PRAGMA defer_foreign_keys = ON;
+
+/* ------------------------------ */
+
+CREATE TABLE flow_configuration_events_new
+(
+    event_id          INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    created_time      timestamptz NOT NULL,
+    dataset_id        VARCHAR(100),
+    dataset_flow_type VARCHAR(20) CHECK ( 
+        dataset_flow_type IN (
+            'ingest', 
+            'execute_transform', 
+            'hard_compaction', 
+            'reset',
+            'verify',
+            'soft_compaction',
+            'retention'
+        ) 
+    ),
+    system_flow_type VARCHAR(10) CHECK ( 
+        system_flow_type IN ('gc') 
+    ),
+    event_type       VARCHAR(50) NOT NULL,
+    event_time       TIMESTAMPTZ NOT NULL,
+    event_payload    JSONB NOT NULL
+);
+
+INSERT INTO flow_configuration_events_new
+    SELECT event_id, created_time, dataset_id, dataset_flow_type, system_flow_type, event_type, event_time, event_payload
+    FROM flow_configuration_events;
+
+DROP TABLE flow_configuration_events;
+
+ALTER TABLE flow_configuration_events_new RENAME TO flow_configuration_events;
+
+CREATE INDEX idx_flow_configuration_events_dataset_id_idx
+     ON flow_configuration_events (dataset_id, dataset_flow_type)
+     WHERE dataset_id IS NOT NULL;
+
+CREATE INDEX idx_flow_configuration_events_system_flow_type_idx
+     ON flow_configuration_events (system_flow_type)
+     WHERE system_flow_type IS NOT NULL;
+
+/* ------------------------------ */
+
+CREATE TABLE flows_new
+(
+    flow_id BIGINT NOT NULL PRIMARY KEY REFERENCES flow_ids(flow_id),
+    dataset_id VARCHAR(100),
+    dataset_flow_type VARCHAR(20) CHECK ( 
+        dataset_flow_type IN (
+            'ingest', 
+            'execute_transform', 
+            'hard_compaction', 
+            'reset',
+            'verify',
+            'soft_compaction',
+            'retention'
+        ) 
+    ),
+    system_flow_type VARCHAR(10) CHECK ( 
+        system_flow_type IN ('gc') 
+    ),
+    initiator VARCHAR(100) NOT NULL,  /* No referential integrity with account_id, as it can system initiator value */
+    flow_status VARCHAR(10) CHECK (
+        flow_status IN (
+           'waiting', 
+           'running', 
+           'finished'
+        )
+    ) NOT NULL,
+    scheduled_for_activation_at TIMESTAMPTZ,
+    last_event_id INTEGER REFERENCES flow_events(event_id)
+);
+
+INSERT INTO flows_new
+    SELECT flow_id, dataset_id, dataset_flow_type, system_flow_type, initiator, flow_status, scheduled_for_activation_at, last_event_id
+    FROM flows;
+
+DROP TABLE flows;
+
+ALTER TABLE flows_new RENAME TO flows;
+
+CREATE INDEX idx_flows_dataset_id ON flows (dataset_id) WHERE dataset_id IS NOT NULL;
+CREATE INDEX idx_flows_system_flow_type ON flows (system_flow_type) WHERE system_flow_type IS NOT NULL;
+CREATE INDEX idx_flows_flow_status ON flows(flow_status) WHERE flow_status != 'finished'; 
+
+/* ------------------------------ */
@@ -0,0 +1,9 @@
+/* ------------------------------ */
+
+ALTER TABLE access_tokens
+   ADD COLUMN expires_at TEXT;
+
+ALTER TABLE access_tokens
+   ADD COLUMN scopes TEXT;
+
+/* ------------------------------ */
@@ -0,0 +1,18 @@
+/* ------------------------------ */
+
+CREATE TABLE audit_events(
+    id VARCHAR(36) NOT NULL PRIMARY KEY,
+    event_time timestamptz NOT NULL,
+    account_id VARCHAR(100),
+    action VARCHAR(50) NOT NULL,
+    dataset_id VARCHAR(100),
+    details TEXT
+);
+
+CREATE INDEX idx_audit_events_event_time ON audit_events(event_time);
+
+CREATE INDEX idx_audit_events_account_id ON audit_events(account_id, event_time);
+
+CREATE INDEX idx_audit_events_dataset_id ON audit_events(dataset_id, event_time);
+
+/* ------------------------------ */
@@ -15,6 +15,8 @@ use oso::PolarClass;
 
 const ROLE_READER: &str = "Reader";
 const ROLE_EDITOR: &str = "Editor";
+const ROLE_MAINTAINER: &str = "Maintainer";
+const ROLE_ADMIN: &str = "Admin";
 
 #[derive(PolarClass, Debug, Clone)]
 pub struct DatasetResource {
@@ -37,17 +39,24 @@ impl DatasetResource {
         }
     }
 
-    #[allow(dead_code)]
     pub fn authorize_reader(&mut self, reader: &str) {
         self.authorized_users
             .insert(reader.to_string(), ROLE_READER);
     }
 
-    #[allow(dead_code)]
     pub fn authorize_editor(&mut self, editor: &str) {
         self.authorized_users
             .insert(editor.to_string(), ROLE_EDITOR);
     }
+
+    pub fn authorize_maintainer(&mut self, maintainer: &str) {
+        self.authorized_users
+            .insert(maintainer.to_string(), ROLE_MAINTAINER);
+    }
+
+    pub fn authorize_admin(&mut self, admin: &str) {
+        self.authorized_users.insert(admin.to_string(), ROLE_ADMIN);
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+#![feature(let_chains)]
+
 pub mod dataset_resource;
 pub mod user_actor;
 
@@ -12,8 +12,9 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use dill::*;
-use internal_error::ErrorIntoInternal;
-use kamu_accounts::{CurrentAccountSubject, DEFAULT_ACCOUNT_NAME_STR};
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_accounts::{AccountRepository, CurrentAccountSubject, DEFAULT_ACCOUNT_NAME_STR};
+use kamu_auth_rebac::{AccountToDatasetRelation, RebacService};
 use kamu_core::auth::*;
 use kamu_core::AccessError;
 use opendatafabric::DatasetHandle;
@@ -28,6 +29,8 @@ use crate::KamuAuthOso;
 pub struct OsoDatasetAuthorizer {
     oso: Arc<Oso>,
     current_account_subject: Arc<CurrentAccountSubject>,
+    rebac_service: Arc<dyn RebacService>,
+    account_repo: Arc<dyn AccountRepository>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -39,10 +42,14 @@ impl OsoDatasetAuthorizer {
     pub fn new(
         kamu_auth_oso: Arc<KamuAuthOso>,
         current_account_subject: Arc<CurrentAccountSubject>,
+        rebac_service: Arc<dyn RebacService>,
+        account_repo: Arc<dyn AccountRepository>,
     ) -> Self {
         Self {
             oso: kamu_auth_oso.oso.clone(),
             current_account_subject,
+            rebac_service,
+            account_repo,
         }
     }
 
@@ -55,7 +62,10 @@ impl OsoDatasetAuthorizer {
         }
     }
 
-    fn dataset_resource(&self, dataset_handle: &DatasetHandle) -> DatasetResource {
+    async fn dataset_resource(
+        &self,
+        dataset_handle: &DatasetHandle,
+    ) -> Result<DatasetResource, InternalError> {
         let dataset_alias = &dataset_handle.alias;
         let creator = dataset_alias
             .account_name
@@ -63,8 +73,71 @@ impl OsoDatasetAuthorizer {
             .map_or(DEFAULT_ACCOUNT_NAME_STR, |a| a.as_str());
 
         // TODO: for now let's treat all datasets as public
-        // TODO: explicit read/write permissions
-        DatasetResource::new(creator, true)
+        let mut dataset_resource = DatasetResource::new(creator, true);
+
+        // Permissions granted explicitly or inherited through organization
+        // memberships only matter for non-owners
+        if let CurrentAccountSubject::Logged(l) = self.current_account_subject.as_ref()
+            && !l.is_admin
+            && l.account_name.as_str() != creator
+        {
+            let dataset_owner_id = if let Some(owner_name) = &dataset_alias.account_name {
+                self.account_repo
+                    .find_account_id_by_name(owner_name)
+                    .await
+                    .int_err()?
+            } else {
+                None
+            };
+
+            let maybe_relation = self
+                .rebac_service
+                .get_effective_account_dataset_relation(
+                    &l.account_id,
+                    &dataset_handle.id,
+                    dataset_owner_id.as_ref(),
+                )
+                .await
+                .int_err()?;
+
+            match maybe_relation {
+                Some(AccountToDatasetRelation::Reader) => {
+                    dataset_resource.authorize_reader(l.account_name.as_str());
+                }
+                Some(AccountToDatasetRelation::Editor) => {
+                    dataset_resource.authorize_editor(l.account_name.as_str());
+                }
+                Some(AccountToDatasetRelation::Maintainer) => {
+                    dataset_resource.authorize_maintainer(l.account_name.as_str());
+                }
+                Some(AccountToDatasetRelation::Admin) => {
+                    dataset_resource.authorize_admin(l.account_name.as_str());
+                }
+                None => {}
+            }
+        }
+
+        Ok(dataset_resource)
+    }
+
+    fn is_allowed_by_token_scopes(
+        &self,
+        dataset_handle: &DatasetHandle,
+        action: DatasetAction,
+    ) -> bool {
+        match self.current_account_subject.as_ref() {
+            CurrentAccountSubject::Anonymous(_) => true,
+            CurrentAccountSubject::Logged(l) => match action {
+                DatasetAction::Read => l.token_scopes.allows_read(&dataset_handle.id),
+                // Flow-trigger scopes are checked by the adapters next to the operations
+                // they permit, as they do not cover the rest of the maintenance
+                DatasetAction::Write
+                | DatasetAction::Maintain
+                | DatasetAction::ManagePermissions
+                | DatasetAction::Delete
+                | DatasetAction::Rename => l.token_scopes.allows_write(&dataset_handle.id),
+            },
+        }
     }
 }
 
@@ -79,14 +152,17 @@ impl DatasetActionAuthorizer for OsoDatasetAuthorizer {
         action: DatasetAction,
     ) -> Result<(), DatasetActionUnauthorizedError> {
         let actor = self.actor();
-        let dataset_resource = self.dataset_resource(dataset_handle);
+        let dataset_resource = self
+            .dataset_resource(dataset_handle)
+            .await
+            .map_err(DatasetActionUnauthorizedError::Internal)?;
 
         match self
             .oso
             .is_allowed(actor, action.to_string(), dataset_resource)
         {
             Ok(r) => {
-                if r {
+                if r && self.is_allowed_by_token_scopes(dataset_handle, action) {
                     Ok(())
                 } else {
                     Err(DatasetActionUnauthorizedError::Access(
@@ -107,7 +183,13 @@ impl DatasetActionAuthorizer for OsoDatasetAuthorizer {
     #[tracing::instrument(level = "debug", skip_all, fields(%dataset_handle))]
     async fn get_allowed_actions(&self, dataset_handle: &DatasetHandle) -> HashSet<DatasetAction> {
         let actor = self.actor();
-        let dataset_resource = self.dataset_resource(dataset_handle);
+        let dataset_resource = match self.dataset_resource(dataset_handle).await {
+            Ok(dataset_resource) => dataset_resource,
+            Err(e) => {
+                tracing::error!(error = ?e, "Failed to resolve dataset permissions");
+                return HashSet::new();
+            }
+        };
 
         let allowed_action_names: HashSet<String> = self
             .oso
@@ -117,7 +199,9 @@ impl DatasetActionAuthorizer for OsoDatasetAuthorizer {
         let mut allowed_actions = HashSet::new();
         for action_name in allowed_action_names {
             let action = DatasetAction::from_str(action_name.as_str()).unwrap();
-            allowed_actions.insert(action);
+            if self.is_allowed_by_token_scopes(dataset_handle, action) {
+                allowed_actions.insert(action);
+            }
         }
 
         allowed_actions
@@ -155,6 +155,62 @@ async fn test_having_explicit_write_permission_in_private_dataset() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_having_explicit_maintain_permission_in_private_dataset() {
+    let is_admin = false;
+    let user_actor = UserActor::new("foo", false, is_admin);
+    let mut dataset_resource = DatasetResource::new("bar", false);
+    dataset_resource.authorize_maintainer("foo");
+
+    let oso = KamuAuthOso::new().oso;
+
+    for action in [
+        DatasetAction::Read,
+        DatasetAction::Write,
+        DatasetAction::Maintain,
+    ] {
+        assert_allowed!(oso.is_allowed(
+            user_actor.clone(),
+            format!("{action}"),
+            dataset_resource.clone(),
+        ));
+    }
+
+    for action in [
+        DatasetAction::ManagePermissions,
+        DatasetAction::Delete,
+        DatasetAction::Rename,
+    ] {
+        assert_forbidden!(oso.is_allowed(
+            user_actor.clone(),
+            format!("{action}"),
+            dataset_resource.clone(),
+        ));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_having_explicit_admin_permission_in_private_dataset() {
+    let is_admin = false;
+    let user_actor = UserActor::new("foo", false, is_admin);
+    let mut dataset_resource = DatasetResource::new("bar", false);
+    dataset_resource.authorize_admin("foo");
+
+    let oso = KamuAuthOso::new().oso;
+
+    for action in DatasetAction::ALL {
+        assert_allowed!(oso.is_allowed(
+            user_actor.clone(),
+            format!("{action}"),
+            dataset_resource.clone(),
+        ));
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_admin_can_read_and_write_another_private_dataset() {
     let is_admin = true;
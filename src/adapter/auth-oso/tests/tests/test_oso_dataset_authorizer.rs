@@ -14,12 +14,32 @@ use std::sync::Arc;
 use dill::{Catalog, Component};
 use kamu::testing::MetadataFactory;
 use kamu::{CreateDatasetUseCaseImpl, DatasetRepositoryLocalFs, DatasetRepositoryWriter};
-use kamu_accounts::CurrentAccountSubject;
+use kamu_accounts::{
+    AccessTokenScope,
+    AccessTokenScopes,
+    Account,
+    AccountRepository,
+    AccountType,
+    CurrentAccountSubject,
+};
+use kamu_accounts_inmem::InMemoryAccountRepository;
 use kamu_adapter_auth_oso::{KamuAuthOso, OsoDatasetAuthorizer};
+use kamu_auth_rebac::{
+    AccountToDatasetRelation,
+    AccountToOrganizationRelation,
+    DatasetCollaborationService,
+    OrganizationMembershipService,
+};
+use kamu_auth_rebac_inmem::InMemoryRebacRepository;
+use kamu_auth_rebac_services::{
+    DatasetCollaborationServiceImpl,
+    OrganizationMembershipServiceImpl,
+    RebacServiceImpl,
+};
 use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer, DatasetActionUnauthorizedError};
 use kamu_core::{AccessError, CreateDatasetUseCase, DatasetRepository};
 use messaging_outbox::DummyOutboxImpl;
-use opendatafabric::{AccountID, AccountName, DatasetAlias, DatasetHandle, DatasetKind};
+use opendatafabric::{AccountID, AccountName, DatasetAlias, DatasetHandle, DatasetID, DatasetKind};
 use tempfile::TempDir;
 use time_source::SystemTimeSourceDefault;
 
@@ -50,10 +70,7 @@ async fn test_owner_can_read_and_write() {
     assert_matches!(read_result, Ok(()));
     assert_matches!(write_result, Ok(()));
 
-    assert_eq!(
-        allowed_actions,
-        HashSet::from([DatasetAction::Read, DatasetAction::Write])
-    );
+    assert_eq!(allowed_actions, HashSet::from(DatasetAction::ALL));
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -93,6 +110,206 @@ async fn test_guest_can_read_but_not_write() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_token_scopes_narrow_owner_permissions() {
+    let harness = DatasetAuthorizerHarness::new_with_token_scopes(
+        "john",
+        AccessTokenScopes::Restricted(vec![AccessTokenScope::Read]),
+    );
+    let dataset_handle = harness
+        .create_dataset(&DatasetAlias::try_from("john/foo").unwrap())
+        .await;
+
+    let read_result = harness
+        .dataset_authorizer
+        .check_action_allowed(&dataset_handle, DatasetAction::Read)
+        .await;
+
+    let write_result = harness
+        .dataset_authorizer
+        .check_action_allowed(&dataset_handle, DatasetAction::Write)
+        .await;
+
+    let allowed_actions = harness
+        .dataset_authorizer
+        .get_allowed_actions(&dataset_handle)
+        .await;
+
+    assert_matches!(read_result, Ok(()));
+    assert_matches!(
+        write_result,
+        Err(DatasetActionUnauthorizedError::Access(
+            AccessError::Forbidden(_)
+        ))
+    );
+    assert_eq!(allowed_actions, HashSet::from([DatasetAction::Read]));
+
+    // Write scope limited to another dataset grants nothing on this one
+    let harness = DatasetAuthorizerHarness::new_with_token_scopes(
+        "john",
+        AccessTokenScopes::Restricted(vec![AccessTokenScope::Write(Some(
+            DatasetID::new_seeded_ed25519(b"bar"),
+        ))]),
+    );
+    let dataset_handle = harness
+        .create_dataset(&DatasetAlias::try_from("john/foo").unwrap())
+        .await;
+
+    let allowed_actions = harness
+        .dataset_authorizer
+        .get_allowed_actions(&dataset_handle)
+        .await;
+
+    assert_eq!(allowed_actions, HashSet::new());
+
+    // Flow-trigger scope does not permit maintenance in general
+    let harness = DatasetAuthorizerHarness::new_with_token_scopes(
+        "john",
+        AccessTokenScopes::Restricted(vec![AccessTokenScope::FlowTrigger(None)]),
+    );
+    let dataset_handle = harness
+        .create_dataset(&DatasetAlias::try_from("john/foo").unwrap())
+        .await;
+
+    let allowed_actions = harness
+        .dataset_authorizer
+        .get_allowed_actions(&dataset_handle)
+        .await;
+
+    assert_eq!(allowed_actions, HashSet::from([DatasetAction::Read]));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_collaborator_roles_grant_permissions() {
+    let harness = DatasetAuthorizerHarness::new("kate");
+    let dataset_handle = harness
+        .create_dataset(&DatasetAlias::try_from("john/foo").unwrap())
+        .await;
+
+    for (role, expected_actions) in [
+        (
+            AccountToDatasetRelation::Reader,
+            HashSet::from([DatasetAction::Read]),
+        ),
+        (
+            AccountToDatasetRelation::Editor,
+            HashSet::from([DatasetAction::Read, DatasetAction::Write]),
+        ),
+        (
+            AccountToDatasetRelation::Maintainer,
+            HashSet::from([
+                DatasetAction::Read,
+                DatasetAction::Write,
+                DatasetAction::Maintain,
+            ]),
+        ),
+        (
+            AccountToDatasetRelation::Admin,
+            HashSet::from(DatasetAction::ALL),
+        ),
+    ] {
+        harness
+            .set_collaborator_role(&dataset_handle, "kate", role)
+            .await;
+
+        assert_eq!(
+            harness
+                .dataset_authorizer
+                .get_allowed_actions(&dataset_handle)
+                .await,
+            expected_actions,
+            "{role}"
+        );
+    }
+
+    harness
+        .set_collaborator_role(
+            &dataset_handle,
+            "kate",
+            AccountToDatasetRelation::Maintainer,
+        )
+        .await;
+
+    assert_matches!(
+        harness
+            .dataset_authorizer
+            .check_action_allowed(&dataset_handle, DatasetAction::Delete)
+            .await,
+        Err(DatasetActionUnauthorizedError::Access(
+            AccessError::Forbidden(_)
+        ))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_organization_members_inherit_permissions() {
+    let harness = DatasetAuthorizerHarness::new("john");
+    harness.create_account("john", AccountType::User).await;
+    harness.create_account("kate", AccountType::User).await;
+    harness
+        .create_account("acme", AccountType::Organization)
+        .await;
+
+    let dataset_handle = harness
+        .create_dataset(&DatasetAlias::try_from("acme/foo").unwrap())
+        .await;
+
+    // Not a member yet
+    assert_eq!(
+        harness
+            .dataset_authorizer
+            .get_allowed_actions(&dataset_handle)
+            .await,
+        HashSet::from([DatasetAction::Read])
+    );
+
+    harness
+        .set_member_role("acme", "kate", AccountToOrganizationRelation::Owner)
+        .await;
+    harness
+        .set_member_role("acme", "john", AccountToOrganizationRelation::Member)
+        .await;
+
+    assert_matches!(
+        harness
+            .dataset_authorizer
+            .check_action_allowed(&dataset_handle, DatasetAction::Write)
+            .await,
+        Err(DatasetActionUnauthorizedError::Access(
+            AccessError::Forbidden(_)
+        ))
+    );
+
+    harness
+        .set_member_role("acme", "john", AccountToOrganizationRelation::Maintainer)
+        .await;
+
+    assert_matches!(
+        harness
+            .dataset_authorizer
+            .check_action_allowed(&dataset_handle, DatasetAction::Write)
+            .await,
+        Ok(())
+    );
+    assert_eq!(
+        harness
+            .dataset_authorizer
+            .get_allowed_actions(&dataset_handle)
+            .await,
+        HashSet::from([
+            DatasetAction::Read,
+            DatasetAction::Write,
+            DatasetAction::Maintain,
+        ])
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[allow(dead_code)]
 pub struct DatasetAuthorizerHarness {
     tempdir: TempDir,
@@ -102,6 +319,13 @@ pub struct DatasetAuthorizerHarness {
 
 impl DatasetAuthorizerHarness {
     pub fn new(current_account_name: &str) -> Self {
+        Self::new_with_token_scopes(current_account_name, AccessTokenScopes::Unrestricted)
+    }
+
+    pub fn new_with_token_scopes(
+        current_account_name: &str,
+        token_scopes: AccessTokenScopes,
+    ) -> Self {
         let tempdir = tempfile::tempdir().unwrap();
         let datasets_dir = tempdir.path().join("datasets");
         std::fs::create_dir(&datasets_dir).unwrap();
@@ -109,13 +333,19 @@ impl DatasetAuthorizerHarness {
         let catalog = dill::CatalogBuilder::new()
             .add::<SystemTimeSourceDefault>()
             .add::<DummyOutboxImpl>()
-            .add_value(CurrentAccountSubject::logged(
+            .add_value(CurrentAccountSubject::logged_with_token_scopes(
                 AccountID::new_seeded_ed25519(current_account_name.as_bytes()),
                 AccountName::new_unchecked(current_account_name),
                 false,
+                token_scopes,
             ))
             .add::<KamuAuthOso>()
             .add::<OsoDatasetAuthorizer>()
+            .add::<InMemoryAccountRepository>()
+            .add::<InMemoryRebacRepository>()
+            .add::<RebacServiceImpl>()
+            .add::<OrganizationMembershipServiceImpl>()
+            .add::<DatasetCollaborationServiceImpl>()
             .add_builder(
                 DatasetRepositoryLocalFs::builder()
                     .with_root(datasets_dir)
@@ -149,6 +379,57 @@ impl DatasetAuthorizerHarness {
             .unwrap()
             .dataset_handle
     }
+
+    pub async fn create_account(&self, name: &str, account_type: AccountType) {
+        let account_repo = self.catalog.get_one::<dyn AccountRepository>().unwrap();
+
+        let mut account = Account::test(AccountID::new_seeded_ed25519(name.as_bytes()), name);
+        account.account_type = account_type;
+
+        account_repo.create_account(&account).await.unwrap();
+    }
+
+    pub async fn set_collaborator_role(
+        &self,
+        dataset_handle: &DatasetHandle,
+        account_name: &str,
+        role: AccountToDatasetRelation,
+    ) {
+        let collaboration_service = self
+            .catalog
+            .get_one::<dyn DatasetCollaborationService>()
+            .unwrap();
+
+        collaboration_service
+            .set_collaborator_role(
+                &dataset_handle.id,
+                &AccountID::new_seeded_ed25519(account_name.as_bytes()),
+                role,
+            )
+            .await
+            .unwrap();
+    }
+
+    pub async fn set_member_role(
+        &self,
+        organization_name: &str,
+        account_name: &str,
+        role: AccountToOrganizationRelation,
+    ) {
+        let membership_service = self
+            .catalog
+            .get_one::<dyn OrganizationMembershipService>()
+            .unwrap();
+
+        membership_service
+            .set_member_role(
+                &AccountID::new_seeded_ed25519(organization_name.as_bytes()),
+                &AccountID::new_seeded_ed25519(account_name.as_bytes()),
+                role,
+            )
+            .await
+            .unwrap();
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
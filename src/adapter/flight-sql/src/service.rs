@@ -467,9 +467,14 @@ impl KamuFlightSqlService {
         }
     }
 
-    async fn prepare_statement(query: &str, ctx: &SessionContext) -> Result<LogicalPlan, Status> {
+    async fn prepare_statement(
+        &self,
+        query: &str,
+        ctx: &SessionContext,
+    ) -> Result<LogicalPlan, Status> {
+        let query = self.session_factory.rewrite_query(query)?;
         let plan = ctx
-            .sql(query)
+            .sql(&query)
             .await
             .and_then(DataFrame::into_optimized_plan)
             .map_err(|e| Status::internal(format!("Error building plan: {e}")))?;
@@ -760,7 +765,7 @@ impl FlightSqlService for KamuFlightSqlService {
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         let ctx = self.get_ctx(&request)?;
-        let plan = Self::prepare_statement(&query.query, &ctx).await?;
+        let plan = self.prepare_statement(&query.query, &ctx).await?;
         let df = ctx
             .execute_logical_plan(plan)
             .await
@@ -916,11 +921,10 @@ impl FlightSqlService for KamuFlightSqlService {
 
         tracing::debug!(?query, "Decoded query");
 
-        let plan = Self::prepare_statement(&query.query, &ctx).await?;
-        let df = ctx
-            .execute_logical_plan(plan)
-            .await
-            .map_err(|e| Status::internal(format!("Error executing plan: {e}")))?;
+        let df = self
+            .session_factory
+            .execute_query(&ctx, &query.query)
+            .await?;
 
         self.df_to_stream(df).await
     }
@@ -1081,7 +1085,7 @@ impl FlightSqlService for KamuFlightSqlService {
         request: Request<Action>,
     ) -> Result<ActionCreatePreparedStatementResult, Status> {
         let ctx = self.get_ctx(&request)?;
-        let plan = Self::prepare_statement(&query.query, &ctx).await?;
+        let plan = self.prepare_statement(&query.query, &ctx).await?;
         let schema_bytes = self.df_schema_to_arrow(plan.schema())?;
         let handle = self.cache_plan(plan);
         tracing::debug!(%handle, "Prepared statement");
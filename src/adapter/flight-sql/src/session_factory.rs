@@ -9,7 +9,7 @@
 
 use std::sync::Arc;
 
-use datafusion::prelude::SessionContext;
+use datafusion::prelude::{DataFrame, SessionContext};
 use tonic::Status;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -26,4 +26,18 @@ pub trait SessionFactory: Send + Sync {
     async fn get_context(&self, token: &Token) -> Result<Arc<SessionContext>, Status> {
         Err(Status::unauthenticated("Invalid credentials!"))?
     }
+
+    /// Allows to expand syntax extensions in the query before it is planned
+    fn rewrite_query(&self, query: &str) -> Result<String, Status> {
+        Ok(query.to_string())
+    }
+
+    /// Executes the statement query. Implementations can override this to
+    /// serve the results from a cache.
+    async fn execute_query(&self, ctx: &SessionContext, query: &str) -> Result<DataFrame, Status> {
+        let query = self.rewrite_query(query)?;
+        ctx.sql(&query)
+            .await
+            .map_err(|e| Status::internal(format!("Error building plan: {e}")))
+    }
 }
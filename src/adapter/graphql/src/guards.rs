@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use async_graphql::{Context, Guard, Result};
-use kamu_accounts::{AnonymousAccountReason, CurrentAccountSubject};
+use kamu_accounts::{AccessTokenScopes, AnonymousAccountReason, CurrentAccountSubject};
 
 use crate::prelude::from_catalog;
 
@@ -67,3 +67,41 @@ impl Guard for AdminGuard {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub const TOKEN_SCOPE_FORBIDDEN_MESSAGE: &str =
+    "Operation is not permitted by the access token scopes";
+
+/// Rejects logged accounts whose access token scopes do not permit the
+/// operation. Anonymous access is left to other guards and checks.
+pub struct TokenScopeGuard {
+    allows: fn(&AccessTokenScopes) -> bool,
+}
+
+impl TokenScopeGuard {
+    pub fn dataset_creation() -> Self {
+        Self {
+            allows: AccessTokenScopes::allows_dataset_creation,
+        }
+    }
+
+    pub fn account_management() -> Self {
+        Self {
+            allows: AccessTokenScopes::allows_admin,
+        }
+    }
+}
+
+impl Guard for TokenScopeGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        let current_account_subject = from_catalog::<CurrentAccountSubject>(ctx).unwrap();
+
+        match current_account_subject.as_ref() {
+            CurrentAccountSubject::Logged(a) if !(self.allows)(&a.token_scopes) => {
+                Err(async_graphql::Error::new(TOKEN_SCOPE_FORBIDDEN_MESSAGE))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
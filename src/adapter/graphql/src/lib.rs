@@ -17,6 +17,8 @@ pub(crate) mod prelude;
 pub(crate) mod queries;
 mod root;
 pub mod scalars;
+mod subscriptions;
+pub use subscriptions::{SubscriptionEventHub, MESSAGE_CONSUMER_KAMU_GQL_SUBSCRIPTION_EVENT_HUB};
 pub(crate) mod utils;
 
 pub use root::*;
@@ -9,8 +9,9 @@
 
 use kamu_accounts::Account;
 
-use super::AccountFlowsMut;
+use super::{AccountFlowsMut, AccountWebhooksMut};
 use crate::prelude::*;
+use crate::TokenScopeGuard;
 
 #[derive(Debug, Clone)]
 pub struct AccountMut {
@@ -25,7 +26,14 @@ impl AccountMut {
     }
 
     /// Access to the mutable flow configurations of this account
+    #[graphql(guard = "TokenScopeGuard::account_management()")]
     async fn flows(&self) -> AccountFlowsMut {
         AccountFlowsMut::new(self.account.clone())
     }
+
+    /// Access to the webhook subscriptions of this account
+    #[graphql(guard = "TokenScopeGuard::account_management()")]
+    async fn webhooks(&self) -> AccountWebhooksMut {
+        AccountWebhooksMut::new(self.account.clone())
+    }
 }
@@ -0,0 +1,277 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_accounts::Account;
+use kamu_core::{DatasetRepository, DatasetRepositoryExt};
+use kamu_webhooks::{
+    CreateWebhookSubscriptionError,
+    DeleteWebhookSubscriptionError,
+    UpdateWebhookSubscriptionError,
+    WebhookSubscriptionService,
+};
+
+use crate::prelude::*;
+use crate::queries::WebhookSubscription;
+use crate::utils;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct AccountWebhooksMut {
+    account: Account,
+}
+
+#[Object]
+impl AccountWebhooksMut {
+    #[graphql(skip)]
+    pub fn new(account: Account) -> Self {
+        Self { account }
+    }
+
+    /// Subscribes an endpoint to notifications about events of all datasets
+    /// of this account, or of a single dataset when one is specified
+    async fn create_subscription(
+        &self,
+        ctx: &Context<'_>,
+        target_url: String,
+        event_types: Vec<WebhookEventType>,
+        dataset_id: Option<DatasetID>,
+    ) -> Result<CreateWebhookSubscriptionResult> {
+        if let Some(dataset_id) = &dataset_id {
+            let dataset_repo = from_catalog::<dyn DatasetRepository>(ctx).unwrap();
+            let Some(dataset_handle) = dataset_repo
+                .try_resolve_dataset_ref(&dataset_id.as_local_ref())
+                .await?
+            else {
+                return Ok(CreateWebhookSubscriptionResult::DatasetNotFound(
+                    CreateWebhookSubscriptionResultDatasetNotFound {
+                        dataset_id: dataset_id.clone(),
+                    },
+                ));
+            };
+
+            utils::check_dataset_read_access(ctx, &dataset_handle).await?;
+        }
+
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+        match subscription_service
+            .create_subscription(
+                &self.account.id,
+                &target_url,
+                event_types.into_iter().map(Into::into).collect(),
+                dataset_id.map(Into::into),
+            )
+            .await
+        {
+            Ok(subscription) => Ok(CreateWebhookSubscriptionResult::Success(
+                CreateWebhookSubscriptionResultSuccess {
+                    secret: subscription.secret.clone(),
+                    subscription: WebhookSubscription::new(subscription),
+                },
+            )),
+            Err(CreateWebhookSubscriptionError::InvalidTargetUrl(e)) => {
+                Ok(CreateWebhookSubscriptionResult::InvalidTargetUrl(
+                    CreateWebhookSubscriptionResultInvalidTargetUrl {
+                        message: e.to_string(),
+                    },
+                ))
+            }
+            Err(CreateWebhookSubscriptionError::NoEventTypes(e)) => {
+                Ok(CreateWebhookSubscriptionResult::NoEventTypes(
+                    CreateWebhookSubscriptionResultNoEventTypes {
+                        message: e.to_string(),
+                    },
+                ))
+            }
+            Err(CreateWebhookSubscriptionError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
+    /// Removes the subscription along with its delivery history
+    async fn remove_subscription(
+        &self,
+        ctx: &Context<'_>,
+        subscription_id: WebhookSubscriptionID,
+    ) -> Result<RemoveWebhookSubscriptionResult> {
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+        match subscription_service
+            .remove_subscription(&self.account.id, &subscription_id)
+            .await
+        {
+            Ok(_) => Ok(RemoveWebhookSubscriptionResult::Success(
+                RemoveWebhookSubscriptionResultSuccess { subscription_id },
+            )),
+            Err(DeleteWebhookSubscriptionError::NotFound(_)) => {
+                Ok(RemoveWebhookSubscriptionResult::NotFound(
+                    WebhookSubscriptionResultNotFound { subscription_id },
+                ))
+            }
+            Err(DeleteWebhookSubscriptionError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
+    /// Stops delivering notifications to the subscribed endpoint
+    async fn pause_subscription(
+        &self,
+        ctx: &Context<'_>,
+        subscription_id: WebhookSubscriptionID,
+    ) -> Result<UpdateWebhookSubscriptionResult> {
+        self.set_subscription_enabled(ctx, subscription_id, false)
+            .await
+    }
+
+    /// Resumes delivering notifications to the subscribed endpoint
+    async fn resume_subscription(
+        &self,
+        ctx: &Context<'_>,
+        subscription_id: WebhookSubscriptionID,
+    ) -> Result<UpdateWebhookSubscriptionResult> {
+        self.set_subscription_enabled(ctx, subscription_id, true)
+            .await
+    }
+
+    #[graphql(skip)]
+    async fn set_subscription_enabled(
+        &self,
+        ctx: &Context<'_>,
+        subscription_id: WebhookSubscriptionID,
+        enabled: bool,
+    ) -> Result<UpdateWebhookSubscriptionResult> {
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+        match subscription_service
+            .set_subscription_enabled(&self.account.id, &subscription_id, enabled)
+            .await
+        {
+            Ok(subscription) => Ok(UpdateWebhookSubscriptionResult::Success(
+                UpdateWebhookSubscriptionResultSuccess {
+                    subscription: WebhookSubscription::new(subscription),
+                },
+            )),
+            Err(UpdateWebhookSubscriptionError::NotFound(_)) => {
+                Ok(UpdateWebhookSubscriptionResult::NotFound(
+                    WebhookSubscriptionResultNotFound { subscription_id },
+                ))
+            }
+            Err(UpdateWebhookSubscriptionError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum CreateWebhookSubscriptionResult {
+    Success(CreateWebhookSubscriptionResultSuccess),
+    InvalidTargetUrl(CreateWebhookSubscriptionResultInvalidTargetUrl),
+    NoEventTypes(CreateWebhookSubscriptionResultNoEventTypes),
+    DatasetNotFound(CreateWebhookSubscriptionResultDatasetNotFound),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct CreateWebhookSubscriptionResultSuccess {
+    pub subscription: WebhookSubscription,
+    /// Secret used to sign request bodies. It is only revealed once, upon
+    /// creation of the subscription
+    pub secret: String,
+}
+
+#[ComplexObject]
+impl CreateWebhookSubscriptionResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct CreateWebhookSubscriptionResultInvalidTargetUrl {
+    pub message: String,
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct CreateWebhookSubscriptionResultNoEventTypes {
+    pub message: String,
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct CreateWebhookSubscriptionResultDatasetNotFound {
+    pub dataset_id: DatasetID,
+}
+
+#[ComplexObject]
+impl CreateWebhookSubscriptionResultDatasetNotFound {
+    async fn message(&self) -> String {
+        format!("Dataset {} not found", *self.dataset_id)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum RemoveWebhookSubscriptionResult {
+    Success(RemoveWebhookSubscriptionResultSuccess),
+    NotFound(WebhookSubscriptionResultNotFound),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct RemoveWebhookSubscriptionResultSuccess {
+    pub subscription_id: WebhookSubscriptionID,
+}
+
+#[ComplexObject]
+impl RemoveWebhookSubscriptionResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum UpdateWebhookSubscriptionResult {
+    Success(UpdateWebhookSubscriptionResultSuccess),
+    NotFound(WebhookSubscriptionResultNotFound),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct UpdateWebhookSubscriptionResultSuccess {
+    pub subscription: WebhookSubscription,
+}
+
+#[ComplexObject]
+impl UpdateWebhookSubscriptionResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct WebhookSubscriptionResultNotFound {
+    pub subscription_id: WebhookSubscriptionID,
+}
+
+#[ComplexObject]
+impl WebhookSubscriptionResultNotFound {
+    async fn message(&self) -> String {
+        format!("Webhook subscription {} not found", self.subscription_id)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -8,11 +8,13 @@
 // by the Apache License, Version 2.0.
 
 use async_graphql::Context;
-use kamu_accounts::AuthenticationService;
+use kamu_accounts::{AuthenticationService, CurrentAccountSubject};
+use kamu_auth_rebac::{AccountToOrganizationRelation, OrganizationMembershipService};
 
-use crate::mutations::AccountMut;
+use crate::mutations::{AccountMut, OrganizationMut};
 use crate::prelude::*;
 use crate::utils::{check_logged_account_id_match, check_logged_account_name_match};
+use crate::TokenScopeGuard;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -45,4 +47,57 @@ impl AccountsMut {
             .await?;
         Ok(account_maybe.map(AccountMut::new))
     }
+
+    /// Returns a mutable organization by its name. Only available to owners
+    /// of the organization and administrators
+    #[graphql(guard = "TokenScopeGuard::account_management()")]
+    async fn organization(
+        &self,
+        ctx: &Context<'_>,
+        account_name: AccountName,
+    ) -> Result<Option<OrganizationMut>> {
+        let current_account_subject = from_catalog::<CurrentAccountSubject>(ctx).unwrap();
+        let CurrentAccountSubject::Logged(logged_account) = current_account_subject.as_ref() else {
+            return Err(organization_access_error(&account_name));
+        };
+
+        let authentication_service = from_catalog::<dyn AuthenticationService>(ctx).unwrap();
+        let Some(organization) = authentication_service
+            .account_by_name(&account_name)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if organization.account_type != kamu_accounts::AccountType::Organization {
+            return Err(GqlError::Gql(
+                Error::new("Account is not an organization")
+                    .extend_with(|_, eev| eev.set("account_name", account_name.to_string())),
+            ));
+        }
+
+        if !logged_account.is_admin {
+            let membership_service =
+                from_catalog::<dyn OrganizationMembershipService>(ctx).unwrap();
+
+            let role = membership_service
+                .get_member_role(&organization.id, &logged_account.account_id)
+                .await?;
+
+            if role != Some(AccountToOrganizationRelation::Owner) {
+                return Err(organization_access_error(&account_name));
+            }
+        }
+
+        Ok(Some(OrganizationMut::new(organization)))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn organization_access_error(account_name: &AccountName) -> GqlError {
+    GqlError::Gql(
+        Error::new("Account access error")
+            .extend_with(|_, eev| eev.set("account_name", account_name.to_string())),
+    )
 }
@@ -7,11 +7,19 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use kamu_accounts::{CreateAccessTokenError, RevokeTokenError};
+use chrono::{DateTime, Utc};
+use kamu_accounts::{
+    AccessTokenScope,
+    AccessTokenScopes,
+    CreateAccessTokenError,
+    CurrentAccountSubject,
+    RevokeTokenError,
+};
 
 use crate::prelude::*;
 use crate::queries::{Account, CreateAccessTokenResultSuccess, CreatedAccessToken};
 use crate::utils::{check_access_token_valid, check_logged_account_id_match};
+use crate::TokenScopeGuard;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -48,19 +56,46 @@ impl AuthMut {
         }
     }
 
+    #[graphql(guard = "TokenScopeGuard::account_management()")]
     async fn create_access_token(
         &self,
         ctx: &Context<'_>,
         account_id: AccountID,
         token_name: String,
+        expires_at: Option<DateTime<Utc>>,
+        scopes: Option<Vec<String>>,
     ) -> Result<CreateTokenResult> {
         check_logged_account_id_match(ctx, &account_id)?;
 
+        let scopes = match scopes {
+            None => AccessTokenScopes::Unrestricted,
+            Some(scopes) => match scopes
+                .iter()
+                .map(|s| s.parse::<AccessTokenScope>())
+                .collect::<Result<Vec<_>, _>>()
+            {
+                // A token restricted to no scopes at all would be useless
+                Ok(scopes) if scopes.is_empty() => {
+                    return Ok(CreateTokenResult::InvalidScope(
+                        CreateAccessTokenResultInvalidScope {
+                            scope: String::new(),
+                        },
+                    ))
+                }
+                Ok(scopes) => AccessTokenScopes::Restricted(scopes),
+                Err(e) => {
+                    return Ok(CreateTokenResult::InvalidScope(
+                        CreateAccessTokenResultInvalidScope { scope: e.scope },
+                    ))
+                }
+            },
+        };
+
         let access_token_service =
             from_catalog::<dyn kamu_accounts::AccessTokenService>(ctx).unwrap();
 
         match access_token_service
-            .create_access_token(&token_name, &account_id)
+            .create_access_token(&token_name, &account_id, expires_at, scopes)
             .await
         {
             Ok(created_token) => Ok(CreateTokenResult::Success(CreateAccessTokenResultSuccess {
@@ -70,6 +105,11 @@ impl AuthMut {
                 CreateAccessTokenError::Duplicate(_) => Ok(CreateTokenResult::DuplicateName(
                     CreateAccessTokenResultDuplicate { token_name },
                 )),
+                CreateAccessTokenError::AlreadyExpired => Ok(CreateTokenResult::AlreadyExpired(
+                    CreateAccessTokenResultAlreadyExpired {
+                        expires_at: expires_at.unwrap(),
+                    },
+                )),
                 CreateAccessTokenError::Internal(internal_err) => {
                     Err(GqlError::Internal(internal_err))
                 }
@@ -77,6 +117,7 @@ impl AuthMut {
         }
     }
 
+    #[graphql(guard = "TokenScopeGuard::account_management()")]
     async fn revoke_access_token(
         &self,
         ctx: &Context<'_>,
@@ -84,10 +125,14 @@ impl AuthMut {
     ) -> Result<RevokeResult> {
         check_access_token_valid(ctx, &token_id).await?;
 
+        let current_account_subject = from_catalog::<CurrentAccountSubject>(ctx).unwrap();
         let access_token_service =
             from_catalog::<dyn kamu_accounts::AccessTokenService>(ctx).unwrap();
 
-        match access_token_service.revoke_access_token(&token_id).await {
+        match access_token_service
+            .revoke_access_token(&token_id, current_account_subject.maybe_account_id())
+            .await
+        {
             Ok(_) => Ok(RevokeResult::Success(RevokeResultSuccess { token_id })),
             Err(RevokeTokenError::AlreadyRevoked) => {
                 Ok(RevokeResult::AlreadyRevoked(RevokeResultAlreadyRevoked {
@@ -198,6 +243,8 @@ impl RevokeResultAlreadyRevoked {
 pub enum CreateTokenResult {
     Success(CreateAccessTokenResultSuccess),
     DuplicateName(CreateAccessTokenResultDuplicate),
+    InvalidScope(CreateAccessTokenResultInvalidScope),
+    AlreadyExpired(CreateAccessTokenResultAlreadyExpired),
 }
 
 #[derive(SimpleObject, Debug, Clone)]
@@ -212,3 +259,32 @@ impl CreateAccessTokenResultDuplicate {
         format!("Access token with {} name already exists", self.token_name)
     }
 }
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct CreateAccessTokenResultInvalidScope {
+    pub scope: String,
+}
+
+#[ComplexObject]
+impl CreateAccessTokenResultInvalidScope {
+    pub async fn message(&self) -> String {
+        format!("Invalid access token scope '{}'", self.scope)
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct CreateAccessTokenResultAlreadyExpired {
+    pub expires_at: DateTime<Utc>,
+}
+
+#[ComplexObject]
+impl CreateAccessTokenResultAlreadyExpired {
+    pub async fn message(&self) -> String {
+        format!(
+            "Access token expiration time {} is in the past",
+            self.expires_at
+        )
+    }
+}
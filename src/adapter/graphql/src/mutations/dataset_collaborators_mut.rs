@@ -0,0 +1,225 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_accounts::{Account, AuthenticationService, CurrentAccountSubject};
+use kamu_audit_log::{AuditAction, AuditLogService};
+use kamu_auth_rebac::{
+    AccountToDatasetRelation,
+    DatasetCollaborationService,
+    DatasetCollaborator as DatasetCollaboratorEntity,
+    RemoveDatasetCollaboratorError,
+};
+use opendatafabric as odf;
+
+use crate::prelude::*;
+use crate::queries::DatasetCollaborator;
+use crate::utils;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DatasetCollaboratorsMut {
+    dataset_handle: odf::DatasetHandle,
+}
+
+#[Object]
+impl DatasetCollaboratorsMut {
+    #[graphql(skip)]
+    pub fn new(dataset_handle: odf::DatasetHandle) -> Self {
+        Self { dataset_handle }
+    }
+
+    /// Grants the role to the account, replacing the previously granted one
+    async fn grant_role(
+        &self,
+        ctx: &Context<'_>,
+        account_name: AccountName,
+        role: DatasetRole,
+    ) -> Result<GrantDatasetRoleResult> {
+        utils::check_dataset_manage_permissions_access(ctx, &self.dataset_handle).await?;
+
+        let Some(account) = self.resolve_account(ctx, &account_name).await? else {
+            return Ok(GrantDatasetRoleResult::AccountNotFound(
+                DatasetRoleResultAccountNotFound { account_name },
+            ));
+        };
+
+        let collaboration_service = from_catalog::<dyn DatasetCollaborationService>(ctx).unwrap();
+
+        collaboration_service
+            .set_collaborator_role(&self.dataset_handle.id, &account.id, role.into())
+            .await?;
+
+        self.record_role_change(
+            ctx,
+            AuditAction::DatasetRoleGranted,
+            format!(
+                "Granted role '{}' to account '{}'",
+                AccountToDatasetRelation::from(role),
+                account.account_name
+            ),
+        )
+        .await?;
+
+        Ok(GrantDatasetRoleResult::Success(
+            GrantDatasetRoleResultSuccess {
+                collaborator: DatasetCollaborator::new(DatasetCollaboratorEntity {
+                    account_id: account.id,
+                    dataset_id: self.dataset_handle.id.clone(),
+                    role: role.into(),
+                }),
+            },
+        ))
+    }
+
+    /// Revokes the role previously granted to the account
+    async fn revoke_role(
+        &self,
+        ctx: &Context<'_>,
+        account_name: AccountName,
+    ) -> Result<RevokeDatasetRoleResult> {
+        utils::check_dataset_manage_permissions_access(ctx, &self.dataset_handle).await?;
+
+        let Some(account) = self.resolve_account(ctx, &account_name).await? else {
+            return Ok(RevokeDatasetRoleResult::AccountNotFound(
+                DatasetRoleResultAccountNotFound { account_name },
+            ));
+        };
+
+        let collaboration_service = from_catalog::<dyn DatasetCollaborationService>(ctx).unwrap();
+
+        match collaboration_service
+            .remove_collaborator(&self.dataset_handle.id, &account.id)
+            .await
+        {
+            Ok(_) => {
+                self.record_role_change(
+                    ctx,
+                    AuditAction::DatasetRoleRevoked,
+                    format!("Revoked role of account '{}'", account.account_name),
+                )
+                .await?;
+
+                Ok(RevokeDatasetRoleResult::Success(
+                    RevokeDatasetRoleResultSuccess { account_name },
+                ))
+            }
+            Err(RemoveDatasetCollaboratorError::NotACollaborator(e)) => {
+                Ok(RevokeDatasetRoleResult::NotACollaborator(
+                    RevokeDatasetRoleResultNotACollaborator {
+                        message: e.to_string(),
+                    },
+                ))
+            }
+            Err(RemoveDatasetCollaboratorError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
+    #[graphql(skip)]
+    async fn record_role_change(
+        &self,
+        ctx: &Context<'_>,
+        action: AuditAction,
+        details: String,
+    ) -> Result<()> {
+        let current_account_subject = from_catalog::<CurrentAccountSubject>(ctx).unwrap();
+        let audit_log_service = from_catalog::<dyn AuditLogService>(ctx).unwrap();
+
+        audit_log_service
+            .record(
+                current_account_subject.maybe_account_id(),
+                action,
+                Some(&self.dataset_handle.id),
+                Some(format!(
+                    "{details} in dataset '{}'",
+                    self.dataset_handle.alias
+                )),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[graphql(skip)]
+    async fn resolve_account(
+        &self,
+        ctx: &Context<'_>,
+        account_name: &AccountName,
+    ) -> Result<Option<Account>> {
+        let authentication_service = from_catalog::<dyn AuthenticationService>(ctx).unwrap();
+
+        Ok(authentication_service.account_by_name(account_name).await?)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum GrantDatasetRoleResult {
+    Success(GrantDatasetRoleResultSuccess),
+    AccountNotFound(DatasetRoleResultAccountNotFound),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct GrantDatasetRoleResultSuccess {
+    pub collaborator: DatasetCollaborator,
+}
+
+#[ComplexObject]
+impl GrantDatasetRoleResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum RevokeDatasetRoleResult {
+    Success(RevokeDatasetRoleResultSuccess),
+    AccountNotFound(DatasetRoleResultAccountNotFound),
+    NotACollaborator(RevokeDatasetRoleResultNotACollaborator),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct RevokeDatasetRoleResultSuccess {
+    pub account_name: AccountName,
+}
+
+#[ComplexObject]
+impl RevokeDatasetRoleResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct RevokeDatasetRoleResultNotACollaborator {
+    pub message: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct DatasetRoleResultAccountNotFound {
+    pub account_name: AccountName,
+}
+
+#[ComplexObject]
+impl DatasetRoleResultAccountNotFound {
+    async fn message(&self) -> String {
+        format!("Account '{}' not found", *self.account_name)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
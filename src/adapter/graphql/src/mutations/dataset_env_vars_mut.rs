@@ -41,7 +41,7 @@ impl DatasetEnvVarsMut {
         value: String,
         is_secret: bool,
     ) -> Result<SaveDatasetEnvVarResult> {
-        utils::check_dataset_write_access(ctx, &self.dataset_handle).await?;
+        utils::check_dataset_maintain_access(ctx, &self.dataset_handle).await?;
 
         let dataset_env_var_service = from_catalog::<dyn DatasetEnvVarService>(ctx).unwrap();
 
@@ -83,7 +83,7 @@ impl DatasetEnvVarsMut {
         ctx: &Context<'_>,
         id: DatasetEnvVarID,
     ) -> Result<DeleteDatasetEnvVarResult> {
-        utils::check_dataset_write_access(ctx, &self.dataset_handle).await?;
+        utils::check_dataset_maintain_access(ctx, &self.dataset_handle).await?;
 
         let dataset_env_var_service = from_catalog::<dyn DatasetEnvVarService>(ctx).unwrap();
 
@@ -116,7 +116,7 @@ impl DatasetEnvVarsMut {
         new_value: String,
         is_secret: bool,
     ) -> Result<ModifyDatasetEnvVarResult> {
-        utils::check_dataset_write_access(ctx, &self.dataset_handle).await?;
+        utils::check_dataset_maintain_access(ctx, &self.dataset_handle).await?;
 
         let dataset_env_var_service = from_catalog::<dyn DatasetEnvVarService>(ctx).unwrap();
         let dataset_env_var_value = if is_secret {
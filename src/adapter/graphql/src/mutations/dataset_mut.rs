@@ -12,7 +12,7 @@ use domain::{DeleteDatasetError, RenameDatasetError};
 use kamu_core::{self as domain};
 use opendatafabric as odf;
 
-use super::{DatasetEnvVarsMut, DatasetFlowsMut, DatasetMetadataMut};
+use super::{DatasetCollaboratorsMut, DatasetEnvVarsMut, DatasetFlowsMut, DatasetMetadataMut};
 use crate::prelude::*;
 use crate::utils::ensure_dataset_env_vars_enabled;
 use crate::LoggedInGuard;
@@ -39,6 +39,12 @@ impl DatasetMut {
         DatasetFlowsMut::new(self.dataset_handle.clone())
     }
 
+    /// Access to the roles granted to other accounts in this dataset
+    #[graphql(guard = "LoggedInGuard::new()")]
+    async fn collaborators(&self) -> DatasetCollaboratorsMut {
+        DatasetCollaboratorsMut::new(self.dataset_handle.clone())
+    }
+
     /// Access to the mutable flow configurations of this dataset
     #[allow(clippy::unused_async)]
     async fn env_vars(&self, ctx: &Context<'_>) -> Result<DatasetEnvVarsMut> {
@@ -13,7 +13,7 @@ use opendatafabric as odf;
 use crate::mutations::DatasetMut;
 use crate::prelude::*;
 use crate::queries::Dataset;
-use crate::LoggedInGuard;
+use crate::{LoggedInGuard, TokenScopeGuard};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -33,7 +33,7 @@ impl DatasetsMut {
     }
 
     /// Creates a new empty dataset
-    #[graphql(guard = "LoggedInGuard::new()")]
+    #[graphql(guard = "LoggedInGuard::new().and(TokenScopeGuard::dataset_creation())")]
     async fn create_empty(
         &self,
         ctx: &Context<'_>,
@@ -67,7 +67,7 @@ impl DatasetsMut {
     }
 
     /// Creates a new dataset from provided DatasetSnapshot manifest
-    #[graphql(guard = "LoggedInGuard::new()")]
+    #[graphql(guard = "LoggedInGuard::new().and(TokenScopeGuard::dataset_creation())")]
     async fn create_from_snapshot(
         &self,
         ctx: &Context<'_>,
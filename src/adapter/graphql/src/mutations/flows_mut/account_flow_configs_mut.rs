@@ -9,8 +9,9 @@
 
 use chrono::Utc;
 use fs::FlowConfigurationService;
-use kamu_accounts::Account;
-use kamu_core::DatasetOwnershipService;
+use kamu_accounts::{Account, CurrentAccountSubject};
+use kamu_audit_log::{AuditAction, AuditLogService};
+use kamu_core::{DatasetOwnershipService, DatasetRepository};
 use kamu_flow_system as fs;
 use opendatafabric::DatasetID;
 
@@ -37,6 +38,36 @@ impl AccountFlowConfigsMut {
         Ok(dataset_ids)
     }
 
+    /// Records a separate event for every affected dataset, same as when flows
+    /// of a single dataset are paused or resumed
+    #[graphql(skip)]
+    async fn record_config_update(
+        &self,
+        ctx: &Context<'_>,
+        dataset_id: &DatasetID,
+        details: &str,
+    ) -> Result<()> {
+        let current_account_subject = from_catalog::<CurrentAccountSubject>(ctx).unwrap();
+        let audit_log_service = from_catalog::<dyn AuditLogService>(ctx).unwrap();
+        let dataset_repo = from_catalog::<dyn DatasetRepository>(ctx).unwrap();
+
+        let dataset_handle = dataset_repo
+            .resolve_dataset_ref(&dataset_id.as_local_ref())
+            .await
+            .int_err()?;
+
+        audit_log_service
+            .record(
+                current_account_subject.maybe_account_id(),
+                AuditAction::FlowConfigurationUpdated,
+                Some(dataset_id),
+                Some(format!("{details} of dataset '{}'", dataset_handle.alias)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
     async fn resume_account_dataset_flows(&self, ctx: &Context<'_>) -> Result<bool> {
         let flow_config_service = from_catalog::<dyn FlowConfigurationService>(ctx).unwrap();
 
@@ -46,6 +77,9 @@ impl AccountFlowConfigsMut {
                 .resume_dataset_flows(Utc::now(), dataset_id, None)
                 .await
                 .int_err()?;
+
+            self.record_config_update(ctx, dataset_id, "Resumed all flows")
+                .await?;
         }
 
         Ok(true)
@@ -60,6 +94,9 @@ impl AccountFlowConfigsMut {
                 .pause_dataset_flows(Utc::now(), dataset_id, None)
                 .await
                 .int_err()?;
+
+            self.record_config_update(ctx, dataset_id, "Paused all flows")
+                .await?;
         }
 
         Ok(true)
@@ -8,6 +8,8 @@
 // by the Apache License, Version 2.0.
 
 use chrono::Utc;
+use kamu_accounts::CurrentAccountSubject;
+use kamu_audit_log::{AuditAction, AuditLogService};
 use kamu_flow_system::{
     CompactionRule,
     CompactionRuleFull,
@@ -16,16 +18,20 @@ use kamu_flow_system::{
     FlowConfigurationService,
     FlowKeyDataset,
     IngestRule,
+    RetentionRule,
+    Schedule,
     ScheduleCronError,
+    ScheduleTimeDelta,
     SetFlowConfigurationError,
     TransformRule,
+    VerifyRule,
 };
 use opendatafabric as odf;
 
 use super::{
     ensure_expected_dataset_kind,
+    ensure_flow_config_permission,
     ensure_flow_preconditions,
-    ensure_scheduling_permission,
     FlowIncompatibleDatasetKind,
     FlowPreconditionsNotMet,
 };
@@ -45,6 +51,26 @@ impl DatasetFlowConfigsMut {
         Self { dataset_handle }
     }
 
+    #[graphql(skip)]
+    async fn record_config_update(&self, ctx: &Context<'_>, details: String) -> Result<()> {
+        let current_account_subject = from_catalog::<CurrentAccountSubject>(ctx).unwrap();
+        let audit_log_service = from_catalog::<dyn AuditLogService>(ctx).unwrap();
+
+        audit_log_service
+            .record(
+                current_account_subject.maybe_account_id(),
+                AuditAction::FlowConfigurationUpdated,
+                Some(&self.dataset_handle.id),
+                Some(format!(
+                    "{details} of dataset '{}'",
+                    self.dataset_handle.alias
+                )),
+            )
+            .await?;
+
+        Ok(())
+    }
+
     #[graphql(guard = "LoggedInGuard::new()")]
     async fn set_config_ingest(
         &self,
@@ -69,7 +95,7 @@ impl DatasetFlowConfigsMut {
             return Ok(SetFlowConfigResult::IncompatibleDatasetKind(e));
         }
 
-        ensure_scheduling_permission(ctx, &self.dataset_handle).await?;
+        ensure_flow_config_permission(ctx, &self.dataset_handle).await?;
         if let Some(e) =
             ensure_flow_preconditions(ctx, &self.dataset_handle, dataset_flow_type, None).await?
         {
@@ -94,6 +120,12 @@ impl DatasetFlowConfigsMut {
                 SetFlowConfigurationError::Internal(e) => GqlError::Internal(e),
             })?;
 
+        self.record_config_update(
+            ctx,
+            format!("Updated {dataset_flow_type:?} flow configuration"),
+        )
+        .await?;
+
         Ok(SetFlowConfigResult::Success(SetFlowConfigSuccess {
             config: res.into(),
         }))
@@ -137,7 +169,7 @@ impl DatasetFlowConfigsMut {
             return Ok(SetFlowTransformConfigResult::IncompatibleDatasetKind(e));
         }
 
-        ensure_scheduling_permission(ctx, &self.dataset_handle).await?;
+        ensure_flow_config_permission(ctx, &self.dataset_handle).await?;
         if let Some(e) =
             ensure_flow_preconditions(ctx, &self.dataset_handle, dataset_flow_type, None).await?
         {
@@ -159,6 +191,12 @@ impl DatasetFlowConfigsMut {
                 SetFlowConfigurationError::Internal(e) => GqlError::Internal(e),
             })?;
 
+        self.record_config_update(
+            ctx,
+            format!("Updated {dataset_flow_type:?} flow configuration"),
+        )
+        .await?;
+
         Ok(SetFlowTransformConfigResult::Success(
             SetFlowConfigSuccess { config: res.into() },
         ))
@@ -210,7 +248,7 @@ impl DatasetFlowConfigsMut {
         {
             return Ok(SetFlowCompactionConfigResult::IncompatibleDatasetKind(e));
         }
-        ensure_scheduling_permission(ctx, &self.dataset_handle).await?;
+        ensure_flow_config_permission(ctx, &self.dataset_handle).await?;
 
         let flow_config_service = from_catalog::<dyn FlowConfigurationService>(ctx).unwrap();
 
@@ -227,18 +265,145 @@ impl DatasetFlowConfigsMut {
                 SetFlowConfigurationError::Internal(e) => GqlError::Internal(e),
             })?;
 
+        self.record_config_update(
+            ctx,
+            format!("Updated {dataset_flow_type:?} flow configuration"),
+        )
+        .await?;
+
         Ok(SetFlowCompactionConfigResult::Success(
             SetFlowConfigSuccess { config: res.into() },
         ))
     }
 
+    #[graphql(guard = "LoggedInGuard::new()")]
+    async fn set_config_verify(
+        &self,
+        ctx: &Context<'_>,
+        dataset_flow_type: DatasetFlowType,
+        paused: bool,
+        verify: VerifyConditionInput,
+    ) -> Result<SetFlowConfigResult> {
+        let flow_run_config: FlowRunConfiguration = verify.clone().into();
+        if let Err(err) = flow_run_config.check_type_compatible(dataset_flow_type) {
+            return Ok(SetFlowConfigResult::TypeIsNotSupported(err));
+        };
+
+        ensure_flow_config_permission(ctx, &self.dataset_handle).await?;
+
+        let flow_config_service = from_catalog::<dyn FlowConfigurationService>(ctx).unwrap();
+        let configuration_rule: VerifyRule = verify
+            .try_into()
+            .map_err(|e: ScheduleCronError| GqlError::Gql(e.into()))?;
+
+        let res = flow_config_service
+            .set_configuration(
+                Utc::now(),
+                FlowKeyDataset::new(self.dataset_handle.id.clone(), dataset_flow_type.into())
+                    .into(),
+                paused,
+                FlowConfigurationRule::VerifyRule(configuration_rule),
+            )
+            .await
+            .map_err(|e| match e {
+                SetFlowConfigurationError::Internal(e) => GqlError::Internal(e),
+            })?;
+
+        self.record_config_update(
+            ctx,
+            format!("Updated {dataset_flow_type:?} flow configuration"),
+        )
+        .await?;
+
+        Ok(SetFlowConfigResult::Success(SetFlowConfigSuccess {
+            config: res.into(),
+        }))
+    }
+
+    #[graphql(guard = "LoggedInGuard::new()")]
+    async fn set_config_retention(
+        &self,
+        ctx: &Context<'_>,
+        dataset_flow_type: DatasetFlowType,
+        paused: bool,
+        retention: RetentionConditionInput,
+    ) -> Result<SetFlowRetentionConfigResult> {
+        let flow_run_config: FlowRunConfiguration = retention.clone().into();
+        if let Err(err) = flow_run_config.check_type_compatible(dataset_flow_type) {
+            return Ok(SetFlowRetentionConfigResult::TypeIsNotSupported(err));
+        };
+
+        let schedule_condition = match retention.schedule {
+            ScheduleInput::TimeDelta(td) => {
+                Schedule::TimeDelta(ScheduleTimeDelta { every: td.into() })
+            }
+            ScheduleInput::Cron5ComponentExpression(cron_5component_expression) => {
+                Schedule::try_from_5component_cron_expression(&cron_5component_expression)
+                    .map_err(|e: ScheduleCronError| GqlError::Gql(e.into()))?
+            }
+        };
+
+        let retention_rule = match RetentionRule::new_checked(
+            retention.retention_window.into(),
+            retention.dry_run,
+            schedule_condition,
+        ) {
+            Ok(rule) => rule,
+            Err(e) => {
+                return Ok(SetFlowRetentionConfigResult::InvalidRetentionConfig(
+                    FlowInvalidRetentionConfig {
+                        reason: e.to_string(),
+                    },
+                ))
+            }
+        };
+
+        if let Some(e) = ensure_expected_dataset_kind(
+            ctx,
+            &self.dataset_handle,
+            dataset_flow_type,
+            Some(&flow_run_config),
+        )
+        .await?
+        {
+            return Ok(SetFlowRetentionConfigResult::IncompatibleDatasetKind(e));
+        }
+
+        ensure_flow_config_permission(ctx, &self.dataset_handle).await?;
+
+        let flow_config_service = from_catalog::<dyn FlowConfigurationService>(ctx).unwrap();
+
+        let res = flow_config_service
+            .set_configuration(
+                Utc::now(),
+                FlowKeyDataset::new(self.dataset_handle.id.clone(), dataset_flow_type.into())
+                    .into(),
+                paused,
+                FlowConfigurationRule::RetentionRule(retention_rule),
+            )
+            .await
+            .map_err(|e| match e {
+                SetFlowConfigurationError::Internal(e) => GqlError::Internal(e),
+            })?;
+
+        self.record_config_update(
+            ctx,
+            format!("Updated {dataset_flow_type:?} flow configuration"),
+        )
+        .await?;
+
+        Ok(SetFlowRetentionConfigResult::Success(
+            SetFlowConfigSuccess { config: res.into() },
+        ))
+    }
+
     #[graphql(guard = "LoggedInGuard::new()")]
     async fn pause_flows(
         &self,
         ctx: &Context<'_>,
         dataset_flow_type: Option<DatasetFlowType>,
     ) -> Result<bool> {
-        ensure_scheduling_permission(ctx, &self.dataset_handle).await?;
+        ensure_flow_config_permission(ctx, &self.dataset_handle).await?;
 
         let flow_config_service = from_catalog::<dyn FlowConfigurationService>(ctx).unwrap();
 
@@ -250,6 +415,13 @@ impl DatasetFlowConfigsMut {
             )
             .await?;
 
+        let flow_types = match dataset_flow_type {
+            Some(dataset_flow_type) => format!("{dataset_flow_type:?} flows"),
+            None => "all flows".to_string(),
+        };
+        self.record_config_update(ctx, format!("Paused {flow_types}"))
+            .await?;
+
         Ok(true)
     }
 
@@ -259,7 +431,7 @@ impl DatasetFlowConfigsMut {
         ctx: &Context<'_>,
         dataset_flow_type: Option<DatasetFlowType>,
     ) -> Result<bool> {
-        ensure_scheduling_permission(ctx, &self.dataset_handle).await?;
+        ensure_flow_config_permission(ctx, &self.dataset_handle).await?;
 
         let flow_config_service = from_catalog::<dyn FlowConfigurationService>(ctx).unwrap();
 
@@ -271,6 +443,13 @@ impl DatasetFlowConfigsMut {
             )
             .await?;
 
+        let flow_types = match dataset_flow_type {
+            Some(dataset_flow_type) => format!("{dataset_flow_type:?} flows"),
+            None => "all flows".to_string(),
+        };
+        self.record_config_update(ctx, format!("Resumed {flow_types}"))
+            .await?;
+
         Ok(true)
     }
 }
@@ -335,6 +514,19 @@ impl FlowInvalidCompactionConfig {
     }
 }
 
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub(crate) struct FlowInvalidRetentionConfig {
+    reason: String,
+}
+
+#[ComplexObject]
+impl FlowInvalidRetentionConfig {
+    pub async fn message(&self) -> String {
+        self.reason.clone()
+    }
+}
+
 #[derive(Interface)]
 #[graphql(field(name = "message", ty = "String"))]
 enum SetFlowCompactionConfigResult {
@@ -354,4 +546,13 @@ enum SetFlowTransformConfigResult {
     TypeIsNotSupported(FlowTypeIsNotSupported),
 }
 
+#[derive(Interface)]
+#[graphql(field(name = "message", ty = "String"))]
+enum SetFlowRetentionConfigResult {
+    Success(SetFlowConfigSuccess),
+    IncompatibleDatasetKind(FlowIncompatibleDatasetKind),
+    InvalidRetentionConfig(FlowInvalidRetentionConfig),
+    TypeIsNotSupported(FlowTypeIsNotSupported),
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -14,7 +14,7 @@ use super::{
     check_if_flow_belongs_to_dataset,
     ensure_expected_dataset_kind,
     ensure_flow_preconditions,
-    ensure_scheduling_permission,
+    ensure_flow_trigger_permission,
     FlowInDatasetError,
     FlowIncompatibleDatasetKind,
     FlowNotFound,
@@ -55,7 +55,13 @@ impl DatasetFlowRunsMut {
             return Ok(TriggerFlowResult::IncompatibleDatasetKind(e));
         }
 
-        ensure_scheduling_permission(ctx, &self.dataset_handle).await?;
+        ensure_flow_trigger_permission(
+            ctx,
+            &self.dataset_handle,
+            dataset_flow_type,
+            flow_run_configuration.as_ref(),
+        )
+        .await?;
 
         if let Some(e) = ensure_flow_preconditions(
             ctx,
@@ -110,7 +116,7 @@ impl DatasetFlowRunsMut {
         ctx: &Context<'_>,
         flow_id: FlowID,
     ) -> Result<CancelScheduledTasksResult> {
-        ensure_scheduling_permission(ctx, &self.dataset_handle).await?;
+        utils::check_dataset_flow_trigger_access(ctx, &self.dataset_handle).await?;
 
         if let Some(error) =
             check_if_flow_belongs_to_dataset(ctx, flow_id, &self.dataset_handle).await?
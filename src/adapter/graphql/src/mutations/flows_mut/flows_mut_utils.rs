@@ -17,11 +17,36 @@ use crate::utils;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub(crate) async fn ensure_scheduling_permission(
+pub(crate) async fn ensure_flow_config_permission(
     ctx: &Context<'_>,
     dataset_handle: &odf::DatasetHandle,
 ) -> Result<()> {
-    utils::check_dataset_write_access(ctx, dataset_handle).await
+    utils::check_dataset_maintain_access(ctx, dataset_handle).await
+}
+
+// Flow-trigger tokens may only launch flows with their stored configuration,
+// destructive flows and custom run configurations require maintain access
+pub(crate) async fn ensure_flow_trigger_permission(
+    ctx: &Context<'_>,
+    dataset_handle: &odf::DatasetHandle,
+    dataset_flow_type: DatasetFlowType,
+    flow_run_configuration: Option<&FlowRunConfiguration>,
+) -> Result<()> {
+    let is_destructive = match dataset_flow_type {
+        DatasetFlowType::Ingest
+        | DatasetFlowType::ExecuteTransform
+        | DatasetFlowType::SoftCompaction
+        | DatasetFlowType::Verify => false,
+        DatasetFlowType::HardCompaction | DatasetFlowType::Reset | DatasetFlowType::Retention => {
+            true
+        }
+    };
+
+    if is_destructive || flow_run_configuration.is_some() {
+        utils::check_dataset_maintain_access(ctx, dataset_handle).await
+    } else {
+        utils::check_dataset_flow_trigger_access(ctx, dataset_handle).await
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -135,7 +160,10 @@ pub(crate) async fn ensure_flow_preconditions(
                 }));
             };
         }
-        DatasetFlowType::HardCompaction => (),
+        DatasetFlowType::HardCompaction
+        | DatasetFlowType::SoftCompaction
+        | DatasetFlowType::Verify
+        | DatasetFlowType::Retention => (),
         DatasetFlowType::Reset => {
             if let Some(flow_configuration) = flow_run_configuration
                 && let FlowRunConfiguration::Reset(reset_configuration) = flow_configuration
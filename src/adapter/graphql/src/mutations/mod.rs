@@ -10,20 +10,26 @@
 mod auth_mut;
 
 mod account_mut;
+mod account_webhooks_mut;
 mod accounts_mut;
+mod dataset_collaborators_mut;
 mod dataset_env_vars_mut;
 mod dataset_metadata_mut;
 mod dataset_mut;
 mod datasets_mut;
 mod flows_mut;
 mod metadata_chain_mut;
+mod organization_mut;
 
 pub(crate) use account_mut::*;
+pub(crate) use account_webhooks_mut::*;
 pub(crate) use accounts_mut::*;
 pub(crate) use auth_mut::*;
+pub(crate) use dataset_collaborators_mut::*;
 pub(crate) use dataset_env_vars_mut::*;
 pub(crate) use dataset_metadata_mut::*;
 pub(crate) use dataset_mut::*;
 pub(crate) use datasets_mut::*;
 pub(crate) use flows_mut::*;
 pub(crate) use metadata_chain_mut::*;
+pub(crate) use organization_mut::*;
@@ -0,0 +1,258 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_accounts::{Account, AuthenticationService, CurrentAccountSubject};
+use kamu_audit_log::{AuditAction, AuditLogService};
+use kamu_auth_rebac::{
+    AccountToOrganizationRelation,
+    OrganizationMembership,
+    OrganizationMembershipService,
+    RemoveOrganizationMemberError,
+    SetOrganizationMemberRoleError,
+};
+
+use crate::prelude::*;
+use crate::queries::OrganizationMember;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct OrganizationMut {
+    organization: Account,
+}
+
+#[Object]
+impl OrganizationMut {
+    #[graphql(skip)]
+    pub fn new(organization: Account) -> Self {
+        Self { organization }
+    }
+
+    /// Adds the account to the organization or changes its role if it is
+    /// already a member
+    async fn set_member_role(
+        &self,
+        ctx: &Context<'_>,
+        account_name: AccountName,
+        role: OrganizationRole,
+    ) -> Result<SetOrganizationMemberRoleResult> {
+        let Some(account) = self.resolve_account(ctx, &account_name).await? else {
+            return Ok(SetOrganizationMemberRoleResult::AccountNotFound(
+                OrganizationMemberResultAccountNotFound { account_name },
+            ));
+        };
+
+        let membership_service = from_catalog::<dyn OrganizationMembershipService>(ctx).unwrap();
+
+        match membership_service
+            .set_member_role(&self.organization.id, &account.id, role.into())
+            .await
+        {
+            Ok(_) => {
+                self.record_membership_change(
+                    ctx,
+                    AuditAction::OrganizationMemberRoleSet,
+                    format!(
+                        "Set role '{}' of account '{}'",
+                        AccountToOrganizationRelation::from(role),
+                        account.account_name
+                    ),
+                )
+                .await?;
+
+                Ok(SetOrganizationMemberRoleResult::Success(
+                    SetOrganizationMemberRoleResultSuccess {
+                        member: OrganizationMember::new(OrganizationMembership {
+                            account_id: account.id,
+                            organization_id: self.organization.id.clone(),
+                            role: role.into(),
+                        }),
+                    },
+                ))
+            }
+            Err(SetOrganizationMemberRoleError::NotAUser(e)) => {
+                Ok(SetOrganizationMemberRoleResult::NotAUser(
+                    SetOrganizationMemberRoleResultNotAUser {
+                        message: e.to_string(),
+                    },
+                ))
+            }
+            Err(SetOrganizationMemberRoleError::LastOwner(e)) => Ok(
+                SetOrganizationMemberRoleResult::LastOwner(OrganizationMemberResultLastOwner {
+                    message: e.to_string(),
+                }),
+            ),
+            Err(e @ SetOrganizationMemberRoleError::NotAnOrganization(_)) => {
+                Err(GqlError::Internal(e.int_err()))
+            }
+            Err(SetOrganizationMemberRoleError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
+    /// Removes the account from the organization
+    async fn remove_member(
+        &self,
+        ctx: &Context<'_>,
+        account_name: AccountName,
+    ) -> Result<RemoveOrganizationMemberResult> {
+        let Some(account) = self.resolve_account(ctx, &account_name).await? else {
+            return Ok(RemoveOrganizationMemberResult::AccountNotFound(
+                OrganizationMemberResultAccountNotFound { account_name },
+            ));
+        };
+
+        let membership_service = from_catalog::<dyn OrganizationMembershipService>(ctx).unwrap();
+
+        match membership_service
+            .remove_member(&self.organization.id, &account.id)
+            .await
+        {
+            Ok(_) => {
+                self.record_membership_change(
+                    ctx,
+                    AuditAction::OrganizationMemberRemoved,
+                    format!("Removed account '{}'", account.account_name),
+                )
+                .await?;
+
+                Ok(RemoveOrganizationMemberResult::Success(
+                    RemoveOrganizationMemberResultSuccess { account_name },
+                ))
+            }
+            Err(RemoveOrganizationMemberError::NotAMember(e)) => {
+                Ok(RemoveOrganizationMemberResult::NotAMember(
+                    RemoveOrganizationMemberResultNotAMember {
+                        message: e.to_string(),
+                    },
+                ))
+            }
+            Err(RemoveOrganizationMemberError::LastOwner(e)) => Ok(
+                RemoveOrganizationMemberResult::LastOwner(OrganizationMemberResultLastOwner {
+                    message: e.to_string(),
+                }),
+            ),
+            Err(RemoveOrganizationMemberError::Internal(e)) => Err(GqlError::Internal(e)),
+        }
+    }
+
+    #[graphql(skip)]
+    async fn record_membership_change(
+        &self,
+        ctx: &Context<'_>,
+        action: AuditAction,
+        details: String,
+    ) -> Result<()> {
+        let current_account_subject = from_catalog::<CurrentAccountSubject>(ctx).unwrap();
+        let audit_log_service = from_catalog::<dyn AuditLogService>(ctx).unwrap();
+
+        audit_log_service
+            .record(
+                current_account_subject.maybe_account_id(),
+                action,
+                None,
+                Some(format!(
+                    "{details} in organization '{}'",
+                    self.organization.account_name
+                )),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[graphql(skip)]
+    async fn resolve_account(
+        &self,
+        ctx: &Context<'_>,
+        account_name: &AccountName,
+    ) -> Result<Option<Account>> {
+        let authentication_service = from_catalog::<dyn AuthenticationService>(ctx).unwrap();
+
+        Ok(authentication_service.account_by_name(account_name).await?)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum SetOrganizationMemberRoleResult {
+    Success(SetOrganizationMemberRoleResultSuccess),
+    AccountNotFound(OrganizationMemberResultAccountNotFound),
+    NotAUser(SetOrganizationMemberRoleResultNotAUser),
+    LastOwner(OrganizationMemberResultLastOwner),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct SetOrganizationMemberRoleResultSuccess {
+    pub member: OrganizationMember,
+}
+
+#[ComplexObject]
+impl SetOrganizationMemberRoleResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct SetOrganizationMemberRoleResultNotAUser {
+    pub message: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Interface, Debug, Clone)]
+#[graphql(field(name = "message", ty = "String"))]
+pub enum RemoveOrganizationMemberResult {
+    Success(RemoveOrganizationMemberResultSuccess),
+    AccountNotFound(OrganizationMemberResultAccountNotFound),
+    NotAMember(RemoveOrganizationMemberResultNotAMember),
+    LastOwner(OrganizationMemberResultLastOwner),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct RemoveOrganizationMemberResultSuccess {
+    pub account_name: AccountName,
+}
+
+#[ComplexObject]
+impl RemoveOrganizationMemberResultSuccess {
+    async fn message(&self) -> String {
+        "Success".to_string()
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct RemoveOrganizationMemberResultNotAMember {
+    pub message: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct OrganizationMemberResultAccountNotFound {
+    pub account_name: AccountName,
+}
+
+#[ComplexObject]
+impl OrganizationMemberResultAccountNotFound {
+    async fn message(&self) -> String {
+        format!("Account '{}' not found", *self.account_name)
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct OrganizationMemberResultLastOwner {
+    pub message: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
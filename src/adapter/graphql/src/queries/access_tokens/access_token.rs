@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use chrono::{DateTime, Utc};
-use kamu_accounts::{AccessToken as ViewKamuAccessToken, KamuAccessToken};
+use kamu_accounts::{AccessToken as ViewKamuAccessToken, AccessTokenScopes, KamuAccessToken};
 
 use crate::prelude::*;
 use crate::queries::Account;
@@ -47,6 +47,22 @@ impl ViewAccessToken {
         self.token.revoked_at
     }
 
+    /// Date after which the token is no longer accepted
+    async fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.token.expires_at
+    }
+
+    /// Permissions the token is restricted to, or null if the token carries
+    /// the full power of the account
+    async fn scopes(&self) -> Option<Vec<String>> {
+        match &self.token.scopes {
+            AccessTokenScopes::Unrestricted => None,
+            AccessTokenScopes::Restricted(scopes) => {
+                Some(scopes.iter().map(ToString::to_string).collect())
+            }
+        }
+    }
+
     /// Access token account owner
     async fn account(&self, ctx: &Context<'_>) -> Result<Account> {
         let account = Account::from_account_id(ctx, self.token.account_id.clone()).await?;
@@ -13,10 +13,11 @@ use kamu_accounts::{
     DEFAULT_ACCOUNT_ID,
     DEFAULT_ACCOUNT_NAME,
 };
+use kamu_auth_rebac::OrganizationMembershipService;
 use opendatafabric as odf;
 use tokio::sync::OnceCell;
 
-use super::AccountFlows;
+use super::{AccountFlows, AccountWebhooks, OrganizationMember};
 use crate::prelude::*;
 use crate::utils::check_logged_account_id_match;
 
@@ -178,6 +179,33 @@ impl Account {
         Ok(full_account_info.is_admin)
     }
 
+    /// Members of this organization account and their roles. Empty for user
+    /// accounts
+    async fn members(&self, ctx: &Context<'_>) -> Result<Vec<OrganizationMember>> {
+        let membership_service = from_catalog::<dyn OrganizationMembershipService>(ctx).unwrap();
+
+        let memberships = membership_service.get_members(&self.account_id).await?;
+
+        Ok(memberships
+            .into_iter()
+            .map(OrganizationMember::new)
+            .collect())
+    }
+
+    /// Organizations this account is a member of
+    async fn organizations(&self, ctx: &Context<'_>) -> Result<Vec<OrganizationMember>> {
+        let membership_service = from_catalog::<dyn OrganizationMembershipService>(ctx).unwrap();
+
+        let memberships = membership_service
+            .get_account_organizations(&self.account_id)
+            .await?;
+
+        Ok(memberships
+            .into_iter()
+            .map(OrganizationMember::new)
+            .collect())
+    }
+
     /// Access to the flow configurations of this account
     async fn flows(&self, ctx: &Context<'_>) -> Result<Option<AccountFlows>> {
         check_logged_account_id_match(ctx, &self.account_id)?;
@@ -186,6 +214,15 @@ impl Account {
             self.get_full_account_info(ctx).await?.clone(),
         )))
     }
+
+    /// Access to the webhook subscriptions of this account
+    async fn webhooks(&self, ctx: &Context<'_>) -> Result<Option<AccountWebhooks>> {
+        check_logged_account_id_match(ctx, &self.account_id)?;
+
+        Ok(Some(AccountWebhooks::new(
+            self.get_full_account_info(ctx).await?.clone(),
+        )))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
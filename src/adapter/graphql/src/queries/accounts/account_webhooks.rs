@@ -0,0 +1,94 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::PaginationOpts;
+use kamu_accounts::Account;
+use kamu_webhooks::{GetWebhookSubscriptionError, WebhookSubscriptionService};
+
+use crate::prelude::*;
+use crate::queries::{WebhookDelivery, WebhookSubscription};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct AccountWebhooks {
+    account: Account,
+}
+
+#[Object]
+impl AccountWebhooks {
+    const DEFAULT_PER_PAGE: usize = 15;
+
+    #[graphql(skip)]
+    pub fn new(account: Account) -> Self {
+        Self { account }
+    }
+
+    /// Lists webhook subscriptions of this account
+    async fn subscriptions(&self, ctx: &Context<'_>) -> Result<Vec<WebhookSubscription>> {
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+
+        let subscriptions = subscription_service
+            .list_subscriptions(&self.account.id)
+            .await?;
+
+        Ok(subscriptions
+            .into_iter()
+            .map(WebhookSubscription::new)
+            .collect())
+    }
+
+    /// Returns history of delivery attempts of the subscription, newest first
+    async fn deliveries(
+        &self,
+        ctx: &Context<'_>,
+        subscription_id: WebhookSubscriptionID,
+        page: Option<usize>,
+        per_page: Option<usize>,
+    ) -> Result<WebhookDeliveryConnection> {
+        let page = page.unwrap_or(0);
+        let per_page = per_page.unwrap_or(Self::DEFAULT_PER_PAGE);
+
+        let subscription_service = from_catalog::<dyn WebhookSubscriptionService>(ctx).unwrap();
+        let delivery_listing = subscription_service
+            .list_deliveries(
+                &self.account.id,
+                &subscription_id,
+                Some(PaginationOpts {
+                    offset: (page * per_page),
+                    limit: per_page,
+                }),
+            )
+            .await
+            .map_err(|err| match err {
+                GetWebhookSubscriptionError::NotFound(err) => GqlError::Gql(err.into()),
+                GetWebhookSubscriptionError::Internal(err) => GqlError::Internal(err),
+            })?;
+
+        let deliveries: Vec<_> = delivery_listing
+            .list
+            .into_iter()
+            .map(WebhookDelivery::new)
+            .collect();
+
+        Ok(WebhookDeliveryConnection::new(
+            deliveries,
+            page,
+            per_page,
+            delivery_listing.total_count,
+        ))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+page_based_connection!(
+    WebhookDelivery,
+    WebhookDeliveryConnection,
+    WebhookDeliveryEdge
+);
@@ -11,10 +11,14 @@ mod account;
 mod account_flow_configs;
 mod account_flow_runs;
 mod account_flows;
+mod account_webhooks;
 mod accounts;
+mod organization_member;
 
 pub(crate) use account::*;
 pub(crate) use account_flow_configs::*;
 pub(crate) use account_flow_runs::*;
 pub(crate) use account_flows::*;
+pub(crate) use account_webhooks::*;
 pub(crate) use accounts::*;
+pub(crate) use organization_member::*;
@@ -0,0 +1,45 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_auth_rebac::OrganizationMembership;
+
+use crate::prelude::*;
+use crate::queries::Account;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct OrganizationMember {
+    membership: OrganizationMembership,
+}
+
+#[Object]
+impl OrganizationMember {
+    #[graphql(skip)]
+    pub fn new(membership: OrganizationMembership) -> Self {
+        Self { membership }
+    }
+
+    /// Member account
+    async fn account(&self, ctx: &Context<'_>) -> Result<Account> {
+        Ok(Account::from_account_id(ctx, self.membership.account_id.clone()).await?)
+    }
+
+    /// Organization account
+    async fn organization(&self, ctx: &Context<'_>) -> Result<Account> {
+        Ok(Account::from_account_id(ctx, self.membership.organization_id.clone()).await?)
+    }
+
+    /// Role of the member within the organization
+    async fn role(&self) -> OrganizationRole {
+        self.membership.role.into()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,6 +7,11 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use chrono::{DateTime, Utc};
+use database_common::PaginationOpts;
+use kamu_audit_log::{AuditEventFilter, AuditLogService};
+
+use super::AuditEvent;
 use crate::prelude::*;
 use crate::AdminGuard;
 
@@ -14,9 +19,63 @@ pub struct Admin;
 
 #[Object]
 impl Admin {
+    const DEFAULT_AUDIT_EVENTS_PER_PAGE: usize = 50;
+
     #[allow(clippy::unused_async)]
     #[graphql(guard = "AdminGuard::new()")]
     async fn self_test(&self) -> Result<String> {
         Ok("OK".to_string())
     }
+
+    /// Lists audit log events, the most recent ones first. The time range
+    /// includes its start and excludes its end.
+    #[graphql(guard = "AdminGuard::new()")]
+    #[allow(clippy::too_many_arguments)]
+    async fn audit_events(
+        &self,
+        ctx: &Context<'_>,
+        account_id: Option<AccountID>,
+        dataset_id: Option<DatasetID>,
+        action: Option<AuditAction>,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        page: Option<usize>,
+        per_page: Option<usize>,
+    ) -> Result<AuditEventConnection> {
+        let audit_log_service = from_catalog::<dyn AuditLogService>(ctx).unwrap();
+
+        let page = page.unwrap_or(0);
+        let per_page = per_page.unwrap_or(Self::DEFAULT_AUDIT_EVENTS_PER_PAGE);
+
+        let filter = AuditEventFilter {
+            account_id: account_id.map(Into::into),
+            dataset_id: dataset_id.map(Into::into),
+            action: action.map(Into::into),
+            from_time: from,
+            to_time: to,
+        };
+
+        let listing = audit_log_service
+            .list_events(
+                &filter,
+                PaginationOpts {
+                    offset: page * per_page,
+                    limit: per_page,
+                },
+            )
+            .await?;
+
+        let nodes = listing.list.into_iter().map(AuditEvent::new).collect();
+
+        Ok(AuditEventConnection::new(
+            nodes,
+            page,
+            per_page,
+            listing.total_count,
+        ))
+    }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+page_based_connection!(AuditEvent, AuditEventConnection, AuditEventEdge);
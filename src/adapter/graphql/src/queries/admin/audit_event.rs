@@ -0,0 +1,66 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+
+use crate::prelude::*;
+use crate::queries::Account;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct AuditEvent {
+    event: kamu_audit_log::AuditEvent,
+}
+
+#[Object]
+impl AuditEvent {
+    #[graphql(skip)]
+    pub fn new(event: kamu_audit_log::AuditEvent) -> Self {
+        Self { event }
+    }
+
+    /// Unique identifier of the event
+    async fn id(&self) -> String {
+        self.event.id.to_string()
+    }
+
+    /// Time when the operation was performed
+    async fn event_time(&self) -> DateTime<Utc> {
+        self.event.event_time
+    }
+
+    /// Account that performed the operation, null for anonymous access
+    async fn account(&self, ctx: &Context<'_>) -> Result<Option<Account>> {
+        let Some(account_id) = &self.event.account_id else {
+            return Ok(None);
+        };
+
+        let account = Account::from_account_id(ctx, account_id.clone()).await?;
+
+        Ok(Some(account))
+    }
+
+    /// Kind of the operation
+    async fn action(&self) -> AuditAction {
+        self.event.action.into()
+    }
+
+    /// Identifier of the affected dataset. The dataset may no longer exist,
+    /// so it is not resolved.
+    async fn dataset_id(&self) -> Option<DatasetID> {
+        self.event.dataset_id.clone().map(Into::into)
+    }
+
+    /// Human-readable description of the operation
+    async fn details(&self) -> Option<String> {
+        self.event.details.clone()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -8,5 +8,7 @@
 // by the Apache License, Version 2.0.
 
 mod admin;
+mod audit_event;
 
 pub(crate) use admin::*;
+pub(crate) use audit_event::*;
@@ -20,7 +20,10 @@ pub struct DataQueries;
 impl DataQueries {
     const DEFAULT_QUERY_LIMIT: u64 = 100;
 
-    /// Executes a specified query and returns its result
+    /// Executes a specified query and returns its result. To iterate over
+    /// large results pass the `nextCursor` of the previous page as `cursor`
+    /// - this pins the state of the input datasets and resumes the query
+    /// where the previous page ended.
     #[tracing::instrument(level = "info", skip_all)]
     async fn query(
         &self,
@@ -31,6 +34,7 @@ impl DataQueries {
         schema_format: Option<DataSchemaFormat>,
         skip: Option<u64>,
         limit: Option<u64>,
+        cursor: Option<String>,
     ) -> Result<DataQueryResult> {
         tracing::debug!(
             %query,
@@ -39,6 +43,7 @@ impl DataQueries {
             ?schema_format,
             ?skip,
             ?limit,
+            ?cursor,
             "Query",
         );
 
@@ -47,26 +52,55 @@ impl DataQueries {
         let schema_format = schema_format.unwrap_or(DataSchemaFormat::Parquet);
         let limit = limit.unwrap_or(Self::DEFAULT_QUERY_LIMIT);
 
+        let (options, skip) = match &cursor {
+            None => (domain::QueryOptions::default(), skip.unwrap_or(0)),
+            Some(_) if skip.is_some() => {
+                return Ok(DataQueryResult::invalid_cursor(
+                    "Cursor can't be combined with skip".to_string(),
+                ))
+            }
+            Some(cursor) => {
+                match domain::QueryCursor::decode(cursor)
+                    .and_then(|c| c.ensure_statement(&query).map(|()| c))
+                {
+                    Ok(c) => (c.to_options(), c.offset),
+                    Err(e) => return Ok(DataQueryResult::invalid_cursor(e.to_string())),
+                }
+            }
+        };
+
         let query_svc = from_catalog::<dyn domain::QueryService>(ctx).unwrap();
 
-        let df = match query_dialect {
+        let res = match query_dialect {
             QueryDialect::SqlDataFusion => {
-                let sql_result = query_svc
-                    .sql_statement(&query, domain::QueryOptions::default())
-                    .await;
+                let sql_result = query_svc.sql_statement(&query, options).await;
                 match sql_result {
-                    Ok(r) => r.df,
+                    Ok(r) => r,
                     Err(e) => return Ok(e.into()),
                 }
             }
             _ => unimplemented!(),
+        };
+
+        // Cursors address the pages by the number of the preceding records, so they
+        // are only issued for the queries with stable order of the results
+        let (df, is_ordered) = match kamu::with_stable_order(&query, res.df, &res.state) {
+            Ok(r) => r,
+            Err(e) => return Ok(e.into()),
+        };
+        if cursor.is_some() && !is_ordered {
+            return Ok(DataQueryResult::invalid_cursor(
+                "Cursor requires the query to specify the order of the results".to_string(),
+            ));
         }
+
         // TODO: Sanity limits
-        .limit(
-            usize::try_from(skip.unwrap_or(0)).unwrap(),
-            Some(usize::try_from(limit).unwrap()),
-        )
-        .int_err()?;
+        let df = df
+            .limit(
+                usize::try_from(skip).unwrap(),
+                Some(usize::try_from(limit).unwrap()),
+            )
+            .int_err()?;
 
         let schema = DataSchema::from_data_frame_schema(df.schema(), schema_format)?;
         let record_batches = match df.collect().await {
@@ -75,7 +109,19 @@ impl DataQueries {
         };
         let data = DataBatch::from_records(&record_batches, data_format)?;
 
-        Ok(DataQueryResult::success(Some(schema), data, limit))
+        let num_records: u64 = record_batches.iter().map(|b| b.num_rows() as u64).sum();
+        let next_cursor = if is_ordered && limit != 0 && num_records == limit {
+            Some(domain::QueryCursor::new(&query, res.state, skip + num_records).encode())
+        } else {
+            None
+        };
+
+        Ok(DataQueryResult::success(
+            Some(schema),
+            data,
+            limit,
+            next_cursor,
+        ))
     }
 
     /// Lists engines known to the system and recommended for use
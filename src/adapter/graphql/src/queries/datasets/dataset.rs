@@ -8,12 +8,13 @@
 // by the Apache License, Version 2.0.
 
 use chrono::prelude::*;
+use kamu_auth_rebac::DatasetCollaborationService;
 use kamu_core::{self as domain, MetadataChainExt, SearchSeedVisitor, ServerUrlConfig};
 use opendatafabric as odf;
 
 use crate::prelude::*;
 use crate::queries::*;
-use crate::utils::ensure_dataset_env_vars_enabled;
+use crate::utils::{self, ensure_dataset_env_vars_enabled};
 
 #[derive(Debug, Clone)]
 pub struct Dataset {
@@ -143,18 +144,43 @@ impl Dataset {
         let allowed_actions = dataset_action_authorizer
             .get_allowed_actions(&self.dataset_handle)
             .await;
-        let can_read = allowed_actions.contains(&auth::DatasetAction::Read);
-        let can_write = allowed_actions.contains(&auth::DatasetAction::Write);
+
+        // Flow-trigger tokens can schedule flows without being allowed to maintain
+        let can_schedule =
+            match utils::check_dataset_flow_trigger_access(ctx, &self.dataset_handle).await {
+                Ok(()) => true,
+                Err(GqlError::Gql(_)) => false,
+                Err(e) => return Err(e),
+            };
 
         Ok(DatasetPermissions {
-            can_view: can_read,
-            can_delete: can_write,
-            can_rename: can_write,
-            can_commit: can_write,
-            can_schedule: can_write,
+            can_view: allowed_actions.contains(&auth::DatasetAction::Read),
+            can_delete: allowed_actions.contains(&auth::DatasetAction::Delete),
+            can_rename: allowed_actions.contains(&auth::DatasetAction::Rename),
+            can_commit: allowed_actions.contains(&auth::DatasetAction::Write),
+            can_schedule,
+            can_manage_permissions: allowed_actions
+                .contains(&auth::DatasetAction::ManagePermissions),
         })
     }
 
+    /// Accounts that were granted roles in this dataset. Only available to
+    /// accounts that can manage its permissions
+    async fn collaborators(&self, ctx: &Context<'_>) -> Result<Vec<DatasetCollaborator>> {
+        utils::check_dataset_manage_permissions_access(ctx, &self.dataset_handle).await?;
+
+        let collaboration_service = from_catalog::<dyn DatasetCollaborationService>(ctx).unwrap();
+
+        let collaborators = collaboration_service
+            .get_collaborators(&self.dataset_handle.id)
+            .await?;
+
+        Ok(collaborators
+            .into_iter()
+            .map(DatasetCollaborator::new)
+            .collect())
+    }
+
     /// Various endpoints for interacting with data
     async fn endpoints(&self, ctx: &Context<'_>) -> DatasetEndpoints<'_> {
         let config = from_catalog::<ServerUrlConfig>(ctx).unwrap();
@@ -170,4 +196,5 @@ pub struct DatasetPermissions {
     can_rename: bool,
     can_commit: bool,
     can_schedule: bool,
+    can_manage_permissions: bool,
 }
@@ -0,0 +1,40 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_auth_rebac::DatasetCollaborator as DatasetCollaboratorEntity;
+
+use crate::prelude::*;
+use crate::queries::Account;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct DatasetCollaborator {
+    collaborator: DatasetCollaboratorEntity,
+}
+
+#[Object]
+impl DatasetCollaborator {
+    #[graphql(skip)]
+    pub fn new(collaborator: DatasetCollaboratorEntity) -> Self {
+        Self { collaborator }
+    }
+
+    /// Account the role was granted to
+    async fn account(&self, ctx: &Context<'_>) -> Result<Account> {
+        Ok(Account::from_account_id(ctx, self.collaborator.account_id.clone()).await?)
+    }
+
+    /// Role of the account in the dataset
+    async fn role(&self) -> DatasetRole {
+        self.collaborator.role.into()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,7 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use kamu_core::{self as domain, GetSummaryOpts, QueryError};
+use kamu_core::{self as domain, DiffError, GetSummaryOpts, QueryError};
 use opendatafabric as odf;
 
 use crate::prelude::*;
@@ -19,6 +19,7 @@ pub struct DatasetData {
 #[Object]
 impl DatasetData {
     const DEFAULT_TAIL_LIMIT: u64 = 20;
+    const DEFAULT_DIFF_LIMIT: u64 = 100;
 
     #[graphql(skip)]
     pub fn new(dataset_handle: odf::DatasetHandle) -> Self {
@@ -107,6 +108,75 @@ impl DatasetData {
         };
         let data = DataBatch::from_records(&record_batches, data_format)?;
 
-        Ok(DataQueryResult::success(Some(schema), data, limit))
+        Ok(DataQueryResult::success(Some(schema), data, limit, None))
+    }
+
+    /// Returns record-level differences between the states of the dataset at
+    /// two blocks in the form of appended, retracted, and corrected records.
+    /// Absent `oldBlockHash` denotes an empty state, while absent
+    /// `newBlockHash` denotes the current head. Records are matched by the
+    /// specified primary key, by the primary key of the dataset's merge
+    /// strategy, or by their offsets otherwise.
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn diff(
+        &self,
+        ctx: &Context<'_>,
+        old_block_hash: Option<Multihash>,
+        new_block_hash: Option<Multihash>,
+        primary_key: Option<Vec<String>>,
+        limit: Option<u64>,
+        data_format: Option<DataBatchFormat>,
+        schema_format: Option<DataSchemaFormat>,
+    ) -> Result<DataQueryResult> {
+        tracing::debug!(
+            ?old_block_hash,
+            ?new_block_hash,
+            ?primary_key,
+            ?limit,
+            "Diff query"
+        );
+
+        let data_format = data_format.unwrap_or(DataBatchFormat::Json);
+        let schema_format = schema_format.unwrap_or(DataSchemaFormat::Parquet);
+        let limit = limit.unwrap_or(Self::DEFAULT_DIFF_LIMIT);
+
+        let old_block_hash: Option<odf::Multihash> = old_block_hash.map(Into::into);
+        let new_block_hash: Option<odf::Multihash> = new_block_hash.map(Into::into);
+
+        let diff_svc = from_catalog::<dyn domain::DatasetDiffService>(ctx).unwrap();
+        let diff_result = diff_svc
+            .diff_blocks(
+                &self.dataset_handle.as_local_ref(),
+                old_block_hash.as_ref(),
+                new_block_hash.as_ref(),
+                domain::DiffOptions { primary_key },
+            )
+            .await;
+
+        let df = match diff_result {
+            Ok(r) => r.df,
+            Err(DiffError::DatasetSchemaNotAvailable(_)) => {
+                return Ok(DataQueryResult::no_schema_yet(data_format, limit));
+            }
+            Err(DiffError::Access(e)) => return Ok(DataQueryResult::unauthorized(e.to_string())),
+            Err(err) => {
+                tracing::debug!(?err, "Diff error");
+                return Ok(DataQueryResult::internal(err.to_string()));
+            }
+        };
+
+        let df = match df.limit(0, Some(usize::try_from(limit).int_err()?)) {
+            Ok(df) => df,
+            Err(e) => return Ok(e.into()),
+        };
+
+        let schema = DataSchema::from_data_frame_schema(df.schema(), schema_format)?;
+        let record_batches = match df.collect().await {
+            Ok(rb) => rb,
+            Err(e) => return Ok(e.into()),
+        };
+        let data = DataBatch::from_records(&record_batches, data_format)?;
+
+        Ok(DataQueryResult::success(Some(schema), data, limit, None))
     }
 }
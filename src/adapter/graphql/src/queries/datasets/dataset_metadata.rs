@@ -191,6 +191,28 @@ impl DatasetMetadata {
         Ok(source.map(|(_hash, block)| block.event.into()))
     }
 
+    /// Input columns that each output column of the current transformation is
+    /// derived from
+    async fn current_column_lineage(
+        &self,
+        ctx: &Context<'_>,
+    ) -> Result<Option<Vec<ColumnLineage>>> {
+        let provenance_svc = from_catalog::<dyn domain::ProvenanceService>(ctx).unwrap();
+
+        let lineage = match provenance_svc
+            .get_column_lineage(&self.dataset_handle.as_local_ref())
+            .await
+        {
+            Ok(lineage) => lineage,
+            Err(domain::GetColumnLineageError::UnsupportedQuery(e)) => {
+                return Err(GqlError::Gql(async_graphql::Error::new(e.to_string())));
+            }
+            Err(e) => return Err(e.int_err().into()),
+        };
+
+        Ok(lineage.map(|l| l.columns.into_iter().map(Into::into).collect()))
+    }
+
     /// Current descriptive information about the dataset
     async fn current_info(&self, ctx: &Context<'_>) -> Result<SetInfo> {
         let dataset = self.get_dataset(ctx);
@@ -8,6 +8,7 @@
 // by the Apache License, Version 2.0.
 
 mod dataset;
+mod dataset_collaborator;
 mod dataset_data;
 mod dataset_endpoints;
 mod dataset_env_var;
@@ -20,6 +21,7 @@ mod datasets;
 mod metadata_chain;
 
 pub(crate) use dataset::*;
+pub(crate) use dataset_collaborator::*;
 pub(crate) use dataset_data::*;
 pub(crate) use dataset_endpoints::*;
 pub(crate) use dataset_env_var::*;
@@ -9,7 +9,7 @@
 
 use chrono::{DateTime, Utc};
 use kamu_core::{DatasetChangesService, PollingIngestService};
-use kamu_flow_system::FlowResultDatasetUpdate;
+use kamu_flow_system::{FlowResultDatasetRetention, FlowResultDatasetUpdate};
 use {kamu_flow_system as fs, opendatafabric as odf};
 
 use super::{FlowConfigurationSnapshot, FlowEvent, FlowOutcome, FlowStartCondition, FlowTrigger};
@@ -113,6 +113,15 @@ impl Flow {
                         ),
                 })
             }
+            fs::DatasetFlowType::SoftCompaction => {
+                FlowDescriptionDataset::SoftCompaction(FlowDescriptionDatasetSoftCompaction {
+                    dataset_id: dataset_key.dataset_id.clone().into(),
+                    compaction_result:
+                        FlowDescriptionDatasetSoftCompactionResult::from_maybe_flow_outcome(
+                            self.flow_state.outcome.as_ref(),
+                        ),
+                })
+            }
             fs::DatasetFlowType::Reset => {
                 FlowDescriptionDataset::Reset(FlowDescriptionDatasetReset {
                     dataset_id: dataset_key.dataset_id.clone().into(),
@@ -121,6 +130,20 @@ impl Flow {
                     ),
                 })
             }
+            fs::DatasetFlowType::Verify => {
+                FlowDescriptionDataset::Verify(FlowDescriptionDatasetVerify {
+                    dataset_id: dataset_key.dataset_id.clone().into(),
+                })
+            }
+            fs::DatasetFlowType::Retention => {
+                FlowDescriptionDataset::Retention(FlowDescriptionDatasetRetention {
+                    dataset_id: dataset_key.dataset_id.clone().into(),
+                    retention_result:
+                        FlowDescriptionDatasetRetentionResult::from_maybe_flow_outcome(
+                            self.flow_state.outcome.as_ref(),
+                        ),
+                })
+            }
         })
     }
 
@@ -247,7 +270,10 @@ enum FlowDescriptionDataset {
     PushIngest(FlowDescriptionDatasetPushIngest),
     ExecuteTransform(FlowDescriptionDatasetExecuteTransform),
     HardCompaction(FlowDescriptionDatasetHardCompaction),
+    SoftCompaction(FlowDescriptionDatasetSoftCompaction),
     Reset(FlowDescriptionDatasetReset),
+    Verify(FlowDescriptionDatasetVerify),
+    Retention(FlowDescriptionDatasetRetention),
 }
 
 #[derive(SimpleObject)]
@@ -276,12 +302,29 @@ struct FlowDescriptionDatasetHardCompaction {
     compaction_result: Option<FlowDescriptionDatasetHardCompactionResult>,
 }
 
+#[derive(SimpleObject)]
+struct FlowDescriptionDatasetSoftCompaction {
+    dataset_id: DatasetID,
+    compaction_result: Option<FlowDescriptionDatasetSoftCompactionResult>,
+}
+
 #[derive(SimpleObject)]
 struct FlowDescriptionDatasetReset {
     dataset_id: DatasetID,
     reset_result: Option<FlowDescriptionResetResult>,
 }
 
+#[derive(SimpleObject)]
+struct FlowDescriptionDatasetVerify {
+    dataset_id: DatasetID,
+}
+
+#[derive(SimpleObject)]
+struct FlowDescriptionDatasetRetention {
+    dataset_id: DatasetID,
+    retention_result: Option<FlowDescriptionDatasetRetentionResult>,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Union)]
@@ -314,7 +357,9 @@ impl FlowDescriptionUpdateResult {
                 fs::FlowOutcome::Success(result) => match result {
                     fs::FlowResult::Empty
                     | fs::FlowResult::DatasetCompact(_)
-                    | fs::FlowResult::DatasetReset(_) => Ok(None),
+                    | fs::FlowResult::DatasetSoftCompact(_)
+                    | fs::FlowResult::DatasetReset(_)
+                    | fs::FlowResult::DatasetRetention(_) => Ok(None),
                     fs::FlowResult::DatasetUpdate(update) => match update {
                         FlowResultDatasetUpdate::Changed(update_result) => {
                             let increment = dataset_changes_service
@@ -380,7 +425,10 @@ impl FlowDescriptionDatasetHardCompactionResult {
         if let Some(outcome) = maybe_outcome {
             match outcome {
                 fs::FlowOutcome::Success(result) => match result {
-                    fs::FlowResult::DatasetUpdate(_) | fs::FlowResult::DatasetReset(_) => None,
+                    fs::FlowResult::DatasetUpdate(_)
+                    | fs::FlowResult::DatasetSoftCompact(_)
+                    | fs::FlowResult::DatasetReset(_)
+                    | fs::FlowResult::DatasetRetention(_) => None,
                     fs::FlowResult::Empty => Some(Self::NothingToDo(
                         FlowDescriptionHardCompactionNothingToDo {
                             _dummy: "Nothing to do".to_string(),
@@ -404,6 +452,62 @@ impl FlowDescriptionDatasetHardCompactionResult {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Union, Debug, Clone)]
+enum FlowDescriptionDatasetSoftCompactionResult {
+    NothingToDo(FlowDescriptionSoftCompactionNothingToDo),
+    Success(FlowDescriptionSoftCompactionSuccess),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+struct FlowDescriptionSoftCompactionSuccess {
+    original_slices_count: u64,
+    resulting_slices_count: u64,
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct FlowDescriptionSoftCompactionNothingToDo {
+    pub _dummy: String,
+}
+
+#[ComplexObject]
+impl FlowDescriptionSoftCompactionNothingToDo {
+    async fn message(&self) -> String {
+        "Nothing to do".to_string()
+    }
+}
+
+impl FlowDescriptionDatasetSoftCompactionResult {
+    fn from_maybe_flow_outcome(maybe_outcome: Option<&fs::FlowOutcome>) -> Option<Self> {
+        if let Some(outcome) = maybe_outcome {
+            match outcome {
+                fs::FlowOutcome::Success(result) => match result {
+                    fs::FlowResult::DatasetUpdate(_)
+                    | fs::FlowResult::DatasetCompact(_)
+                    | fs::FlowResult::DatasetReset(_)
+                    | fs::FlowResult::DatasetRetention(_) => None,
+                    fs::FlowResult::Empty => Some(Self::NothingToDo(
+                        FlowDescriptionSoftCompactionNothingToDo {
+                            _dummy: "Nothing to do".to_string(),
+                        },
+                    )),
+                    fs::FlowResult::DatasetSoftCompact(compact) => {
+                        Some(Self::Success(FlowDescriptionSoftCompactionSuccess {
+                            original_slices_count: compact.old_num_slices as u64,
+                            resulting_slices_count: compact.new_num_slices as u64,
+                        }))
+                    }
+                },
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(SimpleObject)]
 struct FlowDescriptionResetResult {
     new_head: Multihash,
@@ -416,7 +520,9 @@ impl FlowDescriptionResetResult {
                 fs::FlowOutcome::Success(result) => match result {
                     fs::FlowResult::Empty
                     | fs::FlowResult::DatasetCompact(_)
-                    | fs::FlowResult::DatasetUpdate(_) => None,
+                    | fs::FlowResult::DatasetSoftCompact(_)
+                    | fs::FlowResult::DatasetUpdate(_)
+                    | fs::FlowResult::DatasetRetention(_) => None,
                     fs::FlowResult::DatasetReset(reset_result) => Some(Self {
                         new_head: reset_result.new_head.clone().into(),
                     }),
@@ -430,3 +536,77 @@ impl FlowDescriptionResetResult {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Union, Debug, Clone)]
+enum FlowDescriptionDatasetRetentionResult {
+    NothingToDo(FlowDescriptionRetentionNothingToDo),
+    Success(FlowDescriptionRetentionSuccess),
+    DryRun(FlowDescriptionRetentionDryRun),
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+struct FlowDescriptionRetentionSuccess {
+    removed_records_count: u64,
+    removed_slices_count: u64,
+    new_head: Multihash,
+}
+
+/// Records that would have been removed if the retention was applied
+#[derive(SimpleObject, Debug, Clone)]
+struct FlowDescriptionRetentionDryRun {
+    removed_records_count: u64,
+    removed_slices_count: u64,
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+#[graphql(complex)]
+pub struct FlowDescriptionRetentionNothingToDo {
+    pub _dummy: String,
+}
+
+#[ComplexObject]
+impl FlowDescriptionRetentionNothingToDo {
+    async fn message(&self) -> String {
+        "Nothing to do".to_string()
+    }
+}
+
+impl FlowDescriptionDatasetRetentionResult {
+    fn from_maybe_flow_outcome(maybe_outcome: Option<&fs::FlowOutcome>) -> Option<Self> {
+        if let Some(outcome) = maybe_outcome {
+            match outcome {
+                fs::FlowOutcome::Success(result) => match result {
+                    fs::FlowResult::DatasetUpdate(_)
+                    | fs::FlowResult::DatasetCompact(_)
+                    | fs::FlowResult::DatasetSoftCompact(_)
+                    | fs::FlowResult::DatasetReset(_) => None,
+                    fs::FlowResult::Empty => {
+                        Some(Self::NothingToDo(FlowDescriptionRetentionNothingToDo {
+                            _dummy: "Nothing to do".to_string(),
+                        }))
+                    }
+                    fs::FlowResult::DatasetRetention(retention) => match retention {
+                        FlowResultDatasetRetention::Applied(applied) => {
+                            Some(Self::Success(FlowDescriptionRetentionSuccess {
+                                removed_records_count: applied.num_records_removed,
+                                removed_slices_count: applied.num_slices_removed as u64,
+                                new_head: applied.new_head.clone().into(),
+                            }))
+                        }
+                        FlowResultDatasetRetention::DryRun(dry_run) => {
+                            Some(Self::DryRun(FlowDescriptionRetentionDryRun {
+                                removed_records_count: dry_run.num_records_removed,
+                                removed_slices_count: dry_run.num_slices_removed as u64,
+                            }))
+                        }
+                    },
+                },
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -19,6 +19,8 @@ pub enum FlowConfigurationSnapshot {
     Compaction(FlowConfigurationCompactionRule),
     Ingest(FlowConfigurationIngest),
     Reset(FlowConfigurationReset),
+    Verify(FlowConfigurationVerify),
+    Retention(FlowConfigurationRetention),
 }
 
 #[derive(SimpleObject)]
@@ -39,6 +41,10 @@ impl From<fs::FlowConfigurationSnapshot> for FlowConfigurationSnapshot {
                 unreachable!()
             }
             fs::FlowConfigurationSnapshot::Reset(reset_rule) => Self::Reset(reset_rule.into()),
+            fs::FlowConfigurationSnapshot::Verify(verify_rule) => Self::Verify(verify_rule.into()),
+            fs::FlowConfigurationSnapshot::Retention(retention_rule) => {
+                Self::Retention(retention_rule.into())
+            }
             fs::FlowConfigurationSnapshot::Compaction(compaction_rule) => {
                 Self::Compaction(FlowConfigurationCompactionRule {
                     compaction_rule: match compaction_rule {
@@ -31,6 +31,7 @@ pub(crate) struct FlowFailedError {
 pub(crate) enum FlowFailureReason {
     General(FlowFailureReasonGeneral),
     InputDatasetCompacted(FlowFailureReasonInputDatasetCompacted),
+    VerificationFailed(FlowFailureReasonVerificationFailed),
 }
 
 #[derive(SimpleObject)]
@@ -54,6 +55,23 @@ pub(crate) struct FlowFailureReasonInputDatasetCompacted {
     message: String,
 }
 
+#[derive(SimpleObject)]
+pub(crate) struct FlowFailureReasonVerificationFailed {
+    kind: VerificationFailureKind,
+    /// First block that failed verification
+    block_hash: Multihash,
+    message: String,
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "kamu_flow_system::FlowVerificationFailureKind")]
+pub(crate) enum VerificationFailureKind {
+    /// Data or checkpoint files don't match metadata
+    Integrity,
+    /// Transformation replay produced non-equivalent results
+    Reproducibility,
+}
+
 impl FlowOutcome {
     pub async fn from_maybe_flow_outcome(
         outcome_result: &Option<kamu_flow_system::FlowOutcome>,
@@ -97,6 +115,15 @@ impl FlowOutcome {
                             message: "New head hash to reset not found".to_owned(),
                         }),
                     }),
+                    FlowError::VerificationFailed(err) => Self::Failed(FlowFailedError {
+                        reason: FlowFailureReason::VerificationFailed(
+                            FlowFailureReasonVerificationFailed {
+                                kind: err.kind.into(),
+                                block_hash: err.block_hash.clone().into(),
+                                message: err.message.clone(),
+                            },
+                        ),
+                    }),
                 },
                 kamu_flow_system::FlowOutcome::Aborted => Self::Aborted(FlowAbortedResult {
                     message: "ABORTED".to_owned(),
@@ -7,8 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use futures::TryStreamExt;
-use kamu_core::{self as domain, TryStreamExtExt};
+use kamu_core::{self as domain};
 
 use crate::prelude::*;
 use crate::queries::{Account, Dataset};
@@ -23,30 +22,39 @@ pub struct Search;
 impl Search {
     const DEFAULT_RESULTS_PER_PAGE: usize = 15;
 
-    /// Perform search across all resources
+    /// Perform search across all resources. Results are ordered by relevance
+    /// to the query, which is matched against dataset names, descriptions,
+    /// keywords, column names, licenses and attachments.
     async fn query(
         &self,
         ctx: &Context<'_>,
         query: String,
+        filters: Option<SearchFilters>,
         page: Option<usize>,
         per_page: Option<usize>,
     ) -> Result<SearchResultConnection> {
-        let dataset_repo = from_catalog::<dyn domain::DatasetRepository>(ctx).unwrap();
+        let search_svc = from_catalog::<dyn domain::SearchService>(ctx).unwrap();
 
         let page = page.unwrap_or(0);
         let per_page = per_page.unwrap_or(Self::DEFAULT_RESULTS_PER_PAGE);
 
-        let mut datasets: Vec<_> = dataset_repo
-            .get_all_datasets()
-            .filter_ok(|hdl| hdl.alias.dataset_name.contains(&query))
-            .try_collect()
-            .await?;
+        let result = search_svc
+            .search_local(
+                Some(&query),
+                domain::SearchLocalOptions {
+                    filters: filters.map(Into::into).unwrap_or_default(),
+                    offset: page * per_page,
+                    limit: per_page,
+                },
+            )
+            .await
+            .int_err()?;
 
-        datasets.sort_by(|a, b| a.alias.cmp(&b.alias));
-        let total_count = datasets.len();
+        let total_count = result.total_count;
 
         let mut nodes: Vec<SearchResult> = Vec::new();
-        for hdl in datasets.into_iter().skip(page * per_page).take(per_page) {
+        for hit in result.datasets {
+            let hdl = hit.handle;
             let maybe_account = Account::from_dataset_alias(ctx, &hdl.alias).await?;
             if let Some(account) = maybe_account {
                 nodes.push(SearchResult::Dataset(Dataset::new(account, hdl)));
@@ -66,6 +74,33 @@ impl Search {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(InputObject, Debug)]
+pub struct SearchFilters {
+    /// Only include datasets of the specified kinds
+    by_kinds: Option<Vec<DatasetKind>>,
+    /// Only include datasets owned by the specified account
+    by_owner: Option<AccountName>,
+    /// Only include datasets that have all the specified keywords
+    by_keywords: Option<Vec<String>>,
+}
+
+impl From<SearchFilters> for domain::SearchFilters {
+    fn from(value: SearchFilters) -> Self {
+        Self {
+            kinds: value
+                .by_kinds
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            owner: value.by_owner.map(Into::into),
+            keywords: value.by_keywords.unwrap_or_default(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Union, Debug, Clone)]
 pub enum SearchResult {
     Dataset(Dataset),
@@ -0,0 +1,61 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug)]
+pub struct WebhookDelivery {
+    delivery: kamu_webhooks::WebhookDelivery,
+}
+
+#[Object]
+impl WebhookDelivery {
+    #[graphql(skip)]
+    pub fn new(delivery: kamu_webhooks::WebhookDelivery) -> Self {
+        Self { delivery }
+    }
+
+    /// Identifier of the delivered event, shared by all attempts to deliver it
+    async fn event_id(&self) -> String {
+        self.delivery.event_id.to_string()
+    }
+
+    async fn event_type(&self) -> WebhookEventType {
+        self.delivery.event_type.into()
+    }
+
+    /// Sequential number of the attempt, starting from 1
+    async fn attempt(&self) -> u32 {
+        self.delivery.attempt
+    }
+
+    async fn requested_at(&self) -> DateTime<Utc> {
+        self.delivery.requested_at
+    }
+
+    /// HTTP status returned by the endpoint, if a response was received
+    async fn response_status(&self) -> Option<u16> {
+        self.delivery.response_status
+    }
+
+    /// Reason of the failure, if the attempt was not successful
+    async fn error(&self) -> Option<String> {
+        self.delivery.error.clone()
+    }
+
+    async fn is_success(&self) -> bool {
+        self.delivery.is_success()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,64 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Debug)]
+pub struct WebhookSubscription {
+    subscription: kamu_webhooks::WebhookSubscription,
+}
+
+#[Object]
+impl WebhookSubscription {
+    #[graphql(skip)]
+    pub fn new(subscription: kamu_webhooks::WebhookSubscription) -> Self {
+        Self { subscription }
+    }
+
+    /// Unique identifier of the subscription
+    async fn id(&self) -> WebhookSubscriptionID {
+        self.subscription.id.into()
+    }
+
+    /// URL that receives event notifications
+    async fn target_url(&self) -> String {
+        self.subscription.target_url.to_string()
+    }
+
+    /// Types of events the subscription is interested in
+    async fn event_types(&self) -> Vec<WebhookEventType> {
+        self.subscription
+            .event_types
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Dataset the subscription is limited to, if any
+    async fn dataset_id(&self) -> Option<DatasetID> {
+        self.subscription.dataset_id.clone().map(Into::into)
+    }
+
+    /// Whether notifications are currently being delivered
+    async fn enabled(&self) -> bool {
+        self.subscription.enabled
+    }
+
+    /// Date of the subscription creation
+    async fn created_at(&self) -> DateTime<Utc> {
+        self.subscription.created_at
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -7,10 +7,15 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use futures::Stream;
+use opendatafabric as odf;
+
 use crate::extensions::*;
 use crate::mutations::*;
 use crate::prelude::*;
 use crate::queries::*;
+use crate::subscriptions::*;
+use crate::utils::{check_logged_account_name_match, get_logged_account};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Query
@@ -92,12 +97,116 @@ impl Mutation {
     }
 }
 
-pub type Schema = async_graphql::Schema<Query, Mutation, EmptySubscription>;
-pub type SchemaBuilder = async_graphql::SchemaBuilder<Query, Mutation, EmptySubscription>;
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Subscription
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct Subscription;
+
+#[Subscription]
+impl Subscription {
+    /// Streams life-cycle changes of the flows of a dataset
+    async fn dataset_flow_progress(
+        &self,
+        ctx: &Context<'_>,
+        dataset_id: DatasetID,
+    ) -> Result<impl Stream<Item = FlowProgressUpdate>> {
+        let dataset_id: odf::DatasetID = dataset_id.into();
+        check_dataset_subscription_access(ctx, &dataset_id).await?;
+
+        let access = SubscriptionAccess::Dataset(dataset_id.clone());
+        Ok(subscription_events(
+            ctx,
+            access,
+            move |event| match event {
+                SubscriptionEvent::FlowProgress(e) if e.dataset_id == dataset_id => Some(e.into()),
+                _ => None,
+            },
+        )?)
+    }
+
+    /// Streams life-cycle changes of the flows of all datasets owned by an
+    /// account
+    async fn account_flow_progress(
+        &self,
+        ctx: &Context<'_>,
+        account_name: AccountName,
+    ) -> Result<impl Stream<Item = FlowProgressUpdate>> {
+        check_logged_account_name_match(ctx, &account_name)?;
+        let account_id = get_logged_account(ctx).account_id;
+
+        Ok(subscription_events(
+            ctx,
+            SubscriptionAccess::Account,
+            move |event| match event {
+                SubscriptionEvent::FlowProgress(e) if e.owner_account_ids.contains(&account_id) => {
+                    Some(e.into())
+                }
+                _ => None,
+            },
+        )?)
+    }
+
+    /// Streams life-cycle changes of the tasks launched by the flows of a
+    /// dataset, optionally narrowed down to a single flow
+    async fn dataset_task_progress(
+        &self,
+        ctx: &Context<'_>,
+        dataset_id: DatasetID,
+        flow_id: Option<FlowID>,
+    ) -> Result<impl Stream<Item = TaskProgressUpdate>> {
+        let dataset_id: odf::DatasetID = dataset_id.into();
+        check_dataset_subscription_access(ctx, &dataset_id).await?;
+
+        let flow_id = flow_id.map(kamu_flow_system::FlowID::from);
+
+        let access = SubscriptionAccess::Dataset(dataset_id.clone());
+        Ok(subscription_events(
+            ctx,
+            access,
+            move |event| match event {
+                SubscriptionEvent::TaskProgress(e)
+                    if e.dataset_id == dataset_id
+                        && flow_id.map_or(true, |flow_id| e.flow_id == flow_id) =>
+                {
+                    Some(e.into())
+                }
+                _ => None,
+            },
+        )?)
+    }
+
+    /// Streams updates of the dataset head, regardless of what has moved it
+    async fn dataset_head_updates(
+        &self,
+        ctx: &Context<'_>,
+        dataset_id: DatasetID,
+    ) -> Result<impl Stream<Item = DatasetHeadUpdate>> {
+        let dataset_id: odf::DatasetID = dataset_id.into();
+        check_dataset_subscription_access(ctx, &dataset_id).await?;
+
+        let access = SubscriptionAccess::Dataset(dataset_id.clone());
+        Ok(subscription_events(
+            ctx,
+            access,
+            move |event| match event {
+                SubscriptionEvent::DatasetHeadUpdated(e) if e.dataset_id == dataset_id => {
+                    Some(e.into())
+                }
+                _ => None,
+            },
+        )?)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub type Schema = async_graphql::Schema<Query, Mutation, Subscription>;
+pub type SchemaBuilder = async_graphql::SchemaBuilder<Query, Mutation, Subscription>;
 
 /// Returns schema builder without any extensions
 pub fn schema_builder() -> SchemaBuilder {
-    Schema::build(Query, Mutation, EmptySubscription)
+    Schema::build(Query, Mutation, Subscription)
 }
 
 /// Returns schema preconfigured with default extensions
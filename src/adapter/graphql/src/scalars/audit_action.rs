@@ -0,0 +1,30 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Kind of operation recorded in the audit log
+#[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "kamu_audit_log::AuditAction")]
+pub enum AuditAction {
+    DatasetDeleted,
+    DatasetRenamed,
+    DatasetReset,
+    AccessTokenCreated,
+    AccessTokenRevoked,
+    FlowConfigurationUpdated,
+    DatasetRoleGranted,
+    DatasetRoleRevoked,
+    OrganizationMemberRoleSet,
+    OrganizationMemberRemoved,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
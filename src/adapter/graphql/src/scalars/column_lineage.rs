@@ -0,0 +1,55 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_core as domain;
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ColumnLineage {
+    /// Name of the output column
+    pub name: String,
+    /// Input columns this column is derived from
+    pub sources: Vec<ColumnLineageSource>,
+}
+
+impl From<domain::OutputColumnLineage> for ColumnLineage {
+    fn from(value: domain::OutputColumnLineage) -> Self {
+        Self {
+            name: value.name,
+            sources: value.sources.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ColumnLineageSource {
+    /// ID of the input dataset
+    pub dataset_id: DatasetID,
+    /// Alias of the input dataset
+    pub dataset_alias: DatasetAlias,
+    /// Name of the column in the input dataset
+    pub column: String,
+}
+
+impl From<domain::ColumnLineageSource> for ColumnLineageSource {
+    fn from(value: domain::ColumnLineageSource) -> Self {
+        Self {
+            dataset_id: value.dataset.id.into(),
+            dataset_alias: value.dataset.alias.into(),
+            column: value.column,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
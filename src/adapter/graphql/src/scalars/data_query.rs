@@ -21,6 +21,11 @@ pub struct DataQueryResultSuccess {
     pub schema: Option<DataSchema>,
     pub data: DataBatch,
     pub limit: u64,
+    /// Continuation token to fetch the next page of results. Present when the
+    /// page was filled up to the limit, so the next page may turn out empty.
+    /// Only issued for the queries that specify the order of the results or
+    /// read a single dataset, which is then ordered by offset.
+    pub next_cursor: Option<String>,
 }
 
 #[derive(SimpleObject)]
@@ -32,6 +37,7 @@ pub struct DataQueryResultError {
 #[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DataQueryResultErrorKind {
     InvalidSql,
+    InvalidCursor,
     Unauthorized,
     InternalError,
 }
@@ -43,11 +49,17 @@ pub enum DataQueryResult {
 }
 
 impl DataQueryResult {
-    pub fn success(schema: Option<DataSchema>, data: DataBatch, limit: u64) -> DataQueryResult {
+    pub fn success(
+        schema: Option<DataSchema>,
+        data: DataBatch,
+        limit: u64,
+        next_cursor: Option<String>,
+    ) -> DataQueryResult {
         DataQueryResult::Success(DataQueryResultSuccess {
             schema,
             data,
             limit,
+            next_cursor,
         })
     }
 
@@ -56,6 +68,7 @@ impl DataQueryResult {
             schema: None,
             data: DataBatch::empty(format),
             limit,
+            next_cursor: None,
         })
     }
 
@@ -66,6 +79,13 @@ impl DataQueryResult {
         })
     }
 
+    pub fn invalid_cursor(error_message: String) -> DataQueryResult {
+        DataQueryResult::Error(DataQueryResultError {
+            error_message,
+            error_kind: DataQueryResultErrorKind::InvalidCursor,
+        })
+    }
+
     pub fn unauthorized(error_message: String) -> DataQueryResult {
         DataQueryResult::Error(DataQueryResultError {
             error_message,
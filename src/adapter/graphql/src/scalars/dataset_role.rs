@@ -0,0 +1,29 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Role of an account in a dataset, every role includes permissions of the
+/// previous ones
+#[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+#[graphql(remote = "kamu_auth_rebac::AccountToDatasetRelation")]
+pub enum DatasetRole {
+    /// Can read the dataset
+    Reader,
+    /// Can commit new data and metadata
+    Editor,
+    /// Can configure and trigger flows
+    Maintainer,
+    /// Can manage roles of other accounts, rename and delete the dataset
+    Admin,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -16,11 +16,13 @@ use kamu_flow_system::{
     FlowConfigurationSnapshot,
     IngestRule,
     ResetRule,
+    RetentionRule,
     Schedule,
     ScheduleCron,
     ScheduleCronError,
     ScheduleTimeDelta,
     TransformRule,
+    VerifyRule,
 };
 use opendatafabric::DatasetHandle;
 
@@ -36,6 +38,8 @@ pub struct FlowConfiguration {
     pub transform: Option<FlowConfigurationTransform>,
     pub compaction: Option<FlowConfigurationCompaction>,
     pub reset: Option<FlowConfigurationReset>,
+    pub verify: Option<FlowConfigurationVerify>,
+    pub retention: Option<FlowConfigurationRetention>,
 }
 
 impl From<kamu_flow_system::FlowConfigurationState> for FlowConfiguration {
@@ -57,6 +61,16 @@ impl From<kamu_flow_system::FlowConfigurationState> for FlowConfiguration {
             } else {
                 None
             },
+            verify: if let FlowConfigurationRule::VerifyRule(verify_rule) = &value.rule {
+                Some(verify_rule.clone().into())
+            } else {
+                None
+            },
+            retention: if let FlowConfigurationRule::RetentionRule(retention_rule) = &value.rule {
+                Some(retention_rule.clone().into())
+            } else {
+                None
+            },
             compaction: if let FlowConfigurationRule::CompactionRule(compaction_args) = &value.rule
             {
                 match compaction_args {
@@ -84,12 +98,7 @@ impl From<IngestRule> for FlowConfigurationIngest {
     fn from(value: IngestRule) -> Self {
         Self {
             fetch_uncacheable: value.fetch_uncacheable,
-            schedule: match value.schedule_condition {
-                Schedule::TimeDelta(time_delta) => {
-                    FlowConfigurationSchedule::TimeDelta(time_delta.every.into())
-                }
-                Schedule::Cron(cron) => FlowConfigurationSchedule::Cron(cron.clone().into()),
-            },
+            schedule: value.schedule_condition.into(),
         }
     }
 }
@@ -100,6 +109,15 @@ pub enum FlowConfigurationSchedule {
     Cron(Cron5ComponentExpression),
 }
 
+impl From<Schedule> for FlowConfigurationSchedule {
+    fn from(value: Schedule) -> Self {
+        match value {
+            Schedule::TimeDelta(time_delta) => Self::TimeDelta(time_delta.every.into()),
+            Schedule::Cron(cron) => Self::Cron(cron.into()),
+        }
+    }
+}
+
 #[derive(SimpleObject, Clone, PartialEq, Eq)]
 pub struct FlowConfigurationTransform {
     pub min_records_to_await: u64,
@@ -117,6 +135,46 @@ impl From<TransformRule> for FlowConfigurationTransform {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(SimpleObject, Clone, PartialEq, Eq)]
+pub struct FlowConfigurationVerify {
+    pub schedule: FlowConfigurationSchedule,
+    /// Whether transformations are replayed to check reproducibility of
+    /// derivative data
+    pub replay_transformations: bool,
+}
+
+impl From<VerifyRule> for FlowConfigurationVerify {
+    fn from(value: VerifyRule) -> Self {
+        Self {
+            schedule: value.schedule_condition.into(),
+            replay_transformations: value.replay_transformations,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(SimpleObject, Clone, PartialEq, Eq)]
+pub struct FlowConfigurationRetention {
+    pub schedule: FlowConfigurationSchedule,
+    /// Records with event time older than this window are removed
+    pub retention_window: TimeDelta,
+    /// Whether the flow only reports what would be removed
+    pub dry_run: bool,
+}
+
+impl From<RetentionRule> for FlowConfigurationRetention {
+    fn from(value: RetentionRule) -> Self {
+        Self {
+            schedule: value.schedule_condition().clone().into(),
+            retention_window: value.retention_window().into(),
+            dry_run: value.dry_run(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(SimpleObject, Clone, PartialEq, Eq)]
 pub struct FlowConfigurationReset {
     pub mode: SnapshotPropagationMode,
@@ -282,6 +340,8 @@ pub enum FlowRunConfiguration {
     Compaction(CompactionConditionInput),
     Ingest(IngestConditionInput),
     Reset(ResetConditionInput),
+    Verify(VerifyConditionInput),
+    Retention(RetentionConditionInput),
 }
 
 #[derive(OneofObject, Clone)]
@@ -427,6 +487,55 @@ impl From<IngestConditionInput> for FlowRunConfiguration {
     }
 }
 
+#[derive(InputObject, Clone)]
+pub struct VerifyConditionInput {
+    /// Flag indicates to also replay transformations of derivative datasets
+    /// to check their reproducibility
+    pub replay_transformations: bool,
+    pub schedule: ScheduleInput,
+}
+
+impl TryFrom<VerifyConditionInput> for VerifyRule {
+    type Error = ScheduleCronError;
+
+    fn try_from(value: VerifyConditionInput) -> std::result::Result<Self, Self::Error> {
+        let schedule = match value.schedule {
+            ScheduleInput::TimeDelta(td) => {
+                Schedule::TimeDelta(ScheduleTimeDelta { every: td.into() })
+            }
+            ScheduleInput::Cron5ComponentExpression(cron_5component_expression) => {
+                Schedule::try_from_5component_cron_expression(&cron_5component_expression)?
+            }
+        };
+        Ok(Self {
+            replay_transformations: value.replay_transformations,
+            schedule_condition: schedule,
+        })
+    }
+}
+
+impl From<VerifyConditionInput> for FlowRunConfiguration {
+    fn from(value: VerifyConditionInput) -> Self {
+        Self::Verify(value)
+    }
+}
+
+#[derive(InputObject, Clone)]
+pub struct RetentionConditionInput {
+    /// Records with event time older than this window are removed
+    pub retention_window: TimeDeltaInput,
+    /// Flag indicates to only report what would be removed without modifying
+    /// the dataset
+    pub dry_run: bool,
+    pub schedule: ScheduleInput,
+}
+
+impl From<RetentionConditionInput> for FlowRunConfiguration {
+    fn from(value: RetentionConditionInput) -> Self {
+        Self::Retention(value)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 impl FlowRunConfiguration {
@@ -487,9 +596,15 @@ impl FlowRunConfiguration {
                     });
                 }
             }
-            DatasetFlowType::HardCompaction => {
+            DatasetFlowType::HardCompaction | DatasetFlowType::SoftCompaction => {
                 if let Some(flow_run_configuration) = flow_run_configuration_maybe {
                     if let Self::Compaction(compaction_input) = flow_run_configuration {
+                        flow_run_configuration
+                            .check_type_compatible(*dataset_flow_type)
+                            .map_err(|_| FlowInvalidRunConfigurations {
+                                error: "Incompatible flow run configuration and dataset flow type"
+                                    .to_string(),
+                            })?;
                         return Ok(Some(FlowConfigurationSnapshot::Compaction(
                             match compaction_input {
                                 CompactionConditionInput::Full(compaction_input) => {
@@ -558,6 +673,61 @@ impl FlowRunConfiguration {
                     recursive: false,
                 })));
             }
+            DatasetFlowType::Verify => {
+                if let Some(flow_run_configuration) = flow_run_configuration_maybe {
+                    if let Self::Verify(verify_input) = flow_run_configuration {
+                        return Ok(Some(FlowConfigurationSnapshot::Verify(
+                            VerifyRule::try_from(verify_input.clone()).map_err(|_| {
+                                FlowInvalidRunConfigurations {
+                                    error: "Invalid schedule flow run configuration".to_string(),
+                                }
+                            })?,
+                        )));
+                    }
+                    return Err(FlowInvalidRunConfigurations {
+                        error: "Incompatible flow run configuration and dataset flow type"
+                            .to_string(),
+                    });
+                }
+            }
+            DatasetFlowType::Retention => {
+                if let Some(flow_run_configuration) = flow_run_configuration_maybe {
+                    if let Self::Retention(retention_input) = flow_run_configuration {
+                        let schedule_condition = match &retention_input.schedule {
+                            ScheduleInput::TimeDelta(td) => {
+                                Schedule::TimeDelta(ScheduleTimeDelta { every: td.into() })
+                            }
+                            ScheduleInput::Cron5ComponentExpression(cron_5component_expression) => {
+                                Schedule::try_from_5component_cron_expression(
+                                    cron_5component_expression,
+                                )
+                                .map_err(|_| {
+                                    FlowInvalidRunConfigurations {
+                                        error: "Invalid schedule flow run configuration"
+                                            .to_string(),
+                                    }
+                                })?
+                            }
+                        };
+                        return Ok(Some(FlowConfigurationSnapshot::Retention(
+                            RetentionRule::new_checked(
+                                (&retention_input.retention_window).into(),
+                                retention_input.dry_run,
+                                schedule_condition,
+                            )
+                            .map_err(|_| {
+                                FlowInvalidRunConfigurations {
+                                    error: "Invalid retention flow run configuration".to_string(),
+                                }
+                            })?,
+                        )));
+                    }
+                    return Err(FlowInvalidRunConfigurations {
+                        error: "Incompatible flow run configuration and dataset flow type"
+                            .to_string(),
+                    });
+                }
+            }
         }
         Ok(None)
     }
@@ -577,8 +747,12 @@ impl FlowRunConfiguration {
                     return Ok(());
                 }
             }
-            Self::Compaction(_) => {
-                if flow_type == DatasetFlowType::HardCompaction {
+            Self::Compaction(compaction_input) => {
+                // Soft compaction only merges data slices and has no metadata-only mode
+                if flow_type == DatasetFlowType::HardCompaction
+                    || (flow_type == DatasetFlowType::SoftCompaction
+                        && matches!(compaction_input, CompactionConditionInput::Full(_)))
+                {
                     return Ok(());
                 }
             }
@@ -587,6 +761,16 @@ impl FlowRunConfiguration {
                     return Ok(());
                 }
             }
+            Self::Verify(_) => {
+                if flow_type == DatasetFlowType::Verify {
+                    return Ok(());
+                }
+            }
+            Self::Retention(_) => {
+                if flow_type == DatasetFlowType::Retention {
+                    return Ok(());
+                }
+            }
         }
         Err(FlowTypeIsNotSupported)
     }
@@ -106,7 +106,10 @@ pub enum DatasetFlowType {
     Ingest,
     ExecuteTransform,
     HardCompaction,
+    SoftCompaction,
     Reset,
+    Verify,
+    Retention,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -9,12 +9,15 @@
 
 mod access_token;
 mod account;
+mod audit_action;
+mod column_lineage;
 mod data_batch;
 mod data_query;
 mod data_schema;
 mod dataset_endpoints;
 mod dataset_env_var;
 mod dataset_id_name;
+mod dataset_role;
 mod dataset_visibility;
 mod engine_desc;
 mod event_id;
@@ -23,19 +26,24 @@ mod flow_scalars;
 mod metadata;
 mod multihash;
 mod odf_generated;
+mod organization;
 mod os_path;
 mod pagination;
 mod task_id;
 mod task_status_outcome;
+mod webhook;
 
 pub(crate) use access_token::*;
 pub(crate) use account::*;
+pub(crate) use audit_action::*;
+pub(crate) use column_lineage::*;
 pub(crate) use data_batch::*;
 pub(crate) use data_query::*;
 pub(crate) use data_schema::*;
 pub(crate) use dataset_endpoints::*;
 pub(crate) use dataset_env_var::*;
 pub(crate) use dataset_id_name::*;
+pub(crate) use dataset_role::*;
 pub(crate) use dataset_visibility::*;
 pub(crate) use engine_desc::*;
 pub(crate) use event_id::*;
@@ -44,10 +52,12 @@ pub(crate) use flow_scalars::*;
 pub(crate) use metadata::*;
 pub(crate) use multihash::*;
 pub(crate) use odf_generated::*;
+pub(crate) use organization::*;
 pub(crate) use os_path::*;
 pub(crate) use pagination::*;
 pub(crate) use task_id::*;
 pub(crate) use task_status_outcome::*;
+pub(crate) use webhook::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -423,6 +423,8 @@ pub enum FetchStep {
     Container(FetchStepContainer),
     Mqtt(FetchStepMqtt),
     EthereumLogs(FetchStepEthereumLogs),
+    Kafka(FetchStepKafka),
+    Sql(FetchStepSql),
 }
 
 impl From<odf::FetchStep> for FetchStep {
@@ -433,6 +435,8 @@ impl From<odf::FetchStep> for FetchStep {
             odf::FetchStep::Container(v) => Self::Container(v.into()),
             odf::FetchStep::Mqtt(v) => Self::Mqtt(v.into()),
             odf::FetchStep::EthereumLogs(v) => Self::EthereumLogs(v.into()),
+            odf::FetchStep::Kafka(v) => Self::Kafka(v.into()),
+            odf::FetchStep::Sql(v) => Self::Sql(v.into()),
         }
     }
 }
@@ -534,6 +538,42 @@ impl From<odf::FetchStepEthereumLogs> for FetchStepEthereumLogs {
     }
 }
 
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct FetchStepKafka {
+    pub brokers: Vec<String>,
+    pub topic: String,
+    pub partitions: Option<Vec<i32>>,
+}
+
+impl From<odf::FetchStepKafka> for FetchStepKafka {
+    fn from(v: odf::FetchStepKafka) -> Self {
+        Self {
+            brokers: v.brokers.into_iter().map(Into::into).collect(),
+            topic: v.topic.into(),
+            partitions: v
+                .partitions
+                .map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct FetchStepSql {
+    pub connection_url: String,
+    pub query: String,
+    pub cursor_column: Option<String>,
+}
+
+impl From<odf::FetchStepSql> for FetchStepSql {
+    fn from(v: odf::FetchStepSql) -> Self {
+        Self {
+            connection_url: v.connection_url.into(),
+            query: v.query.into(),
+            cursor_column: v.cursor_column.map(Into::into),
+        }
+    }
+}
+
 #[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SourceOrdering {
     ByEventTime,
@@ -943,6 +983,8 @@ pub enum ReadStep {
     Json(ReadStepJson),
     NdJson(ReadStepNdJson),
     NdGeoJson(ReadStepNdGeoJson),
+    Avro(ReadStepAvro),
+    Orc(ReadStepOrc),
 }
 
 impl From<odf::ReadStep> for ReadStep {
@@ -955,6 +997,8 @@ impl From<odf::ReadStep> for ReadStep {
             odf::ReadStep::Json(v) => Self::Json(v.into()),
             odf::ReadStep::NdJson(v) => Self::NdJson(v.into()),
             odf::ReadStep::NdGeoJson(v) => Self::NdGeoJson(v.into()),
+            odf::ReadStep::Avro(v) => Self::Avro(v.into()),
+            odf::ReadStep::Orc(v) => Self::Orc(v.into()),
         }
     }
 }
@@ -1084,6 +1128,32 @@ impl From<odf::ReadStepNdGeoJson> for ReadStepNdGeoJson {
     }
 }
 
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct ReadStepAvro {
+    pub schema: Option<Vec<String>>,
+}
+
+impl From<odf::ReadStepAvro> for ReadStepAvro {
+    fn from(v: odf::ReadStepAvro) -> Self {
+        Self {
+            schema: v.schema.map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone, PartialEq, Eq)]
+pub struct ReadStepOrc {
+    pub schema: Option<Vec<String>>,
+}
+
+impl From<odf::ReadStepOrc> for ReadStepOrc {
+    fn from(v: odf::ReadStepOrc) -> Self {
+        Self {
+            schema: v.schema.map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // RequestHeader
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#requestheader-schema
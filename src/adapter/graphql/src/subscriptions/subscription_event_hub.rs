@@ -0,0 +1,340 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use dill::{component, interface, meta, scope, Catalog, Singleton};
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::{
+    DatasetOwnershipService,
+    DatasetReferenceMessage,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+};
+use kamu_flow_system::{
+    DatasetFlowType,
+    FlowID,
+    FlowKey,
+    FlowKeyDataset,
+    FlowOutcome,
+    FlowProgressMessage,
+    FlowQueryService,
+    FlowState,
+    FlowStatus,
+    GetFlowError,
+    METADATA_TASK_FLOW_ID,
+};
+use kamu_flow_system_services::MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE;
+use kamu_task_system::{
+    TaskID,
+    TaskMetadata,
+    TaskOutcome,
+    TaskProgressMessage,
+    TaskStatus,
+    MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
+};
+use messaging_outbox::{
+    MessageConsumer,
+    MessageConsumerMeta,
+    MessageConsumerT,
+    MessageConsumptionDurability,
+};
+use opendatafabric::{AccountID, DatasetID, Multihash};
+use tokio::sync::broadcast;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub const MESSAGE_CONSUMER_KAMU_GQL_SUBSCRIPTION_EVENT_HUB: &str =
+    "dev.kamu.adapter.graphql.SubscriptionEventHub";
+
+const SUBSCRIPTION_EVENTS_CAPACITY: usize = 1024;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub(crate) enum SubscriptionEvent {
+    FlowProgress(FlowProgressEvent),
+    TaskProgress(TaskProgressEvent),
+    DatasetHeadUpdated(DatasetHeadUpdatedEvent),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct FlowProgressEvent {
+    pub event_time: DateTime<Utc>,
+    pub flow_id: FlowID,
+    pub dataset_id: DatasetID,
+    pub flow_type: DatasetFlowType,
+    pub owner_account_ids: Vec<AccountID>,
+    pub status: FlowStatus,
+    pub outcome: Option<FlowOutcome>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct TaskProgressEvent {
+    pub event_time: DateTime<Utc>,
+    pub task_id: TaskID,
+    pub flow_id: FlowID,
+    pub dataset_id: DatasetID,
+    pub status: TaskStatus,
+    pub outcome: Option<TaskOutcome>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DatasetHeadUpdatedEvent {
+    pub event_time: DateTime<Utc>,
+    pub dataset_id: DatasetID,
+    pub old_head: Option<Multihash>,
+    pub new_head: Multihash,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Relays flow and task progress messages, as well as dataset head updates, to
+/// the live GraphQL subscriptions.
+///
+/// Messages are consumed only after the producing transaction is committed,
+/// so subscribers can safely re-query the state they are notified about.
+/// Only flows related to datasets are relayed. Subscriptions don't survive a
+/// restart, so there is nothing to catch up on after it.
+pub struct SubscriptionEventHub {
+    sender: broadcast::Sender<SubscriptionEvent>,
+}
+
+#[component(pub)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<FlowProgressMessage>)]
+#[interface(dyn MessageConsumerT<TaskProgressMessage>)]
+#[interface(dyn MessageConsumerT<DatasetReferenceMessage>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: MESSAGE_CONSUMER_KAMU_GQL_SUBSCRIPTION_EVENT_HUB,
+    feeding_producers: &[
+        MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+        MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
+        MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+    ],
+    durability: MessageConsumptionDurability::BestEffort,
+})]
+#[scope(Singleton)]
+impl SubscriptionEventHub {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(SUBSCRIPTION_EVENTS_CAPACITY);
+        Self { sender }
+    }
+
+    /// Number of currently active subscriptions
+    pub fn receiver_count(&self) -> usize {
+        self.sender.receiver_count()
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<SubscriptionEvent> {
+        self.sender.subscribe()
+    }
+
+    fn publish(&self, event: SubscriptionEvent) {
+        // Sending only fails when there are no active subscriptions
+        let _ = self.sender.send(event);
+    }
+
+    async fn load_dataset_flow(
+        target_catalog: &Catalog,
+        flow_id: FlowID,
+    ) -> Result<Option<(FlowKeyDataset, FlowState)>, InternalError> {
+        let flow_query_service = target_catalog.get_one::<dyn FlowQueryService>().unwrap();
+
+        match flow_query_service.get_flow(flow_id).await {
+            Ok(flow_state) => match flow_state.flow_key.clone() {
+                FlowKey::Dataset(flow_key) => Ok(Some((flow_key, flow_state))),
+                FlowKey::System(_) => Ok(None),
+            },
+            // Immediate delivery may outrun saving of a just created flow
+            Err(GetFlowError::NotFound(_)) => {
+                tracing::debug!(%flow_id, "Skipping progress of unknown flow");
+                Ok(None)
+            }
+            Err(GetFlowError::Internal(e)) => Err(e),
+        }
+    }
+
+    async fn handle_flow_progress_message(
+        &self,
+        target_catalog: &Catalog,
+        message: &FlowProgressMessage,
+    ) -> Result<(), InternalError> {
+        let (event_time, flow_id) = match message {
+            FlowProgressMessage::Scheduled(m) => (m.event_time, m.flow_id),
+            FlowProgressMessage::Running(m) => (m.event_time, m.flow_id),
+            FlowProgressMessage::Finished(m) => (m.event_time, m.flow_id),
+            FlowProgressMessage::Cancelled(m) => (m.event_time, m.flow_id),
+        };
+
+        let Some((flow_key, flow_state)) = Self::load_dataset_flow(target_catalog, flow_id).await?
+        else {
+            return Ok(());
+        };
+
+        let dataset_ownership_service = target_catalog
+            .get_one::<dyn DatasetOwnershipService>()
+            .unwrap();
+        let owner_account_ids = dataset_ownership_service
+            .get_dataset_owners(&flow_key.dataset_id)
+            .await?;
+
+        self.publish(SubscriptionEvent::FlowProgress(FlowProgressEvent {
+            event_time,
+            flow_id,
+            dataset_id: flow_key.dataset_id,
+            flow_type: flow_key.flow_type,
+            owner_account_ids,
+            status: flow_state.status(),
+            outcome: flow_state.outcome,
+        }));
+
+        Ok(())
+    }
+
+    async fn handle_task_progress_message(
+        &self,
+        target_catalog: &Catalog,
+        message: &TaskProgressMessage,
+    ) -> Result<(), InternalError> {
+        let (event_time, task_id, task_metadata, status, outcome) = match message {
+            TaskProgressMessage::Running(m) => (
+                m.event_time,
+                m.task_id,
+                &m.task_metadata,
+                TaskStatus::Running,
+                None,
+            ),
+            TaskProgressMessage::Finished(m) => (
+                m.event_time,
+                m.task_id,
+                &m.task_metadata,
+                TaskStatus::Finished,
+                Some(m.outcome.clone()),
+            ),
+        };
+
+        // Tasks that are not launched by flows are of no interest to subscribers
+        let Some(flow_id) = flow_id_from_task_metadata(task_metadata)? else {
+            return Ok(());
+        };
+        let Some((flow_key, _)) = Self::load_dataset_flow(target_catalog, flow_id).await? else {
+            return Ok(());
+        };
+
+        self.publish(SubscriptionEvent::TaskProgress(TaskProgressEvent {
+            event_time,
+            task_id,
+            flow_id,
+            dataset_id: flow_key.dataset_id,
+            status,
+            outcome,
+        }));
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn flow_id_from_task_metadata(
+    task_metadata: &TaskMetadata,
+) -> Result<Option<FlowID>, InternalError> {
+    task_metadata
+        .try_get_property(METADATA_TASK_FLOW_ID)
+        .map(|flow_id_property| FlowID::from(&flow_id_property).int_err())
+        .transpose()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl MessageConsumer for SubscriptionEventHub {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<FlowProgressMessage> for SubscriptionEventHub {
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        name = "SubscriptionEventHub[FlowProgressMessage]"
+    )]
+    async fn consume_message(
+        &self,
+        target_catalog: &Catalog,
+        message: &FlowProgressMessage,
+    ) -> Result<(), InternalError> {
+        tracing::debug!(received_message = ?message, "Received flow progress message");
+
+        if self.receiver_count() == 0 {
+            return Ok(());
+        }
+
+        self.handle_flow_progress_message(target_catalog, message)
+            .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<TaskProgressMessage> for SubscriptionEventHub {
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        name = "SubscriptionEventHub[TaskProgressMessage]"
+    )]
+    async fn consume_message(
+        &self,
+        target_catalog: &Catalog,
+        message: &TaskProgressMessage,
+    ) -> Result<(), InternalError> {
+        tracing::debug!(received_message = ?message, "Received task progress message");
+
+        if self.receiver_count() == 0 {
+            return Ok(());
+        }
+
+        self.handle_task_progress_message(target_catalog, message)
+            .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<DatasetReferenceMessage> for SubscriptionEventHub {
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        name = "SubscriptionEventHub[DatasetReferenceMessage]"
+    )]
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &DatasetReferenceMessage,
+    ) -> Result<(), InternalError> {
+        tracing::debug!(received_message = ?message, "Received dataset reference message");
+
+        match message {
+            DatasetReferenceMessage::Updated(m) => {
+                self.publish(SubscriptionEvent::DatasetHeadUpdated(
+                    DatasetHeadUpdatedEvent {
+                        event_time: Utc::now(),
+                        dataset_id: m.dataset_id.clone(),
+                        old_head: m.maybe_prev_block_hash.clone(),
+                        new_head: m.new_block_hash.clone(),
+                    },
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
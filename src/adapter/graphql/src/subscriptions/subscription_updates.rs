@@ -0,0 +1,115 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use kamu_flow_system as fs;
+
+use super::{DatasetHeadUpdatedEvent, FlowProgressEvent, TaskProgressEvent};
+use crate::prelude::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Notifies about a flow changing its life-cycle status.
+///
+/// Carries only a summary of the change, the full flow state can be queried
+/// by its identifier
+#[derive(SimpleObject, Debug, Clone)]
+pub struct FlowProgressUpdate {
+    pub flow_id: FlowID,
+    pub dataset_id: DatasetID,
+    pub dataset_flow_type: DatasetFlowType,
+    pub status: FlowStatus,
+    /// Set once the flow reaches the "finished" status
+    pub outcome: Option<FlowOutcomeKind>,
+    pub event_time: DateTime<Utc>,
+}
+
+impl From<FlowProgressEvent> for FlowProgressUpdate {
+    fn from(event: FlowProgressEvent) -> Self {
+        Self {
+            flow_id: event.flow_id.into(),
+            dataset_id: event.dataset_id.into(),
+            dataset_flow_type: event.flow_type.into(),
+            status: event.status.into(),
+            outcome: event.outcome.as_ref().map(Into::into),
+            event_time: event.event_time,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowOutcomeKind {
+    Success,
+    Failed,
+    Aborted,
+}
+
+impl From<&fs::FlowOutcome> for FlowOutcomeKind {
+    fn from(value: &fs::FlowOutcome) -> Self {
+        match value {
+            fs::FlowOutcome::Success(_) => Self::Success,
+            fs::FlowOutcome::Failed(_) => Self::Failed,
+            fs::FlowOutcome::Aborted => Self::Aborted,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Notifies about a task of a dataset flow changing its life-cycle status
+#[derive(SimpleObject, Debug, Clone)]
+pub struct TaskProgressUpdate {
+    pub task_id: TaskID,
+    pub flow_id: FlowID,
+    pub dataset_id: DatasetID,
+    pub status: TaskStatus,
+    /// Set once the task reaches the "finished" status
+    pub outcome: Option<TaskOutcome>,
+    pub event_time: DateTime<Utc>,
+}
+
+impl From<TaskProgressEvent> for TaskProgressUpdate {
+    fn from(event: TaskProgressEvent) -> Self {
+        Self {
+            task_id: event.task_id.into(),
+            flow_id: event.flow_id.into(),
+            dataset_id: event.dataset_id.into(),
+            status: (&event.status).into(),
+            outcome: event.outcome.as_ref().map(Into::into),
+            event_time: event.event_time,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Notifies about the head of a dataset moving to a new block
+#[derive(SimpleObject, Debug, Clone)]
+pub struct DatasetHeadUpdate {
+    pub dataset_id: DatasetID,
+    /// Previous head, when known
+    pub old_head: Option<Multihash>,
+    pub new_head: Multihash,
+    pub event_time: DateTime<Utc>,
+}
+
+impl From<DatasetHeadUpdatedEvent> for DatasetHeadUpdate {
+    fn from(event: DatasetHeadUpdatedEvent) -> Self {
+        Self {
+            dataset_id: event.dataset_id.into(),
+            old_head: event.old_head.map(Into::into),
+            new_head: event.new_head.into(),
+            event_time: event.event_time,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
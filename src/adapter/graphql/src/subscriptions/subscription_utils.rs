@@ -0,0 +1,222 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::time::Duration;
+
+use database_common::DatabaseTransactionRunner;
+use futures::Stream;
+use kamu_accounts::{AuthenticationService, ConnectionAccessToken, GetAccountInfoError};
+use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer, DatasetActionUnauthorizedError};
+use kamu_core::DatasetRepository;
+use opendatafabric as odf;
+use tokio::sync::broadcast;
+
+use super::{SubscriptionEvent, SubscriptionEventHub};
+use crate::prelude::*;
+use crate::utils::make_dataset_access_error;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// How long an idle subscription may go without re-validating its access
+const SUBSCRIPTION_ACCESS_RECHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// What has to stay accessible for the subscription to remain open
+#[derive(Debug, Clone)]
+pub(crate) enum SubscriptionAccess {
+    /// Own account, which only requires the credentials to stay valid
+    Account,
+    Dataset(odf::DatasetID),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Subscriptions outlive the request that has opened them, so the access
+/// check is performed in a dedicated transaction
+pub(crate) async fn check_dataset_subscription_access(
+    ctx: &Context<'_>,
+    dataset_id: &odf::DatasetID,
+) -> Result<(), GqlError> {
+    let catalog = ctx.data::<dill::Catalog>().unwrap();
+
+    DatabaseTransactionRunner::new(catalog.clone())
+        .transactional(|transactional_catalog| async move {
+            let dataset_repo = transactional_catalog
+                .get_one::<dyn DatasetRepository>()
+                .int_err()?;
+            let Some(dataset_handle) = dataset_repo
+                .try_resolve_dataset_ref(&dataset_id.as_local_ref())
+                .await
+                .int_err()?
+            else {
+                return Err(GqlError::Gql(Error::new("Dataset not found").extend_with(
+                    |_, eev| eev.set("dataset_id", dataset_id.to_string()),
+                )));
+            };
+
+            let dataset_action_authorizer = transactional_catalog
+                .get_one::<dyn DatasetActionAuthorizer>()
+                .int_err()?;
+
+            dataset_action_authorizer
+                .check_action_allowed(&dataset_handle, DatasetAction::Read)
+                .await
+                .map_err(|e| match e {
+                    DatasetActionUnauthorizedError::Access(_) => {
+                        make_dataset_access_error(&dataset_handle)
+                    }
+                    DatasetActionUnauthorizedError::Internal(e) => GqlError::Internal(e),
+                })
+        })
+        .await
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the stream of events published after the subscription was opened
+/// and picked by the `select` function.
+///
+/// Access is re-validated before delivering each event and periodically while
+/// the subscription is idle, the stream ends as soon as it is lost.
+pub(crate) fn subscription_events<T, F>(
+    ctx: &Context<'_>,
+    access: SubscriptionAccess,
+    select: F,
+) -> Result<impl Stream<Item = T>, GqlError>
+where
+    T: Send + 'static,
+    F: Fn(SubscriptionEvent) -> Option<T> + Send + 'static,
+{
+    let event_hub = from_catalog::<SubscriptionEventHub>(ctx).int_err()?;
+    let catalog = ctx.data::<dill::Catalog>().unwrap().clone();
+
+    let state = SubscriptionState {
+        receiver: event_hub.subscribe(),
+        catalog,
+        access,
+        select,
+    };
+
+    Ok(futures::stream::unfold(state, |mut state| async move {
+        loop {
+            let maybe_item = match tokio::time::timeout(
+                SUBSCRIPTION_ACCESS_RECHECK_INTERVAL,
+                state.receiver.recv(),
+            )
+            .await
+            {
+                Ok(Ok(event)) => match (state.select)(event) {
+                    Some(item) => Some(item),
+                    None => continue,
+                },
+                Ok(Err(broadcast::error::RecvError::Lagged(skipped_events))) => {
+                    tracing::warn!(skipped_events, "Slow subscriber missed some events");
+                    continue;
+                }
+                Ok(Err(broadcast::error::RecvError::Closed)) => return None,
+                // Idle for a while, time to re-validate the access
+                Err(_) => None,
+            };
+
+            if !state.is_access_valid().await {
+                tracing::debug!(access = ?state.access, "Closing subscription that lost its access");
+                return None;
+            }
+
+            if let Some(item) = maybe_item {
+                return Some((item, state));
+            }
+        }
+    }))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct SubscriptionState<F> {
+    receiver: broadcast::Receiver<SubscriptionEvent>,
+    catalog: dill::Catalog,
+    access: SubscriptionAccess,
+    select: F,
+}
+
+impl<F> SubscriptionState<F> {
+    async fn is_access_valid(&self) -> bool {
+        match check_subscription_access_valid(&self.catalog, &self.access).await {
+            Ok(is_valid) => is_valid,
+            Err(err) => {
+                tracing::warn!(
+                    error = ?err,
+                    error_msg = %err,
+                    "Failed to re-validate subscription access"
+                );
+                false
+            }
+        }
+    }
+}
+
+async fn check_subscription_access_valid(
+    catalog: &dill::Catalog,
+    access: &SubscriptionAccess,
+) -> Result<bool, InternalError> {
+    DatabaseTransactionRunner::new(catalog.clone())
+        .transactional(|transactional_catalog| async move {
+            // Connections authenticated by a token are closed once it expires or
+            // gets revoked
+            if let Ok(access_token) = transactional_catalog.get_one::<ConnectionAccessToken>() {
+                let authentication_service = transactional_catalog
+                    .get_one::<dyn AuthenticationService>()
+                    .int_err()?;
+
+                match authentication_service
+                    .authenticate_by_token(access_token.token.clone())
+                    .await
+                {
+                    Ok(_) => {}
+                    Err(
+                        GetAccountInfoError::AccessToken(_)
+                        | GetAccountInfoError::AccountUnresolved,
+                    ) => return Ok(false),
+                    Err(GetAccountInfoError::Internal(e)) => return Err(e),
+                }
+            }
+
+            let SubscriptionAccess::Dataset(dataset_id) = access else {
+                return Ok(true);
+            };
+
+            let dataset_repo = transactional_catalog
+                .get_one::<dyn DatasetRepository>()
+                .int_err()?;
+            let Some(dataset_handle) = dataset_repo
+                .try_resolve_dataset_ref(&dataset_id.as_local_ref())
+                .await
+                .int_err()?
+            else {
+                return Ok(false);
+            };
+
+            let dataset_action_authorizer = transactional_catalog
+                .get_one::<dyn DatasetActionAuthorizer>()
+                .int_err()?;
+
+            match dataset_action_authorizer
+                .check_action_allowed(&dataset_handle, DatasetAction::Read)
+                .await
+            {
+                Ok(()) => Ok(true),
+                Err(DatasetActionUnauthorizedError::Access(_)) => Ok(false),
+                Err(DatasetActionUnauthorizedError::Internal(e)) => Err(e),
+            }
+        })
+        .await
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -11,8 +11,8 @@ use std::sync::Arc;
 
 use async_graphql::{Context, ErrorExtensions};
 use internal_error::*;
-use kamu_accounts::{CurrentAccountSubject, GetAccessTokenError, LoggedAccount};
-use kamu_core::auth::DatasetActionUnauthorizedError;
+use kamu_accounts::{AccessTokenScopes, CurrentAccountSubject, GetAccessTokenError, LoggedAccount};
+use kamu_core::auth::{DatasetAction, DatasetActionUnauthorizedError};
 use kamu_core::{Dataset, DatasetRepository};
 use kamu_datasets::DatasetEnvVarsConfig;
 use kamu_task_system as ts;
@@ -73,15 +73,76 @@ pub(crate) async fn check_dataset_read_access(
     Ok(())
 }
 
-pub(crate) async fn check_dataset_write_access(
+/// Maintenance covers configuring and triggering flows and managing
+/// environment variables of the dataset. See
+/// [`check_dataset_flow_trigger_access`] for the narrower check applied to
+/// triggering non-destructive flows.
+pub(crate) async fn check_dataset_maintain_access(
     ctx: &Context<'_>,
     dataset_handle: &DatasetHandle,
+) -> Result<(), GqlError> {
+    check_dataset_action_access(ctx, dataset_handle, DatasetAction::Maintain).await
+}
+
+/// Flow-trigger token scopes permit scheduling without permitting the rest of
+/// the maintenance, so the token is checked first and the maintain permission
+/// of the account itself is then evaluated with the token restrictions lifted
+pub(crate) async fn check_dataset_flow_trigger_access(
+    ctx: &Context<'_>,
+    dataset_handle: &DatasetHandle,
+) -> Result<(), GqlError> {
+    let current_account_subject = from_catalog::<CurrentAccountSubject>(ctx).unwrap();
+    let CurrentAccountSubject::Logged(logged_account) = current_account_subject.as_ref() else {
+        return check_dataset_maintain_access(ctx, dataset_handle).await;
+    };
+
+    if !logged_account
+        .token_scopes
+        .allows_flow_trigger(&dataset_handle.id)
+    {
+        return Err(make_dataset_access_error(dataset_handle));
+    }
+
+    let catalog = ctx.data::<dill::Catalog>().unwrap();
+    let account_catalog = dill::CatalogBuilder::new_chained(catalog)
+        .add_value(CurrentAccountSubject::Logged(LoggedAccount {
+            token_scopes: AccessTokenScopes::Unrestricted,
+            ..logged_account.clone()
+        }))
+        .build();
+
+    let dataset_action_authorizer = account_catalog
+        .get_one::<dyn kamu_core::auth::DatasetActionAuthorizer>()
+        .int_err()?;
+
+    dataset_action_authorizer
+        .check_action_allowed(dataset_handle, DatasetAction::Maintain)
+        .await
+        .map_err(|e| match e {
+            DatasetActionUnauthorizedError::Access(_) => make_dataset_access_error(dataset_handle),
+            DatasetActionUnauthorizedError::Internal(e) => GqlError::Internal(e),
+        })?;
+
+    Ok(())
+}
+
+pub(crate) async fn check_dataset_manage_permissions_access(
+    ctx: &Context<'_>,
+    dataset_handle: &DatasetHandle,
+) -> Result<(), GqlError> {
+    check_dataset_action_access(ctx, dataset_handle, DatasetAction::ManagePermissions).await
+}
+
+async fn check_dataset_action_access(
+    ctx: &Context<'_>,
+    dataset_handle: &DatasetHandle,
+    action: DatasetAction,
 ) -> Result<(), GqlError> {
     let dataset_action_authorizer =
         from_catalog::<dyn kamu_core::auth::DatasetActionAuthorizer>(ctx).int_err()?;
 
     dataset_action_authorizer
-        .check_action_allowed(dataset_handle, kamu_core::auth::DatasetAction::Write)
+        .check_action_allowed(dataset_handle, action)
         .await
         .map_err(|e| match e {
             DatasetActionUnauthorizedError::Access(_) => make_dataset_access_error(dataset_handle),
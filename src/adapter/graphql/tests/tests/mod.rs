@@ -11,13 +11,18 @@ mod test_accounts;
 mod test_auth;
 mod test_error_handling;
 mod test_gql_account_flow_configs;
+mod test_gql_account_webhooks;
+mod test_gql_audit_log;
 mod test_gql_data;
+mod test_gql_dataset_collaborators;
 mod test_gql_dataset_env_vars;
 mod test_gql_dataset_flow_configs;
 mod test_gql_dataset_flow_runs;
 mod test_gql_datasets;
 mod test_gql_metadata;
 mod test_gql_metadata_chain;
+mod test_gql_organizations;
 mod test_gql_search;
+mod test_gql_subscriptions;
 mod test_guards;
 mod test_update_schema;
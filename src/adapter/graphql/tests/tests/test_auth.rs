@@ -11,9 +11,18 @@ use async_graphql::value;
 use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin};
 use indoc::indoc;
 use kamu_accounts::testing::{MockAuthenticationService, DUMMY_LOGIN_METHOD};
-use kamu_accounts::{AuthenticationService, DEFAULT_ACCOUNT_ID, DEFAULT_ACCOUNT_NAME_STR};
+use kamu_accounts::{
+    AccessTokenScope,
+    AccessTokenScopes,
+    AuthenticationService,
+    CurrentAccountSubject,
+    DEFAULT_ACCOUNT_ID,
+    DEFAULT_ACCOUNT_NAME,
+    DEFAULT_ACCOUNT_NAME_STR,
+};
 use kamu_accounts_inmem::InMemoryAccessTokenRepository;
 use kamu_accounts_services::AccessTokenServiceImpl;
+use kamu_audit_log::testing::DummyAuditLogService;
 use time_source::SystemTimeSourceDefault;
 
 use crate::utils::authentication_catalogs;
@@ -331,6 +340,163 @@ async fn test_revoke_access_token() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_create_scoped_access_token() {
+    let harness = AuthGQLHarness::new(MockAuthenticationService::expired_token()).await;
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+    let mutation_code = AuthGQLHarness::create_scoped_access_token(
+        &DEFAULT_ACCOUNT_ID.to_string(),
+        "foo",
+        "2050-01-01T00:00:00Z",
+        r#"["read", "flow-trigger"]"#,
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code).data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "auth": {
+                "createAccessToken": {
+                    "__typename": "CreateAccessTokenResultSuccess",
+                    "message": "Success",
+                }
+            }
+        })
+    );
+
+    let query_code = AuthGQLHarness::get_access_tokens_with_scopes(&DEFAULT_ACCOUNT_ID.to_string());
+    let res = schema
+        .execute(async_graphql::Request::new(query_code).data(harness.catalog_authorized.clone()))
+        .await;
+
+    assert_eq!(
+        res.data,
+        value!({
+            "auth": {
+                "listAccessTokens": {
+                    "nodes": [{
+                        "name": "foo",
+                        "expiresAt": "2050-01-01T00:00:00+00:00",
+                        "scopes": ["read", "flow-trigger"],
+                    }]
+                }
+            }
+        })
+    );
+
+    let mutation_code = AuthGQLHarness::create_scoped_access_token(
+        &DEFAULT_ACCOUNT_ID.to_string(),
+        "bar",
+        "2050-01-01T00:00:00Z",
+        r#"["read", "delete"]"#,
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code).data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert_eq!(
+        res.data,
+        value!({
+            "auth": {
+                "createAccessToken": {
+                    "__typename": "CreateAccessTokenResultInvalidScope",
+                    "message": "Invalid access token scope 'delete'",
+                }
+            }
+        })
+    );
+
+    let mutation_code = AuthGQLHarness::create_scoped_access_token(
+        &DEFAULT_ACCOUNT_ID.to_string(),
+        "bar",
+        "2050-01-01T00:00:00Z",
+        "[]",
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code).data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert_eq!(
+        res.data,
+        value!({
+            "auth": {
+                "createAccessToken": {
+                    "__typename": "CreateAccessTokenResultInvalidScope",
+                    "message": "Invalid access token scope ''",
+                }
+            }
+        })
+    );
+
+    let mutation_code = AuthGQLHarness::create_scoped_access_token(
+        &DEFAULT_ACCOUNT_ID.to_string(),
+        "bar",
+        "2000-01-01T00:00:00Z",
+        r#"["read"]"#,
+    );
+
+    let res = schema
+        .execute(async_graphql::Request::new(mutation_code).data(harness.catalog_authorized))
+        .await;
+
+    assert_eq!(
+        res.data,
+        value!({
+            "auth": {
+                "createAccessToken": {
+                    "__typename": "CreateAccessTokenResultAlreadyExpired",
+                    "message": "Access token expiration time 2000-01-01 00:00:00 UTC is in the past",
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_scoped_token_cannot_manage_access_tokens() {
+    let harness = AuthGQLHarness::new(MockAuthenticationService::expired_token()).await;
+
+    let catalog_read_only = dill::CatalogBuilder::new_chained(&harness.catalog_authorized)
+        .add_value(CurrentAccountSubject::logged_with_token_scopes(
+            DEFAULT_ACCOUNT_ID.clone(),
+            DEFAULT_ACCOUNT_NAME.clone(),
+            false,
+            AccessTokenScopes::Restricted(vec![AccessTokenScope::Read]),
+        ))
+        .build();
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+    let mutation_code = AuthGQLHarness::create_access_token(&DEFAULT_ACCOUNT_ID.to_string(), "foo");
+
+    let res = schema
+        .execute(async_graphql::Request::new(mutation_code).data(catalog_read_only))
+        .await;
+
+    assert!(res.is_err());
+    assert_eq!(res.errors.len(), 1);
+    assert_eq!(
+        res.errors[0].message,
+        kamu_adapter_graphql::TOKEN_SCOPE_FORBIDDEN_MESSAGE
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct AuthGQLHarness {
     catalog_anonymous: dill::Catalog,
     catalog_authorized: dill::Catalog,
@@ -346,6 +512,7 @@ impl AuthGQLHarness {
                 .bind::<dyn AuthenticationService, MockAuthenticationService>()
                 .add::<SystemTimeSourceDefault>()
                 .add::<AccessTokenServiceImpl>()
+                .add::<DummyAuditLogService>()
                 .add::<InMemoryAccessTokenRepository>()
                 .add::<DatabaseTransactionRunner>();
 
@@ -387,6 +554,35 @@ impl AuthGQLHarness {
         .replace("<token_name>", token_name)
     }
 
+    fn create_scoped_access_token(
+        account_id: &str,
+        token_name: &str,
+        expires_at: &str,
+        scopes: &str,
+    ) -> String {
+        indoc!(
+            r#"
+            mutation {
+                auth {
+                    createAccessToken (
+                        accountId: "<account_id>",
+                        tokenName: "<token_name>",
+                        expiresAt: "<expires_at>",
+                        scopes: <scopes>
+                    ) {
+                        __typename
+                        message
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<account_id>", account_id)
+        .replace("<token_name>", token_name)
+        .replace("<expires_at>", expires_at)
+        .replace("<scopes>", scopes)
+    }
+
     fn revoke_access_token(token_id: &str) -> String {
         indoc!(
             r#"
@@ -423,4 +619,23 @@ impl AuthGQLHarness {
         )
         .replace("<account_id>", account_id)
     }
+
+    fn get_access_tokens_with_scopes(account_id: &str) -> String {
+        indoc!(
+            r#"
+            query {
+                auth {
+                    listAccessTokens (accountId: "<account_id>", perPage: 10, page: 0) {
+                        nodes {
+                            name,
+                            expiresAt,
+                            scopes
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<account_id>", account_id)
+    }
 }
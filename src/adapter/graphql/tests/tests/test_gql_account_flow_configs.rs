@@ -11,7 +11,7 @@
 
 use async_graphql::value;
 use chrono::Duration;
-use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin};
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin, PaginationOpts};
 use dill::Component;
 use indoc::indoc;
 use kamu::testing::{
@@ -30,9 +30,20 @@ use kamu::{
     DatasetRepositoryWriter,
     DependencyGraphServiceInMemory,
 };
-use kamu_accounts::{JwtAuthenticationConfig, DEFAULT_ACCOUNT_NAME, DEFAULT_ACCOUNT_NAME_STR};
+use kamu_accounts::{
+    AccessTokenScope,
+    AccessTokenScopes,
+    CurrentAccountSubject,
+    JwtAuthenticationConfig,
+    DEFAULT_ACCOUNT_ID,
+    DEFAULT_ACCOUNT_NAME,
+    DEFAULT_ACCOUNT_NAME_STR,
+};
 use kamu_accounts_inmem::InMemoryAccessTokenRepository;
 use kamu_accounts_services::{AccessTokenServiceImpl, AuthenticationServiceImpl};
+use kamu_audit_log::{AuditAction, AuditEvent, AuditEventFilter, AuditLogService};
+use kamu_audit_log_inmem::InMemoryAuditEventRepository;
+use kamu_audit_log_services::AuditLogServiceImpl;
 use kamu_core::*;
 use kamu_flow_system::FlowExecutorConfig;
 use kamu_flow_system_inmem::{InMemoryFlowConfigurationEventStore, InMemoryFlowEventStore};
@@ -338,6 +349,13 @@ async fn test_pause_resume_account_flows() {
 
     assert!(response.is_ok(), "{response:?}");
 
+    let bar_create_result = harness
+        .create_root_dataset(DatasetAlias::new(
+            Some(DEFAULT_ACCOUNT_NAME.clone()),
+            DatasetName::new_unchecked("bar"),
+        ))
+        .await;
+
     let mutation_code = FlowConfigHarness::pause_account_flows(&DEFAULT_ACCOUNT_NAME);
     let response = schema
         .execute(
@@ -435,6 +453,44 @@ async fn test_pause_resume_account_flows() {
             }
         })
     );
+
+    // Every affected dataset gets its own event
+    let mut account_wide_updates = harness
+        .recorded_audit_events()
+        .await
+        .into_iter()
+        .filter(|event| {
+            event.action == AuditAction::FlowConfigurationUpdated
+                && event
+                    .details
+                    .as_ref()
+                    .is_some_and(|details| details.contains("all flows"))
+        })
+        .map(|event| (event.dataset_id.unwrap(), event.details.unwrap()))
+        .collect::<Vec<_>>();
+    account_wide_updates.sort();
+
+    let mut expected_updates = vec![
+        (
+            foo_create_result.dataset_handle.id.clone(),
+            "Paused all flows of dataset 'kamu/foo'".to_string(),
+        ),
+        (
+            foo_create_result.dataset_handle.id.clone(),
+            "Resumed all flows of dataset 'kamu/foo'".to_string(),
+        ),
+        (
+            bar_create_result.dataset_handle.id.clone(),
+            "Paused all flows of dataset 'kamu/bar'".to_string(),
+        ),
+        (
+            bar_create_result.dataset_handle.id.clone(),
+            "Resumed all flows of dataset 'kamu/bar'".to_string(),
+        ),
+    ];
+    expected_updates.sort();
+
+    assert_eq!(account_wide_updates, expected_updates);
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -620,6 +676,44 @@ async fn test_account_configs_all_paused() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_read_only_token_cannot_pause_account_flows() {
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let harness = FlowConfigHarness::with_overrides(FlowRunsHarnessOverrides {
+        mock_dataset_action_authorizer: Some(MockDatasetActionAuthorizer::allowing()),
+        ..Default::default()
+    })
+    .await;
+
+    let catalog_read_only = dill::CatalogBuilder::new_chained(&harness.catalog_authorized)
+        .add_value(CurrentAccountSubject::logged_with_token_scopes(
+            DEFAULT_ACCOUNT_ID.clone(),
+            DEFAULT_ACCOUNT_NAME.clone(),
+            false,
+            AccessTokenScopes::Restricted(vec![AccessTokenScope::Read]),
+        ))
+        .build();
+
+    for mutation_code in [
+        FlowConfigHarness::pause_account_flows(&DEFAULT_ACCOUNT_NAME),
+        FlowConfigHarness::resume_account_flows(&DEFAULT_ACCOUNT_NAME),
+    ] {
+        let response = schema
+            .execute(async_graphql::Request::new(mutation_code).data(catalog_read_only.clone()))
+            .await;
+
+        assert!(response.is_err(), "{response:?}");
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!(
+            response.errors[0].message,
+            kamu_adapter_graphql::TOKEN_SCOPE_FORBIDDEN_MESSAGE
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct FlowConfigHarness {
     _tempdir: tempfile::TempDir,
     _catalog_base: dill::Catalog,
@@ -671,6 +765,8 @@ impl FlowConfigHarness {
             .add_value(mock_dataset_action_authorizer)
             .add::<AuthenticationServiceImpl>()
             .add::<AccessTokenServiceImpl>()
+            .add::<AuditLogServiceImpl>()
+            .add::<InMemoryAuditEventRepository>()
             .add::<InMemoryAccessTokenRepository>()
             .add_value(JwtAuthenticationConfig::default())
             .bind::<dyn kamu::domain::auth::DatasetActionAuthorizer, MockDatasetActionAuthorizer>()
@@ -738,6 +834,25 @@ impl FlowConfigHarness {
             .unwrap()
     }
 
+    async fn recorded_audit_events(&self) -> Vec<AuditEvent> {
+        let audit_log_service = self
+            .catalog_authorized
+            .get_one::<dyn AuditLogService>()
+            .unwrap();
+
+        audit_log_service
+            .list_events(
+                &AuditEventFilter::default(),
+                PaginationOpts {
+                    limit: 100,
+                    offset: 0,
+                },
+            )
+            .await
+            .unwrap()
+            .list
+    }
+
     fn list_flows_query(account_name: &AccountName) -> String {
         indoc!(
             r#"
@@ -0,0 +1,308 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use async_graphql::value;
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin};
+use indoc::indoc;
+use kamu_accounts::DEFAULT_ACCOUNT_NAME_STR;
+use kamu_webhooks::WebhookDeliveryConfig;
+use kamu_webhooks_inmem::{
+    InMemoryWebhookDeliveryRepository,
+    InMemoryWebhookSubscriptionRepository,
+};
+use kamu_webhooks_services::WebhookSubscriptionServiceImpl;
+use messaging_outbox::DummyOutboxImpl;
+use time_source::SystemTimeSourceDefault;
+
+use crate::utils::authentication_catalogs;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_create_and_list_webhook_subscriptions() {
+    let harness = AccountWebhooksHarness::new().await;
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(AccountWebhooksHarness::create_subscription_mutation(
+                "https://example.com/hook",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+
+    let json = serde_json::to_value(&res.data).unwrap();
+    let created = &json["accounts"]["byName"]["webhooks"]["createSubscription"];
+    assert_eq!(created["message"], "Success");
+    assert!(!created["secret"].as_str().unwrap().is_empty());
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(AccountWebhooksHarness::list_subscriptions_query())
+                .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "accounts": {
+                "byName": {
+                    "webhooks": {
+                        "subscriptions": [{
+                            "targetUrl": "https://example.com/hook",
+                            "eventTypes": ["FLOW_SUCCEEDED", "FLOW_FAILED"],
+                            "datasetId": null,
+                            "enabled": true,
+                        }]
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_create_webhook_subscription_invalid_url() {
+    let harness = AccountWebhooksHarness::new().await;
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(AccountWebhooksHarness::create_subscription_mutation(
+                "not-a-url",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+
+    let json = serde_json::to_value(&res.data).unwrap();
+    let created = &json["accounts"]["byName"]["webhooks"]["createSubscription"];
+    assert!(created["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Invalid webhook target URL"));
+    assert!(created["secret"].is_null());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_pause_and_remove_webhook_subscription() {
+    let harness = AccountWebhooksHarness::new().await;
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(AccountWebhooksHarness::create_subscription_mutation(
+                "https://example.com/hook",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    let json = serde_json::to_value(&res.data).unwrap();
+    let subscription_id = json["accounts"]["byName"]["webhooks"]["createSubscription"]
+        ["subscription"]["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(AccountWebhooksHarness::pause_subscription_mutation(
+                &subscription_id,
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "accounts": {
+                "byName": {
+                    "webhooks": {
+                        "pauseSubscription": {
+                            "message": "Success",
+                            "subscription": {
+                                "enabled": false,
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    for expected_message in [
+        "Success".to_string(),
+        format!("Webhook subscription {subscription_id} not found"),
+    ] {
+        let res = schema
+            .execute(
+                async_graphql::Request::new(AccountWebhooksHarness::remove_subscription_mutation(
+                    &subscription_id,
+                ))
+                .data(harness.catalog_authorized.clone()),
+            )
+            .await;
+        assert!(res.is_ok(), "{res:?}");
+        assert_eq!(
+            res.data,
+            value!({
+                "accounts": {
+                    "byName": {
+                        "webhooks": {
+                            "removeSubscription": {
+                                "message": expected_message,
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct AccountWebhooksHarness {
+    _catalog_anonymous: dill::Catalog,
+    catalog_authorized: dill::Catalog,
+}
+
+impl AccountWebhooksHarness {
+    async fn new() -> Self {
+        let catalog_base = {
+            let mut b = dill::CatalogBuilder::new();
+
+            b.add::<DummyOutboxImpl>()
+                .add::<SystemTimeSourceDefault>()
+                .add::<DatabaseTransactionRunner>()
+                .add::<WebhookSubscriptionServiceImpl>()
+                .add_value(WebhookDeliveryConfig::default())
+                .add::<InMemoryWebhookSubscriptionRepository>()
+                .add::<InMemoryWebhookDeliveryRepository>();
+
+            NoOpDatabasePlugin::init_database_components(&mut b);
+
+            b.build()
+        };
+
+        let (catalog_anonymous, catalog_authorized) = authentication_catalogs(&catalog_base).await;
+
+        Self {
+            _catalog_anonymous: catalog_anonymous,
+            catalog_authorized,
+        }
+    }
+
+    fn list_subscriptions_query() -> String {
+        indoc!(
+            r#"
+            {
+                accounts {
+                    byName (name: "<account_name>") {
+                        webhooks {
+                            subscriptions {
+                                targetUrl
+                                eventTypes
+                                datasetId
+                                enabled
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<account_name>", DEFAULT_ACCOUNT_NAME_STR)
+    }
+
+    fn create_subscription_mutation(target_url: &str) -> String {
+        indoc!(
+            r#"
+            mutation {
+                accounts {
+                    byName (accountName: "<account_name>") {
+                        webhooks {
+                            createSubscription (
+                                targetUrl: "<target_url>",
+                                eventTypes: [FLOW_SUCCEEDED, FLOW_FAILED],
+                            ) {
+                                message
+                                ... on CreateWebhookSubscriptionResultSuccess {
+                                    secret
+                                    subscription {
+                                        id
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<account_name>", DEFAULT_ACCOUNT_NAME_STR)
+        .replace("<target_url>", target_url)
+    }
+
+    fn pause_subscription_mutation(subscription_id: &str) -> String {
+        indoc!(
+            r#"
+            mutation {
+                accounts {
+                    byName (accountName: "<account_name>") {
+                        webhooks {
+                            pauseSubscription (subscriptionId: "<subscription_id>") {
+                                message
+                                ... on UpdateWebhookSubscriptionResultSuccess {
+                                    subscription {
+                                        enabled
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<account_name>", DEFAULT_ACCOUNT_NAME_STR)
+        .replace("<subscription_id>", subscription_id)
+    }
+
+    fn remove_subscription_mutation(subscription_id: &str) -> String {
+        indoc!(
+            r#"
+            mutation {
+                accounts {
+                    byName (accountName: "<account_name>") {
+                        webhooks {
+                            removeSubscription (subscriptionId: "<subscription_id>") {
+                                message
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<account_name>", DEFAULT_ACCOUNT_NAME_STR)
+        .replace("<subscription_id>", subscription_id)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
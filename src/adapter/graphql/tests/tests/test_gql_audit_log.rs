@@ -0,0 +1,282 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use async_graphql::value;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use indoc::indoc;
+use kamu_accounts::testing::MockAuthenticationService;
+use kamu_accounts::{
+    AuthenticationService,
+    CurrentAccountSubject,
+    DEFAULT_ACCOUNT_ID,
+    DEFAULT_ACCOUNT_NAME,
+    DEFAULT_ACCOUNT_NAME_STR,
+};
+use kamu_adapter_graphql::STAFF_ONLY_MESSAGE;
+use kamu_audit_log::{AuditAction, AuditLogService};
+use kamu_audit_log_inmem::InMemoryAuditEventRepository;
+use kamu_audit_log_services::AuditLogServiceImpl;
+use opendatafabric::DatasetID;
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_audit_events_require_admin() {
+    let harness = AuditLogHarness::new();
+
+    let res = harness
+        .execute_query(
+            CurrentAccountSubject::new_test(),
+            AuditLogHarness::audit_events_query(""),
+        )
+        .await;
+
+    assert!(res.is_err(), "{res:?}");
+    assert_eq!(
+        res.errors
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<_>>(),
+        vec![STAFF_ONLY_MESSAGE.to_string()]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_audit_events_listing() {
+    let harness = AuditLogHarness::new();
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    harness.record_events(&foo_id).await;
+
+    let res = harness
+        .execute_query(
+            AuditLogHarness::admin_subject(),
+            AuditLogHarness::audit_events_query(""),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "admin": {
+                "auditEvents": {
+                    "totalCount": 3,
+                    "nodes": [
+                        {
+                            "eventTime": "2050-01-01T12:02:00+00:00",
+                            "account": null,
+                            "action": "FLOW_CONFIGURATION_UPDATED",
+                            "datasetId": foo_id.to_string(),
+                            "details": null,
+                        },
+                        {
+                            "eventTime": "2050-01-01T12:01:00+00:00",
+                            "account": {
+                                "accountName": DEFAULT_ACCOUNT_NAME_STR,
+                            },
+                            "action": "DATASET_DELETED",
+                            "datasetId": foo_id.to_string(),
+                            "details": "Deleted dataset 'foo'",
+                        },
+                        {
+                            "eventTime": "2050-01-01T12:00:00+00:00",
+                            "account": {
+                                "accountName": DEFAULT_ACCOUNT_NAME_STR,
+                            },
+                            "action": "ACCESS_TOKEN_CREATED",
+                            "datasetId": null,
+                            "details": null,
+                        },
+                    ],
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_audit_events_filters() {
+    let harness = AuditLogHarness::new();
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    harness.record_events(&foo_id).await;
+
+    let expect_actions = |res: async_graphql::Response, actions: Vec<&str>| {
+        assert!(res.is_ok(), "{res:?}");
+        let json = res.data.into_json().unwrap();
+        let nodes = json["admin"]["auditEvents"]["nodes"].as_array().unwrap();
+        assert_eq!(
+            nodes
+                .iter()
+                .map(|node| node["action"].as_str().unwrap())
+                .collect::<Vec<_>>(),
+            actions
+        );
+    };
+
+    let res = harness
+        .execute_query(
+            AuditLogHarness::admin_subject(),
+            AuditLogHarness::audit_events_query(&format!(
+                r#"(accountId: "{}")"#,
+                *DEFAULT_ACCOUNT_ID
+            )),
+        )
+        .await;
+    expect_actions(res, vec!["DATASET_DELETED", "ACCESS_TOKEN_CREATED"]);
+
+    let res = harness
+        .execute_query(
+            AuditLogHarness::admin_subject(),
+            AuditLogHarness::audit_events_query(&format!(r#"(datasetId: "{foo_id}")"#)),
+        )
+        .await;
+    expect_actions(res, vec!["FLOW_CONFIGURATION_UPDATED", "DATASET_DELETED"]);
+
+    let res = harness
+        .execute_query(
+            AuditLogHarness::admin_subject(),
+            AuditLogHarness::audit_events_query("(action: ACCESS_TOKEN_CREATED)"),
+        )
+        .await;
+    expect_actions(res, vec!["ACCESS_TOKEN_CREATED"]);
+
+    let res = harness
+        .execute_query(
+            AuditLogHarness::admin_subject(),
+            AuditLogHarness::audit_events_query(
+                r#"(from: "2050-01-01T12:01:00Z", to: "2050-01-01T12:02:00Z")"#,
+            ),
+        )
+        .await;
+    expect_actions(res, vec!["DATASET_DELETED"]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct AuditLogHarness {
+    base_catalog: dill::Catalog,
+}
+
+impl AuditLogHarness {
+    fn new() -> Self {
+        let base_catalog = {
+            let mut b = dill::CatalogBuilder::new();
+
+            b.add::<AuditLogServiceImpl>()
+                .add::<InMemoryAuditEventRepository>()
+                .add_value(SystemTimeSourceStub::new_set(Self::start_time()))
+                .bind::<dyn SystemTimeSource, SystemTimeSourceStub>()
+                .add_value(MockAuthenticationService::with_custom_account(
+                    DEFAULT_ACCOUNT_ID.clone(),
+                    DEFAULT_ACCOUNT_NAME.clone(),
+                ))
+                .bind::<dyn AuthenticationService, MockAuthenticationService>();
+
+            b.build()
+        };
+
+        Self { base_catalog }
+    }
+
+    fn start_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap()
+    }
+
+    fn admin_subject() -> CurrentAccountSubject {
+        CurrentAccountSubject::logged(
+            DEFAULT_ACCOUNT_ID.clone(),
+            DEFAULT_ACCOUNT_NAME.clone(),
+            true,
+        )
+    }
+
+    async fn record_events(&self, dataset_id: &DatasetID) {
+        let audit_log_service = self.base_catalog.get_one::<dyn AuditLogService>().unwrap();
+        let time_source = self.base_catalog.get_one::<SystemTimeSourceStub>().unwrap();
+
+        audit_log_service
+            .record(
+                Some(&DEFAULT_ACCOUNT_ID),
+                AuditAction::AccessTokenCreated,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        time_source.set(Self::start_time() + Duration::minutes(1));
+        audit_log_service
+            .record(
+                Some(&DEFAULT_ACCOUNT_ID),
+                AuditAction::DatasetDeleted,
+                Some(dataset_id),
+                Some("Deleted dataset 'foo'".to_string()),
+            )
+            .await
+            .unwrap();
+
+        time_source.set(Self::start_time() + Duration::minutes(2));
+        audit_log_service
+            .record(
+                None,
+                AuditAction::FlowConfigurationUpdated,
+                Some(dataset_id),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    fn audit_events_query(arguments: &str) -> String {
+        indoc!(
+            r#"
+            {
+                admin {
+                    auditEvents<arguments> {
+                        totalCount
+                        nodes {
+                            eventTime
+                            account {
+                                accountName
+                            }
+                            action
+                            datasetId
+                            details
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<arguments>", arguments)
+    }
+
+    async fn execute_query(
+        &self,
+        current_account_subject: CurrentAccountSubject,
+        query: String,
+    ) -> async_graphql::Response {
+        let catalog = dill::CatalogBuilder::new_chained(&self.base_catalog)
+            .add_value(current_account_subject)
+            .build();
+
+        kamu_adapter_graphql::schema_quiet()
+            .execute(async_graphql::Request::new(query).data(catalog))
+            .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -25,6 +25,7 @@ use kamu_accounts_services::{
     LoginPasswordAuthProvider,
     PredefinedAccountsRegistrator,
 };
+use kamu_audit_log::testing::DummyAuditLogService;
 use kamu_core::*;
 use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
@@ -74,6 +75,7 @@ async fn create_catalog_with_local_workspace(
             .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
             .add::<AuthenticationServiceImpl>()
             .add::<AccessTokenServiceImpl>()
+            .add::<DummyAuditLogService>()
             .add::<InMemoryAccessTokenRepository>()
             .add::<InMemoryAccountRepository>()
             .add_value(JwtAuthenticationConfig::default())
@@ -153,6 +155,80 @@ async fn create_test_dataset(
         .unwrap();
 }
 
+/// Creates a dataset whose records are spread over several data slices, so
+/// that they are read by several partitions of the query
+async fn create_test_dataset_with_slices(
+    catalog: &dill::Catalog,
+    tempdir: &Path,
+    name: &str,
+    num_slices: u64,
+    records_per_slice: u64,
+) {
+    let create_dataset = catalog.get_one::<dyn CreateDatasetUseCase>().unwrap();
+
+    let dataset = create_dataset
+        .execute(
+            &DatasetAlias::new(None, DatasetName::new_unchecked(name)),
+            MetadataFactory::metadata_block(MetadataFactory::seed(DatasetKind::Root).build())
+                .build_typed(),
+            Default::default(),
+        )
+        .await
+        .unwrap()
+        .dataset;
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("offset", DataType::UInt64, false),
+        Field::new("blah", DataType::Utf8, false),
+    ]));
+
+    dataset
+        .commit_event(
+            MetadataFactory::set_data_schema()
+                .schema(&schema)
+                .build()
+                .into(),
+            CommitOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    for i in 0..num_slices {
+        let start = i * records_per_slice;
+        let end = start + records_per_slice - 1;
+
+        let offsets: Vec<u64> = (start..=end).collect();
+        let blahs: Vec<String> = offsets.iter().map(|o| format!("r{o}")).collect();
+        let record_batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(UInt64Array::from(offsets)),
+                Arc::new(StringArray::from(blahs)),
+            ],
+        )
+        .unwrap();
+
+        let tmp_data_path = tempdir.join(format!("data-{name}-{i}"));
+        ParquetWriterHelper::from_record_batch(&tmp_data_path, &record_batch).unwrap();
+
+        dataset
+            .commit_add_data(
+                AddDataParams {
+                    prev_checkpoint: None,
+                    prev_offset: start.checked_sub(1),
+                    new_offset_interval: Some(OffsetInterval { start, end }),
+                    new_watermark: None,
+                    new_source_state: None,
+                },
+                Some(OwnedFile::new(tmp_data_path)),
+                None,
+                CommitOpts::default(),
+            )
+            .await
+            .unwrap();
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Tail
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -349,6 +425,163 @@ async fn test_data_query_some() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_query_cursor() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(tempdir.path(), true).await;
+    create_test_dataset(&catalog, tempdir.path(), None).await;
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let query_page = |cursor: Option<String>| {
+        let cursor = cursor
+            .map(|c| format!("cursor: \"{c}\","))
+            .unwrap_or_default();
+        async_graphql::Request::new(format!(
+            r#"
+            {{
+                data {{
+                    query(
+                        query: "select offset from \"kamu/foo\" order by offset",
+                        queryDialect: SQL_DATA_FUSION,
+                        dataFormat: JSON,
+                        limit: 3,
+                        {cursor}
+                    ) {{
+                        ... on DataQueryResultSuccess {{
+                            data {{ content }}
+                            nextCursor
+                        }}
+                        ... on DataQueryResultError {{
+                            errorKind
+                        }}
+                    }}
+                }}
+            }}
+            "#
+        ))
+        .data(catalog.clone())
+    };
+
+    // First page is full and provides a cursor
+    let res = schema.execute(query_page(None)).await;
+    assert!(res.is_ok(), "{res:?}");
+    let json = serde_json::to_string(&res.data).unwrap();
+    let json = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+    let page = &json["data"]["query"];
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(page["data"]["content"].as_str().unwrap())
+            .unwrap(),
+        serde_json::json!([{"offset": 0}, {"offset": 1}, {"offset": 2}])
+    );
+    let cursor = page["nextCursor"].as_str().unwrap().to_string();
+
+    // Second page resumes where the first one ended and is the last one
+    let res = schema.execute(query_page(Some(cursor))).await;
+    assert!(res.is_ok(), "{res:?}");
+    let json = serde_json::to_string(&res.data).unwrap();
+    let json = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+    let page = &json["data"]["query"];
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(page["data"]["content"].as_str().unwrap())
+            .unwrap(),
+        serde_json::json!([{"offset": 3}])
+    );
+    assert_eq!(page["nextCursor"], serde_json::Value::Null);
+
+    // Malformed cursor
+    let res = schema
+        .execute(query_page(Some("garbage".to_string())))
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    let json = serde_json::to_string(&res.data).unwrap();
+    let json = serde_json::from_str::<serde_json::Value>(&json).unwrap();
+    assert_eq!(
+        json["data"]["query"],
+        serde_json::json!({"errorKind": "INVALID_CURSOR"})
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_query_cursor_unordered_multiple_slices() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(tempdir.path(), false).await;
+    create_test_dataset_with_slices(&catalog, tempdir.path(), "foo", 4, 3).await;
+    create_test_dataset_with_slices(&catalog, tempdir.path(), "bar", 2, 3).await;
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let query_page = |query: &str, cursor: Option<String>| {
+        let cursor = cursor
+            .map(|c| format!("cursor: \"{c}\","))
+            .unwrap_or_default();
+        async_graphql::Request::new(format!(
+            r#"
+            {{
+                data {{
+                    query(
+                        query: "{query}",
+                        queryDialect: SQL_DATA_FUSION,
+                        dataFormat: JSON,
+                        limit: 5,
+                        {cursor}
+                    ) {{
+                        ... on DataQueryResultSuccess {{
+                            data {{ content }}
+                            nextCursor
+                        }}
+                        ... on DataQueryResultError {{
+                            errorKind
+                        }}
+                    }}
+                }}
+            }}
+            "#
+        ))
+        .data(catalog.clone())
+    };
+
+    // Results of a single dataset are paged in the order of offsets, even though
+    // the slices are read in parallel
+    let mut offsets = Vec::new();
+    let mut cursor = None;
+    loop {
+        let res = schema
+            .execute(query_page("select offset from foo", cursor.take()))
+            .await;
+        assert!(res.is_ok(), "{res:?}");
+        let json = serde_json::to_value(&res.data).unwrap();
+        let page = &json["data"]["query"];
+
+        let data: Vec<serde_json::Value> =
+            serde_json::from_str(page["data"]["content"].as_str().unwrap()).unwrap();
+        offsets.extend(data.iter().map(|r| r["offset"].as_u64().unwrap()));
+
+        match page["nextCursor"].as_str() {
+            Some(c) => cursor = Some(c.to_string()),
+            None => break,
+        }
+    }
+    assert_eq!(offsets, (0..12).collect::<Vec<_>>());
+
+    // Order of the results of several datasets can't be guaranteed
+    let res = schema
+        .execute(query_page(
+            "select offset from foo union all select offset from bar",
+            None,
+        ))
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    let json = serde_json::to_value(&res.data).unwrap();
+    assert_eq!(json["data"]["query"]["nextCursor"], serde_json::Value::Null);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_data_query_error_sql_unparsable() {
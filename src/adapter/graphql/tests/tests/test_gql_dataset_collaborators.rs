@@ -0,0 +1,428 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use async_graphql::value;
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin, PaginationOpts};
+use dill::Component;
+use indoc::indoc;
+use kamu::testing::{MetadataFactory, MockDatasetActionAuthorizer};
+use kamu::{
+    CreateDatasetFromSnapshotUseCaseImpl,
+    DatasetRepositoryLocalFs,
+    DatasetRepositoryWriter,
+    DependencyGraphServiceInMemory,
+};
+use kamu_accounts::{Account, AccountRepository, JwtAuthenticationConfig};
+use kamu_accounts_inmem::InMemoryAccessTokenRepository;
+use kamu_accounts_services::{AccessTokenServiceImpl, AuthenticationServiceImpl};
+use kamu_audit_log::{AuditAction, AuditEvent, AuditEventFilter, AuditLogService};
+use kamu_audit_log_inmem::InMemoryAuditEventRepository;
+use kamu_audit_log_services::AuditLogServiceImpl;
+use kamu_auth_rebac_inmem::InMemoryRebacRepository;
+use kamu_auth_rebac_services::{DatasetCollaborationServiceImpl, RebacServiceImpl};
+use kamu_core::auth::DatasetActionAuthorizer;
+use kamu_core::{CreateDatasetFromSnapshotUseCase, CreateDatasetResult, DatasetRepository};
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::{AccountID, DatasetKind};
+use time_source::SystemTimeSourceDefault;
+
+use crate::utils::authentication_catalogs;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_manage_dataset_collaborators() {
+    let harness = DatasetCollaboratorsHarness::new(MockDatasetActionAuthorizer::allowing()).await;
+    harness.create_account("alice").await;
+    let created_dataset = harness.create_dataset().await;
+    let dataset_id = created_dataset.dataset_handle.id.to_string();
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(DatasetCollaboratorsHarness::grant_role_mutation(
+                &dataset_id,
+                "alice",
+                "EDITOR",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "collaborators": {
+                        "grantRole": {
+                            "message": "Success",
+                            "collaborator": {
+                                "account": {
+                                    "accountName": "alice",
+                                },
+                                "role": "EDITOR",
+                            },
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    // Granting a new role replaces the previous one
+    let res = schema
+        .execute(
+            async_graphql::Request::new(DatasetCollaboratorsHarness::grant_role_mutation(
+                &dataset_id,
+                "alice",
+                "MAINTAINER",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(DatasetCollaboratorsHarness::list_collaborators_query(
+                &dataset_id,
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "collaborators": [
+                        {
+                            "account": {
+                                "accountName": "alice",
+                            },
+                            "role": "MAINTAINER",
+                        },
+                    ]
+                }
+            }
+        })
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(DatasetCollaboratorsHarness::revoke_role_mutation(
+                &dataset_id,
+                "alice",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "collaborators": {
+                        "revokeRole": {
+                            "message": "Success",
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(DatasetCollaboratorsHarness::revoke_role_mutation(
+                &dataset_id,
+                "alice",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "collaborators": {
+                        "revokeRole": {
+                            "message": format!(
+                                "Account '{}' has no role in dataset '{}'",
+                                AccountID::new_seeded_ed25519(b"alice"),
+                                dataset_id,
+                            ),
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(DatasetCollaboratorsHarness::grant_role_mutation(
+                &dataset_id,
+                "bob",
+                "READER",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "collaborators": {
+                        "grantRole": {
+                            "message": "Account 'bob' not found",
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    // Only the changes that took effect are recorded
+    let audit_events = harness.recorded_audit_events().await;
+    let mut recorded_actions = audit_events
+        .iter()
+        .map(|event| event.action.as_str())
+        .collect::<Vec<_>>();
+    recorded_actions.sort_unstable();
+    assert_eq!(
+        recorded_actions,
+        vec![
+            AuditAction::DatasetRoleGranted.as_str(),
+            AuditAction::DatasetRoleGranted.as_str(),
+            AuditAction::DatasetRoleRevoked.as_str(),
+        ]
+    );
+    assert!(audit_events
+        .iter()
+        .all(|event| event.dataset_id.as_ref() == Some(&created_dataset.dataset_handle.id)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_collaborators_require_manage_permissions_access() {
+    let harness = DatasetCollaboratorsHarness::new(MockDatasetActionAuthorizer::denying()).await;
+    harness.create_account("alice").await;
+    let created_dataset = harness.create_dataset().await;
+    let dataset_id = created_dataset.dataset_handle.id.to_string();
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    for request_code in [
+        DatasetCollaboratorsHarness::grant_role_mutation(&dataset_id, "alice", "ADMIN"),
+        DatasetCollaboratorsHarness::revoke_role_mutation(&dataset_id, "alice"),
+        DatasetCollaboratorsHarness::list_collaborators_query(&dataset_id),
+    ] {
+        let res = schema
+            .execute(
+                async_graphql::Request::new(request_code).data(harness.catalog_authorized.clone()),
+            )
+            .await;
+        assert!(res.is_err());
+        assert_eq!(
+            res.errors
+                .into_iter()
+                .map(|e| e.message)
+                .collect::<Vec<_>>(),
+            vec!["Dataset access error".to_string()]
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DatasetCollaboratorsHarness {
+    _tempdir: tempfile::TempDir,
+    catalog_authorized: dill::Catalog,
+}
+
+impl DatasetCollaboratorsHarness {
+    async fn new(mock_dataset_action_authorizer: MockDatasetActionAuthorizer) -> Self {
+        let tempdir = tempfile::tempdir().unwrap();
+        let datasets_dir = tempdir.path().join("datasets");
+        std::fs::create_dir(&datasets_dir).unwrap();
+
+        let catalog_base = {
+            let mut b = dill::CatalogBuilder::new();
+
+            b.add::<DummyOutboxImpl>()
+                .add_builder(
+                    DatasetRepositoryLocalFs::builder()
+                        .with_root(datasets_dir)
+                        .with_multi_tenant(false),
+                )
+                .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+                .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+                .add::<CreateDatasetFromSnapshotUseCaseImpl>()
+                .add::<SystemTimeSourceDefault>()
+                .add_value(mock_dataset_action_authorizer)
+                .bind::<dyn DatasetActionAuthorizer, MockDatasetActionAuthorizer>()
+                .add::<DependencyGraphServiceInMemory>()
+                .add::<DatabaseTransactionRunner>()
+                .add::<AuthenticationServiceImpl>()
+                .add::<AccessTokenServiceImpl>()
+                .add::<AuditLogServiceImpl>()
+                .add::<InMemoryAuditEventRepository>()
+                .add::<InMemoryAccessTokenRepository>()
+                .add_value(JwtAuthenticationConfig::default())
+                .add::<InMemoryRebacRepository>()
+                .add::<RebacServiceImpl>()
+                .add::<DatasetCollaborationServiceImpl>();
+
+            NoOpDatabasePlugin::init_database_components(&mut b);
+
+            b.build()
+        };
+
+        let (_, catalog_authorized) = authentication_catalogs(&catalog_base).await;
+
+        Self {
+            _tempdir: tempdir,
+            catalog_authorized,
+        }
+    }
+
+    async fn create_account(&self, name: &str) {
+        let account_repo = self
+            .catalog_authorized
+            .get_one::<dyn AccountRepository>()
+            .unwrap();
+
+        account_repo
+            .create_account(&Account::test(
+                AccountID::new_seeded_ed25519(name.as_bytes()),
+                name,
+            ))
+            .await
+            .unwrap();
+    }
+
+    async fn recorded_audit_events(&self) -> Vec<AuditEvent> {
+        let audit_log_service = self
+            .catalog_authorized
+            .get_one::<dyn AuditLogService>()
+            .unwrap();
+
+        audit_log_service
+            .list_events(
+                &AuditEventFilter::default(),
+                PaginationOpts {
+                    limit: 100,
+                    offset: 0,
+                },
+            )
+            .await
+            .unwrap()
+            .list
+    }
+
+    async fn create_dataset(&self) -> CreateDatasetResult {
+        let create_dataset_from_snapshot = self
+            .catalog_authorized
+            .get_one::<dyn CreateDatasetFromSnapshotUseCase>()
+            .unwrap();
+
+        create_dataset_from_snapshot
+            .execute(
+                MetadataFactory::dataset_snapshot()
+                    .kind(DatasetKind::Root)
+                    .name("foo")
+                    .push_event(MetadataFactory::set_polling_source().build())
+                    .build(),
+                Default::default(),
+            )
+            .await
+            .unwrap()
+    }
+
+    fn list_collaborators_query(dataset_id: &str) -> String {
+        indoc!(
+            r#"
+            {
+                datasets {
+                    byId (datasetId: "<dataset_id>") {
+                        collaborators {
+                            account {
+                                accountName
+                            }
+                            role
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<dataset_id>", dataset_id)
+    }
+
+    fn grant_role_mutation(dataset_id: &str, account_name: &str, role: &str) -> String {
+        indoc!(
+            r#"
+            mutation {
+                datasets {
+                    byId (datasetId: "<dataset_id>") {
+                        collaborators {
+                            grantRole (accountName: "<account_name>", role: <role>) {
+                                message
+                                ... on GrantDatasetRoleResultSuccess {
+                                    collaborator {
+                                        account {
+                                            accountName
+                                        }
+                                        role
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<dataset_id>", dataset_id)
+        .replace("<account_name>", account_name)
+        .replace("<role>", role)
+    }
+
+    fn revoke_role_mutation(dataset_id: &str, account_name: &str) -> String {
+        indoc!(
+            r#"
+            mutation {
+                datasets {
+                    byId (datasetId: "<dataset_id>") {
+                        collaborators {
+                            revokeRole (accountName: "<account_name>") {
+                                message
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<dataset_id>", dataset_id)
+        .replace("<account_name>", account_name)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use async_graphql::value;
-use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin};
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin, PaginationOpts};
 use dill::Component;
 use indoc::indoc;
 use kamu::testing::{MetadataFactory, MockPollingIngestService, MockTransformService};
@@ -18,8 +18,11 @@ use kamu::{
     DatasetRepositoryWriter,
     DependencyGraphServiceInMemory,
 };
+use kamu_accounts::AccessTokenScope;
+use kamu_audit_log::{AuditAction, AuditEvent, AuditEventFilter, AuditLogService};
+use kamu_audit_log_inmem::InMemoryAuditEventRepository;
+use kamu_audit_log_services::AuditLogServiceImpl;
 use kamu_core::{
-    auth,
     CreateDatasetFromSnapshotUseCase,
     CreateDatasetResult,
     DatasetRepository,
@@ -32,7 +35,12 @@ use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::SystemTimeSourceDefault;
 
-use crate::utils::{authentication_catalogs, expect_anonymous_access_error};
+use crate::utils::{
+    authentication_catalogs,
+    expect_anonymous_access_error,
+    token_scoped_catalog,
+    TokenScopedDatasetActionAuthorizer,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -203,6 +211,13 @@ async fn test_crud_time_delta_root_dataset() {
             }
         })
     );
+
+    let audit_events = harness.recorded_audit_events().await;
+    assert_eq!(audit_events.len(), 2);
+    assert!(audit_events.iter().all(|event| {
+        event.action == AuditAction::FlowConfigurationUpdated
+            && event.dataset_id.as_ref() == Some(&create_result.dataset_handle.id)
+    }));
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -729,6 +744,239 @@ async fn test_crud_compaction_root_dataset() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_crud_verify_derived_dataset() {
+    let harness = FlowConfigHarness::with_overrides(FlowRunsHarnessOverrides {
+        transform_service_mock: Some(MockTransformService::with_set_transform()),
+        polling_service_mock: Some(MockPollingIngestService::with_active_polling_source()),
+    })
+    .await;
+    harness.create_root_dataset().await;
+    let create_derived_result = harness.create_derived_dataset().await;
+
+    let mutation_code = FlowConfigHarness::set_config_verify_mutation(
+        &create_derived_result.dataset_handle.id,
+        "VERIFY",
+        false,
+        true,
+        (1, "DAYS"),
+    );
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code.clone())
+                .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "flows": {
+                        "configs": {
+                            "setConfigVerify": {
+                                "__typename": "SetFlowConfigSuccess",
+                                "message": "Success",
+                                "config": {
+                                    "__typename": "FlowConfiguration",
+                                    "paused": false,
+                                    "ingest": null,
+                                    "verify": {
+                                        "replayTransformations": true,
+                                        "schedule": {
+                                            "__typename": "TimeDelta",
+                                            "every": 1,
+                                            "unit": "DAYS"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    // Verify configuration is not accepted for other flow types
+    let mutation_code = FlowConfigHarness::set_config_verify_mutation(
+        &create_derived_result.dataset_handle.id,
+        "EXECUTE_TRANSFORM",
+        false,
+        true,
+        (1, "DAYS"),
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code.clone())
+                .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "flows": {
+                        "configs": {
+                            "setConfigVerify": {
+                                "__typename": "FlowTypeIsNotSupported",
+                                "message": "Flow type is not supported",
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_crud_retention_root_dataset() {
+    let harness = FlowConfigHarness::with_overrides(FlowRunsHarnessOverrides {
+        transform_service_mock: Some(MockTransformService::with_set_transform()),
+        polling_service_mock: Some(MockPollingIngestService::with_active_polling_source()),
+    })
+    .await;
+    let create_root_result = harness.create_root_dataset().await;
+    let create_derived_result = harness.create_derived_dataset().await;
+
+    let mutation_code = FlowConfigHarness::set_config_retention_mutation(
+        &create_root_result.dataset_handle.id,
+        "RETENTION",
+        false,
+        (90, "DAYS"),
+        true,
+        (1, "DAYS"),
+    );
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code.clone())
+                .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "flows": {
+                        "configs": {
+                            "setConfigRetention": {
+                                "__typename": "SetFlowConfigSuccess",
+                                "message": "Success",
+                                "config": {
+                                    "__typename": "FlowConfiguration",
+                                    "paused": false,
+                                    "retention": {
+                                        "retentionWindow": {
+                                            "every": 90,
+                                            "unit": "DAYS"
+                                        },
+                                        "dryRun": true,
+                                        "schedule": {
+                                            "__typename": "TimeDelta",
+                                            "every": 1,
+                                            "unit": "DAYS"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    // Retention window has to be positive
+    let mutation_code = FlowConfigHarness::set_config_retention_mutation(
+        &create_root_result.dataset_handle.id,
+        "RETENTION",
+        false,
+        (0, "DAYS"),
+        false,
+        (1, "DAYS"),
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code.clone())
+                .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "flows": {
+                        "configs": {
+                            "setConfigRetention": {
+                                "__typename": "FlowInvalidRetentionConfig",
+                                "message": "Retention window must be a positive duration",
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    // Records of derivative datasets cannot be removed
+    let mutation_code = FlowConfigHarness::set_config_retention_mutation(
+        &create_derived_result.dataset_handle.id,
+        "RETENTION",
+        false,
+        (90, "DAYS"),
+        false,
+        (1, "DAYS"),
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code.clone())
+                .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "flows": {
+                        "configs": {
+                            "setConfigRetention": {
+                                "__typename": "FlowIncompatibleDatasetKind",
+                                "message": "Expected a Root dataset, but a Derivative dataset was provided",
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_transform_config_validation() {
     let harness = FlowConfigHarness::with_overrides(FlowRunsHarnessOverrides {
@@ -1451,6 +1699,99 @@ async fn test_set_metadataonly_compaction_config_form_derivative() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_set_soft_compaction_config_for_derivative() {
+    let harness = FlowConfigHarness::with_overrides(FlowRunsHarnessOverrides {
+        transform_service_mock: Some(MockTransformService::with_set_transform()),
+        polling_service_mock: Some(MockPollingIngestService::with_active_polling_source()),
+    })
+    .await;
+    harness.create_root_dataset().await;
+    let create_derived_result = harness.create_derived_dataset().await;
+
+    let mutation_code = FlowConfigHarness::set_config_compaction_full_mutation(
+        &create_derived_result.dataset_handle.id,
+        "SOFT_COMPACTION",
+        1_000_000,
+        10000,
+        false,
+    );
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code.clone())
+                .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "flows": {
+                        "configs": {
+                            "setConfigCompaction": {
+                                "__typename": "SetFlowConfigSuccess",
+                                "message": "Success",
+                                "config": {
+                                    "__typename": "FlowConfiguration",
+                                    "paused": false,
+                                    "ingest": null,
+                                    "transform": null,
+                                    "compaction": {
+                                        "__typename": "CompactionFull",
+                                        "maxSliceSize": 1_000_000,
+                                        "maxSliceRecords": 10000,
+                                        "recursive": false
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    // Soft compaction has no metadata-only mode
+    let mutation_code = FlowConfigHarness::set_config_compaction_metadata_only_mutation(
+        &create_derived_result.dataset_handle.id,
+        "SOFT_COMPACTION",
+        false,
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(mutation_code.clone())
+                .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "datasets": {
+                "byId": {
+                    "flows": {
+                        "configs": {
+                            "setConfigCompaction": {
+                                "__typename": "FlowTypeIsNotSupported",
+                                "message": "Flow type is not supported",
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_set_config_for_hard_compaction_fails() {
     let harness = FlowConfigHarness::with_overrides(FlowRunsHarnessOverrides {
@@ -1597,6 +1938,98 @@ async fn test_anonymous_setters_fail() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_flow_trigger_token_setters_fail() {
+    let harness = FlowConfigHarness::with_overrides(FlowRunsHarnessOverrides {
+        transform_service_mock: Some(MockTransformService::with_set_transform()),
+        polling_service_mock: Some(MockPollingIngestService::with_active_polling_source()),
+    })
+    .await;
+    let create_root_result = harness.create_root_dataset().await;
+    let create_derived_result = harness.create_derived_dataset().await;
+
+    let mutation_codes = [
+        FlowConfigHarness::set_ingest_config_time_delta_mutation(
+            &create_root_result.dataset_handle.id,
+            "INGEST",
+            false,
+            30,
+            "MINUTES",
+            false,
+        ),
+        FlowConfigHarness::set_config_transform_mutation(
+            &create_derived_result.dataset_handle.id,
+            "EXECUTE_TRANSFORM",
+            false,
+            1,
+            (30, "MINUTES"),
+        ),
+        FlowConfigHarness::set_config_retention_mutation(
+            &create_root_result.dataset_handle.id,
+            "RETENTION",
+            false,
+            (90, "DAYS"),
+            false,
+            (1, "DAYS"),
+        ),
+        FlowConfigHarness::pause_flows_of_type_mutation(
+            &create_root_result.dataset_handle.id,
+            "INGEST",
+        ),
+        FlowConfigHarness::resume_flows_of_type_mutation(
+            &create_root_result.dataset_handle.id,
+            "INGEST",
+        ),
+    ];
+
+    let catalog_flow_trigger = token_scoped_catalog(
+        &harness.catalog_authorized,
+        vec![AccessTokenScope::FlowTrigger(None)],
+    );
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+    for mutation_code in mutation_codes {
+        let res = schema
+            .execute(
+                async_graphql::Request::new(mutation_code.clone())
+                    .data(catalog_flow_trigger.clone()),
+            )
+            .await;
+
+        assert!(res.is_err(), "{res:?}");
+        assert_eq!(
+            res.errors
+                .into_iter()
+                .map(|e| e.message)
+                .collect::<Vec<_>>(),
+            vec!["Dataset access error".to_string()]
+        );
+    }
+
+    // Write access to the dataset is sufficient to configure its flows
+    let res = schema
+        .execute(
+            async_graphql::Request::new(FlowConfigHarness::set_config_retention_mutation(
+                &create_root_result.dataset_handle.id,
+                "RETENTION",
+                false,
+                (90, "DAYS"),
+                false,
+                (1, "DAYS"),
+            ))
+            .data(token_scoped_catalog(
+                &harness.catalog_authorized,
+                vec![AccessTokenScope::Write(Some(
+                    create_root_result.dataset_handle.id.clone(),
+                ))],
+            )),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Default)]
 struct FlowRunsHarnessOverrides {
     transform_service_mock: Option<MockTransformService>,
@@ -1636,10 +2069,12 @@ impl FlowConfigHarness {
                 .bind::<dyn PollingIngestService, MockPollingIngestService>()
                 .add_value(transform_service_mock)
                 .bind::<dyn TransformService, MockTransformService>()
-                .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+                .add::<TokenScopedDatasetActionAuthorizer>()
                 .add::<DependencyGraphServiceInMemory>()
                 .add::<FlowConfigurationServiceImpl>()
                 .add::<InMemoryFlowConfigurationEventStore>()
+                .add::<AuditLogServiceImpl>()
+                .add::<InMemoryAuditEventRepository>()
                 .add::<DatabaseTransactionRunner>();
 
             NoOpDatabasePlugin::init_database_components(&mut b);
@@ -1700,6 +2135,25 @@ impl FlowConfigHarness {
             .unwrap()
     }
 
+    async fn recorded_audit_events(&self) -> Vec<AuditEvent> {
+        let audit_log_service = self
+            .catalog_authorized
+            .get_one::<dyn AuditLogService>()
+            .unwrap();
+
+        audit_log_service
+            .list_events(
+                &AuditEventFilter::default(),
+                PaginationOpts {
+                    limit: 100,
+                    offset: 0,
+                },
+            )
+            .await
+            .unwrap()
+            .list
+    }
+
     fn extract_time_delta_from_response(response_json: &serde_json::Value) -> (u64, &str) {
         let schedule_json = &response_json["datasets"]["byId"]["flows"]["configs"]
             ["setConfigIngest"]["config"]["ingest"]["schedule"];
@@ -1907,6 +2361,140 @@ impl FlowConfigHarness {
         .replace("<minRecordsToAwait>", &min_records_to_await.to_string())
     }
 
+    fn set_config_verify_mutation(
+        id: &DatasetID,
+        dataset_flow_type: &str,
+        paused: bool,
+        replay_transformations: bool,
+        schedule_time_delta: (u32, &str),
+    ) -> String {
+        indoc!(
+            r#"
+            mutation {
+                datasets {
+                    byId (datasetId: "<id>") {
+                        flows {
+                            configs {
+                                setConfigVerify (
+                                    datasetFlowType: "<dataset_flow_type>",
+                                    paused: <paused>,
+                                    verify: {
+                                        replayTransformations: <replayTransformations>,
+                                        schedule: {
+                                            timeDelta: { every: <every>, unit: "<unit>" }
+                                        }
+                                    }
+                                ) {
+                                    __typename,
+                                    message
+                                    ... on SetFlowConfigSuccess {
+                                        config {
+                                            __typename
+                                            paused
+                                            ingest {
+                                                __typename
+                                            }
+                                            verify {
+                                                replayTransformations
+                                                schedule {
+                                                    __typename
+                                                    ... on TimeDelta {
+                                                        every
+                                                        unit
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<id>", &id.to_string())
+        .replace("<dataset_flow_type>", dataset_flow_type)
+        .replace("<paused>", if paused { "true" } else { "false" })
+        .replace(
+            "<replayTransformations>",
+            if replay_transformations {
+                "true"
+            } else {
+                "false"
+            },
+        )
+        .replace("<every>", &schedule_time_delta.0.to_string())
+        .replace("<unit>", schedule_time_delta.1)
+    }
+
+    fn set_config_retention_mutation(
+        id: &DatasetID,
+        dataset_flow_type: &str,
+        paused: bool,
+        retention_window: (u32, &str),
+        dry_run: bool,
+        schedule_time_delta: (u32, &str),
+    ) -> String {
+        indoc!(
+            r#"
+            mutation {
+                datasets {
+                    byId (datasetId: "<id>") {
+                        flows {
+                            configs {
+                                setConfigRetention (
+                                    datasetFlowType: "<dataset_flow_type>",
+                                    paused: <paused>,
+                                    retention: {
+                                        retentionWindow: { every: <windowEvery>, unit: "<windowUnit>" },
+                                        dryRun: <dryRun>,
+                                        schedule: {
+                                            timeDelta: { every: <every>, unit: "<unit>" }
+                                        }
+                                    }
+                                ) {
+                                    __typename,
+                                    message
+                                    ... on SetFlowConfigSuccess {
+                                        config {
+                                            __typename
+                                            paused
+                                            retention {
+                                                retentionWindow {
+                                                    every
+                                                    unit
+                                                }
+                                                dryRun
+                                                schedule {
+                                                    __typename
+                                                    ... on TimeDelta {
+                                                        every
+                                                        unit
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<id>", &id.to_string())
+        .replace("<dataset_flow_type>", dataset_flow_type)
+        .replace("<paused>", if paused { "true" } else { "false" })
+        .replace("<windowEvery>", &retention_window.0.to_string())
+        .replace("<windowUnit>", retention_window.1)
+        .replace("<dryRun>", if dry_run { "true" } else { "false" })
+        .replace("<every>", &schedule_time_delta.0.to_string())
+        .replace("<unit>", schedule_time_delta.1)
+    }
+
     fn set_config_compaction_full_mutation(
         id: &DatasetID,
         dataset_flow_type: &str,
@@ -30,6 +30,7 @@ use kamu::{
     DependencyGraphServiceInMemory,
 };
 use kamu_accounts::{
+    AccessTokenScope,
     CurrentAccountSubject,
     JwtAuthenticationConfig,
     LoggedAccount,
@@ -38,8 +39,8 @@ use kamu_accounts::{
 };
 use kamu_accounts_inmem::InMemoryAccessTokenRepository;
 use kamu_accounts_services::{AccessTokenServiceImpl, AuthenticationServiceImpl};
+use kamu_audit_log::testing::DummyAuditLogService;
 use kamu_core::{
-    auth,
     CompactionResult,
     CreateDatasetFromSnapshotUseCase,
     CreateDatasetResult,
@@ -73,7 +74,12 @@ use messaging_outbox::{register_message_dispatcher, Outbox, OutboxExt, OutboxImm
 use opendatafabric::{AccountID, DatasetID, DatasetKind, Multihash};
 use time_source::SystemTimeSourceDefault;
 
-use crate::utils::{authentication_catalogs, expect_anonymous_access_error};
+use crate::utils::{
+    authentication_catalogs,
+    expect_anonymous_access_error,
+    token_scoped_catalog,
+    TokenScopedDatasetActionAuthorizer,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -3013,6 +3019,97 @@ async fn test_anonymous_operation_fails() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_flow_trigger_token_permissions() {
+    let harness = FlowRunsHarness::with_overrides(FlowRunsHarnessOverrides {
+        dependency_graph_mock: Some(MockDependencyGraphRepository::no_dependencies()),
+        dataset_changes_mock: Some(MockDatasetChangesService::default()),
+        transform_service_mock: Some(MockTransformService::with_set_transform()),
+        polling_service_mock: Some(MockPollingIngestService::with_active_polling_source()),
+    })
+    .await;
+
+    let create_root_result = harness.create_root_dataset().await;
+
+    let root_dataset_blocks: Vec<_> = create_root_result
+        .dataset
+        .as_metadata_chain()
+        .iter_blocks_interval(&create_root_result.head, None, false)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let catalog_flow_trigger = token_scoped_catalog(
+        &harness.catalog_authorized,
+        vec![AccessTokenScope::FlowTrigger(Some(
+            create_root_result.dataset_handle.id.clone(),
+        ))],
+    );
+
+    // Destructive flows and custom run configurations require maintain access
+    let mutation_codes = [
+        FlowRunsHarness::trigger_reset_flow_mutation(
+            &create_root_result.dataset_handle.id,
+            &root_dataset_blocks[1].0,
+            &root_dataset_blocks[0].0,
+            false,
+            "RESET",
+        ),
+        FlowRunsHarness::trigger_flow_mutation(&create_root_result.dataset_handle.id, "RETENTION"),
+        FlowRunsHarness::trigger_flow_mutation(
+            &create_root_result.dataset_handle.id,
+            "HARD_COMPACTION",
+        ),
+        FlowRunsHarness::trigger_flow_with_compaction_config_mutation(
+            &create_root_result.dataset_handle.id,
+            "HARD_COMPACTION",
+            10000,
+            1_000_000,
+            false,
+        ),
+    ];
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+    for mutation_code in mutation_codes {
+        let response = schema
+            .execute(
+                async_graphql::Request::new(mutation_code.clone())
+                    .data(catalog_flow_trigger.clone()),
+            )
+            .await;
+
+        assert!(response.is_err(), "{response:?}");
+        assert_eq!(
+            response
+                .errors
+                .into_iter()
+                .map(|e| e.message)
+                .collect::<Vec<_>>(),
+            vec!["Dataset access error".to_string()]
+        );
+    }
+
+    // Non-destructive flows with their stored configuration can be triggered
+    let response = schema
+        .execute(
+            async_graphql::Request::new(FlowRunsHarness::trigger_flow_mutation(
+                &create_root_result.dataset_handle.id,
+                "INGEST",
+            ))
+            .data(catalog_flow_trigger.clone()),
+        )
+        .await;
+
+    assert!(response.is_ok(), "{response:?}");
+    assert_eq!(
+        response.data.into_json().unwrap()["datasets"]["byId"]["flows"]["runs"]["triggerFlow"]
+            ["__typename"],
+        "TriggerFlowSuccess"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_config_snapshot_returned_correctly() {
     let harness = FlowRunsHarness::with_overrides(FlowRunsHarnessOverrides {
@@ -3189,7 +3286,7 @@ impl FlowRunsHarness {
             .add_value(dataset_changes_mock)
             .bind::<dyn DatasetChangesService, MockDatasetChangesService>()
             .add::<SystemTimeSourceDefault>()
-            .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+            .add::<TokenScopedDatasetActionAuthorizer>()
             .add::<DependencyGraphServiceInMemory>()
             .add_value(dependency_graph_mock)
             .bind::<dyn DependencyGraphRepository, MockDependencyGraphRepository>()
@@ -3207,6 +3304,7 @@ impl FlowRunsHarness {
             .bind::<dyn PollingIngestService, MockPollingIngestService>()
             .add::<AuthenticationServiceImpl>()
             .add::<AccessTokenServiceImpl>()
+            .add::<DummyAuditLogService>()
             .add::<InMemoryAccessTokenRepository>()
             .add_value(JwtAuthenticationConfig::default())
             .add::<DatasetOwnershipServiceInMemory>()
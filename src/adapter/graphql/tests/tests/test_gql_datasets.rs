@@ -15,6 +15,7 @@ use kamu::testing::MetadataFactory;
 use kamu::*;
 use kamu_accounts::testing::MockAuthenticationService;
 use kamu_accounts::*;
+use kamu_audit_log::testing::DummyAuditLogService;
 use kamu_core::*;
 use messaging_outbox::{register_message_dispatcher, Outbox, OutboxImmediateImpl};
 use mockall::predicate::eq;
@@ -622,6 +623,7 @@ async fn dataset_view_permissions() {
                         canRename
                         canCommit
                         canSchedule
+                        canManagePermissions
                     }
                 }
             }
@@ -643,6 +645,7 @@ async fn dataset_view_permissions() {
                         "canRename": true,
                         "canCommit": true,
                         "canSchedule": true,
+                        "canManagePermissions": true,
                     }
                 }
             }
@@ -685,6 +688,7 @@ impl GraphQLDatasetsHarness {
                 .add::<CreateDatasetFromSnapshotUseCaseImpl>()
                 .add::<RenameDatasetUseCaseImpl>()
                 .add::<DeleteDatasetUseCaseImpl>()
+                .add::<DummyAuditLogService>()
                 .add::<DependencyGraphServiceInMemory>()
                 .add_builder(
                     DatasetRepositoryLocalFs::builder()
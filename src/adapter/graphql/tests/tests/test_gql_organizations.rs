@@ -0,0 +1,379 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use async_graphql::value;
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin, PaginationOpts};
+use indoc::indoc;
+use kamu_accounts::{
+    Account,
+    AccountRepository,
+    AccountType,
+    JwtAuthenticationConfig,
+    DEFAULT_ACCOUNT_ID,
+    DEFAULT_ACCOUNT_NAME_STR,
+};
+use kamu_accounts_inmem::InMemoryAccessTokenRepository;
+use kamu_accounts_services::{AccessTokenServiceImpl, AuthenticationServiceImpl};
+use kamu_audit_log::{AuditAction, AuditEvent, AuditEventFilter, AuditLogService};
+use kamu_audit_log_inmem::InMemoryAuditEventRepository;
+use kamu_audit_log_services::AuditLogServiceImpl;
+use kamu_auth_rebac::{AccountToOrganizationRelation, OrganizationMembershipService};
+use kamu_auth_rebac_inmem::InMemoryRebacRepository;
+use kamu_auth_rebac_services::{OrganizationMembershipServiceImpl, RebacServiceImpl};
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::AccountID;
+use time_source::SystemTimeSourceDefault;
+
+use crate::utils::authentication_catalogs;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const ORGANIZATION_NAME: &str = "acme";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_manage_organization_members() {
+    let harness = OrganizationsHarness::new().await;
+    harness.create_account("alice", AccountType::User).await;
+    harness
+        .set_member_role(&DEFAULT_ACCOUNT_ID, AccountToOrganizationRelation::Owner)
+        .await;
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(OrganizationsHarness::set_member_role_mutation(
+                "alice",
+                "MAINTAINER",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "accounts": {
+                "organization": {
+                    "setMemberRole": {
+                        "message": "Success",
+                        "member": {
+                            "account": {
+                                "accountName": "alice",
+                            },
+                            "role": "MAINTAINER",
+                        }
+                    }
+                }
+            }
+        })
+    );
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(OrganizationsHarness::list_members_query())
+                .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "accounts": {
+                "byName": {
+                    "members": [
+                        {
+                            "account": {
+                                "accountName": DEFAULT_ACCOUNT_NAME_STR,
+                            },
+                            "role": "OWNER",
+                        },
+                        {
+                            "account": {
+                                "accountName": "alice",
+                            },
+                            "role": "MAINTAINER",
+                        },
+                    ]
+                }
+            }
+        })
+    );
+
+    for expected_message in [
+        "Success".to_string(),
+        format!(
+            "Account '{}' is not a member of organization '{}'",
+            AccountID::new_seeded_ed25519(b"alice"),
+            AccountID::new_seeded_ed25519(ORGANIZATION_NAME.as_bytes()),
+        ),
+    ] {
+        let res = schema
+            .execute(
+                async_graphql::Request::new(OrganizationsHarness::remove_member_mutation("alice"))
+                    .data(harness.catalog_authorized.clone()),
+            )
+            .await;
+        assert!(res.is_ok(), "{res:?}");
+        assert_eq!(
+            res.data,
+            value!({
+                "accounts": {
+                    "organization": {
+                        "removeMember": {
+                            "message": expected_message,
+                        }
+                    }
+                }
+            })
+        );
+    }
+
+    // Only the changes that took effect are recorded
+    let mut recorded_actions = harness
+        .recorded_audit_events()
+        .await
+        .iter()
+        .map(|event| event.action.as_str())
+        .collect::<Vec<_>>();
+    recorded_actions.sort_unstable();
+    assert_eq!(
+        recorded_actions,
+        vec![
+            AuditAction::OrganizationMemberRemoved.as_str(),
+            AuditAction::OrganizationMemberRoleSet.as_str(),
+        ]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_last_owner_cannot_be_removed() {
+    let harness = OrganizationsHarness::new().await;
+    harness
+        .set_member_role(&DEFAULT_ACCOUNT_ID, AccountToOrganizationRelation::Owner)
+        .await;
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(OrganizationsHarness::remove_member_mutation(
+                DEFAULT_ACCOUNT_NAME_STR,
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_ok(), "{res:?}");
+    assert_eq!(
+        res.data,
+        value!({
+            "accounts": {
+                "organization": {
+                    "removeMember": {
+                        "message": format!(
+                            "Account '{}' is the last owner of organization '{}'",
+                            *DEFAULT_ACCOUNT_ID,
+                            AccountID::new_seeded_ed25519(ORGANIZATION_NAME.as_bytes()),
+                        ),
+                    }
+                }
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_non_owner_cannot_manage_members() {
+    let harness = OrganizationsHarness::new().await;
+    harness.create_account("alice", AccountType::User).await;
+    harness
+        .set_member_role(
+            &DEFAULT_ACCOUNT_ID,
+            AccountToOrganizationRelation::Maintainer,
+        )
+        .await;
+
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let res = schema
+        .execute(
+            async_graphql::Request::new(OrganizationsHarness::set_member_role_mutation(
+                "alice", "MEMBER",
+            ))
+            .data(harness.catalog_authorized.clone()),
+        )
+        .await;
+    assert!(res.is_err());
+    assert_eq!(
+        res.errors
+            .into_iter()
+            .map(|e| e.message)
+            .collect::<Vec<_>>(),
+        vec!["Account access error".to_string()]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct OrganizationsHarness {
+    catalog_authorized: dill::Catalog,
+}
+
+impl OrganizationsHarness {
+    async fn new() -> Self {
+        let catalog_base = {
+            let mut b = dill::CatalogBuilder::new();
+
+            b.add::<DummyOutboxImpl>()
+                .add::<SystemTimeSourceDefault>()
+                .add::<DatabaseTransactionRunner>()
+                .add::<AuthenticationServiceImpl>()
+                .add::<AccessTokenServiceImpl>()
+                .add::<AuditLogServiceImpl>()
+                .add::<InMemoryAuditEventRepository>()
+                .add::<InMemoryAccessTokenRepository>()
+                .add_value(JwtAuthenticationConfig::default())
+                .add::<InMemoryRebacRepository>()
+                .add::<RebacServiceImpl>()
+                .add::<OrganizationMembershipServiceImpl>();
+
+            NoOpDatabasePlugin::init_database_components(&mut b);
+
+            b.build()
+        };
+
+        let (_, catalog_authorized) = authentication_catalogs(&catalog_base).await;
+
+        let harness = Self { catalog_authorized };
+        harness
+            .create_account(ORGANIZATION_NAME, AccountType::Organization)
+            .await;
+        harness
+    }
+
+    async fn create_account(&self, name: &str, account_type: AccountType) {
+        let account_repo = self
+            .catalog_authorized
+            .get_one::<dyn AccountRepository>()
+            .unwrap();
+
+        let mut account = Account::test(AccountID::new_seeded_ed25519(name.as_bytes()), name);
+        account.account_type = account_type;
+
+        account_repo.create_account(&account).await.unwrap();
+    }
+
+    async fn set_member_role(&self, account_id: &AccountID, role: AccountToOrganizationRelation) {
+        let membership_service = self
+            .catalog_authorized
+            .get_one::<dyn OrganizationMembershipService>()
+            .unwrap();
+
+        membership_service
+            .set_member_role(
+                &AccountID::new_seeded_ed25519(ORGANIZATION_NAME.as_bytes()),
+                account_id,
+                role,
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn recorded_audit_events(&self) -> Vec<AuditEvent> {
+        let audit_log_service = self
+            .catalog_authorized
+            .get_one::<dyn AuditLogService>()
+            .unwrap();
+
+        audit_log_service
+            .list_events(
+                &AuditEventFilter::default(),
+                PaginationOpts {
+                    limit: 100,
+                    offset: 0,
+                },
+            )
+            .await
+            .unwrap()
+            .list
+    }
+
+    fn list_members_query() -> String {
+        indoc!(
+            r#"
+            {
+                accounts {
+                    byName (name: "<organization_name>") {
+                        members {
+                            account {
+                                accountName
+                            }
+                            role
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<organization_name>", ORGANIZATION_NAME)
+    }
+
+    fn set_member_role_mutation(account_name: &str, role: &str) -> String {
+        indoc!(
+            r#"
+            mutation {
+                accounts {
+                    organization (accountName: "<organization_name>") {
+                        setMemberRole (accountName: "<account_name>", role: <role>) {
+                            message
+                            ... on SetOrganizationMemberRoleResultSuccess {
+                                member {
+                                    account {
+                                        accountName
+                                    }
+                                    role
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<organization_name>", ORGANIZATION_NAME)
+        .replace("<account_name>", account_name)
+        .replace("<role>", role)
+    }
+
+    fn remove_member_mutation(account_name: &str) -> String {
+        indoc!(
+            r#"
+            mutation {
+                accounts {
+                    organization (accountName: "<organization_name>") {
+                        removeMember (accountName: "<account_name>") {
+                            message
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<organization_name>", ORGANIZATION_NAME)
+        .replace("<account_name>", account_name)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
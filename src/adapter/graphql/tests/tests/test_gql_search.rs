@@ -37,6 +37,10 @@ async fn test_search_query() {
         .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
         .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
         .add::<CreateDatasetFromSnapshotUseCaseImpl>()
+        .add_value(RemoteRepositoryRegistryNull)
+        .bind::<dyn RemoteRepositoryRegistry, RemoteRepositoryRegistryNull>()
+        .add::<SearchServiceImpl>()
+        .add::<SearchIndexInMemory>()
         .build();
 
     let create_dataset_from_snapshot = cat
@@ -148,6 +152,38 @@ async fn test_search_query() {
         })
     );
 
+    let res = schema
+        .execute(
+            async_graphql::Request::new(
+                "
+                {
+                    search {
+                      query(query: \"foo\", filters: { byKinds: [DERIVATIVE] }) {
+                        nodes {
+                          __typename
+                        }
+                        totalCount
+                      }
+                    }
+                  }
+                ",
+            )
+            .data(cat.clone()),
+        )
+        .await;
+    assert!(res.is_ok());
+    assert_eq!(
+        res.data,
+        value!({
+            "search": {
+                "query": {
+                    "nodes": [],
+                    "totalCount": 0i32,
+                }
+            }
+        })
+    );
+
     let res = schema
         .execute(
             async_graphql::Request::new(
@@ -0,0 +1,643 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use async_graphql::value;
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin};
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use indoc::indoc;
+use kamu::testing::{
+    MetadataFactory,
+    MockDatasetChangesService,
+    MockDependencyGraphRepository,
+    MockPollingIngestService,
+    MockTransformService,
+};
+use kamu::{
+    CreateDatasetFromSnapshotUseCaseImpl,
+    DatasetOwnershipServiceInMemory,
+    DatasetRepositoryLocalFs,
+    DatasetRepositoryWriter,
+    DependencyGraphServiceInMemory,
+};
+use kamu_accounts::{ConnectionAccessToken, JwtAuthenticationConfig, DEFAULT_ACCOUNT_NAME_STR};
+use kamu_accounts_inmem::InMemoryAccessTokenRepository;
+use kamu_accounts_services::{AccessTokenServiceImpl, AuthenticationServiceImpl};
+use kamu_adapter_graphql::SubscriptionEventHub;
+use kamu_audit_log::testing::DummyAuditLogService;
+use kamu_core::{
+    auth,
+    CreateDatasetFromSnapshotUseCase,
+    CreateDatasetResult,
+    DatasetChangesService,
+    DatasetLifecycleMessage,
+    DatasetReferenceMessage,
+    DatasetRepository,
+    DependencyGraphRepository,
+    PollingIngestService,
+    PullResult,
+    TransformService,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+};
+use kamu_flow_system::{
+    FlowConfigurationUpdatedMessage,
+    FlowExecutorConfig,
+    FlowExecutorTestDriver,
+    FlowID,
+    FlowProgressMessage,
+    METADATA_TASK_FLOW_ID,
+};
+use kamu_flow_system_inmem::{InMemoryFlowConfigurationEventStore, InMemoryFlowEventStore};
+use kamu_flow_system_services::{
+    MESSAGE_PRODUCER_KAMU_FLOW_CONFIGURATION_SERVICE,
+    MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+};
+use kamu_task_system::{self as ts, TaskMetadata};
+use kamu_task_system_inmem::InMemoryTaskEventStore;
+use kamu_task_system_services::TaskSchedulerImpl;
+use messaging_outbox::{register_message_dispatcher, Outbox, OutboxExt, OutboxImmediateImpl};
+use opendatafabric::{DatasetID, DatasetKind, Multihash};
+use time_source::SystemTimeSourceDefault;
+
+use crate::utils::authentication_catalogs;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_dataset_flow_and_task_progress() {
+    let harness = SubscriptionsHarness::new().await;
+    let create_result = harness.create_root_dataset().await;
+    let dataset_id = create_result.dataset_handle.id;
+
+    let flow_task_id = harness.trigger_and_schedule_ingest_flow(&dataset_id).await;
+    let flow_task_metadata = TaskMetadata::from(vec![(METADATA_TASK_FLOW_ID, "0")]);
+
+    let mut flow_updates = harness
+        .subscribe(SubscriptionsHarness::dataset_flow_progress_subscription(
+            &dataset_id,
+        ))
+        .await;
+    let mut task_updates = harness
+        .subscribe(SubscriptionsHarness::dataset_task_progress_subscription(
+            &dataset_id,
+        ))
+        .await;
+
+    let running_time = Utc::now().duration_round(Duration::seconds(1)).unwrap();
+    harness
+        .mimic_task_running(flow_task_id, flow_task_metadata, running_time)
+        .await;
+
+    let response = next_response(&mut flow_updates).await;
+    assert!(response.is_ok(), "{response:?}");
+    assert_eq!(
+        response.data,
+        value!({
+            "datasetFlowProgress": {
+                "flowId": "0",
+                "datasetId": dataset_id.to_string(),
+                "datasetFlowType": "INGEST",
+                "status": "RUNNING",
+                "outcome": null,
+                "eventTime": running_time.to_rfc3339(),
+            }
+        })
+    );
+
+    let response = next_response(&mut task_updates).await;
+    assert!(response.is_ok(), "{response:?}");
+    assert_eq!(
+        response.data,
+        value!({
+            "datasetTaskProgress": {
+                "taskId": "0",
+                "flowId": "0",
+                "status": "RUNNING",
+                "outcome": null,
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_account_flow_progress() {
+    let harness = SubscriptionsHarness::new().await;
+    let create_result = harness.create_root_dataset().await;
+    let dataset_id = create_result.dataset_handle.id;
+
+    let flow_task_id = harness.trigger_and_schedule_ingest_flow(&dataset_id).await;
+    let flow_task_metadata = TaskMetadata::from(vec![(METADATA_TASK_FLOW_ID, "0")]);
+
+    let running_time = Utc::now().duration_round(Duration::seconds(1)).unwrap();
+    harness
+        .mimic_task_running(flow_task_id, flow_task_metadata.clone(), running_time)
+        .await;
+
+    let mut flow_updates = harness
+        .subscribe(SubscriptionsHarness::account_flow_progress_subscription(
+            DEFAULT_ACCOUNT_NAME_STR,
+        ))
+        .await;
+
+    let complete_time = Utc::now().duration_round(Duration::seconds(1)).unwrap();
+    harness
+        .mimic_task_completed(
+            flow_task_id,
+            flow_task_metadata,
+            complete_time,
+            ts::TaskOutcome::Success(ts::TaskResult::UpdateDatasetResult(
+                ts::TaskUpdateDatasetResult {
+                    pull_result: PullResult::Updated {
+                        old_head: Some(Multihash::from_digest_sha3_256(b"old-slice")),
+                        new_head: Multihash::from_digest_sha3_256(b"new-slice"),
+                    },
+                },
+            )),
+        )
+        .await;
+
+    let response = next_response(&mut flow_updates).await;
+    assert!(response.is_ok(), "{response:?}");
+    assert_eq!(
+        response.data,
+        value!({
+            "accountFlowProgress": {
+                "flowId": "0",
+                "datasetId": dataset_id.to_string(),
+                "datasetFlowType": "INGEST",
+                "status": "FINISHED",
+                "outcome": "SUCCESS",
+                "eventTime": complete_time.to_rfc3339(),
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_dataset_head_updates() {
+    let harness = SubscriptionsHarness::new().await;
+    let create_result = harness.create_root_dataset().await;
+    let dataset_id = create_result.dataset_handle.id;
+
+    let mut head_updates = harness
+        .subscribe(SubscriptionsHarness::dataset_head_updates_subscription(
+            &dataset_id,
+        ))
+        .await;
+
+    let old_head = create_result.head;
+    let new_head = Multihash::from_digest_sha3_256(b"new-slice");
+
+    // Moved by anything, not necessarily a flow
+    harness
+        .mimic_dataset_head_updated(&dataset_id, Some(old_head.clone()), new_head.clone())
+        .await;
+
+    let response = next_response(&mut head_updates).await;
+    assert!(response.is_ok(), "{response:?}");
+    assert_eq!(
+        response.data,
+        value!({
+            "datasetHeadUpdates": {
+                "datasetId": dataset_id.to_string(),
+                "oldHead": old_head.to_string(),
+                "newHead": new_head.to_string(),
+            }
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_subscription_closed_once_token_is_no_longer_valid() {
+    let harness = SubscriptionsHarness::new().await;
+    let create_result = harness.create_root_dataset().await;
+    let dataset_id = create_result.dataset_handle.id;
+
+    let catalog_expired_token = dill::CatalogBuilder::new_chained(&harness.catalog_authorized)
+        .add_value(ConnectionAccessToken::new("expired-or-revoked-token"))
+        .build();
+
+    let mut head_updates = harness
+        .subscribe_with_catalog(
+            SubscriptionsHarness::dataset_head_updates_subscription(&dataset_id),
+            catalog_expired_token,
+        )
+        .await;
+
+    harness
+        .mimic_dataset_head_updated(
+            &dataset_id,
+            Some(create_result.head),
+            Multihash::from_digest_sha3_256(b"new-slice"),
+        )
+        .await;
+
+    let maybe_response =
+        tokio::time::timeout(std::time::Duration::from_secs(5), head_updates.next())
+            .await
+            .expect("Subscription did not end in time");
+    assert!(maybe_response.is_none(), "{maybe_response:?}");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_subscription_access_errors() {
+    let harness = SubscriptionsHarness::new().await;
+    let schema = kamu_adapter_graphql::schema_quiet();
+
+    let mut updates = schema.execute_stream(
+        async_graphql::Request::new(SubscriptionsHarness::account_flow_progress_subscription(
+            "another",
+        ))
+        .data(harness.catalog_authorized.clone()),
+    );
+    let response = next_response(&mut updates).await;
+    assert!(response.is_err());
+    assert_eq!(response.errors[0].message, "Account access error");
+
+    let mut updates = schema.execute_stream(
+        async_graphql::Request::new(SubscriptionsHarness::account_flow_progress_subscription(
+            DEFAULT_ACCOUNT_NAME_STR,
+        ))
+        .data(harness.catalog_anonymous.clone()),
+    );
+    let response = next_response(&mut updates).await;
+    assert!(response.is_err());
+    assert_eq!(response.errors[0].message, "Account access error");
+
+    let mut updates = schema.execute_stream(
+        async_graphql::Request::new(SubscriptionsHarness::dataset_flow_progress_subscription(
+            &DatasetID::new_seeded_ed25519(b"unknown"),
+        ))
+        .data(harness.catalog_authorized.clone()),
+    );
+    let response = next_response(&mut updates).await;
+    assert!(response.is_err());
+    assert_eq!(response.errors[0].message, "Dataset not found");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn next_response(
+    updates: &mut BoxStream<'static, async_graphql::Response>,
+) -> async_graphql::Response {
+    tokio::time::timeout(std::time::Duration::from_secs(5), updates.next())
+        .await
+        .expect("Subscription response timed out")
+        .expect("Subscription stream ended unexpectedly")
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct SubscriptionsHarness {
+    _tempdir: tempfile::TempDir,
+    catalog_anonymous: dill::Catalog,
+    catalog_authorized: dill::Catalog,
+}
+
+impl SubscriptionsHarness {
+    async fn new() -> Self {
+        let tempdir = tempfile::tempdir().unwrap();
+        let datasets_dir = tempdir.path().join("datasets");
+        std::fs::create_dir(&datasets_dir).unwrap();
+
+        let catalog_base = {
+            let mut b = dill::CatalogBuilder::new();
+
+            b.add_builder(
+                messaging_outbox::OutboxImmediateImpl::builder()
+                    .with_consumer_filter(messaging_outbox::ConsumerFilter::AllConsumers),
+            )
+            .bind::<dyn Outbox, OutboxImmediateImpl>()
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .add::<CreateDatasetFromSnapshotUseCaseImpl>()
+            .add_value(MockDatasetChangesService::new())
+            .bind::<dyn DatasetChangesService, MockDatasetChangesService>()
+            .add::<SystemTimeSourceDefault>()
+            .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+            .add::<DependencyGraphServiceInMemory>()
+            .add_value(MockDependencyGraphRepository::no_dependencies())
+            .bind::<dyn DependencyGraphRepository, MockDependencyGraphRepository>()
+            .add::<InMemoryFlowConfigurationEventStore>()
+            .add::<InMemoryFlowEventStore>()
+            .add_value(FlowExecutorConfig::new(
+                Duration::seconds(1),
+                Duration::minutes(1),
+            ))
+            .add::<TaskSchedulerImpl>()
+            .add::<InMemoryTaskEventStore>()
+            .add_value(MockTransformService::new())
+            .bind::<dyn TransformService, MockTransformService>()
+            .add_value(MockPollingIngestService::with_active_polling_source())
+            .bind::<dyn PollingIngestService, MockPollingIngestService>()
+            .add::<AuthenticationServiceImpl>()
+            .add::<AccessTokenServiceImpl>()
+            .add::<DummyAuditLogService>()
+            .add::<InMemoryAccessTokenRepository>()
+            .add_value(JwtAuthenticationConfig::default())
+            .add::<DatasetOwnershipServiceInMemory>()
+            .add::<DatabaseTransactionRunner>()
+            .add::<SubscriptionEventHub>();
+
+            NoOpDatabasePlugin::init_database_components(&mut b);
+            kamu_flow_system_services::register_dependencies(&mut b);
+
+            register_message_dispatcher::<DatasetLifecycleMessage>(
+                &mut b,
+                MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+            );
+            register_message_dispatcher::<DatasetReferenceMessage>(
+                &mut b,
+                MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+            );
+            register_message_dispatcher::<ts::TaskProgressMessage>(
+                &mut b,
+                ts::MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
+            );
+            register_message_dispatcher::<FlowConfigurationUpdatedMessage>(
+                &mut b,
+                MESSAGE_PRODUCER_KAMU_FLOW_CONFIGURATION_SERVICE,
+            );
+            register_message_dispatcher::<FlowProgressMessage>(
+                &mut b,
+                MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+            );
+
+            b.build()
+        };
+
+        let (catalog_anonymous, catalog_authorized) = authentication_catalogs(&catalog_base).await;
+
+        Self {
+            _tempdir: tempdir,
+            catalog_anonymous,
+            catalog_authorized,
+        }
+    }
+
+    async fn create_root_dataset(&self) -> CreateDatasetResult {
+        let create_dataset_from_snapshot = self
+            .catalog_authorized
+            .get_one::<dyn CreateDatasetFromSnapshotUseCase>()
+            .unwrap();
+
+        create_dataset_from_snapshot
+            .execute(
+                MetadataFactory::dataset_snapshot()
+                    .kind(DatasetKind::Root)
+                    .name("foo")
+                    .push_event(MetadataFactory::set_polling_source().build())
+                    .build(),
+                Default::default(),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn trigger_and_schedule_ingest_flow(&self, dataset_id: &DatasetID) -> ts::TaskID {
+        let schema = kamu_adapter_graphql::schema_quiet();
+        let response = schema
+            .execute(
+                async_graphql::Request::new(Self::trigger_ingest_flow_mutation(dataset_id))
+                    .data(self.catalog_authorized.clone()),
+            )
+            .await;
+        assert!(response.is_ok(), "{response:?}");
+
+        let schedule_time = Utc::now().duration_round(Duration::seconds(1)).unwrap();
+        self.mimic_flow_scheduled(FlowID::new(0), schedule_time)
+            .await
+    }
+
+    async fn mimic_flow_scheduled(
+        &self,
+        flow_id: FlowID,
+        schedule_time: DateTime<Utc>,
+    ) -> ts::TaskID {
+        let flow_service_test_driver = self
+            .catalog_authorized
+            .get_one::<dyn FlowExecutorTestDriver>()
+            .unwrap();
+
+        flow_service_test_driver
+            .mimic_flow_scheduled(&self.catalog_authorized, flow_id, schedule_time)
+            .await
+            .unwrap()
+    }
+
+    async fn mimic_task_running(
+        &self,
+        task_id: ts::TaskID,
+        task_metadata: ts::TaskMetadata,
+        event_time: DateTime<Utc>,
+    ) {
+        let task_event_store = self
+            .catalog_anonymous
+            .get_one::<dyn ts::TaskEventStore>()
+            .unwrap();
+
+        let mut task = ts::Task::load(task_id, task_event_store.as_ref())
+            .await
+            .unwrap();
+        task.run(event_time).unwrap();
+        task.save(task_event_store.as_ref()).await.unwrap();
+
+        let outbox = self.catalog_authorized.get_one::<dyn Outbox>().unwrap();
+        outbox
+            .post_message(
+                ts::MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
+                ts::TaskProgressMessage::running(event_time, task_id, task_metadata),
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn mimic_task_completed(
+        &self,
+        task_id: ts::TaskID,
+        task_metadata: ts::TaskMetadata,
+        event_time: DateTime<Utc>,
+        task_outcome: ts::TaskOutcome,
+    ) {
+        let task_event_store = self
+            .catalog_anonymous
+            .get_one::<dyn ts::TaskEventStore>()
+            .unwrap();
+
+        let mut task = ts::Task::load(task_id, task_event_store.as_ref())
+            .await
+            .unwrap();
+        task.finish(event_time, task_outcome.clone()).unwrap();
+        task.save(task_event_store.as_ref()).await.unwrap();
+
+        let outbox = self.catalog_authorized.get_one::<dyn Outbox>().unwrap();
+        outbox
+            .post_message(
+                ts::MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR,
+                ts::TaskProgressMessage::finished(event_time, task_id, task_metadata, task_outcome),
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn mimic_dataset_head_updated(
+        &self,
+        dataset_id: &DatasetID,
+        old_head: Option<Multihash>,
+        new_head: Multihash,
+    ) {
+        let outbox = self.catalog_authorized.get_one::<dyn Outbox>().unwrap();
+        outbox
+            .post_message(
+                MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                DatasetReferenceMessage::updated(dataset_id.clone(), old_head, new_head),
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn subscribe(
+        &self,
+        subscription_code: String,
+    ) -> BoxStream<'static, async_graphql::Response> {
+        self.subscribe_with_catalog(subscription_code, self.catalog_authorized.clone())
+            .await
+    }
+
+    /// Opens a subscription and drives it until it starts listening to events,
+    /// so that nothing published afterwards is missed
+    async fn subscribe_with_catalog(
+        &self,
+        subscription_code: String,
+        catalog: dill::Catalog,
+    ) -> BoxStream<'static, async_graphql::Response> {
+        let event_hub = self
+            .catalog_authorized
+            .get_one::<SubscriptionEventHub>()
+            .unwrap();
+        let initial_receiver_count = event_hub.receiver_count();
+
+        let schema = kamu_adapter_graphql::schema_quiet();
+        let mut updates =
+            schema.execute_stream(async_graphql::Request::new(subscription_code).data(catalog));
+
+        while event_hub.receiver_count() == initial_receiver_count {
+            let poll_result = futures::poll!(updates.next());
+            assert!(poll_result.is_pending(), "{poll_result:?}");
+            tokio::task::yield_now().await;
+        }
+
+        updates
+    }
+
+    fn trigger_ingest_flow_mutation(id: &DatasetID) -> String {
+        indoc!(
+            r#"
+            mutation {
+                datasets {
+                    byId (datasetId: "<id>") {
+                        flows {
+                            runs {
+                                triggerFlow (datasetFlowType: "INGEST") {
+                                    __typename
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            "#
+        )
+        .replace("<id>", &id.to_string())
+    }
+
+    fn dataset_flow_progress_subscription(id: &DatasetID) -> String {
+        indoc!(
+            r#"
+            subscription {
+                datasetFlowProgress (datasetId: "<id>") {
+                    flowId
+                    datasetId
+                    datasetFlowType
+                    status
+                    outcome
+                    eventTime
+                }
+            }
+            "#
+        )
+        .replace("<id>", &id.to_string())
+    }
+
+    fn account_flow_progress_subscription(account_name: &str) -> String {
+        indoc!(
+            r#"
+            subscription {
+                accountFlowProgress (accountName: "<account_name>") {
+                    flowId
+                    datasetId
+                    datasetFlowType
+                    status
+                    outcome
+                    eventTime
+                }
+            }
+            "#
+        )
+        .replace("<account_name>", account_name)
+    }
+
+    fn dataset_task_progress_subscription(id: &DatasetID) -> String {
+        indoc!(
+            r#"
+            subscription {
+                datasetTaskProgress (datasetId: "<id>") {
+                    taskId
+                    flowId
+                    status
+                    outcome
+                }
+            }
+            "#
+        )
+        .replace("<id>", &id.to_string())
+    }
+
+    fn dataset_head_updates_subscription(id: &DatasetID) -> String {
+        indoc!(
+            r#"
+            subscription {
+                datasetHeadUpdates (datasetId: "<id>") {
+                    datasetId
+                    oldHead
+                    newHead
+                }
+            }
+            "#
+        )
+        .replace("<id>", &id.to_string())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
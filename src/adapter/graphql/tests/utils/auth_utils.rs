@@ -7,10 +7,22 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use dill::{component, interface};
 use kamu_accounts::*;
 use kamu_accounts_inmem::InMemoryAccountRepository;
 use kamu_accounts_services::{LoginPasswordAuthProvider, PredefinedAccountsRegistrator};
 use kamu_adapter_graphql::ANONYMOUS_ACCESS_FORBIDDEN_MESSAGE;
+use kamu_core::auth::{
+    DatasetAction,
+    DatasetActionAuthorizer,
+    DatasetActionNotEnoughPermissionsError,
+    DatasetActionUnauthorizedError,
+};
+use kamu_core::AccessError;
+use opendatafabric::DatasetHandle;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -66,3 +78,85 @@ pub fn expect_anonymous_access_error(response: async_graphql::Response) {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Grants every action to the account itself, but honors the scopes of its
+/// access token the same way the production authorizer does
+pub struct TokenScopedDatasetActionAuthorizer {
+    current_account_subject: Arc<CurrentAccountSubject>,
+}
+
+#[component(pub)]
+#[interface(dyn DatasetActionAuthorizer)]
+impl TokenScopedDatasetActionAuthorizer {
+    pub fn new(current_account_subject: Arc<CurrentAccountSubject>) -> Self {
+        Self {
+            current_account_subject,
+        }
+    }
+
+    fn is_allowed_by_token_scopes(
+        &self,
+        dataset_handle: &DatasetHandle,
+        action: DatasetAction,
+    ) -> bool {
+        match self.current_account_subject.as_ref() {
+            CurrentAccountSubject::Anonymous(_) => true,
+            CurrentAccountSubject::Logged(l) => match action {
+                DatasetAction::Read => l.token_scopes.allows_read(&dataset_handle.id),
+                DatasetAction::Write
+                | DatasetAction::Maintain
+                | DatasetAction::ManagePermissions
+                | DatasetAction::Delete
+                | DatasetAction::Rename => l.token_scopes.allows_write(&dataset_handle.id),
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DatasetActionAuthorizer for TokenScopedDatasetActionAuthorizer {
+    async fn check_action_allowed(
+        &self,
+        dataset_handle: &DatasetHandle,
+        action: DatasetAction,
+    ) -> Result<(), DatasetActionUnauthorizedError> {
+        if self.is_allowed_by_token_scopes(dataset_handle, action) {
+            Ok(())
+        } else {
+            Err(DatasetActionUnauthorizedError::Access(
+                AccessError::Forbidden(
+                    DatasetActionNotEnoughPermissionsError {
+                        action,
+                        dataset_ref: dataset_handle.as_local_ref(),
+                    }
+                    .into(),
+                ),
+            ))
+        }
+    }
+
+    async fn get_allowed_actions(&self, dataset_handle: &DatasetHandle) -> HashSet<DatasetAction> {
+        DatasetAction::ALL
+            .into_iter()
+            .filter(|action| self.is_allowed_by_token_scopes(dataset_handle, *action))
+            .collect()
+    }
+}
+
+/// Chains a catalog acting on behalf of the test account with a token
+/// restricted to the given scopes
+pub fn token_scoped_catalog(
+    catalog_authorized: &dill::Catalog,
+    token_scopes: Vec<AccessTokenScope>,
+) -> dill::Catalog {
+    dill::CatalogBuilder::new_chained(catalog_authorized)
+        .add_value(CurrentAccountSubject::logged_with_token_scopes(
+            DEFAULT_ACCOUNT_ID.clone(),
+            DEFAULT_ACCOUNT_NAME.clone(),
+            false,
+            AccessTokenScopes::Restricted(token_scopes),
+        ))
+        .build()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
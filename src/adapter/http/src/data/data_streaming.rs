@@ -0,0 +1,156 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::{Arc, Mutex};
+
+use axum::response::{IntoResponse, Response};
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::arrow::ipc::writer::StreamWriter;
+use datafusion::dataframe::DataFrame;
+use datafusion::parquet::arrow::ArrowWriter;
+use datafusion::parquet::file::properties::WriterProperties;
+use futures::TryStreamExt;
+use internal_error::*;
+use kamu_data_utils::data::format::{CsvWriter, CsvWriterOptions, RecordsWriter};
+
+use super::query_types::DataFormat;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Maximum number of rows the Parquet writer buffers before writing out a row
+/// group, kept small to bound the memory used per response
+const PARQUET_MAX_ROW_GROUP_SIZE: usize = 8 * 1024;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Executes the query and streams the results in the specified format
+/// batch-by-batch without materializing the entire result in memory.
+///
+/// The physical plan is created before the response is returned, so the
+/// datasets are resolved and their data files are listed within the caller's
+/// transaction, while the returned body only reads the data files.
+pub(crate) async fn data_stream_response(
+    df: DataFrame,
+    format: DataFormat,
+) -> Result<Response, InternalError> {
+    assert!(format.is_streamed());
+
+    let schema = df.schema().inner().clone();
+    let record_batches = df.execute_stream().await.int_err()?;
+
+    let buf = SharedBuffer::default();
+    let writer = DataStreamWriter::new(format, schema, buf.clone())?;
+
+    let chunks = futures::stream::try_unfold(
+        (record_batches, Some(writer)),
+        move |(mut record_batches, mut writer)| {
+            let buf = buf.clone();
+            async move {
+                let Some(w) = writer.as_mut() else {
+                    return Ok::<_, InternalError>(None);
+                };
+
+                match record_batches.try_next().await.int_err()? {
+                    Some(batch) => w.write(&batch)?,
+                    None => writer.take().unwrap().finish()?,
+                }
+
+                Ok(Some((buf.take(), (record_batches, writer))))
+            }
+        },
+    )
+    .try_filter(|chunk| futures::future::ready(!chunk.is_empty()));
+
+    Ok((
+        [(http::header::CONTENT_TYPE, format.media_type())],
+        axum::body::Body::from_stream(chunks),
+    )
+        .into_response())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+enum DataStreamWriter {
+    Csv(CsvWriter<SharedBuffer>),
+    ArrowIpc(StreamWriter<SharedBuffer>),
+    Parquet(ArrowWriter<SharedBuffer>),
+}
+
+impl DataStreamWriter {
+    fn new(
+        format: DataFormat,
+        schema: SchemaRef,
+        buf: SharedBuffer,
+    ) -> Result<Self, InternalError> {
+        match format {
+            DataFormat::Csv => Ok(Self::Csv(CsvWriter::new(buf, CsvWriterOptions::default()))),
+            DataFormat::ArrowIpc => Ok(Self::ArrowIpc(
+                StreamWriter::try_new(buf, &schema).int_err()?,
+            )),
+            DataFormat::Parquet => {
+                let props = WriterProperties::builder()
+                    .set_max_row_group_size(PARQUET_MAX_ROW_GROUP_SIZE)
+                    .build();
+                Ok(Self::Parquet(
+                    ArrowWriter::try_new(buf, schema, Some(props)).int_err()?,
+                ))
+            }
+            DataFormat::JsonAoS | DataFormat::JsonSoA | DataFormat::JsonAoA => unreachable!(),
+        }
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> Result<(), InternalError> {
+        match self {
+            Self::Csv(w) => w.write_batch(batch).int_err(),
+            Self::ArrowIpc(w) => w.write(batch).int_err(),
+            // Full row groups are written out by the writer itself, the remainder of
+            // the batch is flushed as a separate row group so that no rows are kept
+            // in memory between the chunks of the response
+            Self::Parquet(w) => {
+                w.write(batch).int_err()?;
+                w.flush().int_err()
+            }
+        }
+    }
+
+    fn finish(self) -> Result<(), InternalError> {
+        match self {
+            Self::Csv(mut w) => w.finish().int_err(),
+            Self::ArrowIpc(mut w) => w.finish().int_err(),
+            Self::Parquet(w) => w.close().map(|_| ()).int_err(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Buffer shared between a writer and the response stream that drains it after
+/// every batch
+#[derive(Default, Clone)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    fn take(&self) -> bytes::Bytes {
+        std::mem::take(&mut *self.0.lock().unwrap()).into()
+    }
+}
+
+impl std::io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -118,6 +118,7 @@ pub async fn dataset_ingest_handler(
                 source_event_time,
                 auto_create_push_source: is_ingest_from_upload,
                 schema_inference: SchemaInferenceOpts::default(),
+                schema_evolution: SchemaEvolutionRules::default(),
             },
             None,
         )
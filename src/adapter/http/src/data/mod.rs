@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod data_streaming;
 mod ingest_handler;
 pub mod metadata_handler;
 mod query_handler;
@@ -17,14 +17,17 @@
 // by the Apache License, Version 2.0.
 
 use axum::extract::{Extension, Query};
-use axum::response::Json;
+use axum::response::{IntoResponse, Json, Response};
 use database_common_macros::transactional_handler;
+use datafusion::prelude::DataFrame;
 use dill::Catalog;
+use http::HeaderMap;
 use http_common::*;
 use internal_error::*;
 use kamu_core::*;
 use opendatafabric as odf;
 
+use super::data_streaming::data_stream_response;
 use super::query_types::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -32,16 +35,121 @@ use super::query_types::*;
 #[transactional_handler]
 pub async fn query_handler_post(
     Extension(catalog): Extension<Catalog>,
-    Json(body): Json<RequestBody>,
-) -> Result<Json<ResponseBody>, ApiError> {
+    headers: HeaderMap,
+    Json(mut body): Json<RequestBody>,
+) -> Result<Response, ApiError> {
+    // Streamed formats requested via `Accept` header take precedence
+    if let Some(data_format) = DataFormat::from_accept_header(&headers) {
+        body.set_data_format(data_format);
+    }
+
+    if body.data_format().is_streamed() {
+        return query_handler_post_streamed(catalog, body).await;
+    }
+
     match body {
-        RequestBody::V1(body) => query_handler_post_v1(catalog, body).await,
-        RequestBody::V2(body) => query_handler_post_v2(catalog, body).await,
+        RequestBody::V1(body) => query_handler_post_v1(catalog, body)
+            .await
+            .map(IntoResponse::into_response),
+        RequestBody::V2(body) => query_handler_post_v2(catalog, body)
+            .await
+            .map(IntoResponse::into_response),
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Header carrying the continuation token of streamed V2 responses, as the
+/// body has no room for it. The number of records is not known before the
+/// body is written, so the token is issued whenever the page is limited and
+/// the next page may turn out empty.
+pub const NEXT_CURSOR_HEADER: &str = "x-kamu-next-cursor";
+
+/// Streams the query results in the requested format.
+///
+/// The handler transaction is committed as soon as the response is returned,
+/// before the body is polled. Everything that touches the catalog - resolving
+/// the datasets, checking the access and listing their data files - happens
+/// while planning the query within the transaction, and only reading the data
+/// files of the already pinned dataset states continues after the commit.
+#[tracing::instrument(level = "info", skip_all)]
+async fn query_handler_post_streamed(
+    catalog: Catalog,
+    body: RequestBody,
+) -> Result<Response, ApiError> {
+    tracing::debug!(request = ?body, "Query (streamed)");
+
+    let (query, options, skip, limit, data_format, is_v2, has_cursor) = match &body {
+        RequestBody::V1(body) => (
+            &body.query,
+            body.to_options(),
+            body.skip,
+            body.limit,
+            body.data_format,
+            false,
+            false,
+        ),
+        RequestBody::V2(body) => {
+            // Proof commits to the output which is not available until the
+            // entire stream is written
+            if body.include.contains(&Include::Proof) {
+                return Err(ApiError::bad_request(ProofNotSupportedForDataFormat(
+                    body.data_format,
+                )));
+            }
+            let (options, skip) = body.to_options_and_skip().map_err(ApiError::bad_request)?;
+            (
+                &body.query,
+                options,
+                skip,
+                body.limit,
+                body.data_format,
+                true,
+                body.cursor.is_some(),
+            )
+        }
+    };
+
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+
+    let res = query_svc
+        .sql_statement(query, options)
+        .await
+        .map_err(map_query_error)?;
+
+    let (df, is_ordered) = if is_v2 {
+        stable_order(query, res.df, &res.state, has_cursor)?
+    } else {
+        (res.df, false)
+    };
+
+    // Apply pagination limits
+    let df = df
+        .limit(
+            usize::try_from(skip).unwrap(),
+            Some(usize::try_from(limit).unwrap()),
+        )
+        .int_err()
+        .api_err()?;
+
+    // Physical planning happens here, i.e. still within the transaction
+    let mut response = data_stream_response(df, data_format).await.api_err()?;
+
+    if is_ordered && limit != 0 {
+        let next_cursor = QueryCursor::new(query, res.state, skip + limit).encode();
+        response.headers_mut().insert(
+            http::HeaderName::from_static(NEXT_CURSOR_HEADER),
+            http::HeaderValue::from_str(&next_cursor)
+                .int_err()
+                .api_err()?,
+        );
+    }
+
+    Ok(response)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[tracing::instrument(level = "info", skip_all)]
 pub(crate) async fn query_handler_post_v2(
     catalog: Catalog,
@@ -49,6 +157,12 @@ pub(crate) async fn query_handler_post_v2(
 ) -> Result<Json<ResponseBody>, ApiError> {
     tracing::debug!(request = ?body, "Query");
 
+    if body.data_format.is_streamed() {
+        return Err(ApiError::bad_request(StreamedDataFormatNotSupported(
+            body.data_format,
+        )));
+    }
+
     // Automatically add `Input` if proof is requested, as proof depends on input
     // for verifiability
     if body.include.contains(&Include::Proof) {
@@ -59,19 +173,22 @@ pub(crate) async fn query_handler_post_v2(
         body.include.insert(Include::Schema);
     }
 
+    let (options, skip) = body.to_options_and_skip().map_err(ApiError::bad_request)?;
+
     let identity = catalog.get_one::<IdentityConfig>().ok();
     let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
 
     let res = query_svc
-        .sql_statement(&body.query, body.to_options())
+        .sql_statement(&body.query, options)
         .await
         .map_err(map_query_error)?;
 
+    let (df, is_ordered) = stable_order(&body.query, res.df, &res.state, body.cursor.is_some())?;
+
     // Apply pagination limits
-    let df = res
-        .df
+    let df = df
         .limit(
-            usize::try_from(body.skip).unwrap(),
+            usize::try_from(skip).unwrap(),
             Some(usize::try_from(body.limit).unwrap()),
         )
         .int_err()
@@ -90,6 +207,13 @@ pub(crate) async fn query_handler_post_v2(
     let record_batches = df.collect().await.int_err().api_err()?;
     let json = serialize_data(&record_batches, body.data_format).api_err()?;
 
+    let num_records: u64 = record_batches.iter().map(|b| b.num_rows() as u64).sum();
+    let next_cursor = if is_ordered && body.limit != 0 && num_records == body.limit {
+        Some(QueryCursor::new(&body.query, res.state.clone(), skip + num_records).encode())
+    } else {
+        None
+    };
+
     // TODO: PERF: Avoid re-serializing data
     let data = serde_json::from_str(&json).unwrap();
 
@@ -106,13 +230,21 @@ pub(crate) async fn query_handler_post_v2(
         if !body.include.contains(&Include::Input) && !body.include.contains(&Include::Proof) {
             None
         } else {
+            // Cursor is replaced with the equivalent explicit state and offset
+            // to keep the input reproducible on its own
             body.datasets = Some(RequestBodyV2::query_state_to_datasets(res.state));
+            body.skip = skip;
+            body.cursor = None;
             body.schema_format = schema_format;
             Some(body)
         };
 
     let response = if !include_proof {
-        ResponseBody::V2(ResponseBodyV2 { input, output })
+        ResponseBody::V2(ResponseBodyV2 {
+            input,
+            output,
+            next_cursor,
+        })
     } else if let Some(identity) = identity {
         use ed25519_dalek::Signer;
 
@@ -141,6 +273,7 @@ pub(crate) async fn query_handler_post_v2(
                 verification_method: identity.did(),
                 proof_value: signature.into(),
             },
+            next_cursor,
         })
     } else {
         Err(ApiError::not_implemented(ResponseSigningNotConfigured))?
@@ -149,6 +282,26 @@ pub(crate) async fn query_handler_post_v2(
     Ok(Json(response))
 }
 
+/// Makes the order of the results stable, as cursors address the pages by the
+/// number of the preceding records. Cursors are not issued for the queries
+/// whose order can't be guaranteed, so they are rejected for such queries too.
+fn stable_order(
+    query: &str,
+    df: DataFrame,
+    state: &QueryState,
+    has_cursor: bool,
+) -> Result<(DataFrame, bool), ApiError> {
+    let (df, is_ordered) = kamu::with_stable_order(query, df, state).map_err(map_query_error)?;
+
+    if has_cursor && !is_ordered {
+        return Err(ApiError::bad_request(InvalidQueryCursorError {
+            reason: "cursor requires the query to specify the order of the results".to_string(),
+        }));
+    }
+
+    Ok((df, is_ordered))
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[tracing::instrument(level = "info", skip_all)]
@@ -214,9 +367,10 @@ async fn query_handler_post_v1(
 
 pub async fn query_handler(
     catalog: Extension<Catalog>,
+    headers: HeaderMap,
     Query(params): Query<RequestParams>,
-) -> Result<Json<ResponseBody>, ApiError> {
-    query_handler_post(catalog, Json(params.into())).await
+) -> Result<Response, ApiError> {
+    query_handler_post(catalog, headers, Json(params.into())).await
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -224,3 +378,7 @@ pub async fn query_handler(
 #[derive(Debug, thiserror::Error)]
 #[error("Response signing is not enabled by the node operator")]
 struct ResponseSigningNotConfigured;
+
+#[derive(Debug, thiserror::Error)]
+#[error("Proofs are not supported for {0:?} data format")]
+struct ProofNotSupportedForDataFormat(DataFormat);
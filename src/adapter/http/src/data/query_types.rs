@@ -85,6 +85,22 @@ pub enum RequestBody {
     V2(RequestBodyV2),
 }
 
+impl RequestBody {
+    pub fn data_format(&self) -> DataFormat {
+        match self {
+            Self::V1(body) => body.data_format,
+            Self::V2(body) => body.data_format,
+        }
+    }
+
+    pub fn set_data_format(&mut self, data_format: DataFormat) {
+        match self {
+            Self::V1(body) => body.data_format = data_format,
+            Self::V2(body) => body.data_format = data_format,
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // TODO: Sanity limits
@@ -122,6 +138,12 @@ pub struct RequestBodyV2 {
     /// Pagination: limits number of records in response to N
     #[serde(default = "RequestBodyV2::default_limit")]
     pub limit: u64,
+
+    /// Pagination: continuation token returned by the previous page. Resumes
+    /// the query from where the previous page ended using the same state of
+    /// the input datasets. Can't be combined with `datasets` and `skip`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
 impl RequestBodyV2 {
@@ -159,6 +181,27 @@ impl RequestBodyV2 {
         }
     }
 
+    /// Returns query options and the number of records to skip, taking the
+    /// continuation cursor into account if one was provided
+    pub fn to_options_and_skip(
+        &self,
+    ) -> Result<(domain::QueryOptions, u64), domain::InvalidQueryCursorError> {
+        let Some(cursor) = &self.cursor else {
+            return Ok((self.to_options(), self.skip));
+        };
+
+        if self.datasets.is_some() || self.skip != 0 {
+            return Err(domain::InvalidQueryCursorError {
+                reason: "cursor can't be combined with `datasets` or `skip`".to_string(),
+            });
+        }
+
+        let cursor = domain::QueryCursor::decode(cursor)?;
+        cursor.ensure_statement(&self.query)?;
+
+        Ok((cursor.to_options(), cursor.offset))
+    }
+
     pub fn query_state_to_datasets(state: domain::QueryState) -> Vec<DatasetState> {
         state
             .input_datasets
@@ -241,20 +284,24 @@ pub struct RequestParams {
 
     /// What information to include in the response
     pub include: Option<CommaSeparatedSet<Include>>,
+
+    /// Continuation token returned by the previous page
+    pub cursor: Option<String>,
 }
 
 impl From<RequestParams> for RequestBody {
     fn from(v: RequestParams) -> Self {
-        if let Some(include) = v.include {
+        if v.include.is_some() || v.cursor.is_some() {
             Self::V2(RequestBodyV2 {
                 query: v.query,
                 query_dialect: v.query_dialect,
                 data_format: v.data_format,
-                include: include.into(),
+                include: v.include.map(Into::into).unwrap_or_default(),
                 schema_format: v.schema_format,
                 datasets: None,
                 skip: v.skip,
                 limit: v.limit,
+                cursor: v.cursor,
             })
         } else {
             Self::V1(RequestBodyV1 {
@@ -296,6 +343,13 @@ pub struct ResponseBodyV2 {
 
     /// Query results
     pub output: Outputs,
+
+    /// Continuation token to fetch the next page of results. Present when the
+    /// page was filled up to the limit, so the next page may turn out empty.
+    /// Only issued for the queries that specify the order of the results or
+    /// read a single dataset, which is then ordered by offset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -318,6 +372,11 @@ pub struct ResponseBodyV2Signed {
 
     /// Signature block
     pub proof: Proof,
+
+    /// Continuation token to fetch the next page of results (not covered by
+    /// the commitment)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -416,7 +475,6 @@ pub struct DatasetState {
 // Data
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum DataFormat {
     #[default]
@@ -432,6 +490,58 @@ pub enum DataFormat {
     #[serde(alias = "json-aoa")]
     #[serde(alias = "JsonAoa")]
     JsonAoA,
+    #[serde(alias = "csv")]
+    #[serde(alias = "CSV")]
+    Csv,
+    #[serde(alias = "arrow")]
+    #[serde(alias = "arrowipc")]
+    #[serde(alias = "arrow-ipc")]
+    #[serde(alias = "ArrowIPC")]
+    ArrowIpc,
+    #[serde(alias = "parquet")]
+    Parquet,
+}
+
+impl DataFormat {
+    pub const MEDIA_TYPE_CSV: &'static str = "text/csv";
+    pub const MEDIA_TYPE_ARROW_IPC: &'static str = "application/vnd.apache.arrow.stream";
+    pub const MEDIA_TYPE_PARQUET: &'static str = "application/vnd.apache.parquet";
+
+    /// Whether the data in this format is streamed in the response body as-is
+    /// instead of being embedded into the JSON response
+    pub fn is_streamed(self) -> bool {
+        match self {
+            Self::JsonAoS | Self::JsonSoA | Self::JsonAoA => false,
+            Self::Csv | Self::ArrowIpc | Self::Parquet => true,
+        }
+    }
+
+    pub fn media_type(self) -> &'static str {
+        match self {
+            Self::JsonAoS | Self::JsonSoA | Self::JsonAoA => "application/json",
+            Self::Csv => Self::MEDIA_TYPE_CSV,
+            Self::ArrowIpc => Self::MEDIA_TYPE_ARROW_IPC,
+            Self::Parquet => Self::MEDIA_TYPE_PARQUET,
+        }
+    }
+
+    /// Picks the first streamed format listed in the `Accept` header. JSON
+    /// and wildcard media types are ignored to let `dataFormat` parameter
+    /// control the layout of the JSON response.
+    pub fn from_accept_header(headers: &http::HeaderMap) -> Option<Self> {
+        headers
+            .get_all(http::header::ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|media_type| media_type.split(';').next().unwrap_or_default().trim())
+            .find_map(|media_type| match media_type {
+                Self::MEDIA_TYPE_CSV => Some(Self::Csv),
+                Self::MEDIA_TYPE_ARROW_IPC => Some(Self::ArrowIpc),
+                Self::MEDIA_TYPE_PARQUET => Some(Self::Parquet),
+                _ => None,
+            })
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -451,6 +561,9 @@ pub(crate) fn serialize_data(
                 Box::new(JsonStructOfArraysWriter::new(&mut buf, MAX_SOA_BUFFER_SIZE))
             }
             DataFormat::JsonAoA => Box::new(JsonArrayOfArraysWriter::new(&mut buf)),
+            DataFormat::Csv | DataFormat::ArrowIpc | DataFormat::Parquet => {
+                return Err(StreamedDataFormatNotSupported(format).int_err())
+            }
         };
 
         for batch in record_batches {
@@ -462,6 +575,10 @@ pub(crate) fn serialize_data(
     Ok(String::from_utf8(buf).unwrap())
 }
 
+#[derive(Debug, thiserror::Error)]
+#[error("Data format {0:?} can't be embedded into a JSON response")]
+pub struct StreamedDataFormatNotSupported(pub DataFormat);
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Schema
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
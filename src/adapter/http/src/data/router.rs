@@ -47,6 +47,9 @@ pub fn dataset_router() -> axum::Router {
             "/ingest",
             axum::routing::post(super::ingest_handler::dataset_ingest_handler),
         )
+        .layer(crate::DatasetAuthorizationLayer::new(
+            crate::http_server_dataset_router::get_dataset_action_for_request,
+        ))
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -17,14 +17,16 @@
 // by the Apache License, Version 2.0.
 
 use axum::extract::{Extension, Query};
-use axum::response::Json;
+use axum::response::{IntoResponse, Json, Response};
 use database_common_macros::transactional_handler;
 use dill::Catalog;
+use http::HeaderMap;
 use http_common::*;
 use internal_error::{ErrorIntoInternal, ResultIntoInternal};
 use kamu_core::*;
 use opendatafabric::DatasetRef;
 
+use super::data_streaming::data_stream_response;
 use super::query_types::{DataFormat, SchemaFormat};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -37,10 +39,16 @@ use super::query_types::{DataFormat, SchemaFormat};
 pub async fn dataset_tail_handler(
     Extension(catalog): Extension<Catalog>,
     Extension(dataset_ref): Extension<DatasetRef>,
-    Query(params): Query<TailRequestParams>,
-) -> Result<Json<TailResponseBody>, ApiError> {
+    headers: HeaderMap,
+    Query(mut params): Query<TailRequestParams>,
+) -> Result<Response, ApiError> {
     tracing::debug!(request = ?params, "Tail");
 
+    // Streamed formats requested via `Accept` header take precedence
+    if let Some(data_format) = DataFormat::from_accept_header(&headers) {
+        params.data_format = data_format;
+    }
+
     let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
 
     let df = query_svc
@@ -56,6 +64,10 @@ pub async fn dataset_tail_handler(
             QueryError::Internal(e) => e.api_err(),
         })?;
 
+    if params.data_format.is_streamed() {
+        return data_stream_response(df, params.data_format).await.api_err();
+    }
+
     let schema = if params.include_schema {
         Some(
             super::query_types::serialize_schema(df.schema().as_arrow(), params.schema_format)
@@ -69,7 +81,7 @@ pub async fn dataset_tail_handler(
     let json = super::query_types::serialize_data(&record_batches, params.data_format).api_err()?;
     let data = serde_json::value::RawValue::from_string(json).unwrap();
 
-    Ok(Json(TailResponseBody { data, schema }))
+    Ok(Json(TailResponseBody { data, schema }).into_response())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
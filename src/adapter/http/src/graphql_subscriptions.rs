@@ -0,0 +1,107 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use async_graphql::{Data, Executor};
+use async_graphql_axum::{GraphQLProtocol, GraphQLWebSocket};
+use axum::extract::WebSocketUpgrade;
+use axum::response::Response;
+use axum::Extension;
+use kamu_accounts::ConnectionAccessToken;
+use serde::Deserialize;
+
+use crate::{resolve_current_account_subject, ws_common, AccessToken};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Browsers can't attach headers to WebSocket connections, so the access token
+/// may be passed in the payload of the `connection_init` message instead
+#[derive(Debug, Default, Deserialize)]
+struct GraphQLConnectionInitPayload {
+    #[serde(alias = "Authorization")]
+    authorization: Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Serves GraphQL subscriptions over WebSocket.
+///
+/// Supports both `graphql-transport-ws` and legacy `graphql-ws` protocols.
+/// The token from the `connection_init` payload takes precedence over the
+/// `Authorization` header of the upgrade request. Subscriptions are closed
+/// once the token expires or gets revoked.
+pub async fn graphql_subscription_handler<TSchema>(
+    Extension(schema): Extension<TSchema>,
+    Extension(catalog): Extension<dill::Catalog>,
+    protocol: GraphQLProtocol,
+    upgrade: WebSocketUpgrade,
+) -> Response
+where
+    TSchema: Executor,
+{
+    upgrade
+        .protocols(async_graphql::http::ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |stream| {
+            GraphQLWebSocket::new(stream, schema, protocol)
+                .on_connection_init(move |payload| connection_init_data(catalog, payload))
+                .serve()
+        })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn connection_init_data(
+    catalog: dill::Catalog,
+    payload: serde_json::Value,
+) -> async_graphql::Result<Data> {
+    let payload = if payload.is_null() {
+        GraphQLConnectionInitPayload::default()
+    } else {
+        ws_common::parse_payload_value::<GraphQLConnectionInitPayload>(payload)
+            .map_err(|e| async_graphql::Error::new(e.to_string()))?
+    };
+
+    let mut data = Data::default();
+
+    let Some(authorization) = payload.authorization else {
+        // Authenticated by the header of the upgrade request, if at all
+        let connection_catalog = match catalog.get_one::<AccessToken>() {
+            Ok(access_token) => dill::CatalogBuilder::new_chained(&catalog)
+                .add_value(ConnectionAccessToken::new(access_token.token.clone()))
+                .build(),
+            Err(_) => catalog,
+        };
+        data.insert(connection_catalog);
+        return Ok(data);
+    };
+
+    let access_token = AccessToken::new(
+        authorization
+            .strip_prefix("Bearer ")
+            .unwrap_or(&authorization),
+    );
+
+    let current_account_subject =
+        resolve_current_account_subject(&catalog, Some(access_token.clone()))
+            .await
+            .map_err(|_| async_graphql::Error::new("Internal error"))?;
+
+    tracing::debug!(subject = ?current_account_subject, "Authenticated subscription");
+
+    data.insert(
+        dill::CatalogBuilder::new_chained(&catalog)
+            .add_value(current_account_subject)
+            .add_value(ConnectionAccessToken::new(access_token.token.clone()))
+            .add_value(access_token)
+            .build(),
+    );
+
+    Ok(data)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
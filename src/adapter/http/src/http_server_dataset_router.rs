@@ -147,7 +147,7 @@ fn is_dataset_optional_for_request(request: &http::Request<axum::body::Body>) ->
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn get_dataset_action_for_request(
+pub(crate) fn get_dataset_action_for_request(
     request: &http::Request<axum::body::Body>,
 ) -> kamu_core::auth::DatasetAction {
     if !request.method().is_safe() || request.uri().path() == "/push" {
@@ -18,6 +18,8 @@ mod access_token;
 pub use access_token::*;
 mod axum_utils;
 pub mod data;
+mod graphql_subscriptions;
+pub use graphql_subscriptions::*;
 #[cfg(feature = "e2e")]
 pub mod e2e;
 mod simple_protocol;
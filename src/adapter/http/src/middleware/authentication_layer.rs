@@ -49,67 +49,68 @@ impl<Svc> Layer<Svc> for AuthenticationLayer {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Debug, Clone)]
-pub struct AuthenticationMiddleware<Svc> {
-    inner: Svc,
-}
-
-impl<Svc> AuthenticationMiddleware<Svc> {
-    async fn current_account_subject(
-        base_catalog: &dill::Catalog,
-        maybe_access_token: Option<AccessToken>,
-    ) -> Result<CurrentAccountSubject, Response> {
-        use tracing::Instrument;
-
-        if let Some(access_token) = maybe_access_token {
-            let account_res = DatabaseTransactionRunner::new(base_catalog.clone())
-                .transactional_with(
-                    |authentication_service: Arc<dyn AuthenticationService>| async move {
-                        authentication_service
-                            .account_by_token(access_token.token)
-                            .await
-                    },
-                )
-                .instrument(tracing::debug_span!(
-                    "AuthenticationMiddleware::current_account_subject"
-                ))
-                .await;
-
-            // TODO: Getting the full account info here is expensive while all we need is
-            //       the caller identity
-            match account_res {
-                Ok(account) => Ok(CurrentAccountSubject::logged(
-                    account.id,
-                    account.account_name,
-                    account.is_admin,
-                )),
-                Err(GetAccountInfoError::AccessToken(e)) => match e {
-                    AccessTokenError::Expired => Ok(CurrentAccountSubject::anonymous(
-                        AnonymousAccountReason::AuthenticationExpired,
-                    )),
-                    AccessTokenError::Invalid(err) => {
-                        tracing::warn!(error = err, "Ignoring invalid auth token",);
-                        Ok(CurrentAccountSubject::anonymous(
-                            AnonymousAccountReason::AuthenticationInvalid,
-                        ))
-                    }
+/// Resolves the caller identity from an optional access token, invalid or
+/// expired tokens result in an anonymous subject
+pub(crate) async fn resolve_current_account_subject(
+    base_catalog: &dill::Catalog,
+    maybe_access_token: Option<AccessToken>,
+) -> Result<CurrentAccountSubject, Response> {
+    use tracing::Instrument;
+
+    if let Some(access_token) = maybe_access_token {
+        let account_res = DatabaseTransactionRunner::new(base_catalog.clone())
+            .transactional_with(
+                |authentication_service: Arc<dyn AuthenticationService>| async move {
+                    authentication_service
+                        .authenticate_by_token(access_token.token)
+                        .await
                 },
-                Err(GetAccountInfoError::AccountUnresolved) => {
-                    tracing::warn!("Ignoring auth token pointing to non-existing account");
+            )
+            .instrument(tracing::debug_span!("resolve_current_account_subject"))
+            .await;
+
+        // TODO: Getting the full account info here is expensive while all we need is
+        //       the caller identity
+        match account_res {
+            Ok(authenticated) => Ok(CurrentAccountSubject::logged_with_token_scopes(
+                authenticated.account.id,
+                authenticated.account.account_name,
+                authenticated.account.is_admin,
+                authenticated.token_scopes,
+            )),
+            Err(GetAccountInfoError::AccessToken(e)) => match e {
+                AccessTokenError::Expired => Ok(CurrentAccountSubject::anonymous(
+                    AnonymousAccountReason::AuthenticationExpired,
+                )),
+                AccessTokenError::Invalid(err) => {
+                    tracing::warn!(error = err, "Ignoring invalid auth token",);
                     Ok(CurrentAccountSubject::anonymous(
                         AnonymousAccountReason::AuthenticationInvalid,
                     ))
                 }
-                Err(GetAccountInfoError::Internal(_)) => Err(internal_server_error_response()),
+            },
+            Err(GetAccountInfoError::AccountUnresolved) => {
+                tracing::warn!("Ignoring auth token pointing to non-existing account");
+                Ok(CurrentAccountSubject::anonymous(
+                    AnonymousAccountReason::AuthenticationInvalid,
+                ))
             }
-        } else {
-            Ok(CurrentAccountSubject::anonymous(
-                AnonymousAccountReason::NoAuthenticationProvided,
-            ))
+            Err(GetAccountInfoError::Internal(_)) => Err(internal_server_error_response()),
         }
+    } else {
+        Ok(CurrentAccountSubject::anonymous(
+            AnonymousAccountReason::NoAuthenticationProvided,
+        ))
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct AuthenticationMiddleware<Svc> {
+    inner: Svc,
+}
+
 impl<Svc> Service<http::Request<Body>> for AuthenticationMiddleware<Svc>
 where
     Svc: Service<http::Request<Body>, Response = Response> + Send + 'static + Clone,
@@ -142,7 +143,8 @@ where
                 .expect("Catalog not found in http server extensions");
 
             let current_account_subject =
-                match Self::current_account_subject(base_catalog, maybe_access_token.clone()).await
+                match resolve_current_account_subject(base_catalog, maybe_access_token.clone())
+                    .await
                 {
                     Ok(current_account_subject) => current_account_subject,
                     Err(response) => return Ok(response),
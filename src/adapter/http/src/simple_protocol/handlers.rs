@@ -221,6 +221,9 @@ pub async fn dataset_push_ws_upgrade_handler(
                     return Err(ApiError::new_forbidden());
                 }
             }
+            if !acc.token_scopes.allows_dataset_creation() {
+                return Err(ApiError::new_forbidden());
+            }
             Ok(None)
         }
         Err(err) => Err(err.api_err()),
@@ -75,6 +75,17 @@ pub fn parse_payload<TMessagePayload: DeserializeOwned>(
     }
 }
 
+pub fn parse_payload_value<TMessagePayload: DeserializeOwned>(
+    raw_value: serde_json::Value,
+) -> Result<TMessagePayload, ReadMessageError> {
+    let parse_result = serde_json::from_value::<TMessagePayload>(raw_value);
+
+    match parse_result {
+        Ok(payload) => Ok(payload),
+        Err(e) => Err(ReadMessageError::SerdeError(e)),
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub fn payload_to_json<TMessagePayload: Serialize>(
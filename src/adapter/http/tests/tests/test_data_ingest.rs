@@ -19,6 +19,7 @@ use kamu_adapter_http::{
     UploadToken,
     UploadTokenBase64Json,
 };
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::{MergeStrategy, *};
 use serde_json::json;
 use url::Url;
@@ -596,6 +597,7 @@ impl DataIngestHarness {
         let catalog = dill::CatalogBuilder::new()
             .add::<DataFormatRegistryImpl>()
             .add::<PushIngestServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .add::<EngineProvisionerNull>()
             .add::<UploadServiceLocal>()
             .add_value(FileUploadLimitConfig::new_in_bytes(1000))
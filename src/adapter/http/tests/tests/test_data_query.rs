@@ -19,6 +19,7 @@ use kamu::testing::MetadataFactory;
 use kamu::*;
 use kamu_adapter_http::data::query_types::IdentityConfig;
 use kamu_ingest_datafusion::DataWriterDataFusion;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use serde_json::json;
 
@@ -54,6 +55,7 @@ impl Harness {
             .add::<DataFormatRegistryImpl>()
             .add::<QueryServiceImpl>()
             .add::<PushIngestServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .add::<EngineProvisionerNull>()
             .build();
 
@@ -1063,6 +1065,122 @@ async fn test_data_query_handler_ranges() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_query_handler_cursor() {
+    let harness = Harness::new().await;
+
+    let client = async move {
+        let cl = reqwest::Client::new();
+
+        let query = format!(
+            "select offset, city, population from \"{}\" order by offset desc",
+            harness.dataset_handle.alias
+        );
+        let query_url = format!("{}query", harness.root_url);
+
+        // First page
+        let res = cl
+            .post(&query_url)
+            .json(&json!({
+                "query": query,
+                // TODO: Remove after V2 transition
+                "queryDialect": "SqlDataFusion",
+                "limit": 1,
+            }))
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        let response = res.json::<serde_json::Value>().await.unwrap();
+        let cursor = response["nextCursor"].as_str().unwrap().to_string();
+
+        pretty_assertions::assert_eq!(
+            response["output"]["data"],
+            json!([{"city": "B", "offset": 1, "population": 200}])
+        );
+
+        // Second page, also via GET
+        let res = cl
+            .get(&query_url)
+            .query(&[
+                ("query", query.as_str()),
+                ("limit", "1"),
+                ("cursor", cursor.as_str()),
+            ])
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        let response = res.json::<serde_json::Value>().await.unwrap();
+        let cursor = response["nextCursor"].as_str().unwrap().to_string();
+
+        pretty_assertions::assert_eq!(
+            response["output"]["data"],
+            json!([{"city": "A", "offset": 0, "population": 100}])
+        );
+
+        // Last page is empty and has no continuation
+        let res = cl
+            .post(&query_url)
+            .json(&json!({
+                "query": query,
+                "limit": 1,
+                "cursor": cursor,
+            }))
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        pretty_assertions::assert_eq!(
+            res.json::<serde_json::Value>().await.unwrap(),
+            json!({
+                "output": {
+                    "data": [],
+                    "dataFormat": "JsonAoS",
+                }
+            })
+        );
+
+        // Cursor issued for a different query
+        let res = cl
+            .post(&query_url)
+            .json(&json!({
+                "query": format!("select * from \"{}\"", harness.dataset_handle.alias),
+                "limit": 1,
+                "cursor": cursor,
+            }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        // Malformed cursor
+        let res = cl
+            .post(&query_url)
+            .json(&json!({
+                "query": query,
+                "cursor": "not-a-cursor",
+            }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+    };
+
+    await_client_server_flow!(harness.server_harness.api_server_run(), client);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_data_query_handler_data_formats() {
@@ -1157,6 +1275,211 @@ async fn test_data_query_handler_data_formats() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_query_handler_streamed_data_formats() {
+    let harness = Harness::new().await;
+
+    let client = async move {
+        let cl = reqwest::Client::new();
+
+        let query = format!(
+            "select offset, city, population from \"{}\" order by offset desc",
+            harness.dataset_handle.alias
+        );
+        let query_url = format!("{}query", harness.root_url);
+
+        let expected_batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("offset", DataType::Int64, false),
+                Field::new("city", DataType::Utf8, false),
+                Field::new("population", DataType::UInt64, false),
+            ])),
+            vec![
+                Arc::new(datafusion::arrow::array::Int64Array::from(vec![1, 0])),
+                Arc::new(StringArray::from(vec!["B", "A"])),
+                Arc::new(UInt64Array::from(vec![200, 100])),
+            ],
+        )
+        .unwrap();
+
+        // CSV via parameter
+        let res = cl
+            .get(&query_url)
+            .query(&[("query", query.as_str()), ("dataFormat", "csv")])
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        assert_eq!(res.headers()[http::header::CONTENT_TYPE], "text/csv");
+        assert_eq!(
+            res.text().await.unwrap(),
+            indoc::indoc!(
+                "
+                offset,city,population
+                1,B,200
+                0,A,100"
+            )
+        );
+
+        // Continuation of a limited page is passed in a header
+        let res = cl
+            .post(&query_url)
+            .json(&json!({
+                "query": query,
+                // TODO: Remove after V2 transition
+                "queryDialect": "SqlDataFusion",
+                "dataFormat": "Csv",
+                "limit": 1,
+            }))
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        let cursor = res.headers()["x-kamu-next-cursor"]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            res.text().await.unwrap(),
+            indoc::indoc!(
+                "
+                offset,city,population
+                1,B,200"
+            )
+        );
+
+        let res = cl
+            .post(&query_url)
+            .json(&json!({
+                "query": query,
+                "dataFormat": "Csv",
+                "limit": 1,
+                "cursor": cursor,
+            }))
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        assert_eq!(
+            res.text().await.unwrap(),
+            indoc::indoc!(
+                "
+                offset,city,population
+                0,A,100"
+            )
+        );
+
+        // Arrow IPC via `Accept` header
+        let res = cl
+            .post(&query_url)
+            .header(
+                http::header::ACCEPT,
+                "application/vnd.apache.arrow.stream, application/json;q=0.9",
+            )
+            .json(&json!({
+                "query": query,
+            }))
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        assert_eq!(
+            res.headers()[http::header::CONTENT_TYPE],
+            "application/vnd.apache.arrow.stream"
+        );
+        let reader = datafusion::arrow::ipc::reader::StreamReader::try_new(
+            std::io::Cursor::new(res.bytes().await.unwrap()),
+            None,
+        )
+        .unwrap();
+        let batches: Vec<_> = reader.map(Result::unwrap).collect();
+        assert_eq!(
+            datafusion::arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap(),
+            expected_batch
+        );
+
+        // Parquet via parameter
+        let res = cl
+            .post(&query_url)
+            .json(&json!({
+                "query": query,
+                "dataFormat": "Parquet",
+            }))
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        assert_eq!(
+            res.headers()[http::header::CONTENT_TYPE],
+            "application/vnd.apache.parquet"
+        );
+        let reader =
+            datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(
+                res.bytes().await.unwrap(),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<_> = reader.map(Result::unwrap).collect();
+        assert_eq!(
+            datafusion::arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap(),
+            expected_batch
+        );
+
+        // Proofs can't be provided for streamed formats
+        let res = cl
+            .post(&query_url)
+            .json(&json!({
+                "query": query,
+                "dataFormat": "Csv",
+                "include": ["proof"],
+            }))
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(res.status(), http::StatusCode::BAD_REQUEST);
+
+        // Tail via `Accept` header
+        let res = cl
+            .get(format!("{}/tail", harness.dataset_url))
+            .header(http::header::ACCEPT, "application/vnd.apache.arrow.stream")
+            .query(&[("limit", "1")])
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+
+        assert_eq!(
+            res.headers()[http::header::CONTENT_TYPE],
+            "application/vnd.apache.arrow.stream"
+        );
+        let reader = datafusion::arrow::ipc::reader::StreamReader::try_new(
+            std::io::Cursor::new(res.bytes().await.unwrap()),
+            None,
+        )
+        .unwrap();
+        let num_rows: usize = reader.map(|b| b.unwrap().num_rows()).sum();
+        assert_eq!(num_rows, 1);
+    };
+
+    await_client_server_flow!(harness.server_harness.api_server_run(), client);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_data_query_handler_schema_formats() {
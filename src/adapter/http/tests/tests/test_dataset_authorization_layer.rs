@@ -187,6 +187,33 @@ async fn test_dataset_access_safe_method_but_potential_write_logged_authorized()
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_dataset_data_routes_check_access() {
+    for (method, path, action) in [
+        (http::Method::GET, "tail", DatasetAction::Read),
+        (http::Method::GET, "metadata", DatasetAction::Read),
+        (http::Method::POST, "ingest", DatasetAction::Write),
+    ] {
+        let server_harness = ServerHarness::new_with_router(
+            CurrentAccountSubject::new_test(),
+            ServerHarness::mock_dataset_action_authorizer(action, false),
+            kamu_adapter_http::data::dataset_router(),
+        )
+        .await;
+
+        let test_url = server_harness.test_url(path);
+
+        let api_server_handle = server_harness.api_server_run();
+
+        let client_handle =
+            ServerHarness::check_access(&test_url, method, http::StatusCode::FORBIDDEN);
+
+        await_client_server_flow!(api_server_handle, client_handle);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 const SAFE_METHODS: [http::Method; 2] = [http::Method::GET, http::Method::HEAD];
 
 const UNSAFE_METHODS: [http::Method; 4] = [
@@ -209,6 +236,36 @@ impl ServerHarness {
     pub async fn new(
         current_account_subject: CurrentAccountSubject,
         dataset_action_authorizer: MockDatasetActionAuthorizer,
+    ) -> Self {
+        let router = axum::Router::new()
+            .route(
+                "/foo",
+                axum::routing::get(ServerHarness::foo_handler)
+                    .put(ServerHarness::foo_handler)
+                    .delete(ServerHarness::foo_handler)
+                    .patch(ServerHarness::foo_handler)
+                    .post(ServerHarness::foo_handler),
+            )
+            .route("/bar", axum::routing::get(ServerHarness::bar_handler))
+            .layer(kamu_adapter_http::DatasetAuthorizationLayer::new(
+                |request| {
+                    if !request.method().is_safe() || request.uri().path() == "/bar" {
+                        kamu::domain::auth::DatasetAction::Write
+                    } else {
+                        kamu::domain::auth::DatasetAction::Read
+                    }
+                },
+            ));
+
+        Self::new_with_router(current_account_subject, dataset_action_authorizer, router).await
+    }
+
+    /// Serves the router that is expected to authorize requests to the
+    /// `mydataset` dataset on its own
+    pub async fn new_with_router(
+        current_account_subject: CurrentAccountSubject,
+        dataset_action_authorizer: MockDatasetActionAuthorizer,
+        router: axum::Router,
     ) -> Self {
         let temp_dir = tempfile::TempDir::new().unwrap();
         let datasets_dir = temp_dir.path().join("datasets");
@@ -263,30 +320,11 @@ impl ServerHarness {
             .add_value(current_account_subject)
             .build();
 
-        let app = axum::Router::new()
-            .route(
-                "/foo",
-                axum::routing::get(ServerHarness::foo_handler)
-                    .put(ServerHarness::foo_handler)
-                    .delete(ServerHarness::foo_handler)
-                    .patch(ServerHarness::foo_handler)
-                    .post(ServerHarness::foo_handler),
-            )
-            .route("/bar", axum::routing::get(ServerHarness::bar_handler))
-            .layer(
-                tower::ServiceBuilder::new()
-                    .layer(axum::Extension(catalog_test))
-                    .layer(axum::Extension(DatasetRef::from_str("mydataset").unwrap()))
-                    .layer(kamu_adapter_http::DatasetAuthorizationLayer::new(
-                        |request| {
-                            if !request.method().is_safe() || request.uri().path() == "/bar" {
-                                kamu::domain::auth::DatasetAction::Write
-                            } else {
-                                kamu::domain::auth::DatasetAction::Read
-                            }
-                        },
-                    )),
-            );
+        let app = router.layer(
+            tower::ServiceBuilder::new()
+                .layer(axum::Extension(catalog_test))
+                .layer(axum::Extension(DatasetRef::from_str("mydataset").unwrap())),
+        );
 
         let addr = SocketAddr::from((IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 0));
         let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
@@ -22,6 +22,7 @@ use kamu_accounts_services::{
     PredefinedAccountsRegistrator,
 };
 use kamu_adapter_http::{LoginRequestBody, LoginResponseBody};
+use kamu_audit_log::testing::DummyAuditLogService;
 use opendatafabric::AccountName;
 use serde_json::json;
 use time_source::{SystemTimeSource, SystemTimeSourceStub};
@@ -71,6 +72,7 @@ impl Harness {
                 .add_value(JwtAuthenticationConfig::default())
                 .add::<DatabaseTransactionRunner>()
                 .add::<AccessTokenServiceImpl>()
+                .add::<DummyAuditLogService>()
                 .add::<InMemoryAccessTokenRepository>()
                 .add::<PredefinedAccountsRegistrator>();
 
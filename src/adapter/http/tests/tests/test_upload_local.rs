@@ -29,6 +29,7 @@ use kamu_adapter_http::{
     UploadToken,
     UploadTokenBase64Json,
 };
+use kamu_audit_log::testing::DummyAuditLogService;
 use kamu_core::MediaType;
 use time_source::SystemTimeSourceDefault;
 
@@ -61,6 +62,7 @@ impl Harness {
                 .add::<AuthenticationServiceImpl>()
                 .add::<InMemoryAccountRepository>()
                 .add::<AccessTokenServiceImpl>()
+                .add::<DummyAuditLogService>()
                 .add::<InMemoryAccessTokenRepository>()
                 .add::<SystemTimeSourceDefault>()
                 .add::<LoginPasswordAuthProvider>()
@@ -25,6 +25,7 @@ use kamu_accounts_services::{
     PredefinedAccountsRegistrator,
 };
 use kamu_adapter_http::{FileUploadLimitConfig, UploadContext, UploadService, UploadServiceS3};
+use kamu_audit_log::testing::DummyAuditLogService;
 use time_source::SystemTimeSourceDefault;
 use tokio::io::AsyncReadExt;
 
@@ -56,6 +57,7 @@ impl Harness {
                 .add::<AuthenticationServiceImpl>()
                 .add::<InMemoryAccountRepository>()
                 .add::<AccessTokenServiceImpl>()
+                .add::<DummyAuditLogService>()
                 .add::<InMemoryAccessTokenRepository>()
                 .add::<SystemTimeSourceDefault>()
                 .add::<LoginPasswordAuthProvider>()
@@ -161,7 +161,9 @@ pub(crate) async fn test_smart_pull_existing_diverged_dataset<TServerHarness: Se
             .await;
 
         let new_head = match scenario.server_compaction_result {
-            CompactionResult::NothingToDo => panic!("unexpected compaction result"),
+            CompactionResult::NothingToDo | CompactionResult::SoftSuccess { .. } => {
+                panic!("unexpected compaction result")
+            }
             CompactionResult::Success { new_head, .. } => new_head,
         };
 
@@ -228,7 +228,9 @@ pub(crate) async fn test_smart_push_existing_diverged_dataset<TServerHarness: Se
             .await;
 
         let new_head = match scenario.client_compaction_result {
-            CompactionResult::NothingToDo => panic!("unexpected compaction result"),
+            CompactionResult::NothingToDo | CompactionResult::SoftSuccess { .. } => {
+                panic!("unexpected compaction result")
+            }
             CompactionResult::Success { new_head, .. } => new_head,
         };
 
@@ -23,7 +23,12 @@ use kamu_accounts::*;
 use kamu_accounts_services::PredefinedAccountsRegistrator;
 use kamu_adapter_http::{FileUploadLimitConfig, UploadServiceLocal};
 use kamu_adapter_oauth::GithubAuthenticationConfig;
-use kamu_auth_rebac_services::{MultiTenantRebacDatasetLifecycleMessageConsumer, RebacServiceImpl};
+use kamu_auth_rebac_services::{
+    DatasetCollaborationServiceImpl,
+    MultiTenantRebacDatasetLifecycleMessageConsumer,
+    OrganizationMembershipServiceImpl,
+    RebacServiceImpl,
+};
 use kamu_datasets::DatasetEnvVar;
 use kamu_datasets_services::{DatasetEntryIndexer, DatasetEntryService};
 use kamu_flow_system_inmem::domain::{FlowConfigurationUpdatedMessage, FlowProgressMessage};
@@ -32,6 +37,10 @@ use kamu_flow_system_services::{
     MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
 };
 use kamu_task_system_inmem::domain::{TaskProgressMessage, MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR};
+use kamu_webhooks_services::{
+    WebhookDeliveryMessage,
+    MESSAGE_PRODUCER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+};
 use messaging_outbox::{register_message_dispatcher, Outbox, OutboxDispatchingImpl};
 use time_source::{SystemTimeSource, SystemTimeSourceDefault, SystemTimeSourceStub};
 use tracing::{warn, Instrument};
@@ -437,7 +446,10 @@ pub fn configure_base_catalog(
 
     b.add::<CompactionServiceImpl>();
 
+    b.add::<RetentionServiceImpl>();
+
     b.add::<SearchServiceImpl>();
+    b.add::<SearchIndexInMemory>();
 
     b.add::<SyncServiceImpl>();
 
@@ -449,6 +461,12 @@ pub fn configure_base_catalog(
 
     b.add::<ProvenanceServiceImpl>();
 
+    b.add::<DatasetDiffServiceImpl>();
+
+    b.add::<DatasetArchiveServiceImpl>();
+
+    b.add::<DataExportServiceImpl>();
+
     b.add::<QueryServiceImpl>();
 
     b.add::<ObjectStoreRegistryImpl>();
@@ -493,6 +511,8 @@ pub fn configure_base_catalog(
     b.add::<DatabaseTransactionRunner>();
 
     b.add::<RebacServiceImpl>();
+    b.add::<OrganizationMembershipServiceImpl>();
+    b.add::<DatasetCollaborationServiceImpl>();
 
     if multi_tenant_workspace {
         b.add::<MultiTenantRebacDatasetLifecycleMessageConsumer>();
@@ -500,6 +520,10 @@ pub fn configure_base_catalog(
 
     b.add::<DatasetEntryService>();
 
+    b.add::<kamu_webhooks_services::WebhookSubscriptionServiceImpl>();
+
+    b.add::<kamu_audit_log_services::AuditLogServiceImpl>();
+
     b.add_builder(
         messaging_outbox::OutboxImmediateImpl::builder()
             .with_consumer_filter(messaging_outbox::ConsumerFilter::BestEffortConsumers),
@@ -514,6 +538,10 @@ pub fn configure_base_catalog(
         &mut b,
         MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
     );
+    register_message_dispatcher::<DatasetReferenceMessage>(
+        &mut b,
+        MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+    );
 
     b
 }
@@ -550,6 +578,10 @@ pub fn configure_server_catalog(base_catalog: &Catalog) -> CatalogBuilder {
     ));
     kamu_flow_system_services::register_dependencies(&mut b);
 
+    kamu_webhooks_services::register_dependencies(&mut b);
+
+    b.add::<kamu_adapter_graphql::SubscriptionEventHub>();
+
     b.add::<UploadServiceLocal>();
 
     register_message_dispatcher::<FlowProgressMessage>(
@@ -563,6 +595,11 @@ pub fn configure_server_catalog(base_catalog: &Catalog) -> CatalogBuilder {
 
     register_message_dispatcher::<TaskProgressMessage>(&mut b, MESSAGE_PRODUCER_KAMU_TASK_EXECUTOR);
 
+    register_message_dispatcher::<WebhookDeliveryMessage>(
+        &mut b,
+        MESSAGE_PRODUCER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+    );
+
     b
 }
 
@@ -689,6 +726,16 @@ pub fn register_config_in_catalog(
             .unwrap()
             .to_infra_cfg(),
     );
+    catalog_builder.add_value(
+        config
+            .source
+            .as_ref()
+            .unwrap()
+            .kafka
+            .as_ref()
+            .unwrap()
+            .to_infra_cfg(),
+    );
     catalog_builder.add_value(
         config
             .source
@@ -779,6 +826,28 @@ pub fn register_config_in_catalog(
         Duration::seconds(outbox_config.awaiting_step_secs.unwrap()),
         outbox_config.batch_size.unwrap(),
     ));
+
+    let query_cache_config = config.query_cache.as_ref().unwrap();
+    if query_cache_config.enabled.unwrap() {
+        catalog_builder.add_value(query_cache_config.to_infra_cfg());
+        catalog_builder.add::<QueryResultCacheMetrics>();
+        match query_cache_config.store.unwrap() {
+            config::QueryCacheStore::Memory => {
+                catalog_builder.add::<QueryResultCacheInMemory>();
+            }
+            config::QueryCacheStore::LocalFs => {
+                catalog_builder.add::<QueryResultCacheLocalFs>();
+            }
+        }
+    }
+
+    let webhooks_config = config.webhooks.as_ref().unwrap();
+    catalog_builder.add_value(kamu_webhooks::WebhookDeliveryConfig::new(
+        webhooks_config.max_attempts.unwrap(),
+        Duration::seconds(webhooks_config.initial_backoff_secs.unwrap()),
+        Duration::seconds(webhooks_config.request_timeout_secs.unwrap()),
+        webhooks_config.allow_insecure_localhost.unwrap(),
+    ));
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
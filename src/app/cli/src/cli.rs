@@ -83,6 +83,9 @@ pub enum Command {
     Completions(Completions),
     Config(Config),
     Delete(Delete),
+    Diff(Diff),
+    Export(Export),
+    Import(Import),
     Ingest(Ingest),
     Init(Init),
     Inspect(Inspect),
@@ -92,6 +95,7 @@ pub enum Command {
     Logout(Logout),
     New(New),
     Notebook(Notebook),
+    Org(Org),
     Pull(Pull),
     Push(Push),
     Rename(Rename),
@@ -104,6 +108,7 @@ pub enum Command {
     Ui(Ui),
     Verify(Verify),
     Version(Version),
+    Webhook(Webhook),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -111,7 +116,12 @@ pub enum Command {
 impl Cli {
     pub fn tabular_output_format(&self) -> Option<OutputFormat> {
         match &self.command {
+            Command::Diff(c) => c.output_format,
             Command::List(c) => c.output_format,
+            Command::Org(c) => match &c.subcommand {
+                OrgSubCommand::Members(sc) => sc.output_format,
+                _ => None,
+            },
             Command::Repo(c) => match &c.subcommand {
                 RepoSubCommand::Alias(sc) => match &sc.subcommand {
                     RepoAliasSubCommand::List(ssc) => ssc.output_format,
@@ -123,6 +133,10 @@ impl Cli {
             Command::Search(c) => c.output_format,
             Command::Sql(c) => c.output_format,
             Command::Tail(c) => c.output_format,
+            Command::Webhook(c) => match &c.subcommand {
+                WebhookSubCommand::List(sc) => sc.output_format,
+                _ => None,
+            },
             _ => None,
         }
     }
@@ -370,6 +384,173 @@ pub struct Delete {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Shows record-level differences between two states of a dataset
+#[derive(Debug, clap::Args)]
+#[command(after_help = r#"
+Changes are displayed as appended (`+A`), retracted (`-R`), and corrected (`-C` / `+C`) records. Records of two states are matched by the primary key of the dataset's merge strategy, or by their offsets if the dataset doesn't have one.
+
+**Examples:**
+
+Show changes introduced since a specific block:
+
+    kamu diff my.dataset --old zW1a...
+
+Show changes between two blocks matching records by a custom key:
+
+    kamu diff my.dataset --old zW1a... --new zW1b... --primary-key city,year
+
+Compare current states of two datasets with the same schema:
+
+    kamu diff my.dataset my.dataset.fixed
+"#)]
+pub struct Diff {
+    /// Format to display the results in
+    #[arg(long, short = 'o', value_name = "FMT", value_enum)]
+    pub output_format: Option<OutputFormat>,
+
+    /// Block hash of the old state (defaults to an empty state)
+    #[arg(long, value_name = "HASH", value_parser = parsers::multihash)]
+    pub old: Option<odf::Multihash>,
+
+    /// Block hash of the new state (defaults to the current head)
+    #[arg(long, value_name = "HASH", value_parser = parsers::multihash)]
+    pub new: Option<odf::Multihash>,
+
+    /// Columns to match records by
+    #[arg(long, value_name = "COL", value_delimiter = ',')]
+    pub primary_key: Option<Vec<String>>,
+
+    /// Local dataset reference
+    #[arg(index = 1, value_parser = parsers::dataset_ref)]
+    pub dataset: odf::DatasetRef,
+
+    /// Local reference of a dataset to compare with
+    #[arg(index = 2, value_parser = parsers::dataset_ref, conflicts_with_all = ["old", "new"])]
+    pub other_dataset: Option<odf::DatasetRef>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Exports a dataset into a single portable archive or its data into files
+#[derive(Debug, clap::Args)]
+#[command(
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true,
+    after_help = r#"
+Archive is a `zstd`-compressed `tar` file that contains the metadata chain, refs, data and checkpoints of the dataset along with a manifest holding the hashes of all files. Use `kamu import` to restore the dataset from it in another workspace.
+
+To get only the data out in one of the common formats use the `kamu export data` subcommand.
+
+**Examples:**
+
+Export a dataset into an archive:
+
+    kamu export my.dataset --archive my.dataset.tar.zst
+"#
+)]
+pub struct Export {
+    #[command(subcommand)]
+    pub subcommand: Option<ExportSubCommand>,
+
+    /// Path of the archive file to create
+    #[arg(long, value_name = "PATH", required = true)]
+    pub archive: Option<PathBuf>,
+
+    /// Local dataset reference
+    #[arg(index = 1, value_parser = parsers::dataset_ref, required = true)]
+    pub dataset: Option<odf::DatasetRef>,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ExportSubCommand {
+    Data(ExportData),
+}
+
+/// Writes data of a dataset or a result of an SQL query into files
+#[derive(Debug, clap::Args)]
+#[command(after_help = r#"
+Files are written into a local directory or into an S3-compatible object store. Multiple files can be produced, and the existing files in the destination are not removed.
+
+When exporting a dataset the position of the export is remembered, so that the `--incremental` mode can later write only the data that was added since the previous export into the same destination.
+
+**Examples:**
+
+Export a dataset into a local directory as Parquet files:
+
+    kamu export data my.dataset --to ./export/
+
+Export a dataset into S3 as compressed CSV files partitioned by a column:
+
+    kamu export data my.dataset --to s3://my-bucket/my.dataset/ --format csv --compression gzip --partition-by city
+
+Export only data added since the previous export:
+
+    kamu export data my.dataset --to s3://my-bucket/my.dataset/ --incremental
+
+Export a result of an SQL query:
+
+    kamu export data --sql 'select city, count(*) from "my.dataset" group by city' --to ./stats/ --format ndjson
+"#)]
+pub struct ExportData {
+    /// Local directory or object store URL to write the files into
+    #[arg(long, value_name = "DEST")]
+    pub to: String,
+
+    /// Format of the output files
+    #[arg(long, value_name = "FMT", value_enum, default_value = "parquet")]
+    pub format: parsers::ExportDataFormat,
+
+    /// Compression codec of the output files
+    #[arg(long, value_name = "CODEC", value_enum)]
+    pub compression: Option<parsers::ExportDataCompression>,
+
+    /// Columns to partition the output files by
+    #[arg(long, value_name = "COL", value_delimiter = ',')]
+    pub partition_by: Vec<String>,
+
+    /// Export only data added since the previous export into the same
+    /// destination
+    #[arg(long, conflicts_with = "sql")]
+    pub incremental: bool,
+
+    /// SQL statement whose result should be exported instead of a dataset
+    #[arg(long, value_name = "SQL", conflicts_with = "dataset")]
+    pub sql: Option<String>,
+
+    /// Local dataset reference
+    #[arg(index = 1, value_parser = parsers::dataset_ref, required_unless_present = "sql")]
+    pub dataset: Option<odf::DatasetRef>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Imports a dataset from an archive created by `kamu export`
+#[derive(Debug, clap::Args)]
+#[command(after_help = r#"
+All files are validated against the hashes in the archive manifest and the imported dataset is verified before the command completes. If the import is interrupted it can be resumed by running the same command again.
+
+**Examples:**
+
+Import a dataset under its original name:
+
+    kamu import my.dataset.tar.zst
+
+Import a dataset under a different name:
+
+    kamu import my.dataset.tar.zst --name my.dataset.copy
+"#)]
+pub struct Import {
+    /// Name to import the dataset under (defaults to the name in the archive)
+    #[arg(long, value_name = "NAME", value_parser = parsers::dataset_name)]
+    pub name: Option<odf::DatasetName>,
+
+    /// Path of the archive file
+    #[arg(index = 1)]
+    pub archive: PathBuf,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Adds data to the root dataset according to its push source configuration
 #[derive(Debug, clap::Args)]
 #[command(after_help = r#"
@@ -408,6 +589,12 @@ pub struct Ingest {
     #[arg(long, short = 'r')]
     pub recursive: bool,
 
+    /// Allow additive schema evolution (new nullable columns, wider numeric
+    /// types, relaxed non-null constraints) if new data does not match the
+    /// schema of the dataset
+    #[arg(long)]
+    pub evolve_schema: bool,
+
     /// Overrides the media type of the data expected by the push source
     #[arg(long, value_name = "FMT", value_parser = [
         "csv",
@@ -416,6 +603,8 @@ pub struct Ingest {
         "geojson",
         "ndgeojson",
         "parquet",
+        "avro",
+        "orc",
         "esrishapefile",
     ])]
     pub input_format: Option<String>,
@@ -470,6 +659,7 @@ pub struct Inspect {
 #[derive(Debug, clap::Subcommand)]
 pub enum InspectSubCommand {
     Lineage(InspectLineage),
+    Provenance(InspectProvenance),
     Query(InspectQuery),
     Schema(InspectSchema),
 }
@@ -492,6 +682,10 @@ Show lineage graph of all datasets in a browser:
 Render the lineage graph into a png image (needs graphviz installed):
 
     kamu inspect lineage -o dot | dot -Tpng > depgraph.png
+
+Show which input columns feed each column of a derivative dataset:
+
+    kamu inspect lineage --columns my.dataset
 "#)]
 pub struct InspectLineage {
     /// Format of the output
@@ -502,11 +696,36 @@ pub struct InspectLineage {
     #[arg(long, short = 'b')]
     pub browse: bool,
 
+    /// Show which input columns each output column is derived from
+    #[arg(long)]
+    pub columns: bool,
+
     /// Local dataset reference(s)
     #[arg(value_parser = parsers::dataset_ref)]
     pub dataset: Vec<odf::DatasetRef>,
 }
 
+/// Traces a record back to the input data it was derived from
+#[derive(Debug, clap::Args)]
+#[command(after_help = r#"
+Locates the metadata block that added the record with the specified offset and shows the input datasets, block intervals, and offset intervals that were considered by the transformation. When the transformation is a simple projection or filter of a single input the input records that pass its filters are shown as possible sources of the record.
+
+**Examples:**
+
+Show where the record with offset 100 came from:
+
+    kamu inspect provenance my.dataset --offset 100
+"#)]
+pub struct InspectProvenance {
+    /// Offset of the record
+    #[arg(long, value_name = "OFFSET")]
+    pub offset: u64,
+
+    /// Local dataset reference
+    #[arg(value_parser = parsers::dataset_ref)]
+    pub dataset: odf::DatasetRef,
+}
+
 /// Shows the transformations used by a derivative dataset
 #[derive(Debug, clap::Args)]
 #[command(after_help = r#"
@@ -786,6 +1005,84 @@ pub struct Notebook {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Manage organization members
+#[derive(Debug, clap::Args)]
+#[command(after_help = r#"
+Organizations are accounts that are shared by a group of users. Members of an organization get access to datasets it owns according to their role:
+- `owner` - can modify datasets and manage members of the organization
+- `maintainer` - can modify datasets
+- `member` - can read datasets
+
+Members can only be managed by the owners of the organization or by administrators.
+
+**Examples:**
+
+Add a user to the organization:
+
+    kamu org set-role acme alice member
+
+Promote a member to be an owner:
+
+    kamu org set-role acme alice owner
+
+List members:
+
+    kamu org members acme
+"#)]
+pub struct Org {
+    #[command(subcommand)]
+    pub subcommand: OrgSubCommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum OrgSubCommand {
+    Members(OrgMembers),
+    RemoveMember(OrgRemoveMember),
+    SetRole(OrgSetRole),
+}
+
+/// Lists members of the organization
+#[derive(Debug, clap::Args)]
+pub struct OrgMembers {
+    /// Format to display the results in
+    #[arg(long, short = 'o', value_name = "FMT", value_enum)]
+    pub output_format: Option<OutputFormat>,
+
+    /// Name of the organization
+    #[arg(index = 1, value_parser = parsers::account_name)]
+    pub organization: odf::AccountName,
+}
+
+/// Removes an account from the organization
+#[derive(Debug, clap::Args)]
+pub struct OrgRemoveMember {
+    /// Name of the organization
+    #[arg(index = 1, value_parser = parsers::account_name)]
+    pub organization: odf::AccountName,
+
+    /// Name of the member account
+    #[arg(index = 2, value_parser = parsers::account_name)]
+    pub account: odf::AccountName,
+}
+
+/// Adds an account to the organization or changes the role of a member
+#[derive(Debug, clap::Args)]
+pub struct OrgSetRole {
+    /// Name of the organization
+    #[arg(index = 1, value_parser = parsers::account_name)]
+    pub organization: odf::AccountName,
+
+    /// Name of the member account
+    #[arg(index = 2, value_parser = parsers::account_name)]
+    pub account: odf::AccountName,
+
+    /// Role of the account within the organization
+    #[arg(index = 3, value_enum)]
+    pub role: parsers::OrganizationRole,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Pull new data into the datasets
 #[derive(Debug, clap::Args)]
 #[command(after_help = r#"
@@ -840,6 +1137,12 @@ pub struct Pull {
     #[arg(long)]
     pub fetch_uncacheable: bool,
 
+    /// Allow additive schema evolution (new nullable columns, wider numeric
+    /// types, relaxed non-null constraints) if new data does not match the
+    /// schema of the dataset
+    #[arg(long)]
+    pub evolve_schema: bool,
+
     /// Local name of a dataset to use when syncing from a repository
     #[arg(long, value_name = "NAME", value_parser = parsers::dataset_name)]
     pub r#as: Option<odf::DatasetName>,
@@ -1149,6 +1452,8 @@ pub struct RepoAliasList {
 #[command(after_help = r#"
 Search is delegated to the repository implementations and its capabilities depend on the type of the repo. Whereas smart repos may support advanced full-text search, simple storage-only repos may be limited to a substring search by dataset name.
 
+When `--local` flag is specified the datasets in the local workspace are searched instead. Local search matches the terms against dataset names, descriptions, keywords, column names, licenses, and attachments, and orders results by relevance.
+
 **Examples:**
 
 Search all repositories:
@@ -1158,6 +1463,10 @@ Search all repositories:
 Search only specific repositories:
 
     kamu search covid19 --repo kamu --repo statcan.gc.ca
+
+Search root datasets in the local workspace that are tagged with a keyword:
+
+    kamu search covid19 --local --kind root --keyword health
 "#)]
 pub struct Search {
     /// Format to display the results in
@@ -1165,9 +1474,38 @@ pub struct Search {
     pub output_format: Option<OutputFormat>,
 
     /// Repository name(s) to search in
-    #[arg(long, value_parser = parsers::repo_name)]
+    #[arg(long, value_parser = parsers::repo_name, conflicts_with = "local")]
     pub repo: Option<Vec<odf::RepoName>>,
 
+    /// Search datasets in the local workspace instead of remote repositories
+    #[arg(long)]
+    pub local: bool,
+
+    /// Only include datasets of the specified kind(s)
+    #[arg(long, value_name = "KIND", value_enum)]
+    pub kind: Option<Vec<parsers::DatasetKind>>,
+
+    /// Only include datasets owned by the specified account
+    #[arg(long, value_name = "ACC", value_parser = parsers::account_name)]
+    pub owner: Option<odf::AccountName>,
+
+    /// Only include datasets that have all the specified keyword(s)
+    #[arg(long, value_name = "KW")]
+    pub keyword: Option<Vec<String>>,
+
+    /// Fetch dataset descriptions from remote repositories (requires a
+    /// recent version of the remote node)
+    #[arg(long, conflicts_with = "local")]
+    pub with_description: bool,
+
+    /// Number of results to display (local search only)
+    #[arg(long, short = 'n', default_value_t = 50, value_name = "NUM")]
+    pub num_results: usize,
+
+    /// Number of top results to skip (local search only)
+    #[arg(long, short = 's', default_value_t = 0, value_name = "SKP")]
+    pub skip_results: usize,
+
     /// Search terms
     #[arg()]
     pub query: Option<String>,
@@ -1190,6 +1528,11 @@ Execute SQL command and return its output in CSV format:
 
     kamu sql -c 'SELECT * FROM `org.example.data` LIMIT 10' -o csv
 
+Query the state of a dataset as of a specific block or point in time:
+
+    kamu sql -c "SELECT * FROM \"org.example.data\" AS OF BLOCK 'f1620...'"
+    kamu sql -c "SELECT * FROM \"org.example.data\" AS OF SYSTEM TIME '2024-01-01'"
+
 Run SQL server to use with external data processing tools:
 
     kamu sql server --address 0.0.0.0 --port 8080
@@ -1272,6 +1615,8 @@ pub enum SystemSubCommand {
     Gc(SystemGc),
     Info(SystemInfo),
     Ipfs(SystemIpfs),
+    Partition(SystemPartition),
+    Statistics(SystemStatistics),
     UpgradeWorkspace(SystemUpgradeWorkspace),
 }
 
@@ -1356,6 +1701,10 @@ Hard compactions rewrite the history of the dataset as if data was originally wr
 Perform a history-altering hard compaction:
 
     kamu system compact --hard my.dataset
+
+Perform a soft compaction that keeps the history intact:
+
+    kamu system compact --soft my.dataset
 "#)]
 pub struct SystemCompact {
     /// Maximum size of a single data slice file in bytes
@@ -1367,9 +1716,14 @@ pub struct SystemCompact {
     pub max_slice_records: u64,
 
     /// Perform 'hard' compaction that rewrites the history of a dataset
-    #[arg(long)]
+    #[arg(long, conflicts_with = "soft")]
     pub hard: bool,
 
+    /// Perform 'soft' compaction that merges data slices without altering the
+    /// history of a dataset
+    #[arg(long)]
+    pub soft: bool,
+
     /// Perform compaction without saving data blocks
     #[arg(long)]
     pub keep_metadata_only: bool,
@@ -1425,6 +1779,17 @@ pub struct SystemGc {}
 // TODO: This command is temporary and likely will be removed soon
 /// Generate a platform token from a known secret for debugging
 #[derive(Debug, clap::Args)]
+#[command(after_help = r#"
+**Examples:**
+
+Generate a token for a predefined account:
+
+    kamu system generate-token --login kamu
+
+Generate a token that can only read datasets and trigger flows of one dataset:
+
+    kamu system generate-token --login kamu --scope read --scope flow-trigger:did:odf:fed0...
+"#)]
 pub struct SystemGenerateToken {
     /// Account ID to generate token for
     #[arg(long)]
@@ -1437,6 +1802,12 @@ pub struct SystemGenerateToken {
     /// Token expiration time in seconds
     #[arg(long, default_value_t = 3600)]
     pub expiration_time_sec: usize,
+
+    /// Restrict the token to a scope (read, write[:<dataset-id>],
+    /// flow-trigger[:<dataset-id>], admin), can be specified multiple times.
+    /// Without scopes the token carries the full power of the account
+    #[arg(long = "scope", value_name = "SCOPE", value_parser = parsers::access_token_scope)]
+    pub scopes: Vec<kamu_accounts::AccessTokenScope>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1471,6 +1842,84 @@ pub struct SystemIpfsAdd {
     pub dataset: odf::DatasetRef,
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Configure partitioning of the dataset data slices
+#[derive(Debug, clap::Args)]
+#[command(after_help = r#"
+Partitioning indexes the values of a column in every new data slice, recording which values each row group of the slice file contains. Queries that filter on this column can then skip reading the files and the row groups that cannot contain matching records.
+
+Data slices referenced by the metadata chain are left intact, so partitioning does not affect the hashes of blocks and the dataset remains verifiable. The index is stored alongside the metadata and applies only to the data added after partitioning was enabled. Changing the partitioning column discards the index of the existing slices.
+
+**Examples:**
+
+Partition data by the day of the event time:
+
+    kamu system partition my.dataset --by event_time --day
+
+Partition data by the values of a low-cardinality column:
+
+    kamu system partition my.dataset --by country
+
+Disable partitioning:
+
+    kamu system partition my.dataset --clear
+"#)]
+pub struct SystemPartition {
+    /// Column to partition the data by
+    #[arg(long, value_name = "COLUMN", required_unless_present = "clear")]
+    pub by: Option<String>,
+
+    /// Group timestamp or date values of the column by the calendar day
+    #[arg(long, requires = "by")]
+    pub day: bool,
+
+    /// Disable partitioning of the dataset
+    #[arg(long, conflicts_with = "by")]
+    pub clear: bool,
+
+    /// Local dataset reference
+    #[arg(value_parser = parsers::dataset_ref)]
+    pub dataset: odf::DatasetRef,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Configure the index of column statistics of the dataset data slices
+#[derive(Debug, clap::Args)]
+#[command(after_help = r#"
+The index stores min / max values and null counts of every column per data slice, and optionally bloom filters of the values of selected columns. Queries use it to skip the data slices that cannot contain matching records without opening their Parquet files, which speeds up point lookups on datasets with many slices.
+
+Statistics are collected when new data is committed or compacted. Statistics of the existing data are collected by this command when it enables the index. Queries only read the statistics and read the data slices without them in full.
+
+**Examples:**
+
+Enable min / max statistics:
+
+    kamu system statistics my.dataset
+
+Enable statistics with bloom filters for point lookups by `id` column:
+
+    kamu system statistics my.dataset --bloom-filter id
+
+Remove the index:
+
+    kamu system statistics my.dataset --clear
+"#)]
+pub struct SystemStatistics {
+    /// Column to build a bloom filter of values for
+    #[arg(long, value_name = "COLUMN")]
+    pub bloom_filter: Vec<String>,
+
+    /// Remove the index
+    #[arg(long, conflicts_with = "bloom_filter")]
+    pub clear: bool,
+
+    /// Local dataset reference
+    #[arg(value_parser = parsers::dataset_ref)]
+    pub dataset: odf::DatasetRef,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Upgrade the layout of a local workspace to the latest version
@@ -1597,3 +2046,70 @@ pub struct Version {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Manage webhook notifications
+#[derive(Debug, clap::Args)]
+#[command(after_help = r#"
+Webhooks notify external HTTPS endpoints about flow outcomes and dataset lifecycle events. Each request is signed with a secret generated upon subscription, so that the receiver can verify that notifications originate from this node. Deliveries are only performed while the API server is running.
+
+**Examples:**
+
+Get notified when any flow of your datasets fails:
+
+    kamu webhook add https://example.com/hooks/kamu --event flow-failed
+
+Get notified about new data in a specific dataset:
+
+    kamu webhook add https://example.com/hooks/kamu --event dataset-head-updated --dataset org.example.data
+
+List subscriptions:
+
+    kamu webhook list
+"#)]
+pub struct Webhook {
+    #[command(subcommand)]
+    pub subcommand: WebhookSubCommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum WebhookSubCommand {
+    Add(WebhookAdd),
+    Delete(WebhookDelete),
+    List(WebhookList),
+}
+
+/// Subscribes an endpoint to event notifications
+#[derive(Debug, clap::Args)]
+pub struct WebhookAdd {
+    /// Type(s) of events to be notified about
+    #[arg(long, value_name = "EVENT", value_enum, required = true)]
+    pub event: Vec<parsers::WebhookEventType>,
+
+    /// Only notify about events of the specified dataset
+    #[arg(long, value_parser = parsers::dataset_ref)]
+    pub dataset: Option<odf::DatasetRef>,
+
+    /// URL of the endpoint that will receive notifications
+    #[arg(index = 1)]
+    pub url: String,
+}
+
+/// Deletes webhook subscription(s)
+#[derive(Debug, clap::Args)]
+#[command(visible_alias = "rm")]
+pub struct WebhookDelete {
+    /// Subscription ID(s)
+    #[arg(required = true)]
+    pub subscription: Vec<uuid::Uuid>,
+}
+
+/// Lists webhook subscriptions
+#[derive(Debug, clap::Args)]
+#[command(visible_alias = "ls")]
+pub struct WebhookList {
+    /// Format to display the results in
+    #[arg(long, short = 'o', value_name = "FMT", value_enum)]
+    pub output_format: Option<OutputFormat>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -95,6 +95,41 @@ pub fn get_command(
             c.all,
             c.recursive,
         )),
+        cli::Command::Diff(c) => Box::new(DiffCommand::new(
+            cli_catalog.get_one()?,
+            validate_dataset_ref(cli_catalog, c.dataset)?,
+            c.other_dataset
+                .map(|r| validate_dataset_ref(cli_catalog, r))
+                .transpose()?,
+            c.old,
+            c.new,
+            c.primary_key,
+            cli_catalog.get_one()?,
+        )),
+        cli::Command::Export(c) => match c.subcommand {
+            Some(cli::ExportSubCommand::Data(sc)) => Box::new(ExportDataCommand::new(
+                cli_catalog.get_one()?,
+                sc.dataset
+                    .map(|r| validate_dataset_ref(cli_catalog, r))
+                    .transpose()?,
+                sc.sql,
+                sc.to,
+                sc.format.into(),
+                sc.compression.map(Into::into),
+                sc.partition_by,
+                sc.incremental,
+            )),
+            None => Box::new(ExportCommand::new(
+                cli_catalog.get_one()?,
+                validate_dataset_ref(cli_catalog, c.dataset.unwrap())?,
+                c.archive.unwrap(),
+            )),
+        },
+        cli::Command::Import(c) => Box::new(ImportCommand::new(
+            cli_catalog.get_one()?,
+            c.archive,
+            c.name,
+        )),
         cli::Command::Ingest(c) => Box::new(IngestCommand::new(
             cli_catalog.get_one()?,
             cli_catalog.get_one()?,
@@ -108,6 +143,7 @@ pub fn get_command(
             c.stdin,
             c.recursive,
             c.input_format,
+            c.evolve_schema,
         )),
         cli::Command::Init(c) => {
             if c.pull_images {
@@ -133,9 +169,16 @@ pub fn get_command(
                 cli_catalog.get_one()?,
                 validate_many_dataset_refs(cli_catalog, sc.dataset)?,
                 sc.browse,
+                sc.columns,
                 sc.output_format,
                 cli_catalog.get_one()?,
             )),
+            cli::InspectSubCommand::Provenance(sc) => Box::new(InspectProvenanceCommand::new(
+                cli_catalog.get_one()?,
+                validate_dataset_ref(cli_catalog, sc.dataset)?,
+                sc.offset,
+                cli_catalog.get_one()?,
+            )),
             cli::InspectSubCommand::Query(sc) => Box::new(InspectQueryCommand::new(
                 cli_catalog.get_one()?,
                 cli_catalog.get_one()?,
@@ -249,6 +292,29 @@ pub fn get_command(
             c.http_port,
             c.env.unwrap_or_default(),
         )),
+        cli::Command::Org(c) => match c.subcommand {
+            cli::OrgSubCommand::Members(sc) => Box::new(OrgMembersCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                sc.organization,
+            )),
+            cli::OrgSubCommand::RemoveMember(sc) => Box::new(OrgRemoveMemberCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                sc.organization,
+                sc.account,
+            )),
+            cli::OrgSubCommand::SetRole(sc) => Box::new(OrgSetRoleCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                sc.organization,
+                sc.account,
+                sc.role.into(),
+            )),
+        },
         cli::Command::Pull(c) => {
             if let Some(set_watermark) = c.set_watermark {
                 Box::new(SetWatermarkCommand::new(
@@ -271,6 +337,7 @@ pub fn get_command(
                     c.all,
                     c.recursive,
                     c.fetch_uncacheable,
+                    c.evolve_schema,
                     c.r#as,
                     !c.no_alias,
                     c.force,
@@ -349,6 +416,20 @@ pub fn get_command(
             cli_catalog.get_one()?,
             c.query,
             c.repo.unwrap_or_default(),
+            c.local,
+            kamu::domain::SearchFilters {
+                kinds: c
+                    .kind
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                owner: c.owner,
+                keywords: c.keyword.unwrap_or_default(),
+            },
+            c.with_description,
+            c.skip_results,
+            c.num_results,
         )),
         cli::Command::Sql(c) => match c.subcommand {
             None => Box::new(SqlShellCommand::new(
@@ -431,6 +512,7 @@ pub fn get_command(
                 sc.max_slice_size,
                 sc.max_slice_records,
                 sc.hard,
+                sc.soft,
                 sc.verify,
                 sc.keep_metadata_only,
             )),
@@ -454,6 +536,7 @@ pub fn get_command(
                 sc.login,
                 sc.subject,
                 sc.expiration_time_sec,
+                sc.scopes,
             )),
             cli::SystemSubCommand::Info(sc) => Box::new(SystemInfoCommand::new(
                 cli_catalog.get_one()?,
@@ -467,6 +550,19 @@ pub fn get_command(
                     ssc.dataset,
                 )),
             },
+            cli::SystemSubCommand::Partition(sc) => Box::new(SystemPartitionCommand::new(
+                cli_catalog.get_one()?,
+                validate_dataset_ref(cli_catalog, sc.dataset)?,
+                sc.by,
+                sc.day,
+                sc.clear,
+            )),
+            cli::SystemSubCommand::Statistics(sc) => Box::new(SystemStatisticsCommand::new(
+                cli_catalog.get_one()?,
+                validate_dataset_ref(cli_catalog, sc.dataset)?,
+                sc.bloom_filter,
+                sc.clear,
+            )),
             cli::SystemSubCommand::UpgradeWorkspace(_) => {
                 Box::new(UpgradeWorkspaceCommand::new(cli_catalog.get_one()?))
             }
@@ -516,6 +612,28 @@ pub fn get_command(
         cli::Command::Version(c) => {
             Box::new(VersionCommand::new(cli_catalog.get_one()?, c.output_format))
         }
+        cli::Command::Webhook(c) => match c.subcommand {
+            cli::WebhookSubCommand::Add(sc) => Box::new(WebhookAddCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                sc.url,
+                sc.event.into_iter().map(Into::into),
+                sc.dataset,
+            )),
+            cli::WebhookSubCommand::Delete(sc) => Box::new(WebhookDeleteCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                sc.subscription,
+            )),
+            cli::WebhookSubCommand::List(_) => Box::new(WebhookListCommand::new(
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+                cli_catalog.get_one()?,
+            )),
+        },
     };
 
     Ok(command)
@@ -530,8 +648,12 @@ pub fn command_needs_transaction(args: &cli::Cli) -> bool {
         },
         cli::Command::Add(_)
         | cli::Command::Delete(_)
+        | cli::Command::Import(_)
+        | cli::Command::Org(_)
         | cli::Command::Rename(_)
-        | cli::Command::Pull(_) => true,
+        | cli::Command::Reset(_)
+        | cli::Command::Pull(_)
+        | cli::Command::Webhook(_) => true,
         _ => false,
     }
 }
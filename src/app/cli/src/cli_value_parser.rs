@@ -52,6 +52,15 @@ pub(crate) fn dataset_name(s: &str) -> Result<odf::DatasetName, String> {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub(crate) fn account_name(s: &str) -> Result<odf::AccountName, String> {
+    match odf::AccountName::try_from(s) {
+        Ok(v) => Ok(v),
+        Err(_) => Err("Account name can only contain alphanumerics and dashes".to_string()),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub(crate) fn dataset_ref(s: &str) -> Result<odf::DatasetRef, String> {
     match odf::DatasetRef::try_from(s) {
         Ok(v) => Ok(v),
@@ -106,6 +115,17 @@ pub(crate) fn multihash(s: &str) -> Result<odf::Multihash, String> {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub(crate) fn access_token_scope(s: &str) -> Result<kamu_accounts::AccessTokenScope, String> {
+    match kamu_accounts::AccessTokenScope::from_str(s) {
+        Ok(v) => Ok(v),
+        Err(_) => Err("Scope should be one of: read, write[:<dataset-id>], \
+                       flow-trigger[:<dataset-id>], admin"
+            .to_string()),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub(crate) fn log_filter(s: &str) -> Result<String, String> {
     let items: Vec<_> = s.split(',').collect();
     for item in items {
@@ -183,3 +203,102 @@ impl From<DatasetVisibility> for kamu::domain::DatasetVisibility {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum DatasetKind {
+    Root,
+    Derivative,
+}
+
+impl From<DatasetKind> for odf::DatasetKind {
+    fn from(value: DatasetKind) -> Self {
+        match value {
+            DatasetKind::Root => odf::DatasetKind::Root,
+            DatasetKind::Derivative => odf::DatasetKind::Derivative,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ExportDataFormat {
+    Parquet,
+    Csv,
+    Ndjson,
+    Arrow,
+}
+
+impl From<ExportDataFormat> for kamu::domain::DataExportFormat {
+    fn from(value: ExportDataFormat) -> Self {
+        match value {
+            ExportDataFormat::Parquet => kamu::domain::DataExportFormat::Parquet,
+            ExportDataFormat::Csv => kamu::domain::DataExportFormat::Csv,
+            ExportDataFormat::Ndjson => kamu::domain::DataExportFormat::NdJson,
+            ExportDataFormat::Arrow => kamu::domain::DataExportFormat::Arrow,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ExportDataCompression {
+    Gzip,
+    Zstd,
+}
+
+impl From<ExportDataCompression> for kamu::domain::DataExportCompression {
+    fn from(value: ExportDataCompression) -> Self {
+        match value {
+            ExportDataCompression::Gzip => kamu::domain::DataExportCompression::Gzip,
+            ExportDataCompression::Zstd => kamu::domain::DataExportCompression::Zstd,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum OrganizationRole {
+    Owner,
+    Maintainer,
+    Member,
+}
+
+impl From<OrganizationRole> for kamu_auth_rebac::AccountToOrganizationRelation {
+    fn from(value: OrganizationRole) -> Self {
+        match value {
+            OrganizationRole::Owner => kamu_auth_rebac::AccountToOrganizationRelation::Owner,
+            OrganizationRole::Maintainer => {
+                kamu_auth_rebac::AccountToOrganizationRelation::Maintainer
+            }
+            OrganizationRole::Member => kamu_auth_rebac::AccountToOrganizationRelation::Member,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum WebhookEventType {
+    FlowSucceeded,
+    FlowFailed,
+    DatasetHeadUpdated,
+    DatasetCreated,
+    DatasetDeleted,
+}
+
+impl From<WebhookEventType> for kamu_webhooks::WebhookEventType {
+    fn from(value: WebhookEventType) -> Self {
+        match value {
+            WebhookEventType::FlowSucceeded => kamu_webhooks::WebhookEventType::FlowSucceeded,
+            WebhookEventType::FlowFailed => kamu_webhooks::WebhookEventType::FlowFailed,
+            WebhookEventType::DatasetHeadUpdated => {
+                kamu_webhooks::WebhookEventType::DatasetHeadUpdated
+            }
+            WebhookEventType::DatasetCreated => kamu_webhooks::WebhookEventType::DatasetCreated,
+            WebhookEventType::DatasetDeleted => kamu_webhooks::WebhookEventType::DatasetDeleted,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -11,6 +11,7 @@ use std::sync::Arc;
 
 use futures::TryStreamExt as _;
 use kamu::domain::{
+    CompactionMode,
     CompactionOptions,
     CompactionService,
     DatasetRepository,
@@ -38,6 +39,7 @@ pub struct CompactCommand {
     max_slice_size: u64,
     max_slice_records: u64,
     is_hard: bool,
+    is_soft: bool,
     is_verify: bool,
     keep_metadata_only: bool,
 }
@@ -52,6 +54,7 @@ impl CompactCommand {
         max_slice_size: u64,
         max_slice_records: u64,
         is_hard: bool,
+        is_soft: bool,
         is_verify: bool,
         keep_metadata_only: bool,
     ) -> Self {
@@ -64,6 +67,7 @@ impl CompactCommand {
             max_slice_size,
             max_slice_records,
             is_hard,
+            is_soft,
             is_verify,
             keep_metadata_only,
         }
@@ -100,9 +104,19 @@ impl Command for CompactCommand {
             return Err(CLIError::usage_error("Specify a dataset or a pattern"));
         }
 
-        if !self.is_hard {
+        let mode = match (self.is_hard, self.is_soft) {
+            (true, false) => CompactionMode::Hard,
+            (false, true) => CompactionMode::Soft,
+            _ => {
+                return Err(CLIError::usage_error(
+                    "Specify the type of compaction with either --hard or --soft",
+                ))
+            }
+        };
+
+        if mode == CompactionMode::Soft && self.keep_metadata_only {
             return Err(CLIError::usage_error(
-                "Soft compactions are not yet supported",
+                "--keep-metadata-only is only supported by hard compactions",
             ));
         }
 
@@ -115,15 +129,17 @@ impl Command for CompactCommand {
             .await?
         };
 
-        self.interact.require_confirmation(format!(
-            "{}\n  {}\n{}",
-            console::style(
-                "You are about to perform a hard compaction of the following dataset(s):"
-            )
-            .yellow(),
-            itertools::join(dataset_handles.iter().map(|h| &h.alias), "\n  "),
-            console::style("This operation is history-altering and irreversible!").yellow(),
-        ))?;
+        if mode == CompactionMode::Hard {
+            self.interact.require_confirmation(format!(
+                "{}\n  {}\n{}",
+                console::style(
+                    "You are about to perform a hard compaction of the following dataset(s):"
+                )
+                .yellow(),
+                itertools::join(dataset_handles.iter().map(|h| &h.alias), "\n  "),
+                console::style("This operation is history-altering and irreversible!").yellow(),
+            ))?;
+        }
 
         if self.is_verify {
             for hdl in &dataset_handles {
@@ -153,6 +169,7 @@ impl Command for CompactCommand {
                     max_slice_size: Some(self.max_slice_size),
                     max_slice_records: Some(self.max_slice_records),
                     keep_metadata_only: self.keep_metadata_only,
+                    mode,
                 },
                 Some(listener.clone()),
             )
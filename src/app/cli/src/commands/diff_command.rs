@@ -0,0 +1,90 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{operation_type_column_format, CLIError, Command};
+use crate::output::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DiffCommand {
+    diff_svc: Arc<dyn DatasetDiffService>,
+    dataset_ref: DatasetRef,
+    other_dataset_ref: Option<DatasetRef>,
+    old_head: Option<Multihash>,
+    new_head: Option<Multihash>,
+    primary_key: Option<Vec<String>>,
+    output_cfg: Arc<OutputConfig>,
+}
+
+impl DiffCommand {
+    pub fn new(
+        diff_svc: Arc<dyn DatasetDiffService>,
+        dataset_ref: DatasetRef,
+        other_dataset_ref: Option<DatasetRef>,
+        old_head: Option<Multihash>,
+        new_head: Option<Multihash>,
+        primary_key: Option<Vec<String>>,
+        output_cfg: Arc<OutputConfig>,
+    ) -> Self {
+        Self {
+            diff_svc,
+            dataset_ref,
+            other_dataset_ref,
+            old_head,
+            new_head,
+            primary_key,
+            output_cfg,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for DiffCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let options = DiffOptions {
+            primary_key: self.primary_key.clone(),
+        };
+
+        let res = if let Some(other_dataset_ref) = &self.other_dataset_ref {
+            self.diff_svc
+                .diff_datasets(&self.dataset_ref, other_dataset_ref, options)
+                .await
+        } else {
+            self.diff_svc
+                .diff_blocks(
+                    &self.dataset_ref,
+                    self.old_head.as_ref(),
+                    self.new_head.as_ref(),
+                    options,
+                )
+                .await
+        };
+
+        let df = match res {
+            Ok(res) => res.df,
+            Err(DiffError::Internal(e)) => return Err(CLIError::critical(e)),
+            Err(e) => return Err(CLIError::failure(e)),
+        };
+
+        let mut writer = self.output_cfg.get_records_writer(
+            df.schema().as_arrow(),
+            RecordsFormat::default().with_column_formats(vec![operation_type_column_format()]),
+        );
+
+        let record_batches = df.collect().await.map_err(CLIError::failure)?;
+        writer.write_batches(&record_batches)?;
+        writer.finish()?;
+        Ok(())
+    }
+}
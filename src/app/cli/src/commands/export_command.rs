@@ -0,0 +1,69 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ExportCommand {
+    dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+    dataset_ref: DatasetRef,
+    archive_path: PathBuf,
+}
+
+impl ExportCommand {
+    pub fn new(
+        dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+        dataset_ref: DatasetRef,
+        archive_path: PathBuf,
+    ) -> Self {
+        Self {
+            dataset_archive_svc,
+            dataset_ref,
+            archive_path,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for ExportCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let result = match self
+            .dataset_archive_svc
+            .export_archive(&self.dataset_ref, &self.archive_path)
+            .await
+        {
+            Ok(result) => Ok(result),
+            Err(ExportArchiveError::DatasetNotFound(e)) => Err(CLIError::failure(e)),
+            Err(ExportArchiveError::Access(e)) => Err(CLIError::failure(e)),
+            Err(e) => Err(CLIError::critical(e)),
+        }?;
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Exported {} files ({}) of dataset {} into {}",
+                result.manifest.entries.len(),
+                humansize::format_size(result.manifest.total_size(), humansize::BINARY),
+                result.dataset_handle.alias,
+                result.archive_path.display(),
+            ))
+            .green()
+            .bold()
+        );
+
+        Ok(())
+    }
+}
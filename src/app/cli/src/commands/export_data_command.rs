@@ -0,0 +1,126 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ExportDataCommand {
+    data_export_svc: Arc<dyn DataExportService>,
+    dataset_ref: Option<DatasetRef>,
+    sql: Option<String>,
+    destination: String,
+    format: DataExportFormat,
+    compression: Option<DataExportCompression>,
+    partition_by: Vec<String>,
+    incremental: bool,
+}
+
+impl ExportDataCommand {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        data_export_svc: Arc<dyn DataExportService>,
+        dataset_ref: Option<DatasetRef>,
+        sql: Option<String>,
+        destination: String,
+        format: DataExportFormat,
+        compression: Option<DataExportCompression>,
+        partition_by: Vec<String>,
+        incremental: bool,
+    ) -> Self {
+        Self {
+            data_export_svc,
+            dataset_ref,
+            sql,
+            destination,
+            format,
+            compression,
+            partition_by,
+            incremental,
+        }
+    }
+
+    fn parse_destination(&self) -> Result<url::Url, CLIError> {
+        // Anything that doesn't look like a URL is treated as a local directory
+        if let Ok(url) = url::Url::parse(&self.destination)
+            && url.scheme().len() > 1
+        {
+            return Ok(url);
+        }
+
+        let path = PathBuf::from(&self.destination);
+        std::fs::create_dir_all(&path).map_err(|e| {
+            CLIError::usage_error(format!("Invalid destination {}: {e}", self.destination))
+        })?;
+        let path = path.canonicalize().map_err(|e| {
+            CLIError::usage_error(format!("Invalid destination {}: {e}", self.destination))
+        })?;
+
+        url::Url::from_directory_path(path)
+            .map_err(|_| CLIError::usage_error(format!("Invalid destination {}", self.destination)))
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for ExportDataCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let source = match (&self.dataset_ref, &self.sql) {
+            (Some(dataset_ref), None) => DataExportSource::Dataset(dataset_ref.clone()),
+            (None, Some(sql)) => DataExportSource::Sql(sql.clone()),
+            _ => {
+                return Err(CLIError::usage_error(
+                    "Specify either a dataset or an SQL statement",
+                ))
+            }
+        };
+
+        let options = DataExportOptions {
+            destination: self.parse_destination()?,
+            format: self.format,
+            compression: self.compression,
+            partition_by: self.partition_by.clone(),
+            incremental: self.incremental,
+        };
+
+        let result = match self.data_export_svc.export_data(source, options).await {
+            Ok(result) => Ok(result),
+            Err(DataExportError::DatasetNotFound(e)) => Err(CLIError::failure(e)),
+            Err(DataExportError::InvalidOptions(e)) => Err(CLIError::usage_error_from(e)),
+            Err(DataExportError::PositionNotFound(e)) => Err(CLIError::failure(e)),
+            Err(DataExportError::Query(e)) => Err(CLIError::failure(e)),
+            Err(DataExportError::Access(e)) => Err(CLIError::failure(e)),
+            Err(e) => Err(CLIError::critical(e)),
+        }?;
+
+        match result {
+            DataExportResult::UpToDate => {
+                eprintln!("{}", console::style("No new data to export").yellow());
+            }
+            DataExportResult::Exported { num_records, .. } => {
+                eprintln!(
+                    "{}",
+                    console::style(format!(
+                        "Exported {num_records} records to {}",
+                        self.destination
+                    ))
+                    .green()
+                    .bold()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ImportCommand {
+    dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+    archive_path: PathBuf,
+    dataset_name: Option<DatasetName>,
+}
+
+impl ImportCommand {
+    pub fn new(
+        dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+        archive_path: PathBuf,
+        dataset_name: Option<DatasetName>,
+    ) -> Self {
+        Self {
+            dataset_archive_svc,
+            archive_path,
+            dataset_name,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for ImportCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        if !self.archive_path.is_file() {
+            return Err(CLIError::usage_error(format!(
+                "Archive {} does not exist",
+                self.archive_path.display()
+            )));
+        }
+
+        let dataset_alias = self
+            .dataset_name
+            .clone()
+            .map(|name| DatasetAlias::new(None, name));
+
+        let result = match self
+            .dataset_archive_svc
+            .import_archive(&self.archive_path, dataset_alias)
+            .await
+        {
+            Ok(result) => Ok(result),
+            Err(ImportArchiveError::InvalidArchive(e)) => Err(CLIError::failure(e)),
+            Err(ImportArchiveError::IntegrityCheckFailed(e)) => Err(CLIError::failure(e)),
+            Err(ImportArchiveError::HeadMismatch(e)) => Err(CLIError::failure(e)),
+            Err(ImportArchiveError::Sync(e)) => Err(CLIError::failure(e)),
+            Err(ImportArchiveError::Verification(e)) => Err(CLIError::failure(e)),
+            Err(e) => Err(CLIError::critical(e)),
+        }?;
+
+        let msg = match result.sync_result {
+            SyncResult::UpToDate => {
+                format!("Dataset {} is up-to-date", result.dataset_handle.alias)
+            }
+            SyncResult::Updated { num_blocks, .. } => format!(
+                "Imported dataset {} ({num_blocks} blocks)",
+                result.dataset_handle.alias
+            ),
+        };
+
+        eprintln!("{}", console::style(msg).green().bold());
+
+        Ok(())
+    }
+}
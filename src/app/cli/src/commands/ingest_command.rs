@@ -36,6 +36,7 @@ pub struct IngestCommand {
     stdin: bool,
     recursive: bool,
     input_format: Option<String>,
+    evolve_schema: bool,
 }
 
 impl IngestCommand {
@@ -52,6 +53,7 @@ impl IngestCommand {
         stdin: bool,
         recursive: bool,
         input_format: Option<impl Into<String>>,
+        evolve_schema: bool,
     ) -> Self
     where
         I: IntoIterator<Item = S>,
@@ -70,6 +72,7 @@ impl IngestCommand {
             stdin,
             recursive,
             input_format: input_format.map(Into::into),
+            evolve_schema,
         }
     }
 
@@ -202,6 +205,11 @@ impl Command for IngestCommand {
                         source_event_time,
                         auto_create_push_source: false,
                         schema_inference: SchemaInferenceOpts::default(),
+                        schema_evolution: if self.evolve_schema {
+                            SchemaEvolutionRules::all()
+                        } else {
+                            SchemaEvolutionRules::default()
+                        },
                     },
                     listener.clone(),
                 )
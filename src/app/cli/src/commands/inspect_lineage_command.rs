@@ -7,7 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -37,6 +37,7 @@ pub struct InspectLineageCommand {
     workspace_layout: Arc<WorkspaceLayout>,
     dataset_refs: Vec<DatasetRef>,
     browse: bool,
+    columns: bool,
     output_format: Option<LineageOutputFormat>,
     output_config: Arc<OutputConfig>,
 }
@@ -48,6 +49,7 @@ impl InspectLineageCommand {
         workspace_layout: Arc<WorkspaceLayout>,
         dataset_refs: I,
         browse: bool,
+        columns: bool,
         output_format: Option<LineageOutputFormat>,
         output_config: Arc<OutputConfig>,
     ) -> Self
@@ -60,11 +62,115 @@ impl InspectLineageCommand {
             workspace_layout,
             dataset_refs: dataset_refs.into_iter().collect(),
             browse,
+            columns,
             output_format,
             output_config,
         }
     }
 
+    fn get_effective_output_format(&self) -> LineageOutputFormat {
+        match self.output_format {
+            Some(fmt) => fmt,
+            None if !self.output_config.is_tty => LineageOutputFormat::Csv,
+            None if self.browse => LineageOutputFormat::Html,
+            None => LineageOutputFormat::Shell,
+        }
+    }
+
+    async fn get_column_lineage(
+        &self,
+        dataset_handles: Vec<DatasetHandle>,
+    ) -> Result<Vec<(DatasetHandle, ColumnLineage)>, CLIError> {
+        let mut visited = HashSet::new();
+        let mut queue = dataset_handles;
+        let mut res = Vec::new();
+
+        while let Some(hdl) = queue.pop() {
+            if !visited.insert(hdl.id.clone()) {
+                continue;
+            }
+
+            let Some(lineage) = self
+                .provenance_svc
+                .get_column_lineage(&hdl.as_local_ref())
+                .await
+                .map_err(CLIError::failure)?
+            else {
+                continue;
+            };
+
+            for source in lineage.columns.iter().flat_map(|c| &c.sources) {
+                if !visited.contains(&source.dataset.id) {
+                    queue.push(source.dataset.clone());
+                }
+            }
+
+            res.push((hdl, lineage));
+        }
+
+        res.sort_by(|a, b| a.0.alias.cmp(&b.0.alias));
+        Ok(res)
+    }
+
+    fn render_column_lineage(&self, lineage: &[(DatasetHandle, ColumnLineage)]) {
+        match self.get_effective_output_format() {
+            LineageOutputFormat::Shell => {
+                for (hdl, dataset_lineage) in lineage {
+                    println!("{}", console::style(&hdl.alias).bold());
+                    let num_columns = dataset_lineage.columns.len();
+                    for (i, column) in dataset_lineage.columns.iter().enumerate() {
+                        let prefix = if i + 1 == num_columns {
+                            "└── "
+                        } else {
+                            "├── "
+                        };
+                        let sources: Vec<_> = column
+                            .sources
+                            .iter()
+                            .map(|s| format!("{}.{}", s.dataset.alias, s.column))
+                            .collect();
+                        println!(
+                            "{prefix}{}{}",
+                            column.name,
+                            console::style(format!(" <- {}", sources.join(", "))).dim(),
+                        );
+                    }
+                }
+            }
+            LineageOutputFormat::Csv => {
+                println!("dataset,column,source_dataset,source_column");
+                for (hdl, dataset_lineage) in lineage {
+                    for column in &dataset_lineage.columns {
+                        for source in &column.sources {
+                            println!(
+                                "\"{}\",\"{}\",\"{}\",\"{}\"",
+                                hdl.alias, column.name, source.dataset.alias, source.column
+                            );
+                        }
+                    }
+                }
+            }
+            LineageOutputFormat::Dot => {
+                print!("{}", column_lineage_to_dot(lineage));
+            }
+            LineageOutputFormat::Html => {
+                let html = LINEAGE_HTML_TEMPLATE.replace(
+                    "<URL_ENCODED_DOT>",
+                    &urlencoding::encode(&column_lineage_to_dot(lineage)),
+                );
+
+                if self.output_format.is_none() {
+                    let temp_path = self.workspace_layout.run_info_dir.join("lineage.html");
+                    std::fs::write(&temp_path, html).unwrap();
+                    webbrowser::open(url::Url::from_file_path(&temp_path).unwrap().as_ref())
+                        .unwrap();
+                } else {
+                    print!("{html}");
+                }
+            }
+        }
+    }
+
     fn get_visitor(&self) -> Box<dyn LineageVisitor> {
         match self.output_format {
             None => {
@@ -109,6 +215,12 @@ impl Command for InspectLineageCommand {
 
         dataset_handles.sort_by(|a, b| a.alias.cmp(&b.alias));
 
+        if self.columns {
+            let lineage = self.get_column_lineage(dataset_handles).await?;
+            self.render_column_lineage(&lineage);
+            return Ok(());
+        }
+
         let mut visitor = self.get_visitor();
         visitor.begin();
         for dataset_handle in dataset_handles {
@@ -127,6 +239,52 @@ impl Command for InspectLineageCommand {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Column lineage
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn column_lineage_to_dot(lineage: &[(DatasetHandle, ColumnLineage)]) -> String {
+    // Group column nodes by dataset to render each dataset as a cluster
+    let mut nodes: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut edges = Vec::new();
+
+    for (hdl, dataset_lineage) in lineage {
+        for column in &dataset_lineage.columns {
+            nodes
+                .entry(hdl.alias.to_string())
+                .or_default()
+                .insert(column.name.clone());
+
+            for source in &column.sources {
+                nodes
+                    .entry(source.dataset.alias.to_string())
+                    .or_default()
+                    .insert(source.column.clone());
+
+                edges.push((
+                    format!("{}.{}", source.dataset.alias, source.column),
+                    format!("{}.{}", hdl.alias, column.name),
+                ));
+            }
+        }
+    }
+
+    let mut dot = String::new();
+    writeln!(dot, "digraph columns {{\nrankdir = LR;").unwrap();
+    for (i, (dataset, columns)) in nodes.iter().enumerate() {
+        writeln!(dot, "subgraph cluster_{i} {{\nlabel = \"{dataset}\";").unwrap();
+        for column in columns {
+            writeln!(dot, "\"{dataset}.{column}\" [label=\"{column}\"];").unwrap();
+        }
+        writeln!(dot, "}}").unwrap();
+    }
+    for (from, to) in edges {
+        writeln!(dot, "\"{from}\" -> \"{to}\";").unwrap();
+    }
+    writeln!(dot, "}}").unwrap();
+    dot
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Shell
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -267,6 +425,8 @@ impl LineageVisitor for CsvVisitor {
 // HTML
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+const LINEAGE_HTML_TEMPLATE: &str = include_str!("../../resources/lineage.html");
+
 struct HtmlVisitor<W: Write> {
     dot_visitor: DotVisitor<String, HtmlStyle>,
     writer: W,
@@ -283,8 +443,6 @@ impl<W: Write> HtmlVisitor<W> {
     fn unwrap(self) -> W {
         self.writer
     }
-
-    const TEMPLATE: &'static str = include_str!("../../resources/lineage.html");
 }
 
 impl<W: Write + Send> LineageVisitor for HtmlVisitor<W> {
@@ -307,7 +465,7 @@ impl<W: Write + Send> LineageVisitor for HtmlVisitor<W> {
         let dot = visitor.unwrap();
         let dot_encoded = urlencoding::encode(&dot);
 
-        let html = Self::TEMPLATE.replace("<URL_ENCODED_DOT>", &dot_encoded);
+        let html = LINEAGE_HTML_TEMPLATE.replace("<URL_ENCODED_DOT>", &dot_encoded);
         write!(self.writer, "{html}").unwrap();
     }
 }
@@ -0,0 +1,128 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use chrono::SecondsFormat;
+use console::style;
+use kamu::domain::*;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+use crate::output::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InspectProvenanceCommand {
+    provenance_svc: Arc<dyn ProvenanceService>,
+    dataset_ref: DatasetRef,
+    offset: u64,
+    output_config: Arc<OutputConfig>,
+}
+
+impl InspectProvenanceCommand {
+    pub fn new(
+        provenance_svc: Arc<dyn ProvenanceService>,
+        dataset_ref: DatasetRef,
+        offset: u64,
+        output_config: Arc<OutputConfig>,
+    ) -> Self {
+        Self {
+            provenance_svc,
+            dataset_ref,
+            offset,
+            output_config,
+        }
+    }
+
+    fn format_interval(interval: Option<&OffsetInterval>) -> String {
+        match interval {
+            Some(i) => format!("[{}, {}]", i.start, i.end),
+            None => "empty".to_string(),
+        }
+    }
+
+    fn format_block(hash: Option<&Multihash>) -> String {
+        match hash {
+            Some(h) => h.to_string(),
+            None => "-".to_string(),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for InspectProvenanceCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let provenance = self
+            .provenance_svc
+            .get_record_provenance(&self.dataset_ref, self.offset)
+            .await
+            .map_err(|e| match e {
+                GetRecordProvenanceError::Internal(e) => CLIError::critical(e),
+                _ => CLIError::failure(e),
+            })?;
+
+        println!(
+            "{}: {}",
+            style("Block").green(),
+            style(&provenance.block_hash).yellow(),
+        );
+        println!(
+            "{} {}",
+            style("As Of:").dim(),
+            provenance
+                .system_time
+                .to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        );
+        println!(
+            "{} {}",
+            style("Offsets:").dim(),
+            Self::format_interval(Some(&provenance.offset_interval))
+        );
+
+        if provenance.inputs.is_empty() {
+            println!(
+                "{}",
+                style("Record was added directly and has no inputs").dim()
+            );
+            return Ok(());
+        }
+
+        println!("{}", style("Inputs:").dim());
+
+        for input in provenance.inputs {
+            println!("  {}", style(&input.dataset.alias).bold());
+            println!(
+                "    {} ({}, {}]",
+                style("Blocks:").dim(),
+                Self::format_block(input.prev_block_hash.as_ref()),
+                Self::format_block(input.new_block_hash.as_ref()),
+            );
+            println!(
+                "    {} {}",
+                style("Offsets:").dim(),
+                Self::format_interval(input.offset_interval.as_ref())
+            );
+
+            if let Some(df) = input.possible_source_records {
+                println!("    {}", style("Possible Sources:").dim());
+
+                let mut writer = self
+                    .output_config
+                    .get_records_writer(df.schema().as_arrow(), RecordsFormat::default());
+
+                let record_batches = df.collect().await.map_err(CLIError::failure)?;
+                writer.write_batches(&record_batches)?;
+                writer.finish()?;
+            }
+        }
+
+        Ok(())
+    }
+}
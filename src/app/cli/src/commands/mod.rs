@@ -17,10 +17,15 @@ mod complete_command;
 mod completions_command;
 mod config_command;
 mod delete_command;
+mod diff_command;
+mod export_command;
+mod export_data_command;
 mod gc_command;
+mod import_command;
 mod ingest_command;
 mod init_command;
 mod inspect_lineage_command;
+mod inspect_provenance_command;
 mod inspect_query_command;
 mod inspect_schema_command;
 mod list_command;
@@ -30,6 +35,9 @@ mod login_silent_command;
 mod logout_command;
 mod new_dataset_command;
 mod notebook_command;
+mod org_members_command;
+mod org_remove_member_command;
+mod org_set_role_command;
 mod pull_command;
 mod pull_images_command;
 mod push_command;
@@ -54,10 +62,15 @@ mod system_e2e_command;
 mod system_generate_token_command;
 mod system_info_command;
 mod system_ipfs_add_command;
+mod system_partition_command;
+mod system_statistics_command;
 mod tail_command;
 mod ui_command;
 mod upgrade_workspace_command;
 mod verify_command;
+mod webhook_add_command;
+mod webhook_delete_command;
+mod webhook_list_command;
 
 pub use add_command::*;
 pub use alias_add_command::*;
@@ -68,10 +81,15 @@ pub use complete_command::*;
 pub use completions_command::*;
 pub use config_command::*;
 pub use delete_command::*;
+pub use diff_command::*;
+pub use export_command::*;
+pub use export_data_command::*;
 pub use gc_command::*;
+pub use import_command::*;
 pub use ingest_command::*;
 pub use init_command::*;
 pub use inspect_lineage_command::*;
+pub use inspect_provenance_command::*;
 pub use inspect_query_command::*;
 pub use inspect_schema_command::*;
 pub use list_command::*;
@@ -81,6 +99,9 @@ pub use login_silent_command::*;
 pub use logout_command::*;
 pub use new_dataset_command::*;
 pub use notebook_command::*;
+pub use org_members_command::*;
+pub use org_remove_member_command::*;
+pub use org_set_role_command::*;
 pub use pull_command::*;
 pub use pull_images_command::*;
 pub use push_command::*;
@@ -105,10 +126,15 @@ pub use system_e2e_command::*;
 pub use system_generate_token_command::*;
 pub use system_info_command::*;
 pub use system_ipfs_add_command::*;
+pub use system_partition_command::*;
+pub use system_statistics_command::*;
 pub use tail_command::*;
 pub use ui_command::*;
 pub use upgrade_workspace_command::*;
 pub use verify_command::*;
+pub use webhook_add_command::*;
+pub use webhook_delete_command::*;
+pub use webhook_list_command::*;
 
 pub use super::error::*;
 
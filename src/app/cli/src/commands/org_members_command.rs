@@ -0,0 +1,133 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use datafusion::arrow::array::{RecordBatch, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use internal_error::ResultIntoInternal;
+use kamu_accounts::{AccountRepository, AccountType, GetAccountByNameError};
+use kamu_auth_rebac::{OrganizationMembership, OrganizationMembershipService};
+use opendatafabric::AccountName;
+
+use super::{CLIError, Command};
+use crate::output::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct OrgMembersCommand {
+    membership_service: Arc<dyn OrganizationMembershipService>,
+    account_repo: Arc<dyn AccountRepository>,
+    output_config: Arc<OutputConfig>,
+    organization_name: AccountName,
+}
+
+impl OrgMembersCommand {
+    pub fn new(
+        membership_service: Arc<dyn OrganizationMembershipService>,
+        account_repo: Arc<dyn AccountRepository>,
+        output_config: Arc<OutputConfig>,
+        organization_name: AccountName,
+    ) -> Self {
+        Self {
+            membership_service,
+            account_repo,
+            output_config,
+            organization_name,
+        }
+    }
+
+    fn records_format(&self) -> RecordsFormat {
+        RecordsFormat::new()
+            .with_default_column_format(ColumnFormat::default())
+            .with_column_formats(vec![
+                ColumnFormat::new().with_style_spec("l"),
+                ColumnFormat::new().with_style_spec("l"),
+            ])
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            Field::new("Account", DataType::Utf8, false),
+            Field::new("Role", DataType::Utf8, false),
+        ]))
+    }
+
+    async fn records(
+        &self,
+        schema: Arc<Schema>,
+        members: &[OrganizationMembership],
+    ) -> Result<RecordBatch, CLIError> {
+        let mut col_account = Vec::new();
+        let mut col_role = Vec::new();
+
+        for member in members {
+            let account = self
+                .account_repo
+                .get_account_by_id(&member.account_id)
+                .await
+                .int_err()?;
+
+            col_account.push(account.account_name.to_string());
+            col_role.push(member.role.to_string());
+        }
+
+        let records = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(col_account)),
+                Arc::new(StringArray::from(col_role)),
+            ],
+        )
+        .int_err()?;
+
+        Ok(records)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for OrgMembersCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let organization = match self
+            .account_repo
+            .get_account_by_name(&self.organization_name)
+            .await
+        {
+            Ok(account) => account,
+            Err(e @ GetAccountByNameError::NotFound(_)) => return Err(CLIError::failure(e)),
+            Err(GetAccountByNameError::Internal(e)) => return Err(CLIError::critical(e)),
+        };
+
+        if organization.account_type != AccountType::Organization {
+            return Err(CLIError::usage_error(format!(
+                "Account {} is not an organization",
+                self.organization_name
+            )));
+        }
+
+        let members = self
+            .membership_service
+            .get_members(&organization.id)
+            .await?;
+
+        let schema = self.schema();
+        let records = self.records(schema.clone(), &members).await?;
+
+        let mut writer = self
+            .output_config
+            .get_records_writer(&schema, self.records_format());
+
+        writer.write_batch(&records)?;
+        writer.finish()?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
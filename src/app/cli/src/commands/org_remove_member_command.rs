@@ -0,0 +1,82 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu_accounts::{AccountRepository, CurrentAccountSubject};
+use kamu_auth_rebac::{OrganizationMembershipService, RemoveOrganizationMemberError};
+use opendatafabric::AccountName;
+
+use super::{managed_organization_id, resolve_account_id, CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct OrgRemoveMemberCommand {
+    membership_service: Arc<dyn OrganizationMembershipService>,
+    account_repo: Arc<dyn AccountRepository>,
+    current_account_subject: Arc<CurrentAccountSubject>,
+    organization_name: AccountName,
+    account_name: AccountName,
+}
+
+impl OrgRemoveMemberCommand {
+    pub fn new(
+        membership_service: Arc<dyn OrganizationMembershipService>,
+        account_repo: Arc<dyn AccountRepository>,
+        current_account_subject: Arc<CurrentAccountSubject>,
+        organization_name: AccountName,
+        account_name: AccountName,
+    ) -> Self {
+        Self {
+            membership_service,
+            account_repo,
+            current_account_subject,
+            organization_name,
+            account_name,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for OrgRemoveMemberCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let organization_id = managed_organization_id(
+            self.membership_service.as_ref(),
+            self.account_repo.as_ref(),
+            &self.current_account_subject,
+            &self.organization_name,
+        )
+        .await?;
+
+        let account_id = resolve_account_id(self.account_repo.as_ref(), &self.account_name).await?;
+
+        self.membership_service
+            .remove_member(&organization_id, &account_id)
+            .await
+            .map_err(|e| match e {
+                e @ (RemoveOrganizationMemberError::NotAMember(_)
+                | RemoveOrganizationMemberError::LastOwner(_)) => CLIError::failure(e),
+                RemoveOrganizationMemberError::Internal(e) => CLIError::critical(e),
+            })?;
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Removed account {} from organization {}",
+                self.account_name, self.organization_name
+            ))
+            .green()
+            .bold()
+        );
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
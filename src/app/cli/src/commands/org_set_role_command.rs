@@ -0,0 +1,144 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu_accounts::{AccountRepository, AccountType, CurrentAccountSubject, GetAccountByNameError};
+use kamu_auth_rebac::{
+    AccountToOrganizationRelation,
+    OrganizationMembershipService,
+    SetOrganizationMemberRoleError,
+};
+use opendatafabric::{AccountID, AccountName};
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct OrgSetRoleCommand {
+    membership_service: Arc<dyn OrganizationMembershipService>,
+    account_repo: Arc<dyn AccountRepository>,
+    current_account_subject: Arc<CurrentAccountSubject>,
+    organization_name: AccountName,
+    account_name: AccountName,
+    role: AccountToOrganizationRelation,
+}
+
+impl OrgSetRoleCommand {
+    pub fn new(
+        membership_service: Arc<dyn OrganizationMembershipService>,
+        account_repo: Arc<dyn AccountRepository>,
+        current_account_subject: Arc<CurrentAccountSubject>,
+        organization_name: AccountName,
+        account_name: AccountName,
+        role: AccountToOrganizationRelation,
+    ) -> Self {
+        Self {
+            membership_service,
+            account_repo,
+            current_account_subject,
+            organization_name,
+            account_name,
+            role,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for OrgSetRoleCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let organization_id = managed_organization_id(
+            self.membership_service.as_ref(),
+            self.account_repo.as_ref(),
+            &self.current_account_subject,
+            &self.organization_name,
+        )
+        .await?;
+
+        let account_id = resolve_account_id(self.account_repo.as_ref(), &self.account_name).await?;
+
+        self.membership_service
+            .set_member_role(&organization_id, &account_id, self.role)
+            .await
+            .map_err(|e| match e {
+                e @ (SetOrganizationMemberRoleError::NotAUser(_)
+                | SetOrganizationMemberRoleError::LastOwner(_)) => CLIError::failure(e),
+                e @ SetOrganizationMemberRoleError::NotAnOrganization(_) => CLIError::critical(e),
+                SetOrganizationMemberRoleError::Internal(e) => CLIError::critical(e),
+            })?;
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Account {} is now {} of organization {}",
+                self.account_name, self.role, self.organization_name
+            ))
+            .green()
+            .bold()
+        );
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) async fn resolve_account_id(
+    account_repo: &dyn AccountRepository,
+    account_name: &AccountName,
+) -> Result<AccountID, CLIError> {
+    match account_repo.get_account_by_name(account_name).await {
+        Ok(account) => Ok(account.id),
+        Err(e @ GetAccountByNameError::NotFound(_)) => Err(CLIError::failure(e)),
+        Err(GetAccountByNameError::Internal(e)) => Err(CLIError::critical(e)),
+    }
+}
+
+/// Resolves the organization and makes sure the logged account is allowed to
+/// manage its members, i.e. is an owner of the organization or an admin
+pub(crate) async fn managed_organization_id(
+    membership_service: &dyn OrganizationMembershipService,
+    account_repo: &dyn AccountRepository,
+    current_account_subject: &CurrentAccountSubject,
+    organization_name: &AccountName,
+) -> Result<AccountID, CLIError> {
+    let CurrentAccountSubject::Logged(logged_account) = current_account_subject else {
+        return Err(CLIError::usage_error(
+            "Organizations can only be managed by a logged in account",
+        ));
+    };
+
+    let organization = match account_repo.get_account_by_name(organization_name).await {
+        Ok(account) => account,
+        Err(e @ GetAccountByNameError::NotFound(_)) => return Err(CLIError::failure(e)),
+        Err(GetAccountByNameError::Internal(e)) => return Err(CLIError::critical(e)),
+    };
+
+    if organization.account_type != AccountType::Organization {
+        return Err(CLIError::usage_error(format!(
+            "Account {organization_name} is not an organization"
+        )));
+    }
+
+    if !logged_account.is_admin {
+        let role = membership_service
+            .get_member_role(&organization.id, &logged_account.account_id)
+            .await?;
+
+        if role != Some(AccountToOrganizationRelation::Owner) {
+            return Err(CLIError::usage_error(format!(
+                "Only owners can manage members of organization {organization_name}"
+            )));
+        }
+    }
+
+    Ok(organization.id)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
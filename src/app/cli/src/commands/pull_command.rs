@@ -35,6 +35,7 @@ pub struct PullCommand {
     all: bool,
     recursive: bool,
     fetch_uncacheable: bool,
+    evolve_schema: bool,
     as_name: Option<DatasetName>,
     add_aliases: bool,
     force: bool,
@@ -52,6 +53,7 @@ impl PullCommand {
         all: bool,
         recursive: bool,
         fetch_uncacheable: bool,
+        evolve_schema: bool,
         as_name: Option<DatasetName>,
         add_aliases: bool,
         force: bool,
@@ -70,6 +72,7 @@ impl PullCommand {
             all,
             recursive,
             fetch_uncacheable,
+            evolve_schema,
             as_name,
             add_aliases,
             force,
@@ -141,6 +144,11 @@ impl PullCommand {
                         exhaust_sources: true,
                         dataset_env_vars: HashMap::new(),
                         schema_inference: SchemaInferenceOpts::default(),
+                        schema_evolution: if self.evolve_schema {
+                            SchemaEvolutionRules::all()
+                        } else {
+                            SchemaEvolutionRules::default()
+                        },
                     },
                     sync_options: SyncOptions {
                         force: self.force,
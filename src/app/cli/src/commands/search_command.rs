@@ -22,6 +22,11 @@ pub struct SearchCommand {
     output_config: Arc<OutputConfig>,
     query: Option<String>,
     repository_names: Vec<RepoName>,
+    local: bool,
+    filters: SearchFilters,
+    include_description: bool,
+    skip_results: usize,
+    num_results: usize,
 }
 
 impl SearchCommand {
@@ -30,6 +35,11 @@ impl SearchCommand {
         output_config: Arc<OutputConfig>,
         query: Option<S>,
         repository_names: I,
+        local: bool,
+        filters: SearchFilters,
+        include_description: bool,
+        skip_results: usize,
+        num_results: usize,
     ) -> Self
     where
         S: Into<String>,
@@ -40,9 +50,73 @@ impl SearchCommand {
             output_config,
             query: query.map(Into::into),
             repository_names: repository_names.into_iter().collect(),
+            local,
+            filters,
+            include_description,
+            skip_results,
+            num_results,
         }
     }
 
+    async fn search_remote(&self) -> Result<Vec<SearchRow>, CLIError> {
+        let mut result = self
+            .search_svc
+            .search(
+                self.query.as_deref(),
+                SearchOptions {
+                    repository_names: self.repository_names.clone(),
+                    filters: self.filters.clone(),
+                    include_description: self.include_description,
+                },
+            )
+            .await
+            .map_err(CLIError::failure)?;
+
+        result.datasets.sort_by(|a, b| a.alias.cmp(&b.alias));
+
+        Ok(result
+            .datasets
+            .into_iter()
+            .map(|ds| SearchRow {
+                alias: ds.alias.to_string(),
+                kind: ds.kind,
+                description: ds.description,
+                num_blocks: ds.num_blocks,
+                num_records: ds.num_records,
+                estimated_size: ds.estimated_size,
+            })
+            .collect())
+    }
+
+    async fn search_local(&self) -> Result<Vec<SearchRow>, CLIError> {
+        let result = self
+            .search_svc
+            .search_local(
+                self.query.as_deref(),
+                SearchLocalOptions {
+                    filters: self.filters.clone(),
+                    offset: self.skip_results,
+                    limit: self.num_results,
+                },
+            )
+            .await
+            .map_err(CLIError::failure)?;
+
+        // Preserve the relevance order
+        Ok(result
+            .datasets
+            .into_iter()
+            .map(|ds| SearchRow {
+                alias: ds.handle.alias.to_string(),
+                kind: Some(ds.kind),
+                description: ds.description,
+                num_blocks: Some(ds.num_blocks),
+                num_records: Some(ds.num_records),
+                estimated_size: Some(ds.estimated_size),
+            })
+            .collect())
+    }
+
     fn humanize_data_size(size: u64) -> String {
         if size == 0 {
             return "-".to_owned();
@@ -63,18 +137,11 @@ impl SearchCommand {
 #[async_trait::async_trait(?Send)]
 impl Command for SearchCommand {
     async fn run(&mut self) -> Result<(), CLIError> {
-        let mut result = self
-            .search_svc
-            .search(
-                self.query.as_deref(),
-                SearchOptions {
-                    repository_names: self.repository_names.clone(),
-                },
-            )
-            .await
-            .map_err(CLIError::failure)?;
-
-        result.datasets.sort_by(|a, b| a.alias.cmp(&b.alias));
+        let datasets = if self.local {
+            self.search_local().await?
+        } else {
+            self.search_remote().await?
+        };
 
         let schema = Arc::new(Schema::new(vec![
             Field::new("Alias", DataType::Utf8, false),
@@ -109,10 +176,10 @@ impl Command for SearchCommand {
         let mut records = Vec::new();
         let mut size = Vec::new();
 
-        for ds in result.datasets {
-            alias.push(ds.alias.to_string());
+        for ds in datasets {
+            alias.push(ds.alias);
             kind.push(ds.kind.map(|k| format!("{k:?}")));
-            description.push(Option::<String>::None);
+            description.push(ds.description);
             blocks.push(ds.num_blocks);
             records.push(ds.num_records);
             size.push(ds.estimated_size);
@@ -140,3 +207,14 @@ impl Command for SearchCommand {
         Ok(())
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct SearchRow {
+    alias: String,
+    kind: Option<DatasetKind>,
+    description: Option<String>,
+    num_blocks: Option<u64>,
+    num_records: Option<u64>,
+    estimated_size: Option<u64>,
+}
@@ -12,9 +12,9 @@ use std::sync::Arc;
 
 use arrow_flight::flight_service_server::FlightServiceServer;
 use console::style as s;
-use datafusion::prelude::SessionContext;
+use datafusion::prelude::{DataFrame, SessionContext};
 use internal_error::*;
-use kamu::domain::QueryService;
+use kamu::domain::{QueryOptions, QueryService};
 use kamu_adapter_flight_sql::{SessionFactory, Token};
 use tokio::net::TcpListener;
 use tonic::transport::Server;
@@ -109,4 +109,18 @@ impl SessionFactory for SessionFactoryImpl {
     async fn get_context(&self, _token: &Token) -> Result<Arc<SessionContext>, Status> {
         Ok(Arc::new(self.query_svc.create_session().await.unwrap()))
     }
+
+    fn rewrite_query(&self, query: &str) -> Result<String, Status> {
+        kamu::rewrite_time_travel(query).map_err(|e| Status::invalid_argument(e.to_string()))
+    }
+
+    // Going through the query service lets statements benefit from the query
+    // result cache
+    async fn execute_query(&self, _ctx: &SessionContext, query: &str) -> Result<DataFrame, Status> {
+        self.query_svc
+            .sql_statement(query, QueryOptions::default())
+            .await
+            .map(|res| res.df)
+            .map_err(|e| Status::internal(format!("Error building plan: {e}")))
+    }
 }
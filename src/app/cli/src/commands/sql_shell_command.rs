@@ -14,6 +14,7 @@ use container_runtime::ContainerRuntime;
 use internal_error::*;
 use kamu::domain::{QueryOptions, QueryService};
 use kamu::*;
+use kamu_datafusion_cli::cli_context::SessionContextWithSqlRewriter;
 use kamu_datafusion_cli::exec;
 use kamu_datafusion_cli::print_format::PrintFormat;
 use kamu_datafusion_cli::print_options::{MaxRows, PrintOptions};
@@ -149,7 +150,11 @@ impl SqlShellCommand {
             maxrows: MaxRows::Limited(DEFAULT_MAX_ROWS_FOR_OUTPUT),
         };
 
-        let ctx = self.query_svc.create_session().await.unwrap();
+        // Support time travel clauses that DataFusion cannot parse on its own
+        let ctx = SessionContextWithSqlRewriter::new(
+            self.query_svc.create_session().await.unwrap(),
+            kamu::rewrite_time_travel,
+        );
 
         eprintln!(
             "{}",
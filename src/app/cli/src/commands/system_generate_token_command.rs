@@ -10,6 +10,7 @@
 use std::sync::Arc;
 
 use internal_error::*;
+use kamu_accounts::{AccessTokenScope, AccessTokenScopes};
 use kamu_accounts_services::AuthenticationServiceImpl;
 use opendatafabric::AccountID;
 
@@ -22,6 +23,7 @@ pub struct GenerateTokenCommand {
     login: Option<String>,
     subject: Option<String>,
     expiration_time_sec: usize,
+    scopes: Vec<AccessTokenScope>,
 }
 
 impl GenerateTokenCommand {
@@ -30,12 +32,14 @@ impl GenerateTokenCommand {
         login: Option<String>,
         subject: Option<String>,
         expiration_time_sec: usize,
+        scopes: Vec<AccessTokenScope>,
     ) -> Self {
         Self {
             auth_service,
             login,
             subject,
             expiration_time_sec,
+            scopes,
         }
     }
 }
@@ -55,9 +59,17 @@ impl Command for GenerateTokenCommand {
             return Err(CLIError::usage_error("Specify --login or --subject"));
         };
 
-        let token = self
-            .auth_service
-            .make_access_token(&subject, self.expiration_time_sec)?;
+        let scopes = if self.scopes.is_empty() {
+            AccessTokenScopes::Unrestricted
+        } else {
+            AccessTokenScopes::Restricted(self.scopes.clone())
+        };
+
+        let token = self.auth_service.make_scoped_access_token(
+            &subject,
+            self.expiration_time_sec,
+            scopes,
+        )?;
 
         println!("{token}");
         Ok(())
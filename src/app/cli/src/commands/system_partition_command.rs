@@ -0,0 +1,112 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::domain::utils::partitioned_slices::*;
+use kamu::domain::*;
+use kamu::utils::slice_indexes::delete_partitioning;
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Command
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SystemPartitionCommand {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_ref: DatasetRef,
+    column: Option<String>,
+    by_day: bool,
+    clear: bool,
+}
+
+impl SystemPartitionCommand {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_ref: DatasetRef,
+        column: Option<String>,
+        by_day: bool,
+        clear: bool,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_ref,
+            column,
+            by_day,
+            clear,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for SystemPartitionCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let dataset = self
+            .dataset_repo
+            .find_dataset_by_ref(&self.dataset_ref)
+            .await
+            .map_err(CLIError::failure)?;
+
+        let info_repo = dataset.as_info_repo();
+        let current = read_partitioning_spec(info_repo).await?;
+
+        if self.clear {
+            if current.is_some() {
+                delete_partitioning(dataset.as_ref()).await?;
+                eprintln!(
+                    "{}",
+                    console::style("Partitioning was disabled").green().bold()
+                );
+            } else {
+                eprintln!("{}", console::style("Dataset is not partitioned").yellow());
+            }
+            return Ok(());
+        }
+
+        let Some(column) = self.column.clone() else {
+            return Err(CLIError::usage_error(
+                "Specify the partitioning column with --by or disable partitioning with --clear",
+            ));
+        };
+
+        let spec = PartitioningSpec {
+            column,
+            transform: if self.by_day {
+                PartitionTransform::Day
+            } else {
+                PartitionTransform::Identity
+            },
+        };
+
+        if current.as_ref() == Some(&spec) {
+            eprintln!(
+                "{}",
+                console::style("Dataset is already partitioned with this spec").yellow()
+            );
+            return Ok(());
+        }
+
+        // Changing the spec discards the partitions of the existing slices
+        if current.is_some() {
+            delete_partitioning(dataset.as_ref()).await?;
+        }
+        write_partitioning_spec(info_repo, &spec).await?;
+
+        eprintln!(
+            "{}",
+            console::style("Partitioning was enabled, it will apply to the newly added data")
+                .green()
+                .bold()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::domain::utils::slice_statistics::*;
+use kamu::domain::*;
+use kamu::utils::slice_indexes::{build_statistics, delete_statistics};
+use opendatafabric::*;
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Command
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SystemStatisticsCommand {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_ref: DatasetRef,
+    bloom_filter_columns: Vec<String>,
+    clear: bool,
+}
+
+impl SystemStatisticsCommand {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_ref: DatasetRef,
+        bloom_filter_columns: Vec<String>,
+        clear: bool,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_ref,
+            bloom_filter_columns,
+            clear,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for SystemStatisticsCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let dataset = self
+            .dataset_repo
+            .find_dataset_by_ref(&self.dataset_ref)
+            .await
+            .map_err(CLIError::failure)?;
+
+        let info_repo = dataset.as_info_repo();
+        let current = read_slice_statistics_config(info_repo).await?;
+
+        if self.clear {
+            if current.is_some() {
+                delete_statistics(dataset.as_ref()).await?;
+                eprintln!(
+                    "{}",
+                    console::style("Slice statistics were disabled")
+                        .green()
+                        .bold()
+                );
+            } else {
+                eprintln!(
+                    "{}",
+                    console::style("Dataset has no slice statistics").yellow()
+                );
+            }
+            return Ok(());
+        }
+
+        let mut bloom_filter_columns = self.bloom_filter_columns.clone();
+        bloom_filter_columns.sort();
+        bloom_filter_columns.dedup();
+
+        if current
+            .as_ref()
+            .is_some_and(|c| c.bloom_filter_columns == bloom_filter_columns)
+        {
+            eprintln!(
+                "{}",
+                console::style("Slice statistics are already enabled").yellow()
+            );
+            return Ok(());
+        }
+
+        // Changing the bloom filter columns discards the statistics collected so far
+        if current.is_some() {
+            delete_statistics(dataset.as_ref()).await?;
+        }
+        write_slice_statistics_config(
+            info_repo,
+            &SliceStatisticsConfig {
+                bloom_filter_columns,
+            },
+        )
+        .await?;
+
+        let num_files = build_statistics(dataset.as_ref()).await?;
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Slice statistics were enabled, collected statistics of {num_files} existing data \
+                 file(s)"
+            ))
+            .green()
+            .bold()
+        );
+
+        Ok(())
+    }
+}
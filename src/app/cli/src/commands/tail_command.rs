@@ -60,32 +60,7 @@ impl Command for TailCommand {
                 // TODO: `RecordsFormat` should allow specifying column formats by name, not
                 // only positionally
                 ColumnFormat::default(),
-                ColumnFormat::default().with_value_fmt(|array, row, _| {
-                    let err = Err(InvalidOperationType(0));
-                    let op = match array.data_type() {
-                        DataType::UInt8 => array
-                            .as_any()
-                            .downcast_ref::<UInt8Array>()
-                            .map(|a| a.value(row))
-                            .map_or(err, OperationType::try_from),
-                        // Compatibility fallback
-                        DataType::Int32 => array
-                            .as_any()
-                            .downcast_ref::<Int32Array>()
-                            .and_then(|a| u8::try_from(a.value(row)).ok())
-                            .map(OperationType::try_from)
-                            .unwrap_or(err),
-                        _ => err,
-                    };
-                    match op {
-                        Ok(OperationType::Append) => "+A",
-                        Ok(OperationType::Retract) => "-R",
-                        Ok(OperationType::CorrectFrom) => "-C",
-                        Ok(OperationType::CorrectTo) => "+C",
-                        _ => "??",
-                    }
-                    .to_string()
-                }),
+                operation_type_column_format(),
             ]),
         );
 
@@ -96,4 +71,34 @@ impl Command for TailCommand {
     }
 }
 
+/// Displays operation type codes in their short symbolic form
+pub(crate) fn operation_type_column_format() -> ColumnFormat {
+    ColumnFormat::default().with_value_fmt(|array, row, _| {
+        let err = Err(InvalidOperationType(0));
+        let op = match array.data_type() {
+            DataType::UInt8 => array
+                .as_any()
+                .downcast_ref::<UInt8Array>()
+                .map(|a| a.value(row))
+                .map_or(err, OperationType::try_from),
+            // Compatibility fallback
+            DataType::Int32 => array
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .and_then(|a| u8::try_from(a.value(row)).ok())
+                .map(OperationType::try_from)
+                .unwrap_or(err),
+            _ => err,
+        };
+        match op {
+            Ok(OperationType::Append) => "+A",
+            Ok(OperationType::Retract) => "-R",
+            Ok(OperationType::CorrectFrom) => "-C",
+            Ok(OperationType::CorrectTo) => "+C",
+            _ => "??",
+        }
+        .to_string()
+    })
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
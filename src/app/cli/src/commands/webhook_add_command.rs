@@ -0,0 +1,108 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu::domain::*;
+use kamu_accounts::CurrentAccountSubject;
+use kamu_webhooks::{CreateWebhookSubscriptionError, WebhookEventType, WebhookSubscriptionService};
+use opendatafabric::{AccountID, DatasetRef};
+
+use super::{CLIError, Command};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookAddCommand {
+    subscription_service: Arc<dyn WebhookSubscriptionService>,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    current_account_subject: Arc<CurrentAccountSubject>,
+    target_url: String,
+    event_types: Vec<WebhookEventType>,
+    dataset_ref: Option<DatasetRef>,
+}
+
+impl WebhookAddCommand {
+    pub fn new<I>(
+        subscription_service: Arc<dyn WebhookSubscriptionService>,
+        dataset_repo: Arc<dyn DatasetRepository>,
+        current_account_subject: Arc<CurrentAccountSubject>,
+        target_url: String,
+        event_types: I,
+        dataset_ref: Option<DatasetRef>,
+    ) -> Self
+    where
+        I: IntoIterator<Item = WebhookEventType>,
+    {
+        Self {
+            subscription_service,
+            dataset_repo,
+            current_account_subject,
+            target_url,
+            event_types: event_types.into_iter().collect(),
+            dataset_ref,
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for WebhookAddCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let account_id = logged_account_id(&self.current_account_subject)?;
+
+        let dataset_id = if let Some(dataset_ref) = &self.dataset_ref {
+            Some(self.dataset_repo.resolve_dataset_ref(dataset_ref).await?.id)
+        } else {
+            None
+        };
+
+        let subscription = self
+            .subscription_service
+            .create_subscription(
+                &account_id,
+                &self.target_url,
+                self.event_types.clone(),
+                dataset_id,
+            )
+            .await
+            .map_err(|e| match e {
+                e @ (CreateWebhookSubscriptionError::InvalidTargetUrl(_)
+                | CreateWebhookSubscriptionError::NoEventTypes(_)) => CLIError::usage_error_from(e),
+                CreateWebhookSubscriptionError::Internal(e) => CLIError::critical(e),
+            })?;
+
+        eprintln!(
+            "{}",
+            console::style(format!("Added webhook subscription {}", subscription.id))
+                .green()
+                .bold()
+        );
+        eprintln!(
+            "Use the following secret to verify signatures of the requests, it will not be shown \
+             again:"
+        );
+        println!("{}", subscription.secret);
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn logged_account_id(
+    current_account_subject: &CurrentAccountSubject,
+) -> Result<AccountID, CLIError> {
+    match current_account_subject {
+        CurrentAccountSubject::Logged(l) => Ok(l.account_id.clone()),
+        CurrentAccountSubject::Anonymous(_) => Err(CLIError::usage_error(
+            "Webhooks can only be managed by a logged in account",
+        )),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
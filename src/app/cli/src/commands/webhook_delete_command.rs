@@ -0,0 +1,86 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use kamu_accounts::CurrentAccountSubject;
+use kamu_webhooks::{DeleteWebhookSubscriptionError, WebhookSubscriptionService};
+use uuid::Uuid;
+
+use super::{logged_account_id, CLIError, Command};
+use crate::Interact;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookDeleteCommand {
+    interact: Arc<Interact>,
+    subscription_service: Arc<dyn WebhookSubscriptionService>,
+    current_account_subject: Arc<CurrentAccountSubject>,
+    subscription_ids: Vec<Uuid>,
+}
+
+impl WebhookDeleteCommand {
+    pub fn new<I>(
+        interact: Arc<Interact>,
+        subscription_service: Arc<dyn WebhookSubscriptionService>,
+        current_account_subject: Arc<CurrentAccountSubject>,
+        subscription_ids: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = Uuid>,
+    {
+        Self {
+            interact,
+            subscription_service,
+            current_account_subject,
+            subscription_ids: subscription_ids.into_iter().collect(),
+        }
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for WebhookDeleteCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let account_id = logged_account_id(&self.current_account_subject)?;
+
+        if self.subscription_ids.is_empty() {
+            return Err(CLIError::usage_error("Specify a subscription"));
+        }
+
+        self.interact.require_confirmation(format!(
+            "{}: {}",
+            console::style("You are about to delete following webhook subscription(s)").yellow(),
+            itertools::join(&self.subscription_ids, ", "),
+        ))?;
+
+        for subscription_id in &self.subscription_ids {
+            self.subscription_service
+                .remove_subscription(&account_id, subscription_id)
+                .await
+                .map_err(|e| match e {
+                    e @ DeleteWebhookSubscriptionError::NotFound(_) => CLIError::failure(e),
+                    DeleteWebhookSubscriptionError::Internal(e) => CLIError::critical(e),
+                })?;
+        }
+
+        eprintln!(
+            "{}",
+            console::style(format!(
+                "Deleted {} webhook subscription(s)",
+                self.subscription_ids.len()
+            ))
+            .green()
+            .bold()
+        );
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,143 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use datafusion::arrow::array::{BooleanArray, RecordBatch, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use internal_error::ResultIntoInternal;
+use kamu::domain::*;
+use kamu_accounts::CurrentAccountSubject;
+use kamu_webhooks::{WebhookSubscription, WebhookSubscriptionService};
+
+use super::{logged_account_id, CLIError, Command};
+use crate::output::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookListCommand {
+    subscription_service: Arc<dyn WebhookSubscriptionService>,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    current_account_subject: Arc<CurrentAccountSubject>,
+    output_config: Arc<OutputConfig>,
+}
+
+impl WebhookListCommand {
+    pub fn new(
+        subscription_service: Arc<dyn WebhookSubscriptionService>,
+        dataset_repo: Arc<dyn DatasetRepository>,
+        current_account_subject: Arc<CurrentAccountSubject>,
+        output_config: Arc<OutputConfig>,
+    ) -> Self {
+        Self {
+            subscription_service,
+            dataset_repo,
+            current_account_subject,
+            output_config,
+        }
+    }
+
+    fn records_format(&self) -> RecordsFormat {
+        RecordsFormat::new()
+            .with_default_column_format(ColumnFormat::default())
+            .with_column_formats(vec![
+                ColumnFormat::new().with_style_spec("l"),
+                ColumnFormat::new().with_style_spec("l"),
+                ColumnFormat::new().with_style_spec("l"),
+                ColumnFormat::new().with_style_spec("l"),
+                ColumnFormat::new().with_style_spec("c"),
+            ])
+    }
+
+    fn schema(&self) -> Arc<Schema> {
+        Arc::new(Schema::new(vec![
+            Field::new("ID", DataType::Utf8, false),
+            Field::new("URL", DataType::Utf8, false),
+            Field::new("Events", DataType::Utf8, false),
+            Field::new("Dataset", DataType::Utf8, true),
+            Field::new("Enabled", DataType::Boolean, false),
+        ]))
+    }
+
+    async fn records(
+        &self,
+        schema: Arc<Schema>,
+        subscriptions: &[WebhookSubscription],
+    ) -> Result<RecordBatch, CLIError> {
+        let mut col_id = Vec::new();
+        let mut col_url = Vec::new();
+        let mut col_events = Vec::new();
+        let mut col_dataset = Vec::new();
+        let mut col_enabled = Vec::new();
+
+        for subscription in subscriptions {
+            let dataset = if let Some(dataset_id) = &subscription.dataset_id {
+                // Dataset might have been deleted since the subscription was created
+                Some(
+                    match self
+                        .dataset_repo
+                        .try_resolve_dataset_ref(&dataset_id.as_local_ref())
+                        .await?
+                    {
+                        Some(hdl) => hdl.alias.to_string(),
+                        None => dataset_id.to_string(),
+                    },
+                )
+            } else {
+                None
+            };
+
+            col_id.push(subscription.id.to_string());
+            col_url.push(subscription.target_url.to_string());
+            col_events.push(itertools::join(&subscription.event_types, ", "));
+            col_dataset.push(dataset);
+            col_enabled.push(subscription.enabled);
+        }
+
+        let records = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from(col_id)),
+                Arc::new(StringArray::from(col_url)),
+                Arc::new(StringArray::from(col_events)),
+                Arc::new(StringArray::from(col_dataset)),
+                Arc::new(BooleanArray::from(col_enabled)),
+            ],
+        )
+        .int_err()?;
+
+        Ok(records)
+    }
+}
+
+#[async_trait::async_trait(?Send)]
+impl Command for WebhookListCommand {
+    async fn run(&mut self) -> Result<(), CLIError> {
+        let account_id = logged_account_id(&self.current_account_subject)?;
+
+        let subscriptions = self
+            .subscription_service
+            .list_subscriptions(&account_id)
+            .await?;
+
+        let schema = self.schema();
+        let records = self.records(schema.clone(), &subscriptions).await?;
+
+        let mut writer = self
+            .output_config
+            .get_records_writer(&schema, self.records_format());
+
+        writer.write_batch(&records)?;
+        writer.finish()?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
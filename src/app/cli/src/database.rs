@@ -124,6 +124,11 @@ pub fn configure_database_components(
             b.add::<kamu_messaging_outbox_postgres::PostgresOutboxMessageConsumptionRepository>();
 
             b.add::<kamu_auth_rebac_postgres::PostgresRebacRepository>();
+
+            b.add::<kamu_webhooks_postgres::PostgresWebhookSubscriptionRepository>();
+            b.add::<kamu_webhooks_postgres::PostgresWebhookDeliveryRepository>();
+
+            b.add::<kamu_audit_log_postgres::PostgresAuditEventRepository>();
         }
         DatabaseProvider::MySql | DatabaseProvider::MariaDB => {
             MySqlPlugin::init_database_components(b);
@@ -146,6 +151,11 @@ pub fn configure_database_components(
             b.add::<kamu_messaging_outbox_inmem::InMemoryOutboxMessageConsumptionRepository>();
 
             b.add::<kamu_auth_rebac_inmem::InMemoryRebacRepository>();
+
+            b.add::<kamu_webhooks_inmem::InMemoryWebhookSubscriptionRepository>();
+            b.add::<kamu_webhooks_inmem::InMemoryWebhookDeliveryRepository>();
+
+            b.add::<kamu_audit_log_inmem::InMemoryAuditEventRepository>();
         }
         DatabaseProvider::Sqlite => {
             SqlitePlugin::init_database_components(b);
@@ -165,6 +175,11 @@ pub fn configure_database_components(
             b.add::<kamu_messaging_outbox_sqlite::SqliteOutboxMessageConsumptionRepository>();
 
             b.add::<kamu_auth_rebac_sqlite::SqliteRebacRepository>();
+
+            b.add::<kamu_webhooks_sqlite::SqliteWebhookSubscriptionRepository>();
+            b.add::<kamu_webhooks_sqlite::SqliteWebhookDeliveryRepository>();
+
+            b.add::<kamu_audit_log_sqlite::SqliteAuditEventRepository>();
         }
     }
 
@@ -187,6 +202,9 @@ pub fn configure_in_memory_components(b: &mut CatalogBuilder) {
     b.add::<kamu_datasets_inmem::InMemoryDatasetEnvVarRepository>();
     b.add::<kamu_datasets_inmem::InMemoryDatasetEntryRepository>();
     b.add::<kamu_auth_rebac_inmem::InMemoryRebacRepository>();
+    b.add::<kamu_webhooks_inmem::InMemoryWebhookSubscriptionRepository>();
+    b.add::<kamu_webhooks_inmem::InMemoryWebhookDeliveryRepository>();
+    b.add::<kamu_audit_log_inmem::InMemoryAuditEventRepository>();
 
     NoOpDatabasePlugin::init_database_components(b);
 }
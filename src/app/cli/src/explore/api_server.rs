@@ -24,6 +24,7 @@ use kamu::domain::{Protocols, ServerUrlConfig};
 use kamu_adapter_http::e2e::e2e_router;
 use kamu_flow_system_inmem::domain::FlowExecutor;
 use kamu_task_system_inmem::domain::TaskExecutor;
+use kamu_webhooks_services::WebhookDeliveryRetrier;
 use messaging_outbox::OutboxExecutor;
 use tokio::sync::Notify;
 use url::Url;
@@ -36,6 +37,7 @@ pub struct APIServer {
     task_executor: Arc<dyn TaskExecutor>,
     flow_executor: Arc<dyn FlowExecutor>,
     outbox_executor: Arc<OutboxExecutor>,
+    webhook_delivery_retrier: Arc<WebhookDeliveryRetrier>,
     maybe_shutdown_notify: Option<Arc<Notify>>,
 }
 
@@ -57,6 +59,8 @@ impl APIServer {
 
         let outbox_executor = cli_catalog.get_one().unwrap();
 
+        let webhook_delivery_retrier = cli_catalog.get_one().unwrap();
+
         let gql_schema = kamu_adapter_graphql::schema();
 
         let addr = SocketAddr::from((
@@ -99,6 +103,12 @@ impl APIServer {
                 "/graphql",
                 axum::routing::get(graphql_playground_handler).post(graphql_handler),
             )
+            .route(
+                "/graphql/ws",
+                axum::routing::get(
+                    kamu_adapter_http::graphql_subscription_handler::<kamu_adapter_graphql::Schema>,
+                ),
+            )
             .route(
                 "/platform/login",
                 axum::routing::post(kamu_adapter_http::platform_login_handler),
@@ -187,6 +197,7 @@ impl APIServer {
             task_executor,
             flow_executor,
             outbox_executor,
+            webhook_delivery_retrier,
             maybe_shutdown_notify,
         })
     }
@@ -214,7 +225,8 @@ impl APIServer {
             res = server_run_fut => { res.int_err() },
             res = self.outbox_executor.run() => { res.int_err() },
             res = self.task_executor.run() => { res.int_err() },
-            res = self.flow_executor.run() => { res.int_err() }
+            res = self.flow_executor.run() => { res.int_err() },
+            res = self.webhook_delivery_retrier.run() => { res.int_err() }
         }
     }
 }
@@ -252,7 +264,8 @@ async fn graphql_handler(
 
 async fn graphql_playground_handler() -> impl axum::response::IntoResponse {
     axum::response::Html(async_graphql::http::playground_source(
-        async_graphql::http::GraphQLPlaygroundConfig::new("/graphql"),
+        async_graphql::http::GraphQLPlaygroundConfig::new("/graphql")
+            .subscription_endpoint("/graphql/ws"),
     ))
 }
 
@@ -125,6 +125,21 @@ impl CompactionListener for CompactionProgress {
                     ),
                 );
             }
+            CompactionResult::SoftSuccess {
+                head: _,
+                old_num_slices,
+                new_num_slices,
+            } => {
+                self.curr_progress.finish_with_message(
+                    self.spinner_message(
+                        console::style(format!(
+                            "Dataset compacted successfully ({old_num_slices} -> {new_num_slices} \
+                             slices)"
+                        ))
+                        .green(),
+                    ),
+                );
+            }
         }
     }
 
@@ -133,6 +148,7 @@ impl CompactionListener for CompactionProgress {
             CompactionPhase::GatherChainInfo => "Gathering chain information",
             CompactionPhase::MergeDataslices => "Merging dataslices",
             CompactionPhase::CommitNewBlocks => "Committing new blocks",
+            CompactionPhase::CommitCompactedSlices => "Committing compacted slices",
         };
         self.curr_progress.set_message(message);
     }
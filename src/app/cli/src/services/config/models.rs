@@ -54,6 +54,10 @@ pub struct CLIConfig {
     #[merge(strategy = merge_recursive)]
     pub protocol: Option<ProtocolConfig>,
 
+    /// SQL query results cache configuration
+    #[merge(strategy = merge_recursive)]
+    pub query_cache: Option<QueryCacheConfig>,
+
     /// Source configuration
     #[merge(strategy = merge_recursive)]
     pub source: Option<SourceConfig>,
@@ -65,6 +69,10 @@ pub struct CLIConfig {
     /// Uploads configuration
     #[merge(strategy = merge_recursive)]
     pub uploads: Option<UploadsConfig>,
+
+    /// Webhook notifications configuration
+    #[merge(strategy = merge_recursive)]
+    pub webhooks: Option<WebhooksConfig>,
 }
 
 impl CLIConfig {
@@ -77,9 +85,11 @@ impl CLIConfig {
             identity: None,
             outbox: None,
             protocol: None,
+            query_cache: None,
             source: None,
             users: None,
             uploads: None,
+            webhooks: None,
         }
     }
 
@@ -96,9 +106,11 @@ impl CLIConfig {
             identity: Some(IdentityConfig::sample()),
             outbox: Some(OutboxConfig::sample()),
             protocol: Some(ProtocolConfig::sample()),
+            query_cache: Some(QueryCacheConfig::sample()),
             source: Some(SourceConfig::sample()),
             users: Some(PredefinedAccountsConfig::sample()),
             uploads: Some(UploadsConfig::sample()),
+            webhooks: Some(WebhooksConfig::sample()),
         }
     }
 }
@@ -113,9 +125,11 @@ impl Default for CLIConfig {
             identity: Some(IdentityConfig::default()),
             outbox: Some(OutboxConfig::default()),
             protocol: Some(ProtocolConfig::default()),
+            query_cache: Some(QueryCacheConfig::default()),
             source: Some(SourceConfig::default()),
             users: Some(PredefinedAccountsConfig::default()),
             uploads: Some(UploadsConfig::default()),
+            webhooks: Some(WebhooksConfig::default()),
         }
     }
 }
@@ -237,6 +251,9 @@ pub struct SourceConfig {
     /// MQTT-specific configuration
     #[merge(strategy = merge_recursive)]
     pub mqtt: Option<MqttSourceConfig>,
+    /// Kafka-specific configuration
+    #[merge(strategy = merge_recursive)]
+    pub kafka: Option<KafkaSourceConfig>,
     /// Ethereum-specific configuration
     #[merge(strategy = merge_recursive)]
     pub ethereum: Option<EthereumSourceConfig>,
@@ -248,6 +265,7 @@ impl SourceConfig {
             target_records_per_slice: None,
             http: None,
             mqtt: None,
+            kafka: None,
             ethereum: None,
         }
     }
@@ -256,6 +274,7 @@ impl SourceConfig {
         Self {
             http: Some(HttpSourceConfig::sample()),
             mqtt: Some(MqttSourceConfig::sample()),
+            kafka: Some(KafkaSourceConfig::sample()),
             ethereum: Some(EthereumSourceConfig::sample()),
             ..Self::default()
         }
@@ -275,6 +294,7 @@ impl Default for SourceConfig {
             target_records_per_slice: Some(infra_cfg.target_records_per_slice),
             http: Some(HttpSourceConfig::default()),
             mqtt: Some(MqttSourceConfig::default()),
+            kafka: Some(KafkaSourceConfig::default()),
             ethereum: Some(EthereumSourceConfig::default()),
         }
     }
@@ -368,6 +388,51 @@ impl Default for MqttSourceConfig {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct KafkaSourceConfig {
+    /// Maximum time in milliseconds the broker is allowed to wait for new
+    /// records to arrive before answering a fetch request. Receiving an empty
+    /// response is considered a sign that we have "caught up" with the
+    /// partition.
+    pub broker_max_wait_ms: Option<i32>,
+    /// Upper limit on the size of a single fetch response in bytes.
+    pub fetch_max_bytes: Option<i32>,
+}
+
+impl KafkaSourceConfig {
+    pub fn new() -> Self {
+        Self {
+            broker_max_wait_ms: None,
+            fetch_max_bytes: None,
+        }
+    }
+
+    fn sample() -> Self {
+        Self { ..Self::default() }
+    }
+
+    pub fn to_infra_cfg(&self) -> kamu::ingest::KafkaSourceConfig {
+        kamu::ingest::KafkaSourceConfig {
+            broker_max_wait_ms: self.broker_max_wait_ms.unwrap(),
+            fetch_max_bytes: self.fetch_max_bytes.unwrap(),
+        }
+    }
+}
+
+impl Default for KafkaSourceConfig {
+    fn default() -> Self {
+        let infra_cfg = kamu::ingest::KafkaSourceConfig::default();
+        Self {
+            broker_max_wait_ms: Some(infra_cfg.broker_max_wait_ms),
+            fetch_max_bytes: Some(infra_cfg.fetch_max_bytes),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, Merge, Serialize, Deserialize)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
@@ -784,6 +849,99 @@ impl Default for OutboxConfig {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Query cache
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryCacheConfig {
+    /// Whether to cache the results of SQL queries until their input datasets
+    /// change
+    pub enabled: Option<bool>,
+    /// Where the cached results are stored
+    pub store: Option<QueryCacheStore>,
+    /// Results larger than this are not cached
+    pub max_entry_size_in_mb: Option<usize>,
+    /// Least recently used results are evicted once the cache grows beyond
+    /// this size
+    pub max_total_size_in_mb: Option<usize>,
+}
+
+impl QueryCacheConfig {
+    pub fn sample() -> Self {
+        Default::default()
+    }
+
+    pub fn to_infra_cfg(&self) -> kamu::domain::QueryResultCacheConfig {
+        const MB: usize = 1024 * 1024;
+
+        kamu::domain::QueryResultCacheConfig {
+            max_entry_size: self.max_entry_size_in_mb.unwrap() * MB,
+            max_total_size: self.max_total_size_in_mb.unwrap() * MB,
+        }
+    }
+}
+
+impl Default for QueryCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Some(false),
+            store: Some(QueryCacheStore::Memory),
+            max_entry_size_in_mb: Some(10),
+            max_total_size_in_mb: Some(256),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QueryCacheStore {
+    /// Keep results in memory of the running process
+    Memory,
+    /// Keep results in the workspace cache directory
+    LocalFs,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Webhooks
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Merge, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhooksConfig {
+    /// Maximal number of attempts to deliver a single event
+    pub max_attempts: Option<u32>,
+    /// Delay before the first retry, doubled after every failed attempt
+    pub initial_backoff_secs: Option<i64>,
+    /// Time to wait for the endpoint response
+    pub request_timeout_secs: Option<i64>,
+    /// Whether endpoints on the local host, including plain HTTP ones, are
+    /// accepted. Intended for local development only
+    pub allow_insecure_localhost: Option<bool>,
+}
+
+impl WebhooksConfig {
+    pub fn sample() -> Self {
+        Default::default()
+    }
+}
+
+impl Default for WebhooksConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: Some(3),
+            initial_backoff_secs: Some(1),
+            request_timeout_secs: Some(10),
+            allow_insecure_localhost: Some(false),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
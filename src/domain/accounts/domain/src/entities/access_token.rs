@@ -10,6 +10,8 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 use chrono::{DateTime, Utc};
+#[cfg(feature = "sqlx")]
+use internal_error::{InternalError, ResultIntoInternal};
 use jsonwebtoken::TokenData;
 use opendatafabric::{AccountID, Multihash};
 use rand::{self, Rng};
@@ -17,6 +19,8 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::AccessTokenScopes;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub const ACCESS_TOKEN_PREFIX: &str = "ka";
@@ -122,9 +126,17 @@ pub struct AccessToken {
     pub token_hash: [u8; 32],
     pub created_at: DateTime<Utc>,
     pub revoked_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub scopes: AccessTokenScopes,
     pub account_id: AccountID,
 }
 
+impl AccessToken {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Our claims struct, it needs to derive `Serialize` and/or `Deserialize`
@@ -134,6 +146,8 @@ pub struct JWTClaims {
     pub iat: usize,
     pub iss: String,
     pub sub: String,
+    #[serde(default, skip_serializing_if = "AccessTokenScopes::is_unrestricted")]
+    pub scopes: AccessTokenScopes,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -169,19 +183,35 @@ pub struct AccessTokenRowModel {
     pub token_hash: Vec<u8>,
     pub created_at: DateTime<Utc>,
     pub revoked_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub scopes: Option<String>,
     pub account_id: AccountID,
 }
 
 #[cfg(feature = "sqlx")]
-impl From<AccessTokenRowModel> for AccessToken {
-    fn from(value: AccessTokenRowModel) -> Self {
-        AccessToken {
+impl TryFrom<AccessTokenRowModel> for AccessToken {
+    type Error = InternalError;
+
+    fn try_from(value: AccessTokenRowModel) -> Result<Self, Self::Error> {
+        let token_hash = value
+            .token_hash
+            .try_into()
+            .map_err(|_| InternalError::new("Invalid access token hash length"))?;
+
+        let scopes = match value.scopes {
+            None => AccessTokenScopes::Unrestricted,
+            Some(s) => s.parse().int_err()?,
+        };
+
+        Ok(AccessToken {
             id: value.id,
             token_name: value.token_name,
-            token_hash: value.token_hash.try_into().unwrap(),
+            token_hash,
             created_at: value.created_at,
             revoked_at: value.revoked_at,
+            expires_at: value.expires_at,
+            scopes,
             account_id: value.account_id,
-        }
+        })
     }
 }
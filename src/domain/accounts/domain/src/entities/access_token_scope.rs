@@ -0,0 +1,249 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+use std::str::FromStr;
+
+use opendatafabric::DatasetID;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Single permission granted to an access token. Scopes can only narrow down
+/// what the owning account is allowed to do, never extend it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum AccessTokenScope {
+    /// Read access to all datasets visible to the account
+    Read,
+    /// Read and write access to all datasets, or to a single dataset only
+    Write(Option<DatasetID>),
+    /// Triggering non-destructive flows with their stored configuration for
+    /// all datasets, or for a single dataset only
+    FlowTrigger(Option<DatasetID>),
+    /// Full access, including administrative operations if the account is an
+    /// administrator
+    Admin,
+}
+
+impl AccessTokenScope {
+    const READ: &'static str = "read";
+    const WRITE: &'static str = "write";
+    const FLOW_TRIGGER: &'static str = "flow-trigger";
+    const ADMIN: &'static str = "admin";
+
+    fn covers(maybe_dataset_id: Option<&DatasetID>, dataset_id: &DatasetID) -> bool {
+        maybe_dataset_id.map_or(true, |id| id == dataset_id)
+    }
+}
+
+impl fmt::Display for AccessTokenScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read => write!(f, "{}", Self::READ),
+            Self::Write(None) => write!(f, "{}", Self::WRITE),
+            Self::Write(Some(dataset_id)) => write!(f, "{}:{dataset_id}", Self::WRITE),
+            Self::FlowTrigger(None) => write!(f, "{}", Self::FLOW_TRIGGER),
+            Self::FlowTrigger(Some(dataset_id)) => {
+                write!(f, "{}:{dataset_id}", Self::FLOW_TRIGGER)
+            }
+            Self::Admin => write!(f, "{}", Self::ADMIN),
+        }
+    }
+}
+
+impl FromStr for AccessTokenScope {
+    type Err = InvalidAccessTokenScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || InvalidAccessTokenScopeError {
+            scope: s.to_string(),
+        };
+
+        let (kind, maybe_dataset_id) = match s.split_once(':') {
+            Some((kind, dataset_id)) => (
+                kind,
+                Some(DatasetID::from_did_str(dataset_id).map_err(|_| err())?),
+            ),
+            None => (s, None),
+        };
+
+        match (kind, maybe_dataset_id) {
+            (Self::READ, None) => Ok(Self::Read),
+            (Self::WRITE, maybe_dataset_id) => Ok(Self::Write(maybe_dataset_id)),
+            (Self::FLOW_TRIGGER, maybe_dataset_id) => Ok(Self::FlowTrigger(maybe_dataset_id)),
+            (Self::ADMIN, None) => Ok(Self::Admin),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl TryFrom<String> for AccessTokenScope {
+    type Error = InvalidAccessTokenScopeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<AccessTokenScope> for String {
+    fn from(value: AccessTokenScope) -> Self {
+        value.to_string()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Set of permissions an access token is restricted to. Tokens issued without
+/// explicit scopes, as well as login sessions, carry the full power of the
+/// account.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    from = "Option<Vec<AccessTokenScope>>",
+    into = "Option<Vec<AccessTokenScope>>"
+)]
+pub enum AccessTokenScopes {
+    #[default]
+    Unrestricted,
+    Restricted(Vec<AccessTokenScope>),
+}
+
+impl AccessTokenScopes {
+    /// Textual representation of unrestricted scopes, distinct from any
+    /// single scope
+    const UNRESTRICTED: &'static str = "*";
+
+    pub fn is_unrestricted(&self) -> bool {
+        matches!(self, Self::Unrestricted)
+    }
+
+    pub fn allows_read(&self, dataset_id: &DatasetID) -> bool {
+        self.any(|scope| match scope {
+            AccessTokenScope::Read | AccessTokenScope::Admin => true,
+            AccessTokenScope::Write(maybe_id) | AccessTokenScope::FlowTrigger(maybe_id) => {
+                AccessTokenScope::covers(maybe_id.as_ref(), dataset_id)
+            }
+        })
+    }
+
+    pub fn allows_write(&self, dataset_id: &DatasetID) -> bool {
+        self.any(|scope| match scope {
+            AccessTokenScope::Admin => true,
+            AccessTokenScope::Write(maybe_id) => {
+                AccessTokenScope::covers(maybe_id.as_ref(), dataset_id)
+            }
+            AccessTokenScope::Read | AccessTokenScope::FlowTrigger(_) => false,
+        })
+    }
+
+    /// Creating new datasets requires write access that is not limited to a
+    /// single dataset
+    pub fn allows_dataset_creation(&self) -> bool {
+        self.any(|scope| {
+            matches!(
+                scope,
+                AccessTokenScope::Admin | AccessTokenScope::Write(None)
+            )
+        })
+    }
+
+    pub fn allows_flow_trigger(&self, dataset_id: &DatasetID) -> bool {
+        self.any(|scope| match scope {
+            AccessTokenScope::Admin => true,
+            AccessTokenScope::Write(maybe_id) | AccessTokenScope::FlowTrigger(maybe_id) => {
+                AccessTokenScope::covers(maybe_id.as_ref(), dataset_id)
+            }
+            AccessTokenScope::Read => false,
+        })
+    }
+
+    /// Administrative operations and management of the account itself
+    /// (including its access tokens)
+    pub fn allows_admin(&self) -> bool {
+        self.any(|scope| matches!(scope, AccessTokenScope::Admin))
+    }
+
+    fn any(&self, predicate: impl Fn(&AccessTokenScope) -> bool) -> bool {
+        match self {
+            Self::Unrestricted => true,
+            Self::Restricted(scopes) => scopes.iter().any(predicate),
+        }
+    }
+}
+
+impl fmt::Display for AccessTokenScopes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unrestricted => write!(f, "{}", Self::UNRESTRICTED),
+            Self::Restricted(scopes) => {
+                for (i, scope) in scopes.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{scope}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Parses a non-empty space-separated list of scopes, or `*` for unrestricted
+/// scopes
+impl FromStr for AccessTokenScopes {
+    type Err = InvalidAccessTokenScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim() == Self::UNRESTRICTED {
+            return Ok(Self::Unrestricted);
+        }
+
+        let scopes = s
+            .split_whitespace()
+            .map(AccessTokenScope::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if scopes.is_empty() {
+            return Err(InvalidAccessTokenScopeError {
+                scope: s.to_string(),
+            });
+        }
+
+        Ok(Self::Restricted(scopes))
+    }
+}
+
+impl From<Option<Vec<AccessTokenScope>>> for AccessTokenScopes {
+    fn from(value: Option<Vec<AccessTokenScope>>) -> Self {
+        match value {
+            None => Self::Unrestricted,
+            Some(scopes) => Self::Restricted(scopes),
+        }
+    }
+}
+
+impl From<AccessTokenScopes> for Option<Vec<AccessTokenScope>> {
+    fn from(value: AccessTokenScopes) -> Self {
+        match value {
+            AccessTokenScopes::Unrestricted => None,
+            AccessTokenScopes::Restricted(scopes) => Some(scopes),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+#[error("Invalid access token scope '{scope}'")]
+pub struct InvalidAccessTokenScopeError {
+    pub scope: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,33 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Access token a long-lived connection, such as a WebSocket, has been
+/// authenticated with.
+///
+/// Lets the services running on such connection re-validate the credentials,
+/// since the token may expire or get revoked while the connection is open.
+#[derive(Clone, Debug)]
+pub struct ConnectionAccessToken {
+    pub token: String,
+}
+
+impl ConnectionAccessToken {
+    pub fn new<S>(token: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
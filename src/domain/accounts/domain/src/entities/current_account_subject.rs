@@ -9,7 +9,7 @@
 
 use opendatafabric::{AccountID, AccountName};
 
-use crate::{DEFAULT_ACCOUNT_ID, DEFAULT_ACCOUNT_NAME};
+use crate::{AccessTokenScopes, DEFAULT_ACCOUNT_ID, DEFAULT_ACCOUNT_NAME};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -24,6 +24,8 @@ pub struct LoggedAccount {
     pub account_id: AccountID,
     pub account_name: AccountName,
     pub is_admin: bool,
+    /// Restrictions of the access token the account was authenticated with
+    pub token_scopes: AccessTokenScopes,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -39,13 +41,37 @@ impl CurrentAccountSubject {
     }
 
     pub fn logged(account_id: AccountID, account_name: AccountName, is_admin: bool) -> Self {
-        Self::Logged(LoggedAccount {
+        Self::logged_with_token_scopes(
             account_id,
             account_name,
             is_admin,
+            AccessTokenScopes::Unrestricted,
+        )
+    }
+
+    /// Administrative privileges are only retained if the token allows them
+    pub fn logged_with_token_scopes(
+        account_id: AccountID,
+        account_name: AccountName,
+        is_admin: bool,
+        token_scopes: AccessTokenScopes,
+    ) -> Self {
+        Self::Logged(LoggedAccount {
+            account_id,
+            account_name,
+            is_admin: is_admin && token_scopes.allows_admin(),
+            token_scopes,
         })
     }
 
+    /// Identifier of the logged account, if any
+    pub fn maybe_account_id(&self) -> Option<&AccountID> {
+        match self {
+            Self::Logged(l) => Some(&l.account_id),
+            Self::Anonymous(_) => None,
+        }
+    }
+
     pub fn new_test() -> Self {
         let is_admin = false;
 
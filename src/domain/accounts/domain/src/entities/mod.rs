@@ -8,11 +8,15 @@
 // by the Apache License, Version 2.0.
 
 mod access_token;
+mod access_token_scope;
 mod account;
+mod connection_access_token;
 mod current_account_subject;
 mod predefined_accounts_config;
 
 pub use access_token::*;
+pub use access_token_scope::*;
 pub use account::*;
+pub use connection_access_token::*;
 pub use current_account_subject::*;
 pub use predefined_accounts_config::*;
@@ -58,6 +58,9 @@ pub enum CreateAccessTokenError {
     #[error(transparent)]
     Duplicate(CreateAccessTokenErrorDuplicate),
 
+    #[error("Access token expiration time is in the past")]
+    AlreadyExpired,
+
     #[error(transparent)]
     Internal(#[from] InternalError),
 }
@@ -92,6 +95,9 @@ pub enum FindAccountByTokenError {
     #[error("Access token hash is invalid")]
     InvalidTokenHash,
 
+    #[error("Access token has expired")]
+    Expired,
+
     #[error(transparent)]
     NotFound(AccessTokenNotFoundError),
 
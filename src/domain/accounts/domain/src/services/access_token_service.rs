@@ -7,13 +7,15 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use chrono::{DateTime, Utc};
 use database_common::PaginationOpts;
 use opendatafabric::AccountID;
 use uuid::Uuid;
 
 use crate::{
     AccessToken,
-    Account,
+    AccessTokenScopes,
+    AuthenticatedAccount,
     CreateAccessTokenError,
     FindAccountByTokenError,
     GetAccessTokenError,
@@ -33,13 +35,17 @@ pub trait AccessTokenService: Sync + Send {
         &self,
         token_name: &str,
         account_id: &AccountID,
+        expires_at: Option<DateTime<Utc>>,
+        scopes: AccessTokenScopes,
     ) -> Result<KamuAccessToken, CreateAccessTokenError>;
 
+    /// Resolves the account owning an active (not revoked and not expired)
+    /// token, along with the scopes the token is restricted to
     async fn find_account_by_active_token_id(
         &self,
         token_id: &Uuid,
         token_hash: [u8; 32],
-    ) -> Result<Account, FindAccountByTokenError>;
+    ) -> Result<AuthenticatedAccount, FindAccountByTokenError>;
 
     async fn get_token_by_id(&self, token_id: &Uuid) -> Result<AccessToken, GetAccessTokenError>;
 
@@ -49,7 +55,13 @@ pub trait AccessTokenService: Sync + Send {
         pagination: &PaginationOpts,
     ) -> Result<AccessTokenListing, GetAccessTokenError>;
 
-    async fn revoke_access_token(&self, token_id: &Uuid) -> Result<(), RevokeTokenError>;
+    /// Revokes the token on behalf of the acting account, which may differ
+    /// from the token owner
+    async fn revoke_access_token(
+        &self,
+        token_id: &Uuid,
+        revoked_by: Option<&AccountID>,
+    ) -> Result<(), RevokeTokenError>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
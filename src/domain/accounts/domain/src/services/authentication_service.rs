@@ -12,7 +12,12 @@ use opendatafabric::{AccountID, AccountName};
 use thiserror::Error;
 
 use super::{InvalidCredentialsError, RejectedCredentialsError};
-use crate::{Account, FindAccountIdByProviderIdentityKeyError, ProviderLoginError};
+use crate::{
+    AccessTokenScopes,
+    Account,
+    FindAccountIdByProviderIdentityKeyError,
+    ProviderLoginError,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -28,6 +33,13 @@ pub trait AuthenticationService: Sync + Send {
 
     async fn account_by_token(&self, access_token: String) -> Result<Account, GetAccountInfoError>;
 
+    /// Same as [`AuthenticationService::account_by_token`], but also returns
+    /// the scopes the token is restricted to
+    async fn authenticate_by_token(
+        &self,
+        access_token: String,
+    ) -> Result<AuthenticatedAccount, GetAccountInfoError>;
+
     async fn account_by_id(&self, account_id: &AccountID)
         -> Result<Option<Account>, InternalError>;
 
@@ -63,6 +75,14 @@ pub struct LoginResponse {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone)]
+pub struct AuthenticatedAccount {
+    pub account: Account,
+    pub token_scopes: AccessTokenScopes,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Error)]
 pub enum LoginError {
     #[error(transparent)]
@@ -16,7 +16,9 @@ use thiserror::Error;
 
 use crate::{
     AccessTokenError,
+    AccessTokenScopes,
     Account,
+    AuthenticatedAccount,
     AuthenticationService,
     GetAccountInfoError,
     LoginError,
@@ -50,6 +52,11 @@ mockall::mock! {
             access_token: String,
         ) -> Result<Account, GetAccountInfoError>;
 
+        async fn authenticate_by_token(
+            &self,
+            access_token: String,
+        ) -> Result<AuthenticatedAccount, GetAccountInfoError>;
+
         async fn account_by_id(
             &self,
             account_id: &AccountID,
@@ -116,6 +123,15 @@ impl MockAuthenticationService {
             .with(eq(DUMMY_ACCESS_TOKEN.to_string()))
             .returning(|_| Ok(Account::dummy()));
         mock_authentication_service
+            .expect_authenticate_by_token()
+            .with(eq(DUMMY_ACCESS_TOKEN.to_string()))
+            .returning(|_| {
+                Ok(AuthenticatedAccount {
+                    account: Account::dummy(),
+                    token_scopes: AccessTokenScopes::Unrestricted,
+                })
+            });
+        mock_authentication_service
     }
 
     pub fn unsupported_login_method() -> Self {
@@ -138,6 +154,10 @@ impl MockAuthenticationService {
             .with(eq(DUMMY_ACCESS_TOKEN.to_string()))
             .returning(|_| Err(GetAccountInfoError::AccessToken(AccessTokenError::Expired)));
         mock_authentication_service
+            .expect_authenticate_by_token()
+            .with(eq(DUMMY_ACCESS_TOKEN.to_string()))
+            .returning(|_| Err(GetAccountInfoError::AccessToken(AccessTokenError::Expired)));
+        mock_authentication_service
     }
 
     pub fn invalid_token() -> Self {
@@ -159,20 +179,48 @@ impl MockAuthenticationService {
                 )))
             });
         mock_authentication_service
+            .expect_authenticate_by_token()
+            .with(eq(DUMMY_ACCESS_TOKEN.to_string()))
+            .returning(|_| {
+                Err(GetAccountInfoError::AccessToken(AccessTokenError::Invalid(
+                    Box::new(InvalidTokenError {}),
+                )))
+            });
+        mock_authentication_service
     }
 
     pub fn resolving_token(access_token: &str, expected_account_info: Account) -> Self {
+        Self::resolving_scoped_token(
+            access_token,
+            expected_account_info,
+            AccessTokenScopes::Unrestricted,
+        )
+    }
+
+    pub fn resolving_scoped_token(
+        access_token: &str,
+        expected_account_info: Account,
+        token_scopes: AccessTokenScopes,
+    ) -> Self {
         let mut mock_authentication_service = MockAuthenticationService::new();
         let account_cloned = expected_account_info.clone();
         mock_authentication_service
             .expect_account_by_id()
             .with(eq(account_cloned.id.clone()))
             .returning(move |_| Ok(Some(account_cloned.clone())));
+        let authenticated_account = AuthenticatedAccount {
+            account: expected_account_info.clone(),
+            token_scopes,
+        };
         mock_authentication_service
             .expect_account_by_token()
             .with(eq(access_token.to_string()))
             .returning(move |_| Ok(expected_account_info.clone()));
         mock_authentication_service
+            .expect_authenticate_by_token()
+            .with(eq(access_token.to_string()))
+            .returning(move |_| Ok(authenticated_account.clone()));
+        mock_authentication_service
     }
 }
 
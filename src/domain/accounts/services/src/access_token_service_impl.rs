@@ -9,20 +9,23 @@
 
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use database_common::PaginationOpts;
 use dill::*;
 use kamu_accounts::{
     AccessToken,
     AccessTokenListing,
     AccessTokenRepository,
+    AccessTokenScopes,
     AccessTokenService,
-    Account,
+    AuthenticatedAccount,
     CreateAccessTokenError,
     FindAccountByTokenError,
     GetAccessTokenError,
     KamuAccessToken,
     RevokeTokenError,
 };
+use kamu_audit_log::{AuditAction, AuditLogService};
 use opendatafabric::AccountID;
 use time_source::SystemTimeSource;
 use uuid::Uuid;
@@ -38,6 +41,7 @@ pub const ENCODED_ACCESS_TOKEN_LENGTH: usize = 61;
 pub struct AccessTokenServiceImpl {
     access_token_repository: Arc<dyn AccessTokenRepository>,
     time_source: Arc<dyn SystemTimeSource>,
+    audit_log_service: Arc<dyn AuditLogService>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -49,10 +53,12 @@ impl AccessTokenServiceImpl {
     pub fn new(
         access_token_repository: Arc<dyn AccessTokenRepository>,
         time_source: Arc<dyn SystemTimeSource>,
+        audit_log_service: Arc<dyn AuditLogService>,
     ) -> Self {
         Self {
             access_token_repository,
             time_source,
+            audit_log_service,
         }
     }
 }
@@ -65,7 +71,14 @@ impl AccessTokenService for AccessTokenServiceImpl {
         &self,
         token_name: &str,
         account_id: &AccountID,
+        expires_at: Option<DateTime<Utc>>,
+        scopes: AccessTokenScopes,
     ) -> Result<KamuAccessToken, CreateAccessTokenError> {
+        let now = self.time_source.now();
+        if expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(CreateAccessTokenError::AlreadyExpired);
+        }
+
         let kamu_access_token = KamuAccessToken::new();
 
         self.access_token_repository
@@ -73,12 +86,26 @@ impl AccessTokenService for AccessTokenServiceImpl {
                 id: kamu_access_token.id,
                 token_name: token_name.to_string(),
                 token_hash: kamu_access_token.random_bytes_hash,
-                created_at: self.time_source.now(),
+                created_at: now,
                 revoked_at: None,
+                expires_at,
+                scopes,
                 account_id: account_id.clone(),
             })
             .await?;
 
+        self.audit_log_service
+            .record(
+                Some(account_id),
+                AuditAction::AccessTokenCreated,
+                None,
+                Some(format!(
+                    "Created access token '{token_name}' ({})",
+                    kamu_access_token.id
+                )),
+            )
+            .await?;
+
         Ok(kamu_access_token)
     }
 
@@ -86,10 +113,29 @@ impl AccessTokenService for AccessTokenServiceImpl {
         &self,
         token_id: &Uuid,
         token_hash: [u8; 32],
-    ) -> Result<Account, FindAccountByTokenError> {
-        self.access_token_repository
+    ) -> Result<AuthenticatedAccount, FindAccountByTokenError> {
+        let account = self
+            .access_token_repository
             .find_account_by_active_token_id(token_id, token_hash)
+            .await?;
+
+        let access_token = self
+            .access_token_repository
+            .get_token_by_id(token_id)
             .await
+            .map_err(|e| match e {
+                GetAccessTokenError::NotFound(e) => FindAccountByTokenError::NotFound(e),
+                GetAccessTokenError::Internal(e) => FindAccountByTokenError::Internal(e),
+            })?;
+
+        if access_token.is_expired(self.time_source.now()) {
+            return Err(FindAccountByTokenError::Expired);
+        }
+
+        Ok(AuthenticatedAccount {
+            account,
+            token_scopes: access_token.scopes,
+        })
     }
 
     async fn get_access_tokens_by_account_id(
@@ -118,10 +164,37 @@ impl AccessTokenService for AccessTokenServiceImpl {
         })
     }
 
-    async fn revoke_access_token(&self, token_id: &Uuid) -> Result<(), RevokeTokenError> {
+    async fn revoke_access_token(
+        &self,
+        token_id: &Uuid,
+        revoked_by: Option<&AccountID>,
+    ) -> Result<(), RevokeTokenError> {
         self.access_token_repository
             .mark_revoked(token_id, self.time_source.now())
+            .await?;
+
+        let access_token = self
+            .access_token_repository
+            .get_token_by_id(token_id)
             .await
+            .map_err(|e| match e {
+                GetAccessTokenError::NotFound(e) => RevokeTokenError::NotFound(e),
+                GetAccessTokenError::Internal(e) => RevokeTokenError::Internal(e),
+            })?;
+
+        self.audit_log_service
+            .record(
+                revoked_by,
+                AuditAction::AccessTokenRevoked,
+                None,
+                Some(format!(
+                    "Revoked access token '{}' ({token_id})",
+                    access_token.token_name
+                )),
+            )
+            .await?;
+
+        Ok(())
     }
 
     async fn get_token_by_id(&self, token_id: &Uuid) -> Result<AccessToken, GetAccessTokenError> {
@@ -90,6 +90,19 @@ impl AuthenticationServiceImpl {
         &self,
         account_id: &AccountID,
         expiration_time_sec: usize,
+    ) -> Result<String, InternalError> {
+        self.make_scoped_access_token(
+            account_id,
+            expiration_time_sec,
+            AccessTokenScopes::Unrestricted,
+        )
+    }
+
+    pub fn make_scoped_access_token(
+        &self,
+        account_id: &AccountID,
+        expiration_time_sec: usize,
+        scopes: AccessTokenScopes,
     ) -> Result<String, InternalError> {
         let current_time = self.time_source.now();
         let iat = usize::try_from(current_time.timestamp()).unwrap();
@@ -100,6 +113,7 @@ impl AuthenticationServiceImpl {
             exp,
             iss: String::from(KAMU_JWT_ISSUER),
             sub: account_id.to_string(),
+            scopes,
         };
 
         encode(
@@ -133,7 +147,7 @@ impl AuthenticationServiceImpl {
     pub async fn account_by_token_impl(
         &self,
         access_token: &str,
-    ) -> Result<Account, GetAccountInfoError> {
+    ) -> Result<AuthenticatedAccount, GetAccountInfoError> {
         let decoded_access_token = self
             .decode_access_token(access_token)
             .map_err(GetAccountInfoError::AccessToken)?;
@@ -144,7 +158,10 @@ impl AuthenticationServiceImpl {
                     .map_err(|e| GetAccountInfoError::Internal(e.int_err()))?;
 
                 match self.account_by_id(&account_id).await {
-                    Ok(Some(account)) => Ok(account),
+                    Ok(Some(account)) => Ok(AuthenticatedAccount {
+                        account,
+                        token_scopes: token_data.claims.scopes,
+                    }),
                     Ok(None) => Err(GetAccountInfoError::AccountUnresolved),
                     Err(e) => Err(GetAccountInfoError::Internal(e)),
                 }
@@ -161,6 +178,9 @@ impl AuthenticationServiceImpl {
                     FindAccountByTokenError::InvalidTokenHash => {
                         GetAccountInfoError::AccessToken(AccessTokenError::Invalid(Box::new(err)))
                     }
+                    FindAccountByTokenError::Expired => {
+                        GetAccountInfoError::AccessToken(AccessTokenError::Expired)
+                    }
                     FindAccountByTokenError::Internal(err) => GetAccountInfoError::Internal(err),
                 }),
         }
@@ -239,6 +259,15 @@ impl AuthenticationService for AuthenticationServiceImpl {
     }
 
     async fn account_by_token(&self, access_token: String) -> Result<Account, GetAccountInfoError> {
+        self.account_by_token_impl(&access_token)
+            .await
+            .map(|authenticated| authenticated.account)
+    }
+
+    async fn authenticate_by_token(
+        &self,
+        access_token: String,
+    ) -> Result<AuthenticatedAccount, GetAccountInfoError> {
         self.account_by_token_impl(&access_token).await
     }
 
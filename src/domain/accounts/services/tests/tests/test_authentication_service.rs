@@ -9,10 +9,12 @@
 
 use std::assert_matches::assert_matches;
 
+use chrono::{Duration, Utc};
 use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin};
 use kamu_accounts::*;
 use kamu_accounts_inmem::{InMemoryAccessTokenRepository, InMemoryAccountRepository};
 use kamu_accounts_services::{AccessTokenServiceImpl, AuthenticationServiceImpl};
+use kamu_audit_log::testing::DummyAuditLogService;
 use opendatafabric::{AccountID, AccountName};
 use time_source::{SystemTimeSource, SystemTimeSourceStub};
 
@@ -94,6 +96,97 @@ async fn test_use_bad_access_token() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_use_scoped_jwt_access_token() {
+    let catalog = make_catalog();
+    let authentication_service = catalog.get_one::<AuthenticationServiceImpl>().unwrap();
+
+    let login_response = authentication_service
+        .login("method-A", "dummy".to_string())
+        .await
+        .unwrap();
+
+    let scopes = AccessTokenScopes::Restricted(vec![
+        AccessTokenScope::Read,
+        AccessTokenScope::FlowTrigger(None),
+    ]);
+    let access_token = authentication_service
+        .make_scoped_access_token(&login_response.account_id, 60, scopes.clone())
+        .unwrap();
+
+    let authenticated = authentication_service
+        .authenticate_by_token(access_token)
+        .await
+        .unwrap();
+    assert_eq!(authenticated.account.id, login_response.account_id);
+    assert_eq!(authenticated.token_scopes, scopes);
+
+    let authenticated = authentication_service
+        .authenticate_by_token(login_response.access_token)
+        .await
+        .unwrap();
+    assert_eq!(authenticated.token_scopes, AccessTokenScopes::Unrestricted);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_use_expiring_kamu_access_token() {
+    let catalog = make_catalog();
+    let authentication_service = catalog.get_one::<dyn AuthenticationService>().unwrap();
+    let access_token_service = catalog.get_one::<dyn AccessTokenService>().unwrap();
+    let time_source = catalog.get_one::<SystemTimeSourceStub>().unwrap();
+
+    let now = Utc::now();
+    time_source.set(now);
+
+    let login_response = authentication_service
+        .login("method-A", "dummy".to_string())
+        .await
+        .unwrap();
+
+    let scopes = AccessTokenScopes::Restricted(vec![AccessTokenScope::Read]);
+    let access_token = access_token_service
+        .create_access_token(
+            "foo",
+            &login_response.account_id,
+            Some(now + Duration::hours(1)),
+            scopes.clone(),
+        )
+        .await
+        .unwrap();
+
+    let authenticated = authentication_service
+        .authenticate_by_token(access_token.composed_token.clone())
+        .await
+        .unwrap();
+    assert_eq!(authenticated.account.id, login_response.account_id);
+    assert_eq!(authenticated.token_scopes, scopes);
+
+    time_source.set(now + Duration::hours(2));
+
+    assert_matches!(
+        authentication_service
+            .authenticate_by_token(access_token.composed_token)
+            .await,
+        Err(GetAccountInfoError::AccessToken(AccessTokenError::Expired))
+    );
+
+    assert_matches!(
+        access_token_service
+            .create_access_token(
+                "bar",
+                &login_response.account_id,
+                Some(now),
+                AccessTokenScopes::Unrestricted,
+            )
+            .await,
+        Err(CreateAccessTokenError::AlreadyExpired)
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 fn make_catalog() -> dill::Catalog {
     let mut b = dill::CatalogBuilder::new();
 
@@ -102,6 +195,7 @@ fn make_catalog() -> dill::Catalog {
         .add::<AuthenticationServiceImpl>()
         .add::<InMemoryAccountRepository>()
         .add::<AccessTokenServiceImpl>()
+        .add::<DummyAuditLogService>()
         .add::<InMemoryAccessTokenRepository>()
         .add_value(PredefinedAccountsConfig::single_tenant())
         .add_value(SystemTimeSourceStub::new())
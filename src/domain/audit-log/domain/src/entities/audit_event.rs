@@ -0,0 +1,154 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use opendatafabric::{AccountID, DatasetID};
+use thiserror::Error;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Record of a security-relevant or mutating operation. Events are only ever
+/// appended to the log and never modified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub event_time: DateTime<Utc>,
+    /// Account that performed the operation, absent for anonymous access
+    pub account_id: Option<AccountID>,
+    pub action: AuditAction,
+    /// Dataset affected by the operation, if any
+    pub dataset_id: Option<DatasetID>,
+    /// Human-readable description of the operation
+    pub details: Option<String>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AuditAction {
+    DatasetDeleted,
+    DatasetRenamed,
+    DatasetReset,
+    AccessTokenCreated,
+    AccessTokenRevoked,
+    FlowConfigurationUpdated,
+    DatasetRoleGranted,
+    DatasetRoleRevoked,
+    OrganizationMemberRoleSet,
+    OrganizationMemberRemoved,
+}
+
+impl AuditAction {
+    pub fn all() -> &'static [AuditAction] {
+        &[
+            Self::DatasetDeleted,
+            Self::DatasetRenamed,
+            Self::DatasetReset,
+            Self::AccessTokenCreated,
+            Self::AccessTokenRevoked,
+            Self::FlowConfigurationUpdated,
+            Self::DatasetRoleGranted,
+            Self::DatasetRoleRevoked,
+            Self::OrganizationMemberRoleSet,
+            Self::OrganizationMemberRemoved,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DatasetDeleted => "dataset_deleted",
+            Self::DatasetRenamed => "dataset_renamed",
+            Self::DatasetReset => "dataset_reset",
+            Self::AccessTokenCreated => "access_token_created",
+            Self::AccessTokenRevoked => "access_token_revoked",
+            Self::FlowConfigurationUpdated => "flow_configuration_updated",
+            Self::DatasetRoleGranted => "dataset_role_granted",
+            Self::DatasetRoleRevoked => "dataset_role_revoked",
+            Self::OrganizationMemberRoleSet => "organization_member_role_set",
+            Self::OrganizationMemberRemoved => "organization_member_removed",
+        }
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = InvalidAuditActionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .iter()
+            .find(|action| action.as_str() == s)
+            .copied()
+            .ok_or_else(|| InvalidAuditActionError {
+                value: s.to_string(),
+            })
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Unknown audit action: '{value}'")]
+pub struct InvalidAuditActionError {
+    pub value: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Criteria of audit events selection, unset criteria match any event
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditEventFilter {
+    pub account_id: Option<AccountID>,
+    pub dataset_id: Option<DatasetID>,
+    pub action: Option<AuditAction>,
+    /// Inclusive lower bound of the event time
+    pub from_time: Option<DateTime<Utc>>,
+    /// Exclusive upper bound of the event time
+    pub to_time: Option<DateTime<Utc>>,
+}
+
+impl AuditEventFilter {
+    pub fn matches(&self, event: &AuditEvent) -> bool {
+        if let Some(account_id) = &self.account_id
+            && event.account_id.as_ref() != Some(account_id)
+        {
+            return false;
+        }
+        if let Some(dataset_id) = &self.dataset_id
+            && event.dataset_id.as_ref() != Some(dataset_id)
+        {
+            return false;
+        }
+        if let Some(action) = self.action
+            && event.action != action
+        {
+            return false;
+        }
+        if let Some(from_time) = self.from_time
+            && event.event_time < from_time
+        {
+            return false;
+        }
+        if let Some(to_time) = self.to_time
+            && event.event_time >= to_time
+        {
+            return false;
+        }
+        true
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,32 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::PaginationOpts;
+use internal_error::InternalError;
+
+use crate::{AuditEvent, AuditEventFilter};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Append-only storage of audit events
+#[async_trait::async_trait]
+pub trait AuditEventRepository: Send + Sync {
+    async fn save_event(&self, event: &AuditEvent) -> Result<(), InternalError>;
+
+    async fn get_events_count(&self, filter: &AuditEventFilter) -> Result<usize, InternalError>;
+
+    /// Returns events matching the filter, the most recent ones first
+    async fn get_events(
+        &self,
+        filter: &AuditEventFilter,
+        pagination: &PaginationOpts,
+    ) -> Result<Vec<AuditEvent>, InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
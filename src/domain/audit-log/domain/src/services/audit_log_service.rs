@@ -0,0 +1,46 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::PaginationOpts;
+use internal_error::InternalError;
+use opendatafabric::{AccountID, DatasetID};
+
+use crate::{AuditAction, AuditEvent, AuditEventFilter};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Records operations into the audit log. Callers are expected to record an
+/// operation only after it has succeeded, and within the same transaction.
+#[async_trait::async_trait]
+pub trait AuditLogService: Send + Sync {
+    async fn record(
+        &self,
+        account_id: Option<&AccountID>,
+        action: AuditAction,
+        dataset_id: Option<&DatasetID>,
+        details: Option<String>,
+    ) -> Result<(), InternalError>;
+
+    /// Lists recorded events, the most recent ones first
+    async fn list_events(
+        &self,
+        filter: &AuditEventFilter,
+        pagination: PaginationOpts,
+    ) -> Result<AuditEventListing, InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+pub struct AuditEventListing {
+    pub list: Vec<AuditEvent>,
+    pub total_count: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,48 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::PaginationOpts;
+use dill::{component, interface};
+use internal_error::InternalError;
+use opendatafabric::{AccountID, DatasetID};
+
+use crate::{AuditAction, AuditEventFilter, AuditEventListing, AuditLogService};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Audit log that discards all events, for tests that don't verify auditing
+#[component(pub)]
+#[interface(dyn AuditLogService)]
+pub struct DummyAuditLogService {}
+
+#[async_trait::async_trait]
+impl AuditLogService for DummyAuditLogService {
+    async fn record(
+        &self,
+        _account_id: Option<&AccountID>,
+        _action: AuditAction,
+        _dataset_id: Option<&DatasetID>,
+        _details: Option<String>,
+    ) -> Result<(), InternalError> {
+        Ok(())
+    }
+
+    async fn list_events(
+        &self,
+        _filter: &AuditEventFilter,
+        _pagination: PaginationOpts,
+    ) -> Result<AuditEventListing, InternalError> {
+        Ok(AuditEventListing {
+            list: vec![],
+            total_count: 0,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,85 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use database_common::PaginationOpts;
+use dill::{component, interface};
+use internal_error::InternalError;
+use kamu_audit_log::{
+    AuditAction,
+    AuditEvent,
+    AuditEventFilter,
+    AuditEventListing,
+    AuditEventRepository,
+    AuditLogService,
+};
+use opendatafabric::{AccountID, DatasetID};
+use time_source::SystemTimeSource;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct AuditLogServiceImpl {
+    audit_event_repo: Arc<dyn AuditEventRepository>,
+    time_source: Arc<dyn SystemTimeSource>,
+}
+
+#[component(pub)]
+#[interface(dyn AuditLogService)]
+impl AuditLogServiceImpl {
+    pub fn new(
+        audit_event_repo: Arc<dyn AuditEventRepository>,
+        time_source: Arc<dyn SystemTimeSource>,
+    ) -> Self {
+        Self {
+            audit_event_repo,
+            time_source,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogService for AuditLogServiceImpl {
+    async fn record(
+        &self,
+        account_id: Option<&AccountID>,
+        action: AuditAction,
+        dataset_id: Option<&DatasetID>,
+        details: Option<String>,
+    ) -> Result<(), InternalError> {
+        let event = AuditEvent {
+            id: Uuid::new_v4(),
+            event_time: self.time_source.now(),
+            account_id: account_id.cloned(),
+            action,
+            dataset_id: dataset_id.cloned(),
+            details,
+        };
+
+        self.audit_event_repo.save_event(&event).await
+    }
+
+    async fn list_events(
+        &self,
+        filter: &AuditEventFilter,
+        pagination: PaginationOpts,
+    ) -> Result<AuditEventListing, InternalError> {
+        let total_count = self.audit_event_repo.get_events_count(filter).await?;
+
+        let list = self
+            .audit_event_repo
+            .get_events(filter, &pagination)
+            .await?;
+
+        Ok(AuditEventListing { list, total_count })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,161 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use chrono::{Duration, TimeZone, Utc};
+use database_common::PaginationOpts;
+use dill::CatalogBuilder;
+use kamu_audit_log::*;
+use kamu_audit_log_inmem::InMemoryAuditEventRepository;
+use kamu_audit_log_services::AuditLogServiceImpl;
+use opendatafabric::{AccountID, DatasetID};
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_record_and_list_events() {
+    let harness = AuditLogServiceHarness::new();
+
+    let alice_id = AccountID::new_seeded_ed25519(b"alice");
+    let bob_id = AccountID::new_seeded_ed25519(b"bob");
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+
+    harness
+        .audit_log_service
+        .record(
+            Some(&alice_id),
+            AuditAction::DatasetRenamed,
+            Some(&foo_id),
+            Some("Renamed dataset 'foo' to 'bar'".to_string()),
+        )
+        .await
+        .unwrap();
+
+    harness
+        .time_source
+        .set(AuditLogServiceHarness::start_time() + Duration::minutes(1));
+
+    harness
+        .audit_log_service
+        .record(Some(&bob_id), AuditAction::AccessTokenCreated, None, None)
+        .await
+        .unwrap();
+
+    harness
+        .time_source
+        .set(AuditLogServiceHarness::start_time() + Duration::minutes(2));
+
+    harness
+        .audit_log_service
+        .record(
+            Some(&alice_id),
+            AuditAction::DatasetDeleted,
+            Some(&foo_id),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let listing = harness
+        .audit_log_service
+        .list_events(
+            &AuditEventFilter::default(),
+            PaginationOpts {
+                offset: 0,
+                limit: 2,
+            },
+        )
+        .await
+        .unwrap();
+
+    // The most recent events go first, the count is not affected by pagination
+    assert_eq!(listing.total_count, 3);
+    pretty_assertions::assert_eq!(
+        listing
+            .list
+            .iter()
+            .map(|e| (e.action, e.account_id.clone(), e.event_time))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                AuditAction::DatasetDeleted,
+                Some(alice_id.clone()),
+                AuditLogServiceHarness::start_time() + Duration::minutes(2)
+            ),
+            (
+                AuditAction::AccessTokenCreated,
+                Some(bob_id.clone()),
+                AuditLogServiceHarness::start_time() + Duration::minutes(1)
+            ),
+        ]
+    );
+
+    let listing = harness
+        .audit_log_service
+        .list_events(
+            &AuditEventFilter {
+                account_id: Some(alice_id.clone()),
+                dataset_id: Some(foo_id.clone()),
+                action: Some(AuditAction::DatasetRenamed),
+                ..Default::default()
+            },
+            PaginationOpts {
+                offset: 0,
+                limit: 10,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(listing.total_count, 1);
+    assert_eq!(
+        listing.list[0].details.as_deref(),
+        Some("Renamed dataset 'foo' to 'bar'")
+    );
+    assert_eq!(
+        listing.list[0].event_time,
+        AuditLogServiceHarness::start_time()
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct AuditLogServiceHarness {
+    audit_log_service: Arc<dyn AuditLogService>,
+    time_source: Arc<SystemTimeSourceStub>,
+}
+
+impl AuditLogServiceHarness {
+    fn new() -> Self {
+        let catalog = {
+            let mut b = CatalogBuilder::new();
+
+            b.add::<AuditLogServiceImpl>();
+            b.add::<InMemoryAuditEventRepository>();
+
+            b.add_value(SystemTimeSourceStub::new_set(Self::start_time()));
+            b.bind::<dyn SystemTimeSource, SystemTimeSourceStub>();
+
+            b.build()
+        };
+
+        Self {
+            audit_log_service: catalog.get_one().unwrap(),
+            time_source: catalog.get_one().unwrap(),
+        }
+    }
+
+    fn start_time() -> chrono::DateTime<Utc> {
+        Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
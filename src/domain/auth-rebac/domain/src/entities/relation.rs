@@ -15,12 +15,16 @@ use internal_error::{InternalError, ResultIntoInternal};
 
 pub const RELATION_GROUP_SEPARATOR: &str = "/";
 const RELATION_GROUP_ACCOUNT_TO_DATASET: &str = "account->dataset";
+const RELATION_GROUP_ACCOUNT_TO_ORGANIZATION: &str = "account->organization";
+const RELATION_GROUP_ORGANIZATION_TO_DATASET: &str = "organization->dataset";
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Relation {
     AccountToDataset(AccountToDatasetRelation),
+    AccountToOrganization(AccountToOrganizationRelation),
+    OrganizationToDataset(OrganizationToDatasetRelation),
 }
 
 impl Relation {
@@ -32,9 +36,39 @@ impl Relation {
         Self::AccountToDataset(AccountToDatasetRelation::Editor)
     }
 
+    pub fn account_is_a_dataset_maintainer() -> Self {
+        Self::AccountToDataset(AccountToDatasetRelation::Maintainer)
+    }
+
+    pub fn account_is_a_dataset_admin() -> Self {
+        Self::AccountToDataset(AccountToDatasetRelation::Admin)
+    }
+
+    pub fn account_is_an_organization_owner() -> Self {
+        Self::AccountToOrganization(AccountToOrganizationRelation::Owner)
+    }
+
+    pub fn account_is_an_organization_maintainer() -> Self {
+        Self::AccountToOrganization(AccountToOrganizationRelation::Maintainer)
+    }
+
+    pub fn account_is_an_organization_member() -> Self {
+        Self::AccountToOrganization(AccountToOrganizationRelation::Member)
+    }
+
+    pub fn organization_is_a_dataset_reader() -> Self {
+        Self::OrganizationToDataset(OrganizationToDatasetRelation::Reader)
+    }
+
+    pub fn organization_is_a_dataset_editor() -> Self {
+        Self::OrganizationToDataset(OrganizationToDatasetRelation::Editor)
+    }
+
     pub fn relation_group(&self) -> &'static str {
         match self {
             Relation::AccountToDataset(_) => RELATION_GROUP_ACCOUNT_TO_DATASET,
+            Relation::AccountToOrganization(_) => RELATION_GROUP_ACCOUNT_TO_ORGANIZATION,
+            Relation::OrganizationToDataset(_) => RELATION_GROUP_ORGANIZATION_TO_DATASET,
         }
     }
 }
@@ -48,6 +82,18 @@ impl std::fmt::Display for Relation {
                     "{RELATION_GROUP_ACCOUNT_TO_DATASET}{RELATION_GROUP_SEPARATOR}{relation}"
                 )
             }
+            Self::AccountToOrganization(relation) => {
+                write!(
+                    f,
+                    "{RELATION_GROUP_ACCOUNT_TO_ORGANIZATION}{RELATION_GROUP_SEPARATOR}{relation}"
+                )
+            }
+            Self::OrganizationToDataset(relation) => {
+                write!(
+                    f,
+                    "{RELATION_GROUP_ORGANIZATION_TO_DATASET}{RELATION_GROUP_SEPARATOR}{relation}"
+                )
+            }
         }
     }
 }
@@ -69,6 +115,20 @@ impl FromStr for Relation {
 
                 Self::AccountToDataset(relation)
             }
+            group @ RELATION_GROUP_ACCOUNT_TO_ORGANIZATION => {
+                let relation = relation_name
+                    .parse::<AccountToOrganizationRelation>()
+                    .context_int_err(format!("group '{group}', relation_name '{relation_name}'"))?;
+
+                Self::AccountToOrganization(relation)
+            }
+            group @ RELATION_GROUP_ORGANIZATION_TO_DATASET => {
+                let relation = relation_name
+                    .parse::<OrganizationToDatasetRelation>()
+                    .context_int_err(format!("group '{group}', relation_name '{relation_name}'"))?;
+
+                Self::OrganizationToDataset(relation)
+            }
             unexpected_property_group => {
                 return InternalError::bail(format!(
                     "Unexpected relation group: '{unexpected_property_group}'"
@@ -85,10 +145,70 @@ impl FromStr for Relation {
 #[derive(
     Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, strum::EnumString, strum::Display,
 )]
+/// Role of an account in a dataset. Variants are ordered from the least to the
+/// most privileged one, every role includes permissions of the previous ones:
+/// - `Reader` can read the dataset
+/// - `Editor` can also commit new data and metadata
+/// - `Maintainer` can also configure and trigger flows
+/// - `Admin` can also manage roles of other accounts, rename and delete the
+///   dataset
 #[strum(serialize_all = "snake_case")]
 pub enum AccountToDatasetRelation {
     Reader,
     Editor,
+    Maintainer,
+    Admin,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Role of an account within an organization. Variants are ordered from the
+/// least to the most privileged one.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, strum::EnumString, strum::Display,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountToOrganizationRelation {
+    Member,
+    Maintainer,
+    Owner,
+}
+
+impl AccountToOrganizationRelation {
+    /// Relation to datasets owned by the organization, that members with this
+    /// role inherit
+    pub fn owned_dataset_relation(self) -> AccountToDatasetRelation {
+        match self {
+            Self::Owner => AccountToDatasetRelation::Admin,
+            Self::Maintainer => AccountToDatasetRelation::Maintainer,
+            Self::Member => AccountToDatasetRelation::Reader,
+        }
+    }
+
+    /// Relation to a dataset, that members with this role inherit from the
+    /// organization's own relation to it
+    pub fn inherited_dataset_relation(
+        self,
+        organization_relation: OrganizationToDatasetRelation,
+    ) -> AccountToDatasetRelation {
+        match (self, organization_relation) {
+            (Self::Owner | Self::Maintainer, OrganizationToDatasetRelation::Editor) => {
+                AccountToDatasetRelation::Editor
+            }
+            _ => AccountToDatasetRelation::Reader,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, strum::EnumString, strum::Display,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum OrganizationToDatasetRelation {
+    Reader,
+    Editor,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -57,6 +57,16 @@ pub trait RebacRepository: Send + Sync {
         object_entity: &Entity,
     ) -> Result<(), DeleteEntitiesRelationError>;
 
+    /// Deletes the relation unless it is the last relation of this kind to the
+    /// object entity. The check and the deletion are performed atomically,
+    /// so concurrent deletions can't remove all such relations.
+    async fn delete_entities_relation_unless_last(
+        &self,
+        subject_entity: &Entity,
+        relationship: Relation,
+        object_entity: &Entity,
+    ) -> Result<(), DeleteEntitiesRelationUnlessLastError>;
+
     async fn get_subject_entity_relations(
         &self,
         subject_entity: &Entity,
@@ -68,6 +78,11 @@ pub trait RebacRepository: Send + Sync {
         object_entity_type: EntityType,
     ) -> Result<Vec<EntityWithRelation>, SubjectEntityRelationsByObjectTypeError>;
 
+    async fn get_object_entity_relations(
+        &self,
+        object_entity: &Entity,
+    ) -> Result<Vec<EntityWithRelation>, ObjectEntityRelationsError>;
+
     async fn get_relations_between_entities(
         &self,
         subject_entity: &Entity,
@@ -219,6 +234,53 @@ pub struct EntitiesRelationNotFoundError {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Error, Debug)]
+pub enum DeleteEntitiesRelationUnlessLastError {
+    #[error(transparent)]
+    NotFound(EntitiesRelationNotFoundError),
+
+    #[error(transparent)]
+    Last(LastEntitiesRelationError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+impl DeleteEntitiesRelationUnlessLastError {
+    pub fn not_found(
+        subject_entity: &Entity,
+        relationship: Relation,
+        object_entity: &Entity,
+    ) -> Self {
+        Self::NotFound(EntitiesRelationNotFoundError {
+            subject_entity: subject_entity.clone().into_owned(),
+            relationship,
+            object_entity: object_entity.clone().into_owned(),
+        })
+    }
+
+    pub fn last(subject_entity: &Entity, relationship: Relation, object_entity: &Entity) -> Self {
+        Self::Last(LastEntitiesRelationError {
+            subject_entity: subject_entity.clone().into_owned(),
+            relationship,
+            object_entity: object_entity.clone().into_owned(),
+        })
+    }
+}
+
+#[derive(Error, Debug)]
+#[error(
+    "Last entities relation not deleted: subject_entity='{subject_entity:?}', \
+     relationship='{relationship:?}', object_entity='{object_entity:?}'"
+)]
+pub struct LastEntitiesRelationError {
+    pub subject_entity: Entity<'static>,
+    pub relationship: Relation,
+    pub object_entity: Entity<'static>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Error, Debug)]
 pub enum SubjectEntityRelationsError {
     #[error(transparent)]
@@ -235,6 +297,14 @@ pub enum SubjectEntityRelationsByObjectTypeError {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Error, Debug)]
+pub enum ObjectEntityRelationsError {
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Error, Debug)]
 pub enum GetRelationsBetweenEntitiesError {
     #[error(transparent)]
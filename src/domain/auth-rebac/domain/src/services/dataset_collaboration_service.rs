@@ -0,0 +1,71 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use opendatafabric::{AccountID, DatasetID};
+use thiserror::Error;
+
+use crate::{AccountToDatasetRelation, DatasetCollaborator};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Manages roles explicitly granted to accounts in datasets. Permission to do
+/// so is expected to be checked by the caller.
+#[async_trait::async_trait]
+pub trait DatasetCollaborationService: Send + Sync {
+    /// Grants the role to the account, replacing the previously granted one
+    async fn set_collaborator_role(
+        &self,
+        dataset_id: &DatasetID,
+        account_id: &AccountID,
+        role: AccountToDatasetRelation,
+    ) -> Result<(), InternalError>;
+
+    async fn remove_collaborator(
+        &self,
+        dataset_id: &DatasetID,
+        account_id: &AccountID,
+    ) -> Result<(), RemoveDatasetCollaboratorError>;
+
+    async fn get_collaborator_role(
+        &self,
+        dataset_id: &DatasetID,
+        account_id: &AccountID,
+    ) -> Result<Option<AccountToDatasetRelation>, InternalError>;
+
+    /// Returns collaborators of the dataset, the most privileged ones first
+    async fn get_collaborators(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetCollaborator>, InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum RemoveDatasetCollaboratorError {
+    #[error(transparent)]
+    NotACollaborator(NotADatasetCollaboratorError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+#[error("Account '{account_id}' has no role in dataset '{dataset_id}'")]
+pub struct NotADatasetCollaboratorError {
+    pub dataset_id: DatasetID,
+    pub account_id: AccountID,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
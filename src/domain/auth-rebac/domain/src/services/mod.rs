@@ -7,6 +7,10 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod dataset_collaboration_service;
+mod organization_membership_service;
 mod rebac_service;
 
+pub use dataset_collaboration_service::*;
+pub use organization_membership_service::*;
 pub use rebac_service::*;
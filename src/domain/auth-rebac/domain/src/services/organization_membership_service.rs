@@ -0,0 +1,115 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use opendatafabric::AccountID;
+use thiserror::Error;
+
+use crate::{AccountToOrganizationRelation, OrganizationMembership};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Manages members of organization accounts and their roles
+#[async_trait::async_trait]
+pub trait OrganizationMembershipService: Send + Sync {
+    /// Adds the account to the organization or changes its role if it is
+    /// already a member
+    async fn set_member_role(
+        &self,
+        organization_id: &AccountID,
+        account_id: &AccountID,
+        role: AccountToOrganizationRelation,
+    ) -> Result<(), SetOrganizationMemberRoleError>;
+
+    async fn remove_member(
+        &self,
+        organization_id: &AccountID,
+        account_id: &AccountID,
+    ) -> Result<(), RemoveOrganizationMemberError>;
+
+    async fn get_member_role(
+        &self,
+        organization_id: &AccountID,
+        account_id: &AccountID,
+    ) -> Result<Option<AccountToOrganizationRelation>, InternalError>;
+
+    /// Returns members of the organization, owners first
+    async fn get_members(
+        &self,
+        organization_id: &AccountID,
+    ) -> Result<Vec<OrganizationMembership>, InternalError>;
+
+    async fn get_account_organizations(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<OrganizationMembership>, InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum SetOrganizationMemberRoleError {
+    #[error(transparent)]
+    NotAnOrganization(NotAnOrganizationError),
+
+    #[error(transparent)]
+    NotAUser(NotAUserError),
+
+    #[error(transparent)]
+    LastOwner(LastOrganizationOwnerError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum RemoveOrganizationMemberError {
+    #[error(transparent)]
+    NotAMember(NotAnOrganizationMemberError),
+
+    #[error(transparent)]
+    LastOwner(LastOrganizationOwnerError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+#[error("Account '{account_id}' is not an organization")]
+pub struct NotAnOrganizationError {
+    pub account_id: AccountID,
+}
+
+#[derive(Error, Debug)]
+#[error("Account '{account_id}' is not a user and cannot join organizations")]
+pub struct NotAUserError {
+    pub account_id: AccountID,
+}
+
+#[derive(Error, Debug)]
+#[error("Account '{account_id}' is not a member of organization '{organization_id}'")]
+pub struct NotAnOrganizationMemberError {
+    pub organization_id: AccountID,
+    pub account_id: AccountID,
+}
+
+#[derive(Error, Debug)]
+#[error("Account '{account_id}' is the last owner of organization '{organization_id}'")]
+pub struct LastOrganizationOwnerError {
+    pub organization_id: AccountID,
+    pub account_id: AccountID,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
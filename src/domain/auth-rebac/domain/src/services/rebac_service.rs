@@ -14,10 +14,13 @@ use thiserror::Error;
 use crate::{
     AccountPropertyName,
     AccountToDatasetRelation,
+    AccountToOrganizationRelation,
     DatasetPropertyName,
     EntityNotFoundError,
     EntityWithRelation,
     GetEntityPropertiesError,
+    LastEntitiesRelationError,
+    OrganizationToDatasetRelation,
     PropertyName,
     PropertyValue,
     SetEntityPropertyError,
@@ -90,6 +93,87 @@ pub trait RebacService: Send + Sync {
         &self,
         account_id: &AccountID,
     ) -> Result<Vec<EntityWithRelation>, SubjectEntityRelationsError>;
+
+    /// Lists accounts having direct relations to the dataset
+    async fn get_dataset_collaborators(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetCollaborator>, GetDatasetCollaboratorsError>;
+
+    // Organizations
+    async fn insert_account_organization_relation(
+        &self,
+        account_id: &AccountID,
+        relationship: AccountToOrganizationRelation,
+        organization_id: &AccountID,
+    ) -> Result<(), InsertRelationError>;
+
+    async fn delete_account_organization_relation(
+        &self,
+        account_id: &AccountID,
+        relationship: AccountToOrganizationRelation,
+        organization_id: &AccountID,
+    ) -> Result<(), DeleteRelationError>;
+
+    /// Deletes the relation unless the account is the last one having this
+    /// relationship to the organization
+    async fn delete_account_organization_relation_unless_last(
+        &self,
+        account_id: &AccountID,
+        relationship: AccountToOrganizationRelation,
+        organization_id: &AccountID,
+    ) -> Result<(), DeleteRelationUnlessLastError>;
+
+    async fn get_account_organization_memberships(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<OrganizationMembership>, GetOrganizationMembershipsError>;
+
+    async fn get_organization_memberships(
+        &self,
+        organization_id: &AccountID,
+    ) -> Result<Vec<OrganizationMembership>, GetOrganizationMembershipsError>;
+
+    async fn insert_organization_dataset_relation(
+        &self,
+        organization_id: &AccountID,
+        relationship: OrganizationToDatasetRelation,
+        dataset_id: &DatasetID,
+    ) -> Result<(), InsertRelationError>;
+
+    async fn delete_organization_dataset_relation(
+        &self,
+        organization_id: &AccountID,
+        relationship: OrganizationToDatasetRelation,
+        dataset_id: &DatasetID,
+    ) -> Result<(), DeleteRelationError>;
+
+    /// Resolves the strongest relation of the account to the dataset, taking
+    /// into account both direct relations and the ones inherited through
+    /// memberships in organizations (including the organization that owns the
+    /// dataset)
+    async fn get_effective_account_dataset_relation(
+        &self,
+        account_id: &AccountID,
+        dataset_id: &DatasetID,
+        dataset_owner_id: Option<&AccountID>,
+    ) -> Result<Option<AccountToDatasetRelation>, GetEffectiveRelationError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrganizationMembership {
+    pub account_id: AccountID,
+    pub organization_id: AccountID,
+    pub role: AccountToOrganizationRelation,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatasetCollaborator {
+    pub account_id: AccountID,
+    pub dataset_id: DatasetID,
+    pub role: AccountToDatasetRelation,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -130,3 +214,38 @@ pub enum DeleteRelationError {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum DeleteRelationUnlessLastError {
+    #[error(transparent)]
+    Last(LastEntitiesRelationError),
+
+    #[error(transparent)]
+    Internal(InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetOrganizationMembershipsError {
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetDatasetCollaboratorsError {
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetEffectiveRelationError {
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
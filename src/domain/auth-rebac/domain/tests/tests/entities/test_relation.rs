@@ -10,7 +10,13 @@
 use std::assert_matches::assert_matches;
 use std::str::FromStr;
 
-use kamu_auth_rebac::{AccountToDatasetRelation, Relation, RELATION_GROUP_SEPARATOR};
+use kamu_auth_rebac::{
+    AccountToDatasetRelation,
+    AccountToOrganizationRelation,
+    OrganizationToDatasetRelation,
+    Relation,
+    RELATION_GROUP_SEPARATOR,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -71,7 +77,17 @@ fn test_try_parse_unexpected_relation_name() {
 
 #[test]
 fn test_parse_relation() {
-    let inputs = ["account->dataset/editor", "account->dataset/reader"];
+    let inputs = [
+        "account->dataset/admin",
+        "account->dataset/maintainer",
+        "account->dataset/editor",
+        "account->dataset/reader",
+        "account->organization/owner",
+        "account->organization/maintainer",
+        "account->organization/member",
+        "organization->dataset/editor",
+        "organization->dataset/reader",
+    ];
 
     for input in inputs {
         let relation = match Relation::from_str(input) {
@@ -86,3 +102,27 @@ fn test_parse_relation() {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_organization_role_dataset_relations() {
+    use {
+        AccountToDatasetRelation as D,
+        AccountToOrganizationRelation as O,
+        OrganizationToDatasetRelation as G,
+    };
+
+    assert_eq!(O::Owner.owned_dataset_relation(), D::Admin);
+    assert_eq!(O::Maintainer.owned_dataset_relation(), D::Maintainer);
+    assert_eq!(O::Member.owned_dataset_relation(), D::Reader);
+
+    assert_eq!(O::Owner.inherited_dataset_relation(G::Editor), D::Editor);
+    assert_eq!(
+        O::Maintainer.inherited_dataset_relation(G::Editor),
+        D::Editor
+    );
+    assert_eq!(O::Member.inherited_dataset_relation(G::Editor), D::Reader);
+    assert_eq!(O::Owner.inherited_dataset_relation(G::Reader), D::Reader);
+    assert_eq!(O::Member.inherited_dataset_relation(G::Reader), D::Reader);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,137 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_auth_rebac::{
+    AccountToDatasetRelation,
+    DatasetCollaborationService,
+    DatasetCollaborator,
+    NotADatasetCollaboratorError,
+    RebacService,
+    RemoveDatasetCollaboratorError,
+};
+use opendatafabric::{AccountID, DatasetID};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DatasetCollaborationServiceImpl {
+    rebac_service: Arc<dyn RebacService>,
+}
+
+#[component(pub)]
+#[interface(dyn DatasetCollaborationService)]
+impl DatasetCollaborationServiceImpl {
+    pub fn new(rebac_service: Arc<dyn RebacService>) -> Self {
+        Self { rebac_service }
+    }
+
+    async fn get_collaborator_roles(
+        &self,
+        dataset_id: &DatasetID,
+        account_id: &AccountID,
+    ) -> Result<Vec<AccountToDatasetRelation>, InternalError> {
+        let collaborators = self.get_collaborators(dataset_id).await?;
+
+        Ok(collaborators
+            .into_iter()
+            .filter(|collaborator| collaborator.account_id == *account_id)
+            .map(|collaborator| collaborator.role)
+            .collect())
+    }
+}
+
+#[async_trait::async_trait]
+impl DatasetCollaborationService for DatasetCollaborationServiceImpl {
+    #[tracing::instrument(level = "debug", skip_all, fields(%dataset_id, %account_id, %role))]
+    async fn set_collaborator_role(
+        &self,
+        dataset_id: &DatasetID,
+        account_id: &AccountID,
+        role: AccountToDatasetRelation,
+    ) -> Result<(), InternalError> {
+        let current_roles = self.get_collaborator_roles(dataset_id, account_id).await?;
+
+        if current_roles == [role] {
+            return Ok(());
+        }
+
+        for current_role in current_roles {
+            self.rebac_service
+                .delete_account_dataset_relation(account_id, current_role, dataset_id)
+                .await
+                .int_err()?;
+        }
+
+        self.rebac_service
+            .insert_account_dataset_relation(account_id, role, dataset_id)
+            .await
+            .int_err()
+    }
+
+    #[tracing::instrument(level = "debug", skip_all, fields(%dataset_id, %account_id))]
+    async fn remove_collaborator(
+        &self,
+        dataset_id: &DatasetID,
+        account_id: &AccountID,
+    ) -> Result<(), RemoveDatasetCollaboratorError> {
+        let current_roles = self.get_collaborator_roles(dataset_id, account_id).await?;
+
+        if current_roles.is_empty() {
+            return Err(RemoveDatasetCollaboratorError::NotACollaborator(
+                NotADatasetCollaboratorError {
+                    dataset_id: dataset_id.clone(),
+                    account_id: account_id.clone(),
+                },
+            ));
+        }
+
+        for current_role in current_roles {
+            self.rebac_service
+                .delete_account_dataset_relation(account_id, current_role, dataset_id)
+                .await
+                .int_err()?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_collaborator_role(
+        &self,
+        dataset_id: &DatasetID,
+        account_id: &AccountID,
+    ) -> Result<Option<AccountToDatasetRelation>, InternalError> {
+        let current_roles = self.get_collaborator_roles(dataset_id, account_id).await?;
+
+        Ok(current_roles.into_iter().max())
+    }
+
+    async fn get_collaborators(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetCollaborator>, InternalError> {
+        let mut collaborators = self
+            .rebac_service
+            .get_dataset_collaborators(dataset_id)
+            .await
+            .int_err()?;
+
+        collaborators.sort_by(|a, b| {
+            b.role
+                .cmp(&a.role)
+                .then_with(|| a.account_id.cmp(&b.account_id))
+        });
+
+        Ok(collaborators)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -10,10 +10,14 @@
 // Re-exports
 pub use kamu_auth_rebac as domain;
 
+mod dataset_collaboration_service_impl;
 mod messages;
 mod multi_tenant_rebac_dataset_lifecycle_message_consumer;
+mod organization_membership_service_impl;
 mod rebac_service_impl;
 
+pub use dataset_collaboration_service_impl::*;
 pub use messages::*;
 pub use multi_tenant_rebac_dataset_lifecycle_message_consumer::*;
+pub use organization_membership_service_impl::*;
 pub use rebac_service_impl::*;
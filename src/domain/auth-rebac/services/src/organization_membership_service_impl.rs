@@ -0,0 +1,232 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_accounts::{AccountRepository, AccountType, GetAccountByIdError};
+use kamu_auth_rebac::{
+    AccountToOrganizationRelation,
+    DeleteRelationUnlessLastError,
+    LastOrganizationOwnerError,
+    NotAUserError,
+    NotAnOrganizationError,
+    NotAnOrganizationMemberError,
+    OrganizationMembership,
+    OrganizationMembershipService,
+    RebacService,
+    RemoveOrganizationMemberError,
+    SetOrganizationMemberRoleError,
+};
+use opendatafabric::AccountID;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct OrganizationMembershipServiceImpl {
+    rebac_service: Arc<dyn RebacService>,
+    account_repo: Arc<dyn AccountRepository>,
+}
+
+#[component(pub)]
+#[interface(dyn OrganizationMembershipService)]
+impl OrganizationMembershipServiceImpl {
+    pub fn new(
+        rebac_service: Arc<dyn RebacService>,
+        account_repo: Arc<dyn AccountRepository>,
+    ) -> Self {
+        Self {
+            rebac_service,
+            account_repo,
+        }
+    }
+
+    async fn get_account_type(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Option<AccountType>, InternalError> {
+        match self.account_repo.get_account_by_id(account_id).await {
+            Ok(account) => Ok(Some(account.account_type)),
+            Err(GetAccountByIdError::NotFound(_)) => Ok(None),
+            Err(GetAccountByIdError::Internal(e)) => Err(e),
+        }
+    }
+
+    /// The owner role is removed only if the organization has other owners
+    async fn remove_member_role(
+        &self,
+        membership: &OrganizationMembership,
+    ) -> Result<(), DeleteRelationUnlessLastError> {
+        if membership.role == AccountToOrganizationRelation::Owner {
+            self.rebac_service
+                .delete_account_organization_relation_unless_last(
+                    &membership.account_id,
+                    membership.role,
+                    &membership.organization_id,
+                )
+                .await
+        } else {
+            self.rebac_service
+                .delete_account_organization_relation(
+                    &membership.account_id,
+                    membership.role,
+                    &membership.organization_id,
+                )
+                .await
+                .map_err(|e| DeleteRelationUnlessLastError::Internal(e.int_err()))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OrganizationMembershipService for OrganizationMembershipServiceImpl {
+    #[tracing::instrument(level = "debug", skip_all, fields(%organization_id, %account_id, %role))]
+    async fn set_member_role(
+        &self,
+        organization_id: &AccountID,
+        account_id: &AccountID,
+        role: AccountToOrganizationRelation,
+    ) -> Result<(), SetOrganizationMemberRoleError> {
+        if self.get_account_type(organization_id).await? != Some(AccountType::Organization) {
+            return Err(SetOrganizationMemberRoleError::NotAnOrganization(
+                NotAnOrganizationError {
+                    account_id: organization_id.clone(),
+                },
+            ));
+        }
+
+        if self.get_account_type(account_id).await? != Some(AccountType::User) {
+            return Err(SetOrganizationMemberRoleError::NotAUser(NotAUserError {
+                account_id: account_id.clone(),
+            }));
+        }
+
+        // Members are ordered by role, so the owner role is removed first and the
+        // membership stays intact if the account is the last owner
+        let current_memberships: Vec<_> = self
+            .get_members(organization_id)
+            .await?
+            .into_iter()
+            .filter(|membership| membership.account_id == *account_id)
+            .collect();
+
+        for membership in current_memberships.iter().filter(|m| m.role != role) {
+            self.remove_member_role(membership)
+                .await
+                .map_err(|e| match e {
+                    DeleteRelationUnlessLastError::Last(_) => {
+                        SetOrganizationMemberRoleError::LastOwner(LastOrganizationOwnerError {
+                            organization_id: organization_id.clone(),
+                            account_id: account_id.clone(),
+                        })
+                    }
+                    DeleteRelationUnlessLastError::Internal(e) => e.into(),
+                })?;
+        }
+
+        if !current_memberships.iter().any(|m| m.role == role) {
+            self.rebac_service
+                .insert_account_organization_relation(account_id, role, organization_id)
+                .await
+                .int_err()?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all, fields(%organization_id, %account_id))]
+    async fn remove_member(
+        &self,
+        organization_id: &AccountID,
+        account_id: &AccountID,
+    ) -> Result<(), RemoveOrganizationMemberError> {
+        let memberships = self.get_members(organization_id).await?;
+
+        let current_memberships: Vec<_> = memberships
+            .into_iter()
+            .filter(|membership| membership.account_id == *account_id)
+            .collect();
+
+        if current_memberships.is_empty() {
+            return Err(RemoveOrganizationMemberError::NotAMember(
+                NotAnOrganizationMemberError {
+                    organization_id: organization_id.clone(),
+                    account_id: account_id.clone(),
+                },
+            ));
+        }
+
+        // Members are ordered by role, so the owner role is removed first
+        for membership in &current_memberships {
+            self.remove_member_role(membership)
+                .await
+                .map_err(|e| match e {
+                    DeleteRelationUnlessLastError::Last(_) => {
+                        RemoveOrganizationMemberError::LastOwner(LastOrganizationOwnerError {
+                            organization_id: organization_id.clone(),
+                            account_id: account_id.clone(),
+                        })
+                    }
+                    DeleteRelationUnlessLastError::Internal(e) => e.into(),
+                })?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_member_role(
+        &self,
+        organization_id: &AccountID,
+        account_id: &AccountID,
+    ) -> Result<Option<AccountToOrganizationRelation>, InternalError> {
+        let memberships = self.get_members(organization_id).await?;
+
+        Ok(memberships
+            .into_iter()
+            .filter(|membership| membership.account_id == *account_id)
+            .map(|membership| membership.role)
+            .max())
+    }
+
+    async fn get_members(
+        &self,
+        organization_id: &AccountID,
+    ) -> Result<Vec<OrganizationMembership>, InternalError> {
+        let mut members = self
+            .rebac_service
+            .get_organization_memberships(organization_id)
+            .await
+            .int_err()?;
+
+        members.sort_by(|a, b| {
+            b.role
+                .cmp(&a.role)
+                .then_with(|| a.account_id.cmp(&b.account_id))
+        });
+
+        Ok(members)
+    }
+
+    async fn get_account_organizations(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<OrganizationMembership>, InternalError> {
+        let mut organizations = self
+            .rebac_service
+            .get_account_organization_memberships(account_id)
+            .await
+            .int_err()?;
+
+        organizations.sort_by(|a, b| a.organization_id.cmp(&b.organization_id));
+
+        Ok(organizations)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -10,20 +10,31 @@
 use std::sync::Arc;
 
 use dill::{component, interface};
+use internal_error::ResultIntoInternal;
 use kamu_auth_rebac::{
     AccountPropertyName,
     AccountToDatasetRelation,
+    AccountToOrganizationRelation,
+    DatasetCollaborator,
     DatasetPropertyName,
     DeleteEntitiesRelationError,
+    DeleteEntitiesRelationUnlessLastError,
     DeleteEntityPropertiesError,
     DeleteEntityPropertyError,
     DeletePropertiesError,
     DeleteRelationError,
+    DeleteRelationUnlessLastError,
     Entity,
+    EntityType,
     EntityWithRelation,
+    GetDatasetCollaboratorsError,
+    GetEffectiveRelationError,
     GetEntityPropertiesError,
+    GetOrganizationMembershipsError,
     InsertEntitiesRelationError,
     InsertRelationError,
+    OrganizationMembership,
+    OrganizationToDatasetRelation,
     PropertyName,
     PropertyValue,
     RebacRepository,
@@ -47,6 +58,44 @@ impl RebacServiceImpl {
     pub fn new(rebac_repo: Arc<dyn RebacRepository>) -> Self {
         Self { rebac_repo }
     }
+
+    async fn insert_relation(
+        &self,
+        subject_entity: &Entity<'_>,
+        relationship: Relation,
+        object_entity: &Entity<'_>,
+    ) -> Result<(), InsertRelationError> {
+        match self
+            .rebac_repo
+            .insert_entities_relation(subject_entity, relationship, object_entity)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => match err {
+                InsertEntitiesRelationError::Duplicate(_) => Ok(()),
+                InsertEntitiesRelationError::Internal(e) => Err(InsertRelationError::Internal(e)),
+            },
+        }
+    }
+
+    async fn delete_relation(
+        &self,
+        subject_entity: &Entity<'_>,
+        relationship: Relation,
+        object_entity: &Entity<'_>,
+    ) -> Result<(), DeleteRelationError> {
+        match self
+            .rebac_repo
+            .delete_entities_relation(subject_entity, relationship, object_entity)
+            .await
+        {
+            Ok(_) => Ok(()),
+            Err(err) => match err {
+                DeleteEntitiesRelationError::NotFound(_) => Ok(()),
+                DeleteEntitiesRelationError::Internal(e) => Err(DeleteRelationError::Internal(e)),
+            },
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -167,30 +216,18 @@ impl RebacService for RebacServiceImpl {
         relationship: AccountToDatasetRelation,
         dataset_id: &DatasetID,
     ) -> Result<(), InsertRelationError> {
-        use futures::FutureExt;
-
         let account_id = account_id.as_did_str().to_stack_string();
         let account_entity = Entity::new_account(account_id.as_str());
 
         let dataset_id = dataset_id.as_did_str().to_stack_string();
         let dataset_id_entity = Entity::new_dataset(dataset_id.as_str());
 
-        self.rebac_repo
-            .insert_entities_relation(
-                &account_entity,
-                Relation::AccountToDataset(relationship),
-                &dataset_id_entity,
-            )
-            .map(|res| match res {
-                Ok(_) => Ok(()),
-                Err(err) => match err {
-                    InsertEntitiesRelationError::Duplicate(_) => Ok(()),
-                    InsertEntitiesRelationError::Internal(e) => {
-                        Err(InsertRelationError::Internal(e))
-                    }
-                },
-            })
-            .await
+        self.insert_relation(
+            &account_entity,
+            Relation::AccountToDataset(relationship),
+            &dataset_id_entity,
+        )
+        .await
     }
 
     async fn delete_account_dataset_relation(
@@ -205,36 +242,288 @@ impl RebacService for RebacServiceImpl {
         let dataset_id = dataset_id.as_did_str().to_stack_string();
         let dataset_id_entity = Entity::new_dataset(dataset_id.as_str());
 
+        self.delete_relation(
+            &account_entity,
+            Relation::AccountToDataset(relationship),
+            &dataset_id_entity,
+        )
+        .await
+    }
+
+    async fn get_account_dataset_relations(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<EntityWithRelation>, SubjectEntityRelationsError> {
+        let account_id = account_id.as_did_str().to_stack_string();
+        let account_entity = Entity::new_account(account_id.as_str());
+
+        let object_entities = self
+            .rebac_repo
+            .get_subject_entity_relations(&account_entity)
+            .await?;
+
+        Ok(object_entities)
+    }
+
+    async fn get_dataset_collaborators(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<DatasetCollaborator>, GetDatasetCollaboratorsError> {
+        let dataset_id_stack = dataset_id.as_did_str().to_stack_string();
+        let dataset_id_entity = Entity::new_dataset(dataset_id_stack.as_str());
+
+        let subject_entities = self
+            .rebac_repo
+            .get_object_entity_relations(&dataset_id_entity)
+            .await
+            .int_err()?;
+
+        let mut collaborators = Vec::new();
+        for subject_entity in subject_entities {
+            let Relation::AccountToDataset(role) = subject_entity.relation else {
+                continue;
+            };
+
+            collaborators.push(DatasetCollaborator {
+                account_id: AccountID::from_did_str(&subject_entity.entity.entity_id).int_err()?,
+                dataset_id: dataset_id.clone(),
+                role,
+            });
+        }
+
+        Ok(collaborators)
+    }
+
+    async fn insert_account_organization_relation(
+        &self,
+        account_id: &AccountID,
+        relationship: AccountToOrganizationRelation,
+        organization_id: &AccountID,
+    ) -> Result<(), InsertRelationError> {
+        let account_id = account_id.as_did_str().to_stack_string();
+        let account_entity = Entity::new_account(account_id.as_str());
+
+        let organization_id = organization_id.as_did_str().to_stack_string();
+        let organization_entity = Entity::new_account(organization_id.as_str());
+
+        self.insert_relation(
+            &account_entity,
+            Relation::AccountToOrganization(relationship),
+            &organization_entity,
+        )
+        .await
+    }
+
+    async fn delete_account_organization_relation(
+        &self,
+        account_id: &AccountID,
+        relationship: AccountToOrganizationRelation,
+        organization_id: &AccountID,
+    ) -> Result<(), DeleteRelationError> {
+        let account_id = account_id.as_did_str().to_stack_string();
+        let account_entity = Entity::new_account(account_id.as_str());
+
+        let organization_id = organization_id.as_did_str().to_stack_string();
+        let organization_entity = Entity::new_account(organization_id.as_str());
+
+        self.delete_relation(
+            &account_entity,
+            Relation::AccountToOrganization(relationship),
+            &organization_entity,
+        )
+        .await
+    }
+
+    async fn delete_account_organization_relation_unless_last(
+        &self,
+        account_id: &AccountID,
+        relationship: AccountToOrganizationRelation,
+        organization_id: &AccountID,
+    ) -> Result<(), DeleteRelationUnlessLastError> {
+        let account_id = account_id.as_did_str().to_stack_string();
+        let account_entity = Entity::new_account(account_id.as_str());
+
+        let organization_id = organization_id.as_did_str().to_stack_string();
+        let organization_entity = Entity::new_account(organization_id.as_str());
+
         match self
             .rebac_repo
-            .delete_entities_relation(
+            .delete_entities_relation_unless_last(
                 &account_entity,
-                Relation::AccountToDataset(relationship),
-                &dataset_id_entity,
+                Relation::AccountToOrganization(relationship),
+                &organization_entity,
             )
             .await
         {
             Ok(_) => Ok(()),
             Err(err) => match err {
-                DeleteEntitiesRelationError::NotFound(_) => Ok(()),
-                DeleteEntitiesRelationError::Internal(e) => Err(DeleteRelationError::Internal(e)),
+                DeleteEntitiesRelationUnlessLastError::NotFound(_) => Ok(()),
+                DeleteEntitiesRelationUnlessLastError::Last(e) => {
+                    Err(DeleteRelationUnlessLastError::Last(e))
+                }
+                DeleteEntitiesRelationUnlessLastError::Internal(e) => {
+                    Err(DeleteRelationUnlessLastError::Internal(e))
+                }
             },
         }
     }
 
-    async fn get_account_dataset_relations(
+    async fn get_account_organization_memberships(
         &self,
         account_id: &AccountID,
-    ) -> Result<Vec<EntityWithRelation>, SubjectEntityRelationsError> {
-        let account_id = account_id.as_did_str().to_stack_string();
-        let account_entity = Entity::new_account(account_id.as_str());
+    ) -> Result<Vec<OrganizationMembership>, GetOrganizationMembershipsError> {
+        let account_id_stack = account_id.as_did_str().to_stack_string();
+        let account_entity = Entity::new_account(account_id_stack.as_str());
 
         let object_entities = self
             .rebac_repo
-            .get_subject_entity_relations(&account_entity)
-            .await?;
+            .get_subject_entity_relations_by_object_type(&account_entity, EntityType::Account)
+            .await
+            .int_err()?;
+
+        let mut memberships = Vec::new();
+        for object_entity in object_entities {
+            let Relation::AccountToOrganization(role) = object_entity.relation else {
+                continue;
+            };
+
+            memberships.push(OrganizationMembership {
+                account_id: account_id.clone(),
+                organization_id: AccountID::from_did_str(&object_entity.entity.entity_id)
+                    .int_err()?,
+                role,
+            });
+        }
 
-        Ok(object_entities)
+        Ok(memberships)
+    }
+
+    async fn get_organization_memberships(
+        &self,
+        organization_id: &AccountID,
+    ) -> Result<Vec<OrganizationMembership>, GetOrganizationMembershipsError> {
+        let organization_id_stack = organization_id.as_did_str().to_stack_string();
+        let organization_entity = Entity::new_account(organization_id_stack.as_str());
+
+        let subject_entities = self
+            .rebac_repo
+            .get_object_entity_relations(&organization_entity)
+            .await
+            .int_err()?;
+
+        let mut memberships = Vec::new();
+        for subject_entity in subject_entities {
+            let Relation::AccountToOrganization(role) = subject_entity.relation else {
+                continue;
+            };
+
+            memberships.push(OrganizationMembership {
+                account_id: AccountID::from_did_str(&subject_entity.entity.entity_id).int_err()?,
+                organization_id: organization_id.clone(),
+                role,
+            });
+        }
+
+        Ok(memberships)
+    }
+
+    async fn insert_organization_dataset_relation(
+        &self,
+        organization_id: &AccountID,
+        relationship: OrganizationToDatasetRelation,
+        dataset_id: &DatasetID,
+    ) -> Result<(), InsertRelationError> {
+        let organization_id = organization_id.as_did_str().to_stack_string();
+        let organization_entity = Entity::new_account(organization_id.as_str());
+
+        let dataset_id = dataset_id.as_did_str().to_stack_string();
+        let dataset_id_entity = Entity::new_dataset(dataset_id.as_str());
+
+        self.insert_relation(
+            &organization_entity,
+            Relation::OrganizationToDataset(relationship),
+            &dataset_id_entity,
+        )
+        .await
+    }
+
+    async fn delete_organization_dataset_relation(
+        &self,
+        organization_id: &AccountID,
+        relationship: OrganizationToDatasetRelation,
+        dataset_id: &DatasetID,
+    ) -> Result<(), DeleteRelationError> {
+        let organization_id = organization_id.as_did_str().to_stack_string();
+        let organization_entity = Entity::new_account(organization_id.as_str());
+
+        let dataset_id = dataset_id.as_did_str().to_stack_string();
+        let dataset_id_entity = Entity::new_dataset(dataset_id.as_str());
+
+        self.delete_relation(
+            &organization_entity,
+            Relation::OrganizationToDataset(relationship),
+            &dataset_id_entity,
+        )
+        .await
+    }
+
+    async fn get_effective_account_dataset_relation(
+        &self,
+        account_id: &AccountID,
+        dataset_id: &DatasetID,
+        dataset_owner_id: Option<&AccountID>,
+    ) -> Result<Option<AccountToDatasetRelation>, GetEffectiveRelationError> {
+        let account_id_stack = account_id.as_did_str().to_stack_string();
+        let account_entity = Entity::new_account(account_id_stack.as_str());
+
+        let dataset_id_stack = dataset_id.as_did_str().to_stack_string();
+        let dataset_id_entity = Entity::new_dataset(dataset_id_stack.as_str());
+
+        let mut effective_relation = None;
+
+        // Direct relations
+        let direct_relations = self
+            .rebac_repo
+            .get_relations_between_entities(&account_entity, &dataset_id_entity)
+            .await
+            .int_err()?;
+
+        for relation in direct_relations {
+            if let Relation::AccountToDataset(relation) = relation {
+                effective_relation = effective_relation.max(Some(relation));
+            }
+        }
+
+        // Relations inherited through organization memberships
+        let memberships = self
+            .get_account_organization_memberships(account_id)
+            .await
+            .int_err()?;
+
+        for membership in memberships {
+            if dataset_owner_id == Some(&membership.organization_id) {
+                effective_relation =
+                    effective_relation.max(Some(membership.role.owned_dataset_relation()));
+            }
+
+            let organization_id = membership.organization_id.as_did_str().to_stack_string();
+            let organization_entity = Entity::new_account(organization_id.as_str());
+
+            let organization_relations = self
+                .rebac_repo
+                .get_relations_between_entities(&organization_entity, &dataset_id_entity)
+                .await
+                .int_err()?;
+
+            for relation in organization_relations {
+                if let Relation::OrganizationToDataset(relation) = relation {
+                    effective_relation = effective_relation
+                        .max(Some(membership.role.inherited_dataset_relation(relation)));
+                }
+            }
+        }
+
+        Ok(effective_relation)
     }
 }
 
@@ -7,4 +7,6 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod test_dataset_collaboration_service;
 mod test_multi_tenant_rebac_dataset_lifecycle_message_consumer;
+mod test_organization_membership_service;
@@ -0,0 +1,132 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use dill::CatalogBuilder;
+use kamu_auth_rebac::{
+    AccountToDatasetRelation,
+    DatasetCollaborationService,
+    RebacService,
+    RemoveDatasetCollaboratorError,
+};
+use kamu_auth_rebac_inmem::InMemoryRebacRepository;
+use kamu_auth_rebac_services::{DatasetCollaborationServiceImpl, RebacServiceImpl};
+use opendatafabric::{AccountID, DatasetID};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_collaborator_roles_management() {
+    let harness = DatasetCollaborationHarness::new();
+
+    let alice_id = AccountID::new_seeded_ed25519(b"alice");
+    let bob_id = AccountID::new_seeded_ed25519(b"bob");
+
+    harness
+        .set_role(&alice_id, AccountToDatasetRelation::Reader)
+        .await;
+    harness
+        .set_role(&bob_id, AccountToDatasetRelation::Editor)
+        .await;
+
+    // Granting a new role replaces the previous one
+    harness
+        .set_role(&alice_id, AccountToDatasetRelation::Admin)
+        .await;
+
+    let collaborators: Vec<_> = harness
+        .collaboration_service
+        .get_collaborators(&harness.dataset_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|c| (c.account_id, c.role))
+        .collect();
+
+    // The most privileged collaborators are listed first
+    assert_eq!(
+        collaborators,
+        vec![
+            (alice_id.clone(), AccountToDatasetRelation::Admin),
+            (bob_id.clone(), AccountToDatasetRelation::Editor),
+        ]
+    );
+
+    // Direct relations are visible to the authorization checks
+    assert_eq!(
+        harness
+            .rebac_service
+            .get_effective_account_dataset_relation(&alice_id, &harness.dataset_id, None)
+            .await
+            .unwrap(),
+        Some(AccountToDatasetRelation::Admin)
+    );
+
+    assert_matches!(
+        harness
+            .collaboration_service
+            .remove_collaborator(&harness.dataset_id, &bob_id)
+            .await,
+        Ok(())
+    );
+    assert_matches!(
+        harness
+            .collaboration_service
+            .get_collaborator_role(&harness.dataset_id, &bob_id)
+            .await,
+        Ok(None)
+    );
+    assert_matches!(
+        harness
+            .collaboration_service
+            .remove_collaborator(&harness.dataset_id, &bob_id)
+            .await,
+        Err(RemoveDatasetCollaboratorError::NotACollaborator(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DatasetCollaborationHarness {
+    rebac_service: Arc<dyn RebacService>,
+    collaboration_service: Arc<dyn DatasetCollaborationService>,
+    dataset_id: DatasetID,
+}
+
+impl DatasetCollaborationHarness {
+    fn new() -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+
+        catalog_builder
+            .add::<DatasetCollaborationServiceImpl>()
+            .add::<RebacServiceImpl>()
+            .add::<InMemoryRebacRepository>();
+
+        let catalog = catalog_builder.build();
+
+        let (_, dataset_id) = DatasetID::new_generated_ed25519();
+
+        Self {
+            rebac_service: catalog.get_one().unwrap(),
+            collaboration_service: catalog.get_one().unwrap(),
+            dataset_id,
+        }
+    }
+
+    async fn set_role(&self, account_id: &AccountID, role: AccountToDatasetRelation) {
+        self.collaboration_service
+            .set_collaborator_role(&self.dataset_id, account_id, role)
+            .await
+            .unwrap();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -129,7 +129,10 @@ async fn test_rebac_properties_deleted() {
     // Simulate deletion
     {
         harness
-            .consume_message(DatasetLifecycleMessage::deleted(dataset_id.clone()))
+            .consume_message(DatasetLifecycleMessage::deleted(
+                dataset_id.clone(),
+                owner_id.clone(),
+            ))
             .await;
     }
 
@@ -148,7 +151,10 @@ async fn test_rebac_properties_deleted() {
     // Simulate deletion again to check idempotency
     {
         harness
-            .consume_message(DatasetLifecycleMessage::deleted(dataset_id.clone()))
+            .consume_message(DatasetLifecycleMessage::deleted(
+                dataset_id.clone(),
+                owner_id.clone(),
+            ))
             .await;
     }
 
@@ -0,0 +1,323 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use dill::CatalogBuilder;
+use kamu_accounts::{Account, AccountRepository, AccountType};
+use kamu_accounts_inmem::InMemoryAccountRepository;
+use kamu_auth_rebac::{
+    AccountToDatasetRelation,
+    AccountToOrganizationRelation,
+    OrganizationMembershipService,
+    OrganizationToDatasetRelation,
+    RebacService,
+    RemoveOrganizationMemberError,
+    SetOrganizationMemberRoleError,
+};
+use kamu_auth_rebac_inmem::InMemoryRebacRepository;
+use kamu_auth_rebac_services::{OrganizationMembershipServiceImpl, RebacServiceImpl};
+use opendatafabric::{AccountID, DatasetID};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_member_roles_management() {
+    let harness = OrganizationMembershipHarness::new().await;
+
+    // Only users can join, only organizations can be joined
+    assert_matches!(
+        harness
+            .membership_service
+            .set_member_role(
+                &harness.alice_id,
+                &harness.bob_id,
+                AccountToOrganizationRelation::Member
+            )
+            .await,
+        Err(SetOrganizationMemberRoleError::NotAnOrganization(e))
+            if e.account_id == harness.alice_id
+    );
+    assert_matches!(
+        harness
+            .membership_service
+            .set_member_role(
+                &harness.org_id,
+                &harness.org_id,
+                AccountToOrganizationRelation::Member
+            )
+            .await,
+        Err(SetOrganizationMemberRoleError::NotAUser(e))
+            if e.account_id == harness.org_id
+    );
+
+    harness
+        .set_role(&harness.alice_id, AccountToOrganizationRelation::Owner)
+        .await;
+    harness
+        .set_role(&harness.bob_id, AccountToOrganizationRelation::Member)
+        .await;
+
+    // Changing the role replaces the previous one
+    harness
+        .set_role(&harness.bob_id, AccountToOrganizationRelation::Maintainer)
+        .await;
+
+    let members: Vec<_> = harness
+        .membership_service
+        .get_members(&harness.org_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|m| (m.account_id, m.role))
+        .collect();
+
+    // Owners are listed first
+    let expected_members = vec![
+        (
+            harness.alice_id.clone(),
+            AccountToOrganizationRelation::Owner,
+        ),
+        (
+            harness.bob_id.clone(),
+            AccountToOrganizationRelation::Maintainer,
+        ),
+    ];
+
+    assert_eq!(members, expected_members);
+
+    let organizations = harness
+        .membership_service
+        .get_account_organizations(&harness.bob_id)
+        .await
+        .unwrap();
+    assert_eq!(organizations.len(), 1);
+    assert_eq!(organizations[0].organization_id, harness.org_id);
+
+    // The last owner can't leave or be demoted
+    assert_matches!(
+        harness
+            .membership_service
+            .set_member_role(
+                &harness.org_id,
+                &harness.alice_id,
+                AccountToOrganizationRelation::Member
+            )
+            .await,
+        Err(SetOrganizationMemberRoleError::LastOwner(_))
+    );
+    assert_matches!(
+        harness
+            .membership_service
+            .remove_member(&harness.org_id, &harness.alice_id)
+            .await,
+        Err(RemoveOrganizationMemberError::LastOwner(_))
+    );
+
+    // ... unless there is another owner
+    harness
+        .set_role(&harness.bob_id, AccountToOrganizationRelation::Owner)
+        .await;
+    assert_matches!(
+        harness
+            .membership_service
+            .remove_member(&harness.org_id, &harness.alice_id)
+            .await,
+        Ok(())
+    );
+    assert_matches!(
+        harness
+            .membership_service
+            .get_member_role(&harness.org_id, &harness.alice_id)
+            .await,
+        Ok(None)
+    );
+    assert_matches!(
+        harness
+            .membership_service
+            .remove_member(&harness.org_id, &harness.alice_id)
+            .await,
+        Err(RemoveOrganizationMemberError::NotAMember(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_effective_dataset_relations_through_membership() {
+    let harness = OrganizationMembershipHarness::new().await;
+
+    harness
+        .set_role(&harness.alice_id, AccountToOrganizationRelation::Owner)
+        .await;
+    harness
+        .set_role(&harness.bob_id, AccountToOrganizationRelation::Member)
+        .await;
+
+    let (_, org_dataset_id) = DatasetID::new_generated_ed25519();
+    let (_, foreign_dataset_id) = DatasetID::new_generated_ed25519();
+
+    // Datasets owned by the organization
+    assert_eq!(
+        harness
+            .effective_relation(&harness.alice_id, &org_dataset_id, &harness.org_id)
+            .await,
+        Some(AccountToDatasetRelation::Admin)
+    );
+    assert_eq!(
+        harness
+            .effective_relation(&harness.bob_id, &org_dataset_id, &harness.org_id)
+            .await,
+        Some(AccountToDatasetRelation::Reader)
+    );
+    assert_eq!(
+        harness
+            .effective_relation(&harness.carol_id, &org_dataset_id, &harness.org_id)
+            .await,
+        None
+    );
+
+    // Datasets the organization was granted access to
+    assert_eq!(
+        harness
+            .effective_relation(&harness.alice_id, &foreign_dataset_id, &harness.carol_id)
+            .await,
+        None
+    );
+
+    harness
+        .rebac_service
+        .insert_organization_dataset_relation(
+            &harness.org_id,
+            OrganizationToDatasetRelation::Editor,
+            &foreign_dataset_id,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        harness
+            .effective_relation(&harness.alice_id, &foreign_dataset_id, &harness.carol_id)
+            .await,
+        Some(AccountToDatasetRelation::Editor)
+    );
+    assert_eq!(
+        harness
+            .effective_relation(&harness.bob_id, &foreign_dataset_id, &harness.carol_id)
+            .await,
+        Some(AccountToDatasetRelation::Reader)
+    );
+
+    // Direct relations are combined with inherited ones
+    harness
+        .rebac_service
+        .insert_account_dataset_relation(
+            &harness.bob_id,
+            AccountToDatasetRelation::Editor,
+            &foreign_dataset_id,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        harness
+            .effective_relation(&harness.bob_id, &foreign_dataset_id, &harness.carol_id)
+            .await,
+        Some(AccountToDatasetRelation::Editor)
+    );
+
+    // Leaving the organization revokes inherited relations
+    harness
+        .membership_service
+        .remove_member(&harness.org_id, &harness.bob_id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        harness
+            .effective_relation(&harness.bob_id, &org_dataset_id, &harness.org_id)
+            .await,
+        None
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct OrganizationMembershipHarness {
+    rebac_service: Arc<dyn RebacService>,
+    membership_service: Arc<dyn OrganizationMembershipService>,
+    org_id: AccountID,
+    alice_id: AccountID,
+    bob_id: AccountID,
+    carol_id: AccountID,
+}
+
+impl OrganizationMembershipHarness {
+    async fn new() -> Self {
+        let mut catalog_builder = CatalogBuilder::new();
+
+        catalog_builder
+            .add::<OrganizationMembershipServiceImpl>()
+            .add::<RebacServiceImpl>()
+            .add::<InMemoryRebacRepository>()
+            .add::<InMemoryAccountRepository>();
+
+        let catalog = catalog_builder.build();
+
+        let account_repo = catalog.get_one::<dyn AccountRepository>().unwrap();
+
+        let mut ids = Vec::new();
+        for (name, account_type) in [
+            ("acme", AccountType::Organization),
+            ("alice", AccountType::User),
+            ("bob", AccountType::User),
+            ("carol", AccountType::User),
+        ] {
+            let id = AccountID::new_seeded_ed25519(name.as_bytes());
+            let mut account = Account::test(id.clone(), name);
+            account.account_type = account_type;
+
+            account_repo.create_account(&account).await.unwrap();
+            ids.push(id);
+        }
+
+        let [org_id, alice_id, bob_id, carol_id] = <[AccountID; 4]>::try_from(ids).unwrap();
+
+        Self {
+            rebac_service: catalog.get_one().unwrap(),
+            membership_service: catalog.get_one().unwrap(),
+            org_id,
+            alice_id,
+            bob_id,
+            carol_id,
+        }
+    }
+
+    async fn set_role(&self, account_id: &AccountID, role: AccountToOrganizationRelation) {
+        self.membership_service
+            .set_member_role(&self.org_id, account_id, role)
+            .await
+            .unwrap();
+    }
+
+    async fn effective_relation(
+        &self,
+        account_id: &AccountID,
+        dataset_id: &DatasetID,
+        dataset_owner_id: &AccountID,
+    ) -> Option<AccountToDatasetRelation> {
+        self.rebac_service
+            .get_effective_account_dataset_relation(account_id, dataset_id, Some(dataset_owner_id))
+            .await
+            .unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -46,20 +46,41 @@ pub trait DatasetActionAuthorizer: Sync + Send {
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum DatasetAction {
+    /// Read metadata and data
     Read,
+    /// Commit new metadata and data
     Write,
+    /// Configure and trigger flows, manage environment variables
+    Maintain,
+    /// Grant and revoke roles of other accounts
+    ManagePermissions,
+    Delete,
+    Rename,
+}
+
+impl DatasetAction {
+    pub const ALL: [DatasetAction; 6] = [
+        DatasetAction::Read,
+        DatasetAction::Write,
+        DatasetAction::Maintain,
+        DatasetAction::ManagePermissions,
+        DatasetAction::Delete,
+        DatasetAction::Rename,
+    ];
 }
 
 impl FromStr for DatasetAction {
     type Err = InternalError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s == "read" {
-            Ok(DatasetAction::Read)
-        } else if s == "write" {
-            Ok(DatasetAction::Write)
-        } else {
-            Err(format!("Invalid DatasetAction: {s}").int_err())
+        match s {
+            "read" => Ok(DatasetAction::Read),
+            "write" => Ok(DatasetAction::Write),
+            "maintain" => Ok(DatasetAction::Maintain),
+            "manage_permissions" => Ok(DatasetAction::ManagePermissions),
+            "delete" => Ok(DatasetAction::Delete),
+            "rename" => Ok(DatasetAction::Rename),
+            _ => Err(format!("Invalid DatasetAction: {s}").int_err()),
         }
     }
 }
@@ -69,6 +90,10 @@ impl std::fmt::Display for DatasetAction {
         match self {
             DatasetAction::Read => write!(f, "read"),
             DatasetAction::Write => write!(f, "write"),
+            DatasetAction::Maintain => write!(f, "maintain"),
+            DatasetAction::ManagePermissions => write!(f, "manage_permissions"),
+            DatasetAction::Delete => write!(f, "delete"),
+            DatasetAction::Rename => write!(f, "rename"),
         }
     }
 }
@@ -115,7 +140,7 @@ impl DatasetActionAuthorizer for AlwaysHappyDatasetActionAuthorizer {
     }
 
     async fn get_allowed_actions(&self, _dataset_handle: &DatasetHandle) -> HashSet<DatasetAction> {
-        HashSet::from([DatasetAction::Read, DatasetAction::Write])
+        HashSet::from(DatasetAction::ALL)
     }
 }
 
@@ -0,0 +1,94 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{HashMap, HashSet};
+
+use opendatafabric::serde::yaml::*;
+use opendatafabric::{Multihash, OffsetInterval};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Side index produced by the soft compaction.
+///
+/// Soft compaction merges small data slices into larger files without
+/// touching the metadata chain. The original slices referenced by
+/// `AddData` / `ExecuteTransform` events are retained, so the chain remains
+/// verifiable, while the query engine can substitute a merged file for the
+/// group of original slices it was produced from.
+///
+/// The index is cleared whenever the chain is rewritten (reset, hard
+/// compaction, retention), so a merged file is substituted only when all of
+/// its original slices are referenced by the chain at the queried state.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CompactedSlices {
+    /// Merged files in the order of the metadata chain (oldest first)
+    pub slices: Vec<CompactedSlice>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct CompactedSlice {
+    /// Hash of the merged file in the data repository
+    pub physical_hash: Multihash,
+    /// Size of the merged file in bytes
+    pub size: u64,
+    /// Offsets of the records contained in the merged file
+    #[serde_as(as = "OffsetIntervalDef")]
+    pub offset_interval: OffsetInterval,
+    /// Physical hashes of the original slices merged into this file
+    pub source_slices: Vec<Multihash>,
+}
+
+impl CompactedSlices {
+    /// Replaces every group of original slices that was merged into a single
+    /// file by the merged file. Groups that are only partially present in
+    /// the provided list are left untouched.
+    pub fn substitute(&self, files: Vec<Multihash>) -> Vec<Multihash> {
+        let present: HashSet<&Multihash> = files.iter().collect();
+
+        let mut replaced_by = HashMap::new();
+        for slice in &self.slices {
+            if slice.source_slices.iter().all(|h| present.contains(h)) {
+                for h in &slice.source_slices {
+                    replaced_by.insert(h.clone(), slice);
+                }
+            }
+        }
+
+        if replaced_by.is_empty() {
+            return files;
+        }
+
+        let mut emitted = HashSet::new();
+        let mut res = Vec::new();
+        for h in files {
+            match replaced_by.get(&h) {
+                None => res.push(h),
+                Some(slice) => {
+                    if emitted.insert(&slice.physical_hash) {
+                        res.push(slice.physical_hash.clone());
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    /// Returns the set of original slices that are already covered by merged
+    /// files
+    pub fn source_slices(&self) -> impl Iterator<Item = &Multihash> {
+        self.slices.iter().flat_map(|s| s.source_slices.iter())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,55 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use opendatafabric::Multihash;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Side index that remembers up to which block the data of a dataset was
+/// exported into each of the external destinations, so that subsequent
+/// incremental exports only write the data added since then.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DataExportPositions {
+    pub positions: Vec<DataExportPosition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DataExportPosition {
+    /// Location the data was exported into
+    pub destination: Url,
+    /// Last block whose data was exported
+    pub head: Multihash,
+}
+
+impl DataExportPositions {
+    pub fn get(&self, destination: &Url) -> Option<&DataExportPosition> {
+        self.positions
+            .iter()
+            .find(|p| p.destination == *destination)
+    }
+
+    pub fn set(&mut self, destination: &Url, head: Multihash) {
+        if let Some(position) = self
+            .positions
+            .iter_mut()
+            .find(|p| p.destination == *destination)
+        {
+            position.head = head;
+        } else {
+            self.positions.push(DataExportPosition {
+                destination: destination.clone(),
+                head,
+            });
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use opendatafabric::{DatasetAlias, DatasetID, Multihash};
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Describes the contents of a portable dataset archive.
+///
+/// Manifest is stored as the first entry of the archive so that the importer
+/// can validate every file it unpacks without reading the archive twice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DatasetArchiveManifest {
+    pub dataset_id: DatasetID,
+    pub dataset_alias: DatasetAlias,
+    /// Head of the metadata chain at the time of the export
+    pub head: Multihash,
+    /// Files of the dataset in the order they appear in the archive
+    pub entries: Vec<DatasetArchiveEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct DatasetArchiveEntry {
+    /// Path of the file relative to the dataset root using `/` as a separator
+    pub path: String,
+    pub size: u64,
+    pub physical_hash: Multihash,
+}
+
+impl DatasetArchiveManifest {
+    pub fn entry(&self, path: &str) -> Option<&DatasetArchiveEntry> {
+        self.entries.iter().find(|e| e.path == path)
+    }
+
+    pub fn total_size(&self) -> u64 {
+        self.entries.iter().map(|e| e.size).sum()
+    }
+}
@@ -7,13 +7,23 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+pub mod compacted_slices;
+pub mod data_export_positions;
 pub mod dataset;
+pub mod dataset_archive;
 pub mod dataset_summary;
 pub mod engine;
 pub mod metadata_chain;
 pub mod metadata_stream;
+pub mod partitioned_slices;
+pub mod slice_statistics;
 
+pub use compacted_slices::*;
+pub use data_export_positions::*;
 pub use dataset::*;
+pub use dataset_archive::*;
 pub use dataset_summary::*;
 pub use metadata_chain::*;
 pub use metadata_stream::*;
+pub use partitioned_slices::*;
+pub use slice_statistics::*;
@@ -0,0 +1,148 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{Duration, NaiveDate};
+use datafusion::arrow::datatypes::{DataType, TimeUnit};
+use datafusion::prelude::*;
+use datafusion::scalar::ScalarValue;
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Describes how data slices of a dataset are split into partitions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct PartitioningSpec {
+    /// Column whose values determine the partition of a record, e.g. the
+    /// event time column of the dataset vocabulary
+    pub column: String,
+    /// How the column values are mapped onto partitions
+    #[serde(default)]
+    pub transform: PartitionTransform,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PartitionTransform {
+    /// Every distinct value of the column forms its own partition
+    #[default]
+    Identity,
+    /// Timestamps and dates are grouped by the calendar day (UTC)
+    Day,
+}
+
+impl PartitioningSpec {
+    /// Expression that evaluates into the partition value of a record
+    pub fn partition_value_expr(&self) -> Expr {
+        let column = col(Column::from_name(&self.column));
+        let value = match self.transform {
+            PartitionTransform::Identity => column,
+            PartitionTransform::Day => cast(column, DataType::Date32),
+        };
+        cast(value, DataType::Utf8)
+    }
+
+    /// Returns the inclusive range of the column values that records in the
+    /// partition with the specified value can have, or `None` if it cannot be
+    /// determined for the column type
+    pub fn partition_bounds(
+        &self,
+        value: &str,
+        data_type: &DataType,
+    ) -> Option<(ScalarValue, ScalarValue)> {
+        match self.transform {
+            PartitionTransform::Identity => {
+                let v = ScalarValue::try_from_string(value.to_string(), data_type).ok()?;
+                Some((v.clone(), v))
+            }
+            PartitionTransform::Day => {
+                let start = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .ok()?
+                    .and_hms_opt(0, 0, 0)?
+                    .and_utc();
+                let end = start + Duration::days(1);
+
+                match data_type {
+                    DataType::Timestamp(unit, tz) => {
+                        let (min, max) = match unit {
+                            TimeUnit::Second => (start.timestamp(), end.timestamp() - 1),
+                            TimeUnit::Millisecond => {
+                                (start.timestamp_millis(), end.timestamp_millis() - 1)
+                            }
+                            TimeUnit::Microsecond => {
+                                (start.timestamp_micros(), end.timestamp_micros() - 1)
+                            }
+                            TimeUnit::Nanosecond => {
+                                (start.timestamp_nanos_opt()?, end.timestamp_nanos_opt()? - 1)
+                            }
+                        };
+                        let scalar = |v| match unit {
+                            TimeUnit::Second => ScalarValue::TimestampSecond(Some(v), tz.clone()),
+                            TimeUnit::Millisecond => {
+                                ScalarValue::TimestampMillisecond(Some(v), tz.clone())
+                            }
+                            TimeUnit::Microsecond => {
+                                ScalarValue::TimestampMicrosecond(Some(v), tz.clone())
+                            }
+                            TimeUnit::Nanosecond => {
+                                ScalarValue::TimestampNanosecond(Some(v), tz.clone())
+                            }
+                        };
+                        Some((scalar(min), scalar(max)))
+                    }
+                    DataType::Date32 => {
+                        let days = i32::try_from(start.timestamp() / 86400).ok()?;
+                        Some((
+                            ScalarValue::Date32(Some(days)),
+                            ScalarValue::Date32(Some(days)),
+                        ))
+                    }
+                    DataType::Date64 => Some((
+                        ScalarValue::Date64(Some(start.timestamp_millis())),
+                        ScalarValue::Date64(Some(end.timestamp_millis() - 1)),
+                    )),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Partition values of the records in a data slice.
+///
+/// Data slices referenced by `AddData` / `ExecuteTransform` events are always
+/// stored as a single file, so the chain remains verifiable via the existing
+/// [`opendatafabric::DataSlice`] hashing. When the dataset has a partitioning
+/// spec, the index records which partition values every row group of the file
+/// contains, allowing the query engine to skip the row groups and the whole
+/// files that cannot match the query predicates.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SlicePartitions {
+    /// Spec the slice was partitioned with
+    pub spec: PartitioningSpec,
+    /// Row groups of the data file in the order they are stored
+    pub row_groups: Vec<RowGroupPartitions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct RowGroupPartitions {
+    /// Offset of the first record in the row group
+    pub offset_start: u64,
+    /// Offset of the last record in the row group (inclusive)
+    pub offset_end: u64,
+    /// Distinct partition values of the records in ascending order, absent
+    /// value stands for the records where the column is null
+    pub values: Vec<Option<String>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,148 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use opendatafabric::Multihash;
+use serde::{Deserialize, Serialize};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Configuration of the per-file column statistics of a dataset.
+///
+/// Allows the query engine to skip data files that cannot contain records
+/// matching the query predicates without opening their Parquet footers. The
+/// statistics are collected for the data files when they are added to the
+/// dataset and stored per file, so an entry may describe an original slice as
+/// well as a compacted file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct SliceStatisticsConfig {
+    /// Columns for which bloom filters are built in addition to min/max
+    pub bloom_filter_columns: Vec<String>,
+}
+
+/// Column statistics of a data file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SliceStatistics {
+    pub columns: Vec<ColumnStatistics>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnStatistics {
+    pub name: String,
+    /// Smallest non-null value of the column in its string representation
+    pub min: Option<String>,
+    /// Largest non-null value of the column in its string representation
+    pub max: Option<String>,
+    /// Number of records where the column is null
+    pub null_count: u64,
+    /// Filter of the distinct non-null values, present only for the
+    /// configured columns
+    pub bloom_filter: Option<BloomFilter>,
+}
+
+impl SliceStatistics {
+    pub fn column(&self, name: &str) -> Option<&ColumnStatistics> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Probabilistic set of the string representations of column values.
+///
+/// Never produces false negatives, so a value that is not contained in the
+/// filter is guaranteed to be absent from the data file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    num_hashes: u32,
+    bits: Vec<u8>,
+}
+
+impl BloomFilter {
+    pub const MAX_SIZE_BYTES: usize = 1024 * 1024;
+    pub const MAX_NUM_HASHES: u32 = 16;
+
+    /// Creates an empty filter sized to hold the specified number of distinct
+    /// values with the desired false positive probability
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn with_capacity(num_values: usize, false_positive_rate: f64) -> Self {
+        let num_values = num_values.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+
+        let num_bits = (-num_values * false_positive_rate.ln() / (ln2 * ln2)).ceil();
+        let num_bytes = ((num_bits / 8.0).ceil() as usize).clamp(1, Self::MAX_SIZE_BYTES);
+
+        let num_hashes = ((num_bytes * 8) as f64 / num_values * ln2).round();
+        let num_hashes = (num_hashes as u32).clamp(1, Self::MAX_NUM_HASHES);
+
+        Self {
+            num_hashes,
+            bits: vec![0; num_bytes],
+        }
+    }
+
+    /// Restores the filter from its parts, e.g. when reading it from storage
+    pub fn from_parts(num_hashes: u32, bits: Vec<u8>) -> Result<Self, InvalidBloomFilterError> {
+        if bits.is_empty() || bits.len() > Self::MAX_SIZE_BYTES {
+            return Err(InvalidBloomFilterError {
+                reason: format!("Unexpected size of {} bytes", bits.len()),
+            });
+        }
+        if num_hashes == 0 || num_hashes > Self::MAX_NUM_HASHES {
+            return Err(InvalidBloomFilterError {
+                reason: format!("Unexpected number of hashes {num_hashes}"),
+            });
+        }
+        Ok(Self { num_hashes, bits })
+    }
+
+    pub fn num_hashes(&self) -> u32 {
+        self.num_hashes
+    }
+
+    pub fn bits(&self) -> &[u8] {
+        &self.bits
+    }
+
+    pub fn insert(&mut self, value: &str) {
+        for bit in self.bit_positions(value) {
+            self.bits[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+
+    pub fn may_contain(&self, value: &str) -> bool {
+        self.bit_positions(value)
+            .all(|bit| self.bits[bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    // Uses double hashing to derive all bit positions from a single digest
+    fn bit_positions(&self, value: &str) -> impl Iterator<Item = usize> {
+        let hash = Multihash::from_digest_sha3_256(value.as_bytes());
+        let digest = hash.digest();
+        let h1 = u64::from_le_bytes(digest[0..8].try_into().unwrap());
+        let h2 = u64::from_le_bytes(digest[8..16].try_into().unwrap());
+
+        let num_bits = (self.bits.len() * 8) as u64;
+
+        (0..u64::from(self.num_hashes))
+            .map(move |i| usize::try_from(h1.wrapping_add(i.wrapping_mul(h2)) % num_bits).unwrap())
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("Invalid bloom filter: {reason}")]
+pub struct InvalidBloomFilterError {
+    pub reason: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
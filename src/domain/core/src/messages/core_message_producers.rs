@@ -12,4 +12,7 @@
 pub const MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE: &str =
     "dev.kamu.domain.core.services.DatasetService";
 
+pub const MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE: &str =
+    "dev.kamu.domain.core.services.DatasetReferenceService";
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use messaging_outbox::Message;
-use opendatafabric::{AccountID, DatasetID, DatasetName};
+use opendatafabric::{AccountID, DatasetID, DatasetName, Multihash};
 use serde::{Deserialize, Serialize};
 
 use crate::DatasetVisibility;
@@ -45,8 +45,11 @@ impl DatasetLifecycleMessage {
         })
     }
 
-    pub fn deleted(dataset_id: DatasetID) -> Self {
-        Self::Deleted(DatasetLifecycleMessageDeleted { dataset_id })
+    pub fn deleted(dataset_id: DatasetID, owner_account_id: AccountID) -> Self {
+        Self::Deleted(DatasetLifecycleMessageDeleted {
+            dataset_id,
+            owner_account_id,
+        })
     }
 
     pub fn renamed(
@@ -90,6 +93,7 @@ pub struct DatasetLifecycleMessageDependenciesUpdated {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DatasetLifecycleMessageDeleted {
     pub dataset_id: DatasetID,
+    pub owner_account_id: AccountID,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -103,3 +107,36 @@ pub struct DatasetLifecycleMessageRenamed {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DatasetReferenceMessage {
+    Updated(DatasetReferenceMessageUpdated),
+}
+
+impl DatasetReferenceMessage {
+    pub fn updated(
+        dataset_id: DatasetID,
+        maybe_prev_block_hash: Option<Multihash>,
+        new_block_hash: Multihash,
+    ) -> Self {
+        Self::Updated(DatasetReferenceMessageUpdated {
+            dataset_id,
+            maybe_prev_block_hash,
+            new_block_hash,
+        })
+    }
+}
+
+impl Message for DatasetReferenceMessage {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Emitted when the `HEAD` reference of a dataset moves
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatasetReferenceMessageUpdated {
+    pub dataset_id: DatasetID,
+    pub maybe_prev_block_hash: Option<Multihash>,
+    pub new_block_hash: Multihash,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -154,6 +154,7 @@ pub enum CompactionPhase {
     GatherChainInfo,
     MergeDataslices,
     CommitNewBlocks,
+    CommitCompactedSlices,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -165,6 +166,12 @@ pub enum CompactionResult {
         old_num_blocks: usize,
         new_num_blocks: usize,
     },
+    /// Result of a soft compaction that leaves the metadata chain intact
+    SoftSuccess {
+        head: Multihash,
+        old_num_slices: usize,
+        new_num_slices: usize,
+    },
 }
 
 #[derive(Debug)]
@@ -180,6 +187,7 @@ pub struct CompactionOptions {
     pub max_slice_size: Option<u64>,
     pub max_slice_records: Option<u64>,
     pub keep_metadata_only: bool,
+    pub mode: CompactionMode,
 }
 
 impl Default for CompactionOptions {
@@ -188,6 +196,18 @@ impl Default for CompactionOptions {
             max_slice_size: Some(DEFAULT_MAX_SLICE_SIZE),
             max_slice_records: Some(DEFAULT_MAX_SLICE_RECORDS),
             keep_metadata_only: false,
+            mode: CompactionMode::default(),
         }
     }
 }
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CompactionMode {
+    /// Rewrites the metadata chain as if data was originally written in big
+    /// batches, changing block hashes
+    #[default]
+    Hard,
+    /// Merges data slices into new files recorded in a side index, leaving
+    /// the metadata chain and the original files intact
+    Soft,
+}
@@ -0,0 +1,170 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use opendatafabric::*;
+use thiserror::Error;
+use url::Url;
+
+use crate::auth::DatasetActionUnauthorizedError;
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Service
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Writes data of datasets and results of queries into files in external
+/// locations
+#[async_trait::async_trait]
+pub trait DataExportService: Send + Sync {
+    async fn export_data(
+        &self,
+        source: DataExportSource,
+        options: DataExportOptions,
+    ) -> Result<DataExportResult, DataExportError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DTOs
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub enum DataExportSource {
+    /// Exports all records of a dataset
+    Dataset(DatasetRef),
+    /// Exports the result of an SQL statement
+    Sql(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct DataExportOptions {
+    /// Directory to write the files into, either local (`file://`) or in one
+    /// of the supported object stores
+    pub destination: Url,
+    pub format: DataExportFormat,
+    pub compression: Option<DataExportCompression>,
+    /// Columns to split the output into `<column>=<value>` directories by
+    pub partition_by: Vec<String>,
+    /// Whether to export only data added since the previous export of the
+    /// dataset into the same destination. Only applicable to datasets.
+    pub incremental: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataExportFormat {
+    Parquet,
+    Csv,
+    NdJson,
+    /// Arrow IPC file format
+    Arrow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataExportCompression {
+    Gzip,
+    Zstd,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataExportResult {
+    /// No new data since the previous export
+    UpToDate,
+    Exported {
+        num_records: u64,
+        /// Block the data was exported up to, for dataset sources
+        head: Option<Multihash>,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum DataExportError {
+    #[error(transparent)]
+    DatasetNotFound(
+        #[from]
+        #[backtrace]
+        DatasetNotFoundError,
+    ),
+    #[error(transparent)]
+    InvalidOptions(
+        #[from]
+        #[backtrace]
+        InvalidDataExportOptionsError,
+    ),
+    #[error(transparent)]
+    PositionNotFound(
+        #[from]
+        #[backtrace]
+        DataExportPositionNotFoundError,
+    ),
+    #[error(transparent)]
+    Query(
+        #[from]
+        #[backtrace]
+        QueryError,
+    ),
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+#[derive(Debug, Error)]
+#[error("Invalid export options: {reason}")]
+pub struct InvalidDataExportOptionsError {
+    pub reason: String,
+}
+
+impl InvalidDataExportOptionsError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error(
+    "Block {head} of the previous export into {destination} is no longer in the metadata chain of \
+     {dataset_handle}, perform a full export instead"
+)]
+pub struct DataExportPositionNotFoundError {
+    pub dataset_handle: DatasetHandle,
+    pub destination: Url,
+    pub head: Multihash,
+}
+
+impl From<GetDatasetError> for DataExportError {
+    fn from(v: GetDatasetError) -> Self {
+        match v {
+            GetDatasetError::NotFound(e) => Self::DatasetNotFound(e),
+            GetDatasetError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<DatasetActionUnauthorizedError> for DataExportError {
+    fn from(v: DatasetActionUnauthorizedError) -> Self {
+        match v {
+            DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::{Path, PathBuf};
+
+use internal_error::{ErrorIntoInternal, InternalError};
+use opendatafabric::*;
+use thiserror::Error;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Service
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Packages datasets into single-file portable archives and restores them.
+///
+/// An archive is a `zstd`-compressed `tar` that contains a manifest followed by
+/// the metadata chain, refs, data and checkpoints of a dataset.
+#[async_trait::async_trait]
+pub trait DatasetArchiveService: Send + Sync {
+    /// Writes the current state of the dataset into an archive at the
+    /// specified path. The archive file is replaced only once fully written.
+    async fn export_archive(
+        &self,
+        dataset_ref: &DatasetRef,
+        archive_path: &Path,
+    ) -> Result<ExportArchiveResult, ExportArchiveError>;
+
+    /// Restores the dataset from an archive. When the alias is not specified
+    /// the dataset is imported under the name it had in the source workspace.
+    ///
+    /// Unpacked files are kept between attempts so that an interrupted import
+    /// can be resumed by repeating the command.
+    async fn import_archive(
+        &self,
+        archive_path: &Path,
+        dataset_alias: Option<DatasetAlias>,
+    ) -> Result<ImportArchiveResult, ImportArchiveError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DTOs
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub const DATASET_ARCHIVE_MANIFEST_KIND: &str = "DatasetArchive";
+pub const DATASET_ARCHIVE_MANIFEST_PATH: &str = "manifest.yaml";
+pub const DATASET_ARCHIVE_DATA_DIR: &str = "dataset";
+
+#[derive(Debug, Clone)]
+pub struct ExportArchiveResult {
+    pub dataset_handle: DatasetHandle,
+    pub archive_path: PathBuf,
+    pub manifest: DatasetArchiveManifest,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportArchiveResult {
+    pub dataset_handle: DatasetHandle,
+    pub manifest: DatasetArchiveManifest,
+    pub sync_result: SyncResult,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum ExportArchiveError {
+    #[error(transparent)]
+    DatasetNotFound(
+        #[from]
+        #[backtrace]
+        DatasetNotFoundError,
+    ),
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+impl From<GetDatasetError> for ExportArchiveError {
+    fn from(v: GetDatasetError) -> Self {
+        match v {
+            GetDatasetError::NotFound(e) => Self::DatasetNotFound(e),
+            GetDatasetError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<auth::DatasetActionUnauthorizedError> for ExportArchiveError {
+    fn from(v: auth::DatasetActionUnauthorizedError) -> Self {
+        match v {
+            auth::DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            auth::DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<SyncError> for ExportArchiveError {
+    fn from(v: SyncError) -> Self {
+        match v {
+            SyncError::DatasetNotFound(e) => Self::DatasetNotFound(e),
+            SyncError::Access(e) => Self::Access(e),
+            SyncError::Internal(e) => Self::Internal(e),
+            e => Self::Internal(e.int_err()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum ImportArchiveError {
+    #[error(transparent)]
+    InvalidArchive(
+        #[from]
+        #[backtrace]
+        InvalidArchiveError,
+    ),
+    #[error(transparent)]
+    IntegrityCheckFailed(
+        #[from]
+        #[backtrace]
+        ArchiveIntegrityError,
+    ),
+    #[error(transparent)]
+    HeadMismatch(
+        #[from]
+        #[backtrace]
+        ArchiveHeadMismatchError,
+    ),
+    #[error(transparent)]
+    Sync(
+        #[from]
+        #[backtrace]
+        SyncError,
+    ),
+    #[error(transparent)]
+    Verification(
+        #[from]
+        #[backtrace]
+        VerificationError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+#[derive(Debug, Error)]
+#[error("Invalid dataset archive: {reason}")]
+pub struct InvalidArchiveError {
+    pub reason: String,
+}
+
+impl InvalidArchiveError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("Archive entry {path} is corrupted: expected hash {expected} but got {actual}")]
+pub struct ArchiveIntegrityError {
+    pub path: String,
+    pub expected: Multihash,
+    pub actual: Multihash,
+}
+
+#[derive(Debug, Error)]
+#[error("Imported dataset head {actual} does not match the archive head {expected}")]
+pub struct ArchiveHeadMismatchError {
+    pub expected: Multihash,
+    pub actual: Multihash,
+}
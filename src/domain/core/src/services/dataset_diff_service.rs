@@ -0,0 +1,127 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use datafusion::prelude::DataFrame;
+use internal_error::InternalError;
+use opendatafabric::*;
+use thiserror::Error;
+
+use crate::auth::DatasetActionUnauthorizedError;
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait DatasetDiffService: Sync + Send {
+    /// Computes record-level differences between the states of the dataset at
+    /// two blocks. Absent `old_head` denotes an empty state, while absent
+    /// `new_head` denotes the current head of the dataset.
+    async fn diff_blocks(
+        &self,
+        dataset_ref: &DatasetRef,
+        old_head: Option<&Multihash>,
+        new_head: Option<&Multihash>,
+        options: DiffOptions,
+    ) -> Result<DiffResponse, DiffError>;
+
+    /// Computes record-level differences between the current states of two
+    /// datasets with the same schema
+    async fn diff_datasets(
+        &self,
+        old_dataset_ref: &DatasetRef,
+        new_dataset_ref: &DatasetRef,
+        options: DiffOptions,
+    ) -> Result<DiffResponse, DiffError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Default)]
+pub struct DiffOptions {
+    /// Columns to match the records by. When not specified the primary key of
+    /// the dataset's merge strategy is used, falling back to record offsets.
+    pub primary_key: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DiffResponse {
+    /// Changes in the form of appended (`+A`), retracted (`-R`), and corrected
+    /// (`-C` / `+C`) records, where operation type is stored in the column
+    /// defined by the dataset vocabulary
+    pub df: DataFrame,
+    /// How records of two states were matched
+    pub key: DiffKey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffKey {
+    PrimaryKey(Vec<String>),
+    Offset,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum DiffError {
+    #[error(transparent)]
+    DatasetNotFound(#[from] DatasetNotFoundError),
+
+    #[error(transparent)]
+    BlockNotFound(#[from] BlockNotFoundError),
+
+    #[error(transparent)]
+    InvalidInterval(#[from] InvalidIntervalError),
+
+    #[error(transparent)]
+    DatasetSchemaNotAvailable(#[from] DatasetSchemaNotAvailableError),
+
+    #[error(transparent)]
+    SchemaMismatch(#[from] DiffSchemaMismatchError),
+
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+#[derive(Debug, Error)]
+#[error("Datasets {old_dataset} and {new_dataset} have incompatible schemas")]
+pub struct DiffSchemaMismatchError {
+    pub old_dataset: DatasetAlias,
+    pub new_dataset: DatasetAlias,
+}
+
+impl From<GetDatasetError> for DiffError {
+    fn from(v: GetDatasetError) -> Self {
+        match v {
+            GetDatasetError::NotFound(e) => Self::DatasetNotFound(e),
+            GetDatasetError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<DatasetActionUnauthorizedError> for DiffError {
+    fn from(v: DatasetActionUnauthorizedError) -> Self {
+        match v {
+            DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
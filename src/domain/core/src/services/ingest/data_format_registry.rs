@@ -81,6 +81,10 @@ impl MediaType {
     pub const NDGEOJSON: MediaTypeRef<'static> = MediaTypeRef("application/x-ndgeojson");
     /// See: <https://issues.apache.org/jira/browse/PARQUET-1889>
     pub const PARQUET: MediaTypeRef<'static> = MediaTypeRef("application/vnd.apache.parquet");
+    /// See: <https://avro.apache.org/docs/1.11.1/specification/#object-container-files>
+    pub const AVRO: MediaTypeRef<'static> = MediaTypeRef("application/avro");
+    /// No standard found
+    pub const ORC: MediaTypeRef<'static> = MediaTypeRef("application/vnd.apache.orc");
     /// Multiple in use
     /// See: <https://www.iana.org/assignments/media-types/application/vnd.shp>
     /// See: <https://en.wikipedia.org/wiki/Shapefile>
@@ -17,7 +17,7 @@ use internal_error::*;
 use opendatafabric as odf;
 
 use super::MergeError;
-use crate::{AddDataParams, CommitError, OwnedFile};
+use crate::{AddDataParams, CommitError, OwnedFile, PartitioningSpec};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -101,6 +101,9 @@ pub struct StageDataResult {
     pub add_data: Option<AddDataParams>,
     /// Set when commmit will contains some data
     pub data_file: Option<OwnedFile>,
+    /// Set when the dataset has a partitioning spec, holds the partition
+    /// values of the data file
+    pub partitions: Option<SlicePartitions>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
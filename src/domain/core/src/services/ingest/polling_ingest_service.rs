@@ -15,6 +15,7 @@ use std::sync::Arc;
 use chrono::{DateTime, Utc};
 use container_runtime::ImagePullError;
 use internal_error::{BoxedError, InternalError};
+pub use kamu_data_utils::schema::evolution::SchemaEvolutionRules;
 use kamu_datasets::{DatasetEnvVar, FindDatasetEnvVarError};
 use opendatafabric::*;
 use thiserror::Error;
@@ -66,6 +67,9 @@ pub struct PollingIngestOptions {
     pub dataset_env_vars: HashMap<String, DatasetEnvVar>,
     /// Schema inference configuration
     pub schema_inference: SchemaInferenceOpts,
+    /// Schema evolution rules to apply when new data does not match the
+    /// schema of the dataset
+    pub schema_evolution: SchemaEvolutionRules,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -193,6 +197,22 @@ impl InvalidIngestParameterFormat {
     }
 }
 
+#[derive(Error, Debug)]
+#[error("Invalid source state '{state}': {reason}")]
+pub struct InvalidSourceState {
+    pub state: String,
+    pub reason: String,
+}
+
+impl InvalidSourceState {
+    pub fn new(state: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            state: state.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
 // TODO: Revisit error granularity
 #[derive(Debug, Error)]
 pub enum PollingIngestError {
@@ -308,6 +328,13 @@ pub enum PollingIngestError {
         InvalidIngestParameterFormat,
     ),
 
+    #[error(transparent)]
+    InvalidSourceState(
+        #[from]
+        #[backtrace]
+        InvalidSourceState,
+    ),
+
     #[error(transparent)]
     Internal(
         #[from]
@@ -68,6 +68,9 @@ pub struct PushIngestOpts {
     pub auto_create_push_source: bool,
     /// Schema inference configuration
     pub schema_inference: SchemaInferenceOpts,
+    /// Schema evolution rules to apply when new data does not match the
+    /// schema of the dataset
+    pub schema_evolution: SchemaEvolutionRules,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
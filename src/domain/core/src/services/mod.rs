@@ -11,7 +11,10 @@
 pub use container_runtime::{NullPullImageListener, PullImageListener};
 
 pub mod compaction_service;
+pub mod data_export_service;
+pub mod dataset_archive_service;
 pub mod dataset_changes_service;
+pub mod dataset_diff_service;
 pub mod dataset_ownership_service;
 pub mod dependency_graph_repository;
 pub mod dependency_graph_service;
@@ -20,12 +23,14 @@ pub mod ingest;
 pub mod provenance_service;
 pub mod pull_service;
 pub mod push_service;
+pub mod query_result_cache;
 pub mod query_service;
 pub mod remote_aliases;
 pub mod remote_aliases_registry;
 pub mod remote_repository_registry;
 pub mod reset_service;
 pub mod resource_loader;
+pub mod retention_service;
 pub mod search_service;
 pub mod server_url_config;
 pub mod sync_service;
@@ -33,7 +38,10 @@ pub mod transform_service;
 pub mod verification_service;
 
 pub use compaction_service::*;
+pub use data_export_service::*;
+pub use dataset_archive_service::*;
 pub use dataset_changes_service::*;
+pub use dataset_diff_service::*;
 pub use dataset_ownership_service::*;
 pub use dependency_graph_repository::*;
 pub use dependency_graph_service::*;
@@ -42,12 +50,14 @@ pub use ingest::*;
 pub use provenance_service::*;
 pub use pull_service::*;
 pub use push_service::*;
+pub use query_result_cache::*;
 pub use query_service::*;
 pub use remote_aliases::*;
 pub use remote_aliases_registry::*;
 pub use remote_repository_registry::*;
 pub use reset_service::*;
 pub use resource_loader::*;
+pub use retention_service::*;
 pub use search_service::*;
 pub use server_url_config::*;
 pub use sync_service::*;
@@ -7,6 +7,8 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use chrono::{DateTime, Utc};
+use datafusion::prelude::DataFrame;
 use internal_error::InternalError;
 use opendatafabric::*;
 use thiserror::Error;
@@ -25,6 +27,22 @@ pub trait ProvenanceService: Sync + Send {
         visitor: &mut dyn LineageVisitor,
         options: LineageOptions,
     ) -> Result<(), GetLineageError>;
+
+    /// Derives column-level lineage of a derivative dataset from the logical
+    /// plan of its current transform queries. Returns `None` for datasets that
+    /// don't have a transform.
+    async fn get_column_lineage(
+        &self,
+        dataset_ref: &DatasetRef,
+    ) -> Result<Option<ColumnLineage>, GetColumnLineageError>;
+
+    /// Locates the transaction that produced the record with the specified
+    /// offset and returns the input data that was considered in it
+    async fn get_record_provenance(
+        &self,
+        dataset_ref: &DatasetRef,
+        offset: u64,
+    ) -> Result<RecordProvenance, GetRecordProvenanceError>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -78,6 +96,58 @@ pub struct LineageOptions {}
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnLineage {
+    /// Output columns in the order of the transform result schema
+    pub columns: Vec<OutputColumnLineage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputColumnLineage {
+    /// Name of the column in the transform output
+    pub name: String,
+    /// Input columns this column is derived from. Empty for columns computed
+    /// from literals only.
+    pub sources: Vec<ColumnLineageSource>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnLineageSource {
+    pub dataset: DatasetHandle,
+    pub column: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct RecordProvenance {
+    /// Hash of the block that added the record
+    pub block_hash: Multihash,
+    /// System time of the block that added the record
+    pub system_time: DateTime<Utc>,
+    /// Interval of all records that were added by the same block
+    pub offset_interval: OffsetInterval,
+    /// Inputs of the transformation. Empty for records of root datasets.
+    pub inputs: Vec<RecordProvenanceInput>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordProvenanceInput {
+    pub dataset: DatasetHandle,
+    /// Half-open `(prev_block_hash, new_block_hash]` interval of input blocks
+    pub prev_block_hash: Option<Multihash>,
+    pub new_block_hash: Option<Multihash>,
+    /// Input records considered in the transaction, if any
+    pub offset_interval: Option<OffsetInterval>,
+    /// Input records that pass the filters of the transform and could have
+    /// produced the record. The exact source is not known, as engines don't
+    /// preserve the order of records. Only available when the transform is a
+    /// simple projection or filter of a single input.
+    pub possible_source_records: Option<DataFrame>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Error)]
 pub enum GetLineageError {
     #[error(transparent)]
@@ -113,3 +183,96 @@ impl From<auth::DatasetActionUnauthorizedError> for GetLineageError {
         }
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum GetColumnLineageError {
+    #[error(transparent)]
+    NotFound(#[from] DatasetNotFoundError),
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    UnsupportedQuery(#[from] UnsupportedQueryError),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+#[derive(Debug, Error)]
+#[error("Cannot derive column lineage: {message}")]
+pub struct UnsupportedQueryError {
+    pub message: String,
+}
+
+impl From<GetDatasetError> for GetColumnLineageError {
+    fn from(v: GetDatasetError) -> Self {
+        match v {
+            GetDatasetError::NotFound(e) => Self::NotFound(e),
+            GetDatasetError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<auth::DatasetActionUnauthorizedError> for GetColumnLineageError {
+    fn from(v: auth::DatasetActionUnauthorizedError) -> Self {
+        match v {
+            auth::DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            auth::DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum GetRecordProvenanceError {
+    #[error(transparent)]
+    NotFound(#[from] DatasetNotFoundError),
+    #[error(transparent)]
+    RecordNotFound(#[from] RecordNotFoundError),
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+#[derive(Debug, Error)]
+#[error("Dataset {dataset_alias} does not contain a record with offset {offset}")]
+pub struct RecordNotFoundError {
+    pub dataset_alias: DatasetAlias,
+    pub offset: u64,
+}
+
+impl From<GetDatasetError> for GetRecordProvenanceError {
+    fn from(v: GetDatasetError) -> Self {
+        match v {
+            GetDatasetError::NotFound(e) => Self::NotFound(e),
+            GetDatasetError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<auth::DatasetActionUnauthorizedError> for GetRecordProvenanceError {
+    fn from(v: auth::DatasetActionUnauthorizedError) -> Self {
+        match v {
+            auth::DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            auth::DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
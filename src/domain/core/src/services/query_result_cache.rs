@@ -0,0 +1,152 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt::Write as _;
+
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::datatypes::SchemaRef;
+use internal_error::InternalError;
+use opendatafabric::Multihash;
+
+use crate::QueryState;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Stores materialized results of SQL queries.
+///
+/// Results are keyed by the normalized statement and the block hashes of all
+/// input datasets, so a cached result stays valid exactly while the heads of
+/// its inputs don't move. Entries of the queries whose inputs have changed are
+/// never hit again and are eventually evicted by the size limits.
+#[async_trait::async_trait]
+pub trait QueryResultCache: Send + Sync {
+    async fn get(
+        &self,
+        key: &QueryResultCacheKey,
+    ) -> Result<Option<CachedQueryResult>, InternalError>;
+
+    /// Stores the result unless it exceeds the configured entry size limit
+    async fn put(
+        &self,
+        key: &QueryResultCacheKey,
+        result: &CachedQueryResult,
+    ) -> Result<(), InternalError>;
+
+    /// Size limit (in bytes) of a single entry, allowing callers to stop
+    /// buffering results that will not be cached anyway
+    fn max_entry_size(&self) -> usize;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueryResultCacheKey(Multihash);
+
+impl QueryResultCacheKey {
+    pub fn new(statement: &str, state: &QueryState) -> Self {
+        let mut buf = normalize_statement(statement);
+        for (id, ds) in &state.input_datasets {
+            write!(buf, "\n{id} {} {}", ds.alias, ds.block_hash).unwrap();
+        }
+        Self(Multihash::from_digest_sha3_256(buf.as_bytes()))
+    }
+
+    pub fn as_multihash(&self) -> &Multihash {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for QueryResultCacheKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.as_multibase())
+    }
+}
+
+/// Collapses insignificant whitespace and trailing semicolons so that
+/// cosmetically different statements share the cache entries. Whitespace
+/// inside string literals and quoted identifiers is preserved.
+pub fn normalize_statement(statement: &str) -> String {
+    let mut res = String::with_capacity(statement.len());
+    let mut quote = None;
+    let mut pending_space = false;
+
+    for c in statement
+        .trim()
+        .trim_end_matches(|c: char| c == ';' || c.is_whitespace())
+        .chars()
+    {
+        match quote {
+            Some(q) => {
+                if c == q {
+                    quote = None;
+                }
+            }
+            None if c.is_whitespace() => {
+                pending_space = true;
+                continue;
+            }
+            None => {
+                if c == '\'' || c == '"' {
+                    quote = Some(c);
+                }
+            }
+        }
+        if pending_space {
+            res.push(' ');
+            pending_space = false;
+        }
+        res.push(c);
+    }
+
+    res
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct CachedQueryResult {
+    pub schema: SchemaRef,
+    pub batches: Vec<RecordBatch>,
+}
+
+impl CachedQueryResult {
+    pub fn new(schema: SchemaRef, batches: Vec<RecordBatch>) -> Self {
+        Self { schema, batches }
+    }
+
+    /// Approximate in-memory size of the result in bytes
+    pub fn size(&self) -> usize {
+        self.batches
+            .iter()
+            .map(RecordBatch::get_array_memory_size)
+            .sum()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct QueryResultCacheConfig {
+    /// Results larger than this (in bytes) are not cached
+    pub max_entry_size: usize,
+    /// Least recently used entries are evicted once the total size of the
+    /// cache (in bytes) exceeds this limit
+    pub max_total_size: usize,
+}
+
+impl Default for QueryResultCacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entry_size: 10 * 1024 * 1024,
+            max_total_size: 256 * 1024 * 1024,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
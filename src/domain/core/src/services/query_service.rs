@@ -49,6 +49,14 @@ pub trait QueryService: Send + Sync {
     /// Prepares an execution plan for the SQL statement and returns a
     /// [DataFrame] that can be used to get schema and data, and the state
     /// information that can be used for reproducibility.
+    ///
+    /// Statement can query past states of datasets using the time travel
+    /// clauses that follow the table name:
+    ///
+    /// ```text
+    /// select * from "my.dataset" as of block 'f1620...'
+    /// select * from "my.dataset" as of system time '2024-01-01T00:00:00Z'
+    /// ```
     async fn sql_statement(
         &self,
         statement: &str,
@@ -130,7 +138,7 @@ pub struct QueryResponse {
     pub state: QueryState,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct QueryState {
     /// State of the input datasets used in the query
     pub input_datasets: BTreeMap<DatasetID, QueryStateDataset>,
@@ -147,6 +155,137 @@ pub struct QueryStateDataset {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Continuation token that allows fetching the results of a query page by
+/// page. It pins the state of the input datasets observed when the first page
+/// was served, so the following pages stay consistent even if the datasets
+/// get updated in between the requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryCursor {
+    /// Hash of the statement the cursor was issued for
+    pub statement_hash: Multihash,
+    /// State of the input datasets pinned by the first page
+    pub state: QueryState,
+    /// Number of records preceding the next page
+    pub offset: u64,
+}
+
+impl QueryCursor {
+    pub fn new(statement: &str, state: QueryState, offset: u64) -> Self {
+        Self {
+            statement_hash: Self::hash_statement(statement),
+            state,
+            offset,
+        }
+    }
+
+    fn hash_statement(statement: &str) -> Multihash {
+        Multihash::from_digest_sha3_256(statement.trim().as_bytes())
+    }
+
+    /// Returns an error if the cursor was issued for a different statement
+    pub fn ensure_statement(&self, statement: &str) -> Result<(), InvalidQueryCursorError> {
+        if self.statement_hash != Self::hash_statement(statement) {
+            return Err(InvalidQueryCursorError {
+                reason: "cursor was issued for a different query".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Query options that reproduce the pinned state of the input datasets
+    pub fn to_options(&self) -> QueryOptions {
+        QueryOptions {
+            input_datasets: self
+                .state
+                .input_datasets
+                .iter()
+                .map(|(id, ds)| {
+                    (
+                        id.clone(),
+                        QueryOptionsDataset {
+                            alias: ds.alias.clone(),
+                            block_hash: Some(ds.block_hash.clone()),
+                            hints: None,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Serializes the cursor into an opaque URL-safe string
+    pub fn encode(&self) -> String {
+        use base64::Engine as _;
+
+        let repr = QueryCursorRepr {
+            statement_hash: self.statement_hash.clone(),
+            inputs: self
+                .state
+                .input_datasets
+                .iter()
+                .map(|(id, ds)| QueryCursorReprDataset {
+                    id: id.clone(),
+                    alias: ds.alias.clone(),
+                    block_hash: ds.block_hash.clone(),
+                })
+                .collect(),
+            offset: self.offset,
+        };
+
+        let json = serde_json::to_vec(&repr).unwrap();
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(s: &str) -> Result<Self, InvalidQueryCursorError> {
+        use base64::Engine as _;
+
+        let invalid = |e: &dyn std::fmt::Display| InvalidQueryCursorError {
+            reason: e.to_string(),
+        };
+
+        let json = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(s)
+            .map_err(|e| invalid(&e))?;
+        let repr: QueryCursorRepr = serde_json::from_slice(&json).map_err(|e| invalid(&e))?;
+
+        Ok(Self {
+            statement_hash: repr.statement_hash,
+            state: QueryState {
+                input_datasets: repr
+                    .inputs
+                    .into_iter()
+                    .map(|ds| {
+                        (
+                            ds.id,
+                            QueryStateDataset {
+                                alias: ds.alias,
+                                block_hash: ds.block_hash,
+                            },
+                        )
+                    })
+                    .collect(),
+            },
+            offset: repr.offset,
+        })
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct QueryCursorRepr {
+    statement_hash: Multihash,
+    inputs: Vec<QueryCursorReprDataset>,
+    offset: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct QueryCursorReprDataset {
+    id: DatasetID,
+    alias: String,
+    block_hash: Multihash,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EngineDesc {
     /// A short name of the engine, e.g. "Spark", "Flink", "Datafusion"
@@ -231,7 +370,7 @@ pub enum QueryError {
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// This error returned only when the caller provides an explicit block hash to
-/// query via [`QueryOptionsDataset`]
+/// query via [`QueryOptionsDataset`] or via the `AS OF BLOCK` clause
 #[derive(Error, Clone, PartialEq, Eq, Debug)]
 #[error("Dataset {dataset_id} does not have a block {block_hash}")]
 pub struct DatasetBlockNotFoundError {
@@ -279,6 +418,14 @@ pub struct DatasetSchemaNotAvailableError {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Error, Clone, PartialEq, Eq, Debug)]
+#[error("Invalid query cursor: {reason}")]
+pub struct InvalidQueryCursorError {
+    pub reason: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 impl From<GetDatasetError> for QueryError {
     fn from(v: GetDatasetError) -> Self {
         match v {
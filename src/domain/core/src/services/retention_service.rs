@@ -0,0 +1,148 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use ::serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use internal_error::{ErrorIntoInternal, InternalError};
+use opendatafabric::*;
+use thiserror::Error;
+
+use crate::entities::SetRefError;
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait RetentionService: Send + Sync {
+    /// Removes records of a root dataset with event time older than the cutoff
+    /// by rewriting the affected part of the metadata chain
+    async fn apply_retention(
+        &self,
+        dataset_handle: &DatasetHandle,
+        options: RetentionOptions,
+    ) -> Result<RetentionResult, RetentionError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionOptions {
+    /// Records with event time strictly before this moment are removed
+    pub cutoff: DateTime<Utc>,
+    /// Only report what would be removed without modifying the dataset
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RetentionResult {
+    NothingToDo,
+    /// Result of a dry run: the dataset was left intact and the numbers
+    /// describe what would have been removed
+    DryRun {
+        head: Multihash,
+        num_records_removed: u64,
+        num_slices_removed: usize,
+    },
+    Success {
+        old_head: Multihash,
+        new_head: Multihash,
+        num_records_removed: u64,
+        /// Number of data slices that expired entirely
+        num_slices_removed: usize,
+    },
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Error)]
+pub enum RetentionError {
+    #[error(transparent)]
+    DatasetNotFound(
+        #[from]
+        #[backtrace]
+        DatasetNotFoundError,
+    ),
+    #[error(transparent)]
+    InvalidDatasetKind(
+        #[from]
+        #[backtrace]
+        InvalidDatasetKindError,
+    ),
+    #[error(transparent)]
+    CASFailed(
+        #[from]
+        #[backtrace]
+        RefCASError,
+    ),
+    #[error(transparent)]
+    Access(
+        #[from]
+        #[backtrace]
+        AccessError,
+    ),
+    #[error(transparent)]
+    Internal(
+        #[from]
+        #[backtrace]
+        InternalError,
+    ),
+}
+
+impl From<GetDatasetError> for RetentionError {
+    fn from(v: GetDatasetError) -> Self {
+        match v {
+            GetDatasetError::NotFound(e) => Self::DatasetNotFound(e),
+            GetDatasetError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<auth::DatasetActionUnauthorizedError> for RetentionError {
+    fn from(v: auth::DatasetActionUnauthorizedError) -> Self {
+        match v {
+            auth::DatasetActionUnauthorizedError::Access(e) => Self::Access(e),
+            auth::DatasetActionUnauthorizedError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<GetRefError> for RetentionError {
+    fn from(v: GetRefError) -> Self {
+        match v {
+            GetRefError::NotFound(e) => Self::Internal(e.int_err()),
+            GetRefError::Access(e) => Self::Access(e),
+            GetRefError::Internal(e) => Self::Internal(e),
+        }
+    }
+}
+
+impl From<IterBlocksError> for RetentionError {
+    fn from(v: IterBlocksError) -> Self {
+        match v {
+            IterBlocksError::Access(e) => Self::Access(e),
+            IterBlocksError::Internal(e) => Self::Internal(e),
+            _ => Self::Internal(v.int_err()),
+        }
+    }
+}
+
+impl From<SetRefError> for RetentionError {
+    fn from(v: SetRefError) -> Self {
+        match v {
+            SetRefError::CASFailed(e) => Self::CASFailed(e),
+            SetRefError::Access(e) => Self::Access(e),
+            SetRefError::Internal(e) => Self::Internal(e),
+            SetRefError::BlockNotFound(e) => Self::Internal(e.int_err()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -19,16 +19,44 @@ use crate::*;
 
 #[async_trait::async_trait]
 pub trait SearchService: Send + Sync {
+    /// Searches for datasets in the registered remote repositories
     async fn search(
         &self,
         query: Option<&str>,
         options: SearchOptions,
     ) -> Result<SearchResult, SearchError>;
+
+    /// Searches for datasets in the local repository using the index built
+    /// from their metadata chains. Results are ordered by relevance.
+    async fn search_local(
+        &self,
+        query: Option<&str>,
+        options: SearchLocalOptions,
+    ) -> Result<SearchLocalResult, SearchError>;
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct SearchOptions {
     pub repository_names: Vec<RepoName>,
+    pub filters: SearchFilters,
+    /// Whether to request dataset descriptions from remote nodes
+    pub include_description: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchFilters {
+    /// Only include datasets of the specified kinds (any kind when empty)
+    pub kinds: Vec<DatasetKind>,
+    /// Only include datasets owned by the specified account
+    pub owner: Option<AccountName>,
+    /// Only include datasets that have all the specified keywords
+    pub keywords: Vec<String>,
+}
+
+impl SearchFilters {
+    pub fn is_empty(&self) -> bool {
+        self.kinds.is_empty() && self.owner.is_none() && self.keywords.is_empty()
+    }
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -41,11 +69,54 @@ pub struct SearchResultDataset {
     pub id: Option<DatasetID>,
     pub alias: DatasetAliasRemote,
     pub kind: Option<DatasetKind>,
+    pub description: Option<String>,
     pub num_blocks: Option<u64>,
     pub num_records: Option<u64>,
     pub estimated_size: Option<u64>,
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct SearchLocalOptions {
+    pub filters: SearchFilters,
+    /// Number of top results to skip
+    pub offset: usize,
+    /// Maximal number of results to return
+    pub limit: usize,
+}
+
+impl Default for SearchLocalOptions {
+    fn default() -> Self {
+        Self {
+            filters: SearchFilters::default(),
+            offset: 0,
+            limit: usize::MAX,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchLocalResult {
+    /// Requested page of results, most relevant first
+    pub datasets: Vec<SearchLocalResultDataset>,
+    /// Total number of datasets matching the query and filters
+    pub total_count: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchLocalResultDataset {
+    pub handle: DatasetHandle,
+    pub kind: DatasetKind,
+    pub description: Option<String>,
+    pub keywords: Vec<String>,
+    pub num_blocks: u64,
+    pub num_records: u64,
+    pub estimated_size: u64,
+    /// Relevance of the dataset to the query (higher is better)
+    pub score: u32,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Errors
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
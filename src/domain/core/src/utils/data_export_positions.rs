@@ -0,0 +1,63 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use opendatafabric::serde::yaml::Manifest;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Name of the object in the dataset info repository that holds the positions
+/// of the incremental data exports
+pub const DATA_EXPORT_POSITIONS_KEY: &str = "export-positions";
+
+const DATA_EXPORT_POSITIONS_KIND: &str = "DataExportPositions";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn read_data_export_positions(
+    info_repo: &dyn NamedObjectRepository,
+) -> Result<DataExportPositions, InternalError> {
+    let data = match info_repo.get(DATA_EXPORT_POSITIONS_KEY).await {
+        Ok(data) => data,
+        Err(GetNamedError::NotFound(_)) => return Ok(DataExportPositions::default()),
+        Err(e) => return Err(e.int_err()),
+    };
+
+    let manifest: Manifest<DataExportPositions> = serde_yaml::from_slice(&data[..]).int_err()?;
+
+    if manifest.kind != DATA_EXPORT_POSITIONS_KIND {
+        return Err(InvalidObjectKind {
+            expected: DATA_EXPORT_POSITIONS_KIND.to_owned(),
+            actual: manifest.kind,
+        }
+        .int_err());
+    }
+
+    Ok(manifest.content)
+}
+
+pub async fn write_data_export_positions(
+    info_repo: &dyn NamedObjectRepository,
+    positions: &DataExportPositions,
+) -> Result<(), InternalError> {
+    let manifest = Manifest {
+        kind: DATA_EXPORT_POSITIONS_KIND.to_owned(),
+        version: 1,
+        content: positions.clone(),
+    };
+
+    let data = serde_yaml::to_string(&manifest).int_err()?.into_bytes();
+
+    info_repo
+        .set(DATA_EXPORT_POSITIONS_KEY, &data)
+        .await
+        .int_err()
+}
@@ -7,6 +7,9 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+pub mod data_export_positions;
 pub mod metadata_chain_comparator;
 pub mod owned_file;
+pub mod partitioned_slices;
 pub mod paths;
+pub mod slice_statistics;
@@ -0,0 +1,144 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use opendatafabric::serde::yaml::Manifest;
+use opendatafabric::Multihash;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Name of the object in the dataset info repository that holds the
+/// partitioning spec applied to the newly added data slices
+pub const PARTITIONING_SPEC_KEY: &str = "partitioning";
+
+/// Prefix of the objects in the dataset info repository that hold the
+/// partitions of individual data slices, followed by the slice physical hash
+pub const SLICE_PARTITIONS_KEY_PREFIX: &str = "partitions.";
+
+const PARTITIONING_SPEC_KIND: &str = "PartitioningSpec";
+const SLICE_PARTITIONS_KIND: &str = "SlicePartitions";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn slice_partitions_key(physical_hash: &Multihash) -> String {
+    format!(
+        "{SLICE_PARTITIONS_KEY_PREFIX}{}",
+        physical_hash.as_multibase()
+    )
+}
+
+pub async fn read_partitioning_spec(
+    info_repo: &dyn NamedObjectRepository,
+) -> Result<Option<PartitioningSpec>, InternalError> {
+    read_manifest(info_repo, PARTITIONING_SPEC_KEY, PARTITIONING_SPEC_KIND).await
+}
+
+pub async fn write_partitioning_spec(
+    info_repo: &dyn NamedObjectRepository,
+    spec: &PartitioningSpec,
+) -> Result<(), InternalError> {
+    write_manifest(
+        info_repo,
+        PARTITIONING_SPEC_KEY,
+        PARTITIONING_SPEC_KIND,
+        spec,
+    )
+    .await
+}
+
+pub async fn delete_partitioning_spec(
+    info_repo: &dyn NamedObjectRepository,
+) -> Result<(), InternalError> {
+    info_repo.delete(PARTITIONING_SPEC_KEY).await.int_err()
+}
+
+pub async fn read_slice_partitions(
+    info_repo: &dyn NamedObjectRepository,
+    physical_hash: &Multihash,
+) -> Result<Option<SlicePartitions>, InternalError> {
+    read_manifest(
+        info_repo,
+        &slice_partitions_key(physical_hash),
+        SLICE_PARTITIONS_KIND,
+    )
+    .await
+}
+
+pub async fn write_slice_partitions(
+    info_repo: &dyn NamedObjectRepository,
+    physical_hash: &Multihash,
+    partitions: &SlicePartitions,
+) -> Result<(), InternalError> {
+    write_manifest(
+        info_repo,
+        &slice_partitions_key(physical_hash),
+        SLICE_PARTITIONS_KIND,
+        partitions,
+    )
+    .await
+}
+
+pub async fn delete_slice_partitions(
+    info_repo: &dyn NamedObjectRepository,
+    physical_hash: &Multihash,
+) -> Result<(), InternalError> {
+    info_repo
+        .delete(&slice_partitions_key(physical_hash))
+        .await
+        .int_err()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn read_manifest<T: DeserializeOwned>(
+    info_repo: &dyn NamedObjectRepository,
+    key: &str,
+    kind: &str,
+) -> Result<Option<T>, InternalError> {
+    let data = match info_repo.get(key).await {
+        Ok(data) => data,
+        Err(GetNamedError::NotFound(_)) => return Ok(None),
+        Err(e) => return Err(e.int_err()),
+    };
+
+    let manifest: Manifest<T> = serde_yaml::from_slice(&data[..]).int_err()?;
+
+    if manifest.kind != kind {
+        return Err(InvalidObjectKind {
+            expected: kind.to_owned(),
+            actual: manifest.kind,
+        }
+        .int_err());
+    }
+
+    Ok(Some(manifest.content))
+}
+
+async fn write_manifest<T: Serialize + Clone>(
+    info_repo: &dyn NamedObjectRepository,
+    key: &str,
+    kind: &str,
+    content: &T,
+) -> Result<(), InternalError> {
+    let manifest = Manifest {
+        kind: kind.to_owned(),
+        version: 1,
+        content: content.clone(),
+    };
+
+    let data = serde_yaml::to_string(&manifest).int_err()?.into_bytes();
+
+    info_repo.set(key, &data).await.int_err()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,247 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use opendatafabric::serde::yaml::Manifest;
+use opendatafabric::Multihash;
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Name of the object in the dataset info repository that holds the
+/// configuration of the column statistics
+pub const SLICE_STATISTICS_CONFIG_KEY: &str = "statistics";
+
+/// Prefix of the objects in the dataset info repository that hold the column
+/// statistics of individual data files, followed by the file physical hash
+pub const SLICE_STATISTICS_KEY_PREFIX: &str = "statistics.";
+
+const SLICE_STATISTICS_CONFIG_KIND: &str = "SliceStatisticsConfig";
+
+// Statistics of a data file are stored as the magic bytes and the format
+// version, followed by the length-prefixed JSON header that describes the
+// columns, followed by the bits of the bloom filters in the order of columns
+const SLICE_STATISTICS_MAGIC: &[u8; 4] = b"KSST";
+const SLICE_STATISTICS_VERSION: u16 = 1;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn read_slice_statistics_config(
+    info_repo: &dyn NamedObjectRepository,
+) -> Result<Option<SliceStatisticsConfig>, InternalError> {
+    let data = match info_repo.get(SLICE_STATISTICS_CONFIG_KEY).await {
+        Ok(data) => data,
+        Err(GetNamedError::NotFound(_)) => return Ok(None),
+        Err(e) => return Err(e.int_err()),
+    };
+
+    let manifest: Manifest<SliceStatisticsConfig> = serde_yaml::from_slice(&data[..]).int_err()?;
+
+    if manifest.kind != SLICE_STATISTICS_CONFIG_KIND {
+        return Err(InvalidObjectKind {
+            expected: SLICE_STATISTICS_CONFIG_KIND.to_owned(),
+            actual: manifest.kind,
+        }
+        .int_err());
+    }
+
+    Ok(Some(manifest.content))
+}
+
+pub async fn write_slice_statistics_config(
+    info_repo: &dyn NamedObjectRepository,
+    config: &SliceStatisticsConfig,
+) -> Result<(), InternalError> {
+    let manifest = Manifest {
+        kind: SLICE_STATISTICS_CONFIG_KIND.to_owned(),
+        version: 1,
+        content: config.clone(),
+    };
+
+    let data = serde_yaml::to_string(&manifest).int_err()?.into_bytes();
+
+    info_repo
+        .set(SLICE_STATISTICS_CONFIG_KEY, &data)
+        .await
+        .int_err()
+}
+
+pub async fn delete_slice_statistics_config(
+    info_repo: &dyn NamedObjectRepository,
+) -> Result<(), InternalError> {
+    info_repo
+        .delete(SLICE_STATISTICS_CONFIG_KEY)
+        .await
+        .int_err()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn slice_statistics_key(physical_hash: &Multihash) -> String {
+    format!(
+        "{SLICE_STATISTICS_KEY_PREFIX}{}",
+        physical_hash.as_multibase()
+    )
+}
+
+pub async fn read_slice_statistics(
+    info_repo: &dyn NamedObjectRepository,
+    physical_hash: &Multihash,
+) -> Result<Option<SliceStatistics>, InternalError> {
+    let data = match info_repo.get(&slice_statistics_key(physical_hash)).await {
+        Ok(data) => data,
+        Err(GetNamedError::NotFound(_)) => return Ok(None),
+        Err(e) => return Err(e.int_err()),
+    };
+
+    decode_slice_statistics(&data).map(Some)
+}
+
+pub async fn write_slice_statistics(
+    info_repo: &dyn NamedObjectRepository,
+    physical_hash: &Multihash,
+    statistics: &SliceStatistics,
+) -> Result<(), InternalError> {
+    let data = encode_slice_statistics(statistics)?;
+
+    info_repo
+        .set(&slice_statistics_key(physical_hash), &data)
+        .await
+        .int_err()
+}
+
+pub async fn delete_slice_statistics(
+    info_repo: &dyn NamedObjectRepository,
+    physical_hash: &Multihash,
+) -> Result<(), InternalError> {
+    info_repo
+        .delete(&slice_statistics_key(physical_hash))
+        .await
+        .int_err()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct StatisticsHeader {
+    columns: Vec<ColumnHeader>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct ColumnHeader {
+    name: String,
+    min: Option<String>,
+    max: Option<String>,
+    null_count: u64,
+    bloom_filter: Option<BloomFilterHeader>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct BloomFilterHeader {
+    num_hashes: u32,
+    num_bytes: usize,
+}
+
+pub fn encode_slice_statistics(statistics: &SliceStatistics) -> Result<Vec<u8>, InternalError> {
+    let header = StatisticsHeader {
+        columns: statistics
+            .columns
+            .iter()
+            .map(|c| ColumnHeader {
+                name: c.name.clone(),
+                min: c.min.clone(),
+                max: c.max.clone(),
+                null_count: c.null_count,
+                bloom_filter: c.bloom_filter.as_ref().map(|f| BloomFilterHeader {
+                    num_hashes: f.num_hashes(),
+                    num_bytes: f.bits().len(),
+                }),
+            })
+            .collect(),
+    };
+
+    let header = serde_json::to_vec(&header).int_err()?;
+
+    let mut data = Vec::new();
+    data.extend_from_slice(SLICE_STATISTICS_MAGIC);
+    data.extend_from_slice(&SLICE_STATISTICS_VERSION.to_le_bytes());
+    data.extend_from_slice(&u32::try_from(header.len()).int_err()?.to_le_bytes());
+    data.extend_from_slice(&header);
+
+    for bloom_filter in statistics
+        .columns
+        .iter()
+        .filter_map(|c| c.bloom_filter.as_ref())
+    {
+        data.extend_from_slice(bloom_filter.bits());
+    }
+
+    Ok(data)
+}
+
+pub fn decode_slice_statistics(data: &[u8]) -> Result<SliceStatistics, InternalError> {
+    let mut rest = data;
+
+    if take(&mut rest, SLICE_STATISTICS_MAGIC.len())? != SLICE_STATISTICS_MAGIC {
+        return InternalError::bail("Slice statistics have unexpected format");
+    }
+
+    let version = u16::from_le_bytes(take(&mut rest, 2)?.try_into().unwrap());
+    if version != SLICE_STATISTICS_VERSION {
+        return InternalError::bail(format!(
+            "Slice statistics have unsupported version {version}"
+        ));
+    }
+
+    let header_len =
+        usize::try_from(u32::from_le_bytes(take(&mut rest, 4)?.try_into().unwrap())).int_err()?;
+    let header: StatisticsHeader =
+        serde_json::from_slice(take(&mut rest, header_len)?).int_err()?;
+
+    let mut columns = Vec::with_capacity(header.columns.len());
+    for c in header.columns {
+        let bloom_filter = match c.bloom_filter {
+            None => None,
+            Some(h) => {
+                let bits = take(&mut rest, h.num_bytes)?.to_vec();
+                Some(BloomFilter::from_parts(h.num_hashes, bits).int_err()?)
+            }
+        };
+
+        columns.push(ColumnStatistics {
+            name: c.name,
+            min: c.min,
+            max: c.max,
+            null_count: c.null_count,
+            bloom_filter,
+        });
+    }
+
+    if !rest.is_empty() {
+        return InternalError::bail("Slice statistics have trailing data");
+    }
+
+    Ok(SliceStatistics { columns })
+}
+
+fn take<'a>(rest: &mut &'a [u8], len: usize) -> Result<&'a [u8], InternalError> {
+    if rest.len() < len {
+        return InternalError::bail("Slice statistics are truncated");
+    }
+    let (chunk, tail) = rest.split_at(len);
+    *rest = tail;
+    Ok(chunk)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -71,13 +71,15 @@ async fn test_correctly_handles_outbox_messages() {
     harness
         .mimic_dataset_renamed(
             dataset_id.clone(),
-            owner_account_id,
+            owner_account_id.clone(),
             initial_dataset_name,
             new_dataset_name,
         )
         .await;
 
-    harness.mimic_dataset_deleted(dataset_id).await;
+    harness
+        .mimic_dataset_deleted(dataset_id, owner_account_id)
+        .await;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -288,11 +290,11 @@ impl DatasetEntryServiceHarness {
             .unwrap();
     }
 
-    async fn mimic_dataset_deleted(&self, dataset_id: DatasetID) {
+    async fn mimic_dataset_deleted(&self, dataset_id: DatasetID, owner_account_id: AccountID) {
         self.outbox
             .post_message(
                 MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
-                DatasetLifecycleMessage::deleted(dataset_id),
+                DatasetLifecycleMessage::deleted(dataset_id, owner_account_id),
             )
             .await
             .unwrap();
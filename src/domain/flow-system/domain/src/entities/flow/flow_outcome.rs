@@ -7,8 +7,13 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use kamu_core::{CompactionResult, PullResult, PullResultUpToDate};
-use kamu_task_system::{self as ts, ResetDatasetTaskError, UpdateDatasetTaskError};
+use kamu_core::{CompactionResult, PullResult, PullResultUpToDate, RetentionResult};
+use kamu_task_system::{
+    self as ts,
+    ResetDatasetTaskError,
+    UpdateDatasetTaskError,
+    VerifyDatasetTaskError,
+};
 use opendatafabric::{DatasetID, Multihash};
 use serde::{Deserialize, Serialize};
 use ts::TaskError;
@@ -41,7 +46,9 @@ pub enum FlowResult {
     Empty,
     DatasetUpdate(FlowResultDatasetUpdate),
     DatasetCompact(FlowResultDatasetCompact),
+    DatasetSoftCompact(FlowResultDatasetSoftCompact),
     DatasetReset(FlowResultDatasetReset),
+    DatasetRetention(FlowResultDatasetRetention),
 }
 
 impl FlowResult {
@@ -50,7 +57,10 @@ impl FlowResult {
             FlowResult::Empty => true,
             FlowResult::DatasetUpdate(_)
             | FlowResult::DatasetCompact(_)
+            | FlowResult::DatasetSoftCompact(_)
             | FlowResult::DatasetReset(_) => false,
+            // Dry run leaves the dataset intact, so there is nothing to propagate
+            FlowResult::DatasetRetention(retention) => retention.is_dry_run(),
         }
     }
 }
@@ -60,6 +70,7 @@ pub enum FlowError {
     Failed,
     InputDatasetCompacted(FlowInputDatasetCompactedError),
     ResetHeadNotFound,
+    VerificationFailed(FlowVerificationFailedError),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -67,6 +78,22 @@ pub struct FlowInputDatasetCompactedError {
     pub dataset_id: DatasetID,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowVerificationFailedError {
+    pub kind: FlowVerificationFailureKind,
+    /// First block that failed verification
+    pub block_hash: Multihash,
+    pub message: String,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlowVerificationFailureKind {
+    /// Data or checkpoint files don't match metadata
+    Integrity,
+    /// Transformation replay produced non-equivalent results
+    Reproducibility,
+}
+
 impl From<&TaskError> for FlowError {
     fn from(value: &TaskError) -> Self {
         match value {
@@ -81,6 +108,21 @@ impl From<&TaskError> for FlowError {
             TaskError::ResetDatasetError(reset_dataset_error) => match reset_dataset_error {
                 ResetDatasetTaskError::ResetHeadNotFound => Self::ResetHeadNotFound,
             },
+            TaskError::VerifyDatasetError(verify_dataset_error) => {
+                let (kind, err) = match verify_dataset_error {
+                    VerifyDatasetTaskError::IntegrityCheckFailed(err) => {
+                        (FlowVerificationFailureKind::Integrity, err)
+                    }
+                    VerifyDatasetTaskError::ReproducibilityCheckFailed(err) => {
+                        (FlowVerificationFailureKind::Reproducibility, err)
+                    }
+                };
+                Self::VerificationFailed(FlowVerificationFailedError {
+                    kind,
+                    block_hash: err.block_hash.clone(),
+                    message: err.message.clone(),
+                })
+            }
         }
     }
 }
@@ -109,11 +151,42 @@ pub struct FlowResultDatasetCompact {
     pub new_num_blocks: usize,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowResultDatasetSoftCompact {
+    pub old_num_slices: usize,
+    pub new_num_slices: usize,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct FlowResultDatasetReset {
     pub new_head: Multihash,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlowResultDatasetRetention {
+    Applied(FlowResultDatasetRetentionApplied),
+    DryRun(FlowResultDatasetRetentionDryRun),
+}
+
+impl FlowResultDatasetRetention {
+    pub fn is_dry_run(&self) -> bool {
+        matches!(self, Self::DryRun(_))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowResultDatasetRetentionApplied {
+    pub new_head: Multihash,
+    pub num_records_removed: u64,
+    pub num_slices_removed: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlowResultDatasetRetentionDryRun {
+    pub num_records_removed: u64,
+    pub num_slices_removed: usize,
+}
+
 impl From<ts::TaskResult> for FlowResult {
     fn from(value: ts::TaskResult) -> Self {
         match value {
@@ -160,6 +233,41 @@ impl From<ts::TaskResult> for FlowResult {
                         old_num_blocks,
                         new_num_blocks,
                     }),
+                    CompactionResult::SoftSuccess {
+                        old_num_slices,
+                        new_num_slices,
+                        ..
+                    } => Self::DatasetSoftCompact(FlowResultDatasetSoftCompact {
+                        old_num_slices,
+                        new_num_slices,
+                    }),
+                }
+            }
+            ts::TaskResult::RetentionDatasetResult(task_retention_result) => {
+                match task_retention_result.retention_result {
+                    RetentionResult::NothingToDo => Self::Empty,
+                    RetentionResult::DryRun {
+                        num_records_removed,
+                        num_slices_removed,
+                        ..
+                    } => Self::DatasetRetention(FlowResultDatasetRetention::DryRun(
+                        FlowResultDatasetRetentionDryRun {
+                            num_records_removed,
+                            num_slices_removed,
+                        },
+                    )),
+                    RetentionResult::Success {
+                        new_head,
+                        num_records_removed,
+                        num_slices_removed,
+                        ..
+                    } => Self::DatasetRetention(FlowResultDatasetRetention::Applied(
+                        FlowResultDatasetRetentionApplied {
+                            new_head,
+                            num_records_removed,
+                            num_slices_removed,
+                        },
+                    )),
                 }
             }
         }
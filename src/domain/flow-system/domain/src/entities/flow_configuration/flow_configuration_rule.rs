@@ -9,7 +9,15 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{CompactionRule, IngestRule, ResetRule, Schedule, TransformRule};
+use crate::{
+    CompactionRule,
+    IngestRule,
+    ResetRule,
+    RetentionRule,
+    Schedule,
+    TransformRule,
+    VerifyRule,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -20,6 +28,8 @@ pub enum FlowConfigurationRule {
     CompactionRule(CompactionRule),
     IngestRule(IngestRule),
     ResetRule(ResetRule),
+    VerifyRule(VerifyRule),
+    RetentionRule(RetentionRule),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -32,6 +32,10 @@ impl FlowConfigurationState {
         match self.rule {
             FlowConfigurationRule::Schedule(schedule) => Some(schedule),
             FlowConfigurationRule::IngestRule(ingest) => Some(ingest.schedule_condition),
+            FlowConfigurationRule::VerifyRule(verify) => Some(verify.schedule_condition),
+            FlowConfigurationRule::RetentionRule(retention) => {
+                Some(retention.schedule_condition().clone())
+            }
             FlowConfigurationRule::CompactionRule(_)
             | FlowConfigurationRule::ResetRule(_)
             | FlowConfigurationRule::TransformRule(_) => None,
@@ -69,6 +73,22 @@ impl FlowConfigurationState {
             None
         }
     }
+
+    pub fn try_get_verify_rule(self) -> Option<VerifyRule> {
+        if let FlowConfigurationRule::VerifyRule(verify_rule) = self.rule {
+            Some(verify_rule)
+        } else {
+            None
+        }
+    }
+
+    pub fn try_get_retention_rule(self) -> Option<RetentionRule> {
+        if let FlowConfigurationRule::RetentionRule(retention_rule) = self.rule {
+            Some(retention_rule)
+        } else {
+            None
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
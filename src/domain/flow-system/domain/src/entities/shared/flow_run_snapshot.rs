@@ -20,6 +20,8 @@ pub enum FlowConfigurationSnapshot {
     Schedule(Schedule),
     Ingest(IngestRule),
     Reset(ResetRule),
+    Verify(VerifyRule),
+    Retention(RetentionRule),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -17,7 +17,10 @@ pub enum DatasetFlowType {
     Ingest,
     ExecuteTransform,
     HardCompaction,
+    SoftCompaction,
     Reset,
+    Verify,
+    Retention,
 }
 
 impl DatasetFlowType {
@@ -26,17 +29,22 @@ impl DatasetFlowType {
             Self::Ingest,
             Self::ExecuteTransform,
             Self::HardCompaction,
+            Self::SoftCompaction,
             Self::Reset,
+            Self::Verify,
+            Self::Retention,
         ]
     }
 
     pub fn dataset_kind_restriction(&self) -> Option<opendatafabric::DatasetKind> {
         match self {
-            DatasetFlowType::Ingest | DatasetFlowType::HardCompaction => {
-                Some(opendatafabric::DatasetKind::Root)
-            }
+            DatasetFlowType::Ingest
+            | DatasetFlowType::HardCompaction
+            | DatasetFlowType::Retention => Some(opendatafabric::DatasetKind::Root),
             DatasetFlowType::ExecuteTransform => Some(opendatafabric::DatasetKind::Derivative),
-            DatasetFlowType::Reset => None,
+            DatasetFlowType::SoftCompaction | DatasetFlowType::Reset | DatasetFlowType::Verify => {
+                None
+            }
         }
     }
 }
@@ -71,7 +79,8 @@ impl AnyFlowType {
                 DatasetFlowType::Ingest
                 | DatasetFlowType::ExecuteTransform
                 | DatasetFlowType::HardCompaction
-                | DatasetFlowType::Reset,
+                | DatasetFlowType::Reset
+                | DatasetFlowType::Retention,
             ) => FlowSuccessFollowupMethod::TriggerDependent,
             _ => FlowSuccessFollowupMethod::Ignore,
         }
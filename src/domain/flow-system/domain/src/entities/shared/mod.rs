@@ -14,8 +14,10 @@ mod flow_task_metadata;
 mod flow_type;
 mod ingest_rule;
 mod reset_rule;
+mod retention_rule;
 mod schedule;
 mod transform_rule;
+mod verify_rule;
 
 pub use compaction_rule::*;
 pub use flow_key::*;
@@ -24,5 +26,7 @@ pub use flow_task_metadata::*;
 pub use flow_type::*;
 pub use ingest_rule::*;
 pub use reset_rule::*;
+pub use retention_rule::*;
 pub use schedule::*;
 pub use transform_rule::*;
+pub use verify_rule::*;
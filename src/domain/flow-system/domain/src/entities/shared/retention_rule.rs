@@ -0,0 +1,111 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use thiserror::Error;
+
+use super::Schedule;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionRule {
+    /// Records with event time older than this window are removed
+    #[serde_as(as = "serde_with::DurationSecondsWithFrac<String>")]
+    retention_window: chrono::Duration,
+    /// Only report what would be removed without modifying the dataset
+    dry_run: bool,
+    // ToDo: Schedule should be on higher level and not mixed up
+    // with general configuration rules
+    schedule_condition: Schedule,
+}
+
+impl RetentionRule {
+    pub fn new_checked(
+        retention_window: chrono::Duration,
+        dry_run: bool,
+        schedule_condition: Schedule,
+    ) -> Result<Self, RetentionRuleValidationError> {
+        if retention_window <= chrono::Duration::zero() {
+            return Err(RetentionRuleValidationError::RetentionWindowNotPositive);
+        }
+
+        Ok(Self {
+            retention_window,
+            dry_run,
+            schedule_condition,
+        })
+    }
+
+    #[inline]
+    pub fn retention_window(&self) -> chrono::Duration {
+        self.retention_window
+    }
+
+    #[inline]
+    pub fn dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    #[inline]
+    pub fn schedule_condition(&self) -> &Schedule {
+        &self.schedule_condition
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum RetentionRuleValidationError {
+    #[error("Retention window must be a positive duration")]
+    RetentionWindowNotPositive,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches::assert_matches;
+
+    use chrono::Duration;
+
+    use crate::{RetentionRule, RetentionRuleValidationError, Schedule};
+
+    #[test]
+    fn test_valid_retention_rule() {
+        let schedule: Schedule = Duration::days(1).into();
+
+        assert_matches!(
+            RetentionRule::new_checked(Duration::days(90), false, schedule.clone()),
+            Ok(_)
+        );
+        assert_matches!(
+            RetentionRule::new_checked(Duration::seconds(1), true, schedule),
+            Ok(_)
+        );
+    }
+
+    #[test]
+    fn test_non_positive_retention_window() {
+        let schedule: Schedule = Duration::days(1).into();
+
+        assert_matches!(
+            RetentionRule::new_checked(Duration::zero(), false, schedule.clone()),
+            Err(RetentionRuleValidationError::RetentionWindowNotPositive)
+        );
+        assert_matches!(
+            RetentionRule::new_checked(Duration::days(-1), false, schedule),
+            Err(RetentionRuleValidationError::RetentionWindowNotPositive)
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
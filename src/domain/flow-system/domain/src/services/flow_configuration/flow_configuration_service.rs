@@ -126,6 +126,18 @@ pub trait FlowConfigurationServiceExt {
         flow_type: DatasetFlowType,
     ) -> Result<Option<ResetRule>, FindFlowConfigurationError>;
 
+    async fn try_get_dataset_verify_rule(
+        &self,
+        dataset_id: DatasetID,
+        flow_type: DatasetFlowType,
+    ) -> Result<Option<VerifyRule>, FindFlowConfigurationError>;
+
+    async fn try_get_dataset_retention_rule(
+        &self,
+        dataset_id: DatasetID,
+        flow_type: DatasetFlowType,
+    ) -> Result<Option<RetentionRule>, FindFlowConfigurationError>;
+
     async fn try_get_config_snapshot_by_key(
         &self,
         flow_key: FlowKey,
@@ -226,6 +238,44 @@ impl<T: FlowConfigurationService + ?Sized> FlowConfigurationServiceExt for T {
         )
     }
 
+    async fn try_get_dataset_verify_rule(
+        &self,
+        dataset_id: DatasetID,
+        flow_type: DatasetFlowType,
+    ) -> Result<Option<VerifyRule>, FindFlowConfigurationError> {
+        let maybe_config = self
+            .find_configuration(FlowKey::dataset(dataset_id, flow_type))
+            .await?;
+        Ok(
+            if let Some(config) = maybe_config
+                && config.is_active()
+            {
+                config.try_get_verify_rule()
+            } else {
+                None
+            },
+        )
+    }
+
+    async fn try_get_dataset_retention_rule(
+        &self,
+        dataset_id: DatasetID,
+        flow_type: DatasetFlowType,
+    ) -> Result<Option<RetentionRule>, FindFlowConfigurationError> {
+        let maybe_config = self
+            .find_configuration(FlowKey::dataset(dataset_id, flow_type))
+            .await?;
+        Ok(
+            if let Some(config) = maybe_config
+                && config.is_active()
+            {
+                config.try_get_retention_rule()
+            } else {
+                None
+            },
+        )
+    }
+
     async fn try_get_config_snapshot_by_key(
         &self,
         flow_key: FlowKey,
@@ -257,13 +307,27 @@ impl<T: FlowConfigurationService + ?Sized> FlowConfigurationServiceExt for T {
                     )
                     .await?
                     .map(FlowConfigurationSnapshot::Reset),
-                DatasetFlowType::HardCompaction => self
+                DatasetFlowType::HardCompaction | DatasetFlowType::SoftCompaction => self
                     .try_get_dataset_compaction_rule(
                         dataset_flow_key.dataset_id,
                         dataset_flow_key.flow_type,
                     )
                     .await?
                     .map(FlowConfigurationSnapshot::Compaction),
+                DatasetFlowType::Verify => self
+                    .try_get_dataset_verify_rule(
+                        dataset_flow_key.dataset_id,
+                        dataset_flow_key.flow_type,
+                    )
+                    .await?
+                    .map(FlowConfigurationSnapshot::Verify),
+                DatasetFlowType::Retention => self
+                    .try_get_dataset_retention_rule(
+                        dataset_flow_key.dataset_id,
+                        dataset_flow_key.flow_type,
+                    )
+                    .await?
+                    .map(FlowConfigurationSnapshot::Retention),
             },
         };
 
@@ -403,6 +403,24 @@ impl FlowExecutorImpl {
                         keep_metadata_only,
                     }))
                 }
+                DatasetFlowType::SoftCompaction => {
+                    let mut max_slice_size: Option<u64> = None;
+                    let mut max_slice_records: Option<u64> = None;
+
+                    if let Some(config_snapshot) = maybe_config_snapshot
+                        && let FlowConfigurationSnapshot::Compaction(compaction_rule) =
+                            config_snapshot
+                    {
+                        max_slice_size = compaction_rule.max_slice_size();
+                        max_slice_records = compaction_rule.max_slice_records();
+                    };
+
+                    Ok(LogicalPlan::SoftCompactionDataset(SoftCompactionDataset {
+                        dataset_id: flow_key.dataset_id.clone(),
+                        max_slice_size,
+                        max_slice_records,
+                    }))
+                }
                 DatasetFlowType::Reset => {
                     if let Some(config_rule) = maybe_config_snapshot
                         && let FlowConfigurationSnapshot::Reset(reset_rule) = config_rule
@@ -416,6 +434,31 @@ impl FlowExecutorImpl {
                     }
                     InternalError::bail("Reset flow cannot be called without configuration")
                 }
+                DatasetFlowType::Verify => {
+                    let mut replay_transformations = true;
+                    if let Some(config_snapshot) = maybe_config_snapshot
+                        && let FlowConfigurationSnapshot::Verify(verify_rule) = config_snapshot
+                    {
+                        replay_transformations = verify_rule.replay_transformations;
+                    }
+                    Ok(LogicalPlan::VerifyDataset(VerifyDataset {
+                        dataset_id: flow_key.dataset_id.clone(),
+                        replay_transformations,
+                    }))
+                }
+                DatasetFlowType::Retention => {
+                    if let Some(config_snapshot) = maybe_config_snapshot
+                        && let FlowConfigurationSnapshot::Retention(retention_rule) =
+                            config_snapshot
+                    {
+                        return Ok(LogicalPlan::RetentionDataset(RetentionDataset {
+                            dataset_id: flow_key.dataset_id.clone(),
+                            cutoff: self.time_source.now() - retention_rule.retention_window(),
+                            dry_run: retention_rule.dry_run(),
+                        }));
+                    }
+                    InternalError::bail("Retention flow cannot be called without configuration")
+                }
             },
             FlowKey::System(flow_key) => {
                 match flow_key.flow_type {
@@ -80,6 +80,22 @@ impl FlowSchedulingHelper {
                         )
                         .await?;
                     }
+                    FlowConfigurationRule::VerifyRule(verify_rule) => {
+                        self.schedule_auto_polling_flow(
+                            start_time,
+                            &flow_key,
+                            &verify_rule.schedule_condition,
+                        )
+                        .await?;
+                    }
+                    FlowConfigurationRule::RetentionRule(retention_rule) => {
+                        self.schedule_auto_polling_flow(
+                            start_time,
+                            &flow_key,
+                            retention_rule.schedule_condition(),
+                        )
+                        .await?;
+                    }
                     // Such as compaction and reset is very dangerous operation we
                     // skip running it during activation flow configurations.
                     // And schedule will be used only for system flows
@@ -278,6 +294,14 @@ impl FlowSchedulingHelper {
                     DownstreamDependencyTriggerType::Empty
                 }
             }
+            // Dependent transformations have to notice the rewritten history of their input
+            DatasetFlowType::Retention => {
+                DownstreamDependencyTriggerType::TriggerAllEnabledExecuteTransform
+            }
+            // Neither soft compaction nor verification modify the metadata chain
+            DatasetFlowType::SoftCompaction | DatasetFlowType::Verify => {
+                DownstreamDependencyTriggerType::Empty
+            }
         }
     }
 
@@ -511,8 +535,10 @@ impl FlowSchedulingHelper {
         for trigger in &flow.triggers {
             if let FlowTrigger::InputDatasetFlow(trigger) = trigger {
                 match &trigger.flow_result {
-                    FlowResult::Empty | FlowResult::DatasetReset(_) => {}
-                    FlowResult::DatasetCompact(_) => {
+                    FlowResult::Empty
+                    | FlowResult::DatasetReset(_)
+                    | FlowResult::DatasetSoftCompact(_) => {}
+                    FlowResult::DatasetCompact(_) | FlowResult::DatasetRetention(_) => {
                         is_compacted = true;
                     }
                     FlowResult::DatasetUpdate(update) => {
@@ -18,6 +18,7 @@ use futures::TryStreamExt;
 use kamu::testing::MetadataFactory;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
+use kamu_audit_log::testing::DummyAuditLogService;
 use kamu_core::*;
 use kamu_flow_system::*;
 use kamu_flow_system_inmem::*;
@@ -461,7 +462,8 @@ impl FlowConfigurationHarness {
             .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
             .add::<DependencyGraphServiceInMemory>()
             .add::<CreateDatasetFromSnapshotUseCaseImpl>()
-            .add::<DeleteDatasetUseCaseImpl>();
+            .add::<DeleteDatasetUseCaseImpl>()
+            .add::<DummyAuditLogService>();
 
             database_common::NoOpDatabasePlugin::init_database_components(&mut b);
 
@@ -1422,6 +1422,104 @@ async fn test_manual_trigger_compaction_with_config() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_manual_trigger_soft_compaction_with_config() {
+    let max_slice_size = 1_000_000u64;
+    let max_slice_records = 1000u64;
+    let harness = FlowHarness::new().await;
+
+    let foo_create_result = harness
+        .create_root_dataset(DatasetAlias {
+            dataset_name: DatasetName::new_unchecked("foo"),
+            account_name: None,
+        })
+        .await;
+    let foo_id = foo_create_result.dataset_handle.id;
+
+    harness.eager_initialization().await;
+    harness
+        .set_dataset_flow_compaction_rule(
+            harness.now_datetime(),
+            foo_id.clone(),
+            DatasetFlowType::SoftCompaction,
+            CompactionRule::Full(
+                CompactionRuleFull::new_checked(max_slice_size, max_slice_records, false).unwrap(),
+            ),
+        )
+        .await;
+
+    let foo_flow_key: FlowKey =
+        FlowKeyDataset::new(foo_id.clone(), DatasetFlowType::SoftCompaction).into();
+
+    let test_flow_listener = harness.catalog.get_one::<FlowSystemTestListener>().unwrap();
+    test_flow_listener.define_dataset_display_name(foo_id.clone(), "foo".to_string());
+
+    // Run scheduler concurrently with manual triggers script
+    tokio::select! {
+        // Run API service
+        res = harness.flow_executor.run() => res.int_err(),
+
+        // Run simulation script and task drivers
+        _ = async {
+                // Task 0: "foo" start running at 30ms, finish at 40ms
+                let task0_driver = harness.task_driver(TaskDriverArgs {
+                    task_id: TaskID::new(0),
+                    task_metadata: TaskMetadata::from(vec![(METADATA_TASK_FLOW_ID, "0")]),
+                    dataset_id: Some(foo_id.clone()),
+                    run_since_start: Duration::milliseconds(30),
+                    finish_in_with: Some((Duration::milliseconds(10), TaskOutcome::Success(TaskResult::Empty))),
+                    expected_logical_plan: LogicalPlan::SoftCompactionDataset(SoftCompactionDataset {
+                      dataset_id: foo_id.clone(),
+                      max_slice_size: Some(max_slice_size),
+                      max_slice_records: Some(max_slice_records),
+                    }),
+                });
+                let task0_handle = task0_driver.run();
+
+                let trigger0_driver = harness.manual_flow_trigger_driver(ManualFlowTriggerArgs {
+                    flow_key: foo_flow_key,
+                    run_since_start: Duration::milliseconds(20),
+                    initiator_id: None,
+                });
+                let trigger0_handle = trigger0_driver.run();
+
+                // Main simulation script
+                let main_handle = async {
+                    // Moment 30ms - manual foo trigger happens here:
+                    //  - flow 0 trigger and finishes at 40ms
+                    harness.advance_time(Duration::milliseconds(80)).await;
+                };
+
+                tokio::join!(task0_handle, trigger0_handle, main_handle)
+            } => Ok(())
+    }
+    .unwrap();
+
+    pretty_assertions::assert_eq!(
+        format!("{}", test_flow_listener.as_ref()),
+        indoc::indoc!(
+            r#"
+            #0: +0ms:
+
+            #1: +20ms:
+              "foo" SoftCompaction:
+                Flow ID = 0 Waiting Manual Executor(task=0, since=20ms)
+
+            #2: +30ms:
+              "foo" SoftCompaction:
+                Flow ID = 0 Running(task=0)
+
+            #3: +40ms:
+              "foo" SoftCompaction:
+                Flow ID = 0 Finished Success
+
+            "#
+        )
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_full_hard_compaction_trigger_keep_metadata_compaction_for_derivatives() {
     let max_slice_size = 1_000_000u64;
@@ -3044,6 +3142,299 @@ async fn test_task_completions_trigger_next_loop_on_success() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_log::test(tokio::test)]
+async fn test_verify_trigger_with_verify_config() {
+    let harness = FlowHarness::new().await;
+
+    let foo_create_result = harness
+        .create_root_dataset(DatasetAlias {
+            dataset_name: DatasetName::new_unchecked("foo"),
+            account_name: None,
+        })
+        .await;
+    let foo_id = foo_create_result.dataset_handle.id;
+
+    let bar_create_result = harness
+        .create_root_dataset(DatasetAlias {
+            dataset_name: DatasetName::new_unchecked("bar"),
+            account_name: None,
+        })
+        .await;
+    let bar_id = bar_create_result.dataset_handle.id;
+
+    for dataset_id in [&foo_id, &bar_id] {
+        harness
+            .set_dataset_flow_verify_rule(
+                harness.now_datetime(),
+                dataset_id.clone(),
+                DatasetFlowType::Verify,
+                VerifyRule {
+                    replay_transformations: false,
+                    schedule_condition: Duration::milliseconds(40).into(),
+                },
+            )
+            .await;
+    }
+
+    // Enforce dependency graph initialization
+    harness.eager_initialization().await;
+
+    // Flow listener will collect snapshots at important moments of time
+    let test_flow_listener = harness.catalog.get_one::<FlowSystemTestListener>().unwrap();
+    test_flow_listener.define_dataset_display_name(foo_id.clone(), "foo".to_string());
+    test_flow_listener.define_dataset_display_name(bar_id.clone(), "bar".to_string());
+
+    let bar_verification_error = TaskError::VerifyDatasetError(
+        VerifyDatasetTaskError::IntegrityCheckFailed(VerificationFailedError {
+            block_hash: Multihash::from_digest_sha3_256(b"bar-block"),
+            message: "Data doesn't match metadata".to_string(),
+        }),
+    );
+
+    // Run scheduler concurrently with manual triggers script
+    tokio::select! {
+        // Run API service
+        res = harness.flow_executor.run() => res.int_err(),
+
+        // Run simulation script and task drivers
+        _ = async {
+            // Task 0: "foo" start running at 10ms, finish at 20ms
+            let task0_driver = harness.task_driver(TaskDriverArgs {
+                task_id: TaskID::new(0),
+                task_metadata: TaskMetadata::from(vec![(METADATA_TASK_FLOW_ID, "0")]),
+                dataset_id: Some(foo_id.clone()),
+                run_since_start: Duration::milliseconds(10),
+                finish_in_with: Some((Duration::milliseconds(10), TaskOutcome::Success(TaskResult::Empty))),
+                expected_logical_plan: LogicalPlan::VerifyDataset(VerifyDataset {
+                  dataset_id: foo_id.clone(),
+                  replay_transformations: false,
+                }),
+            });
+            let task0_handle = task0_driver.run();
+
+            // Task 1: "bar" start running at 20ms, finish at 30ms with verification failure
+            let task1_driver = harness.task_driver(TaskDriverArgs {
+                task_id: TaskID::new(1),
+                task_metadata: TaskMetadata::from(vec![(METADATA_TASK_FLOW_ID, "1")]),
+                dataset_id: Some(bar_id.clone()),
+                run_since_start: Duration::milliseconds(20),
+                finish_in_with: Some((Duration::milliseconds(10), TaskOutcome::Failed(bar_verification_error.clone()))),
+                expected_logical_plan: LogicalPlan::VerifyDataset(VerifyDataset {
+                  dataset_id: bar_id.clone(),
+                  replay_transformations: false,
+                }),
+            });
+            let task1_handle = task1_driver.run();
+
+            // Main simulation script
+            let main_handle = async {
+                // 0ms: both datasets are scheduled immediately without waiting:
+                //  "foo":
+                //   - flow 0 scheduled at 0ms
+                //   - task 0 starts at 10ms, finishes at 20ms
+                //   - next flow 2 scheduled for 20ms + period = 60ms
+                //  "bar":
+                //   - flow 1 scheduled at 0ms
+                //   - task 1 starts at 20ms, finishes at 30ms with failure
+                //   - next flow not scheduled
+
+                // 80ms: the succeeded dataset schedule another verification
+                harness.advance_time(Duration::milliseconds(80)).await;
+            };
+
+            tokio::join!(task0_handle, task1_handle, main_handle)
+
+         } => Ok(()),
+    }
+    .unwrap();
+
+    pretty_assertions::assert_eq!(
+        format!("{}", test_flow_listener.as_ref()),
+        indoc::indoc!(
+            r#"
+            #0: +0ms:
+              "bar" Verify:
+                Flow ID = 1 Waiting AutoPolling
+              "foo" Verify:
+                Flow ID = 0 Waiting AutoPolling
+
+            #1: +0ms:
+              "bar" Verify:
+                Flow ID = 1 Waiting AutoPolling Executor(task=1, since=0ms)
+              "foo" Verify:
+                Flow ID = 0 Waiting AutoPolling Executor(task=0, since=0ms)
+
+            #2: +10ms:
+              "bar" Verify:
+                Flow ID = 1 Waiting AutoPolling Executor(task=1, since=0ms)
+              "foo" Verify:
+                Flow ID = 0 Running(task=0)
+
+            #3: +20ms:
+              "bar" Verify:
+                Flow ID = 1 Waiting AutoPolling Executor(task=1, since=0ms)
+              "foo" Verify:
+                Flow ID = 2 Waiting AutoPolling Schedule(wakeup=60ms)
+                Flow ID = 0 Finished Success
+
+            #4: +20ms:
+              "bar" Verify:
+                Flow ID = 1 Running(task=1)
+              "foo" Verify:
+                Flow ID = 2 Waiting AutoPolling Schedule(wakeup=60ms)
+                Flow ID = 0 Finished Success
+
+            #5: +30ms:
+              "bar" Verify:
+                Flow ID = 1 Finished Failed
+              "foo" Verify:
+                Flow ID = 2 Waiting AutoPolling Schedule(wakeup=60ms)
+                Flow ID = 0 Finished Success
+
+            #6: +60ms:
+              "bar" Verify:
+                Flow ID = 1 Finished Failed
+              "foo" Verify:
+                Flow ID = 2 Waiting AutoPolling Executor(task=2, since=60ms)
+                Flow ID = 0 Finished Success
+
+            "#
+        )
+    );
+
+    // Verification outcome is recorded in the flow
+    let bar_flow = harness
+        .flow_query_service
+        .get_flow(FlowID::new(1))
+        .await
+        .unwrap();
+    assert_eq!(
+        bar_flow.outcome,
+        Some(FlowOutcome::Failed(FlowError::VerificationFailed(
+            FlowVerificationFailedError {
+                kind: FlowVerificationFailureKind::Integrity,
+                block_hash: Multihash::from_digest_sha3_256(b"bar-block"),
+                message: "Data doesn't match metadata".to_string(),
+            }
+        )))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_retention_trigger_with_retention_config() {
+    let harness = FlowHarness::new().await;
+
+    let foo_create_result = harness
+        .create_root_dataset(DatasetAlias {
+            dataset_name: DatasetName::new_unchecked("foo"),
+            account_name: None,
+        })
+        .await;
+    let foo_id = foo_create_result.dataset_handle.id;
+
+    let retention_window = Duration::days(90);
+    harness
+        .set_dataset_flow_retention_rule(
+            harness.now_datetime(),
+            foo_id.clone(),
+            DatasetFlowType::Retention,
+            RetentionRule::new_checked(retention_window, false, Duration::milliseconds(40).into())
+                .unwrap(),
+        )
+        .await;
+
+    // Enforce dependency graph initialization
+    harness.eager_initialization().await;
+
+    // Flow listener will collect snapshots at important moments of time
+    let test_flow_listener = harness.catalog.get_one::<FlowSystemTestListener>().unwrap();
+    test_flow_listener.define_dataset_display_name(foo_id.clone(), "foo".to_string());
+
+    // Cutoff is computed relative to the moment the task is scheduled
+    let start_time = harness.now_datetime();
+
+    // Run scheduler concurrently with manual triggers script
+    tokio::select! {
+        // Run API service
+        res = harness.flow_executor.run() => res.int_err(),
+
+        // Run simulation script and task drivers
+        _ = async {
+            // Task 0: "foo" start running at 10ms, finish at 20ms
+            let task0_driver = harness.task_driver(TaskDriverArgs {
+                task_id: TaskID::new(0),
+                task_metadata: TaskMetadata::from(vec![(METADATA_TASK_FLOW_ID, "0")]),
+                dataset_id: Some(foo_id.clone()),
+                run_since_start: Duration::milliseconds(10),
+                finish_in_with: Some((
+                  Duration::milliseconds(10),
+                  TaskOutcome::Success(TaskResult::RetentionDatasetResult(TaskRetentionDatasetResult {
+                    retention_result: RetentionResult::Success {
+                      old_head: Multihash::from_digest_sha3_256(b"old-slice"),
+                      new_head: Multihash::from_digest_sha3_256(b"new-slice"),
+                      num_records_removed: 10,
+                      num_slices_removed: 1,
+                    }
+                  }))
+                )),
+                expected_logical_plan: LogicalPlan::RetentionDataset(RetentionDataset {
+                  dataset_id: foo_id.clone(),
+                  cutoff: start_time - retention_window,
+                  dry_run: false,
+                }),
+            });
+            let task0_handle = task0_driver.run();
+
+            // Main simulation script
+            let main_handle = async {
+                // 0ms: "foo" is scheduled immediately without waiting:
+                //   - flow 0 scheduled at 0ms
+                //   - task 0 starts at 10ms, finishes at 20ms
+                //   - next flow 1 scheduled for 20ms + period = 60ms
+                harness.advance_time(Duration::milliseconds(80)).await;
+            };
+
+            tokio::join!(task0_handle, main_handle)
+
+         } => Ok(()),
+    }
+    .unwrap();
+
+    pretty_assertions::assert_eq!(
+        format!("{}", test_flow_listener.as_ref()),
+        indoc::indoc!(
+            r#"
+            #0: +0ms:
+              "foo" Retention:
+                Flow ID = 0 Waiting AutoPolling
+
+            #1: +0ms:
+              "foo" Retention:
+                Flow ID = 0 Waiting AutoPolling Executor(task=0, since=0ms)
+
+            #2: +10ms:
+              "foo" Retention:
+                Flow ID = 0 Running(task=0)
+
+            #3: +20ms:
+              "foo" Retention:
+                Flow ID = 1 Waiting AutoPolling Schedule(wakeup=60ms)
+                Flow ID = 0 Finished Success
+
+            #4: +60ms:
+              "foo" Retention:
+                Flow ID = 1 Waiting AutoPolling Executor(task=1, since=60ms)
+                Flow ID = 0 Finished Success
+
+            "#
+        )
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_log::test(tokio::test)]
 async fn test_derived_dataset_triggered_initially_and_after_input_change() {
     let harness = FlowHarness::with_overrides(FlowHarnessOverrides {
@@ -28,6 +28,7 @@ use kamu_accounts_services::{
     LoginPasswordAuthProvider,
     PredefinedAccountsRegistrator,
 };
+use kamu_audit_log::testing::DummyAuditLogService;
 use kamu_core::*;
 use kamu_flow_system::*;
 use kamu_flow_system_inmem::*;
@@ -163,6 +164,7 @@ impl FlowHarness {
             .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
             .add::<CreateDatasetFromSnapshotUseCaseImpl>()
             .add::<DeleteDatasetUseCaseImpl>()
+            .add::<DummyAuditLogService>()
             .add::<AuthenticationServiceImpl>()
             .add_value(JwtAuthenticationConfig::default())
             .add::<AccessTokenServiceImpl>()
@@ -387,6 +389,42 @@ impl FlowHarness {
             .unwrap();
     }
 
+    pub async fn set_dataset_flow_verify_rule(
+        &self,
+        request_time: DateTime<Utc>,
+        dataset_id: DatasetID,
+        dataset_flow_type: DatasetFlowType,
+        verify_rule: VerifyRule,
+    ) {
+        self.flow_configuration_service
+            .set_configuration(
+                request_time,
+                FlowKeyDataset::new(dataset_id, dataset_flow_type).into(),
+                false,
+                FlowConfigurationRule::VerifyRule(verify_rule),
+            )
+            .await
+            .unwrap();
+    }
+
+    pub async fn set_dataset_flow_retention_rule(
+        &self,
+        request_time: DateTime<Utc>,
+        dataset_id: DatasetID,
+        dataset_flow_type: DatasetFlowType,
+        retention_rule: RetentionRule,
+    ) {
+        self.flow_configuration_service
+            .set_configuration(
+                request_time,
+                FlowKeyDataset::new(dataset_id, dataset_flow_type).into(),
+                false,
+                FlowConfigurationRule::RetentionRule(retention_rule),
+            )
+            .await
+            .unwrap();
+    }
+
     pub async fn pause_dataset_flow(
         &self,
         request_time: DateTime<Utc>,
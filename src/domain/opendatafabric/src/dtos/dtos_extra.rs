@@ -161,6 +161,8 @@ impl ReadStep {
             ReadStep::NdGeoJson(v) => v.schema.as_ref(),
             ReadStep::EsriShapefile(v) => v.schema.as_ref(),
             ReadStep::Parquet(v) => v.schema.as_ref(),
+            ReadStep::Avro(v) => v.schema.as_ref(),
+            ReadStep::Orc(v) => v.schema.as_ref(),
         }
     }
 }
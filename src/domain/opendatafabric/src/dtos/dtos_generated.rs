@@ -341,6 +341,8 @@ pub enum FetchStep {
     Container(FetchStepContainer),
     Mqtt(FetchStepMqtt),
     EthereumLogs(FetchStepEthereumLogs),
+    Kafka(FetchStepKafka),
+    Sql(FetchStepSql),
 }
 
 impl_enum_with_variants!(FetchStep);
@@ -430,6 +432,38 @@ pub struct FetchStepEthereumLogs {
 
 impl_enum_variant!(FetchStep::EthereumLogs(FetchStepEthereumLogs));
 
+/// Consumes events from the specified topic of a Kafka-compatible broker.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FetchStepKafka {
+    /// List of bootstrap broker addresses in `host:port` format.
+    pub brokers: Vec<String>,
+    /// Name of the topic to consume.
+    pub topic: String,
+    /// Partitions to consume from. All partitions of the topic are consumed if
+    /// not specified.
+    pub partitions: Option<Vec<i32>>,
+}
+
+impl_enum_variant!(FetchStep::Kafka(FetchStepKafka));
+
+/// Runs the specified query against a relational database.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FetchStepSql {
+    /// Connection URL of the database (can be templated). Supported schemes are
+    /// `postgres`, `mysql`, and `sqlite`.
+    pub connection_url: String,
+    /// SQL query that selects the data to ingest.
+    pub query: String,
+    /// Name of the column that monotonically increases with new rows (e.g. an
+    /// auto-increment key or a modification time). When specified, only rows
+    /// with values greater than the last seen one are fetched on every
+    /// iteration. Values don't need to be unique - rows sharing the same value
+    /// are always fetched together. Rows with a null value are skipped.
+    pub cursor_column: Option<String>,
+}
+
+impl_enum_variant!(FetchStep::Sql(FetchStepSql));
+
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum SourceOrdering {
     ByEventTime,
@@ -742,6 +776,8 @@ pub enum ReadStep {
     Json(ReadStepJson),
     NdJson(ReadStepNdJson),
     NdGeoJson(ReadStepNdGeoJson),
+    Avro(ReadStepAvro),
+    Orc(ReadStepOrc),
 }
 
 impl_enum_with_variants!(ReadStep);
@@ -873,6 +909,28 @@ pub struct ReadStepNdGeoJson {
 
 impl_enum_variant!(ReadStep::NdGeoJson(ReadStepNdGeoJson));
 
+/// Reader for Apache Avro object container files. Schema is read from the file
+/// header if not specified explicitly.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ReadStepAvro {
+    /// A DDL-formatted schema. Schema can be used to coerce values into more
+    /// appropriate data types.
+    pub schema: Option<Vec<String>>,
+}
+
+impl_enum_variant!(ReadStep::Avro(ReadStepAvro));
+
+/// Reader for Apache ORC files. Schema is read from the file footer if not
+/// specified explicitly.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ReadStepOrc {
+    /// A DDL-formatted schema. Schema can be used to coerce values into more
+    /// appropriate data types.
+    pub schema: Option<Vec<String>>,
+}
+
+impl_enum_variant!(ReadStep::Orc(ReadStepOrc));
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // RequestHeader
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#requestheader-schema
@@ -670,6 +670,14 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::FetchStep> for odf::FetchStep {
                 fb::FetchStep::FetchStepEthereumLogs,
                 v.serialize(fb).as_union_value(),
             ),
+            odf::FetchStep::Kafka(v) => (
+                fb::FetchStep::FetchStepKafka,
+                v.serialize(fb).as_union_value(),
+            ),
+            odf::FetchStep::Sql(v) => (
+                fb::FetchStep::FetchStepSql,
+                v.serialize(fb).as_union_value(),
+            ),
         }
     }
 }
@@ -702,6 +710,16 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::FetchStep> for odf::FetchStep {
                     fb::FetchStepEthereumLogs::init_from_table(table)
                 }))
             }
+            fb::FetchStep::FetchStepKafka => {
+                odf::FetchStep::Kafka(odf::FetchStepKafka::deserialize(unsafe {
+                    fb::FetchStepKafka::init_from_table(table)
+                }))
+            }
+            fb::FetchStep::FetchStepSql => {
+                odf::FetchStep::Sql(odf::FetchStepSql::deserialize(unsafe {
+                    fb::FetchStepSql::init_from_table(table)
+                }))
+            }
             _ => panic!("Invalid enum value: {}", t.0),
         }
     }
@@ -899,6 +917,62 @@ impl<'fb> FlatbuffersDeserializable<fb::FetchStepEthereumLogs<'fb>> for odf::Fet
     }
 }
 
+impl<'fb> FlatbuffersSerializable<'fb> for odf::FetchStepKafka {
+    type OffsetT = WIPOffset<fb::FetchStepKafka<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let brokers_offset = {
+            let offsets: Vec<_> = self.brokers.iter().map(|i| fb.create_string(&i)).collect();
+            fb.create_vector(&offsets)
+        };
+        let topic_offset = { fb.create_string(&self.topic) };
+        let partitions_offset = self.partitions.as_ref().map(|v| fb.create_vector(&v[..]));
+        let mut builder = fb::FetchStepKafkaBuilder::new(fb);
+        builder.add_brokers(brokers_offset);
+        builder.add_topic(topic_offset);
+        partitions_offset.map(|off| builder.add_partitions(off));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::FetchStepKafka<'fb>> for odf::FetchStepKafka {
+    fn deserialize(proxy: fb::FetchStepKafka<'fb>) -> Self {
+        odf::FetchStepKafka {
+            brokers: proxy
+                .brokers()
+                .map(|v| v.iter().map(|i| i.to_owned()).collect())
+                .unwrap(),
+            topic: proxy.topic().map(|v| v.to_owned()).unwrap(),
+            partitions: proxy.partitions().map(|v| v.iter().collect()),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::FetchStepSql {
+    type OffsetT = WIPOffset<fb::FetchStepSql<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let connection_url_offset = { fb.create_string(&self.connection_url) };
+        let query_offset = { fb.create_string(&self.query) };
+        let cursor_column_offset = self.cursor_column.as_ref().map(|v| fb.create_string(&v));
+        let mut builder = fb::FetchStepSqlBuilder::new(fb);
+        builder.add_connection_url(connection_url_offset);
+        builder.add_query(query_offset);
+        cursor_column_offset.map(|off| builder.add_cursor_column(off));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::FetchStepSql<'fb>> for odf::FetchStepSql {
+    fn deserialize(proxy: fb::FetchStepSql<'fb>) -> Self {
+        odf::FetchStepSql {
+            connection_url: proxy.connection_url().map(|v| v.to_owned()).unwrap(),
+            query: proxy.query().map(|v| v.to_owned()).unwrap(),
+            cursor_column: proxy.cursor_column().map(|v| v.to_owned()),
+        }
+    }
+}
+
 impl From<odf::SourceOrdering> for fb::SourceOrdering {
     fn from(v: odf::SourceOrdering) -> Self {
         match v {
@@ -1628,6 +1702,10 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::ReadStep> for odf::ReadStep {
                 fb::ReadStep::ReadStepNdGeoJson,
                 v.serialize(fb).as_union_value(),
             ),
+            odf::ReadStep::Avro(v) => {
+                (fb::ReadStep::ReadStepAvro, v.serialize(fb).as_union_value())
+            }
+            odf::ReadStep::Orc(v) => (fb::ReadStep::ReadStepOrc, v.serialize(fb).as_union_value()),
         }
     }
 }
@@ -1670,6 +1748,16 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::ReadStep> for odf::ReadStep {
                     fb::ReadStepNdGeoJson::init_from_table(table)
                 }))
             }
+            fb::ReadStep::ReadStepAvro => {
+                odf::ReadStep::Avro(odf::ReadStepAvro::deserialize(unsafe {
+                    fb::ReadStepAvro::init_from_table(table)
+                }))
+            }
+            fb::ReadStep::ReadStepOrc => {
+                odf::ReadStep::Orc(odf::ReadStepOrc::deserialize(unsafe {
+                    fb::ReadStepOrc::init_from_table(table)
+                }))
+            }
             _ => panic!("Invalid enum value: {}", t.0),
         }
     }
@@ -1892,6 +1980,54 @@ impl<'fb> FlatbuffersDeserializable<fb::ReadStepNdGeoJson<'fb>> for odf::ReadSte
     }
 }
 
+impl<'fb> FlatbuffersSerializable<'fb> for odf::ReadStepAvro {
+    type OffsetT = WIPOffset<fb::ReadStepAvro<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let schema_offset = self.schema.as_ref().map(|v| {
+            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
+            fb.create_vector(&offsets)
+        });
+        let mut builder = fb::ReadStepAvroBuilder::new(fb);
+        schema_offset.map(|off| builder.add_schema(off));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::ReadStepAvro<'fb>> for odf::ReadStepAvro {
+    fn deserialize(proxy: fb::ReadStepAvro<'fb>) -> Self {
+        odf::ReadStepAvro {
+            schema: proxy
+                .schema()
+                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::ReadStepOrc {
+    type OffsetT = WIPOffset<fb::ReadStepOrc<'fb>>;
+
+    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+        let schema_offset = self.schema.as_ref().map(|v| {
+            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
+            fb.create_vector(&offsets)
+        });
+        let mut builder = fb::ReadStepOrcBuilder::new(fb);
+        schema_offset.map(|off| builder.add_schema(off));
+        builder.finish()
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::ReadStepOrc<'fb>> for odf::ReadStepOrc {
+    fn deserialize(proxy: fb::ReadStepOrc<'fb>) -> Self {
+        odf::ReadStepOrc {
+            schema: proxy
+                .schema()
+                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // RequestHeader
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#requestheader-schema
@@ -28,13 +28,13 @@ pub const ENUM_MIN_READ_STEP: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_READ_STEP: u8 = 7;
+pub const ENUM_MAX_READ_STEP: u8 = 9;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_READ_STEP: [ReadStep; 8] = [
+pub const ENUM_VALUES_READ_STEP: [ReadStep; 10] = [
     ReadStep::NONE,
     ReadStep::ReadStepCsv,
     ReadStep::ReadStepGeoJson,
@@ -43,6 +43,8 @@ pub const ENUM_VALUES_READ_STEP: [ReadStep; 8] = [
     ReadStep::ReadStepJson,
     ReadStep::ReadStepNdJson,
     ReadStep::ReadStepNdGeoJson,
+    ReadStep::ReadStepAvro,
+    ReadStep::ReadStepOrc,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -58,9 +60,11 @@ impl ReadStep {
     pub const ReadStepJson: Self = Self(5);
     pub const ReadStepNdJson: Self = Self(6);
     pub const ReadStepNdGeoJson: Self = Self(7);
+    pub const ReadStepAvro: Self = Self(8);
+    pub const ReadStepOrc: Self = Self(9);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 7;
+    pub const ENUM_MAX: u8 = 9;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::NONE,
         Self::ReadStepCsv,
@@ -70,6 +74,8 @@ impl ReadStep {
         Self::ReadStepJson,
         Self::ReadStepNdJson,
         Self::ReadStepNdGeoJson,
+        Self::ReadStepAvro,
+        Self::ReadStepOrc,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -82,6 +88,8 @@ impl ReadStep {
             Self::ReadStepJson => Some("ReadStepJson"),
             Self::ReadStepNdJson => Some("ReadStepNdJson"),
             Self::ReadStepNdGeoJson => Some("ReadStepNdGeoJson"),
+            Self::ReadStepAvro => Some("ReadStepAvro"),
+            Self::ReadStepOrc => Some("ReadStepOrc"),
             _ => None,
         }
     }
@@ -911,19 +919,21 @@ pub const ENUM_MIN_FETCH_STEP: u8 = 0;
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
-pub const ENUM_MAX_FETCH_STEP: u8 = 5;
+pub const ENUM_MAX_FETCH_STEP: u8 = 7;
 #[deprecated(
     since = "2.0.0",
     note = "Use associated constants instead. This will no longer be generated in 2021."
 )]
 #[allow(non_camel_case_types)]
-pub const ENUM_VALUES_FETCH_STEP: [FetchStep; 6] = [
+pub const ENUM_VALUES_FETCH_STEP: [FetchStep; 8] = [
     FetchStep::NONE,
     FetchStep::FetchStepUrl,
     FetchStep::FetchStepFilesGlob,
     FetchStep::FetchStepContainer,
     FetchStep::FetchStepMqtt,
     FetchStep::FetchStepEthereumLogs,
+    FetchStep::FetchStepKafka,
+    FetchStep::FetchStepSql,
 ];
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -937,9 +947,11 @@ impl FetchStep {
     pub const FetchStepContainer: Self = Self(3);
     pub const FetchStepMqtt: Self = Self(4);
     pub const FetchStepEthereumLogs: Self = Self(5);
+    pub const FetchStepKafka: Self = Self(6);
+    pub const FetchStepSql: Self = Self(7);
 
     pub const ENUM_MIN: u8 = 0;
-    pub const ENUM_MAX: u8 = 5;
+    pub const ENUM_MAX: u8 = 7;
     pub const ENUM_VALUES: &'static [Self] = &[
         Self::NONE,
         Self::FetchStepUrl,
@@ -947,6 +959,8 @@ impl FetchStep {
         Self::FetchStepContainer,
         Self::FetchStepMqtt,
         Self::FetchStepEthereumLogs,
+        Self::FetchStepKafka,
+        Self::FetchStepSql,
     ];
     /// Returns the variant's name or "" if unknown.
     pub fn variant_name(self) -> Option<&'static str> {
@@ -957,6 +971,8 @@ impl FetchStep {
             Self::FetchStepContainer => Some("FetchStepContainer"),
             Self::FetchStepMqtt => Some("FetchStepMqtt"),
             Self::FetchStepEthereumLogs => Some("FetchStepEthereumLogs"),
+            Self::FetchStepKafka => Some("FetchStepKafka"),
+            Self::FetchStepSql => Some("FetchStepSql"),
             _ => None,
         }
     }
@@ -3884,6 +3900,232 @@ impl core::fmt::Debug for ReadStepNdGeoJson<'_> {
         ds.finish()
     }
 }
+pub enum ReadStepAvroOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReadStepAvro<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReadStepAvro<'a> {
+    type Inner = ReadStepAvro<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ReadStepAvro<'a> {
+    pub const VT_SCHEMA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReadStepAvro { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ReadStepAvroArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReadStepAvro<'bldr>> {
+        let mut builder = ReadStepAvroBuilder::new(_fbb);
+        if let Some(x) = args.schema {
+            builder.add_schema(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn schema(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(ReadStepAvro::VT_SCHEMA, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReadStepAvro<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("schema", Self::VT_SCHEMA, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReadStepAvroArgs<'a> {
+    pub schema: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+}
+impl<'a> Default for ReadStepAvroArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ReadStepAvroArgs { schema: None }
+    }
+}
+
+pub struct ReadStepAvroBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ReadStepAvroBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_schema(
+        &mut self,
+        schema: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReadStepAvro::VT_SCHEMA, schema);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReadStepAvroBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ReadStepAvroBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReadStepAvro<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReadStepAvro<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReadStepAvro");
+        ds.field("schema", &self.schema());
+        ds.finish()
+    }
+}
+pub enum ReadStepOrcOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct ReadStepOrc<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for ReadStepOrc<'a> {
+    type Inner = ReadStepOrc<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> ReadStepOrc<'a> {
+    pub const VT_SCHEMA: flatbuffers::VOffsetT = 4;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        ReadStepOrc { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args ReadStepOrcArgs<'args>,
+    ) -> flatbuffers::WIPOffset<ReadStepOrc<'bldr>> {
+        let mut builder = ReadStepOrcBuilder::new(_fbb);
+        if let Some(x) = args.schema {
+            builder.add_schema(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn schema(&self) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(ReadStepOrc::VT_SCHEMA, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for ReadStepOrc<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("schema", Self::VT_SCHEMA, false)?
+            .finish();
+        Ok(())
+    }
+}
+pub struct ReadStepOrcArgs<'a> {
+    pub schema: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+}
+impl<'a> Default for ReadStepOrcArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        ReadStepOrcArgs { schema: None }
+    }
+}
+
+pub struct ReadStepOrcBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> ReadStepOrcBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_schema(
+        &mut self,
+        schema: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(ReadStepOrc::VT_SCHEMA, schema);
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> ReadStepOrcBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        ReadStepOrcBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<ReadStepOrc<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for ReadStepOrc<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("ReadStepOrc");
+        ds.field("schema", &self.schema());
+        ds.finish()
+    }
+}
 pub enum SqlQueryStepOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
@@ -4983,6 +5225,36 @@ impl<'a> AddPushSource<'a> {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_avro(&self) -> Option<ReadStepAvro<'a>> {
+        if self.read_type() == ReadStep::ReadStepAvro {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepAvro::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_orc(&self) -> Option<ReadStepOrc<'a>> {
+        if self.read_type() == ReadStep::ReadStepOrc {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepOrc::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     pub fn preprocess_as_transform_sql(&self) -> Option<TransformSql<'a>> {
@@ -5062,6 +5334,8 @@ impl flatbuffers::Verifiable for AddPushSource<'_> {
           ReadStep::ReadStepJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepJson>>("ReadStep::ReadStepJson", pos),
           ReadStep::ReadStepNdJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepNdJson>>("ReadStep::ReadStepNdJson", pos),
           ReadStep::ReadStepNdGeoJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepNdGeoJson>>("ReadStep::ReadStepNdGeoJson", pos),
+          ReadStep::ReadStepAvro => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepAvro>>("ReadStep::ReadStepAvro", pos),
+          ReadStep::ReadStepOrc => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepOrc>>("ReadStep::ReadStepOrc", pos),
           _ => Ok(()),
         }
      })?
@@ -5251,6 +5525,26 @@ impl core::fmt::Debug for AddPushSource<'_> {
                     )
                 }
             }
+            ReadStep::ReadStepAvro => {
+                if let Some(x) = self.read_as_read_step_avro() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ReadStep::ReadStepOrc => {
+                if let Some(x) = self.read_as_read_step_orc() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
             _ => {
                 let x: Option<()> = None;
                 ds.field("read", &x)
@@ -8326,15 +8620,15 @@ impl core::fmt::Debug for FetchStepEthereumLogs<'_> {
         ds.finish()
     }
 }
-pub enum PrepStepDecompressOffset {}
+pub enum FetchStepKafkaOffset {}
 #[derive(Copy, Clone, PartialEq)]
 
-pub struct PrepStepDecompress<'a> {
+pub struct FetchStepKafka<'a> {
     pub _tab: flatbuffers::Table<'a>,
 }
 
-impl<'a> flatbuffers::Follow<'a> for PrepStepDecompress<'a> {
-    type Inner = PrepStepDecompress<'a>;
+impl<'a> flatbuffers::Follow<'a> for FetchStepKafka<'a> {
+    type Inner = FetchStepKafka<'a>;
     #[inline]
     unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
         Self {
@@ -8343,54 +8637,392 @@ impl<'a> flatbuffers::Follow<'a> for PrepStepDecompress<'a> {
     }
 }
 
-impl<'a> PrepStepDecompress<'a> {
-    pub const VT_FORMAT: flatbuffers::VOffsetT = 4;
-    pub const VT_SUB_PATH: flatbuffers::VOffsetT = 6;
+impl<'a> FetchStepKafka<'a> {
+    pub const VT_BROKERS: flatbuffers::VOffsetT = 4;
+    pub const VT_TOPIC: flatbuffers::VOffsetT = 6;
+    pub const VT_PARTITIONS: flatbuffers::VOffsetT = 8;
 
     #[inline]
     pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
-        PrepStepDecompress { _tab: table }
+        FetchStepKafka { _tab: table }
     }
     #[allow(unused_mut)]
     pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
         _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
-        args: &'args PrepStepDecompressArgs<'args>,
-    ) -> flatbuffers::WIPOffset<PrepStepDecompress<'bldr>> {
-        let mut builder = PrepStepDecompressBuilder::new(_fbb);
-        if let Some(x) = args.sub_path {
-            builder.add_sub_path(x);
+        args: &'args FetchStepKafkaArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepKafka<'bldr>> {
+        let mut builder = FetchStepKafkaBuilder::new(_fbb);
+        if let Some(x) = args.partitions {
+            builder.add_partitions(x);
+        }
+        if let Some(x) = args.topic {
+            builder.add_topic(x);
+        }
+        if let Some(x) = args.brokers {
+            builder.add_brokers(x);
         }
-        builder.add_format(args.format);
         builder.finish()
     }
 
     #[inline]
-    pub fn format(&self) -> CompressionFormat {
+    pub fn brokers(
+        &self,
+    ) -> Option<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
-            self._tab
-                .get::<CompressionFormat>(
-                    PrepStepDecompress::VT_FORMAT,
-                    Some(CompressionFormat::Gzip),
-                )
-                .unwrap()
+            self._tab.get::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>,
+            >>(FetchStepKafka::VT_BROKERS, None)
         }
     }
     #[inline]
-    pub fn sub_path(&self) -> Option<&'a str> {
+    pub fn topic(&self) -> Option<&'a str> {
         // Safety:
         // Created from valid Table for this object
         // which contains a valid value in this slot
         unsafe {
             self._tab
-                .get::<flatbuffers::ForwardsUOffset<&str>>(PrepStepDecompress::VT_SUB_PATH, None)
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepKafka::VT_TOPIC, None)
         }
     }
-}
-
-impl flatbuffers::Verifiable for PrepStepDecompress<'_> {
+    #[inline]
+    pub fn partitions(&self) -> Option<flatbuffers::Vector<'a, i32>> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, i32>>>(
+                    FetchStepKafka::VT_PARTITIONS,
+                    None,
+                )
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for FetchStepKafka<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<
+                flatbuffers::Vector<'_, flatbuffers::ForwardsUOffset<&'_ str>>,
+            >>("brokers", Self::VT_BROKERS, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("topic", Self::VT_TOPIC, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, i32>>>(
+                "partitions",
+                Self::VT_PARTITIONS,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct FetchStepKafkaArgs<'a> {
+    pub brokers: Option<
+        flatbuffers::WIPOffset<flatbuffers::Vector<'a, flatbuffers::ForwardsUOffset<&'a str>>>,
+    >,
+    pub topic: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub partitions: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, i32>>>,
+}
+impl<'a> Default for FetchStepKafkaArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FetchStepKafkaArgs {
+            brokers: None,
+            topic: None,
+            partitions: None,
+        }
+    }
+}
+
+pub struct FetchStepKafkaBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FetchStepKafkaBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_brokers(
+        &mut self,
+        brokers: flatbuffers::WIPOffset<
+            flatbuffers::Vector<'b, flatbuffers::ForwardsUOffset<&'b str>>,
+        >,
+    ) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepKafka::VT_BROKERS, brokers);
+    }
+    #[inline]
+    pub fn add_topic(&mut self, topic: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepKafka::VT_TOPIC, topic);
+    }
+    #[inline]
+    pub fn add_partitions(
+        &mut self,
+        partitions: flatbuffers::WIPOffset<flatbuffers::Vector<'b, i32>>,
+    ) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepKafka::VT_PARTITIONS,
+            partitions,
+        );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FetchStepKafkaBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        FetchStepKafkaBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepKafka<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for FetchStepKafka<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("FetchStepKafka");
+        ds.field("brokers", &self.brokers());
+        ds.field("topic", &self.topic());
+        ds.field("partitions", &self.partitions());
+        ds.finish()
+    }
+}
+pub enum FetchStepSqlOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct FetchStepSql<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for FetchStepSql<'a> {
+    type Inner = FetchStepSql<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> FetchStepSql<'a> {
+    pub const VT_CONNECTION_URL: flatbuffers::VOffsetT = 4;
+    pub const VT_QUERY: flatbuffers::VOffsetT = 6;
+    pub const VT_CURSOR_COLUMN: flatbuffers::VOffsetT = 8;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        FetchStepSql { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args FetchStepSqlArgs<'args>,
+    ) -> flatbuffers::WIPOffset<FetchStepSql<'bldr>> {
+        let mut builder = FetchStepSqlBuilder::new(_fbb);
+        if let Some(x) = args.cursor_column {
+            builder.add_cursor_column(x);
+        }
+        if let Some(x) = args.query {
+            builder.add_query(x);
+        }
+        if let Some(x) = args.connection_url {
+            builder.add_connection_url(x);
+        }
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn connection_url(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepSql::VT_CONNECTION_URL, None)
+        }
+    }
+    #[inline]
+    pub fn query(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepSql::VT_QUERY, None)
+        }
+    }
+    #[inline]
+    pub fn cursor_column(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(FetchStepSql::VT_CURSOR_COLUMN, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for FetchStepSql<'_> {
+    #[inline]
+    fn run_verifier(
+        v: &mut flatbuffers::Verifier,
+        pos: usize,
+    ) -> Result<(), flatbuffers::InvalidFlatbuffer> {
+        use self::flatbuffers::Verifiable;
+        v.visit_table(pos)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "connection_url",
+                Self::VT_CONNECTION_URL,
+                false,
+            )?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>("query", Self::VT_QUERY, false)?
+            .visit_field::<flatbuffers::ForwardsUOffset<&str>>(
+                "cursor_column",
+                Self::VT_CURSOR_COLUMN,
+                false,
+            )?
+            .finish();
+        Ok(())
+    }
+}
+pub struct FetchStepSqlArgs<'a> {
+    pub connection_url: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub query: Option<flatbuffers::WIPOffset<&'a str>>,
+    pub cursor_column: Option<flatbuffers::WIPOffset<&'a str>>,
+}
+impl<'a> Default for FetchStepSqlArgs<'a> {
+    #[inline]
+    fn default() -> Self {
+        FetchStepSqlArgs {
+            connection_url: None,
+            query: None,
+            cursor_column: None,
+        }
+    }
+}
+
+pub struct FetchStepSqlBuilder<'a: 'b, 'b> {
+    fbb_: &'b mut flatbuffers::FlatBufferBuilder<'a>,
+    start_: flatbuffers::WIPOffset<flatbuffers::TableUnfinishedWIPOffset>,
+}
+impl<'a: 'b, 'b> FetchStepSqlBuilder<'a, 'b> {
+    #[inline]
+    pub fn add_connection_url(&mut self, connection_url: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepSql::VT_CONNECTION_URL,
+            connection_url,
+        );
+    }
+    #[inline]
+    pub fn add_query(&mut self, query: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_
+            .push_slot_always::<flatbuffers::WIPOffset<_>>(FetchStepSql::VT_QUERY, query);
+    }
+    #[inline]
+    pub fn add_cursor_column(&mut self, cursor_column: flatbuffers::WIPOffset<&'b str>) {
+        self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(
+            FetchStepSql::VT_CURSOR_COLUMN,
+            cursor_column,
+        );
+    }
+    #[inline]
+    pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a>) -> FetchStepSqlBuilder<'a, 'b> {
+        let start = _fbb.start_table();
+        FetchStepSqlBuilder {
+            fbb_: _fbb,
+            start_: start,
+        }
+    }
+    #[inline]
+    pub fn finish(self) -> flatbuffers::WIPOffset<FetchStepSql<'a>> {
+        let o = self.fbb_.end_table(self.start_);
+        flatbuffers::WIPOffset::new(o.value())
+    }
+}
+
+impl core::fmt::Debug for FetchStepSql<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut ds = f.debug_struct("FetchStepSql");
+        ds.field("connection_url", &self.connection_url());
+        ds.field("query", &self.query());
+        ds.field("cursor_column", &self.cursor_column());
+        ds.finish()
+    }
+}
+pub enum PrepStepDecompressOffset {}
+#[derive(Copy, Clone, PartialEq)]
+
+pub struct PrepStepDecompress<'a> {
+    pub _tab: flatbuffers::Table<'a>,
+}
+
+impl<'a> flatbuffers::Follow<'a> for PrepStepDecompress<'a> {
+    type Inner = PrepStepDecompress<'a>;
+    #[inline]
+    unsafe fn follow(buf: &'a [u8], loc: usize) -> Self::Inner {
+        Self {
+            _tab: flatbuffers::Table::new(buf, loc),
+        }
+    }
+}
+
+impl<'a> PrepStepDecompress<'a> {
+    pub const VT_FORMAT: flatbuffers::VOffsetT = 4;
+    pub const VT_SUB_PATH: flatbuffers::VOffsetT = 6;
+
+    #[inline]
+    pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
+        PrepStepDecompress { _tab: table }
+    }
+    #[allow(unused_mut)]
+    pub fn create<'bldr: 'args, 'args: 'mut_bldr, 'mut_bldr>(
+        _fbb: &'mut_bldr mut flatbuffers::FlatBufferBuilder<'bldr>,
+        args: &'args PrepStepDecompressArgs<'args>,
+    ) -> flatbuffers::WIPOffset<PrepStepDecompress<'bldr>> {
+        let mut builder = PrepStepDecompressBuilder::new(_fbb);
+        if let Some(x) = args.sub_path {
+            builder.add_sub_path(x);
+        }
+        builder.add_format(args.format);
+        builder.finish()
+    }
+
+    #[inline]
+    pub fn format(&self) -> CompressionFormat {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<CompressionFormat>(
+                    PrepStepDecompress::VT_FORMAT,
+                    Some(CompressionFormat::Gzip),
+                )
+                .unwrap()
+        }
+    }
+    #[inline]
+    pub fn sub_path(&self) -> Option<&'a str> {
+        // Safety:
+        // Created from valid Table for this object
+        // which contains a valid value in this slot
+        unsafe {
+            self._tab
+                .get::<flatbuffers::ForwardsUOffset<&str>>(PrepStepDecompress::VT_SUB_PATH, None)
+        }
+    }
+}
+
+impl flatbuffers::Verifiable for PrepStepDecompress<'_> {
     #[inline]
     fn run_verifier(
         v: &mut flatbuffers::Verifier,
@@ -9029,6 +9661,36 @@ impl<'a> SetPollingSource<'a> {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn fetch_as_fetch_step_kafka(&self) -> Option<FetchStepKafka<'a>> {
+        if self.fetch_type() == FetchStep::FetchStepKafka {
+            self.fetch().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { FetchStepKafka::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn fetch_as_fetch_step_sql(&self) -> Option<FetchStepSql<'a>> {
+        if self.fetch_type() == FetchStep::FetchStepSql {
+            self.fetch().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { FetchStepSql::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     pub fn read_as_read_step_csv(&self) -> Option<ReadStepCsv<'a>> {
@@ -9134,6 +9796,36 @@ impl<'a> SetPollingSource<'a> {
         }
     }
 
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_avro(&self) -> Option<ReadStepAvro<'a>> {
+        if self.read_type() == ReadStep::ReadStepAvro {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepAvro::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(non_snake_case)]
+    pub fn read_as_read_step_orc(&self) -> Option<ReadStepOrc<'a>> {
+        if self.read_type() == ReadStep::ReadStepOrc {
+            self.read().map(|t| {
+                // Safety:
+                // Created from a valid Table for this object
+                // Which contains a valid union in this slot
+                unsafe { ReadStepOrc::init_from_table(t) }
+            })
+        } else {
+            None
+        }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     pub fn preprocess_as_transform_sql(&self) -> Option<TransformSql<'a>> {
@@ -9210,6 +9902,8 @@ impl flatbuffers::Verifiable for SetPollingSource<'_> {
           FetchStep::FetchStepContainer => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepContainer>>("FetchStep::FetchStepContainer", pos),
           FetchStep::FetchStepMqtt => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepMqtt>>("FetchStep::FetchStepMqtt", pos),
           FetchStep::FetchStepEthereumLogs => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepEthereumLogs>>("FetchStep::FetchStepEthereumLogs", pos),
+          FetchStep::FetchStepKafka => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepKafka>>("FetchStep::FetchStepKafka", pos),
+          FetchStep::FetchStepSql => v.verify_union_variant::<flatbuffers::ForwardsUOffset<FetchStepSql>>("FetchStep::FetchStepSql", pos),
           _ => Ok(()),
         }
      })?
@@ -9223,6 +9917,8 @@ impl flatbuffers::Verifiable for SetPollingSource<'_> {
           ReadStep::ReadStepJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepJson>>("ReadStep::ReadStepJson", pos),
           ReadStep::ReadStepNdJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepNdJson>>("ReadStep::ReadStepNdJson", pos),
           ReadStep::ReadStepNdGeoJson => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepNdGeoJson>>("ReadStep::ReadStepNdGeoJson", pos),
+          ReadStep::ReadStepAvro => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepAvro>>("ReadStep::ReadStepAvro", pos),
+          ReadStep::ReadStepOrc => v.verify_union_variant::<flatbuffers::ForwardsUOffset<ReadStepOrc>>("ReadStep::ReadStepOrc", pos),
           _ => Ok(()),
         }
      })?
@@ -9417,6 +10113,26 @@ impl core::fmt::Debug for SetPollingSource<'_> {
                     )
                 }
             }
+            FetchStep::FetchStepKafka => {
+                if let Some(x) = self.fetch_as_fetch_step_kafka() {
+                    ds.field("fetch", &x)
+                } else {
+                    ds.field(
+                        "fetch",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            FetchStep::FetchStepSql => {
+                if let Some(x) = self.fetch_as_fetch_step_sql() {
+                    ds.field("fetch", &x)
+                } else {
+                    ds.field(
+                        "fetch",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
             _ => {
                 let x: Option<()> = None;
                 ds.field("fetch", &x)
@@ -9495,6 +10211,26 @@ impl core::fmt::Debug for SetPollingSource<'_> {
                     )
                 }
             }
+            ReadStep::ReadStepAvro => {
+                if let Some(x) = self.read_as_read_step_avro() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
+            ReadStep::ReadStepOrc => {
+                if let Some(x) = self.read_as_read_step_orc() {
+                    ds.field("read", &x)
+                } else {
+                    ds.field(
+                        "read",
+                        &"InvalidFlatbuffer: Union discriminant does not match value.",
+                    )
+                }
+            }
             _ => {
                 let x: Option<()> = None;
                 ds.field("read", &x)
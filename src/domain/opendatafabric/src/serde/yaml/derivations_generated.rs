@@ -435,6 +435,10 @@ pub enum FetchStepDef {
     Mqtt(#[serde_as(as = "FetchStepMqttDef")] FetchStepMqtt),
     #[serde(alias = "ethereumLogs", alias = "ethereumlogs")]
     EthereumLogs(#[serde_as(as = "FetchStepEthereumLogsDef")] FetchStepEthereumLogs),
+    #[serde(alias = "kafka")]
+    Kafka(#[serde_as(as = "FetchStepKafkaDef")] FetchStepKafka),
+    #[serde(alias = "sql")]
+    Sql(#[serde_as(as = "FetchStepSqlDef")] FetchStepSql),
 }
 
 implement_serde_as!(FetchStep, FetchStepDef, "FetchStepDef");
@@ -455,6 +459,8 @@ implement_serde_as!(
     FetchStepEthereumLogsDef,
     "FetchStepEthereumLogsDef"
 );
+implement_serde_as!(FetchStepKafka, FetchStepKafkaDef, "FetchStepKafkaDef");
+implement_serde_as!(FetchStepSql, FetchStepSqlDef, "FetchStepSqlDef");
 
 #[serde_as]
 #[skip_serializing_none]
@@ -532,6 +538,28 @@ pub struct FetchStepEthereumLogsDef {
     pub signature: Option<String>,
 }
 
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "FetchStepKafka")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FetchStepKafkaDef {
+    pub brokers: Vec<String>,
+    pub topic: String,
+    pub partitions: Option<Vec<i32>>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "FetchStepSql")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct FetchStepSqlDef {
+    pub connection_url: String,
+    pub query: String,
+    pub cursor_column: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(remote = "SourceOrdering")]
 #[serde(deny_unknown_fields)]
@@ -907,6 +935,10 @@ pub enum ReadStepDef {
     NdJson(#[serde_as(as = "ReadStepNdJsonDef")] ReadStepNdJson),
     #[serde(alias = "ndGeoJson", alias = "ndgeojson")]
     NdGeoJson(#[serde_as(as = "ReadStepNdGeoJsonDef")] ReadStepNdGeoJson),
+    #[serde(alias = "avro")]
+    Avro(#[serde_as(as = "ReadStepAvroDef")] ReadStepAvro),
+    #[serde(alias = "orc")]
+    Orc(#[serde_as(as = "ReadStepOrcDef")] ReadStepOrc),
 }
 
 implement_serde_as!(ReadStep, ReadStepDef, "ReadStepDef");
@@ -925,6 +957,8 @@ implement_serde_as!(
     "ReadStepEsriShapefileDef"
 );
 implement_serde_as!(ReadStepParquet, ReadStepParquetDef, "ReadStepParquetDef");
+implement_serde_as!(ReadStepAvro, ReadStepAvroDef, "ReadStepAvroDef");
+implement_serde_as!(ReadStepOrc, ReadStepOrcDef, "ReadStepOrcDef");
 
 #[serde_as]
 #[skip_serializing_none]
@@ -1006,6 +1040,24 @@ pub struct ReadStepNdGeoJsonDef {
     pub schema: Option<Vec<String>>,
 }
 
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "ReadStepAvro")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ReadStepAvroDef {
+    pub schema: Option<Vec<String>>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(remote = "ReadStepOrc")]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ReadStepOrcDef {
+    pub schema: Option<Vec<String>>,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // RequestHeader
 // https://github.com/kamu-data/open-data-fabric/blob/master/open-data-fabric.md#requestheader-schema
@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use chrono::{DateTime, Utc};
 use enum_variants::*;
 use opendatafabric::{DatasetID, Multihash};
 use serde::{Deserialize, Serialize};
@@ -25,8 +26,14 @@ pub enum LogicalPlan {
     Probe(Probe),
     /// Perform a dataset hard compaction
     HardCompactionDataset(HardCompactionDataset),
+    /// Perform a dataset soft compaction
+    SoftCompactionDataset(SoftCompactionDataset),
     /// Perform a dataset resetting
     Reset(ResetDataset),
+    /// Perform a dataset verification
+    VerifyDataset(VerifyDataset),
+    /// Remove records of a dataset that fall out of the retention window
+    RetentionDataset(RetentionDataset),
 }
 
 impl LogicalPlan {
@@ -38,7 +45,12 @@ impl LogicalPlan {
             LogicalPlan::HardCompactionDataset(hard_compaction) => {
                 Some(&hard_compaction.dataset_id)
             }
+            LogicalPlan::SoftCompactionDataset(soft_compaction) => {
+                Some(&soft_compaction.dataset_id)
+            }
             LogicalPlan::Reset(reset) => Some(&reset.dataset_id),
+            LogicalPlan::VerifyDataset(verify) => Some(&verify.dataset_id),
+            LogicalPlan::RetentionDataset(retention) => Some(&retention.dataset_id),
         }
     }
 }
@@ -78,6 +90,17 @@ pub struct HardCompactionDataset {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// A task to merge small data slices of a dataset without altering its
+/// metadata chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SoftCompactionDataset {
+    pub dataset_id: DatasetID,
+    pub max_slice_size: Option<u64>,
+    pub max_slice_records: Option<u64>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// A task to perform the resetting of a dataset
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ResetDataset {
@@ -89,6 +112,26 @@ pub struct ResetDataset {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// A task to verify integrity and, optionally, reproducibility of a dataset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerifyDataset {
+    pub dataset_id: DatasetID,
+    pub replay_transformations: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A task to remove records of a root dataset with event time older than the
+/// cutoff
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionDataset {
+    pub dataset_id: DatasetID,
+    pub cutoff: DateTime<Utc>,
+    pub dry_run: bool,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // TODO: Replace with derive macro
 impl_enum_with_variants!(LogicalPlan);
 impl_enum_variant!(LogicalPlan::UpdateDataset(UpdateDataset));
@@ -7,7 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use kamu_core::{CompactionResult, PullResult};
+use kamu_core::{CompactionResult, PullResult, RetentionResult};
 use opendatafabric::{DatasetID, Multihash};
 use serde::{Deserialize, Serialize};
 
@@ -52,6 +52,7 @@ pub enum TaskResult {
     UpdateDatasetResult(TaskUpdateDatasetResult),
     ResetDatasetResult(TaskResetDatasetResult),
     CompactionDatasetResult(TaskCompactionDatasetResult),
+    RetentionDatasetResult(TaskRetentionDatasetResult),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -77,6 +78,19 @@ impl From<CompactionResult> for TaskCompactionDatasetResult {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskRetentionDatasetResult {
+    pub retention_result: RetentionResult,
+}
+
+impl From<RetentionResult> for TaskRetentionDatasetResult {
+    fn from(value: RetentionResult) -> Self {
+        Self {
+            retention_result: value,
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -84,6 +98,7 @@ pub enum TaskError {
     Empty,
     UpdateDatasetError(UpdateDatasetTaskError),
     ResetDatasetError(ResetDatasetTaskError),
+    VerifyDatasetError(VerifyDatasetTaskError),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -101,4 +116,20 @@ pub enum ResetDatasetTaskError {
     ResetHeadNotFound,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerifyDatasetTaskError {
+    /// Data or checkpoint files don't match hashes and sizes recorded in
+    /// metadata
+    IntegrityCheckFailed(VerificationFailedError),
+    /// Replaying the transformation produced non-equivalent results
+    ReproducibilityCheckFailed(VerificationFailedError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VerificationFailedError {
+    /// First block that failed verification
+    pub block_hash: Multihash,
+    pub message: String,
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
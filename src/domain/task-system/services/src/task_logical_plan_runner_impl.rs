@@ -10,10 +10,11 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use database_common_macros::transactional_method1;
+use database_common_macros::{transactional_method1, transactional_method2};
 use dill::*;
 use internal_error::InternalError;
 use kamu_core::{
+    CompactionMode,
     CompactionOptions,
     CompactionService,
     DatasetRepository,
@@ -23,7 +24,12 @@ use kamu_core::{
     PullService,
     ResetError,
     ResetService,
+    RetentionOptions,
+    RetentionService,
     TransformError,
+    VerificationError,
+    VerificationOptions,
+    VerificationService,
 };
 use kamu_datasets::{DatasetEnvVar, DatasetEnvVarService};
 use kamu_task_system::*;
@@ -103,9 +109,11 @@ impl TaskLogicalPlanRunnerImpl {
             .int_err()
     }
 
+    #[transactional_method2(
+        reset_svc: Arc<dyn ResetService>,
+        dataset_repo: Arc<dyn DatasetRepository>
+    )]
     async fn run_reset(&self, args: &ResetDataset) -> Result<TaskOutcome, InternalError> {
-        let reset_svc = self.catalog.get_one::<dyn ResetService>().int_err()?;
-        let dataset_repo = self.catalog.get_one::<dyn DatasetRepository>().int_err()?;
         let dataset_handle = dataset_repo
             .resolve_dataset_ref(&args.dataset_id.as_local_ref())
             .await
@@ -149,6 +157,39 @@ impl TaskLogicalPlanRunnerImpl {
                     max_slice_size: args.max_slice_size,
                     max_slice_records: args.max_slice_records,
                     keep_metadata_only: args.keep_metadata_only,
+                    mode: CompactionMode::Hard,
+                },
+                None,
+            )
+            .await;
+
+        match compaction_result {
+            Ok(result) => Ok(TaskOutcome::Success(TaskResult::CompactionDatasetResult(
+                result.into(),
+            ))),
+            Err(_) => Ok(TaskOutcome::Failed(TaskError::Empty)),
+        }
+    }
+
+    async fn run_soft_compaction(
+        &self,
+        args: &SoftCompactionDataset,
+    ) -> Result<TaskOutcome, InternalError> {
+        let compaction_svc = self.catalog.get_one::<dyn CompactionService>().int_err()?;
+        let dataset_repo = self.catalog.get_one::<dyn DatasetRepository>().int_err()?;
+        let dataset_handle = dataset_repo
+            .resolve_dataset_ref(&args.dataset_id.as_local_ref())
+            .await
+            .int_err()?;
+
+        let compaction_result = compaction_svc
+            .compact_dataset(
+                &dataset_handle,
+                CompactionOptions {
+                    max_slice_size: args.max_slice_size,
+                    max_slice_records: args.max_slice_records,
+                    keep_metadata_only: false,
+                    mode: CompactionMode::Soft,
                 },
                 None,
             )
@@ -161,6 +202,80 @@ impl TaskLogicalPlanRunnerImpl {
             Err(_) => Ok(TaskOutcome::Failed(TaskError::Empty)),
         }
     }
+
+    async fn run_verify(&self, args: &VerifyDataset) -> Result<TaskOutcome, InternalError> {
+        let verification_svc = self
+            .catalog
+            .get_one::<dyn VerificationService>()
+            .int_err()?;
+
+        let verification_result = verification_svc
+            .verify(
+                &args.dataset_id.as_local_ref(),
+                (None, None),
+                VerificationOptions {
+                    replay_transformations: args.replay_transformations,
+                    ..VerificationOptions::default()
+                },
+                None,
+            )
+            .await;
+
+        match verification_result.outcome {
+            Ok(()) => Ok(TaskOutcome::Success(TaskResult::Empty)),
+            Err(err) => Ok(TaskOutcome::Failed(match err {
+                // Only the failed checks are reported as the task outcome, other errors
+                // are not related to the state of the dataset and are propagated as is
+                VerificationError::DataDoesNotMatchMetadata(e) => TaskError::VerifyDatasetError(
+                    VerifyDatasetTaskError::IntegrityCheckFailed(VerificationFailedError {
+                        message: e.to_string(),
+                        block_hash: e.block_hash,
+                    }),
+                ),
+                VerificationError::CheckpointDoesNotMatchMetadata(e) => {
+                    TaskError::VerifyDatasetError(VerifyDatasetTaskError::IntegrityCheckFailed(
+                        VerificationFailedError {
+                            message: e.to_string(),
+                            block_hash: e.block_hash,
+                        },
+                    ))
+                }
+                VerificationError::DataNotReproducible(e) => TaskError::VerifyDatasetError(
+                    VerifyDatasetTaskError::ReproducibilityCheckFailed(VerificationFailedError {
+                        message: e.to_string(),
+                        block_hash: e.block_hash,
+                    }),
+                ),
+                err => return Err(err.int_err()),
+            })),
+        }
+    }
+
+    async fn run_retention(&self, args: &RetentionDataset) -> Result<TaskOutcome, InternalError> {
+        let retention_svc = self.catalog.get_one::<dyn RetentionService>().int_err()?;
+        let dataset_repo = self.catalog.get_one::<dyn DatasetRepository>().int_err()?;
+        let dataset_handle = dataset_repo
+            .resolve_dataset_ref(&args.dataset_id.as_local_ref())
+            .await
+            .int_err()?;
+
+        let retention_result = retention_svc
+            .apply_retention(
+                &dataset_handle,
+                RetentionOptions {
+                    cutoff: args.cutoff,
+                    dry_run: args.dry_run,
+                },
+            )
+            .await;
+
+        match retention_result {
+            Ok(result) => Ok(TaskOutcome::Success(TaskResult::RetentionDatasetResult(
+                result.into(),
+            ))),
+            Err(_) => Ok(TaskOutcome::Failed(TaskError::Empty)),
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -178,6 +293,11 @@ impl TaskLogicalPlanRunner for TaskLogicalPlanRunnerImpl {
             LogicalPlan::HardCompactionDataset(compaction) => {
                 self.run_hard_compaction(compaction).await?
             }
+            LogicalPlan::SoftCompactionDataset(compaction) => {
+                self.run_soft_compaction(compaction).await?
+            }
+            LogicalPlan::VerifyDataset(verify) => self.run_verify(verify).await?,
+            LogicalPlan::RetentionDataset(retention) => self.run_retention(retention).await?,
         };
 
         Ok(task_outcome)
@@ -0,0 +1,54 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{WebhookEvent, WebhookEventType};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Record of a single attempt to deliver an event to a subscribed endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    /// Sequential number of the attempt, starting from 1
+    pub attempt: u32,
+    pub requested_at: DateTime<Utc>,
+    /// HTTP status returned by the endpoint, if a response was received
+    pub response_status: Option<u16>,
+    /// Reason of the failure, if the attempt was not successful
+    pub error: Option<String>,
+}
+
+impl WebhookDelivery {
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Pending attempt to deliver an event: the first one, or a retry scheduled
+/// after a failed one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDeliveryRetry {
+    pub id: Uuid,
+    pub subscription_id: Uuid,
+    pub event: WebhookEvent,
+    /// Sequential number of the scheduled attempt
+    pub attempt: u32,
+    /// Moment after which the attempt should be performed
+    pub due_at: DateTime<Utc>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,101 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone)]
+pub struct WebhookDeliveryConfig {
+    /// Maximal number of attempts to deliver a single event
+    pub max_attempts: u32,
+    /// Delay before the second attempt, doubled after every failed attempt
+    pub initial_backoff: chrono::Duration,
+    /// Time to wait for the endpoint response
+    pub request_timeout: chrono::Duration,
+    /// Whether endpoints on the local host, including plain HTTP ones, are
+    /// accepted. Intended for local development only
+    pub allow_insecure_localhost: bool,
+}
+
+impl WebhookDeliveryConfig {
+    pub fn new(
+        max_attempts: u32,
+        initial_backoff: chrono::Duration,
+        request_timeout: chrono::Duration,
+        allow_insecure_localhost: bool,
+    ) -> Self {
+        Self {
+            max_attempts,
+            initial_backoff,
+            request_timeout,
+            allow_insecure_localhost,
+        }
+    }
+
+    /// Whether an endpoint at the specified address may be contacted.
+    /// Private, link-local and unspecified addresses are never allowed, so
+    /// that subscriptions cannot reach into the internal network
+    pub fn is_allowed_target_ip(&self, ip: IpAddr) -> bool {
+        let ip = match ip {
+            IpAddr::V6(ipv6) => ipv6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+            IpAddr::V4(_) => ip,
+        };
+
+        if ip.is_loopback() {
+            return self.allow_insecure_localhost;
+        }
+
+        match ip {
+            IpAddr::V4(ipv4) => !is_internal_ipv4(ipv4),
+            IpAddr::V6(ipv6) => !is_internal_ipv6(ipv6),
+        }
+    }
+
+    /// Delay to wait before the specified attempt
+    pub fn backoff_before_attempt(&self, attempt: u32) -> chrono::Duration {
+        if attempt <= 1 {
+            return chrono::Duration::zero();
+        }
+        self.initial_backoff * 2i32.pow(attempt.min(16) - 2)
+    }
+}
+
+impl Default for WebhookDeliveryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: chrono::Duration::seconds(1),
+            request_timeout: chrono::Duration::seconds(10),
+            allow_insecure_localhost: false,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    // Shared address space (100.64.0.0/10)
+    let is_shared = a == 100 && (b & 0xc0) == 64;
+
+    ip.is_private() || ip.is_link_local() || ip.is_unspecified() || ip.is_broadcast() || is_shared
+}
+
+fn is_internal_ipv6(ip: Ipv6Addr) -> bool {
+    let first_segment = ip.segments()[0];
+    // Unique local addresses (fc00::/7)
+    let is_unique_local = (first_segment & 0xfe00) == 0xfc00;
+    // Link-local unicast addresses (fe80::/10)
+    let is_link_local = (first_segment & 0xffc0) == 0xfe80;
+
+    ip.is_unspecified() || is_unique_local || is_link_local
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
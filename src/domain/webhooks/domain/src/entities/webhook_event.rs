@@ -0,0 +1,213 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use opendatafabric::{AccountID, DatasetID, DatasetName, Multihash};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventType {
+    FlowSucceeded,
+    FlowFailed,
+    DatasetHeadUpdated,
+    DatasetCreated,
+    DatasetDeleted,
+}
+
+impl WebhookEventType {
+    pub fn all() -> &'static [WebhookEventType] {
+        &[
+            Self::FlowSucceeded,
+            Self::FlowFailed,
+            Self::DatasetHeadUpdated,
+            Self::DatasetCreated,
+            Self::DatasetDeleted,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FlowSucceeded => "flow_succeeded",
+            Self::FlowFailed => "flow_failed",
+            Self::DatasetHeadUpdated => "dataset_head_updated",
+            Self::DatasetCreated => "dataset_created",
+            Self::DatasetDeleted => "dataset_deleted",
+        }
+    }
+}
+
+impl fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WebhookEventType {
+    type Err = InvalidWebhookEventTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .iter()
+            .find(|event_type| event_type.as_str() == s)
+            .copied()
+            .ok_or_else(|| InvalidWebhookEventTypeError {
+                value: s.to_string(),
+            })
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Unknown webhook event type: '{value}'")]
+pub struct InvalidWebhookEventTypeError {
+    pub value: String,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Event delivered to subscribed endpoints. Serialized form of this structure
+/// is the body of a webhook request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEvent {
+    pub id: Uuid,
+    pub occurred_at: DateTime<Utc>,
+    pub dataset_id: DatasetID,
+    #[serde(flatten)]
+    pub payload: WebhookEventPayload,
+}
+
+impl WebhookEvent {
+    pub fn new(
+        occurred_at: DateTime<Utc>,
+        dataset_id: DatasetID,
+        payload: WebhookEventPayload,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            occurred_at,
+            dataset_id,
+            payload,
+        }
+    }
+
+    pub fn event_type(&self) -> WebhookEventType {
+        match &self.payload {
+            WebhookEventPayload::FlowSucceeded(_) => WebhookEventType::FlowSucceeded,
+            WebhookEventPayload::FlowFailed(_) => WebhookEventType::FlowFailed,
+            WebhookEventPayload::DatasetHeadUpdated(_) => WebhookEventType::DatasetHeadUpdated,
+            WebhookEventPayload::DatasetCreated(_) => WebhookEventType::DatasetCreated,
+            WebhookEventPayload::DatasetDeleted => WebhookEventType::DatasetDeleted,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "eventType", content = "data", rename_all = "snake_case")]
+pub enum WebhookEventPayload {
+    FlowSucceeded(WebhookEventFlowSucceeded),
+    FlowFailed(WebhookEventFlowFailed),
+    DatasetHeadUpdated(WebhookEventDatasetHeadUpdated),
+    DatasetCreated(WebhookEventDatasetCreated),
+    DatasetDeleted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEventFlowSucceeded {
+    pub flow_id: u64,
+    pub flow_type: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEventFlowFailed {
+    pub flow_id: u64,
+    pub flow_type: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEventDatasetHeadUpdated {
+    pub old_head: Option<Multihash>,
+    pub new_head: Multihash,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEventDatasetCreated {
+    pub dataset_name: DatasetName,
+    pub owner_account_id: AccountID,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use std::assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn test_event_type_round_trip() {
+        for event_type in WebhookEventType::all() {
+            assert_eq!(
+                event_type.as_str().parse::<WebhookEventType>().unwrap(),
+                *event_type
+            );
+        }
+
+        assert_matches!(
+            "flow_exploded".parse::<WebhookEventType>(),
+            Err(InvalidWebhookEventTypeError { value }) if value == "flow_exploded"
+        );
+    }
+
+    #[test]
+    fn test_event_serialization() {
+        let event = WebhookEvent::new(
+            DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            DatasetID::new_seeded_ed25519(b"foo"),
+            WebhookEventPayload::FlowFailed(WebhookEventFlowFailed {
+                flow_id: 7,
+                flow_type: "INGEST".to_string(),
+                reason: "Failed".to_string(),
+            }),
+        );
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["eventType"], "flow_failed");
+        assert_eq!(json["data"]["flowId"], 7);
+        assert_eq!(json["occurredAt"], "2023-11-14T22:13:20Z");
+
+        let deserialized: WebhookEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, event);
+
+        let event = WebhookEvent::new(
+            DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            DatasetID::new_seeded_ed25519(b"foo"),
+            WebhookEventPayload::DatasetDeleted,
+        );
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["eventType"], "dataset_deleted");
+
+        let deserialized: WebhookEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.event_type(), WebhookEventType::DatasetDeleted);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
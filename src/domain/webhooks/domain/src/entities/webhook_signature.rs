@@ -0,0 +1,69 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use hmac::{Hmac, Mac};
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use sha2::Sha256;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub const WEBHOOK_HEADER_EVENT_TYPE: &str = "X-Kamu-Webhook-Event";
+pub const WEBHOOK_HEADER_DELIVERY_ID: &str = "X-Kamu-Webhook-Delivery";
+pub const WEBHOOK_HEADER_TIMESTAMP: &str = "X-Kamu-Webhook-Timestamp";
+pub const WEBHOOK_HEADER_SIGNATURE: &str = "X-Kamu-Webhook-Signature";
+
+const WEBHOOK_SECRET_LENGTH: usize = 32;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn generate_webhook_secret() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(WEBHOOK_SECRET_LENGTH)
+        .map(char::from)
+        .collect()
+}
+
+/// Computes a hex-encoded HMAC-SHA256 signature of `{timestamp}.{body}`.
+///
+/// Including the timestamp lets receivers reject replayed requests.
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    hex::encode(mac.finalize().into_bytes())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_webhook_payload() {
+        assert_eq!(
+            sign_webhook_payload("test-secret", 1_700_000_000, br#"{"hello":"world"}"#),
+            "f85455a1b55ea86f7a15f5f9923d0abc4b888da84ec485f2ff358f427776beca"
+        );
+    }
+
+    #[test]
+    fn test_generate_webhook_secret() {
+        let secret = generate_webhook_secret();
+        assert_eq!(secret.len(), WEBHOOK_SECRET_LENGTH);
+        assert_ne!(secret, generate_webhook_secret());
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,64 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use opendatafabric::{AccountID, DatasetID};
+use url::Url;
+use uuid::Uuid;
+
+use crate::{generate_webhook_secret, WebhookEvent, WebhookEventType};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookSubscription {
+    pub id: Uuid,
+    pub account_id: AccountID,
+    pub target_url: Url,
+    pub event_types: Vec<WebhookEventType>,
+    /// When specified, only events of this dataset are delivered, otherwise
+    /// the subscription covers all datasets owned by the account
+    pub dataset_id: Option<DatasetID>,
+    /// Shared secret used to sign request bodies
+    pub secret: String,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    pub fn new(
+        account_id: AccountID,
+        target_url: Url,
+        event_types: Vec<WebhookEventType>,
+        dataset_id: Option<DatasetID>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            account_id,
+            target_url,
+            event_types,
+            dataset_id,
+            secret: generate_webhook_secret(),
+            enabled: true,
+            created_at,
+        }
+    }
+
+    pub fn is_interested_in(&self, event: &WebhookEvent) -> bool {
+        self.enabled
+            && self.event_types.contains(&event.event_type())
+            && self
+                .dataset_id
+                .as_ref()
+                .map_or(true, |dataset_id| *dataset_id == event.dataset_id)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,53 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::PaginationOpts;
+use internal_error::InternalError;
+use uuid::Uuid;
+
+use crate::{WebhookDelivery, WebhookDeliveryRetry};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), InternalError>;
+
+    async fn get_deliveries_count_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<usize, InternalError>;
+
+    /// Returns deliveries of the subscription, the most recent ones first
+    async fn get_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        pagination: &PaginationOpts,
+    ) -> Result<Vec<WebhookDelivery>, InternalError>;
+
+    /// Removes delivery log and pending retries of the subscription
+    async fn delete_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), InternalError>;
+
+    async fn schedule_retry(&self, retry: &WebhookDeliveryRetry) -> Result<(), InternalError>;
+
+    /// Returns retries due at the specified moment, the oldest ones first
+    async fn get_due_retries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDeliveryRetry>, InternalError>;
+
+    async fn delete_retry(&self, retry_id: &Uuid) -> Result<(), InternalError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
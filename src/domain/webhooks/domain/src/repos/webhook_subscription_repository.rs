@@ -0,0 +1,95 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::InternalError;
+use opendatafabric::{AccountID, DatasetID};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::WebhookSubscription;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+pub trait WebhookSubscriptionRepository: Send + Sync {
+    async fn save_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), InternalError>;
+
+    async fn get_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError>;
+
+    async fn get_subscriptions_by_account(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<WebhookSubscription>, InternalError>;
+
+    /// Returns enabled subscriptions scoped to the specified dataset, as well
+    /// as enabled account-wide subscriptions of its owner, if it is known
+    async fn get_enabled_subscriptions_for_dataset(
+        &self,
+        dataset_id: &DatasetID,
+        owner_account_id: Option<&AccountID>,
+    ) -> Result<Vec<WebhookSubscription>, InternalError>;
+
+    async fn set_subscription_enabled(
+        &self,
+        subscription_id: &Uuid,
+        enabled: bool,
+    ) -> Result<(), UpdateWebhookSubscriptionError>;
+
+    async fn delete_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum GetWebhookSubscriptionError {
+    #[error(transparent)]
+    NotFound(WebhookSubscriptionNotFoundError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+#[derive(Error, Debug)]
+#[error("Webhook subscription not found: '{subscription_id}'")]
+pub struct WebhookSubscriptionNotFoundError {
+    pub subscription_id: Uuid,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum UpdateWebhookSubscriptionError {
+    #[error(transparent)]
+    NotFound(WebhookSubscriptionNotFoundError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum DeleteWebhookSubscriptionError {
+    #[error(transparent)]
+    NotFound(WebhookSubscriptionNotFoundError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,105 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use database_common::PaginationOpts;
+use internal_error::InternalError;
+use opendatafabric::{AccountID, DatasetID};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::{
+    DeleteWebhookSubscriptionError,
+    GetWebhookSubscriptionError,
+    UpdateWebhookSubscriptionError,
+    WebhookDelivery,
+    WebhookEventType,
+    WebhookSubscription,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Manages webhook subscriptions on behalf of an account. Subscriptions of
+/// other accounts are reported as not found. Access to the dataset of a
+/// dataset-scoped subscription is expected to be checked by the caller.
+#[async_trait::async_trait]
+pub trait WebhookSubscriptionService: Sync + Send {
+    async fn create_subscription(
+        &self,
+        account_id: &AccountID,
+        target_url: &str,
+        event_types: Vec<WebhookEventType>,
+        dataset_id: Option<DatasetID>,
+    ) -> Result<WebhookSubscription, CreateWebhookSubscriptionError>;
+
+    async fn list_subscriptions(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<WebhookSubscription>, InternalError>;
+
+    async fn get_subscription(
+        &self,
+        account_id: &AccountID,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError>;
+
+    async fn set_subscription_enabled(
+        &self,
+        account_id: &AccountID,
+        subscription_id: &Uuid,
+        enabled: bool,
+    ) -> Result<WebhookSubscription, UpdateWebhookSubscriptionError>;
+
+    async fn remove_subscription(
+        &self,
+        account_id: &AccountID,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError>;
+
+    async fn list_deliveries(
+        &self,
+        account_id: &AccountID,
+        subscription_id: &Uuid,
+        pagination: Option<PaginationOpts>,
+    ) -> Result<WebhookDeliveryListing, GetWebhookSubscriptionError>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+pub struct WebhookDeliveryListing {
+    pub list: Vec<WebhookDelivery>,
+    pub total_count: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug)]
+pub enum CreateWebhookSubscriptionError {
+    #[error(transparent)]
+    InvalidTargetUrl(InvalidWebhookTargetUrlError),
+
+    #[error(transparent)]
+    NoEventTypes(NoWebhookEventTypesError),
+
+    #[error(transparent)]
+    Internal(#[from] InternalError),
+}
+
+#[derive(Error, Debug)]
+#[error("Invalid webhook target URL '{url}': {reason}")]
+pub struct InvalidWebhookTargetUrlError {
+    pub url: String,
+    pub reason: String,
+}
+
+#[derive(Error, Debug)]
+#[error("Webhook subscription requires at least one event type")]
+pub struct NoWebhookEventTypesError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
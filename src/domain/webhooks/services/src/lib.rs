@@ -0,0 +1,27 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+// Re-exports
+pub use kamu_webhooks as domain;
+
+mod dependencies;
+mod messages;
+mod webhook_delivery_retrier;
+mod webhook_delivery_worker;
+mod webhook_event_dispatcher;
+mod webhook_sender_impl;
+mod webhook_subscription_service_impl;
+
+pub use dependencies::*;
+pub use messages::*;
+pub use webhook_delivery_retrier::*;
+pub use webhook_delivery_worker::*;
+pub use webhook_event_dispatcher::*;
+pub use webhook_sender_impl::*;
+pub use webhook_subscription_service_impl::*;
@@ -0,0 +1,35 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use kamu_webhooks::WebhookEvent;
+use messaging_outbox::Message;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Requests delivery of an event to the endpoint of a single subscription
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookDeliveryMessage {
+    pub subscription_id: Uuid,
+    pub event: WebhookEvent,
+}
+
+impl WebhookDeliveryMessage {
+    pub fn new(subscription_id: Uuid, event: WebhookEvent) -> Self {
+        Self {
+            subscription_id,
+            event,
+        }
+    }
+}
+
+impl Message for WebhookDeliveryMessage {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,143 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use database_common::DatabaseTransactionRunner;
+use dill::{component, scope, Catalog, Singleton};
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_webhooks::*;
+use time_source::SystemTimeSource;
+use uuid::Uuid;
+
+use crate::WebhookDeliveryWorker;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const RETRY_PASS_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Periodically performs webhook delivery attempts queued by
+/// [`WebhookDeliveryWorker`], including first attempts and retries scheduled
+/// after failed ones. Requests are sent outside of database transactions.
+pub struct WebhookDeliveryRetrier {
+    catalog: Catalog,
+    webhook_sender: Arc<dyn WebhookSender>,
+    time_source: Arc<dyn SystemTimeSource>,
+}
+
+#[component(pub)]
+#[scope(Singleton)]
+impl WebhookDeliveryRetrier {
+    pub fn new(
+        catalog: Catalog,
+        webhook_sender: Arc<dyn WebhookSender>,
+        time_source: Arc<dyn SystemTimeSource>,
+    ) -> Self {
+        Self {
+            catalog,
+            webhook_sender,
+            time_source,
+        }
+    }
+
+    pub async fn run(&self) -> Result<(), InternalError> {
+        loop {
+            match self.run_retry_pass().await {
+                Ok(0) => {}
+                Ok(retries_count) => {
+                    tracing::debug!(retries_count, "Performed webhook delivery attempts");
+                }
+                // Failed pass must not stop the deliveries, unrecorded attempts
+                // will be claimed again once their claim expires
+                Err(e) => {
+                    tracing::error!(error = ?e, "Webhook delivery pass failed");
+                }
+            }
+
+            tokio::time::sleep(RETRY_PASS_INTERVAL).await;
+        }
+    }
+
+    /// Performs all attempts that are due by now and returns their number
+    pub async fn run_retry_pass(&self) -> Result<usize, InternalError> {
+        let claimed = DatabaseTransactionRunner::new(self.catalog.clone())
+            .transactional_with(|worker: Arc<WebhookDeliveryWorker>| async move {
+                worker.claim_due_deliveries().await
+            })
+            .await?;
+
+        for (subscription, retry) in &claimed {
+            let delivery = self.attempt_delivery(subscription, retry).await?;
+
+            DatabaseTransactionRunner::new(self.catalog.clone())
+                .transactional_with(|worker: Arc<WebhookDeliveryWorker>| async move {
+                    worker.record_delivery(retry, &delivery).await
+                })
+                .await?;
+        }
+
+        Ok(claimed.len())
+    }
+
+    async fn attempt_delivery(
+        &self,
+        subscription: &WebhookSubscription,
+        retry: &WebhookDeliveryRetry,
+    ) -> Result<WebhookDelivery, InternalError> {
+        let body = serde_json::to_vec(&retry.event).int_err()?;
+
+        let delivery_id = Uuid::new_v4();
+        let requested_at = self.time_source.now();
+        let timestamp = requested_at.timestamp();
+
+        let request = WebhookRequest {
+            target_url: subscription.target_url.clone(),
+            headers: vec![
+                (
+                    WEBHOOK_HEADER_EVENT_TYPE,
+                    retry.event.event_type().to_string(),
+                ),
+                (WEBHOOK_HEADER_DELIVERY_ID, delivery_id.to_string()),
+                (WEBHOOK_HEADER_TIMESTAMP, timestamp.to_string()),
+                (
+                    WEBHOOK_HEADER_SIGNATURE,
+                    format!(
+                        "sha256={}",
+                        sign_webhook_payload(&subscription.secret, timestamp, &body)
+                    ),
+                ),
+            ],
+            body,
+        };
+
+        let (response_status, error) = match self.webhook_sender.send(request).await {
+            Ok(status) if (200..300).contains(&status) => (Some(status), None),
+            Ok(status) => (
+                Some(status),
+                Some(format!("Endpoint responded with status {status}")),
+            ),
+            Err(e) => (None, Some(e.reason)),
+        };
+
+        Ok(WebhookDelivery {
+            id: delivery_id,
+            subscription_id: subscription.id,
+            event_id: retry.event.id,
+            event_type: retry.event.event_type(),
+            attempt: retry.attempt,
+            requested_at,
+            response_status,
+            error,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,217 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use dill::{component, interface, meta, Catalog};
+use internal_error::InternalError;
+use kamu_webhooks::*;
+use messaging_outbox::{
+    MessageConsumer,
+    MessageConsumerMeta,
+    MessageConsumerT,
+    MessageConsumptionDurability,
+};
+use time_source::SystemTimeSource;
+use uuid::Uuid;
+
+use crate::{
+    WebhookDeliveryMessage,
+    MESSAGE_CONSUMER_KAMU_WEBHOOK_DELIVERY_WORKER,
+    MESSAGE_PRODUCER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const RETRY_BATCH_SIZE: usize = 100;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Keeps the queue of pending webhook delivery attempts and the delivery log.
+/// Requests are sent by [`crate::WebhookDeliveryRetrier`] outside of any
+/// transaction: attempts are claimed first, and their outcome is recorded
+/// afterwards. A failed attempt is not retried in place: the next one is
+/// scheduled with an exponential backoff.
+pub struct WebhookDeliveryWorker {
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    config: Arc<WebhookDeliveryConfig>,
+    time_source: Arc<dyn SystemTimeSource>,
+}
+
+#[component(pub)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<WebhookDeliveryMessage>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: MESSAGE_CONSUMER_KAMU_WEBHOOK_DELIVERY_WORKER,
+    feeding_producers: &[
+        MESSAGE_PRODUCER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+    ],
+    durability: MessageConsumptionDurability::Durable,
+})]
+impl WebhookDeliveryWorker {
+    pub fn new(
+        subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+        config: Arc<WebhookDeliveryConfig>,
+        time_source: Arc<dyn SystemTimeSource>,
+    ) -> Self {
+        Self {
+            subscription_repo,
+            delivery_repo,
+            config,
+            time_source,
+        }
+    }
+
+    /// Claims attempts that are due by now. Claimed attempts are postponed
+    /// for as long as their requests may take, so that they are performed
+    /// again should the process stop before recording the outcome.
+    pub async fn claim_due_deliveries(
+        &self,
+    ) -> Result<Vec<(WebhookSubscription, WebhookDeliveryRetry)>, InternalError> {
+        let now = self.time_source.now();
+
+        let due_retries = self
+            .delivery_repo
+            .get_due_retries(now, RETRY_BATCH_SIZE)
+            .await?;
+
+        let claim_duration =
+            self.config.request_timeout * (i32::try_from(due_retries.len()).unwrap() + 1);
+
+        let mut claimed = Vec::with_capacity(due_retries.len());
+        for retry in due_retries {
+            self.delivery_repo.delete_retry(&retry.id).await?;
+
+            let Some(subscription) = self
+                .get_enabled_subscription(&retry.subscription_id)
+                .await?
+            else {
+                continue;
+            };
+
+            let retry = WebhookDeliveryRetry {
+                due_at: now + claim_duration,
+                ..retry
+            };
+            self.delivery_repo.schedule_retry(&retry).await?;
+
+            claimed.push((subscription, retry));
+        }
+
+        Ok(claimed)
+    }
+
+    /// Records the outcome of a claimed attempt and schedules the next one if
+    /// it has failed
+    pub async fn record_delivery(
+        &self,
+        retry: &WebhookDeliveryRetry,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), InternalError> {
+        self.delivery_repo.delete_retry(&retry.id).await?;
+        self.delivery_repo.save_delivery(delivery).await?;
+
+        if delivery.is_success() {
+            return Ok(());
+        }
+
+        if retry.attempt >= self.config.max_attempts {
+            tracing::error!(
+                subscription_id = %retry.subscription_id,
+                event_id = %retry.event.id,
+                max_attempts = self.config.max_attempts,
+                error = delivery.error.as_deref().unwrap_or_default(),
+                "Webhook delivery failed, giving up"
+            );
+            return Ok(());
+        }
+
+        tracing::warn!(
+            subscription_id = %retry.subscription_id,
+            event_id = %retry.event.id,
+            attempt = retry.attempt,
+            error = delivery.error.as_deref().unwrap_or_default(),
+            "Webhook delivery attempt failed, scheduling a retry"
+        );
+
+        let next_attempt = retry.attempt + 1;
+        self.delivery_repo
+            .schedule_retry(&WebhookDeliveryRetry {
+                id: Uuid::new_v4(),
+                subscription_id: retry.subscription_id,
+                event: retry.event.clone(),
+                attempt: next_attempt,
+                due_at: delivery.requested_at + self.config.backoff_before_attempt(next_attempt),
+            })
+            .await
+    }
+
+    async fn get_enabled_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<Option<WebhookSubscription>, InternalError> {
+        match self
+            .subscription_repo
+            .get_subscription(subscription_id)
+            .await
+        {
+            Ok(subscription) if subscription.enabled => Ok(Some(subscription)),
+            Ok(_) => Ok(None),
+            // Subscription was removed after the event was dispatched
+            Err(GetWebhookSubscriptionError::NotFound(_)) => Ok(None),
+            Err(GetWebhookSubscriptionError::Internal(e)) => Err(e),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl MessageConsumer for WebhookDeliveryWorker {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<WebhookDeliveryMessage> for WebhookDeliveryWorker {
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        name = "WebhookDeliveryWorker[WebhookDeliveryMessage]"
+    )]
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &WebhookDeliveryMessage,
+    ) -> Result<(), InternalError> {
+        tracing::debug!(received_message = ?message, "Received webhook delivery message");
+
+        if self
+            .get_enabled_subscription(&message.subscription_id)
+            .await?
+            .is_none()
+        {
+            return Ok(());
+        }
+
+        // The request is not sent within the message transaction, the first
+        // attempt is queued to be performed by the next retry pass instead
+        self.delivery_repo
+            .schedule_retry(&WebhookDeliveryRetry {
+                id: Uuid::new_v4(),
+                subscription_id: message.subscription_id,
+                event: message.event.clone(),
+                attempt: 1,
+                due_at: self.time_source.now(),
+            })
+            .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,448 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use dill::{component, interface, meta, Catalog, CatalogBuilder};
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_accounts::{AccountRepository, CurrentAccountSubject, GetAccountByIdError};
+use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer};
+use kamu_core::{
+    DatasetLifecycleMessage,
+    DatasetOwnershipService,
+    DatasetReferenceMessage,
+    DatasetRepository,
+    DatasetRepositoryExt,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+};
+use kamu_flow_system::{
+    DatasetFlowType,
+    FlowError,
+    FlowKey,
+    FlowOutcome,
+    FlowProgressMessage,
+    FlowProgressMessageFinished,
+    FlowQueryService,
+};
+use kamu_flow_system_services::MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE;
+use kamu_webhooks::*;
+use messaging_outbox::{
+    MessageConsumer,
+    MessageConsumerMeta,
+    MessageConsumerT,
+    MessageConsumptionDurability,
+    Outbox,
+    OutboxExt,
+};
+use opendatafabric::{AccountID, DatasetID};
+use time_source::SystemTimeSource;
+
+use crate::{
+    WebhookDeliveryMessage,
+    MESSAGE_CONSUMER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+    MESSAGE_PRODUCER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Translates dataset lifecycle, dataset reference and flow progress messages
+/// into webhook events and requests their delivery to every interested
+/// subscription
+pub struct WebhookEventDispatcher {
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    account_repo: Arc<dyn AccountRepository>,
+    dataset_ownership_service: Arc<dyn DatasetOwnershipService>,
+    flow_query_service: Arc<dyn FlowQueryService>,
+    outbox: Arc<dyn Outbox>,
+    time_source: Arc<dyn SystemTimeSource>,
+}
+
+#[component(pub)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<DatasetLifecycleMessage>)]
+#[interface(dyn MessageConsumerT<DatasetReferenceMessage>)]
+#[interface(dyn MessageConsumerT<FlowProgressMessage>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: MESSAGE_CONSUMER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+    feeding_producers: &[
+        MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+        MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+        MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+    ],
+    durability: MessageConsumptionDurability::Durable,
+})]
+impl WebhookEventDispatcher {
+    pub fn new(
+        subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+        dataset_repo: Arc<dyn DatasetRepository>,
+        account_repo: Arc<dyn AccountRepository>,
+        dataset_ownership_service: Arc<dyn DatasetOwnershipService>,
+        flow_query_service: Arc<dyn FlowQueryService>,
+        outbox: Arc<dyn Outbox>,
+        time_source: Arc<dyn SystemTimeSource>,
+    ) -> Self {
+        Self {
+            subscription_repo,
+            dataset_repo,
+            account_repo,
+            dataset_ownership_service,
+            flow_query_service,
+            outbox,
+            time_source,
+        }
+    }
+
+    async fn dispatch_event(
+        &self,
+        catalog: &Catalog,
+        event: WebhookEvent,
+        owner_account_ids: Vec<AccountID>,
+    ) -> Result<(), InternalError> {
+        let mut subscriptions = Vec::new();
+        if owner_account_ids.is_empty() {
+            subscriptions = self
+                .subscription_repo
+                .get_enabled_subscriptions_for_dataset(&event.dataset_id, None)
+                .await?;
+        } else {
+            let mut seen_ids = HashSet::new();
+            for owner_account_id in &owner_account_ids {
+                for subscription in self
+                    .subscription_repo
+                    .get_enabled_subscriptions_for_dataset(
+                        &event.dataset_id,
+                        Some(owner_account_id),
+                    )
+                    .await?
+                {
+                    if seen_ids.insert(subscription.id) {
+                        subscriptions.push(subscription);
+                    }
+                }
+            }
+        }
+
+        for subscription in subscriptions {
+            if !subscription.is_interested_in(&event) {
+                continue;
+            }
+
+            if !self
+                .is_subscription_authorized(catalog, &subscription, &owner_account_ids)
+                .await?
+            {
+                tracing::debug!(
+                    subscription_id = %subscription.id,
+                    account_id = %subscription.account_id,
+                    dataset_id = %event.dataset_id,
+                    "Skipping webhook delivery to an account that lost access to the dataset"
+                );
+                continue;
+            }
+
+            tracing::debug!(
+                subscription_id = %subscription.id,
+                event_id = %event.id,
+                event_type = %event.event_type(),
+                "Requesting webhook delivery"
+            );
+
+            self.outbox
+                .post_message(
+                    MESSAGE_PRODUCER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+                    WebhookDeliveryMessage::new(subscription.id, event.clone()),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Subscriptions scoped to a dataset may belong to accounts other than its
+    /// owners, whose read access may have been revoked since the subscription
+    /// was created, so it is re-checked for every event
+    async fn is_subscription_authorized(
+        &self,
+        catalog: &Catalog,
+        subscription: &WebhookSubscription,
+        owner_account_ids: &[AccountID],
+    ) -> Result<bool, InternalError> {
+        let Some(dataset_id) = &subscription.dataset_id else {
+            // Account-wide subscriptions only cover datasets owned by the account
+            return Ok(true);
+        };
+
+        if owner_account_ids.contains(&subscription.account_id) {
+            return Ok(true);
+        }
+
+        // Access to a dataset that no longer exists cannot be verified
+        let Some(dataset_handle) = self
+            .dataset_repo
+            .try_resolve_dataset_ref(&dataset_id.as_local_ref())
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        let account = match self
+            .account_repo
+            .get_account_by_id(&subscription.account_id)
+            .await
+        {
+            Ok(account) => account,
+            Err(GetAccountByIdError::NotFound(_)) => return Ok(false),
+            Err(GetAccountByIdError::Internal(e)) => return Err(e),
+        };
+
+        let account_catalog = CatalogBuilder::new_chained(catalog)
+            .add_value(CurrentAccountSubject::logged(
+                account.id,
+                account.account_name,
+                account.is_admin,
+            ))
+            .build();
+
+        account_catalog
+            .get_one::<dyn DatasetActionAuthorizer>()
+            .int_err()?
+            .is_action_allowed(&dataset_handle, DatasetAction::Read)
+            .await
+    }
+
+    async fn get_dataset_owners(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<AccountID>, InternalError> {
+        self.dataset_ownership_service
+            .get_dataset_owners(dataset_id)
+            .await
+    }
+
+    async fn handle_dataset_lifecycle_message(
+        &self,
+        catalog: &Catalog,
+        message: &DatasetLifecycleMessage,
+    ) -> Result<(), InternalError> {
+        match message {
+            DatasetLifecycleMessage::Created(message) => {
+                let event = WebhookEvent::new(
+                    self.time_source.now(),
+                    message.dataset_id.clone(),
+                    WebhookEventPayload::DatasetCreated(WebhookEventDatasetCreated {
+                        dataset_name: message.dataset_name.clone(),
+                        owner_account_id: message.owner_account_id.clone(),
+                    }),
+                );
+                self.dispatch_event(catalog, event, vec![message.owner_account_id.clone()])
+                    .await
+            }
+
+            DatasetLifecycleMessage::Deleted(message) => {
+                // Ownership records may already be cleaned up by the time this
+                // message is processed, so the owner is taken from the message
+                let event = WebhookEvent::new(
+                    self.time_source.now(),
+                    message.dataset_id.clone(),
+                    WebhookEventPayload::DatasetDeleted,
+                );
+                self.dispatch_event(catalog, event, vec![message.owner_account_id.clone()])
+                    .await
+            }
+
+            DatasetLifecycleMessage::DependenciesUpdated(_)
+            | DatasetLifecycleMessage::Renamed(_) => {
+                // No action required
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_dataset_reference_message(
+        &self,
+        catalog: &Catalog,
+        message: &DatasetReferenceMessage,
+    ) -> Result<(), InternalError> {
+        match message {
+            DatasetReferenceMessage::Updated(message) => {
+                let event = WebhookEvent::new(
+                    self.time_source.now(),
+                    message.dataset_id.clone(),
+                    WebhookEventPayload::DatasetHeadUpdated(WebhookEventDatasetHeadUpdated {
+                        old_head: message.maybe_prev_block_hash.clone(),
+                        new_head: message.new_block_hash.clone(),
+                    }),
+                );
+                let owner_account_ids = self.get_dataset_owners(&message.dataset_id).await?;
+                self.dispatch_event(catalog, event, owner_account_ids).await
+            }
+        }
+    }
+
+    async fn handle_flow_finished_message(
+        &self,
+        catalog: &Catalog,
+        message: &FlowProgressMessageFinished,
+    ) -> Result<(), InternalError> {
+        let flow_state = self
+            .flow_query_service
+            .get_flow(message.flow_id)
+            .await
+            .int_err()?;
+
+        // System flows are not related to any dataset, so there is nobody to notify
+        let FlowKey::Dataset(flow_key) = flow_state.flow_key else {
+            return Ok(());
+        };
+
+        let Some(event) =
+            build_flow_finished_event(message, &flow_key.dataset_id, flow_key.flow_type)
+        else {
+            return Ok(());
+        };
+
+        let owner_account_ids = self.get_dataset_owners(&flow_key.dataset_id).await?;
+        self.dispatch_event(catalog, event, owner_account_ids).await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn build_flow_finished_event(
+    message: &FlowProgressMessageFinished,
+    dataset_id: &DatasetID,
+    flow_type: DatasetFlowType,
+) -> Option<WebhookEvent> {
+    let flow_id: u64 = message.flow_id.into();
+    let flow_type_name = flow_type_name(flow_type).to_string();
+
+    let payload = match &message.outcome {
+        FlowOutcome::Success(_) => WebhookEventPayload::FlowSucceeded(WebhookEventFlowSucceeded {
+            flow_id,
+            flow_type: flow_type_name,
+        }),
+        FlowOutcome::Failed(flow_error) => {
+            WebhookEventPayload::FlowFailed(WebhookEventFlowFailed {
+                flow_id,
+                flow_type: flow_type_name,
+                reason: flow_failure_reason(flow_error),
+            })
+        }
+        // Aborted flows were cancelled on purpose, that's not worth a notification
+        FlowOutcome::Aborted => return None,
+    };
+
+    Some(WebhookEvent::new(
+        message.event_time,
+        dataset_id.clone(),
+        payload,
+    ))
+}
+
+fn flow_type_name(flow_type: DatasetFlowType) -> &'static str {
+    match flow_type {
+        DatasetFlowType::Ingest => "INGEST",
+        DatasetFlowType::ExecuteTransform => "EXECUTE_TRANSFORM",
+        DatasetFlowType::HardCompaction => "HARD_COMPACTION",
+        DatasetFlowType::SoftCompaction => "SOFT_COMPACTION",
+        DatasetFlowType::Reset => "RESET",
+        DatasetFlowType::Verify => "VERIFY",
+        DatasetFlowType::Retention => "RETENTION",
+    }
+}
+
+fn flow_failure_reason(flow_error: &FlowError) -> String {
+    match flow_error {
+        FlowError::Failed => "Flow failed".to_string(),
+        FlowError::InputDatasetCompacted(e) => {
+            format!("Input dataset '{}' was compacted", e.dataset_id)
+        }
+        FlowError::ResetHeadNotFound => "New head of the reset was not found".to_string(),
+        FlowError::VerificationFailed(e) => format!("Verification failed: {}", e.message),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl MessageConsumer for WebhookEventDispatcher {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<DatasetLifecycleMessage> for WebhookEventDispatcher {
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        name = "WebhookEventDispatcher[DatasetLifecycleMessage]"
+    )]
+    async fn consume_message(
+        &self,
+        target_catalog: &Catalog,
+        message: &DatasetLifecycleMessage,
+    ) -> Result<(), InternalError> {
+        tracing::debug!(received_message = ?message, "Received dataset lifecycle message");
+
+        self.handle_dataset_lifecycle_message(target_catalog, message)
+            .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<DatasetReferenceMessage> for WebhookEventDispatcher {
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        name = "WebhookEventDispatcher[DatasetReferenceMessage]"
+    )]
+    async fn consume_message(
+        &self,
+        target_catalog: &Catalog,
+        message: &DatasetReferenceMessage,
+    ) -> Result<(), InternalError> {
+        tracing::debug!(received_message = ?message, "Received dataset reference message");
+
+        self.handle_dataset_reference_message(target_catalog, message)
+            .await
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<FlowProgressMessage> for WebhookEventDispatcher {
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        name = "WebhookEventDispatcher[FlowProgressMessage]"
+    )]
+    async fn consume_message(
+        &self,
+        target_catalog: &Catalog,
+        message: &FlowProgressMessage,
+    ) -> Result<(), InternalError> {
+        tracing::debug!(received_message = ?message, "Received flow progress message");
+
+        match message {
+            FlowProgressMessage::Finished(message) => {
+                self.handle_flow_finished_message(target_catalog, message)
+                    .await
+            }
+            FlowProgressMessage::Scheduled(_)
+            | FlowProgressMessage::Running(_)
+            | FlowProgressMessage::Cancelled(_) => Ok(()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
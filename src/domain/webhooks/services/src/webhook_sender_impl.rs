@@ -0,0 +1,127 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use dill::*;
+use kamu_webhooks::{WebhookDeliveryConfig, WebhookRequest, WebhookSendError, WebhookSender};
+use url::Host;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookSenderImpl {
+    client: reqwest::Client,
+    config: Arc<WebhookDeliveryConfig>,
+}
+
+#[component(pub)]
+#[interface(dyn WebhookSender)]
+#[scope(Singleton)]
+impl WebhookSenderImpl {
+    pub fn new(config: Arc<WebhookDeliveryConfig>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(config.request_timeout.to_std().unwrap())
+            // Endpoints are expected to respond directly, following redirects
+            // would resend the signed payload to an unverified location
+            .redirect(reqwest::redirect::Policy::none())
+            // Addresses are checked at connection time, as DNS records may
+            // have changed since the subscription was validated
+            .dns_resolver(Arc::new(TargetAddressResolver {
+                config: config.clone(),
+            }))
+            .build()
+            .unwrap();
+
+        Self { client, config }
+    }
+
+    fn check_target_host(&self, target_url: &url::Url) -> Result<(), WebhookSendError> {
+        // Host names are checked by the resolver
+        let ip = match target_url.host() {
+            Some(Host::Ipv4(ip)) => IpAddr::V4(ip),
+            Some(Host::Ipv6(ip)) => IpAddr::V6(ip),
+            Some(Host::Domain(_)) => return Ok(()),
+            None => {
+                return Err(WebhookSendError {
+                    reason: "Target URL has no host".to_string(),
+                })
+            }
+        };
+
+        if self.config.is_allowed_target_ip(ip) {
+            Ok(())
+        } else {
+            Err(WebhookSendError {
+                reason: format!("Target address {ip} is not allowed"),
+            })
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSender for WebhookSenderImpl {
+    #[tracing::instrument(level = "debug", skip_all, fields(target_url = %request.target_url))]
+    async fn send(&self, request: WebhookRequest) -> Result<u16, WebhookSendError> {
+        self.check_target_host(&request.target_url)?;
+
+        let mut request_builder = self
+            .client
+            .post(request.target_url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(request.body);
+
+        for (name, value) in request.headers {
+            request_builder = request_builder.header(name, value);
+        }
+
+        let response = request_builder.send().await.map_err(|e| WebhookSendError {
+            reason: e.to_string(),
+        })?;
+
+        Ok(response.status().as_u16())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Resolves endpoint host names and refuses to connect to any address the
+/// configuration does not allow
+struct TargetAddressResolver {
+    config: Arc<WebhookDeliveryConfig>,
+}
+
+impl reqwest::dns::Resolve for TargetAddressResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let config = self.config.clone();
+
+        Box::pin(async move {
+            // The port is replaced with the one of the target URL by the connector
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((name.as_str(), 0)).await?.collect();
+
+            if let Some(addr) = addrs
+                .iter()
+                .find(|addr| !config.is_allowed_target_ip(addr.ip()))
+            {
+                return Err(format!(
+                    "Host '{}' resolves to address {} that is not allowed",
+                    name.as_str(),
+                    addr.ip()
+                )
+                .into());
+            }
+
+            let addrs: reqwest::dns::Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,257 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use database_common::PaginationOpts;
+use dill::*;
+use internal_error::InternalError;
+use kamu_webhooks::*;
+use opendatafabric::{AccountID, DatasetID};
+use time_source::SystemTimeSource;
+use url::{Host, Url};
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookSubscriptionServiceImpl {
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    time_source: Arc<dyn SystemTimeSource>,
+    config: Arc<WebhookDeliveryConfig>,
+}
+
+#[component(pub)]
+#[interface(dyn WebhookSubscriptionService)]
+impl WebhookSubscriptionServiceImpl {
+    pub fn new(
+        subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+        time_source: Arc<dyn SystemTimeSource>,
+        config: Arc<WebhookDeliveryConfig>,
+    ) -> Self {
+        Self {
+            subscription_repo,
+            delivery_repo,
+            time_source,
+            config,
+        }
+    }
+
+    // Host names are resolved again by the sender before every delivery, as
+    // the addresses they point to may change after the subscription is created
+    fn validate_target_url(&self, target_url: &str) -> Result<Url, InvalidWebhookTargetUrlError> {
+        let invalid_url = |reason: &str| InvalidWebhookTargetUrlError {
+            url: target_url.to_string(),
+            reason: reason.to_string(),
+        };
+
+        let url = Url::parse(target_url).map_err(|e| invalid_url(&e.to_string()))?;
+
+        let is_loopback = match url.host() {
+            Some(Host::Domain(domain)) => domain.eq_ignore_ascii_case("localhost"),
+            Some(Host::Ipv4(ip)) => {
+                if !self.config.is_allowed_target_ip(IpAddr::V4(ip)) {
+                    return Err(invalid_url("internal network addresses are not allowed"));
+                }
+                ip.is_loopback()
+            }
+            Some(Host::Ipv6(ip)) => {
+                if !self.config.is_allowed_target_ip(IpAddr::V6(ip)) {
+                    return Err(invalid_url("internal network addresses are not allowed"));
+                }
+                ip.is_loopback()
+            }
+            None => return Err(invalid_url("host is missing")),
+        };
+
+        if is_loopback && !self.config.allow_insecure_localhost {
+            return Err(invalid_url("local endpoints are not allowed"));
+        }
+
+        match url.scheme() {
+            "https" => Ok(url),
+            // Plain HTTP is only tolerated for local development
+            "http" if is_loopback => Ok(url),
+            "http" => Err(invalid_url("only HTTPS endpoints are supported")),
+            _ => Err(invalid_url("unsupported scheme")),
+        }
+    }
+
+    async fn get_owned_subscription(
+        &self,
+        account_id: &AccountID,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError> {
+        let subscription = self
+            .subscription_repo
+            .get_subscription(subscription_id)
+            .await?;
+
+        if subscription.account_id != *account_id {
+            return Err(GetWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                },
+            ));
+        }
+
+        Ok(subscription)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionService for WebhookSubscriptionServiceImpl {
+    async fn create_subscription(
+        &self,
+        account_id: &AccountID,
+        target_url: &str,
+        event_types: Vec<WebhookEventType>,
+        dataset_id: Option<DatasetID>,
+    ) -> Result<WebhookSubscription, CreateWebhookSubscriptionError> {
+        let target_url = self
+            .validate_target_url(target_url)
+            .map_err(CreateWebhookSubscriptionError::InvalidTargetUrl)?;
+
+        let mut unique_event_types = Vec::with_capacity(event_types.len());
+        for event_type in event_types {
+            if !unique_event_types.contains(&event_type) {
+                unique_event_types.push(event_type);
+            }
+        }
+        if unique_event_types.is_empty() {
+            return Err(CreateWebhookSubscriptionError::NoEventTypes(
+                NoWebhookEventTypesError {},
+            ));
+        }
+
+        let subscription = WebhookSubscription::new(
+            account_id.clone(),
+            target_url,
+            unique_event_types,
+            dataset_id,
+            self.time_source.now(),
+        );
+
+        self.subscription_repo
+            .save_subscription(&subscription)
+            .await?;
+
+        Ok(subscription)
+    }
+
+    async fn list_subscriptions(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<WebhookSubscription>, InternalError> {
+        self.subscription_repo
+            .get_subscriptions_by_account(account_id)
+            .await
+    }
+
+    async fn get_subscription(
+        &self,
+        account_id: &AccountID,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError> {
+        self.get_owned_subscription(account_id, subscription_id)
+            .await
+    }
+
+    async fn set_subscription_enabled(
+        &self,
+        account_id: &AccountID,
+        subscription_id: &Uuid,
+        enabled: bool,
+    ) -> Result<WebhookSubscription, UpdateWebhookSubscriptionError> {
+        let mut subscription = self
+            .get_owned_subscription(account_id, subscription_id)
+            .await
+            .map_err(|err| match err {
+                GetWebhookSubscriptionError::NotFound(e) => {
+                    UpdateWebhookSubscriptionError::NotFound(e)
+                }
+                GetWebhookSubscriptionError::Internal(e) => {
+                    UpdateWebhookSubscriptionError::Internal(e)
+                }
+            })?;
+
+        if subscription.enabled != enabled {
+            self.subscription_repo
+                .set_subscription_enabled(subscription_id, enabled)
+                .await?;
+            subscription.enabled = enabled;
+        }
+
+        Ok(subscription)
+    }
+
+    async fn remove_subscription(
+        &self,
+        account_id: &AccountID,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError> {
+        self.get_owned_subscription(account_id, subscription_id)
+            .await
+            .map_err(|err| match err {
+                GetWebhookSubscriptionError::NotFound(e) => {
+                    DeleteWebhookSubscriptionError::NotFound(e)
+                }
+                GetWebhookSubscriptionError::Internal(e) => {
+                    DeleteWebhookSubscriptionError::Internal(e)
+                }
+            })?;
+
+        self.delivery_repo
+            .delete_deliveries_by_subscription(subscription_id)
+            .await?;
+
+        self.subscription_repo
+            .delete_subscription(subscription_id)
+            .await
+    }
+
+    async fn list_deliveries(
+        &self,
+        account_id: &AccountID,
+        subscription_id: &Uuid,
+        pagination: Option<PaginationOpts>,
+    ) -> Result<WebhookDeliveryListing, GetWebhookSubscriptionError> {
+        self.get_owned_subscription(account_id, subscription_id)
+            .await?;
+
+        let total_count = self
+            .delivery_repo
+            .get_deliveries_count_by_subscription(subscription_id)
+            .await?;
+        if total_count == 0 {
+            return Ok(WebhookDeliveryListing {
+                list: vec![],
+                total_count,
+            });
+        }
+
+        let database_pagination = pagination.unwrap_or(PaginationOpts {
+            limit: total_count,
+            offset: 0,
+        });
+
+        let list = self
+            .delivery_repo
+            .get_deliveries_by_subscription(subscription_id, &database_pagination)
+            .await?;
+
+        Ok(WebhookDeliveryListing { list, total_count })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
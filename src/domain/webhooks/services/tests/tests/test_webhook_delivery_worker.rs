@@ -0,0 +1,342 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use database_common::{DatabaseTransactionRunner, NoOpDatabasePlugin, PaginationOpts};
+use dill::{Catalog, CatalogBuilder};
+use kamu_webhooks::*;
+use kamu_webhooks_inmem::{
+    InMemoryWebhookDeliveryRepository,
+    InMemoryWebhookSubscriptionRepository,
+};
+use kamu_webhooks_services::{
+    WebhookDeliveryMessage,
+    WebhookDeliveryRetrier,
+    WebhookDeliveryWorker,
+};
+use messaging_outbox::MessageConsumerT;
+use opendatafabric::{AccountID, DatasetID};
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const TEST_SECRET: &str = "test-secret";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_delivers_signed_request() {
+    let mut mock_webhook_sender = MockWebhookSender::new();
+    mock_webhook_sender
+        .expect_send()
+        .withf(|request| {
+            let header = |name| {
+                request
+                    .headers
+                    .iter()
+                    .find(|(header_name, _)| *header_name == name)
+                    .map(|(_, value)| value.clone())
+                    .unwrap()
+            };
+
+            let timestamp: i64 = header(WEBHOOK_HEADER_TIMESTAMP).parse().unwrap();
+            let event: WebhookEvent = serde_json::from_slice(&request.body).unwrap();
+
+            request.target_url.as_str() == "https://example.com/hooks"
+                && header(WEBHOOK_HEADER_EVENT_TYPE) == "dataset_deleted"
+                && event.event_type() == WebhookEventType::DatasetDeleted
+                && header(WEBHOOK_HEADER_SIGNATURE)
+                    == format!(
+                        "sha256={}",
+                        sign_webhook_payload(TEST_SECRET, timestamp, &request.body)
+                    )
+        })
+        .times(1)
+        .returning(|_| Ok(204));
+
+    let harness = WebhookDeliveryWorkerHarness::new(mock_webhook_sender, 3);
+    let subscription = harness.create_subscription(true).await;
+
+    harness.deliver(&subscription).await;
+    assert_eq!(harness.retry_due_deliveries(Duration::zero()).await, 1);
+
+    let deliveries = harness.get_deliveries(&subscription).await;
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].attempt, 1);
+    assert_eq!(deliveries[0].response_status, Some(204));
+    assert!(deliveries[0].is_success());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_schedules_retries_of_failed_attempts() {
+    let mut responses = vec![
+        Ok(503),
+        Err(WebhookSendError {
+            reason: "Connection refused".to_string(),
+        }),
+        Ok(200),
+    ]
+    .into_iter();
+
+    let mut mock_webhook_sender = MockWebhookSender::new();
+    mock_webhook_sender
+        .expect_send()
+        .times(3)
+        .returning(move |_| responses.next().unwrap());
+
+    let harness = WebhookDeliveryWorkerHarness::new(mock_webhook_sender, 5);
+    let subscription = harness.create_subscription(true).await;
+
+    // The first attempt is queued upon the message and performed by a pass
+    harness.deliver(&subscription).await;
+    assert!(harness.get_deliveries(&subscription).await.is_empty());
+
+    assert_eq!(harness.retry_due_deliveries(Duration::zero()).await, 1);
+    assert_eq!(harness.get_deliveries(&subscription).await.len(), 1);
+
+    // Retries are performed once their backoff has elapsed
+    assert_eq!(harness.retry_due_deliveries(Duration::zero()).await, 0);
+    assert_eq!(harness.retry_due_deliveries(Duration::seconds(1)).await, 1);
+    assert_eq!(harness.retry_due_deliveries(Duration::seconds(2)).await, 0);
+    assert_eq!(harness.retry_due_deliveries(Duration::seconds(3)).await, 1);
+    assert_eq!(harness.retry_due_deliveries(Duration::hours(1)).await, 0);
+
+    let mut deliveries = harness.get_deliveries(&subscription).await;
+    deliveries.sort_by_key(|d| d.attempt);
+
+    pretty_assertions::assert_eq!(
+        deliveries
+            .iter()
+            .map(|d| (d.attempt, d.response_status, d.error.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                1,
+                Some(503),
+                Some("Endpoint responded with status 503".to_string())
+            ),
+            (2, None, Some("Connection refused".to_string())),
+            (3, Some(200), None),
+        ]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_gives_up_after_max_attempts() {
+    let mut mock_webhook_sender = MockWebhookSender::new();
+    mock_webhook_sender
+        .expect_send()
+        .times(2)
+        .returning(|_| Ok(500));
+
+    let harness = WebhookDeliveryWorkerHarness::new(mock_webhook_sender, 2);
+    let subscription = harness.create_subscription(true).await;
+
+    harness.deliver(&subscription).await;
+    assert_eq!(harness.retry_due_deliveries(Duration::zero()).await, 1);
+    assert_eq!(harness.retry_due_deliveries(Duration::seconds(1)).await, 1);
+    assert_eq!(harness.retry_due_deliveries(Duration::hours(1)).await, 0);
+
+    let deliveries = harness.get_deliveries(&subscription).await;
+    assert_eq!(deliveries.len(), 2);
+    assert!(deliveries.iter().all(|d| !d.is_success()));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_skips_disabled_subscription() {
+    let mut mock_webhook_sender = MockWebhookSender::new();
+    mock_webhook_sender.expect_send().never();
+
+    let harness = WebhookDeliveryWorkerHarness::new(mock_webhook_sender, 3);
+    let subscription = harness.create_subscription(false).await;
+
+    harness.deliver(&subscription).await;
+    assert_eq!(harness.retry_due_deliveries(Duration::zero()).await, 0);
+
+    assert!(harness.get_deliveries(&subscription).await.is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_drops_retries_of_disabled_subscription() {
+    let mut mock_webhook_sender = MockWebhookSender::new();
+    mock_webhook_sender
+        .expect_send()
+        .times(1)
+        .returning(|_| Ok(500));
+
+    let harness = WebhookDeliveryWorkerHarness::new(mock_webhook_sender, 3);
+    let subscription = harness.create_subscription(true).await;
+
+    harness.deliver(&subscription).await;
+    assert_eq!(harness.retry_due_deliveries(Duration::zero()).await, 1);
+
+    harness
+        .subscription_repo
+        .set_subscription_enabled(&subscription.id, false)
+        .await
+        .unwrap();
+
+    assert_eq!(harness.retry_due_deliveries(Duration::seconds(1)).await, 0);
+    assert_eq!(harness.retry_due_deliveries(Duration::hours(1)).await, 0);
+
+    assert_eq!(harness.get_deliveries(&subscription).await.len(), 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_reclaims_unrecorded_attempts() {
+    let mut mock_webhook_sender = MockWebhookSender::new();
+    mock_webhook_sender
+        .expect_send()
+        .times(1)
+        .returning(|_| Ok(200));
+
+    let harness = WebhookDeliveryWorkerHarness::new(mock_webhook_sender, 3);
+    let subscription = harness.create_subscription(true).await;
+
+    harness.deliver(&subscription).await;
+
+    // Attempt is claimed, but its outcome is never recorded
+    assert_eq!(
+        harness.worker.claim_due_deliveries().await.unwrap().len(),
+        1
+    );
+    assert_eq!(harness.retry_due_deliveries(Duration::seconds(5)).await, 0);
+
+    // Attempt is performed again once the claim expires
+    assert_eq!(harness.retry_due_deliveries(Duration::seconds(10)).await, 1);
+
+    let deliveries = harness.get_deliveries(&subscription).await;
+    assert_eq!(deliveries.len(), 1);
+    assert!(deliveries[0].is_success());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn start_time() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap()
+}
+
+struct WebhookDeliveryWorkerHarness {
+    catalog: Catalog,
+    worker: Arc<WebhookDeliveryWorker>,
+    retrier: Arc<WebhookDeliveryRetrier>,
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    time_source: Arc<SystemTimeSourceStub>,
+}
+
+impl WebhookDeliveryWorkerHarness {
+    fn new(mock_webhook_sender: MockWebhookSender, max_attempts: u32) -> Self {
+        let catalog = {
+            let mut b = CatalogBuilder::new();
+
+            b.add::<WebhookDeliveryWorker>();
+            b.add::<WebhookDeliveryRetrier>();
+            b.add::<DatabaseTransactionRunner>();
+            b.add::<InMemoryWebhookSubscriptionRepository>();
+            b.add::<InMemoryWebhookDeliveryRepository>();
+
+            b.add_value(mock_webhook_sender);
+            b.bind::<dyn WebhookSender, MockWebhookSender>();
+
+            b.add_value(WebhookDeliveryConfig::new(
+                max_attempts,
+                Duration::seconds(1),
+                Duration::seconds(5),
+                false,
+            ));
+
+            b.add_value(SystemTimeSourceStub::new_set(start_time()));
+            b.bind::<dyn SystemTimeSource, SystemTimeSourceStub>();
+
+            NoOpDatabasePlugin::init_database_components(&mut b);
+
+            b.build()
+        };
+
+        Self {
+            worker: catalog.get_one().unwrap(),
+            retrier: catalog.get_one().unwrap(),
+            subscription_repo: catalog.get_one().unwrap(),
+            delivery_repo: catalog.get_one().unwrap(),
+            time_source: catalog.get_one().unwrap(),
+            catalog,
+        }
+    }
+
+    async fn create_subscription(&self, enabled: bool) -> WebhookSubscription {
+        let mut subscription = WebhookSubscription::new(
+            AccountID::new_seeded_ed25519(b"alice"),
+            Url::parse("https://example.com/hooks").unwrap(),
+            vec![WebhookEventType::DatasetDeleted],
+            None,
+            Utc::now(),
+        );
+        subscription.secret = TEST_SECRET.to_string();
+        subscription.enabled = enabled;
+
+        self.subscription_repo
+            .save_subscription(&subscription)
+            .await
+            .unwrap();
+
+        subscription
+    }
+
+    async fn deliver(&self, subscription: &WebhookSubscription) {
+        let event = WebhookEvent::new(
+            Utc::now(),
+            DatasetID::new_seeded_ed25519(b"foo"),
+            WebhookEventPayload::DatasetDeleted,
+        );
+
+        self.worker
+            .consume_message(
+                &self.catalog,
+                &WebhookDeliveryMessage::new(subscription.id, event),
+            )
+            .await
+            .unwrap();
+    }
+
+    /// Runs a retry pass at the specified offset from the start time
+    async fn retry_due_deliveries(&self, elapsed: Duration) -> usize {
+        self.time_source.set(start_time() + elapsed);
+
+        self.retrier.run_retry_pass().await.unwrap()
+    }
+
+    async fn get_deliveries(&self, subscription: &WebhookSubscription) -> Vec<WebhookDelivery> {
+        self.delivery_repo
+            .get_deliveries_by_subscription(
+                &subscription.id,
+                &PaginationOpts {
+                    offset: 0,
+                    limit: 100,
+                },
+            )
+            .await
+            .unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,799 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, TimeZone, Utc};
+use database_common::PaginationOpts;
+use dill::{component, interface, meta, scope, Catalog, CatalogBuilder, Singleton};
+use internal_error::InternalError;
+use kamu_accounts::{Account, AccountRepository, CurrentAccountSubject};
+use kamu_accounts_inmem::InMemoryAccountRepository;
+use kamu_core::auth::{
+    DatasetAction,
+    DatasetActionAuthorizer,
+    DatasetActionNotEnoughPermissionsError,
+    DatasetActionUnauthorizedError,
+};
+use kamu_core::testing::MockDatasetRepository;
+use kamu_core::{
+    AccessError,
+    DatasetLifecycleMessage,
+    DatasetOwnershipService,
+    DatasetReferenceMessage,
+    DatasetRepository,
+    DatasetVisibility,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+};
+use kamu_flow_system::*;
+use kamu_flow_system_services::MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE;
+use kamu_webhooks::*;
+use kamu_webhooks_inmem::InMemoryWebhookSubscriptionRepository;
+use kamu_webhooks_services::{
+    WebhookDeliveryMessage,
+    WebhookEventDispatcher,
+    MESSAGE_PRODUCER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+};
+use messaging_outbox::{
+    register_message_dispatcher,
+    ConsumerFilter,
+    MessageConsumer,
+    MessageConsumerMeta,
+    MessageConsumerT,
+    MessageConsumptionDurability,
+    Outbox,
+    OutboxExt,
+    OutboxImmediateImpl,
+};
+use opendatafabric::{
+    AccountID,
+    DatasetAlias,
+    DatasetHandle,
+    DatasetID,
+    DatasetName,
+    DatasetRef,
+    Multihash,
+};
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_dataset_created_notifies_owner_subscriptions() {
+    let harness = WebhookEventDispatcherHarness::new().await;
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+
+    let alice_created = harness
+        .subscribe(&harness.alice_id, WebhookEventType::DatasetCreated, None)
+        .await;
+    // Not interested in this event type
+    harness
+        .subscribe(&harness.alice_id, WebhookEventType::DatasetDeleted, None)
+        .await;
+    // Not an owner of the dataset
+    harness
+        .subscribe(&harness.bob_id, WebhookEventType::DatasetCreated, None)
+        .await;
+
+    harness
+        .outbox
+        .post_message(
+            MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+            DatasetLifecycleMessage::created(
+                foo_id.clone(),
+                harness.alice_id.clone(),
+                DatasetVisibility::Private,
+                DatasetName::new_unchecked("foo"),
+            ),
+        )
+        .await
+        .unwrap();
+
+    let messages = harness.delivery_messages();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].subscription_id, alice_created.id);
+    assert_eq!(messages[0].event.dataset_id, foo_id);
+    pretty_assertions::assert_eq!(
+        messages[0].event.payload,
+        WebhookEventPayload::DatasetCreated(WebhookEventDatasetCreated {
+            dataset_name: DatasetName::new_unchecked("foo"),
+            owner_account_id: harness.alice_id.clone(),
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_dataset_deleted_notifies_owner_subscriptions() {
+    let harness = WebhookEventDispatcherHarness::new().await;
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+
+    // Ownership records are already cleaned up, the owner comes from the message
+    let alice_deleted = harness
+        .subscribe(&harness.alice_id, WebhookEventType::DatasetDeleted, None)
+        .await;
+    let foo_deleted = harness
+        .subscribe(
+            &harness.alice_id,
+            WebhookEventType::DatasetDeleted,
+            Some(foo_id.clone()),
+        )
+        .await;
+    // Not an owner of the dataset
+    harness
+        .subscribe(&harness.bob_id, WebhookEventType::DatasetDeleted, None)
+        .await;
+
+    harness
+        .outbox
+        .post_message(
+            MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+            DatasetLifecycleMessage::deleted(foo_id, harness.alice_id.clone()),
+        )
+        .await
+        .unwrap();
+
+    let messages = harness.delivery_messages();
+    pretty_assertions::assert_eq!(
+        messages
+            .iter()
+            .map(|m| (m.subscription_id, m.event.event_type()))
+            .collect::<Vec<_>>(),
+        vec![
+            (alice_deleted.id, WebhookEventType::DatasetDeleted),
+            (foo_deleted.id, WebhookEventType::DatasetDeleted),
+        ]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_dataset_head_updated() {
+    let harness = WebhookEventDispatcherHarness::new().await;
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    let bar_id = DatasetID::new_seeded_ed25519(b"bar");
+    harness.set_dataset_owner(&foo_id, &harness.alice_id);
+
+    let alice_head_updated = harness
+        .subscribe(
+            &harness.alice_id,
+            WebhookEventType::DatasetHeadUpdated,
+            None,
+        )
+        .await;
+    // Scoped to another dataset
+    harness
+        .subscribe(
+            &harness.alice_id,
+            WebhookEventType::DatasetHeadUpdated,
+            Some(bar_id),
+        )
+        .await;
+    // Not interested in this event type
+    harness
+        .subscribe(&harness.alice_id, WebhookEventType::FlowSucceeded, None)
+        .await;
+
+    // Head changes are reported regardless of whether they come from flows
+    harness
+        .outbox
+        .post_message(
+            MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+            DatasetReferenceMessage::updated(
+                foo_id.clone(),
+                Some(Multihash::from_digest_sha3_256(b"old-slice")),
+                Multihash::from_digest_sha3_256(b"new-slice"),
+            ),
+        )
+        .await
+        .unwrap();
+
+    let messages = harness.delivery_messages();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].subscription_id, alice_head_updated.id);
+    assert_eq!(messages[0].event.dataset_id, foo_id);
+    pretty_assertions::assert_eq!(
+        messages[0].event.payload,
+        WebhookEventPayload::DatasetHeadUpdated(WebhookEventDatasetHeadUpdated {
+            old_head: Some(Multihash::from_digest_sha3_256(b"old-slice")),
+            new_head: Multihash::from_digest_sha3_256(b"new-slice"),
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_flow_succeeded() {
+    let harness = WebhookEventDispatcherHarness::new().await;
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    let bar_id = DatasetID::new_seeded_ed25519(b"bar");
+    harness.set_dataset_owner(&foo_id, &harness.alice_id);
+
+    let flow_succeeded = harness
+        .subscribe(&harness.alice_id, WebhookEventType::FlowSucceeded, None)
+        .await;
+    // Head updates are not derived from flow outcomes
+    harness
+        .subscribe(
+            &harness.alice_id,
+            WebhookEventType::DatasetHeadUpdated,
+            Some(foo_id.clone()),
+        )
+        .await;
+    // Scoped to another dataset
+    harness
+        .subscribe(
+            &harness.alice_id,
+            WebhookEventType::FlowSucceeded,
+            Some(bar_id),
+        )
+        .await;
+
+    let flow_id = harness.add_dataset_flow(&foo_id, DatasetFlowType::Ingest);
+    harness
+        .finish_flow(
+            flow_id,
+            FlowOutcome::Success(FlowResult::DatasetUpdate(FlowResultDatasetUpdate::Changed(
+                FlowResultDatasetUpdateChanged {
+                    old_head: Some(Multihash::from_digest_sha3_256(b"old-slice")),
+                    new_head: Multihash::from_digest_sha3_256(b"new-slice"),
+                },
+            ))),
+        )
+        .await;
+
+    let messages = harness.delivery_messages();
+    pretty_assertions::assert_eq!(
+        messages
+            .iter()
+            .map(|m| (m.subscription_id, m.event.payload.clone()))
+            .collect::<Vec<_>>(),
+        vec![(
+            flow_succeeded.id,
+            WebhookEventPayload::FlowSucceeded(WebhookEventFlowSucceeded {
+                flow_id: 1,
+                flow_type: "INGEST".to_string(),
+            })
+        )]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_flow_failed() {
+    let harness = WebhookEventDispatcherHarness::new().await;
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    harness.set_dataset_owner(&foo_id, &harness.alice_id);
+
+    let flow_failed = harness
+        .subscribe(&harness.alice_id, WebhookEventType::FlowFailed, None)
+        .await;
+    harness
+        .subscribe(&harness.alice_id, WebhookEventType::FlowSucceeded, None)
+        .await;
+
+    let flow_id = harness.add_dataset_flow(&foo_id, DatasetFlowType::ExecuteTransform);
+    harness
+        .finish_flow(flow_id, FlowOutcome::Failed(FlowError::Failed))
+        .await;
+
+    let messages = harness.delivery_messages();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].subscription_id, flow_failed.id);
+    pretty_assertions::assert_eq!(
+        messages[0].event.payload,
+        WebhookEventPayload::FlowFailed(WebhookEventFlowFailed {
+            flow_id: 1,
+            flow_type: "EXECUTE_TRANSFORM".to_string(),
+            reason: "Flow failed".to_string(),
+        })
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_aborted_and_system_flows_are_ignored() {
+    let harness = WebhookEventDispatcherHarness::new().await;
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    harness.set_dataset_owner(&foo_id, &harness.alice_id);
+
+    for event_type in WebhookEventType::all() {
+        harness
+            .subscribe(&harness.alice_id, *event_type, None)
+            .await;
+    }
+
+    let flow_id = harness.add_dataset_flow(&foo_id, DatasetFlowType::Ingest);
+    harness.finish_flow(flow_id, FlowOutcome::Aborted).await;
+
+    let flow_id = harness.add_flow(FlowKey::System(FlowKeySystem::new(SystemFlowType::GC)));
+    harness
+        .finish_flow(flow_id, FlowOutcome::Success(FlowResult::Empty))
+        .await;
+
+    assert!(harness.delivery_messages().is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_dataset_scoped_subscription_requires_read_access() {
+    let harness = WebhookEventDispatcherHarness::new().await;
+
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    harness.set_dataset_owner(&foo_id, &harness.alice_id);
+    harness.set_dataset_reader(&foo_id, &harness.bob_id, true);
+
+    let bob_head_updated = harness
+        .subscribe(
+            &harness.bob_id,
+            WebhookEventType::DatasetHeadUpdated,
+            Some(foo_id.clone()),
+        )
+        .await;
+
+    let update_head = |new_head: &'static [u8]| {
+        let foo_id = foo_id.clone();
+        let outbox = harness.outbox.clone();
+        async move {
+            outbox
+                .post_message(
+                    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                    DatasetReferenceMessage::updated(
+                        foo_id,
+                        None,
+                        Multihash::from_digest_sha3_256(new_head),
+                    ),
+                )
+                .await
+                .unwrap();
+        }
+    };
+
+    update_head(b"slice-1").await;
+
+    // Access is lost after the subscription was created
+    harness.set_dataset_reader(&foo_id, &harness.bob_id, false);
+    update_head(b"slice-2").await;
+
+    let messages = harness.delivery_messages();
+    pretty_assertions::assert_eq!(
+        messages
+            .iter()
+            .map(|m| (m.subscription_id, m.event.payload.clone()))
+            .collect::<Vec<_>>(),
+        vec![(
+            bob_head_updated.id,
+            WebhookEventPayload::DatasetHeadUpdated(WebhookEventDatasetHeadUpdated {
+                old_head: None,
+                new_head: Multihash::from_digest_sha3_256(b"slice-1"),
+            })
+        )]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct WebhookEventDispatcherHarness {
+    catalog: Catalog,
+    outbox: Arc<dyn Outbox>,
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    dataset_ownership_service: Arc<FakeDatasetOwnershipService>,
+    dataset_readers: Arc<FakeDatasetReaders>,
+    flow_query_service: Arc<FakeFlowQueryService>,
+    alice_id: AccountID,
+    bob_id: AccountID,
+}
+
+impl WebhookEventDispatcherHarness {
+    async fn new() -> Self {
+        let catalog = {
+            let mut b = CatalogBuilder::new();
+
+            b.add::<WebhookEventDispatcher>();
+            b.add::<InMemoryWebhookSubscriptionRepository>();
+            b.add::<InMemoryAccountRepository>();
+            b.add::<WebhookDeliveryMessageCollector>();
+
+            b.add_value(Self::mock_dataset_repository());
+            b.bind::<dyn DatasetRepository, MockDatasetRepository>();
+
+            b.add_value(FakeDatasetReaders::default());
+            b.add::<FakeDatasetActionAuthorizer>();
+
+            b.add_value(FakeDatasetOwnershipService::default());
+            b.bind::<dyn DatasetOwnershipService, FakeDatasetOwnershipService>();
+
+            b.add_value(FakeFlowQueryService::default());
+            b.bind::<dyn FlowQueryService, FakeFlowQueryService>();
+
+            b.add_value(SystemTimeSourceStub::new_set(frozen_time_point()));
+            b.bind::<dyn SystemTimeSource, SystemTimeSourceStub>();
+
+            b.add_builder(
+                OutboxImmediateImpl::builder().with_consumer_filter(ConsumerFilter::AllConsumers),
+            );
+            b.bind::<dyn Outbox, OutboxImmediateImpl>();
+
+            register_message_dispatcher::<DatasetLifecycleMessage>(
+                &mut b,
+                MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+            );
+            register_message_dispatcher::<DatasetReferenceMessage>(
+                &mut b,
+                MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+            );
+            register_message_dispatcher::<FlowProgressMessage>(
+                &mut b,
+                MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+            );
+            register_message_dispatcher::<WebhookDeliveryMessage>(
+                &mut b,
+                MESSAGE_PRODUCER_KAMU_WEBHOOK_EVENT_DISPATCHER,
+            );
+
+            b.build()
+        };
+
+        let alice_id = AccountID::new_seeded_ed25519(b"alice");
+        let bob_id = AccountID::new_seeded_ed25519(b"bob");
+
+        let account_repo = catalog.get_one::<dyn AccountRepository>().unwrap();
+        for account in [
+            Account::test(alice_id.clone(), "alice"),
+            Account::test(bob_id.clone(), "bob"),
+        ] {
+            account_repo.create_account(&account).await.unwrap();
+        }
+
+        Self {
+            outbox: catalog.get_one().unwrap(),
+            subscription_repo: catalog.get_one().unwrap(),
+            dataset_ownership_service: catalog.get_one().unwrap(),
+            dataset_readers: catalog.get_one().unwrap(),
+            flow_query_service: catalog.get_one().unwrap(),
+            alice_id,
+            bob_id,
+            catalog,
+        }
+    }
+
+    /// Every dataset referenced by ID exists
+    fn mock_dataset_repository() -> MockDatasetRepository {
+        let mut dataset_repo = MockDatasetRepository::new();
+        dataset_repo
+            .expect_resolve_dataset_ref()
+            .returning(|dataset_ref| match dataset_ref {
+                DatasetRef::ID(dataset_id) => Ok(DatasetHandle::new(
+                    dataset_id.clone(),
+                    DatasetAlias::new(None, DatasetName::new_unchecked("foo")),
+                )),
+                _ => unimplemented!(),
+            });
+        dataset_repo
+    }
+
+    async fn subscribe(
+        &self,
+        account_id: &AccountID,
+        event_type: WebhookEventType,
+        dataset_id: Option<DatasetID>,
+    ) -> WebhookSubscription {
+        let subscription = WebhookSubscription::new(
+            account_id.clone(),
+            Url::parse("https://example.com/hooks").unwrap(),
+            vec![event_type],
+            dataset_id,
+            frozen_time_point(),
+        );
+
+        self.subscription_repo
+            .save_subscription(&subscription)
+            .await
+            .unwrap();
+
+        subscription
+    }
+
+    fn set_dataset_owner(&self, dataset_id: &DatasetID, account_id: &AccountID) {
+        self.dataset_ownership_service
+            .owners_by_dataset
+            .lock()
+            .unwrap()
+            .insert(dataset_id.clone(), vec![account_id.clone()]);
+    }
+
+    fn set_dataset_reader(&self, dataset_id: &DatasetID, account_id: &AccountID, allowed: bool) {
+        let mut readers = self.dataset_readers.readers.lock().unwrap();
+        let reader = (dataset_id.clone(), account_id.clone());
+        if allowed {
+            readers.insert(reader);
+        } else {
+            readers.remove(&reader);
+        }
+    }
+
+    fn add_dataset_flow(&self, dataset_id: &DatasetID, flow_type: DatasetFlowType) -> FlowID {
+        self.add_flow(FlowKeyDataset::new(dataset_id.clone(), flow_type).into())
+    }
+
+    fn add_flow(&self, flow_key: FlowKey) -> FlowID {
+        let mut flows = self.flow_query_service.flows.lock().unwrap();
+
+        let flow_id = FlowID::new(u64::try_from(flows.len()).unwrap() + 1);
+        flows.insert(
+            flow_id,
+            FlowState {
+                flow_id,
+                flow_key,
+                triggers: vec![],
+                start_condition: None,
+                timing: FlowTimingRecords {
+                    scheduled_for_activation_at: None,
+                    awaiting_executor_since: None,
+                    running_since: None,
+                    finished_at: None,
+                },
+                task_ids: vec![],
+                outcome: None,
+                config_snapshot: None,
+            },
+        );
+
+        flow_id
+    }
+
+    async fn finish_flow(&self, flow_id: FlowID, outcome: FlowOutcome) {
+        self.outbox
+            .post_message(
+                MESSAGE_PRODUCER_KAMU_FLOW_PROGRESS_SERVICE,
+                FlowProgressMessage::finished(frozen_time_point(), flow_id, outcome),
+            )
+            .await
+            .unwrap();
+    }
+
+    fn delivery_messages(&self) -> Vec<WebhookDeliveryMessage> {
+        let collector = self
+            .catalog
+            .get_one::<WebhookDeliveryMessageCollector>()
+            .unwrap();
+        let messages = collector.messages.lock().unwrap();
+        messages.clone()
+    }
+}
+
+fn frozen_time_point() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct WebhookDeliveryMessageCollector {
+    messages: Mutex<Vec<WebhookDeliveryMessage>>,
+}
+
+#[component(pub)]
+#[scope(Singleton)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<WebhookDeliveryMessage>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: "WebhookDeliveryMessageCollector",
+    feeding_producers: &[MESSAGE_PRODUCER_KAMU_WEBHOOK_EVENT_DISPATCHER],
+    durability: MessageConsumptionDurability::BestEffort,
+})]
+impl WebhookDeliveryMessageCollector {
+    pub fn new() -> Self {
+        Self {
+            messages: Mutex::new(vec![]),
+        }
+    }
+}
+
+impl MessageConsumer for WebhookDeliveryMessageCollector {}
+
+#[async_trait::async_trait]
+impl MessageConsumerT<WebhookDeliveryMessage> for WebhookDeliveryMessageCollector {
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &WebhookDeliveryMessage,
+    ) -> Result<(), InternalError> {
+        self.messages.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct FakeDatasetOwnershipService {
+    owners_by_dataset: Mutex<HashMap<DatasetID, Vec<AccountID>>>,
+}
+
+#[async_trait::async_trait]
+impl DatasetOwnershipService for FakeDatasetOwnershipService {
+    async fn get_dataset_owners(
+        &self,
+        dataset_id: &DatasetID,
+    ) -> Result<Vec<AccountID>, InternalError> {
+        Ok(self
+            .owners_by_dataset
+            .lock()
+            .unwrap()
+            .get(dataset_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn get_owned_datasets(
+        &self,
+        _account_id: &AccountID,
+    ) -> Result<Vec<DatasetID>, InternalError> {
+        unimplemented!()
+    }
+
+    async fn is_dataset_owned_by(
+        &self,
+        _dataset_id: &DatasetID,
+        _account_id: &AccountID,
+    ) -> Result<bool, InternalError> {
+        unimplemented!()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct FakeDatasetReaders {
+    readers: Mutex<HashSet<(DatasetID, AccountID)>>,
+}
+
+/// Only allows reading datasets to the accounts registered in
+/// [`FakeDatasetReaders`]
+#[component]
+#[interface(dyn DatasetActionAuthorizer)]
+struct FakeDatasetActionAuthorizer {
+    current_account_subject: Arc<CurrentAccountSubject>,
+    dataset_readers: Arc<FakeDatasetReaders>,
+}
+
+#[async_trait::async_trait]
+impl DatasetActionAuthorizer for FakeDatasetActionAuthorizer {
+    async fn check_action_allowed(
+        &self,
+        dataset_handle: &DatasetHandle,
+        action: DatasetAction,
+    ) -> Result<(), DatasetActionUnauthorizedError> {
+        let CurrentAccountSubject::Logged(account) = self.current_account_subject.as_ref() else {
+            unimplemented!()
+        };
+
+        let reader = (dataset_handle.id.clone(), account.account_id.clone());
+        if action == DatasetAction::Read
+            && self
+                .dataset_readers
+                .readers
+                .lock()
+                .unwrap()
+                .contains(&reader)
+        {
+            Ok(())
+        } else {
+            Err(DatasetActionUnauthorizedError::Access(
+                AccessError::Forbidden(
+                    DatasetActionNotEnoughPermissionsError {
+                        action,
+                        dataset_ref: dataset_handle.as_local_ref(),
+                    }
+                    .into(),
+                ),
+            ))
+        }
+    }
+
+    async fn get_allowed_actions(&self, _dataset_handle: &DatasetHandle) -> HashSet<DatasetAction> {
+        unimplemented!()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Only supports reading flows, which is all the dispatcher needs
+#[derive(Default)]
+struct FakeFlowQueryService {
+    flows: Mutex<HashMap<FlowID, FlowState>>,
+}
+
+#[async_trait::async_trait]
+impl FlowQueryService for FakeFlowQueryService {
+    async fn list_all_flows_by_dataset(
+        &self,
+        _dataset_id: &DatasetID,
+        _filters: DatasetFlowFilters,
+        _pagination: PaginationOpts,
+    ) -> Result<FlowStateListing, ListFlowsByDatasetError> {
+        unimplemented!()
+    }
+
+    async fn list_all_flow_initiators_by_dataset(
+        &self,
+        _dataset_id: &DatasetID,
+    ) -> Result<FlowInitiatorListing, ListFlowsByDatasetError> {
+        unimplemented!()
+    }
+
+    async fn list_all_datasets_with_flow_by_account(
+        &self,
+        _account_id: &AccountID,
+    ) -> Result<FlowDatasetListing, ListFlowsByDatasetError> {
+        unimplemented!()
+    }
+
+    async fn list_all_flows_by_account(
+        &self,
+        _account_id: &AccountID,
+        _filters: AccountFlowFilters,
+        _pagination: PaginationOpts,
+    ) -> Result<FlowStateListing, ListFlowsByDatasetError> {
+        unimplemented!()
+    }
+
+    async fn list_all_system_flows(
+        &self,
+        _filters: SystemFlowFilters,
+        _pagination: PaginationOpts,
+    ) -> Result<FlowStateListing, ListSystemFlowsError> {
+        unimplemented!()
+    }
+
+    async fn list_all_flows(
+        &self,
+        _pagination: PaginationOpts,
+    ) -> Result<FlowStateListing, ListFlowsError> {
+        unimplemented!()
+    }
+
+    async fn get_flow(&self, flow_id: FlowID) -> Result<FlowState, GetFlowError> {
+        Ok(self.flows.lock().unwrap().get(&flow_id).cloned().unwrap())
+    }
+
+    async fn trigger_manual_flow(
+        &self,
+        _trigger_time: DateTime<Utc>,
+        _flow_key: FlowKey,
+        _initiator_account_id: AccountID,
+        _flow_run_snapshots_maybe: Option<FlowConfigurationSnapshot>,
+    ) -> Result<FlowState, RequestFlowError> {
+        unimplemented!()
+    }
+
+    async fn cancel_scheduled_tasks(
+        &self,
+        _flow_id: FlowID,
+    ) -> Result<FlowState, CancelScheduledTasksError> {
+        unimplemented!()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
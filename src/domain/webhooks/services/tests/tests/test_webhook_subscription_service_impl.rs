@@ -0,0 +1,369 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use database_common::PaginationOpts;
+use dill::{Catalog, CatalogBuilder};
+use kamu_webhooks::*;
+use kamu_webhooks_inmem::{
+    InMemoryWebhookDeliveryRepository,
+    InMemoryWebhookSubscriptionRepository,
+};
+use kamu_webhooks_services::WebhookSubscriptionServiceImpl;
+use opendatafabric::{AccountID, DatasetID};
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_create_subscription() {
+    let harness = WebhookSubscriptionServiceHarness::new();
+
+    let subscription = harness
+        .subscription_service
+        .create_subscription(
+            &harness.alice_id,
+            "https://example.com/hooks",
+            vec![
+                WebhookEventType::FlowFailed,
+                WebhookEventType::DatasetHeadUpdated,
+                WebhookEventType::FlowFailed,
+            ],
+            Some(DatasetID::new_seeded_ed25519(b"foo")),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(subscription.account_id, harness.alice_id);
+    assert_eq!(
+        subscription.target_url.as_str(),
+        "https://example.com/hooks"
+    );
+    assert_eq!(
+        subscription.event_types,
+        vec![
+            WebhookEventType::FlowFailed,
+            WebhookEventType::DatasetHeadUpdated
+        ]
+    );
+    assert!(subscription.enabled);
+    assert!(!subscription.secret.is_empty());
+
+    pretty_assertions::assert_eq!(
+        harness
+            .subscription_service
+            .list_subscriptions(&harness.alice_id)
+            .await
+            .unwrap(),
+        vec![subscription]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_create_subscription_validation() {
+    let harness = WebhookSubscriptionServiceHarness::new();
+
+    for target_url in [
+        "not a url",
+        "http://example.com/hooks",
+        "ftp://example.com/hooks",
+        // Internal network endpoints
+        "https://localhost/hooks",
+        "http://localhost:8080/hooks",
+        "https://127.0.0.1/hooks",
+        "https://10.0.0.5/hooks",
+        "https://172.16.1.1/hooks",
+        "https://192.168.1.1/hooks",
+        "https://169.254.169.254/latest/meta-data",
+        "https://0.0.0.0/hooks",
+        "https://[::1]/hooks",
+        "https://[fd00::1]/hooks",
+        "https://[fe80::1]/hooks",
+        "https://[::ffff:10.0.0.5]/hooks",
+    ] {
+        assert_matches!(
+            harness
+                .subscription_service
+                .create_subscription(
+                    &harness.alice_id,
+                    target_url,
+                    vec![WebhookEventType::FlowFailed],
+                    None,
+                )
+                .await,
+            Err(CreateWebhookSubscriptionError::InvalidTargetUrl(_)),
+            "{target_url}"
+        );
+    }
+
+    assert_matches!(
+        harness
+            .subscription_service
+            .create_subscription(&harness.alice_id, "https://example.com/hooks", vec![], None,)
+            .await,
+        Err(CreateWebhookSubscriptionError::NoEventTypes(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_create_subscription_for_local_endpoints_when_allowed() {
+    let harness = WebhookSubscriptionServiceHarness::with_config(WebhookDeliveryConfig {
+        allow_insecure_localhost: true,
+        ..Default::default()
+    });
+
+    // Plain HTTP is accepted for local endpoints
+    for target_url in [
+        "http://localhost:8080/hooks",
+        "http://127.0.0.1/hooks",
+        "https://[::1]/hooks",
+    ] {
+        assert_matches!(
+            harness
+                .subscription_service
+                .create_subscription(
+                    &harness.alice_id,
+                    target_url,
+                    vec![WebhookEventType::FlowFailed],
+                    None,
+                )
+                .await,
+            Ok(_),
+            "{target_url}"
+        );
+    }
+
+    // Other internal addresses stay forbidden
+    for target_url in ["http://192.168.1.1/hooks", "https://169.254.169.254/hooks"] {
+        assert_matches!(
+            harness
+                .subscription_service
+                .create_subscription(
+                    &harness.alice_id,
+                    target_url,
+                    vec![WebhookEventType::FlowFailed],
+                    None,
+                )
+                .await,
+            Err(CreateWebhookSubscriptionError::InvalidTargetUrl(_)),
+            "{target_url}"
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_subscriptions_of_other_accounts_are_not_visible() {
+    let harness = WebhookSubscriptionServiceHarness::new();
+
+    let subscription = harness.create_subscription(&harness.alice_id).await;
+
+    assert!(harness
+        .subscription_service
+        .list_subscriptions(&harness.bob_id)
+        .await
+        .unwrap()
+        .is_empty());
+    assert_matches!(
+        harness
+            .subscription_service
+            .get_subscription(&harness.bob_id, &subscription.id)
+            .await,
+        Err(GetWebhookSubscriptionError::NotFound(_))
+    );
+    assert_matches!(
+        harness
+            .subscription_service
+            .set_subscription_enabled(&harness.bob_id, &subscription.id, false)
+            .await,
+        Err(UpdateWebhookSubscriptionError::NotFound(_))
+    );
+    assert_matches!(
+        harness
+            .subscription_service
+            .remove_subscription(&harness.bob_id, &subscription.id)
+            .await,
+        Err(DeleteWebhookSubscriptionError::NotFound(_))
+    );
+    assert_matches!(
+        harness
+            .subscription_service
+            .list_deliveries(&harness.bob_id, &subscription.id, None)
+            .await,
+        Err(GetWebhookSubscriptionError::NotFound(_))
+    );
+
+    assert_matches!(
+        harness
+            .subscription_service
+            .get_subscription(&harness.alice_id, &subscription.id)
+            .await,
+        Ok(s) if s.enabled
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_pause_and_resume_subscription() {
+    let harness = WebhookSubscriptionServiceHarness::new();
+
+    let subscription = harness.create_subscription(&harness.alice_id).await;
+
+    assert_matches!(
+        harness
+            .subscription_service
+            .set_subscription_enabled(&harness.alice_id, &subscription.id, false)
+            .await,
+        Ok(s) if !s.enabled
+    );
+    assert_matches!(
+        harness
+            .subscription_service
+            .get_subscription(&harness.alice_id, &subscription.id)
+            .await,
+        Ok(s) if !s.enabled
+    );
+
+    assert_matches!(
+        harness
+            .subscription_service
+            .set_subscription_enabled(&harness.alice_id, &subscription.id, true)
+            .await,
+        Ok(s) if s.enabled
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_remove_subscription_with_deliveries() {
+    let harness = WebhookSubscriptionServiceHarness::new();
+
+    let subscription = harness.create_subscription(&harness.alice_id).await;
+
+    let delivery_repo = harness
+        .catalog
+        .get_one::<dyn WebhookDeliveryRepository>()
+        .unwrap();
+    for attempt in 1..=2 {
+        delivery_repo
+            .save_delivery(&WebhookDelivery {
+                id: Uuid::new_v4(),
+                subscription_id: subscription.id,
+                event_id: Uuid::new_v4(),
+                event_type: WebhookEventType::FlowFailed,
+                attempt,
+                requested_at: Utc::now(),
+                response_status: Some(500),
+                error: Some("Endpoint responded with status 500".to_string()),
+            })
+            .await
+            .unwrap();
+    }
+
+    let listing = harness
+        .subscription_service
+        .list_deliveries(
+            &harness.alice_id,
+            &subscription.id,
+            Some(PaginationOpts {
+                offset: 0,
+                limit: 1,
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(listing.total_count, 2);
+    assert_eq!(listing.list.len(), 1);
+
+    harness
+        .subscription_service
+        .remove_subscription(&harness.alice_id, &subscription.id)
+        .await
+        .unwrap();
+
+    assert_matches!(
+        harness
+            .subscription_service
+            .get_subscription(&harness.alice_id, &subscription.id)
+            .await,
+        Err(GetWebhookSubscriptionError::NotFound(_))
+    );
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_count_by_subscription(&subscription.id)
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct WebhookSubscriptionServiceHarness {
+    catalog: Catalog,
+    subscription_service: Arc<dyn WebhookSubscriptionService>,
+    alice_id: AccountID,
+    bob_id: AccountID,
+}
+
+impl WebhookSubscriptionServiceHarness {
+    fn new() -> Self {
+        Self::with_config(WebhookDeliveryConfig::default())
+    }
+
+    fn with_config(config: WebhookDeliveryConfig) -> Self {
+        let catalog = {
+            let mut b = CatalogBuilder::new();
+
+            b.add::<WebhookSubscriptionServiceImpl>();
+            b.add_value(config);
+            b.add::<InMemoryWebhookSubscriptionRepository>();
+            b.add::<InMemoryWebhookDeliveryRepository>();
+
+            b.add_value(SystemTimeSourceStub::new_set(
+                Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap(),
+            ));
+            b.bind::<dyn SystemTimeSource, SystemTimeSourceStub>();
+
+            b.build()
+        };
+
+        Self {
+            subscription_service: catalog.get_one().unwrap(),
+            alice_id: AccountID::new_seeded_ed25519(b"alice"),
+            bob_id: AccountID::new_seeded_ed25519(b"bob"),
+            catalog,
+        }
+    }
+
+    async fn create_subscription(&self, account_id: &AccountID) -> WebhookSubscription {
+        self.subscription_service
+            .create_subscription(
+                account_id,
+                "https://example.com/hooks",
+                vec![WebhookEventType::FlowFailed],
+                None,
+            )
+            .await
+            .unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -162,3 +162,44 @@ pub async fn test_compact_verify(kamu: KamuCliPuppet) {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_compact_soft(kamu: KamuCliPuppet) {
+    let dataset_name = DatasetName::new_unchecked("player-scores");
+
+    kamu.execute_with_input(["add", "--stdin"], DATASET_ROOT_PLAYER_SCORES_SNAPSHOT_STR)
+        .await
+        .success();
+
+    kamu.ingest_data(
+        &dataset_name,
+        DATASET_ROOT_PLAYER_SCORES_INGEST_DATA_NDJSON_CHUNK_1,
+    )
+    .await;
+    kamu.ingest_data(
+        &dataset_name,
+        DATASET_ROOT_PLAYER_SCORES_INGEST_DATA_NDJSON_CHUNK_2,
+    )
+    .await;
+
+    let blocks_before_compacting = kamu.list_blocks(&dataset_name).await;
+
+    kamu.assert_success_command_execution(
+        ["system", "compact", dataset_name.as_str(), "--soft"],
+        None,
+        Some(["1 dataset(s) were compacted"]),
+    )
+    .await;
+
+    // History is left intact
+    let blocks_after_compacting = kamu.list_blocks(&dataset_name).await;
+    assert_eq!(blocks_before_compacting, blocks_after_compacting);
+
+    kamu.assert_success_command_execution(
+        ["verify", dataset_name.as_str()],
+        None,
+        Some(["1 dataset(s) are valid"]),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -46,6 +46,8 @@ impl MySqlAccessTokenRepository {
                     token_hash as "token_hash: _",
                     created_at,
                     revoked_at,
+                    expires_at,
+                    scopes,
                     account_id as "account_id: _"
                 FROM access_tokens
                 WHERE id = ?
@@ -56,7 +58,7 @@ impl MySqlAccessTokenRepository {
         .await
         .int_err()?;
 
-        Ok(access_token_row_maybe.map(Into::into))
+        access_token_row_maybe.map(TryInto::try_into).transpose()
     }
 }
 
@@ -70,15 +72,20 @@ impl AccessTokenRepository for MySqlAccessTokenRepository {
 
         let connection_mut = tr.connection_mut().await?;
 
+        let scopes =
+            (!access_token.scopes.is_unrestricted()).then(|| access_token.scopes.to_string());
+
         sqlx::query!(
             r#"
-              INSERT INTO access_tokens (id, token_name, token_hash, created_at, account_id)
-                  VALUES (?, ?, ?, ?, ?)
+              INSERT INTO access_tokens (id, token_name, token_hash, created_at, expires_at, scopes, account_id)
+                  VALUES (?, ?, ?, ?, ?, ?, ?)
               "#,
             access_token.id.to_string(),
             access_token.token_name,
             access_token.token_hash.to_vec(),
             access_token.created_at,
+            access_token.expires_at,
+            scopes,
             access_token.account_id.to_string(),
         )
         .execute(connection_mut)
@@ -156,6 +163,8 @@ impl AccessTokenRepository for MySqlAccessTokenRepository {
                     token_hash as "token_hash: _",
                     created_at,
                     revoked_at,
+                    expires_at,
+                    scopes,
                     account_id as "account_id: _"
               FROM access_tokens
               WHERE account_id = ?
@@ -169,7 +178,12 @@ impl AccessTokenRepository for MySqlAccessTokenRepository {
         .await
         .int_err()?;
 
-        Ok(access_token_rows.into_iter().map(Into::into).collect())
+        let access_tokens = access_token_rows
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, InternalError>>()?;
+
+        Ok(access_tokens)
     }
 
     async fn mark_revoked(
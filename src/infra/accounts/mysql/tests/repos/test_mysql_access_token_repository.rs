@@ -31,6 +31,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = mysql,
+    fixture = kamu_accounts_repo_tests::test_insert_and_locate_scoped_access_token,
+    harness = MySqlAccessTokenRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 database_transactional_test!(
     storage = mysql,
     fixture = kamu_accounts_repo_tests::test_insert_and_locate_multiple_access_tokens,
@@ -48,6 +48,8 @@ impl PostgresAccessTokenRepository {
                     token_hash,
                     created_at,
                     revoked_at,
+                    expires_at,
+                    scopes,
                     account_id as "account_id: _"
                 FROM access_tokens
                 WHERE id = $1
@@ -58,7 +60,7 @@ impl PostgresAccessTokenRepository {
         .await
         .int_err()?;
 
-        Ok(access_token_row_maybe.map(Into::into))
+        access_token_row_maybe.map(TryInto::try_into).transpose()
     }
 }
 
@@ -72,15 +74,20 @@ impl AccessTokenRepository for PostgresAccessTokenRepository {
 
         let connection_mut = tr.connection_mut().await?;
 
+        let scopes =
+            (!access_token.scopes.is_unrestricted()).then(|| access_token.scopes.to_string());
+
         sqlx::query!(
             r#"
-                INSERT INTO access_tokens (id, token_name, token_hash, created_at, account_id)
-                    VALUES ($1, $2, $3, $4, $5)
+                INSERT INTO access_tokens (id, token_name, token_hash, created_at, expires_at, scopes, account_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
                 "#,
             access_token.id,
             access_token.token_name,
             &access_token.token_hash,
             access_token.created_at,
+            access_token.expires_at,
+            scopes,
             access_token.account_id.to_string(),
         )
         .execute(connection_mut)
@@ -155,6 +162,8 @@ impl AccessTokenRepository for PostgresAccessTokenRepository {
                     token_hash,
                     created_at,
                     revoked_at,
+                    expires_at,
+                    scopes,
                     account_id as "account_id: _"
                 FROM access_tokens
                 WHERE account_id = $1
@@ -168,7 +177,12 @@ impl AccessTokenRepository for PostgresAccessTokenRepository {
         .await
         .int_err()?;
 
-        Ok(access_token_rows.into_iter().map(Into::into).collect())
+        let access_tokens = access_token_rows
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, InternalError>>()?;
+
+        Ok(access_tokens)
     }
 
     async fn mark_revoked(
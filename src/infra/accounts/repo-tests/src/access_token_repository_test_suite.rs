@@ -54,6 +54,43 @@ pub async fn test_insert_and_locate_access_token(catalog: &Catalog) {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub async fn test_insert_and_locate_scoped_access_token(catalog: &Catalog) {
+    let dataset_id = opendatafabric::DatasetID::new_seeded_ed25519(b"foo");
+    let access_token = AccessToken {
+        expires_at: Some(Utc::now().round_subsecs(6) + chrono::Duration::hours(1)),
+        scopes: AccessTokenScopes::Restricted(vec![
+            AccessTokenScope::Read,
+            AccessTokenScope::Write(Some(dataset_id)),
+            AccessTokenScope::FlowTrigger(None),
+            AccessTokenScope::Admin,
+        ]),
+        ..make_test_access_token("foo", None, "wasya")
+    };
+    let account = make_test_account(
+        "wasya",
+        kamu_adapter_oauth::PROVIDER_GITHUB,
+        GITHUB_ACCOUNT_ID_WASYA,
+    );
+
+    let account_repo = catalog.get_one::<dyn AccountRepository>().unwrap();
+    let access_token_repo = catalog.get_one::<dyn AccessTokenRepository>().unwrap();
+
+    account_repo.create_account(&account).await.unwrap();
+    access_token_repo
+        .save_access_token(&access_token)
+        .await
+        .unwrap();
+
+    let db_access_token = access_token_repo
+        .get_token_by_id(&access_token.id)
+        .await
+        .unwrap();
+
+    assert_eq!(db_access_token, access_token);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub async fn test_insert_and_locate_multiple_access_tokens(catalog: &Catalog) {
     let foo_access_token = make_test_access_token("foo", None, "wasya");
     let bar_access_token = make_test_access_token("bar", None, "wasya");
@@ -10,7 +10,7 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 use chrono::{SubsecRound, Utc};
-use kamu_accounts::AccessToken;
+use kamu_accounts::{AccessToken, AccessTokenScopes};
 use opendatafabric::AccountID;
 use rand::Rng;
 use uuid::Uuid;
@@ -26,6 +26,8 @@ pub(crate) fn make_test_access_token(
         token_hash: token_hash_maybe.unwrap_or(generate_random_bytes()),
         created_at: Utc::now().round_subsecs(6),
         revoked_at: None,
+        expires_at: None,
+        scopes: AccessTokenScopes::Unrestricted,
         account_id: AccountID::new_seeded_ed25519(account_name.as_bytes()),
     }
 }
@@ -48,6 +48,8 @@ impl SqliteAccessTokenRepository {
                     token_hash,
                     created_at as "created_at: _",
                     revoked_at as "revoked_at: _",
+                    expires_at as "expires_at: _",
+                    scopes,
                     account_id as "account_id: _"
                 FROM access_tokens
                 WHERE id = $1
@@ -58,7 +60,7 @@ impl SqliteAccessTokenRepository {
         .await
         .int_err()?;
 
-        Ok(access_token_row_maybe.map(Into::into))
+        access_token_row_maybe.map(TryInto::try_into).transpose()
     }
 }
 
@@ -76,17 +78,22 @@ impl AccessTokenRepository for SqliteAccessTokenRepository {
         let token_name = access_token.token_name.clone();
         let token_hash = access_token.token_hash.as_slice();
         let crated_at = access_token.created_at;
+        let expires_at = access_token.expires_at;
+        let scopes =
+            (!access_token.scopes.is_unrestricted()).then(|| access_token.scopes.to_string());
         let account_id = access_token.account_id.to_string();
 
         sqlx::query!(
             r#"
-                INSERT INTO access_tokens (id, token_name, token_hash, created_at, account_id)
-                    VALUES ($1, $2, $3, $4, $5)
+                INSERT INTO access_tokens (id, token_name, token_hash, created_at, expires_at, scopes, account_id)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
                 "#,
             token_id,
             token_name,
             token_hash,
             crated_at,
+            expires_at,
+            scopes,
             account_id,
         )
         .execute(connection_mut)
@@ -171,6 +178,8 @@ impl AccessTokenRepository for SqliteAccessTokenRepository {
                     token_hash,
                     created_at as "created_at: _",
                     revoked_at as "revoked_at: _",
+                    expires_at as "expires_at: _",
+                    scopes,
                     account_id as "account_id: _"
                 FROM access_tokens
                 WHERE account_id = $1
@@ -184,7 +193,12 @@ impl AccessTokenRepository for SqliteAccessTokenRepository {
         .await
         .int_err()?;
 
-        Ok(access_token_rows.into_iter().map(Into::into).collect())
+        let access_tokens = access_token_rows
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, InternalError>>()?;
+
+        Ok(access_tokens)
     }
 
     async fn mark_revoked(
@@ -0,0 +1,85 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::{Arc, Mutex};
+
+use database_common::PaginationOpts;
+use dill::*;
+use internal_error::InternalError;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InMemoryAuditEventRepository {
+    state: Arc<Mutex<State>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct State {
+    events: Vec<AuditEvent>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn AuditEventRepository)]
+#[scope(Singleton)]
+impl InMemoryAuditEventRepository {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl AuditEventRepository for InMemoryAuditEventRepository {
+    async fn save_event(&self, event: &AuditEvent) -> Result<(), InternalError> {
+        let mut guard = self.state.lock().unwrap();
+
+        guard.events.push(event.clone());
+
+        Ok(())
+    }
+
+    async fn get_events_count(&self, filter: &AuditEventFilter) -> Result<usize, InternalError> {
+        let guard = self.state.lock().unwrap();
+
+        Ok(guard.events.iter().filter(|e| filter.matches(e)).count())
+    }
+
+    async fn get_events(
+        &self,
+        filter: &AuditEventFilter,
+        pagination: &PaginationOpts,
+    ) -> Result<Vec<AuditEvent>, InternalError> {
+        let guard = self.state.lock().unwrap();
+
+        let mut events: Vec<_> = guard
+            .events
+            .iter()
+            .filter(|e| filter.matches(e))
+            .cloned()
+            .collect();
+        events.sort_by(|a, b| b.event_time.cmp(&a.event_time));
+
+        Ok(events
+            .into_iter()
+            .skip(pagination.offset)
+            .take(pagination.limit)
+            .collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,152 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::{PaginationOpts, TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use opendatafabric::{AccountID, DatasetID};
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct PostgresAuditEventRepository {
+    transaction: TransactionRefT<sqlx::Postgres>,
+}
+
+#[component(pub)]
+#[interface(dyn AuditEventRepository)]
+impl PostgresAuditEventRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditEventRepository for PostgresAuditEventRepository {
+    async fn save_event(&self, event: &AuditEvent) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO audit_events (id, event_time, account_id, action, dataset_id, details)
+                VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            event.id,
+            event.event_time,
+            event.account_id.as_ref().map(ToString::to_string),
+            event.action.as_str(),
+            event.dataset_id.as_ref().map(ToString::to_string),
+            event.details,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn get_events_count(&self, filter: &AuditEventFilter) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!" FROM audit_events
+                WHERE (cast($1 as VARCHAR) IS NULL OR account_id = $1)
+                AND (cast($2 as VARCHAR) IS NULL OR dataset_id = $2)
+                AND (cast($3 as VARCHAR) IS NULL OR action = $3)
+                AND (cast($4 as TIMESTAMPTZ) IS NULL OR event_time >= $4)
+                AND (cast($5 as TIMESTAMPTZ) IS NULL OR event_time < $5)
+            "#,
+            filter.account_id.as_ref().map(ToString::to_string),
+            filter.dataset_id.as_ref().map(ToString::to_string),
+            filter.action.map(|action| action.as_str()),
+            filter.from_time,
+            filter.to_time,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(usize::try_from(count).unwrap())
+    }
+
+    async fn get_events(
+        &self,
+        filter: &AuditEventFilter,
+        pagination: &PaginationOpts,
+    ) -> Result<Vec<AuditEvent>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let rows = sqlx::query_as!(
+            AuditEventRowModel,
+            r#"
+            SELECT id, event_time, account_id AS "account_id: _", action, dataset_id AS "dataset_id: _", details
+                FROM audit_events
+                WHERE (cast($1 as VARCHAR) IS NULL OR account_id = $1)
+                AND (cast($2 as VARCHAR) IS NULL OR dataset_id = $2)
+                AND (cast($3 as VARCHAR) IS NULL OR action = $3)
+                AND (cast($4 as TIMESTAMPTZ) IS NULL OR event_time >= $4)
+                AND (cast($5 as TIMESTAMPTZ) IS NULL OR event_time < $5)
+                ORDER BY event_time DESC
+                LIMIT $6 OFFSET $7
+            "#,
+            filter.account_id.as_ref().map(ToString::to_string),
+            filter.dataset_id.as_ref().map(ToString::to_string),
+            filter.action.map(|action| action.as_str()),
+            filter.from_time,
+            filter.to_time,
+            i64::try_from(pagination.limit).unwrap(),
+            i64::try_from(pagination.offset).unwrap(),
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct AuditEventRowModel {
+    id: Uuid,
+    event_time: DateTime<Utc>,
+    account_id: Option<AccountID>,
+    action: String,
+    dataset_id: Option<DatasetID>,
+    details: Option<String>,
+}
+
+impl TryFrom<AuditEventRowModel> for AuditEvent {
+    type Error = InternalError;
+
+    fn try_from(row: AuditEventRowModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            event_time: row.event_time,
+            account_id: row.account_id,
+            action: row.action.parse::<AuditAction>().int_err()?,
+            dataset_id: row.dataset_id,
+            details: row.details,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
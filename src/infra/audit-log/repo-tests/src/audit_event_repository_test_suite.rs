@@ -0,0 +1,243 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use database_common::PaginationOpts;
+use dill::Catalog;
+use kamu_audit_log::{AuditAction, AuditEvent, AuditEventFilter, AuditEventRepository};
+use opendatafabric::{AccountID, DatasetID};
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_no_events(catalog: &Catalog) {
+    let audit_event_repo = catalog.get_one::<dyn AuditEventRepository>().unwrap();
+
+    let filter = AuditEventFilter::default();
+
+    assert_eq!(audit_event_repo.get_events_count(&filter).await.unwrap(), 0);
+    assert!(audit_event_repo
+        .get_events(&filter, &all_events())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_save_and_get_events(catalog: &Catalog) {
+    let audit_event_repo = catalog.get_one::<dyn AuditEventRepository>().unwrap();
+
+    let start = Utc::now().round_subsecs(6);
+    let alice_id = AccountID::new_seeded_ed25519(b"alice");
+
+    let renamed_event = new_event(
+        start,
+        Some(&alice_id),
+        AuditAction::DatasetRenamed,
+        Some(&DatasetID::new_seeded_ed25519(b"foo")),
+        Some("Renamed dataset 'foo' to 'bar'"),
+    );
+    let token_event = new_event(
+        start + Duration::seconds(1),
+        Some(&alice_id),
+        AuditAction::AccessTokenCreated,
+        None,
+        None,
+    );
+    let anonymous_event = new_event(
+        start + Duration::seconds(2),
+        None,
+        AuditAction::DatasetReset,
+        Some(&DatasetID::new_seeded_ed25519(b"bar")),
+        None,
+    );
+
+    for event in [&renamed_event, &token_event, &anonymous_event] {
+        audit_event_repo.save_event(event).await.unwrap();
+    }
+
+    let filter = AuditEventFilter::default();
+
+    assert_eq!(audit_event_repo.get_events_count(&filter).await.unwrap(), 3);
+
+    // The most recent events go first
+    assert_eq!(
+        audit_event_repo
+            .get_events(&filter, &all_events())
+            .await
+            .unwrap(),
+        vec![
+            anonymous_event.clone(),
+            token_event.clone(),
+            renamed_event.clone()
+        ]
+    );
+    assert_eq!(
+        audit_event_repo
+            .get_events(
+                &filter,
+                &PaginationOpts {
+                    offset: 1,
+                    limit: 1,
+                }
+            )
+            .await
+            .unwrap(),
+        vec![token_event]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_filter_events(catalog: &Catalog) {
+    let audit_event_repo = catalog.get_one::<dyn AuditEventRepository>().unwrap();
+
+    let start = Utc::now().round_subsecs(6);
+    let alice_id = AccountID::new_seeded_ed25519(b"alice");
+    let bob_id = AccountID::new_seeded_ed25519(b"bob");
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    let bar_id = DatasetID::new_seeded_ed25519(b"bar");
+
+    let alice_renamed_foo = new_event(
+        start,
+        Some(&alice_id),
+        AuditAction::DatasetRenamed,
+        Some(&foo_id),
+        None,
+    );
+    let bob_updated_foo_flow = new_event(
+        start + Duration::minutes(1),
+        Some(&bob_id),
+        AuditAction::FlowConfigurationUpdated,
+        Some(&foo_id),
+        None,
+    );
+    let alice_deleted_bar = new_event(
+        start + Duration::minutes(2),
+        Some(&alice_id),
+        AuditAction::DatasetDeleted,
+        Some(&bar_id),
+        None,
+    );
+    let bob_deleted_foo = new_event(
+        start + Duration::minutes(3),
+        Some(&bob_id),
+        AuditAction::DatasetDeleted,
+        Some(&foo_id),
+        None,
+    );
+
+    for event in [
+        &alice_renamed_foo,
+        &bob_updated_foo_flow,
+        &alice_deleted_bar,
+        &bob_deleted_foo,
+    ] {
+        audit_event_repo.save_event(event).await.unwrap();
+    }
+
+    for (filter, expected_events) in [
+        (
+            AuditEventFilter {
+                account_id: Some(alice_id.clone()),
+                ..Default::default()
+            },
+            vec![alice_deleted_bar.clone(), alice_renamed_foo.clone()],
+        ),
+        (
+            AuditEventFilter {
+                dataset_id: Some(foo_id.clone()),
+                ..Default::default()
+            },
+            vec![
+                bob_deleted_foo.clone(),
+                bob_updated_foo_flow.clone(),
+                alice_renamed_foo.clone(),
+            ],
+        ),
+        (
+            AuditEventFilter {
+                action: Some(AuditAction::DatasetDeleted),
+                ..Default::default()
+            },
+            vec![bob_deleted_foo.clone(), alice_deleted_bar.clone()],
+        ),
+        (
+            AuditEventFilter {
+                from_time: Some(start + Duration::minutes(1)),
+                to_time: Some(start + Duration::minutes(3)),
+                ..Default::default()
+            },
+            vec![alice_deleted_bar.clone(), bob_updated_foo_flow.clone()],
+        ),
+        (
+            AuditEventFilter {
+                account_id: Some(bob_id.clone()),
+                dataset_id: Some(foo_id.clone()),
+                action: Some(AuditAction::DatasetDeleted),
+                from_time: Some(start),
+                to_time: None,
+            },
+            vec![bob_deleted_foo.clone()],
+        ),
+        (
+            AuditEventFilter {
+                account_id: Some(alice_id.clone()),
+                action: Some(AuditAction::FlowConfigurationUpdated),
+                ..Default::default()
+            },
+            vec![],
+        ),
+    ] {
+        assert_eq!(
+            audit_event_repo.get_events_count(&filter).await.unwrap(),
+            expected_events.len(),
+            "{filter:?}"
+        );
+        assert_eq!(
+            audit_event_repo
+                .get_events(&filter, &all_events())
+                .await
+                .unwrap(),
+            expected_events,
+            "{filter:?}"
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn new_event(
+    event_time: DateTime<Utc>,
+    account_id: Option<&AccountID>,
+    action: AuditAction,
+    dataset_id: Option<&DatasetID>,
+    details: Option<&str>,
+) -> AuditEvent {
+    AuditEvent {
+        id: Uuid::new_v4(),
+        event_time,
+        account_id: account_id.cloned(),
+        action,
+        dataset_id: dataset_id.cloned(),
+        details: details.map(ToString::to_string),
+    }
+}
+
+fn all_events() -> PaginationOpts {
+    PaginationOpts {
+        offset: 0,
+        limit: 100,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,167 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::{PaginationOpts, TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use opendatafabric::{AccountID, DatasetID};
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SqliteAuditEventRepository {
+    transaction: TransactionRefT<sqlx::Sqlite>,
+}
+
+#[component(pub)]
+#[interface(dyn AuditEventRepository)]
+impl SqliteAuditEventRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditEventRepository for SqliteAuditEventRepository {
+    async fn save_event(&self, event: &AuditEvent) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let id = event.id.to_string();
+        let account_id = event.account_id.as_ref().map(ToString::to_string);
+        let action = event.action.as_str();
+        let dataset_id = event.dataset_id.as_ref().map(ToString::to_string);
+
+        sqlx::query!(
+            r#"
+            INSERT INTO audit_events (id, event_time, account_id, action, dataset_id, details)
+                VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            id,
+            event.event_time,
+            account_id,
+            action,
+            dataset_id,
+            event.details,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn get_events_count(&self, filter: &AuditEventFilter) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let account_id = filter.account_id.as_ref().map(ToString::to_string);
+        let dataset_id = filter.dataset_id.as_ref().map(ToString::to_string);
+        let action = filter.action.map(|action| action.as_str());
+
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS count FROM audit_events
+                WHERE (cast($1 as TEXT) IS NULL OR account_id = $1)
+                AND (cast($2 as TEXT) IS NULL OR dataset_id = $2)
+                AND (cast($3 as TEXT) IS NULL OR action = $3)
+                AND ($4 IS NULL OR event_time >= $4)
+                AND ($5 IS NULL OR event_time < $5)
+            "#,
+            account_id,
+            dataset_id,
+            action,
+            filter.from_time,
+            filter.to_time,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(usize::try_from(count).unwrap())
+    }
+
+    async fn get_events(
+        &self,
+        filter: &AuditEventFilter,
+        pagination: &PaginationOpts,
+    ) -> Result<Vec<AuditEvent>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let account_id = filter.account_id.as_ref().map(ToString::to_string);
+        let dataset_id = filter.dataset_id.as_ref().map(ToString::to_string);
+        let action = filter.action.map(|action| action.as_str());
+        let limit = i64::try_from(pagination.limit).unwrap();
+        let offset = i64::try_from(pagination.offset).unwrap();
+
+        let rows = sqlx::query_as!(
+            AuditEventRowModel,
+            r#"
+            SELECT id, event_time AS "event_time: _", account_id AS "account_id: _", action, dataset_id AS "dataset_id: _", details
+                FROM audit_events
+                WHERE (cast($1 as TEXT) IS NULL OR account_id = $1)
+                AND (cast($2 as TEXT) IS NULL OR dataset_id = $2)
+                AND (cast($3 as TEXT) IS NULL OR action = $3)
+                AND ($4 IS NULL OR event_time >= $4)
+                AND ($5 IS NULL OR event_time < $5)
+                ORDER BY event_time DESC
+                LIMIT $6 OFFSET $7
+            "#,
+            account_id,
+            dataset_id,
+            action,
+            filter.from_time,
+            filter.to_time,
+            limit,
+            offset,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct AuditEventRowModel {
+    id: String,
+    event_time: DateTime<Utc>,
+    account_id: Option<AccountID>,
+    action: String,
+    dataset_id: Option<DatasetID>,
+    details: Option<String>,
+}
+
+impl TryFrom<AuditEventRowModel> for AuditEvent {
+    type Error = InternalError;
+
+    fn try_from(row: AuditEventRowModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::parse_str(&row.id).int_err()?,
+            event_time: row.event_time,
+            account_id: row.account_id,
+            action: row.action.parse::<AuditAction>().int_err()?,
+            dataset_id: row.dataset_id,
+            details: row.details,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
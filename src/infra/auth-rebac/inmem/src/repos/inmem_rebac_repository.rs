@@ -14,6 +14,7 @@ use std::sync::Arc;
 use dill::{component, interface, scope, Singleton};
 use kamu_auth_rebac::{
     DeleteEntitiesRelationError,
+    DeleteEntitiesRelationUnlessLastError,
     DeleteEntityPropertiesError,
     DeleteEntityPropertyError,
     Entity,
@@ -22,6 +23,7 @@ use kamu_auth_rebac::{
     GetEntityPropertiesError,
     GetRelationsBetweenEntitiesError,
     InsertEntitiesRelationError,
+    ObjectEntityRelationsError,
     PropertyName,
     PropertyValue,
     RebacRepository,
@@ -203,6 +205,41 @@ impl RebacRepository for InMemoryRebacRepository {
         Ok(())
     }
 
+    async fn delete_entities_relation_unless_last(
+        &self,
+        subject_entity: &Entity,
+        relationship: Relation,
+        object_entity: &Entity,
+    ) -> Result<(), DeleteEntitiesRelationUnlessLastError> {
+        let mut writable_state = self.state.write().await;
+
+        let row = EntitiesRelationsRow::new(subject_entity, relationship, object_entity);
+        if !writable_state.entities_relations_rows.contains(&row) {
+            return Err(DeleteEntitiesRelationUnlessLastError::not_found(
+                subject_entity,
+                relationship,
+                object_entity,
+            ));
+        }
+
+        let has_others = writable_state.entities_relations_rows.iter().any(|r| {
+            r.relationship == relationship
+                && r.object_entity == *object_entity
+                && r.subject_entity != *subject_entity
+        });
+        if !has_others {
+            return Err(DeleteEntitiesRelationUnlessLastError::last(
+                subject_entity,
+                relationship,
+                object_entity,
+            ));
+        }
+
+        writable_state.entities_relations_rows.remove(&row);
+
+        Ok(())
+    }
+
     async fn get_subject_entity_relations(
         &self,
         subject_entity: &Entity,
@@ -246,6 +283,26 @@ impl RebacRepository for InMemoryRebacRepository {
         Ok(res)
     }
 
+    async fn get_object_entity_relations(
+        &self,
+        object_entity: &Entity,
+    ) -> Result<Vec<EntityWithRelation>, ObjectEntityRelationsError> {
+        let res = self
+            .get_rows(|row| {
+                if row.object_entity == *object_entity {
+                    Some(EntityWithRelation::new(
+                        row.subject_entity.clone(),
+                        row.relationship,
+                    ))
+                } else {
+                    None
+                }
+            })
+            .await;
+
+        Ok(res)
+    }
+
     async fn get_relations_between_entities(
         &self,
         subject_entity: &Entity,
@@ -77,6 +77,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_auth_rebac_repo_tests::test_delete_entities_relation_unless_last,
+    harness = InMemoryRebacRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 database_transactional_test!(
     storage = inmem,
     fixture = kamu_auth_rebac_repo_tests::test_get_relations_crossover_test,
@@ -85,6 +93,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = inmem,
+    fixture = kamu_auth_rebac_repo_tests::test_get_object_entity_relations,
+    harness = InMemoryRebacRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct InMemoryRebacRepositoryHarness {
     catalog: Catalog,
 }
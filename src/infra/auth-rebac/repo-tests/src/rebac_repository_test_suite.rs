@@ -16,6 +16,7 @@ use dill::Catalog;
 use kamu_auth_rebac::{
     DatasetPropertyName,
     DeleteEntitiesRelationError,
+    DeleteEntitiesRelationUnlessLastError,
     DeleteEntityPropertiesError,
     DeleteEntityPropertyError,
     Entity,
@@ -334,6 +335,77 @@ pub async fn test_delete_entities_relation(catalog: &Catalog) {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub async fn test_delete_entities_relation_unless_last(catalog: &Catalog) {
+    let rebac_repo = catalog.get_one::<dyn RebacRepository>().unwrap();
+
+    let owner1 = Entity::new_account("owner1");
+    let owner2 = Entity::new_account("owner2");
+    let member = Entity::new_account("member");
+    let organization = Entity::new_account("organization");
+    let relationship = Relation::account_is_an_organization_owner();
+
+    for owner in [&owner1, &owner2] {
+        let insert_res = rebac_repo
+            .insert_entities_relation(owner, relationship, &organization)
+            .await;
+
+        assert_matches!(insert_res, Ok(()));
+    }
+    {
+        let insert_res = rebac_repo
+            .insert_entities_relation(
+                &member,
+                Relation::account_is_an_organization_member(),
+                &organization,
+            )
+            .await;
+
+        assert_matches!(insert_res, Ok(()));
+    }
+    {
+        let delete_res = rebac_repo
+            .delete_entities_relation_unless_last(&owner1, relationship, &organization)
+            .await;
+
+        assert_matches!(delete_res, Ok(()));
+    }
+    {
+        let delete_res = rebac_repo
+            .delete_entities_relation_unless_last(&owner2, relationship, &organization)
+            .await;
+
+        assert_matches!(
+            delete_res,
+            Err(DeleteEntitiesRelationUnlessLastError::Last(e))
+                if e.subject_entity == owner2
+                    && e.relationship == relationship
+                    && e.object_entity == organization
+        );
+    }
+    {
+        let delete_res = rebac_repo
+            .delete_entities_relation_unless_last(&owner1, relationship, &organization)
+            .await;
+
+        assert_matches!(
+            delete_res,
+            Err(DeleteEntitiesRelationUnlessLastError::NotFound(e))
+                if e.subject_entity == owner1
+                    && e.relationship == relationship
+                    && e.object_entity == organization
+        );
+    }
+    {
+        let relations = rebac_repo
+            .get_relations_between_entities(&owner2, &organization)
+            .await;
+
+        assert_matches!(relations, Ok(relations) if relations == [relationship]);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub async fn test_get_relations_crossover_test(catalog: &Catalog) {
     let rebac_repo = catalog.get_one::<dyn RebacRepository>().unwrap();
 
@@ -470,6 +542,85 @@ pub async fn test_get_relations_crossover_test(catalog: &Catalog) {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub async fn test_get_object_entity_relations(catalog: &Catalog) {
+    let rebac_repo = catalog.get_one::<dyn RebacRepository>().unwrap();
+
+    let owner = Entity::new_account("owner");
+    let member = Entity::new_account("member");
+    let organization = Entity::new_account("organization");
+    let dataset = Entity::new_dataset("dataset");
+
+    {
+        let get_res = rebac_repo.get_object_entity_relations(&organization).await;
+
+        assert_matches!(get_res, Ok(subject_entities) if subject_entities.is_empty());
+    }
+
+    for (subject_entity, relation, object_entity) in [
+        (
+            &owner,
+            Relation::account_is_an_organization_owner(),
+            &organization,
+        ),
+        (
+            &member,
+            Relation::account_is_an_organization_member(),
+            &organization,
+        ),
+        (
+            &organization,
+            Relation::organization_is_a_dataset_editor(),
+            &dataset,
+        ),
+    ] {
+        let insert_res = rebac_repo
+            .insert_entities_relation(subject_entity, relation, object_entity)
+            .await;
+
+        assert_matches!(insert_res, Ok(()));
+    }
+
+    {
+        let mut expected_subject_entities = vec![
+            EntityWithRelation::new(owner.clone(), Relation::account_is_an_organization_owner()),
+            EntityWithRelation::new(
+                member.clone(),
+                Relation::account_is_an_organization_member(),
+            ),
+        ];
+        expected_subject_entities.sort();
+
+        let get_res = rebac_repo.get_object_entity_relations(&organization).await;
+
+        match get_res {
+            Ok(mut actual_subject_entities) => {
+                actual_subject_entities.sort();
+
+                assert_eq!(expected_subject_entities, actual_subject_entities);
+            }
+            unexpected_res => {
+                panic!("Unexpected result: {unexpected_res:?}");
+            }
+        }
+    }
+    {
+        let get_res = rebac_repo.get_object_entity_relations(&dataset).await;
+
+        assert_matches!(
+            get_res,
+            Ok(subject_entities)
+                if subject_entities == vec![
+                    EntityWithRelation::new(
+                        organization.clone(),
+                        Relation::organization_is_a_dataset_editor()
+                    )
+                ]
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 async fn assert_get_relations(rebac_repo: &Arc<dyn RebacRepository>, state: &CrossoverTestState) {
     let account = Entity::new_account(state.account_id);
     let mut object_entities = state.get_object_entities_with_relation();
@@ -249,6 +249,74 @@ impl RebacRepository for SqliteRebacRepository {
         Ok(())
     }
 
+    async fn delete_entities_relation_unless_last(
+        &self,
+        subject_entity: &Entity,
+        relationship: Relation,
+        object_entity: &Entity,
+    ) -> Result<(), DeleteEntitiesRelationUnlessLastError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let subject_entity_id_as_str = subject_entity.entity_id.as_ref();
+        let relation_as_str = relationship.to_string();
+        let object_entity_id_as_str = object_entity.entity_id.as_ref();
+
+        // Single statement is atomic, as SQLite serializes the writes
+        let delete_result = sqlx::query!(
+            r#"
+            DELETE
+            FROM auth_rebac_relations
+            WHERE subject_entity_type = $1
+              AND subject_entity_id = $2
+              AND relationship = $3
+              AND object_entity_type = $4
+              AND object_entity_id = $5
+              AND (
+                SELECT COUNT(*)
+                FROM auth_rebac_relations
+                WHERE relationship = $3
+                  AND object_entity_type = $4
+                  AND object_entity_id = $5
+              ) > 1
+            "#,
+            subject_entity.entity_type,
+            subject_entity_id_as_str,
+            relation_as_str,
+            object_entity.entity_type,
+            object_entity_id_as_str,
+        )
+        .execute(&mut *connection_mut)
+        .await
+        .int_err()?;
+
+        drop(tr);
+
+        if delete_result.rows_affected() == 0 {
+            let relations = self
+                .get_relations_between_entities(subject_entity, object_entity)
+                .await
+                .int_err()?;
+
+            return Err(if relations.contains(&relationship) {
+                DeleteEntitiesRelationUnlessLastError::last(
+                    subject_entity,
+                    relationship,
+                    object_entity,
+                )
+            } else {
+                DeleteEntitiesRelationUnlessLastError::not_found(
+                    subject_entity,
+                    relationship,
+                    object_entity,
+                )
+            });
+        }
+
+        Ok(())
+    }
+
     async fn get_subject_entity_relations(
         &self,
         subject_entity: &Entity,
@@ -320,6 +388,40 @@ impl RebacRepository for SqliteRebacRepository {
             .map_err(SubjectEntityRelationsByObjectTypeError::Internal)
     }
 
+    async fn get_object_entity_relations(
+        &self,
+        object_entity: &Entity,
+    ) -> Result<Vec<EntityWithRelation>, ObjectEntityRelationsError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let object_entity_id_as_str = object_entity.entity_id.as_ref();
+
+        let row_models = sqlx::query_as!(
+            EntityWithRelationRowModel,
+            r#"
+            SELECT subject_entity_type as "entity_type: EntityType",
+                   subject_entity_id as entity_id,
+                   relationship
+            FROM auth_rebac_relations
+            WHERE object_entity_type = $1
+              AND object_entity_id = $2
+            "#,
+            object_entity.entity_type,
+            object_entity_id_as_str,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        row_models
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ObjectEntityRelationsError::Internal)
+    }
+
     async fn get_relations_between_entities(
         &self,
         subject_entity: &Entity,
@@ -79,6 +79,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = sqlite,
+    fixture = kamu_auth_rebac_repo_tests::test_delete_entities_relation_unless_last,
+    harness = SqliteRebacRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 database_transactional_test!(
     storage = sqlite,
     fixture = kamu_auth_rebac_repo_tests::test_get_relations_crossover_test,
@@ -87,6 +95,14 @@ database_transactional_test!(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+database_transactional_test!(
+    storage = sqlite,
+    fixture = kamu_auth_rebac_repo_tests::test_get_object_entity_relations,
+    harness = SqliteRebacRepositoryHarness
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct SqliteRebacRepositoryHarness {
     catalog: Catalog,
 }
@@ -8,16 +8,21 @@
 // by the Apache License, Version 2.0.
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
+use datafusion::arrow::datatypes::{Schema, SchemaRef};
 use datafusion::prelude::*;
 use dill::{component, interface};
 use domain::{
+    CompactedSlice,
+    CompactedSlices,
     CompactionError,
     CompactionListener,
+    CompactionMode,
     CompactionMultiListener,
     CompactionOptions,
     CompactionPhase,
@@ -30,9 +35,16 @@ use domain::{
 };
 use futures::stream::TryStreamExt;
 use internal_error::ResultIntoInternal;
+use kamu_core::utils::partitioned_slices::read_partitioning_spec;
+use kamu_core::utils::slice_statistics::read_slice_statistics_config;
 use kamu_core::*;
+use kamu_ingest_datafusion::partitioning::{commit_slice_partitions, compute_slice_partitions};
+use kamu_ingest_datafusion::statistics::{commit_slice_statistics, compute_slice_statistics};
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::{
+    AddData,
     Checkpoint,
+    DataSlice,
     DatasetHandle,
     DatasetKind,
     DatasetRef,
@@ -41,6 +53,7 @@ use opendatafabric::{
     MetadataEvent,
     Multihash,
     OffsetInterval,
+    SetDataSchema,
     SetVocab,
     SourceState,
 };
@@ -48,7 +61,13 @@ use random_names::get_random_name;
 use time_source::SystemTimeSource;
 use url::Url;
 
+use crate::utils::compacted_slices::{
+    delete_compacted_slices,
+    read_compacted_slices,
+    write_compacted_slices,
+};
 use crate::utils::datasets_filtering::filter_datasets_by_local_pattern;
+use crate::utils::slice_indexes::{delete_orphaned_slice_indexes, delete_slice_indexes};
 use crate::*;
 
 pub struct CompactionServiceImpl {
@@ -57,6 +76,7 @@ pub struct CompactionServiceImpl {
     object_store_registry: Arc<dyn ObjectStoreRegistry>,
     time_source: Arc<dyn SystemTimeSource>,
     run_info_dir: Arc<RunInfoDir>,
+    outbox: Arc<dyn Outbox>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -90,15 +110,28 @@ struct DataSliceBatchInfo {
     pub upper_bound: DataSliceBatchUpperBound,
     pub lower_bound: DataSliceBatchLowerBound,
     pub new_file_path: Option<PathBuf>,
+    pub new_file_partitions: Option<SlicePartitions>,
+    pub new_file_statistics: Option<SliceStatistics>,
 }
 
 struct ChainFilesInfo {
+    head: Multihash,
     old_head: Multihash,
     old_num_blocks: usize,
     offset_column: String,
     data_slice_batches: Vec<DataSliceBatch>,
 }
 
+struct SoftCompactionChainInfo {
+    head: Multihash,
+    offset_column: String,
+    /// Latest schema of the dataset, slices written before the schema has
+    /// evolved are merged according to it
+    schema: Option<SchemaRef>,
+    /// All data slices of the chain in the order they were added (oldest first)
+    data_slices: Vec<DataSlice>,
+}
+
 #[component(pub)]
 #[interface(dyn CompactionService)]
 impl CompactionServiceImpl {
@@ -108,6 +141,7 @@ impl CompactionServiceImpl {
         object_store_registry: Arc<dyn ObjectStoreRegistry>,
         time_source: Arc<dyn SystemTimeSource>,
         run_info_dir: Arc<RunInfoDir>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             dataset_repo,
@@ -115,6 +149,7 @@ impl CompactionServiceImpl {
             object_store_registry,
             time_source,
             run_info_dir,
+            outbox,
         }
     }
 
@@ -234,6 +269,7 @@ impl CompactionServiceImpl {
         let vocab: DatasetVocabulary = vocab_event.unwrap_or_default().into();
 
         Ok(ChainFilesInfo {
+            head: head.clone(),
             data_slice_batches,
             offset_column: vocab.offset_column,
             old_head: old_head.unwrap(),
@@ -263,41 +299,39 @@ impl CompactionServiceImpl {
 
     async fn merge_files(
         &self,
+        dataset: &dyn Dataset,
         data_slice_batches: &mut [DataSliceBatch],
         offset_column: &str,
         compaction_dir_path: &Path,
     ) -> Result<(), CompactionError> {
         let ctx = new_session_context(self.object_store_registry.clone());
+        let partitioning_spec = read_partitioning_spec(dataset.as_info_repo()).await?;
+        let statistics_config = read_slice_statistics_config(dataset.as_info_repo()).await?;
 
         for (index, data_slice_batch) in data_slice_batches.iter_mut().enumerate() {
             if let DataSliceBatch::CompactedBatch(data_slice_batch_info) = data_slice_batch {
-                let data_frame = ctx
-                    .read_parquet(
-                        data_slice_batch_info.data_slices_batch.clone(),
-                        datafusion::execution::options::ParquetReadOptions {
-                            file_extension: "",
-                            ..Default::default()
-                        },
-                    )
-                    .await
-                    .int_err()?
-                    // TODO: PERF: Consider passing sort order hint to `read_parquet` to let DF now
-                    // that the data is already pre-sorted
-                    .sort(vec![col(Column::from_name(offset_column)).sort(true, false)])
-                    .int_err()?;
-
                 let new_file_path =
                     compaction_dir_path.join(format!("merge-slice-{index}").as_str());
 
-                data_frame
-                    .write_parquet(
-                        new_file_path.to_str().unwrap(),
-                        datafusion::dataframe::DataFrameWriteOptions::new()
-                            .with_single_file_output(true),
-                        None,
-                    )
-                    .await
-                    .int_err()?;
+                Self::merge_slice_files(
+                    &ctx,
+                    data_slice_batch_info.data_slices_batch.clone(),
+                    offset_column,
+                    None,
+                    &new_file_path,
+                )
+                .await?;
+
+                if let Some(spec) = &partitioning_spec {
+                    data_slice_batch_info.new_file_partitions =
+                        compute_slice_partitions(&ctx, spec, offset_column, &new_file_path).await?;
+                }
+
+                if let Some(config) = &statistics_config {
+                    data_slice_batch_info.new_file_statistics =
+                        Some(compute_slice_statistics(&ctx, config, &new_file_path).await?);
+                }
+
                 data_slice_batch_info.new_file_path = Some(new_file_path);
             }
         }
@@ -305,6 +339,41 @@ impl CompactionServiceImpl {
         Ok(())
     }
 
+    async fn merge_slice_files(
+        ctx: &SessionContext,
+        data_slice_urls: Vec<Url>,
+        offset_column: &str,
+        schema: Option<&Schema>,
+        new_file_path: &Path,
+    ) -> Result<(), CompactionError> {
+        let data_frame = ctx
+            .read_parquet(
+                data_slice_urls,
+                datafusion::execution::options::ParquetReadOptions {
+                    schema,
+                    file_extension: "",
+                    ..Default::default()
+                },
+            )
+            .await
+            .int_err()?
+            // TODO: PERF: Consider passing sort order hint to `read_parquet` to let DF now
+            // that the data is already pre-sorted
+            .sort(vec![col(Column::from_name(offset_column)).sort(true, false)])
+            .int_err()?;
+
+        data_frame
+            .write_parquet(
+                new_file_path.to_str().unwrap(),
+                datafusion::dataframe::DataFrameWriteOptions::new().with_single_file_output(true),
+                None,
+            )
+            .await
+            .int_err()?;
+
+        Ok(())
+    }
+
     fn create_run_compaction_dir(&self) -> Result<PathBuf, CompactionError> {
         let compaction_dir_path = self
             .run_info_dir
@@ -384,6 +453,40 @@ impl CompactionServiceImpl {
                         .await
                         .int_err()?;
 
+                    if data_slice_batch_info.new_file_partitions.is_some()
+                        || data_slice_batch_info.new_file_statistics.is_some()
+                    {
+                        let new_block = chain
+                            .get_block(&commit_result.new_head)
+                            .await
+                            .int_err()?
+                            .into_typed::<AddData>()
+                            .unwrap();
+
+                        if let Some(new_data) = &new_block.event.new_data {
+                            if let Some(partitions) =
+                                data_slice_batch_info.new_file_partitions.clone()
+                            {
+                                commit_slice_partitions(
+                                    dataset.as_ref(),
+                                    &new_data.physical_hash,
+                                    partitions,
+                                )
+                                .await;
+                            }
+                            if let Some(statistics) =
+                                data_slice_batch_info.new_file_statistics.clone()
+                            {
+                                commit_slice_statistics(
+                                    dataset.as_ref(),
+                                    &new_data.physical_hash,
+                                    statistics,
+                                )
+                                .await;
+                            }
+                        }
+                    }
+
                     current_head = commit_result.new_head;
                     old_data_slices.extend(data_slice_batch_info.data_slices_batch.clone());
                 }
@@ -423,6 +526,7 @@ impl CompactionServiceImpl {
 
         listener.begin_phase(CompactionPhase::MergeDataslices);
         self.merge_files(
+            dataset.as_ref(),
             &mut chain_files_info.data_slice_batches,
             chain_files_info.offset_column.as_str(),
             &compaction_dir_path,
@@ -446,6 +550,9 @@ impl CompactionServiceImpl {
             )
             .await?;
 
+        delete_orphaned_slice_indexes(dataset.as_ref(), &chain_files_info.head, &new_head).await;
+        delete_compacted_slices(dataset.as_ref()).await;
+
         let res = CompactionResult::Success {
             old_head: chain_files_info.old_head,
             new_head,
@@ -457,6 +564,245 @@ impl CompactionServiceImpl {
 
         Ok(res)
     }
+
+    async fn gather_soft_compaction_chain_info(
+        &self,
+        dataset: &dyn Dataset,
+    ) -> Result<SoftCompactionChainInfo, CompactionError> {
+        let chain = dataset.as_metadata_chain();
+        let head = chain.resolve_ref(&BlockRef::Head).await?;
+        let mut block_stream = chain.iter_blocks_interval(&head, None, false);
+
+        let mut vocab_event: Option<SetVocab> = None;
+        let mut schema_event: Option<SetDataSchema> = None;
+        let mut data_slices = Vec::new();
+
+        while let Some((_, block)) = block_stream.try_next().await? {
+            match block.event {
+                MetadataEvent::AddData(e) => data_slices.extend(e.new_data),
+                MetadataEvent::ExecuteTransform(e) => data_slices.extend(e.new_data),
+                MetadataEvent::SetVocab(e) => {
+                    if vocab_event.is_none() {
+                        vocab_event = Some(e);
+                    }
+                }
+                MetadataEvent::SetDataSchema(e) => {
+                    if schema_event.is_none() {
+                        schema_event = Some(e);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        data_slices.reverse();
+
+        let vocab: DatasetVocabulary = vocab_event.unwrap_or_default().into();
+        let schema = schema_event
+            .map(|e| e.schema_as_arrow())
+            .transpose()
+            .int_err()?;
+
+        Ok(SoftCompactionChainInfo {
+            head,
+            offset_column: vocab.offset_column,
+            schema,
+            data_slices,
+        })
+    }
+
+    /// Groups consecutive slices that were not merged before into batches
+    /// limited by size and number of records. Batches of a single slice are
+    /// not worth merging and are skipped.
+    fn plan_soft_compaction_batches<'a>(
+        data_slices: &'a [DataSlice],
+        already_compacted: &HashSet<&Multihash>,
+        max_slice_size: u64,
+        max_slice_records: u64,
+    ) -> Vec<Vec<&'a DataSlice>> {
+        let mut batches = Vec::new();
+        let mut batch: Vec<&DataSlice> = Vec::new();
+        let (mut batch_size, mut batch_records) = (0u64, 0u64);
+
+        for data_slice in data_slices {
+            if already_compacted.contains(&data_slice.physical_hash) {
+                batches.push(std::mem::take(&mut batch));
+                (batch_size, batch_records) = (0, 0);
+                continue;
+            }
+
+            if !batch.is_empty()
+                && (batch_size + data_slice.size > max_slice_size
+                    || batch_records + data_slice.num_records() > max_slice_records)
+            {
+                batches.push(std::mem::take(&mut batch));
+                (batch_size, batch_records) = (0, 0);
+            }
+
+            batch.push(data_slice);
+            batch_size += data_slice.size;
+            batch_records += data_slice.num_records();
+        }
+        batches.push(batch);
+
+        batches.retain(|b| b.len() > 1);
+        batches
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn soft_compact_dataset_impl(
+        &self,
+        dataset: Arc<dyn Dataset>,
+        max_slice_size: u64,
+        max_slice_records: u64,
+        listener: Arc<dyn CompactionListener>,
+    ) -> Result<CompactionResult, CompactionError> {
+        listener.begin_phase(CompactionPhase::GatherChainInfo);
+        let chain_info = self
+            .gather_soft_compaction_chain_info(dataset.as_ref())
+            .await?;
+
+        let all_files: Vec<Multihash> = chain_info
+            .data_slices
+            .iter()
+            .map(|s| s.physical_hash.clone())
+            .collect();
+        let all_files_set: HashSet<&Multihash> = all_files.iter().collect();
+
+        // Entries that refer to slices no longer present in the chain (e.g. after
+        // a reset or a hard compaction) are dropped
+        let (prev_slices, stale_slices): (Vec<_>, Vec<_>) =
+            read_compacted_slices(dataset.as_info_repo())
+                .await?
+                .map(|prev| prev.slices)
+                .unwrap_or_default()
+                .into_iter()
+                .partition(|s| s.source_slices.iter().all(|h| all_files_set.contains(h)));
+
+        let already_compacted: HashSet<&Multihash> = prev_slices
+            .iter()
+            .flat_map(|s| s.source_slices.iter())
+            .collect();
+
+        let batches = Self::plan_soft_compaction_batches(
+            &chain_info.data_slices,
+            &already_compacted,
+            max_slice_size,
+            max_slice_records,
+        );
+
+        if batches.is_empty() && stale_slices.is_empty() {
+            return Ok(CompactionResult::NothingToDo);
+        }
+
+        listener.begin_phase(CompactionPhase::MergeDataslices);
+        let compaction_dir_path = self.create_run_compaction_dir()?;
+        let ctx = new_session_context(self.object_store_registry.clone());
+        let data_repo = dataset.as_data_repo();
+        let partitioning_spec = read_partitioning_spec(dataset.as_info_repo()).await?;
+        let statistics_config = read_slice_statistics_config(dataset.as_info_repo()).await?;
+
+        let mut new_slices = Vec::new();
+        for (index, batch) in batches.iter().enumerate() {
+            let mut data_slice_urls = Vec::with_capacity(batch.len());
+            for data_slice in batch {
+                data_slice_urls.push(data_repo.get_internal_url(&data_slice.physical_hash).await);
+            }
+
+            let new_file_path = compaction_dir_path.join(format!("merge-slice-{index}").as_str());
+
+            Self::merge_slice_files(
+                &ctx,
+                data_slice_urls,
+                &chain_info.offset_column,
+                chain_info.schema.as_deref(),
+                &new_file_path,
+            )
+            .await?;
+
+            let path = new_file_path.clone();
+            let physical_hash = tokio::task::spawn_blocking(move || {
+                kamu_data_utils::data::hash::get_file_physical_hash(&path)
+            })
+            .await
+            .int_err()?
+            .int_err()?;
+            let size = fs::metadata(&new_file_path).int_err()?.len();
+
+            let partitions = if let Some(spec) = &partitioning_spec {
+                compute_slice_partitions(&ctx, spec, &chain_info.offset_column, &new_file_path)
+                    .await?
+            } else {
+                None
+            };
+
+            let statistics = if let Some(config) = &statistics_config {
+                Some(compute_slice_statistics(&ctx, config, &new_file_path).await?)
+            } else {
+                None
+            };
+
+            data_repo
+                .insert_file_move(
+                    &new_file_path,
+                    InsertOpts {
+                        precomputed_hash: Some(&physical_hash),
+                        size_hint: Some(size),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .int_err()?;
+
+            if let Some(partitions) = partitions {
+                commit_slice_partitions(dataset.as_ref(), &physical_hash, partitions).await;
+            }
+            if let Some(statistics) = statistics {
+                commit_slice_statistics(dataset.as_ref(), &physical_hash, statistics).await;
+            }
+
+            new_slices.push(CompactedSlice {
+                physical_hash,
+                size,
+                offset_interval: OffsetInterval {
+                    start: batch.first().unwrap().offset_interval.start,
+                    end: batch.last().unwrap().offset_interval.end,
+                },
+                source_slices: batch.iter().map(|s| s.physical_hash.clone()).collect(),
+            });
+        }
+
+        listener.begin_phase(CompactionPhase::CommitCompactedSlices);
+        let old_num_slices = CompactedSlices {
+            slices: prev_slices.clone(),
+        }
+        .substitute(all_files.clone())
+        .len();
+
+        let mut slices = prev_slices;
+        slices.extend(new_slices);
+        slices.sort_by_key(|s| s.offset_interval.start);
+
+        let compacted_slices = CompactedSlices { slices };
+        write_compacted_slices(dataset.as_info_repo(), &compacted_slices).await?;
+
+        for stale_slice in stale_slices {
+            data_repo
+                .delete(&stale_slice.physical_hash)
+                .await
+                .int_err()?;
+
+            delete_slice_indexes(dataset.as_ref(), &[stale_slice.physical_hash]).await?;
+        }
+
+        let new_num_slices = compacted_slices.substitute(all_files).len();
+
+        Ok(CompactionResult::SoftSuccess {
+            head: chain_info.head,
+            old_num_slices,
+            new_num_slices,
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -480,7 +826,12 @@ impl CompactionService for CompactionServiceImpl {
             .int_err()?
             .kind;
 
-        if !options.keep_metadata_only && dataset_kind != DatasetKind::Root {
+        // Soft compaction does not alter the history and therefore can be applied to
+        // derivative datasets too
+        if options.mode == CompactionMode::Hard
+            && !options.keep_metadata_only
+            && dataset_kind != DatasetKind::Root
+        {
             return Err(CompactionError::InvalidDatasetKind(
                 InvalidDatasetKindError {
                     dataset_name: dataset_handle.alias.dataset_name.clone(),
@@ -495,17 +846,46 @@ impl CompactionService for CompactionServiceImpl {
             .max_slice_records
             .unwrap_or(DEFAULT_MAX_SLICE_RECORDS);
 
-        match self
-            .compact_dataset_impl(
-                dataset,
-                max_slice_size,
-                max_slice_records,
-                options.keep_metadata_only,
-                listener.clone(),
-            )
-            .await
-        {
+        let compaction_result = match options.mode {
+            CompactionMode::Hard => {
+                self.compact_dataset_impl(
+                    dataset,
+                    max_slice_size,
+                    max_slice_records,
+                    options.keep_metadata_only,
+                    listener.clone(),
+                )
+                .await
+            }
+            CompactionMode::Soft => {
+                self.soft_compact_dataset_impl(
+                    dataset,
+                    max_slice_size,
+                    max_slice_records,
+                    listener.clone(),
+                )
+                .await
+            }
+        };
+
+        match compaction_result {
             Ok(res) => {
+                if let CompactionResult::Success {
+                    old_head, new_head, ..
+                } = &res
+                {
+                    self.outbox
+                        .post_message(
+                            MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                            DatasetReferenceMessage::updated(
+                                dataset_handle.id.clone(),
+                                Some(old_head.clone()),
+                                new_head.clone(),
+                            ),
+                        )
+                        .await?;
+                }
+
                 listener.success(&res);
                 Ok(res)
             }
@@ -0,0 +1,381 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use datafusion::arrow::array::UInt64Array;
+use datafusion::common::parsers::CompressionTypeVariant;
+use datafusion::config::{CsvOptions, JsonOptions, TableParquetOptions};
+use datafusion::dataframe::DataFrameWriteOptions;
+use datafusion::datasource::file_format::arrow::ArrowFormatFactory;
+use datafusion::datasource::file_format::format_as_file_type;
+use datafusion::execution::SessionStateBuilder;
+use datafusion::logical_expr::LogicalPlanBuilder;
+use datafusion::prelude::*;
+use dill::*;
+use futures::TryStreamExt;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_core::utils::data_export_positions::*;
+use kamu_core::*;
+use opendatafabric::*;
+use url::Url;
+
+use crate::utils::s3_context::S3Context;
+use crate::{new_session_context, ObjectStoreBuilderS3, ObjectStoreRegistryImpl};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DataExportServiceImpl {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    query_svc: Arc<dyn QueryService>,
+    object_store_builders: Vec<Arc<dyn ObjectStoreBuilder>>,
+}
+
+#[component(pub)]
+#[interface(dyn DataExportService)]
+impl DataExportServiceImpl {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        query_svc: Arc<dyn QueryService>,
+        object_store_builders: Vec<Arc<dyn ObjectStoreBuilder>>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_action_authorizer,
+            query_svc,
+            object_store_builders,
+        }
+    }
+
+    /// Creates a session that can read from the workspace and write into the
+    /// destination, and returns it along with the destination URL in a form
+    /// expected by `DataFusion`.
+    async fn prepare_destination(
+        &self,
+        destination: &Url,
+    ) -> Result<(SessionContext, String), DataExportError> {
+        let mut builders = self.object_store_builders.clone();
+
+        let target = match destination.scheme() {
+            "file" => destination.to_string(),
+            "s3" | "s3+http" | "s3+https" => {
+                let s3_context = S3Context::from_url(destination).await;
+                let target = format!("s3://{}/{}", s3_context.bucket(), s3_context.key_prefix());
+                let allow_http = s3_context
+                    .endpoint()
+                    .is_some_and(|e| e.starts_with("http://"));
+
+                // Registered last to take precedence over the store of the same bucket
+                builders.push(Arc::new(ObjectStoreBuilderS3::new(s3_context, allow_http)));
+                target
+            }
+            scheme => {
+                return Err(InvalidDataExportOptionsError::new(format!(
+                    "Unsupported destination scheme {scheme}"
+                ))
+                .into())
+            }
+        };
+
+        let object_store_registry = Arc::new(ObjectStoreRegistryImpl::new(builders));
+
+        Ok((new_session_context(object_store_registry), target))
+    }
+
+    fn validate_options(options: &DataExportOptions) -> Result<(), DataExportError> {
+        if options.format == DataExportFormat::Arrow && options.compression.is_some() {
+            return Err(InvalidDataExportOptionsError::new(
+                "Compression is not supported for Arrow format",
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Reads the data slices added by the blocks after the specified tail up
+    /// until the head
+    async fn read_slices(
+        ctx: &SessionContext,
+        dataset_handle: &DatasetHandle,
+        dataset: &dyn Dataset,
+        head: &Multihash,
+        tail: Option<&Multihash>,
+        destination: &Url,
+    ) -> Result<Option<DataFrame>, DataExportError> {
+        let mut blocks = dataset
+            .as_metadata_chain()
+            .iter_blocks_interval(head, tail, false)
+            .filter_data_stream_blocks();
+
+        let mut data_slices = Vec::new();
+        loop {
+            match blocks.try_next().await {
+                Ok(Some((_, block))) => data_slices.extend(block.event.new_data),
+                Ok(None) => break,
+                Err(IterBlocksError::InvalidInterval(_)) => {
+                    return Err(DataExportPositionNotFoundError {
+                        dataset_handle: dataset_handle.clone(),
+                        destination: destination.clone(),
+                        head: tail.unwrap().clone(),
+                    }
+                    .into())
+                }
+                Err(e) => return Err(e.int_err().into()),
+            }
+        }
+
+        if data_slices.is_empty() {
+            return Ok(None);
+        }
+
+        let data_repo = dataset.as_data_repo();
+        let mut data_slice_urls = Vec::with_capacity(data_slices.len());
+        for data_slice in data_slices.iter().rev() {
+            data_slice_urls.push(data_repo.get_internal_url(&data_slice.physical_hash).await);
+        }
+
+        // Slices written before the schema has evolved are read according to the
+        // latest schema, same as when the dataset is queried
+        let schema = dataset
+            .as_metadata_chain()
+            .accept_one_by_hash(head, SearchSetDataSchemaVisitor::new())
+            .await
+            .int_err()?
+            .into_event()
+            .map(|set_data_schema| set_data_schema.schema_as_arrow())
+            .transpose()
+            .int_err()?;
+
+        let df = ctx
+            .read_parquet(
+                data_slice_urls,
+                datafusion::execution::options::ParquetReadOptions {
+                    schema: schema.as_deref(),
+                    file_extension: "",
+                    ..Default::default()
+                },
+            )
+            .await
+            .int_err()?;
+
+        Ok(Some(df))
+    }
+
+    async fn write(
+        df: DataFrame,
+        target: &str,
+        options: &DataExportOptions,
+    ) -> Result<u64, DataExportError> {
+        for column in &options.partition_by {
+            if df.schema().field_with_unqualified_name(column).is_err() {
+                return Err(InvalidDataExportOptionsError::new(format!(
+                    "Partitioning column {column} does not exist"
+                ))
+                .into());
+            }
+        }
+
+        let write_options =
+            DataFrameWriteOptions::new().with_partition_by(options.partition_by.clone());
+
+        let compression = match options.compression {
+            None => CompressionTypeVariant::UNCOMPRESSED,
+            Some(DataExportCompression::Gzip) => CompressionTypeVariant::GZIP,
+            Some(DataExportCompression::Zstd) => CompressionTypeVariant::ZSTD,
+        };
+
+        let res = match options.format {
+            DataExportFormat::Parquet => {
+                let mut parquet_options = TableParquetOptions::default();
+                if let Some(codec) = options.compression {
+                    parquet_options.global.compression = Some(
+                        match codec {
+                            DataExportCompression::Gzip => "gzip(6)",
+                            DataExportCompression::Zstd => "zstd(3)",
+                        }
+                        .to_string(),
+                    );
+                }
+                df.write_parquet(target, write_options, Some(parquet_options))
+                    .await
+            }
+            DataExportFormat::Csv => {
+                let csv_options = CsvOptions {
+                    has_header: Some(true),
+                    compression,
+                    ..Default::default()
+                };
+                df.write_csv(target, write_options, Some(csv_options)).await
+            }
+            DataExportFormat::NdJson => {
+                let json_options = JsonOptions {
+                    compression,
+                    ..Default::default()
+                };
+                df.write_json(target, write_options, Some(json_options))
+                    .await
+            }
+            DataExportFormat::Arrow => {
+                // DataFrame API has no shortcut for Arrow IPC files
+                let (state, plan) = df.into_parts();
+                let plan = LogicalPlanBuilder::copy_to(
+                    plan,
+                    target.to_string(),
+                    format_as_file_type(Arc::new(ArrowFormatFactory::new())),
+                    HashMap::new(),
+                    options.partition_by.clone(),
+                )
+                .int_err()?
+                .build()
+                .int_err()?;
+                DataFrame::new(state, plan).collect().await
+            }
+        }
+        .int_err()?;
+
+        let mut num_records = 0;
+        for batch in res {
+            let counts = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .ok_or_else(|| "Unexpected result of a write operation".int_err())?;
+            num_records += counts.values().iter().sum::<u64>();
+        }
+
+        Ok(num_records)
+    }
+
+    async fn export_dataset(
+        &self,
+        dataset_ref: &DatasetRef,
+        options: &DataExportOptions,
+    ) -> Result<DataExportResult, DataExportError> {
+        let dataset_handle = self.dataset_repo.resolve_dataset_ref(dataset_ref).await?;
+
+        self.dataset_action_authorizer
+            .check_action_allowed(&dataset_handle, auth::DatasetAction::Read)
+            .await?;
+
+        let dataset = self.dataset_repo.get_dataset_by_handle(&dataset_handle);
+        let head = dataset
+            .as_metadata_chain()
+            .resolve_ref(&BlockRef::Head)
+            .await
+            .int_err()?;
+
+        let info_repo = dataset.as_info_repo();
+        let mut positions = read_data_export_positions(info_repo).await?;
+
+        let tail = if options.incremental {
+            positions.get(&options.destination).map(|p| p.head.clone())
+        } else {
+            None
+        };
+
+        if tail.as_ref() == Some(&head) {
+            return Ok(DataExportResult::UpToDate);
+        }
+
+        let (ctx, target) = self.prepare_destination(&options.destination).await?;
+
+        let Some(df) = Self::read_slices(
+            &ctx,
+            &dataset_handle,
+            dataset.as_ref(),
+            &head,
+            tail.as_ref(),
+            &options.destination,
+        )
+        .await?
+        else {
+            positions.set(&options.destination, head);
+            write_data_export_positions(info_repo, &positions).await?;
+            return Ok(DataExportResult::UpToDate);
+        };
+
+        let num_records = Self::write(df, &target, options).await?;
+
+        // Position is remembered for full exports too, so that they can be followed
+        // by incremental ones
+        positions.set(&options.destination, head.clone());
+        write_data_export_positions(info_repo, &positions).await?;
+
+        Ok(DataExportResult::Exported {
+            num_records,
+            head: Some(head),
+        })
+    }
+
+    async fn export_sql(
+        &self,
+        statement: &str,
+        options: &DataExportOptions,
+    ) -> Result<DataExportResult, DataExportError> {
+        if options.incremental {
+            return Err(InvalidDataExportOptionsError::new(
+                "Incremental export is only supported for datasets",
+            )
+            .into());
+        }
+
+        let (ctx, target) = self.prepare_destination(&options.destination).await?;
+
+        let res = self
+            .query_svc
+            .sql_statement(statement, QueryOptions::default())
+            .await?;
+
+        // Query is planned in its own session, so it is re-attached to the runtime
+        // that knows how to write into the destination
+        let (state, plan) = res.df.into_parts();
+        let state = SessionStateBuilder::new_from_existing(state)
+            .with_runtime_env(ctx.runtime_env())
+            .build();
+        let df = DataFrame::new(state, plan);
+
+        let num_records = Self::write(df, &target, options).await?;
+
+        Ok(DataExportResult::Exported {
+            num_records,
+            head: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl DataExportService for DataExportServiceImpl {
+    #[tracing::instrument(level = "info", skip_all, fields(?source, destination = %options.destination))]
+    async fn export_data(
+        &self,
+        source: DataExportSource,
+        mut options: DataExportOptions,
+    ) -> Result<DataExportResult, DataExportError> {
+        Self::validate_options(&options)?;
+
+        if !options.destination.path().ends_with('/') {
+            let path = format!("{}/", options.destination.path());
+            options.destination.set_path(&path);
+        }
+
+        let res = match &source {
+            DataExportSource::Dataset(dataset_ref) => {
+                self.export_dataset(dataset_ref, &options).await
+            }
+            DataExportSource::Sql(statement) => self.export_sql(statement, &options).await,
+        }?;
+
+        tracing::info!(result = ?res, "Exported data");
+
+        Ok(res)
+    }
+}
@@ -0,0 +1,502 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use dill::*;
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_core::*;
+use kamu_data_utils::data::hash::get_file_physical_hash;
+use opendatafabric::serde::yaml::Manifest;
+use opendatafabric::*;
+use random_names::get_random_name;
+use url::Url;
+
+use crate::{DatasetFactoryImpl, DatasetLayout};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const ARCHIVE_COMPRESSION_LEVEL: i32 = 3;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DatasetArchiveServiceImpl {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    sync_svc: Arc<dyn SyncService>,
+    verification_svc: Arc<dyn VerificationService>,
+    delete_dataset_use_case: Arc<dyn DeleteDatasetUseCase>,
+    run_info_dir: Arc<RunInfoDir>,
+}
+
+#[component(pub)]
+#[interface(dyn DatasetArchiveService)]
+impl DatasetArchiveServiceImpl {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        sync_svc: Arc<dyn SyncService>,
+        verification_svc: Arc<dyn VerificationService>,
+        delete_dataset_use_case: Arc<dyn DeleteDatasetUseCase>,
+        run_info_dir: Arc<RunInfoDir>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_action_authorizer,
+            sync_svc,
+            verification_svc,
+            delete_dataset_use_case,
+            run_info_dir,
+        }
+    }
+
+    fn dir_url(path: &Path) -> Result<Url, InternalError> {
+        Url::from_directory_path(path)
+            .map_err(|_| format!("Cannot convert path {} into a URL", path.display()).int_err())
+    }
+
+    /// Lists all files of the dataset copy along with their sizes and hashes
+    fn collect_entries(dataset_dir: &Path) -> Result<Vec<DatasetArchiveEntry>, InternalError> {
+        let mut entries = Vec::new();
+
+        for entry in walkdir::WalkDir::new(dataset_dir).sort_by_file_name() {
+            let entry = entry.int_err()?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let rel_path = entry.path().strip_prefix(dataset_dir).int_err()?;
+            let path = rel_path
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            entries.push(DatasetArchiveEntry {
+                path,
+                size: entry.metadata().int_err()?.len(),
+                physical_hash: get_file_physical_hash(entry.path()).int_err()?,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    fn write_archive(
+        archive_path: &Path,
+        dataset_dir: &Path,
+        manifest: &DatasetArchiveManifest,
+    ) -> Result<(), InternalError> {
+        let manifest_data = serde_yaml::to_string(&Manifest {
+            kind: DATASET_ARCHIVE_MANIFEST_KIND.to_owned(),
+            version: 1,
+            content: manifest.clone(),
+        })
+        .int_err()?
+        .into_bytes();
+
+        // Write into a temporary file first so that an interrupted export does not
+        // leave a truncated archive behind
+        let mut tmp_path = archive_path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        let file = std::fs::File::create(&tmp_path).int_err()?;
+        let encoder = zstd::Encoder::new(file, ARCHIVE_COMPRESSION_LEVEL).int_err()?;
+        let mut builder = tar::Builder::new(encoder);
+
+        let mut header = tar::Header::new_gnu();
+        header.set_size(manifest_data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder
+            .append_data(
+                &mut header,
+                DATASET_ARCHIVE_MANIFEST_PATH,
+                &manifest_data[..],
+            )
+            .int_err()?;
+
+        for entry in &manifest.entries {
+            builder
+                .append_path_with_name(
+                    dataset_dir.join(&entry.path),
+                    format!("{DATASET_ARCHIVE_DATA_DIR}/{}", entry.path),
+                )
+                .int_err()?;
+        }
+
+        let file = builder.into_inner().int_err()?.finish().int_err()?;
+        file.sync_all().int_err()?;
+
+        std::fs::rename(&tmp_path, archive_path).int_err()?;
+        Ok(())
+    }
+
+    fn read_manifest(
+        entries: &mut tar::Entries<'_, impl Read>,
+    ) -> Result<(DatasetArchiveManifest, Multihash), ImportArchiveError> {
+        let Some(entry) = entries.next() else {
+            return Err(InvalidArchiveError::new("Archive is empty").into());
+        };
+        let mut entry = entry.int_err()?;
+
+        if entry.path().int_err()?.as_ref() != Path::new(DATASET_ARCHIVE_MANIFEST_PATH) {
+            return Err(InvalidArchiveError::new("Archive does not start with a manifest").into());
+        }
+
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data).int_err()?;
+
+        let manifest: Manifest<DatasetArchiveManifest> = serde_yaml::from_slice(&data)
+            .map_err(|e| InvalidArchiveError::new(format!("Malformed manifest: {e}")))?;
+
+        if manifest.kind != DATASET_ARCHIVE_MANIFEST_KIND {
+            return Err(InvalidArchiveError::new(format!(
+                "Unexpected manifest kind {}",
+                manifest.kind
+            ))
+            .into());
+        }
+
+        for entry in &manifest.content.entries {
+            let is_safe = !entry.path.is_empty()
+                && Path::new(&entry.path)
+                    .components()
+                    .all(|c| matches!(c, Component::Normal(_)));
+            if !is_safe {
+                return Err(
+                    InvalidArchiveError::new(format!("Invalid entry path {}", entry.path)).into(),
+                );
+            }
+        }
+
+        Ok((manifest.content, Multihash::from_digest_sha3_256(&data)))
+    }
+
+    /// Unpacks the dataset files into the staging directory, validating every
+    /// file against the manifest. Files left over from a previous attempt are
+    /// kept if their hashes match.
+    fn unpack_archive(
+        archive_path: &Path,
+        run_info_dir: &Path,
+    ) -> Result<(DatasetArchiveManifest, PathBuf), ImportArchiveError> {
+        let file = std::fs::File::open(archive_path).int_err()?;
+        let decoder = zstd::Decoder::new(file).int_err()?;
+        let mut archive = tar::Archive::new(decoder);
+        let mut entries = archive.entries().int_err()?;
+
+        let (manifest, manifest_hash) = Self::read_manifest(&mut entries)?;
+
+        // Staging directory is derived from the manifest hash to be able to resume
+        // the import of the same archive
+        let staging_dir = run_info_dir.join(format!("archive-import-{manifest_hash}"));
+        let layout = DatasetLayout::create(staging_dir.join(DATASET_ARCHIVE_DATA_DIR)).int_err()?;
+
+        let mut num_unpacked = 0;
+
+        for entry in entries {
+            let mut entry = entry.int_err()?;
+
+            let entry_path = entry.path().int_err()?.to_string_lossy().into_owned();
+
+            // Links could point the unpacked files outside of the staging directory
+            match entry.header().entry_type() {
+                tar::EntryType::Regular => {}
+                tar::EntryType::Directory => continue,
+                entry_type => {
+                    return Err(InvalidArchiveError::new(format!(
+                        "Entry {entry_path} has unsupported type {entry_type:?}"
+                    ))
+                    .into());
+                }
+            }
+            let Some(rel_path) = entry_path
+                .strip_prefix(DATASET_ARCHIVE_DATA_DIR)
+                .and_then(|p| p.strip_prefix('/'))
+            else {
+                return Err(
+                    InvalidArchiveError::new(format!("Unexpected entry {entry_path}")).into(),
+                );
+            };
+
+            let Some(expected) = manifest.entry(rel_path) else {
+                return Err(InvalidArchiveError::new(format!(
+                    "Entry {entry_path} is not listed in the manifest"
+                ))
+                .into());
+            };
+
+            num_unpacked += 1;
+
+            let target_path = layout.root_dir.join(&expected.path);
+            if target_path.is_file()
+                && get_file_physical_hash(&target_path).int_err()? == expected.physical_hash
+            {
+                continue;
+            }
+
+            if let Some(parent) = target_path.parent() {
+                std::fs::create_dir_all(parent).int_err()?;
+            }
+
+            let mut partial_path = target_path.as_os_str().to_owned();
+            partial_path.push(".partial");
+            let partial_path = PathBuf::from(partial_path);
+
+            entry.unpack(&partial_path).int_err()?;
+
+            let actual = get_file_physical_hash(&partial_path).int_err()?;
+            if actual != expected.physical_hash {
+                std::fs::remove_file(&partial_path).int_err()?;
+                return Err(ArchiveIntegrityError {
+                    path: expected.path.clone(),
+                    expected: expected.physical_hash.clone(),
+                    actual,
+                }
+                .into());
+            }
+
+            std::fs::rename(&partial_path, &target_path).int_err()?;
+        }
+
+        if num_unpacked != manifest.entries.len() {
+            return Err(InvalidArchiveError::new(format!(
+                "Archive contains {num_unpacked} files while manifest lists {}",
+                manifest.entries.len()
+            ))
+            .into());
+        }
+
+        Ok((manifest, layout.root_dir))
+    }
+
+    /// Syncs the unpacked dataset into the workspace and verifies the result
+    async fn import_unpacked(
+        &self,
+        dataset_dir: &Path,
+        dataset_alias: &DatasetAlias,
+    ) -> Result<(DatasetHandle, SyncResult), ImportArchiveError> {
+        // Not trusting the source makes sync re-validate hashes of all objects it
+        // transfers into the workspace
+        let sync_result = self
+            .sync_svc
+            .sync(
+                &Self::dir_url(dataset_dir)?.into(),
+                &dataset_alias.as_any_ref(),
+                SyncOptions {
+                    trust_source: Some(false),
+                    create_if_not_exists: true,
+                    ..Default::default()
+                },
+                None,
+            )
+            .await?;
+
+        let dataset_handle = self
+            .dataset_repo
+            .resolve_dataset_ref(&dataset_alias.as_local_ref())
+            .await
+            .int_err()?;
+
+        self.verification_svc
+            .verify(
+                &dataset_handle.as_local_ref(),
+                (None, None),
+                VerificationOptions {
+                    check_integrity: true,
+                    check_logical_hashes: true,
+                    replay_transformations: false,
+                },
+                None,
+            )
+            .await
+            .outcome?;
+
+        Ok((dataset_handle, sync_result))
+    }
+
+    async fn delete_partially_imported(&self, dataset_alias: &DatasetAlias) {
+        let res = self
+            .delete_dataset_use_case
+            .execute_via_ref(&dataset_alias.as_local_ref())
+            .await;
+
+        match res {
+            Ok(()) | Err(DeleteDatasetError::NotFound(_)) => {}
+            Err(err) => {
+                tracing::error!(
+                    %dataset_alias,
+                    error = ?err,
+                    error_msg = %err,
+                    "Failed to delete partially imported dataset",
+                );
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DatasetArchiveService for DatasetArchiveServiceImpl {
+    #[tracing::instrument(level = "info", skip_all, fields(%dataset_ref, ?archive_path))]
+    async fn export_archive(
+        &self,
+        dataset_ref: &DatasetRef,
+        archive_path: &Path,
+    ) -> Result<ExportArchiveResult, ExportArchiveError> {
+        let dataset_handle = self.dataset_repo.resolve_dataset_ref(dataset_ref).await?;
+
+        self.dataset_action_authorizer
+            .check_action_allowed(&dataset_handle, auth::DatasetAction::Read)
+            .await?;
+
+        let staging_dir = self
+            .run_info_dir
+            .join(get_random_name(Some("archive-export-"), 10));
+        let dataset_dir = staging_dir.join(DATASET_ARCHIVE_DATA_DIR);
+        std::fs::create_dir_all(&staging_dir).int_err()?;
+
+        let res = async {
+            // Sync service takes care of copying all blocks, refs, data and checkpoints
+            // of the dataset in a consistent way
+            let sync_result = self
+                .sync_svc
+                .sync(
+                    &dataset_handle.as_any_ref(),
+                    &Self::dir_url(&dataset_dir)?.into(),
+                    SyncOptions {
+                        create_if_not_exists: true,
+                        ..Default::default()
+                    },
+                    None,
+                )
+                .await?;
+
+            let SyncResult::Updated { new_head, .. } = sync_result else {
+                return Err(InternalError::new("Dataset copy turned out to be up-to-date").into());
+            };
+
+            let archive_path = archive_path.to_path_buf();
+            let dataset_id = dataset_handle.id.clone();
+            let dataset_alias = dataset_handle.alias.clone();
+
+            let manifest = tokio::task::spawn_blocking(move || {
+                let manifest = DatasetArchiveManifest {
+                    dataset_id,
+                    dataset_alias,
+                    head: new_head,
+                    entries: Self::collect_entries(&dataset_dir)?,
+                };
+                Self::write_archive(&archive_path, &dataset_dir, &manifest)?;
+                Ok::<_, InternalError>(manifest)
+            })
+            .await
+            .int_err()??;
+
+            Ok::<_, ExportArchiveError>(manifest)
+        }
+        .await;
+
+        std::fs::remove_dir_all(&staging_dir).int_err()?;
+
+        let manifest = res?;
+
+        tracing::info!(
+            head = %manifest.head,
+            num_entries = manifest.entries.len(),
+            "Exported dataset archive",
+        );
+
+        Ok(ExportArchiveResult {
+            dataset_handle,
+            archive_path: archive_path.to_path_buf(),
+            manifest,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip_all, fields(?archive_path, ?dataset_alias))]
+    async fn import_archive(
+        &self,
+        archive_path: &Path,
+        dataset_alias: Option<DatasetAlias>,
+    ) -> Result<ImportArchiveResult, ImportArchiveError> {
+        let (manifest, dataset_dir) = {
+            let archive_path = archive_path.to_path_buf();
+            let run_info_dir = self.run_info_dir.inner().clone();
+            tokio::task::spawn_blocking(move || Self::unpack_archive(&archive_path, &run_info_dir))
+                .await
+                .int_err()??
+        };
+
+        // Account of the source workspace is not meaningful here
+        let dataset_alias = dataset_alias.unwrap_or_else(|| {
+            DatasetAlias::new(None, manifest.dataset_alias.dataset_name.clone())
+        });
+
+        // Manifest is the only part of the archive that is not covered by the hashes
+        // of the chain, so the unpacked chain must end with the block it declares
+        // before anything is imported into the workspace
+        let head = DatasetFactoryImpl::get_local_fs(DatasetLayout::new(&dataset_dir))
+            .as_metadata_chain()
+            .resolve_ref(&BlockRef::Head)
+            .await
+            .map_err(|e| match e {
+                GetRefError::NotFound(_) => {
+                    ImportArchiveError::from(InvalidArchiveError::new("Dataset has no head"))
+                }
+                e => e.int_err().into(),
+            })?;
+
+        if head != manifest.head {
+            return Err(ArchiveHeadMismatchError {
+                expected: manifest.head,
+                actual: head,
+            }
+            .into());
+        }
+
+        let already_existed = self
+            .dataset_repo
+            .try_resolve_dataset_ref(&dataset_alias.as_local_ref())
+            .await?
+            .is_some();
+
+        let (dataset_handle, sync_result) =
+            match self.import_unpacked(&dataset_dir, &dataset_alias).await {
+                Ok(res) => res,
+                Err(err) => {
+                    // Partially imported dataset is removed to let the import be retried,
+                    // while the staging files are kept for it
+                    if !already_existed {
+                        self.delete_partially_imported(&dataset_alias).await;
+                    }
+                    return Err(err);
+                }
+            };
+
+        // Staging files are only removed once the dataset is fully imported
+        if let Some(staging_dir) = dataset_dir.parent() {
+            std::fs::remove_dir_all(staging_dir).int_err()?;
+        }
+
+        tracing::info!(
+            %dataset_handle,
+            head = %manifest.head,
+            "Imported dataset archive",
+        );
+
+        Ok(ImportArchiveResult {
+            dataset_handle,
+            manifest,
+            sync_result,
+        })
+    }
+}
@@ -0,0 +1,367 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use datafusion::arrow::array::RecordBatch;
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::prelude::*;
+use dill::*;
+use futures::TryStreamExt;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::*;
+use kamu_data_utils::data::dataframe_ext::*;
+use kamu_ingest_datafusion::MergeStrategySnapshot;
+use opendatafabric::*;
+
+use crate::new_session_context;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct DatasetDiffServiceImpl {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    object_store_registry: Arc<dyn ObjectStoreRegistry>,
+}
+
+#[component(pub)]
+#[interface(dyn DatasetDiffService)]
+impl DatasetDiffServiceImpl {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        object_store_registry: Arc<dyn ObjectStoreRegistry>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_action_authorizer,
+            object_store_registry,
+        }
+    }
+
+    async fn resolve_dataset(
+        &self,
+        dataset_ref: &DatasetRef,
+    ) -> Result<(DatasetHandle, Arc<dyn Dataset>), DiffError> {
+        let hdl = self.dataset_repo.resolve_dataset_ref(dataset_ref).await?;
+
+        self.dataset_action_authorizer
+            .check_action_allowed(&hdl, auth::DatasetAction::Read)
+            .await?;
+
+        let dataset = self.dataset_repo.get_dataset_by_handle(&hdl);
+        Ok((hdl, dataset))
+    }
+
+    async fn resolve_head(
+        &self,
+        dataset: &dyn Dataset,
+        head: Option<&Multihash>,
+    ) -> Result<Multihash, DiffError> {
+        let chain = dataset.as_metadata_chain();
+
+        if let Some(head) = head {
+            match chain.get_block(head).await {
+                Ok(_) => Ok(head.clone()),
+                Err(GetBlockError::NotFound(e)) => Err(e.into()),
+                Err(e) => Err(e.int_err().into()),
+            }
+        } else {
+            Ok(chain.resolve_ref(&BlockRef::Head).await.int_err()?)
+        }
+    }
+
+    /// Ensures that the old head is an ancestor of the new one, as otherwise
+    /// the ledgers would not be comparable
+    async fn ensure_ancestor(
+        &self,
+        dataset: &dyn Dataset,
+        old_head: &Multihash,
+        new_head: &Multihash,
+    ) -> Result<(), DiffError> {
+        let mut blocks =
+            dataset
+                .as_metadata_chain()
+                .iter_blocks_interval(new_head, Some(old_head), false);
+
+        loop {
+            match blocks.try_next().await {
+                Ok(Some(_)) => {}
+                Ok(None) => return Ok(()),
+                Err(IterBlocksError::InvalidInterval(e)) => return Err(e.into()),
+                Err(e) => return Err(e.int_err().into()),
+            }
+        }
+    }
+
+    async fn get_schema(
+        &self,
+        hdl: &DatasetHandle,
+        dataset: &dyn Dataset,
+        head: &Multihash,
+    ) -> Result<SchemaRef, DiffError> {
+        dataset
+            .as_metadata_chain()
+            .accept_one_by_hash(head, SearchSetDataSchemaVisitor::new())
+            .await
+            .int_err()?
+            .into_event()
+            .map(|e| e.schema_as_arrow())
+            .transpose()
+            .int_err()?
+            .ok_or_else(|| {
+                DatasetSchemaNotAvailableError {
+                    dataset_ref: hdl.as_local_ref(),
+                }
+                .into()
+            })
+    }
+
+    async fn get_vocab(
+        &self,
+        dataset: &dyn Dataset,
+        head: &Multihash,
+    ) -> Result<DatasetVocabulary, InternalError> {
+        Ok(dataset
+            .as_metadata_chain()
+            .accept_one_by_hash(head, SearchSetVocabVisitor::new())
+            .await
+            .int_err()?
+            .into_event()
+            .unwrap_or_default()
+            .into())
+    }
+
+    /// Returns primary key of the latest source's merge strategy if it has one
+    async fn get_primary_key(
+        &self,
+        dataset: &dyn Dataset,
+        head: &Multihash,
+    ) -> Result<Option<Vec<String>>, InternalError> {
+        let mut blocks = dataset
+            .as_metadata_chain()
+            .iter_blocks_interval(head, None, false);
+
+        while let Some((_, block)) = blocks.try_next().await.int_err()? {
+            let merge = match block.event {
+                MetadataEvent::SetPollingSource(e) => e.merge,
+                MetadataEvent::AddPushSource(e) => e.merge,
+                _ => continue,
+            };
+
+            return Ok(match merge {
+                opendatafabric::MergeStrategy::Append(_) => None,
+                opendatafabric::MergeStrategy::Ledger(m) => Some(m.primary_key),
+                opendatafabric::MergeStrategy::Snapshot(m) => Some(m.primary_key),
+            });
+        }
+
+        Ok(None)
+    }
+
+    /// Reads all records added up until the specified block, using the
+    /// specified schema for all slices, as the ones written before the
+    /// schema evolved may lack some of its columns
+    async fn read_ledger(
+        &self,
+        ctx: &SessionContext,
+        dataset: &dyn Dataset,
+        head: &Multihash,
+        schema: SchemaRef,
+    ) -> Result<DataFrame, InternalError> {
+        let data_slices: Vec<_> = dataset
+            .as_metadata_chain()
+            .iter_blocks_interval(head, None, false)
+            .filter_data_stream_blocks()
+            .try_filter_map(|(_, b)| futures::future::ready(Ok(b.event.new_data)))
+            .try_collect()
+            .await
+            .int_err()?;
+
+        if data_slices.is_empty() {
+            return ctx.read_batch(RecordBatch::new_empty(schema)).int_err();
+        }
+
+        let data_repo = dataset.as_data_repo();
+        let mut data_slice_urls = Vec::with_capacity(data_slices.len());
+        for data_slice in data_slices.iter().rev() {
+            data_slice_urls.push(data_repo.get_internal_url(&data_slice.physical_hash).await);
+        }
+
+        ctx.read_parquet(
+            data_slice_urls,
+            datafusion::execution::options::ParquetReadOptions {
+                schema: Some(&schema),
+                file_extension: "",
+                ..Default::default()
+            },
+        )
+        .await
+        .int_err()
+    }
+
+    fn diff_ledgers(
+        vocab: DatasetVocabulary,
+        key: &DiffKey,
+        old: DataFrame,
+        new: DataFrame,
+    ) -> Result<DataFrame, InternalError> {
+        let (primary_key, old, new) = match key {
+            DiffKey::PrimaryKey(primary_key) => {
+                let strategy = MergeStrategySnapshot::new(
+                    vocab.clone(),
+                    opendatafabric::MergeStrategySnapshot {
+                        primary_key: primary_key.clone(),
+                        compare_columns: None,
+                    },
+                );
+                (
+                    primary_key.clone(),
+                    strategy.project(old)?,
+                    strategy.project(new)?,
+                )
+            }
+            // Without a primary key records are matched by their position in the ledger
+            DiffKey::Offset => (vec![vocab.offset_column.clone()], old, new),
+        };
+
+        let mut system_columns = vec![
+            vocab.operation_type_column.as_str(),
+            vocab.system_time_column.as_str(),
+        ];
+        if *key != DiffKey::Offset {
+            system_columns.push(vocab.offset_column.as_str());
+        }
+
+        let old = old.without_columns(&system_columns).int_err()?;
+        let new = new.without_columns(&system_columns).int_err()?;
+
+        let strategy = MergeStrategySnapshot::new(
+            vocab.clone(),
+            opendatafabric::MergeStrategySnapshot {
+                primary_key,
+                compare_columns: None,
+            },
+        );
+
+        strategy.diff(old, new)
+    }
+
+    fn get_key(options: DiffOptions, primary_key: Option<Vec<String>>) -> DiffKey {
+        match options.primary_key.or(primary_key) {
+            Some(primary_key) if !primary_key.is_empty() => DiffKey::PrimaryKey(primary_key),
+            _ => DiffKey::Offset,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DatasetDiffService for DatasetDiffServiceImpl {
+    #[tracing::instrument(level = "info", skip_all, fields(%dataset_ref, ?old_head, ?new_head))]
+    async fn diff_blocks(
+        &self,
+        dataset_ref: &DatasetRef,
+        old_head: Option<&Multihash>,
+        new_head: Option<&Multihash>,
+        options: DiffOptions,
+    ) -> Result<DiffResponse, DiffError> {
+        let (hdl, dataset) = self.resolve_dataset(dataset_ref).await?;
+
+        let new_head = self.resolve_head(dataset.as_ref(), new_head).await?;
+        let old_head = match old_head {
+            Some(h) => {
+                let old_head = self.resolve_head(dataset.as_ref(), Some(h)).await?;
+                self.ensure_ancestor(dataset.as_ref(), &old_head, &new_head)
+                    .await?;
+                Some(old_head)
+            }
+            None => None,
+        };
+
+        let schema = self.get_schema(&hdl, dataset.as_ref(), &new_head).await?;
+        let vocab = self.get_vocab(dataset.as_ref(), &new_head).await?;
+        let key = Self::get_key(
+            options,
+            self.get_primary_key(dataset.as_ref(), &new_head).await?,
+        );
+
+        let ctx = new_session_context(self.object_store_registry.clone());
+
+        let new = self
+            .read_ledger(&ctx, dataset.as_ref(), &new_head, schema.clone())
+            .await?;
+        let old = match &old_head {
+            Some(old_head) => {
+                self.read_ledger(&ctx, dataset.as_ref(), old_head, schema)
+                    .await?
+            }
+            None => ctx.read_batch(RecordBatch::new_empty(schema)).int_err()?,
+        };
+
+        let df = Self::diff_ledgers(vocab, &key, old, new)?;
+
+        Ok(DiffResponse { df, key })
+    }
+
+    #[tracing::instrument(level = "info", skip_all, fields(%old_dataset_ref, %new_dataset_ref))]
+    async fn diff_datasets(
+        &self,
+        old_dataset_ref: &DatasetRef,
+        new_dataset_ref: &DatasetRef,
+        options: DiffOptions,
+    ) -> Result<DiffResponse, DiffError> {
+        let (old_hdl, old_dataset) = self.resolve_dataset(old_dataset_ref).await?;
+        let (new_hdl, new_dataset) = self.resolve_dataset(new_dataset_ref).await?;
+
+        let old_head = self.resolve_head(old_dataset.as_ref(), None).await?;
+        let new_head = self.resolve_head(new_dataset.as_ref(), None).await?;
+
+        let old_schema = self
+            .get_schema(&old_hdl, old_dataset.as_ref(), &old_head)
+            .await?;
+        let new_schema = self
+            .get_schema(&new_hdl, new_dataset.as_ref(), &new_head)
+            .await?;
+
+        let same_columns = old_schema.fields().len() == new_schema.fields().len()
+            && old_schema
+                .fields()
+                .iter()
+                .zip(new_schema.fields().iter())
+                .all(|(a, b)| a.name() == b.name() && a.data_type() == b.data_type());
+
+        if !same_columns {
+            return Err(DiffSchemaMismatchError {
+                old_dataset: old_hdl.alias,
+                new_dataset: new_hdl.alias,
+            }
+            .into());
+        }
+
+        let vocab = self.get_vocab(new_dataset.as_ref(), &new_head).await?;
+        let key = Self::get_key(
+            options,
+            self.get_primary_key(new_dataset.as_ref(), &new_head)
+                .await?,
+        );
+
+        let ctx = new_session_context(self.object_store_registry.clone());
+
+        let old = self
+            .read_ledger(&ctx, old_dataset.as_ref(), &old_head, old_schema)
+            .await?;
+        let new = self
+            .read_ledger(&ctx, new_dataset.as_ref(), &new_head, new_schema)
+            .await?;
+
+        let df = Self::diff_ledgers(vocab, &key, old, new)?;
+
+        Ok(DiffResponse { df, key })
+    }
+}
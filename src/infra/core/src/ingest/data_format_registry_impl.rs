@@ -52,6 +52,16 @@ impl DataFormatRegistryImpl {
         media_type: MediaType::PARQUET,
         file_extensions: &["parquet"],
     };
+    pub const FMT_AVRO: DataFormatDesc = DataFormatDesc {
+        short_name: "Avro",
+        media_type: MediaType::AVRO,
+        file_extensions: &["avro"],
+    };
+    pub const FMT_ORC: DataFormatDesc = DataFormatDesc {
+        short_name: "ORC",
+        media_type: MediaType::ORC,
+        file_extensions: &["orc"],
+    };
     pub const FMT_ESRI_SHAPEFILE: DataFormatDesc = DataFormatDesc {
         short_name: "Shapefile",
         media_type: MediaType::ESRI_SHAPEFILE,
@@ -73,6 +83,8 @@ impl DataFormatRegistry for DataFormatRegistryImpl {
             Self::FMT_GEOJSON,
             Self::FMT_NDGEOJSON,
             Self::FMT_PARQUET,
+            Self::FMT_AVRO,
+            Self::FMT_ORC,
             Self::FMT_ESRI_SHAPEFILE,
         ]
     }
@@ -97,6 +109,8 @@ impl DataFormatRegistry for DataFormatRegistryImpl {
             ReadStep::GeoJson(_) => Self::FMT_GEOJSON,
             ReadStep::NdGeoJson(_) => Self::FMT_NDGEOJSON,
             ReadStep::Parquet(_) => Self::FMT_PARQUET,
+            ReadStep::Avro(_) => Self::FMT_AVRO,
+            ReadStep::Orc(_) => Self::FMT_ORC,
             ReadStep::EsriShapefile(_) => Self::FMT_ESRI_SHAPEFILE,
         }
     }
@@ -119,6 +133,8 @@ impl DataFormatRegistry for DataFormatRegistryImpl {
                 Arc::new(ReaderEsriShapefile::new(ctx, conf, temp_path).await?)
             }
             ReadStep::Parquet(conf) => Arc::new(ReaderParquet::new(ctx, conf).await?),
+            ReadStep::Avro(conf) => Arc::new(ReaderAvro::new(ctx, conf).await?),
+            ReadStep::Orc(conf) => Arc::new(ReaderOrc::new(ctx, conf, temp_path).await?),
         };
 
         Ok(reader)
@@ -168,6 +184,8 @@ impl DataFormatRegistry for DataFormatRegistryImpl {
             MediaType::GEOJSON => Ok(ReadStepGeoJson { schema }.into()),
             MediaType::NDGEOJSON => Ok(ReadStepNdGeoJson { schema }.into()),
             MediaType::PARQUET => Ok(ReadStepParquet { schema }.into()),
+            MediaType::AVRO => Ok(ReadStepAvro { schema }.into()),
+            MediaType::ORC => Ok(ReadStepOrc { schema }.into()),
             MediaType::ESRI_SHAPEFILE | MediaTypeRef("x-gis/x-shapefile") => {
                 Ok(ReadStepEsriShapefile {
                     schema,
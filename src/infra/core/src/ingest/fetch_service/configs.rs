@@ -69,6 +69,28 @@ impl Default for MqttSourceConfig {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Clone)]
+pub struct KafkaSourceConfig {
+    /// Maximum time in milliseconds the broker is allowed to wait for new
+    /// records to arrive before answering a fetch request. Receiving an empty
+    /// response is considered a sign that we have "caught up" with the
+    /// partition.
+    pub broker_max_wait_ms: i32,
+    /// Upper limit on the size of a single fetch response in bytes.
+    pub fetch_max_bytes: i32,
+}
+
+impl Default for KafkaSourceConfig {
+    fn default() -> Self {
+        Self {
+            broker_max_wait_ms: 1_000,
+            fetch_max_bytes: 5 * 1024 * 1024,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Clone)]
 pub struct EthereumSourceConfig {
     /// Default RPC endpoints to use if source does not specify one explicitly.
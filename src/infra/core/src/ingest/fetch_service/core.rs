@@ -42,6 +42,9 @@ pub struct FetchService {
 
     #[cfg_attr(not(feature = "ingest-mqtt"), allow(dead_code))]
     pub(super) mqtt_source_config: Arc<MqttSourceConfig>,
+
+    #[cfg_attr(not(feature = "ingest-kafka"), allow(dead_code))]
+    pub(super) kafka_source_config: Arc<KafkaSourceConfig>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -54,6 +57,7 @@ impl FetchService {
         source_config: Option<Arc<SourceConfig>>,
         http_source_config: Option<Arc<HttpSourceConfig>>,
         mqtt_source_config: Option<Arc<MqttSourceConfig>>,
+        kafka_source_config: Option<Arc<KafkaSourceConfig>>,
         eth_source_config: Option<Arc<EthereumSourceConfig>>,
         dataset_key_value_svc: Arc<dyn DatasetKeyValueService>,
         run_info_dir: Arc<RunInfoDir>,
@@ -63,6 +67,7 @@ impl FetchService {
             source_config: source_config.unwrap_or_default(),
             http_source_config: http_source_config.unwrap_or_default(),
             mqtt_source_config: mqtt_source_config.unwrap_or_default(),
+            kafka_source_config: kafka_source_config.unwrap_or_default(),
             eth_source_config: eth_source_config.unwrap_or_default(),
             dataset_key_value_svc,
             run_info_dir,
@@ -173,6 +178,37 @@ impl FetchService {
                     }
                 }
             }
+            FetchStep::Kafka(fetch) => {
+                cfg_if::cfg_if! {
+                    if #[cfg(feature = "ingest-kafka")] {
+                        self.fetch_kafka(
+                            fetch,
+                            prev_source_state,
+                            target_path,
+                            &listener,
+                        )
+                        .await
+                    } else {
+                        unimplemented!("Kamu was compiled without Kafka support")
+                    }
+                }
+            }
+            FetchStep::Sql(fetch) => {
+                cfg_if::cfg_if! {
+                    if #[cfg(feature = "ingest-sql")] {
+                        self.fetch_sql(
+                            fetch,
+                            prev_source_state,
+                            target_path,
+                            dataset_env_vars,
+                            &listener,
+                        )
+                        .await
+                    } else {
+                        unimplemented!("Kamu was compiled without SQL database support")
+                    }
+                }
+            }
         }
     }
 
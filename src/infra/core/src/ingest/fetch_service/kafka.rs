@@ -0,0 +1,246 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use internal_error::{ErrorIntoInternal, ResultIntoInternal};
+use kamu_core::*;
+use opendatafabric::*;
+
+use super::*;
+use crate::PollingSourceState;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl FetchService {
+    // TODO: Support SASL/TLS auth and consumer group offset management
+    pub(crate) async fn fetch_kafka(
+        &self,
+        fetch: &FetchStepKafka,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        use std::io::Write as _;
+
+        use rskafka::client::error::{Error as KafkaError, ProtocolError};
+        use rskafka::client::partition::{OffsetAt, UnknownTopicHandling};
+        use rskafka::client::ClientBuilder;
+
+        // Get last state
+        let mut offsets = match prev_source_state {
+            None => BTreeMap::new(),
+            Some(PollingSourceState::ETag(s)) => decode_partition_offsets(s)?,
+            Some(PollingSourceState::LastModified(t)) => {
+                return Err(InvalidSourceState::new(
+                    t.to_rfc3339(),
+                    "Kafka source expects partition offsets in the ETag state",
+                )
+                .into());
+            }
+        };
+
+        tracing::debug!(brokers = ?fetch.brokers, topic = %fetch.topic, "Connecting to the Kafka broker");
+
+        let client = ClientBuilder::new(fetch.brokers.clone())
+            .build()
+            .await
+            .int_err()?;
+
+        let partitions: Vec<i32> = if let Some(partitions) = &fetch.partitions {
+            partitions.clone()
+        } else {
+            let topics = client.list_topics().await.int_err()?;
+            let Some(topic) = topics.into_iter().find(|t| t.name == fetch.topic) else {
+                return Err(format!("Topic {} does not exist", fetch.topic)
+                    .int_err()
+                    .into());
+            };
+            topic.partitions.into_iter().collect()
+        };
+
+        let mut fetched_bytes = 0;
+        let mut fetched_records = 0;
+        let mut has_more = false;
+        let mut file = std::fs::File::create(target_path).int_err()?;
+
+        let max_records = self.source_config.target_records_per_slice;
+        let max_bytes = self.kafka_source_config.fetch_max_bytes;
+        let max_wait_ms = self.kafka_source_config.broker_max_wait_ms;
+
+        'partitions: for partition in partitions {
+            let partition_client = client
+                .partition_client(fetch.topic.clone(), partition, UnknownTopicHandling::Error)
+                .await
+                .int_err()?;
+
+            let mut next_offset = if let Some(offset) = offsets.get(&partition) {
+                *offset
+            } else {
+                partition_client
+                    .get_offset(OffsetAt::Earliest)
+                    .await
+                    .int_err()?
+            };
+
+            loop {
+                // Limit number of records read if they keep flowing faster than we can
+                // consume them
+                if fetched_records >= max_records {
+                    has_more = true;
+                    offsets.insert(partition, next_offset);
+                    break 'partitions;
+                }
+
+                let (records, high_watermark) = match partition_client
+                    .fetch_records(next_offset, 1..max_bytes, max_wait_ms)
+                    .await
+                {
+                    Ok(res) => res,
+                    // Stored offset is no longer available, e.g. records were deleted by the
+                    // broker's retention policy or the topic was re-created
+                    Err(KafkaError::ServerError {
+                        protocol_error: ProtocolError::OffsetOutOfRange,
+                        ..
+                    }) => {
+                        let earliest_offset = partition_client
+                            .get_offset(OffsetAt::Earliest)
+                            .await
+                            .int_err()?;
+
+                        tracing::warn!(
+                            topic = %fetch.topic,
+                            partition,
+                            offset = next_offset,
+                            earliest_offset,
+                            "Offset is out of range, resuming from the earliest available offset",
+                        );
+
+                        // Avoid looping forever if the broker keeps rejecting the offset
+                        if earliest_offset == next_offset {
+                            return Err(format!(
+                                "Offset {next_offset} of partition {partition} is out of range"
+                            )
+                            .int_err()
+                            .into());
+                        }
+
+                        next_offset = earliest_offset;
+                        continue;
+                    }
+                    Err(err) => return Err(err.int_err().into()),
+                };
+
+                // Empty response means we have caught up with the partition
+                if records.is_empty() {
+                    break;
+                }
+
+                for record in records {
+                    // Compressed batches may include records preceding the requested offset
+                    if record.offset < next_offset {
+                        continue;
+                    }
+                    if fetched_records >= max_records {
+                        break;
+                    }
+
+                    next_offset = record.offset + 1;
+
+                    // Skip tombstones
+                    let Some(value) = record.record.value else {
+                        continue;
+                    };
+
+                    // TODO: Assuming that payload is JSON and formatting it as line-delimited
+                    if fetched_bytes != 0 {
+                        file.write_all(b"\n").int_err()?;
+                    }
+                    let json = std::str::from_utf8(&value)
+                        .map_err(|e| {
+                            ReadError::from(BadInputError::new(format!(
+                                "Record at offset {} of partition {partition} is not a valid \
+                                 UTF-8 string: {e}",
+                                record.offset
+                            )))
+                        })?
+                        .trim();
+                    file.write_all(json.as_bytes()).int_err()?;
+
+                    fetched_bytes += value.len() as u64 + 1;
+                    fetched_records += 1;
+
+                    listener.on_progress(&FetchProgress {
+                        fetched_bytes,
+                        total_bytes: TotalBytes::Unknown,
+                    });
+                }
+
+                if next_offset >= high_watermark {
+                    break;
+                }
+            }
+
+            offsets.insert(partition, next_offset);
+        }
+
+        tracing::debug!(
+            fetched_bytes,
+            fetched_records,
+            has_more,
+            ?offsets,
+            "Finished consuming from the Kafka topic"
+        );
+
+        file.flush().int_err()?;
+
+        if fetched_records == 0 {
+            Ok(FetchResult::UpToDate)
+        } else {
+            Ok(FetchResult::Updated(FetchResultUpdated {
+                source_state: Some(PollingSourceState::ETag(encode_partition_offsets(&offsets))),
+                source_event_time: None,
+                has_more,
+                zero_copy_path: None,
+            }))
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Encodes next offsets to consume per partition as `partition:offset` pairs
+/// separated by commas
+fn encode_partition_offsets(offsets: &BTreeMap<i32, i64>) -> String {
+    offsets
+        .iter()
+        .map(|(partition, offset)| format!("{partition}:{offset}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn decode_partition_offsets(s: &str) -> Result<BTreeMap<i32, i64>, InvalidSourceState> {
+    s.split(',')
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let malformed =
+                || InvalidSourceState::new(s, format!("Malformed partition offset '{p}'"));
+
+            let (partition, offset) = p.split_once(':').ok_or_else(malformed)?;
+            Ok((
+                partition.parse().map_err(|_| malformed())?,
+                offset.parse().map_err(|_| malformed())?,
+            ))
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
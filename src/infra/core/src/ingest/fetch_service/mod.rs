@@ -21,5 +21,9 @@ mod file;
 #[cfg(feature = "ingest-ftp")]
 mod ftp;
 mod http;
+#[cfg(feature = "ingest-kafka")]
+mod kafka;
 #[cfg(feature = "ingest-mqtt")]
 mod mqtt;
+#[cfg(feature = "ingest-sql")]
+mod sql;
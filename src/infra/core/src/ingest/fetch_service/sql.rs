@@ -0,0 +1,447 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_core::*;
+use kamu_datasets::DatasetEnvVar;
+use opendatafabric::*;
+
+use super::*;
+use crate::PollingSourceState;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl FetchService {
+    // TODO: Consider streaming rows directly into Arrow batches instead of
+    // converting them to NdJson
+    pub(crate) async fn fetch_sql(
+        &self,
+        fetch: &FetchStepSql,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        dataset_env_vars: &HashMap<String, DatasetEnvVar>,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError> {
+        let connection_url = self.template_string(&fetch.connection_url, dataset_env_vars)?;
+
+        // Drivers are used directly instead of `sqlx::Any`, as the latter doesn't
+        // support temporal types that are commonly used as cursor columns
+        let scheme = connection_url
+            .split_once(':')
+            .map(|(scheme, _)| scheme)
+            .unwrap_or_default();
+
+        match scheme {
+            "postgres" | "postgresql" => {
+                self.fetch_sql_with::<sqlx::Postgres>(
+                    fetch,
+                    &connection_url,
+                    prev_source_state,
+                    target_path,
+                    listener,
+                )
+                .await
+            }
+            "mysql" | "mariadb" => {
+                self.fetch_sql_with::<sqlx::MySql>(
+                    fetch,
+                    &connection_url,
+                    prev_source_state,
+                    target_path,
+                    listener,
+                )
+                .await
+            }
+            "sqlite" => {
+                self.fetch_sql_with::<sqlx::Sqlite>(
+                    fetch,
+                    &connection_url,
+                    prev_source_state,
+                    target_path,
+                    listener,
+                )
+                .await
+            }
+            _ => Err(format!(
+                "Unsupported database '{scheme}', expected one of: postgres, mysql, sqlite"
+            )
+            .int_err()
+            .into()),
+        }
+    }
+
+    async fn fetch_sql_with<DB>(
+        &self,
+        fetch: &FetchStepSql,
+        connection_url: &str,
+        prev_source_state: Option<&PollingSourceState>,
+        target_path: &Path,
+        listener: &Arc<dyn FetchProgressListener>,
+    ) -> Result<FetchResult, PollingIngestError>
+    where
+        DB: SqlDriver,
+        for<'c> &'c mut DB::Connection: sqlx::Executor<'c, Database = DB>,
+        for<'q> DB::Arguments<'q>: sqlx::IntoArguments<'q, DB>,
+    {
+        use std::io::Write as _;
+
+        use futures::TryStreamExt;
+        use sqlx::{Column as _, Connection as _, Row as _};
+
+        // Get last state
+        let last_cursor_value = match prev_source_state {
+            None => None,
+            Some(PollingSourceState::ETag(s)) => Some(SqlValue::decode(s)?),
+            Some(PollingSourceState::LastModified(t)) => {
+                return Err(InvalidSourceState::new(
+                    t.to_rfc3339(),
+                    "SQL source expects the last cursor value in the ETag state",
+                )
+                .into());
+            }
+        };
+
+        let max_records = self.source_config.target_records_per_slice;
+        let user_query = fetch.query.trim().trim_end_matches(';');
+
+        // Only Postgres uses numbered placeholders
+        let placeholder = |n: usize| {
+            if connection_url.starts_with("postgres") {
+                format!("${n}")
+            } else {
+                "?".to_string()
+            }
+        };
+
+        tracing::debug!("Connecting to the database");
+
+        let mut connection = DB::Connection::connect(connection_url).await.int_err()?;
+
+        // Cursor values are not required to be unique, so instead of limiting the
+        // number of rows directly we find the cursor value of the last row that fits
+        // into the slice and fetch all rows up to and including it. This way rows
+        // sharing the same cursor value never get split between two slices.
+        let mut upper_cursor_value = None;
+
+        let sql = if let Some(cursor_column) = &fetch.cursor_column {
+            let cursor = quote_identifier(cursor_column, connection_url);
+
+            let mut predicate = format!("{cursor} IS NOT NULL");
+            if last_cursor_value.is_some() {
+                predicate += &format!(" AND {cursor} > {}", placeholder(1));
+            }
+
+            let bound_sql = format!(
+                "SELECT {cursor} FROM ({user_query}) AS src WHERE {predicate} ORDER BY {cursor} \
+                 LIMIT 1 OFFSET {}",
+                max_records.saturating_sub(1)
+            );
+
+            tracing::debug!(sql = %bound_sql, "Finding the upper cursor value of the slice");
+
+            let mut bound_query = sqlx::query::<DB>(&bound_sql);
+            if let Some(value) = &last_cursor_value {
+                bound_query = DB::bind_value(bound_query, value);
+            }
+
+            if let Some(row) = bound_query
+                .fetch_optional(&mut connection)
+                .await
+                .int_err()?
+            {
+                let Some(value) = DB::get_value(&row, 0)? else {
+                    return Err(format!("Column {cursor_column} has null cursor value")
+                        .int_err()
+                        .into());
+                };
+                upper_cursor_value = Some(value);
+
+                predicate += &format!(
+                    " AND {cursor} <= {}",
+                    placeholder(if last_cursor_value.is_some() { 2 } else { 1 })
+                );
+            }
+
+            format!("SELECT * FROM ({user_query}) AS src WHERE {predicate} ORDER BY {cursor}")
+        } else {
+            user_query.to_string()
+        };
+
+        let mut query = sqlx::query::<DB>(&sql);
+        if fetch.cursor_column.is_some() {
+            if let Some(value) = &last_cursor_value {
+                query = DB::bind_value(query, value);
+            }
+            if let Some(value) = &upper_cursor_value {
+                query = DB::bind_value(query, value);
+            }
+        }
+
+        tracing::debug!(%sql, "Executing query");
+
+        let mut fetched_bytes = 0;
+        let mut fetched_records = 0;
+        let mut new_cursor_value = None;
+        let mut file = std::fs::File::create(target_path).int_err()?;
+
+        {
+            let mut rows = query.fetch(&mut connection);
+
+            while let Some(row) = rows.try_next().await.int_err()? {
+                let mut record = serde_json::Map::new();
+                for (index, column) in row.columns().iter().enumerate() {
+                    let value = DB::get_value(&row, index)?;
+
+                    if fetch.cursor_column.as_deref() == Some(column.name()) && value.is_some() {
+                        new_cursor_value.clone_from(&value);
+                    }
+
+                    record.insert(
+                        column.name().to_string(),
+                        value.map_or(serde_json::Value::Null, |v| v.to_json()),
+                    );
+                }
+
+                if let Some(cursor) = &fetch.cursor_column {
+                    if !record.contains_key(cursor) {
+                        return Err(format!("Query result does not contain column {cursor}")
+                            .int_err()
+                            .into());
+                    }
+                }
+
+                let line = serde_json::to_string(&record).int_err()?;
+                file.write_all(line.as_bytes()).int_err()?;
+                file.write_all(b"\n").int_err()?;
+
+                fetched_bytes += line.len() as u64 + 1;
+                fetched_records += 1;
+
+                listener.on_progress(&FetchProgress {
+                    fetched_bytes,
+                    total_bytes: TotalBytes::Unknown,
+                });
+            }
+        }
+
+        connection.close().await.int_err()?;
+
+        tracing::debug!(
+            fetched_bytes,
+            fetched_records,
+            ?new_cursor_value,
+            "Finished fetching from the database"
+        );
+
+        file.flush().int_err()?;
+
+        if fetched_records == 0 {
+            return Ok(FetchResult::UpToDate);
+        }
+
+        let source_state = if let Some(value) = new_cursor_value {
+            Some(PollingSourceState::ETag(value.encode()))
+        } else {
+            prev_source_state.cloned()
+        };
+
+        Ok(FetchResult::Updated(FetchResultUpdated {
+            source_state,
+            source_event_time: None,
+            has_more: upper_cursor_value.is_some(),
+            zero_copy_path: None,
+        }))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Value of a column, the one of the cursor column is persisted in the source
+/// state
+#[derive(Debug, Clone)]
+enum SqlValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    TimestampTz(DateTime<Utc>),
+    Timestamp(NaiveDateTime),
+    Date(NaiveDate),
+}
+
+impl SqlValue {
+    fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Bool(v) => (*v).into(),
+            Self::Int(v) => (*v).into(),
+            Self::Float(v) => (*v).into(),
+            Self::String(v) => v.clone().into(),
+            Self::TimestampTz(v) => v.to_rfc3339_opts(SecondsFormat::AutoSi, true).into(),
+            Self::Timestamp(v) => v.format(TIMESTAMP_FORMAT).to_string().into(),
+            Self::Date(v) => v.format(DATE_FORMAT).to_string().into(),
+        }
+    }
+
+    /// Temporal values are tagged with their type, as otherwise they would be
+    /// indistinguishable from strings and bound with a wrong type
+    fn encode(&self) -> String {
+        let value = match self {
+            Self::TimestampTz(_) => serde_json::json!({ "timestampTz": self.to_json() }),
+            Self::Timestamp(_) => serde_json::json!({ "timestamp": self.to_json() }),
+            Self::Date(_) => serde_json::json!({ "date": self.to_json() }),
+            _ => self.to_json(),
+        };
+        value.to_string()
+    }
+
+    fn decode(state: &str) -> Result<Self, InvalidSourceState> {
+        let value: serde_json::Value = serde_json::from_str(state)
+            .map_err(|e| InvalidSourceState::new(state, format!("Malformed cursor value: {e}")))?;
+
+        let invalid = || {
+            InvalidSourceState::new(
+                state,
+                "Cursor value must be a boolean, a number, a string, or a tagged temporal value",
+            )
+        };
+
+        match value {
+            serde_json::Value::Bool(v) => Ok(Self::Bool(v)),
+            serde_json::Value::Number(v) => v
+                .as_i64()
+                .map(Self::Int)
+                .or_else(|| v.as_f64().map(Self::Float))
+                .ok_or_else(invalid),
+            serde_json::Value::String(v) => Ok(Self::String(v)),
+            serde_json::Value::Object(obj) if obj.len() == 1 => {
+                let (kind, v) = obj.iter().next().unwrap();
+                let v = v.as_str().ok_or_else(invalid)?;
+
+                match kind.as_str() {
+                    "timestampTz" => DateTime::parse_from_rfc3339(v)
+                        .map(|t| Self::TimestampTz(t.with_timezone(&Utc)))
+                        .map_err(|_| invalid()),
+                    "timestamp" => NaiveDateTime::parse_from_str(v, TIMESTAMP_FORMAT)
+                        .map(Self::Timestamp)
+                        .map_err(|_| invalid()),
+                    "date" => NaiveDate::parse_from_str(v, DATE_FORMAT)
+                        .map(Self::Date)
+                        .map_err(|_| invalid()),
+                    _ => Err(invalid()),
+                }
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+type SqlQuery<'q, DB> = sqlx::query::Query<'q, DB, <DB as sqlx::Database>::Arguments<'q>>;
+
+/// Conversion of values from and into the types supported by a database driver
+trait SqlDriver: sqlx::Database {
+    /// Binds the value as its native type, so it is compared with the column
+    /// values without any casts
+    fn bind_value<'q>(query: SqlQuery<'q, Self>, value: &SqlValue) -> SqlQuery<'q, Self>;
+
+    /// Reads the column value by probing the supported types, as column type
+    /// names differ between databases
+    fn get_value(row: &Self::Row, index: usize) -> Result<Option<SqlValue>, InternalError>;
+}
+
+macro_rules! impl_sql_driver {
+    ($db:ty) => {
+        impl SqlDriver for $db {
+            fn bind_value<'q>(query: SqlQuery<'q, Self>, value: &SqlValue) -> SqlQuery<'q, Self> {
+                match value {
+                    SqlValue::Bool(v) => query.bind(*v),
+                    SqlValue::Int(v) => query.bind(*v),
+                    SqlValue::Float(v) => query.bind(*v),
+                    SqlValue::String(v) => query.bind(v.clone()),
+                    SqlValue::TimestampTz(v) => query.bind(*v),
+                    SqlValue::Timestamp(v) => query.bind(*v),
+                    SqlValue::Date(v) => query.bind(*v),
+                }
+            }
+
+            fn get_value(row: &Self::Row, index: usize) -> Result<Option<SqlValue>, InternalError> {
+                use sqlx::{Column as _, Row as _, ValueRef as _};
+
+                if row.try_get_raw(index).int_err()?.is_null() {
+                    return Ok(None);
+                }
+
+                if let Ok(v) = row.try_get::<i64, _>(index) {
+                    return Ok(Some(SqlValue::Int(v)));
+                }
+                if let Ok(v) = row.try_get::<i32, _>(index) {
+                    return Ok(Some(SqlValue::Int(v.into())));
+                }
+                if let Ok(v) = row.try_get::<i16, _>(index) {
+                    return Ok(Some(SqlValue::Int(v.into())));
+                }
+                if let Ok(v) = row.try_get::<f64, _>(index) {
+                    return Ok(Some(SqlValue::Float(v)));
+                }
+                if let Ok(v) = row.try_get::<f32, _>(index) {
+                    return Ok(Some(SqlValue::Float(v.into())));
+                }
+                if let Ok(v) = row.try_get::<bool, _>(index) {
+                    return Ok(Some(SqlValue::Bool(v)));
+                }
+                // Strings are probed before temporal types to keep the values of SQLite, which
+                // stores timestamps as text, in their original format
+                if let Ok(v) = row.try_get::<String, _>(index) {
+                    return Ok(Some(SqlValue::String(v)));
+                }
+                if let Ok(v) = row.try_get::<DateTime<Utc>, _>(index) {
+                    return Ok(Some(SqlValue::TimestampTz(v)));
+                }
+                if let Ok(v) = row.try_get::<NaiveDateTime, _>(index) {
+                    return Ok(Some(SqlValue::Timestamp(v)));
+                }
+                if let Ok(v) = row.try_get::<NaiveDate, _>(index) {
+                    return Ok(Some(SqlValue::Date(v)));
+                }
+
+                Err(format!(
+                    "Column {} has unsupported type, consider casting it to text in the query",
+                    row.column(index).name()
+                )
+                .int_err())
+            }
+        }
+    };
+}
+
+impl_sql_driver!(sqlx::Postgres);
+impl_sql_driver!(sqlx::MySql);
+impl_sql_driver!(sqlx::Sqlite);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Quotes the identifier so it can be safely interpolated into a query
+fn quote_identifier(name: &str, connection_url: &str) -> String {
+    if connection_url.starts_with("mysql") || connection_url.starts_with("mariadb") {
+        format!("`{}`", name.replace('`', "``"))
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
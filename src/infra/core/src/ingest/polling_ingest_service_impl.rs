@@ -17,6 +17,7 @@ use internal_error::{InternalError, ResultIntoInternal};
 use kamu_core::ingest::*;
 use kamu_core::*;
 use kamu_ingest_datafusion::DataWriterDataFusion;
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::serde::yaml::Manifest;
 use opendatafabric::*;
 use random_names::get_random_name;
@@ -36,6 +37,7 @@ pub struct PollingIngestServiceImpl {
     run_info_dir: Arc<RunInfoDir>,
     cache_dir: Arc<CacheDir>,
     time_source: Arc<dyn SystemTimeSource>,
+    outbox: Arc<dyn Outbox>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -53,6 +55,7 @@ impl PollingIngestServiceImpl {
         run_info_dir: Arc<RunInfoDir>,
         cache_dir: Arc<CacheDir>,
         time_source: Arc<dyn SystemTimeSource>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             dataset_repo,
@@ -64,6 +67,7 @@ impl PollingIngestServiceImpl {
             run_info_dir,
             cache_dir,
             time_source,
+            outbox,
         }
     }
 
@@ -106,6 +110,7 @@ impl PollingIngestServiceImpl {
     ) -> Result<PollingIngestResult, PollingIngestError> {
         let ctx = ingest_common::new_session_context(self.object_store_registry.clone());
         let mut data_writer = DataWriterDataFusion::builder(args.dataset.clone(), ctx.clone())
+            .with_schema_evolution(args.options.schema_evolution)
             .with_metadata_state_scanned(None)
             .await
             .int_err()?
@@ -154,6 +159,22 @@ impl PollingIngestServiceImpl {
 
             match self.ingest_iteration(iteration_args).await {
                 Ok(res) => {
+                    if let PollingIngestResult::Updated {
+                        old_head, new_head, ..
+                    } = &res
+                    {
+                        self.outbox
+                            .post_message(
+                                MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                                DatasetReferenceMessage::updated(
+                                    args.dataset_handle.id.clone(),
+                                    Some(old_head.clone()),
+                                    new_head.clone(),
+                                ),
+                            )
+                            .await?;
+                    }
+
                     combined_result = Some(Self::merge_results(combined_result, res));
 
                     let has_more = match combined_result {
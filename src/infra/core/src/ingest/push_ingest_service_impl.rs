@@ -17,6 +17,7 @@ use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::ingest::*;
 use kamu_core::*;
 use kamu_ingest_datafusion::*;
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::*;
 use random_names::get_random_name;
 use time_source::SystemTimeSource;
@@ -34,6 +35,7 @@ pub struct PushIngestServiceImpl {
     time_source: Arc<dyn SystemTimeSource>,
     engine_provisioner: Arc<dyn EngineProvisioner>,
     run_info_dir: Arc<RunInfoDir>,
+    outbox: Arc<dyn Outbox>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -49,6 +51,7 @@ impl PushIngestServiceImpl {
         time_source: Arc<dyn SystemTimeSource>,
         engine_provisioner: Arc<dyn EngineProvisioner>,
         run_info_dir: Arc<RunInfoDir>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             dataset_repo,
@@ -58,6 +61,7 @@ impl PushIngestServiceImpl {
             time_source,
             engine_provisioner,
             run_info_dir,
+            outbox,
         }
     }
 
@@ -85,7 +89,12 @@ impl PushIngestServiceImpl {
             ingest_common::new_session_context(self.object_store_registry.clone());
 
         let mut data_writer = self
-            .make_data_writer(dataset.clone(), source_name, ctx.clone())
+            .make_data_writer(
+                dataset.clone(),
+                source_name,
+                ctx.clone(),
+                opts.schema_evolution,
+            )
             .await?;
 
         let push_source = match (data_writer.source_event(), opts.auto_create_push_source) {
@@ -97,7 +106,12 @@ impl PushIngestServiceImpl {
 
                 // Update data writer, as we've modified the dataset
                 data_writer = self
-                    .make_data_writer(dataset.clone(), source_name, ctx.clone())
+                    .make_data_writer(
+                        dataset.clone(),
+                        source_name,
+                        ctx.clone(),
+                        opts.schema_evolution,
+                    )
                     .await?;
                 Ok(add_push_source_event)
             }
@@ -128,6 +142,23 @@ impl PushIngestServiceImpl {
         match self.do_ingest_inner(source, args).await {
             Ok(res) => {
                 tracing::info!(result = ?res, "Ingest iteration successful");
+
+                if let PushIngestResult::Updated {
+                    old_head, new_head, ..
+                } = &res
+                {
+                    self.outbox
+                        .post_message(
+                            MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                            DatasetReferenceMessage::updated(
+                                dataset_handle.id.clone(),
+                                Some(old_head.clone()),
+                                new_head.clone(),
+                            ),
+                        )
+                        .await?;
+                }
+
                 listener.success(&res);
                 Ok(res)
             }
@@ -144,8 +175,10 @@ impl PushIngestServiceImpl {
         dataset: Arc<dyn Dataset>,
         source_name: Option<&str>,
         ctx: SessionContext,
+        schema_evolution: SchemaEvolutionRules,
     ) -> Result<DataWriterDataFusion, PushIngestError> {
         match DataWriterDataFusion::builder(dataset, ctx)
+            .with_schema_evolution(schema_evolution)
             .with_metadata_state_scanned(source_name)
             .await
         {
@@ -28,8 +28,11 @@ mod use_cases;
 pub mod utils;
 
 mod compaction_service_impl;
+mod data_export_service_impl;
+mod dataset_archive_service_impl;
 mod dataset_changes_service_impl;
 mod dataset_config;
+mod dataset_diff_service_impl;
 mod dataset_layout;
 mod dataset_ownership_service_inmem;
 mod dependency_graph_repository_inmem;
@@ -37,20 +40,28 @@ mod dependency_graph_service_inmem;
 mod provenance_service_impl;
 mod pull_service_impl;
 mod push_service_impl;
+mod query_result_cache_inmem;
+mod query_result_cache_local_fs;
+mod query_result_cache_metrics;
 mod query_service_impl;
 mod remote_alias_resolver_impl;
 mod remote_aliases_registry_impl;
 mod remote_repository_registry_impl;
 mod reset_service_impl;
 mod resource_loader_impl;
+mod retention_service_impl;
+mod search_index_inmem;
 mod search_service_impl;
 mod sync_service_impl;
 mod transform_service_impl;
 mod verification_service_impl;
 
 pub use compaction_service_impl::*;
+pub use data_export_service_impl::*;
+pub use dataset_archive_service_impl::*;
 pub use dataset_changes_service_impl::*;
 pub use dataset_config::*;
+pub use dataset_diff_service_impl::*;
 pub use dataset_layout::*;
 pub use dataset_ownership_service_inmem::*;
 pub use dependency_graph_repository_inmem::*;
@@ -60,6 +71,10 @@ pub use ingest::*;
 pub use provenance_service_impl::*;
 pub use pull_service_impl::*;
 pub use push_service_impl::*;
+pub use query::{rewrite_time_travel, with_stable_order, TimeTravel};
+pub use query_result_cache_inmem::*;
+pub use query_result_cache_local_fs::*;
+pub use query_result_cache_metrics::*;
 pub use query_service_impl::*;
 pub use remote_alias_resolver_impl::*;
 pub use remote_aliases_registry_impl::*;
@@ -67,6 +82,8 @@ pub use remote_repository_registry_impl::*;
 pub use repos::*;
 pub use reset_service_impl::*;
 pub use resource_loader_impl::*;
+pub use retention_service_impl::*;
+pub use search_index_inmem::*;
 pub use search_service_impl::*;
 pub use sync_service_impl::*;
 pub use transform_service_impl::*;
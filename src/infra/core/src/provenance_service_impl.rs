@@ -7,21 +7,31 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use datafusion::datasource::MemTable;
+use datafusion::logical_expr::expr_rewriter::unnormalize_col;
+use datafusion::logical_expr::{Expr, LogicalPlan};
+use datafusion::prelude::*;
+use datafusion::sql::TableReference;
 use dill::*;
-use internal_error::ResultIntoInternal;
+use futures::TryStreamExt;
+use internal_error::{InternalError, ResultIntoInternal};
 use kamu_core::*;
 use opendatafabric::*;
 
+use crate::new_session_context;
+use crate::utils::column_lineage::ColumnLineageAnalyzer;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub struct ProvenanceServiceImpl {
     dataset_repo: Arc<dyn DatasetRepository>,
     dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    object_store_registry: Arc<dyn ObjectStoreRegistry>,
 }
 
 #[component(pub)]
@@ -30,10 +40,126 @@ impl ProvenanceServiceImpl {
     pub fn new(
         dataset_repo: Arc<dyn DatasetRepository>,
         dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        object_store_registry: Arc<dyn ObjectStoreRegistry>,
     ) -> Self {
         Self {
             dataset_repo,
             dataset_action_authorizer,
+            object_store_registry,
+        }
+    }
+
+    /// Reads the input records that pass the filters of the query and thus
+    /// could have produced the output records. Engines don't guarantee to
+    /// preserve the order of records, so the exact source record can't be
+    /// determined. Returns `None` unless the query is a simple projection or
+    /// filter.
+    async fn get_possible_source_records(
+        &self,
+        input_hdl: &DatasetHandle,
+        input: &ExecuteTransformInput,
+        offset_interval: &OffsetInterval,
+        input_name: &str,
+        query: &str,
+    ) -> Result<Option<DataFrame>, InternalError> {
+        let Some(new_block_hash) = &input.new_block_hash else {
+            return Ok(None);
+        };
+
+        let dataset = self.dataset_repo.get_dataset_by_handle(input_hdl);
+
+        let vocab: DatasetVocabulary = dataset
+            .as_metadata_chain()
+            .accept_one(SearchSetVocabVisitor::new())
+            .await
+            .int_err()?
+            .into_event()
+            .unwrap_or_default()
+            .into();
+
+        let data_slices: Vec<_> = dataset
+            .as_metadata_chain()
+            .iter_blocks_interval(new_block_hash, input.prev_block_hash.as_ref(), false)
+            .filter_data_stream_blocks()
+            .try_filter_map(|(_, b)| futures::future::ready(Ok(b.event.new_data)))
+            .try_collect()
+            .await
+            .int_err()?;
+
+        if data_slices.is_empty() {
+            return Ok(None);
+        }
+
+        let data_repo = dataset.as_data_repo();
+        let mut data_slice_urls = Vec::with_capacity(data_slices.len());
+        for data_slice in data_slices.iter().rev() {
+            data_slice_urls.push(data_repo.get_internal_url(&data_slice.physical_hash).await);
+        }
+
+        let ctx = new_session_context(self.object_store_registry.clone());
+
+        let offset_col = || col(Column::from_name(&vocab.offset_column));
+        let df = ctx
+            .read_parquet(
+                data_slice_urls,
+                datafusion::execution::options::ParquetReadOptions {
+                    file_extension: "",
+                    ..Default::default()
+                },
+            )
+            .await
+            .int_err()?
+            .filter(offset_col().between(
+                lit(i64::try_from(offset_interval.start).int_err()?),
+                lit(i64::try_from(offset_interval.end).int_err()?),
+            ))
+            .int_err()?;
+
+        ctx.register_table(TableReference::bare(input_name), df.clone().into_view())
+            .int_err()?;
+
+        // Queries may use syntax specific to the engine they were written for
+        let Ok(plan) = ctx.state().create_logical_plan(query).await else {
+            return Ok(None);
+        };
+
+        let Some((predicates, _)) = Self::simple_plan_predicates(&plan) else {
+            return Ok(None);
+        };
+
+        let mut df = df;
+        for predicate in predicates {
+            df = df.filter(unnormalize_col(predicate)).int_err()?;
+        }
+
+        let df = df.sort(vec![offset_col().sort(true, false)]).int_err()?;
+
+        Ok(Some(df))
+    }
+
+    /// Returns filter predicates of a plan consisting only of projections and
+    /// filters over a single table, along with a flag whether the plan
+    /// contains a projection
+    fn simple_plan_predicates(plan: &LogicalPlan) -> Option<(Vec<Expr>, bool)> {
+        match plan {
+            LogicalPlan::TableScan(_) => Some((Vec::new(), false)),
+            LogicalPlan::SubqueryAlias(alias) => Self::simple_plan_predicates(&alias.input),
+            LogicalPlan::Projection(proj) => {
+                let (predicates, _) = Self::simple_plan_predicates(&proj.input)?;
+                Some((predicates, true))
+            }
+            LogicalPlan::Filter(filter) => {
+                let (mut predicates, has_projection) = Self::simple_plan_predicates(&filter.input)?;
+
+                // Predicates above projections may refer to computed columns
+                if has_projection {
+                    return None;
+                }
+
+                predicates.push(filter.predicate.clone());
+                Some((predicates, has_projection))
+            }
+            _ => None,
         }
     }
 
@@ -111,6 +237,278 @@ impl ProvenanceService for ProvenanceServiceImpl {
         let hdl = self.dataset_repo.resolve_dataset_ref(dataset_ref).await?;
         self.visit_upstream_dependencies_rec(&hdl, visitor).await
     }
+
+    #[tracing::instrument(level = "info", skip_all, fields(%dataset_ref))]
+    async fn get_column_lineage(
+        &self,
+        dataset_ref: &DatasetRef,
+    ) -> Result<Option<ColumnLineage>, GetColumnLineageError> {
+        let hdl = self.dataset_repo.resolve_dataset_ref(dataset_ref).await?;
+
+        self.dataset_action_authorizer
+            .check_action_allowed(&hdl, auth::DatasetAction::Read)
+            .await?;
+
+        let dataset = self.dataset_repo.get_dataset_by_handle(&hdl);
+
+        let Some(set_transform) = dataset
+            .as_metadata_chain()
+            .accept_one(SearchSetTransformVisitor::new())
+            .await
+            .int_err()?
+            .into_event()
+        else {
+            return Ok(None);
+        };
+
+        let Transform::Sql(transform) = set_transform.transform;
+
+        let mut cfg = SessionConfig::new().with_default_catalog_and_schema("kamu", "kamu");
+
+        // Keep identifiers case-sensitive the same way the query service does
+        cfg.options_mut().sql_parser.enable_ident_normalization = false;
+
+        // Spark and Flink queries are closer to Hive's flavor of SQL than to the
+        // generic one (e.g. when it comes to backtick-quoted identifiers)
+        if matches!(transform.engine.as_str(), "spark" | "flink") {
+            cfg.options_mut().sql_parser.dialect = "Hive".to_string();
+        }
+
+        let ctx = SessionContext::new_with_config(cfg);
+        let mut analyzer = ColumnLineageAnalyzer::new();
+        let mut input_handles = HashMap::new();
+
+        for input in &set_transform.inputs {
+            let input_hdl = self
+                .dataset_repo
+                .resolve_dataset_ref(&input.dataset_ref)
+                .await?;
+
+            self.dataset_action_authorizer
+                .check_action_allowed(&input_hdl, auth::DatasetAction::Read)
+                .await?;
+
+            let name = input
+                .alias
+                .clone()
+                .unwrap_or_else(|| input_hdl.alias.dataset_name.to_string());
+
+            let Some(schema) = self
+                .dataset_repo
+                .get_dataset_by_handle(&input_hdl)
+                .as_metadata_chain()
+                .accept_one(SearchSetDataSchemaVisitor::new())
+                .await
+                .int_err()?
+                .into_event()
+                .map(|e| e.schema_as_arrow())
+                .transpose()
+                .int_err()?
+            else {
+                return Err(UnsupportedQueryError {
+                    message: format!(
+                        "Input dataset {} does not define a schema yet",
+                        input_hdl.alias
+                    ),
+                }
+                .into());
+            };
+
+            analyzer.add_input(&name, schema.fields().iter().map(|f| f.name().as_str()));
+            ctx.register_table(
+                TableReference::bare(name.clone()),
+                Arc::new(MemTable::try_new(schema, vec![vec![]]).int_err()?),
+            )
+            .int_err()?;
+
+            input_handles.insert(name, input_hdl);
+        }
+
+        let steps = match (transform.queries, transform.query) {
+            (Some(queries), _) => queries,
+            (None, Some(query)) => vec![SqlQueryStep { alias: None, query }],
+            (None, None) => Vec::new(),
+        };
+
+        let mut output = Vec::new();
+
+        for step in steps {
+            let plan = ctx
+                .state()
+                .create_logical_plan(&step.query)
+                .await
+                .map_err(|e| UnsupportedQueryError {
+                    message: e.to_string(),
+                })?;
+
+            let lineage = analyzer.analyze(&plan);
+
+            if let Some(alias) = step.alias {
+                let schema = Arc::new(plan.schema().as_arrow().clone());
+                ctx.register_table(
+                    TableReference::bare(alias.clone()),
+                    Arc::new(MemTable::try_new(schema, vec![vec![]]).int_err()?),
+                )
+                .int_err()?;
+                analyzer.add_view(&alias, lineage);
+            } else {
+                output = lineage;
+            }
+        }
+
+        let columns = output
+            .into_iter()
+            .map(|(name, origins)| OutputColumnLineage {
+                name,
+                sources: origins
+                    .into_iter()
+                    .filter_map(|o| {
+                        input_handles
+                            .get(&o.relation)
+                            .map(|dataset| ColumnLineageSource {
+                                dataset: dataset.clone(),
+                                column: o.column,
+                            })
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        Ok(Some(ColumnLineage { columns }))
+    }
+
+    #[tracing::instrument(level = "info", skip_all, fields(%dataset_ref, %offset))]
+    async fn get_record_provenance(
+        &self,
+        dataset_ref: &DatasetRef,
+        offset: u64,
+    ) -> Result<RecordProvenance, GetRecordProvenanceError> {
+        let hdl = self.dataset_repo.resolve_dataset_ref(dataset_ref).await?;
+
+        self.dataset_action_authorizer
+            .check_action_allowed(&hdl, auth::DatasetAction::Read)
+            .await?;
+
+        let dataset = self.dataset_repo.get_dataset_by_handle(&hdl);
+
+        // Blocks are iterated from newest to oldest, so the first block that starts
+        // at or before the offset is the only one that can contain it
+        let found = dataset
+            .as_metadata_chain()
+            .iter_blocks()
+            .filter_data_stream_blocks()
+            .try_skip_while(|(_, b)| {
+                futures::future::ready(Ok(b
+                    .event
+                    .new_data
+                    .as_ref()
+                    .map_or(true, |d| d.offset_interval.start > offset)))
+            })
+            .try_next()
+            .await
+            .int_err()?;
+
+        let Some((block_hash, offset_interval)) = found.and_then(|(h, b)| {
+            b.event
+                .new_data
+                .map(|d| d.offset_interval)
+                .filter(|i| offset <= i.end)
+                .map(|i| (h, i))
+        }) else {
+            return Err(RecordNotFoundError {
+                dataset_alias: hdl.alias.clone(),
+                offset,
+            }
+            .into());
+        };
+
+        let block = dataset
+            .as_metadata_chain()
+            .get_block(&block_hash)
+            .await
+            .int_err()?;
+
+        let MetadataEvent::ExecuteTransform(execute_transform) = block.event else {
+            return Ok(RecordProvenance {
+                block_hash,
+                system_time: block.system_time,
+                offset_interval,
+                inputs: Vec::new(),
+            });
+        };
+
+        let set_transform = dataset
+            .as_metadata_chain()
+            .accept_one_by_hash(&block_hash, SearchSetTransformVisitor::new())
+            .await
+            .int_err()?
+            .into_event();
+
+        // Possible source records can only be traced for single-step transforms of a
+        // single input
+        let simple_query = set_transform.and_then(|t| {
+            let Transform::Sql(sql) = t.transform;
+            let query = match (sql.queries, sql.query) {
+                (Some(mut queries), _) if queries.len() == 1 => queries.pop().map(|q| q.query),
+                (None, query) => query,
+                _ => None,
+            }?;
+            match &t.inputs[..] {
+                [input] => Some((input.alias.clone(), query)),
+                _ => None,
+            }
+        });
+
+        let mut inputs = Vec::new();
+        for input in &execute_transform.query_inputs {
+            let input_hdl = self
+                .dataset_repo
+                .resolve_dataset_ref(&input.dataset_id.as_local_ref())
+                .await?;
+
+            self.dataset_action_authorizer
+                .check_action_allowed(&input_hdl, auth::DatasetAction::Read)
+                .await?;
+
+            let input_offset_interval = input.new_offset.map(|end| OffsetInterval {
+                start: input.prev_offset.map_or(0, |o| o + 1),
+                end,
+            });
+
+            let possible_source_records = match (&simple_query, &input_offset_interval) {
+                (Some((alias, query)), Some(input_offset_interval)) => {
+                    let input_name = alias
+                        .clone()
+                        .unwrap_or_else(|| input_hdl.alias.dataset_name.to_string());
+
+                    self.get_possible_source_records(
+                        &input_hdl,
+                        input,
+                        input_offset_interval,
+                        &input_name,
+                        query,
+                    )
+                    .await?
+                }
+                _ => None,
+            };
+
+            inputs.push(RecordProvenanceInput {
+                dataset: input_hdl,
+                prev_block_hash: input.prev_block_hash.clone(),
+                new_block_hash: input.new_block_hash.clone(),
+                offset_interval: input_offset_interval,
+                possible_source_records,
+            });
+        }
+
+        Ok(RecordProvenance {
+            block_hash,
+            system_time: block.system_time,
+            offset_interval,
+            inputs,
+        })
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
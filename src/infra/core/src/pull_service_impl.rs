@@ -17,6 +17,7 @@ use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_accounts::CurrentAccountSubject;
 use kamu_core::*;
 use kamu_ingest_datafusion::DataWriterDataFusion;
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::*;
 use time_source::SystemTimeSource;
 use url::Url;
@@ -30,6 +31,7 @@ pub struct PullServiceImpl {
     system_time_source: Arc<dyn SystemTimeSource>,
     current_account_subject: Arc<CurrentAccountSubject>,
     dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    outbox: Arc<dyn Outbox>,
 }
 
 #[component(pub)]
@@ -44,6 +46,7 @@ impl PullServiceImpl {
         system_time_source: Arc<dyn SystemTimeSource>,
         current_account_subject: Arc<CurrentAccountSubject>,
         dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             dataset_repo,
@@ -54,6 +57,7 @@ impl PullServiceImpl {
             system_time_source,
             current_account_subject,
             dataset_action_authorizer,
+            outbox,
         }
     }
 
@@ -654,10 +658,23 @@ impl PullService for PullServiceImpl {
             )
             .await
         {
-            Ok(res) => Ok(PullResult::Updated {
-                old_head: Some(res.old_head),
-                new_head: res.new_head,
-            }),
+            Ok(res) => {
+                self.outbox
+                    .post_message(
+                        MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                        DatasetReferenceMessage::updated(
+                            dataset_handle.id.clone(),
+                            Some(res.old_head.clone()),
+                            res.new_head.clone(),
+                        ),
+                    )
+                    .await?;
+
+                Ok(PullResult::Updated {
+                    old_head: Some(res.old_head),
+                    new_head: res.new_head,
+                })
+            }
             Err(
                 WriteWatermarkError::EmptyCommit(_)
                 | WriteWatermarkError::CommitError(CommitError::MetadataAppendError(
@@ -7,8 +7,12 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod pagination;
+mod pruning;
+mod time_travel;
+
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 
 use datafusion::arrow::datatypes::{Schema, SchemaRef};
@@ -21,13 +25,17 @@ use datafusion::datasource::{TableProvider, TableType};
 use datafusion::error::DataFusionError;
 use datafusion::execution::context::DataFilePaths;
 use datafusion::execution::options::ReadOptions;
-use datafusion::logical_expr::LogicalPlan;
+use datafusion::logical_expr::{LogicalPlan, TableProviderFilterPushDown};
 use datafusion::physical_plan::ExecutionPlan;
 use datafusion::prelude::*;
+use datafusion::scalar::ScalarValue;
 use futures::stream::{self, StreamExt, TryStreamExt};
 use internal_error::{InternalError, ResultIntoInternal};
 use kamu_core::*;
 use opendatafabric::*;
+pub use pagination::*;
+use pruning::{FilePruner, PartitionStatistics};
+pub use time_travel::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Catalog
@@ -215,6 +223,59 @@ impl KamuSchema {
         let cache = self.ensure_cache().await?;
         Ok(cache.tables.as_ref().unwrap().contains_key(name))
     }
+
+    /// Resolves tables produced by rewriting the time travel clauses (see
+    /// [`rewrite_time_travel`]) into tables pinned to the requested block
+    async fn time_travel_table(
+        &self,
+        name: &str,
+    ) -> Result<Option<Arc<KamuTable>>, DataFusionError> {
+        let Some((alias, time_travel)) = TimeTravel::from_table_name(name) else {
+            return Ok(None);
+        };
+
+        let Some(table) = ({
+            let cache = self
+                .ensure_cache()
+                .await
+                .map_err(|e| DataFusionError::External(e.into()))?;
+
+            cache.tables.as_ref().unwrap().get(alias).cloned()
+        }) else {
+            return Ok(None);
+        };
+
+        // When state is provided via options the tables are already pinned to the
+        // blocks resolved from the time travel clauses
+        let name_resolution_enabled = self.inner.options.input_datasets.is_empty();
+        if !name_resolution_enabled {
+            return Ok(Some(table));
+        }
+
+        let block_hash = time_travel
+            .resolve(table.dataset.as_ref())
+            .await
+            .map_err(|e| DataFusionError::External(e.into()))?
+            .ok_or_else(|| {
+                DataFusionError::Plan(format!(
+                    "Dataset {} has no state as of {time_travel}",
+                    table.dataset_handle.alias
+                ))
+            })?;
+
+        let table = Arc::new(table.with_as_of(block_hash));
+
+        {
+            let mut cache = self.inner.cache.lock().unwrap();
+            cache
+                .tables
+                .as_mut()
+                .unwrap()
+                .insert(name.to_string(), table.clone());
+        }
+
+        Ok(Some(table))
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -269,6 +330,11 @@ impl SchemaProvider for KamuSchema {
             cache.tables.as_ref().unwrap().get(name).cloned()
         };
 
+        let table = match table {
+            Some(table) => Some(table),
+            None => self.time_travel_table(name).await?,
+        };
+
         if let Some(table) = table {
             // HACK: We pre-initialize the schema here because `TableProvider::schema()` is
             // not async
@@ -301,9 +367,24 @@ pub(crate) struct KamuTable {
 #[derive(Default)]
 struct TableCache {
     schema: Option<SchemaRef>,
+    files: Option<Arc<TableFiles>>,
+    statistics: Option<Arc<HashMap<Multihash, SliceStatistics>>>,
     table: Option<Arc<dyn TableProvider>>,
 }
 
+/// Data files that make up the table at the selected state
+struct TableFiles {
+    /// Data slices or groups of compacted slices
+    files: Vec<Multihash>,
+    /// Partitions of the files indexed with the current partitioning spec
+    partitions: HashMap<Multihash, SlicePartitions>,
+    offset_column: String,
+}
+
+/// Limits the number of offset ranges excluded from the scan, as every range
+/// adds a predicate evaluated against the row group statistics
+const MAX_EXCLUDED_OFFSET_RANGES: usize = 100;
+
 impl KamuTable {
     pub(crate) fn new(
         session_config: Arc<SessionConfig>,
@@ -324,15 +405,31 @@ impl KamuTable {
         }
     }
 
+    /// Returns a copy of this table pinned to the specified block
+    fn with_as_of(&self, as_of: Multihash) -> Self {
+        Self::new(
+            self.session_config.clone(),
+            self.table_options.clone(),
+            self.dataset_handle.clone(),
+            self.dataset.clone(),
+            Some(as_of),
+            self.hints.clone(),
+        )
+    }
+
     #[tracing::instrument(level="info", skip_all, fields(dataset_handle = ?self.dataset_handle))]
     async fn init_table_schema(&self) -> Result<SchemaRef, InternalError> {
-        let maybe_set_data_schema = self
-            .dataset
-            .as_metadata_chain()
-            .accept_one(SearchSetDataSchemaVisitor::new())
-            .await
-            .int_err()?
-            .into_event();
+        let chain = self.dataset.as_metadata_chain();
+
+        let maybe_set_data_schema = if let Some(as_of) = &self.as_of {
+            chain
+                .accept_one_by_hash(as_of, SearchSetDataSchemaVisitor::new())
+                .await
+        } else {
+            chain.accept_one(SearchSetDataSchemaVisitor::new()).await
+        }
+        .int_err()?
+        .into_event();
 
         if let Some(set_data_schema) = maybe_set_data_schema {
             set_data_schema.schema_as_arrow().int_err()
@@ -365,8 +462,16 @@ impl KamuTable {
         &self,
         schema: SchemaRef,
     ) -> Result<Arc<dyn TableProvider>, InternalError> {
-        let files = self.collect_data_file_hashes(self.as_of.as_ref()).await?;
+        let files = self.get_table_files().await?;
 
+        self.create_listing_table(schema, files.files.clone()).await
+    }
+
+    async fn create_listing_table(
+        &self,
+        schema: SchemaRef,
+        files: Vec<Multihash>,
+    ) -> Result<Arc<dyn TableProvider>, InternalError> {
         if files.is_empty() {
             return Ok(Arc::new(EmptyTable::new(schema)));
         }
@@ -419,6 +524,255 @@ impl KamuTable {
         }
     }
 
+    /// Returns the table provider that skips the data files which cannot
+    /// satisfy the filters, falling back to the full table when nothing can be
+    /// pruned. Also returns the filters on the offset column that exclude the
+    /// row groups of the selected files that hold no matching partitions.
+    async fn get_pruned_table_provider(
+        &self,
+        filters: &[Expr],
+    ) -> Result<(Arc<dyn TableProvider>, Vec<Expr>), InternalError> {
+        let files = self.get_table_files().await?;
+        let statistics = self.get_table_statistics(&files).await?;
+
+        if files.partitions.is_empty() && statistics.is_empty() {
+            return Ok((self.get_table_provider().await?, Vec::new()));
+        }
+
+        let schema = self.get_table_schema().await?;
+        let pruner = FilePruner::new(&schema, filters);
+
+        let mut selected = Vec::new();
+        let mut excluded_ranges: Vec<(u64, u64)> = Vec::new();
+
+        for file in &files.files {
+            if let Some(s) = statistics.get(file)
+                && !pruner.may_match(s)
+            {
+                continue;
+            }
+
+            let Some(partitions) = files.partitions.get(file) else {
+                selected.push(file.clone());
+                continue;
+            };
+
+            let matching: Vec<bool> = partitions
+                .row_groups
+                .iter()
+                .map(|rg| {
+                    rg.values.iter().any(|value| {
+                        pruner.may_match(&PartitionStatistics {
+                            spec: &partitions.spec,
+                            value: value.as_deref(),
+                        })
+                    })
+                })
+                .collect();
+
+            if !matching.contains(&true) {
+                continue;
+            }
+
+            for (rg, _) in partitions
+                .row_groups
+                .iter()
+                .zip(matching)
+                .filter(|(_, m)| !m)
+            {
+                match excluded_ranges.last_mut() {
+                    Some((_, end)) if *end + 1 == rg.offset_start => *end = rg.offset_end,
+                    _ => excluded_ranges.push((rg.offset_start, rg.offset_end)),
+                }
+            }
+
+            selected.push(file.clone());
+        }
+
+        let offset_filters = Self::offset_range_filters(&schema, &files, excluded_ranges);
+
+        if selected.len() == files.files.len() && offset_filters.is_empty() {
+            return Ok((self.get_table_provider().await?, Vec::new()));
+        }
+
+        tracing::debug!(
+            num_files = files.files.len(),
+            num_selected = selected.len(),
+            num_excluded_offset_ranges = offset_filters.len(),
+            "Pruned data files",
+        );
+
+        Ok((
+            self.create_listing_table(schema, selected).await?,
+            offset_filters,
+        ))
+    }
+
+    /// Builds the filters that let the parquet reader skip the row groups
+    /// whose offsets fall into the excluded ranges
+    fn offset_range_filters(
+        schema: &Schema,
+        files: &TableFiles,
+        excluded_ranges: Vec<(u64, u64)>,
+    ) -> Vec<Expr> {
+        if excluded_ranges.is_empty() || excluded_ranges.len() > MAX_EXCLUDED_OFFSET_RANGES {
+            return Vec::new();
+        }
+
+        let Ok(field) = schema.field_with_name(&files.offset_column) else {
+            return Vec::new();
+        };
+
+        let offset_lit = |offset: u64| {
+            ScalarValue::UInt64(Some(offset))
+                .cast_to(field.data_type())
+                .ok()
+                .map(lit)
+        };
+
+        excluded_ranges
+            .into_iter()
+            .map(|(start, end)| {
+                let offset = col(Column::from_name(&files.offset_column));
+                Some(
+                    offset
+                        .clone()
+                        .lt(offset_lit(start)?)
+                        .or(offset.gt(offset_lit(end)?)),
+                )
+            })
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default()
+    }
+
+    async fn get_table_statistics(
+        &self,
+        files: &TableFiles,
+    ) -> Result<Arc<HashMap<Multihash, SliceStatistics>>, InternalError> {
+        {
+            let cache = self.cache.lock().unwrap();
+            if let Some(statistics) = &cache.statistics {
+                return Ok(Arc::clone(statistics));
+            }
+        }
+
+        let statistics = Arc::new(self.collect_table_statistics(files).await?);
+
+        {
+            let mut cache = self.cache.lock().unwrap();
+            cache.statistics = Some(Arc::clone(&statistics));
+            Ok(statistics)
+        }
+    }
+
+    /// Reads the statistics of the table files. Files without statistics are
+    /// never pruned by them, as planning a query must not modify the dataset.
+    async fn collect_table_statistics(
+        &self,
+        files: &TableFiles,
+    ) -> Result<HashMap<Multihash, SliceStatistics>, InternalError> {
+        use kamu_core::utils::slice_statistics::{
+            read_slice_statistics,
+            read_slice_statistics_config,
+        };
+
+        let info_repo = self.dataset.as_info_repo();
+        let mut statistics = HashMap::new();
+
+        if read_slice_statistics_config(info_repo).await?.is_none() {
+            return Ok(statistics);
+        }
+
+        for physical_hash in &files.files {
+            match read_slice_statistics(info_repo, physical_hash).await {
+                Ok(Some(s)) => {
+                    statistics.insert(physical_hash.clone(), s);
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    tracing::warn!(
+                        %physical_hash,
+                        error = ?err,
+                        error_msg = %err,
+                        "Ignoring invalid slice statistics",
+                    );
+                }
+            }
+        }
+
+        tracing::debug!(
+            num_files = files.files.len(),
+            num_with_statistics = statistics.len(),
+            "Slice statistics collected",
+        );
+
+        Ok(statistics)
+    }
+
+    async fn get_table_files(&self) -> Result<Arc<TableFiles>, InternalError> {
+        {
+            let cache = self.cache.lock().unwrap();
+            if let Some(files) = &cache.files {
+                return Ok(Arc::clone(files));
+            }
+        }
+
+        let files = Arc::new(self.collect_table_files().await?);
+
+        {
+            let mut cache = self.cache.lock().unwrap();
+            cache.files = Some(Arc::clone(&files));
+            Ok(files)
+        }
+    }
+
+    async fn collect_table_files(&self) -> Result<TableFiles, InternalError> {
+        use kamu_core::utils::partitioned_slices::{read_partitioning_spec, read_slice_partitions};
+
+        let files = self.collect_data_file_hashes(self.as_of.as_ref()).await?;
+
+        let info_repo = self.dataset.as_info_repo();
+        let mut partitions = HashMap::new();
+
+        // Slices indexed with a different spec cannot be used for pruning
+        if let Some(spec) = read_partitioning_spec(info_repo).await? {
+            for file in &files {
+                if let Some(p) = read_slice_partitions(info_repo, file).await?
+                    && p.spec == spec
+                {
+                    partitions.insert(file.clone(), p);
+                }
+            }
+
+            tracing::debug!(
+                num_files = files.len(),
+                num_partitioned = partitions.len(),
+                "Slice partitions collected",
+            );
+        }
+
+        let offset_column = if partitions.is_empty() {
+            String::new()
+        } else {
+            let vocab: DatasetVocabulary = self
+                .dataset
+                .as_metadata_chain()
+                .accept_one(SearchSetVocabVisitor::new())
+                .await
+                .int_err()?
+                .into_event()
+                .unwrap_or_default()
+                .into();
+            vocab.offset_column
+        };
+
+        Ok(TableFiles {
+            files,
+            partitions,
+            offset_column,
+        })
+    }
+
     async fn collect_data_file_hashes(
         &self,
         as_of: Option<&Multihash>,
@@ -483,7 +837,119 @@ impl KamuTable {
             .int_err()?;
 
         tracing::debug!(num_slices = final_state.files.len(), "Slices collected");
-        Ok(final_state.files)
+
+        // Substitute the groups of small slices merged by the soft compaction
+        let files = match crate::utils::compacted_slices::read_compacted_slices(
+            self.dataset.as_info_repo(),
+        )
+        .await?
+        {
+            Some(mut compacted_slices) => {
+                self.retain_prunable_compacted_slices(&mut compacted_slices, &final_state.files)
+                    .await?;
+
+                let files = compacted_slices.substitute(final_state.files);
+                tracing::debug!(num_slices = files.len(), "Compacted slices substituted");
+                files
+            }
+            None => final_state.files,
+        };
+
+        Ok(files)
+    }
+
+    /// Drops the merged files that lack the partitions or statistics some of
+    /// their original slices have, as substituting them would defeat the
+    /// pruning, e.g. when indexing a merged file failed
+    async fn retain_prunable_compacted_slices(
+        &self,
+        compacted_slices: &mut CompactedSlices,
+        files: &[Multihash],
+    ) -> Result<(), InternalError> {
+        use kamu_core::utils::partitioned_slices::read_partitioning_spec;
+        use kamu_core::utils::slice_statistics::read_slice_statistics_config;
+
+        let info_repo = self.dataset.as_info_repo();
+
+        let spec = read_partitioning_spec(info_repo).await?;
+        let has_statistics = read_slice_statistics_config(info_repo).await?.is_some();
+
+        if spec.is_none() && !has_statistics {
+            return Ok(());
+        }
+
+        let present: HashSet<&Multihash> = files.iter().collect();
+        let mut retained = Vec::with_capacity(compacted_slices.slices.len());
+
+        for slice in std::mem::take(&mut compacted_slices.slices) {
+            // Groups that are not fully present are not substituted anyway
+            if !slice.source_slices.iter().all(|h| present.contains(h)) {
+                retained.push(slice);
+                continue;
+            }
+
+            let mut loses_index = false;
+
+            if let Some(spec) = &spec
+                && !Self::is_partitioned(info_repo, spec, &slice.physical_hash).await?
+            {
+                for h in &slice.source_slices {
+                    if Self::is_partitioned(info_repo, spec, h).await? {
+                        loses_index = true;
+                        break;
+                    }
+                }
+            }
+
+            if !loses_index
+                && has_statistics
+                && !Self::has_statistics(info_repo, &slice.physical_hash).await
+            {
+                for h in &slice.source_slices {
+                    if Self::has_statistics(info_repo, h).await {
+                        loses_index = true;
+                        break;
+                    }
+                }
+            }
+
+            if loses_index {
+                tracing::debug!(
+                    physical_hash = %slice.physical_hash,
+                    "Skipping compacted slice without the indexes of its original slices",
+                );
+            } else {
+                retained.push(slice);
+            }
+        }
+
+        compacted_slices.slices = retained;
+        Ok(())
+    }
+
+    async fn is_partitioned(
+        info_repo: &dyn NamedObjectRepository,
+        spec: &PartitioningSpec,
+        physical_hash: &Multihash,
+    ) -> Result<bool, InternalError> {
+        use kamu_core::utils::partitioned_slices::read_slice_partitions;
+
+        Ok(read_slice_partitions(info_repo, physical_hash)
+            .await?
+            .is_some_and(|p| p.spec == *spec))
+    }
+
+    async fn has_statistics(
+        info_repo: &dyn NamedObjectRepository,
+        physical_hash: &Multihash,
+    ) -> bool {
+        use kamu_core::utils::slice_statistics::read_slice_statistics;
+
+        // Invalid statistics are ignored by the pruning as well
+        matches!(
+            read_slice_statistics(info_repo, physical_hash).await,
+            Ok(Some(_))
+        )
     }
 }
 
@@ -521,6 +987,15 @@ impl TableProvider for KamuTable {
         None
     }
 
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> datafusion::error::Result<Vec<TableProviderFilterPushDown>> {
+        // Filters are only used to prune partitions and row groups, so the query
+        // engine still has to apply them to the scanned records
+        Ok(vec![TableProviderFilterPushDown::Inexact; filters.len()])
+    }
+
     async fn scan(
         &self,
         state: &dyn Session,
@@ -528,12 +1003,19 @@ impl TableProvider for KamuTable {
         filters: &[Expr],
         limit: Option<usize>,
     ) -> datafusion::error::Result<Arc<dyn ExecutionPlan>> {
-        let p = self
-            .get_table_provider()
-            .await
-            .map_err(|e| datafusion::error::DataFusionError::External(e.into()))?;
+        let (p, offset_filters) = if filters.is_empty() {
+            self.get_table_provider().await.map(|p| (p, Vec::new()))
+        } else {
+            self.get_pruned_table_provider(filters).await
+        }
+        .map_err(|e| datafusion::error::DataFusionError::External(e.into()))?;
 
-        p.scan(state, projection, filters, limit).await
+        if offset_filters.is_empty() {
+            p.scan(state, projection, filters, limit).await
+        } else {
+            let filters: Vec<_> = filters.iter().cloned().chain(offset_filters).collect();
+            p.scan(state, projection, &filters, limit).await
+        }
     }
 
     fn statistics(&self) -> Option<Statistics> {
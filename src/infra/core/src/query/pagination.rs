@@ -0,0 +1,79 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use datafusion::common::Column;
+use datafusion::prelude::*;
+use datafusion::sql::parser::{DFParser, Statement};
+use datafusion::sql::sqlparser::ast;
+use kamu_core::*;
+use opendatafabric::*;
+
+use super::rewrite_time_travel;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Makes the order of the query results stable between the executions, as
+/// [`QueryCursor`] addresses the pages of the results by the number of the
+/// preceding records.
+///
+/// Results of the statements that specify their own `ORDER BY` are returned as
+/// is. Results of the unordered statements that read a single dataset are
+/// ordered by the offset column. Otherwise the order can't be guaranteed and
+/// `false` is returned along with the unchanged results, in which case no
+/// cursor should be issued for the statement.
+pub fn with_stable_order(
+    statement: &str,
+    df: DataFrame,
+    state: &QueryState,
+) -> Result<(DataFrame, bool), QueryError> {
+    if is_ordered_statement(statement)? {
+        return Ok((df, true));
+    }
+
+    let offset_column = DatasetVocabulary::default().offset_column;
+
+    if state.input_datasets.len() != 1
+        || df
+            .schema()
+            .fields_with_unqualified_name(&offset_column)
+            .len()
+            != 1
+    {
+        return Ok((df, false));
+    }
+
+    let df = df.sort(vec![col(Column::from_name(offset_column)).sort(true, false)])?;
+
+    Ok((df, true))
+}
+
+fn is_ordered_statement(statement: &str) -> Result<bool, QueryError> {
+    let statement = rewrite_time_travel(statement)?;
+
+    let Ok(mut statements) = DFParser::parse_sql(&statement) else {
+        return Ok(false);
+    };
+
+    if statements.len() != 1 {
+        return Ok(false);
+    }
+
+    Ok(match statements.pop_front() {
+        Some(Statement::Statement(stmt)) => match *stmt {
+            ast::Statement::Query(query) => query
+                .order_by
+                .as_ref()
+                .is_some_and(|order_by| !order_by.exprs.is_empty()),
+            _ => false,
+        },
+        _ => false,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,251 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::cmp::Ordering;
+
+use datafusion::arrow::array::{Array, AsArray};
+use datafusion::arrow::compute::cast;
+use datafusion::arrow::datatypes::{DataType, Schema};
+use datafusion::logical_expr::{Between, BinaryExpr, Operator};
+use datafusion::prelude::*;
+use datafusion::scalar::ScalarValue;
+use kamu_core::{PartitioningSpec, SliceStatistics};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// What is known about the values of a column in a data file
+pub(crate) enum ColumnRange {
+    /// Nothing can be said about the values
+    Unknown,
+    /// All values are null
+    AllNull,
+    /// Non-null values lie within the inclusive range
+    Range {
+        min: ScalarValue,
+        max: ScalarValue,
+        has_nulls: bool,
+    },
+}
+
+/// Source of the information about the values contained in a data file
+pub(crate) trait FileStatistics {
+    fn column_range(&self, column: &str, data_type: &DataType) -> ColumnRange;
+
+    /// Returns `false` only when the value is guaranteed to be absent in the
+    /// column. The value is already cast to the type of the column.
+    fn may_contain(&self, _column: &str, _value: &ScalarValue) -> bool {
+        true
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Determines whether any records of a data file can satisfy the filters that
+/// the query engine pushed down into the table scan.
+///
+/// The check is conservative: filters that cannot be evaluated against the
+/// file statistics are assumed to match.
+pub(crate) struct FilePruner<'a> {
+    schema: &'a Schema,
+    filters: &'a [Expr],
+}
+
+impl<'a> FilePruner<'a> {
+    pub fn new(schema: &'a Schema, filters: &'a [Expr]) -> Self {
+        Self { schema, filters }
+    }
+
+    /// Returns `false` only when none of the records in the file can match
+    /// the filters
+    pub fn may_match(&self, stats: &dyn FileStatistics) -> bool {
+        self.filters.iter().all(|f| self.expr_may_match(stats, f))
+    }
+
+    fn expr_may_match(&self, stats: &dyn FileStatistics, expr: &Expr) -> bool {
+        match expr {
+            Expr::BinaryExpr(BinaryExpr {
+                left,
+                op: Operator::And,
+                right,
+            }) => self.expr_may_match(stats, left) && self.expr_may_match(stats, right),
+            Expr::BinaryExpr(BinaryExpr {
+                left,
+                op: Operator::Or,
+                right,
+            }) => self.expr_may_match(stats, left) || self.expr_may_match(stats, right),
+            Expr::BinaryExpr(BinaryExpr { left, op, right }) => {
+                match (left.as_ref(), right.as_ref()) {
+                    (Expr::Column(c), Expr::Literal(v)) => self.compare(stats, &c.name, *op, v),
+                    (Expr::Literal(v), Expr::Column(c)) => match op.swap() {
+                        Some(op) => self.compare(stats, &c.name, op, v),
+                        None => true,
+                    },
+                    _ => true,
+                }
+            }
+            Expr::Between(Between {
+                expr,
+                negated: false,
+                low,
+                high,
+            }) => match (expr.as_ref(), low.as_ref(), high.as_ref()) {
+                (Expr::Column(c), Expr::Literal(low), Expr::Literal(high)) => {
+                    self.compare(stats, &c.name, Operator::GtEq, low)
+                        && self.compare(stats, &c.name, Operator::LtEq, high)
+                }
+                _ => true,
+            },
+            Expr::InList(in_list) if !in_list.negated => match in_list.expr.as_ref() {
+                Expr::Column(c) => in_list.list.iter().any(|e| match e {
+                    Expr::Literal(v) => self.compare(stats, &c.name, Operator::Eq, v),
+                    _ => true,
+                }),
+                _ => true,
+            },
+            Expr::IsNull(expr) => match expr.as_ref() {
+                Expr::Column(c) => match self.column_range(stats, &c.name) {
+                    ColumnRange::Unknown | ColumnRange::AllNull => true,
+                    ColumnRange::Range { has_nulls, .. } => has_nulls,
+                },
+                _ => true,
+            },
+            Expr::IsNotNull(expr) => match expr.as_ref() {
+                Expr::Column(c) => {
+                    !matches!(self.column_range(stats, &c.name), ColumnRange::AllNull)
+                }
+                _ => true,
+            },
+            _ => true,
+        }
+    }
+
+    fn column_range(&self, stats: &dyn FileStatistics, column: &str) -> ColumnRange {
+        match self.schema.field_with_name(column) {
+            Ok(field) => stats.column_range(column, field.data_type()),
+            Err(_) => ColumnRange::Unknown,
+        }
+    }
+
+    fn compare(
+        &self,
+        stats: &dyn FileStatistics,
+        column: &str,
+        op: Operator,
+        value: &ScalarValue,
+    ) -> bool {
+        let (min, max) = match self.column_range(stats, column) {
+            ColumnRange::Unknown => return true,
+            // Comparisons with nulls never evaluate to true
+            ColumnRange::AllNull => return false,
+            ColumnRange::Range { min, max, .. } => (min, max),
+        };
+        if value.is_null() {
+            return false;
+        }
+
+        let Ok(value) = value.cast_to(&min.data_type()) else {
+            return true;
+        };
+        let (Some(lo), Some(hi)) = (min.partial_cmp(&value), max.partial_cmp(&value)) else {
+            return true;
+        };
+
+        match op {
+            Operator::Eq => lo.is_le() && hi.is_ge() && stats.may_contain(column, &value),
+            Operator::NotEq => !(lo == Ordering::Equal && hi == Ordering::Equal),
+            Operator::Lt => lo.is_lt(),
+            Operator::LtEq => lo.is_le(),
+            Operator::Gt => hi.is_gt(),
+            Operator::GtEq => hi.is_ge(),
+            _ => true,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Values of the partitioning column in the records of one partition
+pub(crate) struct PartitionStatistics<'a> {
+    pub spec: &'a PartitioningSpec,
+    pub value: Option<&'a str>,
+}
+
+impl FileStatistics for PartitionStatistics<'_> {
+    fn column_range(&self, column: &str, data_type: &DataType) -> ColumnRange {
+        if column != self.spec.column {
+            return ColumnRange::Unknown;
+        }
+
+        let Some(value) = self.value else {
+            return ColumnRange::AllNull;
+        };
+
+        match self.spec.partition_bounds(value, data_type) {
+            Some((min, max)) => ColumnRange::Range {
+                min,
+                max,
+                has_nulls: false,
+            },
+            None => ColumnRange::Unknown,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl FileStatistics for SliceStatistics {
+    fn column_range(&self, column: &str, data_type: &DataType) -> ColumnRange {
+        let Some(stats) = self.column(column) else {
+            return ColumnRange::Unknown;
+        };
+
+        match (&stats.min, &stats.max) {
+            (Some(min), Some(max)) => {
+                let (Ok(min), Ok(max)) = (
+                    ScalarValue::try_from_string(min.clone(), data_type),
+                    ScalarValue::try_from_string(max.clone(), data_type),
+                ) else {
+                    return ColumnRange::Unknown;
+                };
+
+                ColumnRange::Range {
+                    min,
+                    max,
+                    has_nulls: stats.null_count != 0,
+                }
+            }
+            (None, None) if stats.null_count != 0 => ColumnRange::AllNull,
+            _ => ColumnRange::Unknown,
+        }
+    }
+
+    fn may_contain(&self, column: &str, value: &ScalarValue) -> bool {
+        let Some(bloom_filter) = self.column(column).and_then(|c| c.bloom_filter.as_ref()) else {
+            return true;
+        };
+
+        // Values are hashed in the same string representation they were cast into
+        // when the filter was built
+        let Ok(array) = value
+            .to_array()
+            .and_then(|a| cast(&a, &DataType::Utf8).map_err(Into::into))
+        else {
+            return true;
+        };
+
+        let array = array.as_string::<i32>();
+        if array.is_null(0) {
+            return true;
+        }
+
+        bloom_filter.may_contain(array.value(0))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,243 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::fmt::Display;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use datafusion::error::DataFusionError;
+use datafusion::sql::sqlparser::dialect::GenericDialect;
+use datafusion::sql::sqlparser::keywords::{Keyword, RESERVED_FOR_TABLE_ALIAS};
+use datafusion::sql::sqlparser::parser::ParserError;
+use datafusion::sql::sqlparser::tokenizer::{Token, Tokenizer, Word};
+use futures::TryStreamExt;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::*;
+use opendatafabric::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Specifies the past state of a dataset that a query should see
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeTravel {
+    /// State as of the specified metadata block
+    Block(Multihash),
+    /// State as of the last block added at or before the specified system time
+    SystemTime(DateTime<Utc>),
+}
+
+impl TimeTravel {
+    const TABLE_NAME_SEPARATOR: char = '@';
+
+    /// Time travel is passed to the catalog by encoding it into the name of the
+    /// table, e.g. `foo@block:f1620...` or `foo@time:2024-01-01T00:00:00Z`
+    pub fn to_table_name(&self, alias: &str) -> String {
+        format!("{alias}{}{self}", Self::TABLE_NAME_SEPARATOR)
+    }
+
+    /// Splits table name produced by [`TimeTravel::to_table_name`] into the
+    /// original alias and the time travel specification
+    pub fn from_table_name(name: &str) -> Option<(&str, Self)> {
+        let (alias, spec) = name.rsplit_once(Self::TABLE_NAME_SEPARATOR)?;
+
+        let tt = if let Some(hash) = spec.strip_prefix("block:") {
+            Self::Block(Multihash::from_multibase(hash).ok()?)
+        } else if let Some(time) = spec.strip_prefix("time:") {
+            Self::SystemTime(DateTime::parse_from_rfc3339(time).ok()?.into())
+        } else {
+            return None;
+        };
+
+        Some((alias, tt))
+    }
+
+    fn parse_system_time(s: &str) -> Option<DateTime<Utc>> {
+        if let Ok(t) = DateTime::parse_from_rfc3339(s) {
+            return Some(t.into());
+        }
+        if let Ok(t) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+            return Some(t.and_utc());
+        }
+        if let Ok(d) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+            return Some(d.and_hms_opt(0, 0, 0).unwrap().and_utc());
+        }
+        None
+    }
+
+    /// Finds the block that represents the state of the dataset specified by
+    /// this time travel, returning `None` if there is no such block
+    pub async fn resolve(&self, dataset: &dyn Dataset) -> Result<Option<Multihash>, InternalError> {
+        let chain = dataset.as_metadata_chain();
+
+        match self {
+            Self::Block(hash) => {
+                if chain.contains_block(hash).await.int_err()? {
+                    Ok(Some(hash.clone()))
+                } else {
+                    Ok(None)
+                }
+            }
+            Self::SystemTime(system_time) => {
+                let mut blocks = chain.iter_blocks();
+                while let Some((hash, block)) = blocks.try_next().await.int_err()? {
+                    if block.system_time <= *system_time {
+                        return Ok(Some(hash));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl Display for TimeTravel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Block(hash) => write!(f, "block:{}", hash.as_multibase()),
+            Self::SystemTime(t) => write!(
+                f,
+                "time:{}",
+                t.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+            ),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Rewrites time travel clauses in an SQL statement into the references to
+/// tables pinned to a specific state that are understood by our catalog.
+///
+/// Supported syntax:
+///
+/// ```text
+/// select * from "my.dataset" as of block 'f1620...'
+/// select * from "my.dataset" as of system time '2024-01-01T00:00:00Z'
+/// ```
+///
+/// The pinned table keeps the original name as an alias unless an explicit
+/// alias is specified, so it can still be referred to by its name in the rest
+/// of the query. Statements without time travel clauses are returned as is.
+pub fn rewrite_time_travel(sql: &str) -> Result<String, DataFusionError> {
+    // Do not unescape the literals so that original statement can be restored from
+    // the tokens
+    let tokens = Tokenizer::new(&GenericDialect {}, sql)
+        .with_unescape(false)
+        .tokenize()
+        .map_err(|e| DataFusionError::SQL(ParserError::from(e), None))?;
+
+    // Indices of the significant tokens
+    let sig: Vec<usize> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, t)| !matches!(t, Token::Whitespace(_)))
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut out = String::with_capacity(sql.len());
+    let mut pos = 0;
+    let mut found = false;
+
+    let mut s = 0;
+    while s < sig.len() {
+        let Some((tt, clause_len)) = parse_time_travel_clause(&tokens, &sig[s..])? else {
+            s += 1;
+            continue;
+        };
+
+        // The clause has to follow a (possibly qualified) table name
+        let Some(Token::Word(table)) = s.checked_sub(1).map(|i| &tokens[sig[i]]) else {
+            return Err(DataFusionError::Plan(
+                "AS OF clause has to follow a table name".to_string(),
+            ));
+        };
+
+        found = true;
+
+        let alias = &table.value;
+        let pinned = Word {
+            value: tt.to_table_name(alias),
+            quote_style: Some('"'),
+            keyword: Keyword::NoKeyword,
+        };
+        let has_explicit_alias = match sig.get(s + clause_len).map(|i| &tokens[*i]) {
+            Some(Token::Word(w)) => {
+                w.keyword == Keyword::AS
+                    || w.quote_style.is_some()
+                    || !RESERVED_FOR_TABLE_ALIAS.contains(&w.keyword)
+            }
+            _ => false,
+        };
+
+        for t in &tokens[pos..sig[s - 1]] {
+            out.push_str(&t.to_string());
+        }
+        out.push_str(&pinned.to_string());
+        if !has_explicit_alias {
+            let alias = Word {
+                value: alias.clone(),
+                quote_style: Some('"'),
+                keyword: Keyword::NoKeyword,
+            };
+            out.push_str(&format!(" AS {alias}"));
+        }
+
+        pos = sig[s + clause_len - 1] + 1;
+        s += clause_len;
+    }
+
+    if !found {
+        return Ok(sql.to_string());
+    }
+
+    for t in &tokens[pos..] {
+        out.push_str(&t.to_string());
+    }
+
+    Ok(out)
+}
+
+/// Parses `AS OF BLOCK '<hash>'` or `AS OF SYSTEM TIME '<time>'` clause at the
+/// beginning of the significant tokens, returning the number of tokens it spans
+fn parse_time_travel_clause(
+    tokens: &[Token],
+    sig: &[usize],
+) -> Result<Option<(TimeTravel, usize)>, DataFusionError> {
+    let is_word = |n: usize, value: &str| match sig.get(n).map(|i| &tokens[*i]) {
+        Some(Token::Word(w)) => w.quote_style.is_none() && w.value.eq_ignore_ascii_case(value),
+        _ => false,
+    };
+    let literal = |n: usize| match sig.get(n).map(|i| &tokens[*i]) {
+        Some(Token::SingleQuotedString(s)) => Ok(s.clone()),
+        _ => Err(DataFusionError::Plan(
+            "AS OF clause expects a string literal".to_string(),
+        )),
+    };
+
+    if !is_word(0, "AS") || !is_word(1, "OF") {
+        return Ok(None);
+    }
+
+    if is_word(2, "BLOCK") {
+        let value = literal(3)?;
+        let hash = Multihash::from_multibase(&value).map_err(|e| {
+            DataFusionError::Plan(format!("Invalid block hash '{value}' in AS OF clause: {e}"))
+        })?;
+        Ok(Some((TimeTravel::Block(hash), 4)))
+    } else if is_word(2, "SYSTEM") && is_word(3, "TIME") {
+        let value = literal(4)?;
+        let system_time = TimeTravel::parse_system_time(&value).ok_or_else(|| {
+            DataFusionError::Plan(format!("Invalid system time '{value}' in AS OF clause"))
+        })?;
+        Ok(Some((TimeTravel::SystemTime(system_time), 5)))
+    } else {
+        Ok(None)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,73 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::{Arc, Mutex};
+
+use dill::*;
+use internal_error::InternalError;
+use kamu_core::{CachedQueryResult, QueryResultCache, QueryResultCacheConfig, QueryResultCacheKey};
+
+use crate::utils::size_bounded_lru::SizeBoundedLru;
+use crate::QueryResultCacheMetrics;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct QueryResultCacheInMemory {
+    config: Arc<QueryResultCacheConfig>,
+    metrics: Arc<QueryResultCacheMetrics>,
+    entries: Mutex<SizeBoundedLru<QueryResultCacheKey, CachedQueryResult>>,
+}
+
+#[component(pub)]
+#[interface(dyn QueryResultCache)]
+#[scope(Singleton)]
+impl QueryResultCacheInMemory {
+    pub fn new(config: Arc<QueryResultCacheConfig>, metrics: Arc<QueryResultCacheMetrics>) -> Self {
+        Self {
+            entries: Mutex::new(SizeBoundedLru::new(config.max_total_size)),
+            config,
+            metrics,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryResultCache for QueryResultCacheInMemory {
+    async fn get(
+        &self,
+        key: &QueryResultCacheKey,
+    ) -> Result<Option<CachedQueryResult>, InternalError> {
+        let result = self.entries.lock().unwrap().get(key).cloned();
+        self.metrics.observe_lookup(result.is_some());
+        Ok(result)
+    }
+
+    async fn put(
+        &self,
+        key: &QueryResultCacheKey,
+        result: &CachedQueryResult,
+    ) -> Result<(), InternalError> {
+        let size = result.size();
+        if size > self.config.max_entry_size {
+            tracing::debug!(%key, size, "Query result is too large to be cached");
+            return Ok(());
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        entries.insert(key.clone(), result.clone(), size);
+        self.metrics.set_size(entries.total_size());
+        Ok(())
+    }
+
+    fn max_entry_size(&self) -> usize {
+        self.config.max_entry_size
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
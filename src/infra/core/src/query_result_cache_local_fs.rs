@@ -0,0 +1,217 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use datafusion::arrow::ipc::reader::FileReader;
+use datafusion::arrow::ipc::writer::FileWriter;
+use dill::*;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::{
+    CacheDir,
+    CachedQueryResult,
+    QueryResultCache,
+    QueryResultCacheConfig,
+    QueryResultCacheKey,
+};
+
+use crate::utils::size_bounded_lru::SizeBoundedLru;
+use crate::QueryResultCacheMetrics;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Stores query results as Arrow IPC files in the `query-results`
+/// sub-directory of the workspace cache, so they survive the restarts
+pub struct QueryResultCacheLocalFs {
+    root: PathBuf,
+    config: Arc<QueryResultCacheConfig>,
+    metrics: Arc<QueryResultCacheMetrics>,
+    entries: Mutex<SizeBoundedLru<String, ()>>,
+}
+
+#[component(pub)]
+#[interface(dyn QueryResultCache)]
+#[scope(Singleton)]
+impl QueryResultCacheLocalFs {
+    pub fn new(
+        cache_dir: Arc<CacheDir>,
+        config: Arc<QueryResultCacheConfig>,
+        metrics: Arc<QueryResultCacheMetrics>,
+    ) -> Self {
+        let root = cache_dir.join("query-results");
+        let mut entries = SizeBoundedLru::new(config.max_total_size);
+
+        // Restore the index of the entries left by the previous runs, oldest first,
+        // deleting the ones that no longer fit into the limits
+        match Self::list_entries(&root) {
+            Ok(existing) => {
+                for (file_name, size) in existing {
+                    for (evicted, ()) in entries.insert(file_name, (), size) {
+                        std::fs::remove_file(root.join(evicted)).ok();
+                    }
+                }
+            }
+            Err(err) => {
+                tracing::warn!(
+                    path = %root.display(),
+                    error = ?err,
+                    error_msg = %err,
+                    "Failed to read the query result cache directory",
+                );
+            }
+        }
+
+        metrics.set_size(entries.total_size());
+
+        Self {
+            root,
+            config,
+            metrics,
+            entries: Mutex::new(entries),
+        }
+    }
+
+    fn list_entries(root: &Path) -> Result<Vec<(String, usize)>, std::io::Error> {
+        if !root.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        for entry in std::fs::read_dir(root)? {
+            let entry = entry?;
+            let meta = entry.metadata()?;
+            if !meta.is_file() {
+                continue;
+            }
+            let Ok(file_name) = entry.file_name().into_string() else {
+                continue;
+            };
+            let size = usize::try_from(meta.len()).unwrap_or(usize::MAX);
+            entries.push((meta.modified()?, file_name, size));
+        }
+        entries.sort();
+
+        Ok(entries
+            .into_iter()
+            .map(|(_, file_name, size)| (file_name, size))
+            .collect())
+    }
+
+    fn read_entry(path: &Path) -> Result<Option<CachedQueryResult>, InternalError> {
+        let file = match std::fs::File::open(path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.int_err()),
+        };
+
+        let reader = FileReader::try_new(file, None).int_err()?;
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>().int_err()?;
+
+        Ok(Some(CachedQueryResult::new(schema, batches)))
+    }
+
+    fn write_entry(
+        root: &Path,
+        path: &Path,
+        result: &CachedQueryResult,
+    ) -> Result<usize, InternalError> {
+        std::fs::create_dir_all(root).int_err()?;
+
+        // Write into a temporary file first so that readers never observe partially
+        // written entries
+        let mut file = tempfile::NamedTempFile::new_in(root).int_err()?;
+        {
+            let mut writer = FileWriter::try_new(file.as_file_mut(), &result.schema).int_err()?;
+            for batch in &result.batches {
+                writer.write(batch).int_err()?;
+            }
+            writer.finish().int_err()?;
+        }
+        let size = file.as_file().metadata().int_err()?.len();
+        file.persist(path).int_err()?;
+
+        Ok(usize::try_from(size).unwrap_or(usize::MAX))
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryResultCache for QueryResultCacheLocalFs {
+    async fn get(
+        &self,
+        key: &QueryResultCacheKey,
+    ) -> Result<Option<CachedQueryResult>, InternalError> {
+        let file_name = key.to_string();
+
+        let known = self.entries.lock().unwrap().get(&file_name).is_some();
+        let result = if known {
+            let path = self.root.join(&file_name);
+            let result = tokio::task::spawn_blocking(move || Self::read_entry(&path))
+                .await
+                .int_err()??;
+
+            // Entry could have been removed by the cache cleanup
+            if result.is_none() {
+                let mut entries = self.entries.lock().unwrap();
+                entries.remove(&file_name);
+                self.metrics.set_size(entries.total_size());
+            }
+            result
+        } else {
+            None
+        };
+
+        self.metrics.observe_lookup(result.is_some());
+        Ok(result)
+    }
+
+    async fn put(
+        &self,
+        key: &QueryResultCacheKey,
+        result: &CachedQueryResult,
+    ) -> Result<(), InternalError> {
+        if result.size() > self.config.max_entry_size {
+            tracing::debug!(%key, size = result.size(), "Query result is too large to be cached");
+            return Ok(());
+        }
+
+        let file_name = key.to_string();
+        let root = self.root.clone();
+        let path = self.root.join(&file_name);
+        let result = result.clone();
+
+        let size = tokio::task::spawn_blocking(move || Self::write_entry(&root, &path, &result))
+            .await
+            .int_err()??;
+
+        let evicted = {
+            let mut entries = self.entries.lock().unwrap();
+            let evicted = entries.insert(file_name, (), size);
+            self.metrics.set_size(entries.total_size());
+            evicted
+        };
+
+        for (file_name, ()) in evicted {
+            if let Err(err) = tokio::fs::remove_file(self.root.join(&file_name)).await
+                && err.kind() != std::io::ErrorKind::NotFound
+            {
+                return Err(err.int_err());
+            }
+        }
+
+        Ok(())
+    }
+
+    fn max_entry_size(&self) -> usize {
+        self.config.max_entry_size
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,70 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use dill::*;
+use observability::metrics::MetricsProvider;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+pub struct QueryResultCacheMetrics {
+    pub hits_total: prometheus::IntCounter,
+    pub misses_total: prometheus::IntCounter,
+    pub size_bytes: prometheus::IntGauge,
+}
+
+#[component(pub)]
+#[interface(dyn MetricsProvider)]
+#[scope(Singleton)]
+impl QueryResultCacheMetrics {
+    pub fn new() -> Self {
+        use prometheus::*;
+
+        Self {
+            hits_total: IntCounter::new(
+                "query_result_cache_hits_total",
+                "Number of queries served from the result cache",
+            )
+            .unwrap(),
+            misses_total: IntCounter::new(
+                "query_result_cache_misses_total",
+                "Number of queries that were not found in the result cache",
+            )
+            .unwrap(),
+            size_bytes: IntGauge::new(
+                "query_result_cache_size_bytes",
+                "Total size of the results currently stored in the cache",
+            )
+            .unwrap(),
+        }
+    }
+
+    pub(crate) fn observe_lookup(&self, hit: bool) {
+        if hit {
+            self.hits_total.inc();
+        } else {
+            self.misses_total.inc();
+        }
+    }
+
+    pub(crate) fn set_size(&self, size: usize) {
+        self.size_bytes.set(i64::try_from(size).unwrap_or(i64::MAX));
+    }
+}
+
+impl MetricsProvider for QueryResultCacheMetrics {
+    fn register(&self, reg: &prometheus::Registry) -> prometheus::Result<()> {
+        reg.register(Box::new(self.hits_total.clone()))?;
+        reg.register(Box::new(self.misses_total.clone()))?;
+        reg.register(Box::new(self.size_bytes.clone()))?;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -12,14 +12,17 @@ use std::convert::TryFrom;
 use std::sync::Arc;
 
 use datafusion::arrow;
+use datafusion::common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion::error::DataFusionError;
 use datafusion::execution::runtime_env::{RuntimeConfig, RuntimeEnv};
+use datafusion::logical_expr::{LogicalPlan, Volatility};
 use datafusion::parquet::arrow::async_reader::ParquetObjectReader;
 use datafusion::parquet::file::metadata::ParquetMetaData;
 use datafusion::parquet::schema::types::Type;
 use datafusion::prelude::*;
 use datafusion::sql::TableReference;
 use dill::*;
+use futures::TryStreamExt;
 use internal_error::{InternalError, ResultIntoInternal};
 use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer};
 use kamu_core::*;
@@ -34,6 +37,7 @@ pub struct QueryServiceImpl {
     dataset_repo: Arc<dyn DatasetRepository>,
     object_store_registry: Arc<dyn ObjectStoreRegistry>,
     dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+    query_result_cache: Option<Arc<dyn QueryResultCache>>,
 }
 
 #[component(pub)]
@@ -43,11 +47,13 @@ impl QueryServiceImpl {
         dataset_repo: Arc<dyn DatasetRepository>,
         object_store_registry: Arc<dyn ObjectStoreRegistry>,
         dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
+        query_result_cache: Option<Arc<dyn QueryResultCache>>,
     ) -> Self {
         Self {
             dataset_repo,
             object_store_registry,
             dataset_action_authorizer,
+            query_result_cache,
         }
     }
 
@@ -92,11 +98,22 @@ impl QueryServiceImpl {
         sql: &str,
         options: QueryOptions,
     ) -> Result<QueryState, QueryError> {
-        use datafusion::sql::parser::Statement;
-
         let name_resolution_enabled = options.input_datasets.is_empty();
 
         if !name_resolution_enabled {
+            // Time travel clauses can pin datasets whose block hashes were not specified
+            // explicitly in the options. Plain references are recorded as `None`.
+            let mut references: BTreeMap<String, Vec<Option<TimeTravel>>> = BTreeMap::new();
+            for name in extract_table_names(sql)? {
+                match TimeTravel::from_table_name(&name) {
+                    Some((alias, tt)) => references
+                        .entry(alias.to_string())
+                        .or_default()
+                        .push(Some(tt)),
+                    None => references.entry(name).or_default().push(None),
+                }
+            }
+
             let mut input_datasets = BTreeMap::new();
 
             for (id, opts) in options.input_datasets {
@@ -108,7 +125,36 @@ impl QueryServiceImpl {
                     .find_dataset_by_ref(&id.as_local_ref())
                     .await?;
 
+                let aliased = references.get(&opts.alias).map_or(&[][..], Vec::as_slice);
+                let has_plain_reference = aliased.iter().any(Option::is_none);
+
+                let mut time_travel_hash: Option<Multihash> = None;
+                for tt in aliased.iter().flatten() {
+                    let hash = tt
+                        .resolve(dataset.as_ref())
+                        .await?
+                        .ok_or_else(|| Self::time_travel_error(&id, &opts.alias, tt))?;
+
+                    if let Some(prev_hash) = &time_travel_hash
+                        && *prev_hash != hash
+                    {
+                        return Err(Self::different_states_error(&opts.alias));
+                    }
+                    time_travel_hash = Some(hash);
+                }
+
                 let block_hash = if let Some(block_hash) = opts.block_hash {
+                    if let Some(time_travel_hash) = time_travel_hash
+                        && time_travel_hash != block_hash
+                    {
+                        return Err(DataFusionError::Plan(format!(
+                            "Dataset {} is pinned to block {block_hash} but time travel resolves \
+                             to block {time_travel_hash}",
+                            opts.alias
+                        ))
+                        .into());
+                    }
+
                     // Validate that block the user is asking for exists
                     // SECURITY: Are we leaking information here by doing this check before auth?
                     if !dataset
@@ -121,6 +167,21 @@ impl QueryServiceImpl {
                     }
 
                     block_hash
+                } else if let Some(time_travel_hash) = time_travel_hash {
+                    // Plain references read the dataset at its head
+                    if has_plain_reference {
+                        let head = dataset
+                            .as_metadata_chain()
+                            .resolve_ref(&BlockRef::Head)
+                            .await
+                            .int_err()?;
+
+                        if head != time_travel_hash {
+                            return Err(Self::different_states_error(&opts.alias));
+                        }
+                    }
+
+                    time_travel_hash
                 } else {
                     dataset
                         .as_metadata_chain()
@@ -142,61 +203,177 @@ impl QueryServiceImpl {
             // In the name resolution mode we have to inspect SQL to
             // understand which datasets the query is using and populate the block hashes
             // and aliases for them
-            let mut table_refs = Vec::new();
-
-            let statements = datafusion::sql::parser::DFParser::parse_sql(sql)
-                .map_err(|e| DataFusionError::SQL(e, None))?;
-
-            for stmt in statements {
-                match stmt {
-                    Statement::Statement(stmt) => {
-                        table_refs.append(&mut extract_table_refs(&stmt)?);
-                    }
-                    Statement::CreateExternalTable(_)
-                    | Statement::CopyTo(_)
-                    | Statement::Explain(_) => {}
-                }
-            }
+            let table_names = extract_table_names(sql)?;
 
             // Resolve table references into datasets.
             // We simply ignore unresolvable, letting query to fail at the execution stage.
-            let mut input_datasets = BTreeMap::new();
+            let mut input_datasets: BTreeMap<DatasetID, QueryStateDataset> = BTreeMap::new();
 
-            for mut table in table_refs {
-                // Strip possible `kamu.kamu.` prefix
-                while table.0.len() > 1 && table.0[0].value == "kamu" {
-                    table.0.remove(0);
-                }
-                if table.0.len() == 1 {
-                    let alias = table.0.pop().unwrap().value;
-                    let Ok(dataset_ref) = DatasetRef::try_from(&alias) else {
-                        tracing::warn!(alias, "Ignoring table with invalid alias");
-                        continue;
-                    };
-                    let Ok(hdl) = self.dataset_repo.resolve_dataset_ref(&dataset_ref).await else {
-                        tracing::warn!(?dataset_ref, "Ignoring table with unresolvable alias");
-                        continue;
-                    };
-
-                    // SECURITY: We expect that access permissions will be validated during
-                    // the query execution and that we're not leaking information here if the user
-                    // doesn't have access to this dataset.
-                    let dataset = self.dataset_repo.get_dataset_by_handle(&hdl);
-
-                    let block_hash = dataset
+            for name in table_names {
+                let (alias, time_travel) = match TimeTravel::from_table_name(&name) {
+                    Some((alias, tt)) => (alias.to_string(), Some(tt)),
+                    None => (name, None),
+                };
+
+                let Ok(dataset_ref) = DatasetRef::try_from(&alias) else {
+                    tracing::warn!(alias, "Ignoring table with invalid alias");
+                    continue;
+                };
+                let Ok(hdl) = self.dataset_repo.resolve_dataset_ref(&dataset_ref).await else {
+                    tracing::warn!(?dataset_ref, "Ignoring table with unresolvable alias");
+                    continue;
+                };
+
+                // SECURITY: We expect that access permissions will be validated during
+                // the query execution and that we're not leaking information here if the user
+                // doesn't have access to this dataset.
+                let dataset = self.dataset_repo.get_dataset_by_handle(&hdl);
+
+                let block_hash = if let Some(tt) = &time_travel {
+                    tt.resolve(dataset.as_ref())
+                        .await?
+                        .ok_or_else(|| Self::time_travel_error(&hdl.id, &alias, tt))?
+                } else {
+                    dataset
                         .as_metadata_chain()
                         .resolve_ref(&BlockRef::Head)
                         .await
-                        .int_err()?;
+                        .int_err()?
+                };
 
-                    input_datasets.insert(hdl.id.clone(), QueryStateDataset { alias, block_hash });
+                // Query state can only pin a dataset to a single block
+                if let Some(existing) = input_datasets.get(&hdl.id)
+                    && existing.block_hash != block_hash
+                {
+                    return Err(Self::different_states_error(&alias));
                 }
+
+                input_datasets.insert(hdl.id.clone(), QueryStateDataset { alias, block_hash });
             }
 
             Ok(QueryState { input_datasets })
         }
     }
 
+    /// Serves the query from the result cache or executes it and caches the
+    /// materialized result
+    async fn execute_cached(
+        &self,
+        cache: &dyn QueryResultCache,
+        ctx: &SessionContext,
+        statement: &str,
+        state: &QueryState,
+    ) -> Result<DataFrame, QueryError> {
+        let key = QueryResultCacheKey::new(statement, state);
+
+        // SECURITY: Cache hits bypass the planning stage where the access to datasets
+        // is normally validated, so we have to check it here
+        if self.is_state_readable(state).await? {
+            match cache.get(&key).await {
+                Ok(Some(cached)) => {
+                    tracing::debug!(%key, "Serving SQL query from the result cache");
+                    let table = datafusion::datasource::MemTable::try_new(
+                        cached.schema,
+                        vec![cached.batches],
+                    )?;
+                    return Ok(ctx.read_table(Arc::new(table))?);
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    tracing::warn!(
+                        %key,
+                        error = ?err,
+                        error_msg = %err,
+                        "Query result cache lookup failed",
+                    );
+                }
+            }
+        }
+
+        let df = ctx.sql(statement).await?;
+
+        // Results of queries calling functions like `now()` or `random()` depend on
+        // more than the state of the inputs
+        if is_volatile(df.logical_plan())? {
+            tracing::debug!(%key, "Not caching the result of a volatile query");
+            return Ok(df);
+        }
+
+        // Buffer the result while it fits into a cache entry, otherwise give up on
+        // caching and let the caller stream the query from scratch
+        let schema = df.schema().inner().clone();
+        let max_entry_size = cache.max_entry_size();
+        let mut stream = df.clone().execute_stream().await?;
+        let mut batches = Vec::new();
+        let mut size = 0;
+
+        while let Some(batch) = stream.try_next().await? {
+            size += batch.get_array_memory_size();
+            if size > max_entry_size {
+                tracing::debug!(%key, "Query result is too large to be cached");
+                return Ok(df);
+            }
+            batches.push(batch);
+        }
+
+        let result = CachedQueryResult::new(schema, batches);
+        if let Err(err) = cache.put(&key, &result).await {
+            tracing::warn!(
+                %key,
+                error = ?err,
+                error_msg = %err,
+                "Failed to cache query result",
+            );
+        }
+
+        let table = datafusion::datasource::MemTable::try_new(result.schema, vec![result.batches])?;
+        Ok(ctx.read_table(Arc::new(table))?)
+    }
+
+    async fn is_state_readable(&self, state: &QueryState) -> Result<bool, QueryError> {
+        for id in state.input_datasets.keys() {
+            let Ok(hdl) = self
+                .dataset_repo
+                .resolve_dataset_ref(&id.as_local_ref())
+                .await
+            else {
+                return Ok(false);
+            };
+            if !self
+                .dataset_action_authorizer
+                .is_action_allowed(&hdl, DatasetAction::Read)
+                .await?
+            {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn time_travel_error(
+        dataset_id: &DatasetID,
+        alias: &str,
+        time_travel: &TimeTravel,
+    ) -> QueryError {
+        match time_travel {
+            TimeTravel::Block(block_hash) => {
+                DatasetBlockNotFoundError::new(dataset_id.clone(), block_hash.clone()).into()
+            }
+            TimeTravel::SystemTime(_) => {
+                DataFusionError::Plan(format!("Dataset {alias} has no state as of {time_travel}"))
+                    .into()
+            }
+        }
+    }
+
+    /// Query state can only pin a dataset to a single block
+    fn different_states_error(alias: &str) -> QueryError {
+        DataFusionError::Plan(format!(
+            "Dataset {alias} is referenced at different states within one query"
+        ))
+        .into()
+    }
+
     async fn single_dataset(
         &self,
         dataset_ref: &DatasetRef,
@@ -374,10 +551,23 @@ impl QueryService for QueryServiceImpl {
     ) -> Result<QueryResponse, QueryError> {
         tracing::info!(statement, ?options, "Executing SQL query");
 
+        let statement = &rewrite_time_travel(statement)?;
+
         let state = self.resolve_query_state(statement, options.clone()).await?;
 
         tracing::info!(?state, "Resolved SQL query state");
 
+        // Results are only cached for the queries reading datasets, as otherwise the
+        // state does not capture what the result depends on. Hints limit the data
+        // that is considered, so such results are not reusable either.
+        let cache = self.query_result_cache.as_ref().filter(|_| {
+            !state.input_datasets.is_empty()
+                && options
+                    .input_datasets
+                    .values()
+                    .all(|opt| opt.hints.is_none())
+        });
+
         // Map resolved state back to options (including hints) for query planner
         let options = QueryOptions {
             input_datasets: state
@@ -399,7 +589,13 @@ impl QueryService for QueryServiceImpl {
                 .collect(),
         };
         let ctx = self.session_context(options);
-        let df = ctx.sql(statement).await?;
+
+        let df = if let Some(cache) = cache {
+            self.execute_cached(cache.as_ref(), &ctx, statement, &state)
+                .await?
+        } else {
+            ctx.sql(statement).await?
+        };
 
         Ok(QueryResponse { df, state })
     }
@@ -495,6 +691,39 @@ async fn read_data_slice_metadata(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Extracts names of the tables in the `kamu` schema that the query refers to
+fn extract_table_names(sql: &str) -> Result<Vec<String>, QueryError> {
+    use datafusion::sql::parser::Statement;
+
+    let mut table_refs = Vec::new();
+
+    let statements = datafusion::sql::parser::DFParser::parse_sql(sql)
+        .map_err(|e| DataFusionError::SQL(e, None))?;
+
+    for stmt in statements {
+        match stmt {
+            Statement::Statement(stmt) => {
+                table_refs.append(&mut extract_table_refs(&stmt)?);
+            }
+            Statement::CreateExternalTable(_) | Statement::CopyTo(_) | Statement::Explain(_) => {}
+        }
+    }
+
+    let mut table_names = Vec::new();
+
+    for mut table in table_refs {
+        // Strip possible `kamu.kamu.` prefix
+        while table.0.len() > 1 && table.0[0].value == "kamu" {
+            table.0.remove(0);
+        }
+        if table.0.len() == 1 {
+            table_names.push(table.0.pop().unwrap().value);
+        }
+    }
+
+    Ok(table_names)
+}
+
 // TODO: This is too complex - we should explore better ways to associate a
 // query with a certain state
 fn extract_table_refs(
@@ -573,3 +802,28 @@ fn extract_table_refs_rec_table_factor(
         _ => Ok(()),
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Checks whether the plan calls any functions that are not guaranteed to
+/// return the same result for the same input
+fn is_volatile(plan: &LogicalPlan) -> Result<bool, DataFusionError> {
+    let mut volatile = false;
+
+    plan.apply_with_subqueries(|node| {
+        for expr in node.expressions() {
+            if expr.exists(|e| {
+                Ok(matches!(
+                    e,
+                    Expr::ScalarFunction(f) if f.func.signature().volatility != Volatility::Immutable
+                ))
+            })? {
+                volatile = true;
+                return Ok(TreeNodeRecursion::Stop);
+            }
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+
+    Ok(volatile)
+}
@@ -304,7 +304,8 @@ impl ValidateUnimplementedEventsVisitor {
                 // TODO: Ensure has previous push source with matching name
                 unimplemented!("Disabling sources is not yet fully supported")
             }
-            // TODO: Consider schema evolution rules
+            // Schema evolution rules are enforced by the ingest writer when it produces
+            // new schemas, as chains received from other nodes were produced by them
             // TODO: Consider what happens with previously defined sources
             MetadataEvent::SetDataSchema(_)
             | MetadataEvent::Seed(_)
@@ -406,6 +407,13 @@ impl ValidateSetPollingSourceVisitor {
                     }
                 }
 
+                // Kafka source must specify at least one broker
+                if let FetchStep::Kafka(f) = &e.fetch {
+                    if f.brokers.is_empty() {
+                        invalid_event!(e.clone(), "Kafka source must specify at least one broker")
+                    }
+                }
+
                 true
             }
             _ => false,
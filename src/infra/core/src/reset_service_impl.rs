@@ -11,7 +11,10 @@ use std::sync::Arc;
 
 use dill::*;
 use internal_error::ResultIntoInternal;
+use kamu_accounts::CurrentAccountSubject;
+use kamu_audit_log::{AuditAction, AuditLogService};
 use kamu_core::*;
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::*;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -19,6 +22,9 @@ use opendatafabric::*;
 pub struct ResetServiceImpl {
     dataset_repo: Arc<dyn DatasetRepository>,
     dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    audit_log_service: Arc<dyn AuditLogService>,
+    current_account_subject: Arc<CurrentAccountSubject>,
+    outbox: Arc<dyn Outbox>,
 }
 
 #[component(pub)]
@@ -27,10 +33,16 @@ impl ResetServiceImpl {
     pub fn new(
         dataset_repo: Arc<dyn DatasetRepository>,
         dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        audit_log_service: Arc<dyn AuditLogService>,
+        current_account_subject: Arc<CurrentAccountSubject>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             dataset_repo,
             dataset_action_authorizer,
+            audit_log_service,
+            current_account_subject,
+            outbox,
         }
     }
 }
@@ -61,15 +73,17 @@ impl ResetService for ResetServiceImpl {
                 .unwrap()
                 .0
         };
+        let maybe_current_head = dataset
+            .as_metadata_chain()
+            .try_get_ref(&BlockRef::Head)
+            .await?;
+
         if let Some(old_head) = old_head_maybe
-            && let Some(current_head) = dataset
-                .as_metadata_chain()
-                .try_get_ref(&BlockRef::Head)
-                .await?
-            && old_head != &current_head
+            && let Some(current_head) = &maybe_current_head
+            && old_head != current_head
         {
             return Err(ResetError::OldHeadMismatch(OldHeadMismatchError {
-                current_head,
+                current_head: current_head.clone(),
                 old_head: old_head.clone(),
             }));
         }
@@ -86,6 +100,40 @@ impl ResetService for ResetServiceImpl {
             )
             .await?;
 
+        if let Some(current_head) = &maybe_current_head {
+            crate::utils::slice_indexes::delete_orphaned_slice_indexes(
+                dataset.as_ref(),
+                current_head,
+                new_head,
+            )
+            .await;
+
+            crate::utils::compacted_slices::delete_compacted_slices(dataset.as_ref()).await;
+        }
+
+        self.outbox
+            .post_message(
+                MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                DatasetReferenceMessage::updated(
+                    dataset_handle.id.clone(),
+                    maybe_current_head,
+                    new_head.clone(),
+                ),
+            )
+            .await?;
+
+        self.audit_log_service
+            .record(
+                self.current_account_subject.maybe_account_id(),
+                AuditAction::DatasetReset,
+                Some(&dataset_handle.id),
+                Some(format!(
+                    "Reset head of dataset '{}' to {new_head}",
+                    dataset_handle.alias
+                )),
+            )
+            .await?;
+
         Ok(new_head.clone())
     }
 }
@@ -0,0 +1,493 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use datafusion::prelude::*;
+use datafusion::scalar::ScalarValue;
+use dill::{component, interface};
+use futures::stream::TryStreamExt;
+use internal_error::ResultIntoInternal;
+use kamu_core::*;
+use messaging_outbox::{Outbox, OutboxExt};
+use opendatafabric::{
+    DatasetHandle,
+    DatasetKind,
+    DatasetVocabulary,
+    MetadataBlock,
+    MetadataEvent,
+    Multihash,
+    OffsetInterval,
+    SetVocab,
+};
+use random_names::get_random_name;
+use time_source::SystemTimeSource;
+use url::Url;
+
+use crate::utils::compacted_slices::delete_compacted_slices;
+use crate::utils::slice_indexes::{
+    build_slice_indexes,
+    collect_slice_hashes,
+    delete_slice_indexes,
+};
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct RetentionServiceImpl {
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    object_store_registry: Arc<dyn ObjectStoreRegistry>,
+    time_source: Arc<dyn SystemTimeSource>,
+    run_info_dir: Arc<RunInfoDir>,
+    outbox: Arc<dyn Outbox>,
+}
+
+struct RetentionChainInfo {
+    head: Multihash,
+    vocab: DatasetVocabulary,
+    /// All blocks of the chain in the order they were added (oldest first)
+    blocks: Vec<(Multihash, MetadataBlock)>,
+}
+
+/// Records of a single data slice that survive the retention
+struct RetainedRecords {
+    data_frame: DataFrame,
+    num_records: u64,
+    num_removed: u64,
+}
+
+#[component(pub)]
+#[interface(dyn RetentionService)]
+impl RetentionServiceImpl {
+    pub fn new(
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        object_store_registry: Arc<dyn ObjectStoreRegistry>,
+        time_source: Arc<dyn SystemTimeSource>,
+        run_info_dir: Arc<RunInfoDir>,
+        outbox: Arc<dyn Outbox>,
+    ) -> Self {
+        Self {
+            dataset_repo,
+            dataset_authorizer,
+            object_store_registry,
+            time_source,
+            run_info_dir,
+            outbox,
+        }
+    }
+
+    async fn gather_chain_info(
+        &self,
+        dataset: &dyn Dataset,
+    ) -> Result<RetentionChainInfo, RetentionError> {
+        let chain = dataset.as_metadata_chain();
+        let head = chain.resolve_ref(&BlockRef::Head).await?;
+
+        let mut blocks: Vec<_> = chain
+            .iter_blocks_interval(&head, None, false)
+            .try_collect()
+            .await?;
+        blocks.reverse();
+
+        let vocab: DatasetVocabulary = blocks
+            .iter()
+            .rev()
+            .find_map(|(_, block)| match &block.event {
+                MetadataEvent::SetVocab(e) => Some(e.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(SetVocab::default)
+            .into();
+
+        Ok(RetentionChainInfo {
+            head,
+            vocab,
+            blocks,
+        })
+    }
+
+    async fn read_retained_records(
+        ctx: &SessionContext,
+        data_slice_url: Url,
+        num_records: u64,
+        event_time_column: &str,
+        cutoff: DateTime<Utc>,
+    ) -> Result<RetainedRecords, RetentionError> {
+        let data_frame = ctx
+            .read_parquet(
+                vec![data_slice_url],
+                datafusion::execution::options::ParquetReadOptions {
+                    file_extension: "",
+                    ..Default::default()
+                },
+            )
+            .await
+            .int_err()?;
+
+        let event_time_data_type = data_frame
+            .schema()
+            .field_with_unqualified_name(event_time_column)
+            .int_err()?
+            .data_type()
+            .clone();
+
+        // Age of records without an event time cannot be determined, so they are
+        // never removed
+        let event_time = col(Column::from_name(event_time_column));
+        let data_frame = data_frame
+            .filter(event_time.clone().is_null().or(event_time.gt_eq(cast(
+                Expr::Literal(ScalarValue::TimestampMillisecond(
+                    Some(cutoff.timestamp_millis()),
+                    Some("UTC".into()),
+                )),
+                event_time_data_type,
+            ))))
+            .int_err()?;
+
+        let num_retained = data_frame.clone().count().await.int_err()? as u64;
+
+        Ok(RetainedRecords {
+            data_frame,
+            num_records: num_retained,
+            num_removed: num_records - num_retained,
+        })
+    }
+
+    /// Writes retained records into a new data file assigning them sequential
+    /// offsets starting from the specified one
+    async fn write_retained_records(
+        data_frame: DataFrame,
+        offset_column: &str,
+        start_offset: u64,
+        new_file_path: &Path,
+    ) -> Result<(), RetentionError> {
+        let columns: Vec<_> = data_frame
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        let columns_str: Vec<_> = columns.iter().map(String::as_str).collect();
+
+        let offset_data_type = data_frame
+            .schema()
+            .field_with_unqualified_name(offset_column)
+            .int_err()?
+            .data_type()
+            .clone();
+
+        let data_frame = data_frame
+            .repartition(Partitioning::RoundRobinBatch(1))
+            .int_err()?
+            .with_column(
+                offset_column,
+                datafusion::functions_window::row_number::row_number()
+                    .order_by(vec![col(Column::from_name(offset_column)).sort(true, false)])
+                    .build()
+                    .int_err()?,
+            )
+            .int_err()?
+            .with_column(
+                offset_column,
+                cast(
+                    col(Column::from_name(offset_column))
+                        + lit(i64::try_from(start_offset).unwrap() - 1),
+                    offset_data_type,
+                ),
+            )
+            .int_err()?
+            .select_columns(&columns_str)
+            .int_err()?
+            .sort(vec![col(Column::from_name(offset_column)).sort(true, false)])
+            .int_err()?;
+
+        data_frame
+            .write_parquet(
+                new_file_path.to_str().unwrap(),
+                datafusion::dataframe::DataFrameWriteOptions::new().with_single_file_output(true),
+                None,
+            )
+            .await
+            .int_err()?;
+
+        Ok(())
+    }
+
+    fn create_run_retention_dir(&self) -> Result<PathBuf, RetentionError> {
+        let retention_dir_path = self
+            .run_info_dir
+            .join(get_random_name(Some("retention-"), 10));
+        fs::create_dir_all(&retention_dir_path).int_err()?;
+        Ok(retention_dir_path)
+    }
+
+    #[tracing::instrument(level = "info", skip_all)]
+    async fn apply_retention_impl(
+        &self,
+        dataset: Arc<dyn Dataset>,
+        options: RetentionOptions,
+    ) -> Result<RetentionResult, RetentionError> {
+        let chain_info = self.gather_chain_info(dataset.as_ref()).await?;
+
+        let ctx = new_session_context(self.object_store_registry.clone());
+        let data_repo = dataset.as_data_repo();
+
+        // Evaluate every data slice against the cutoff
+        let mut retained_slices = Vec::with_capacity(chain_info.blocks.len());
+        let mut first_affected_block = None;
+        let mut num_records_removed = 0;
+        let mut num_slices_removed = 0;
+
+        for (index, (_, block)) in chain_info.blocks.iter().enumerate() {
+            let retained = if let MetadataEvent::AddData(add_data) = &block.event
+                && let Some(data_slice) = &add_data.new_data
+            {
+                let retained = Self::read_retained_records(
+                    &ctx,
+                    data_repo.get_internal_url(&data_slice.physical_hash).await,
+                    data_slice.num_records(),
+                    &chain_info.vocab.event_time_column,
+                    options.cutoff,
+                )
+                .await?;
+
+                if retained.num_removed != 0 {
+                    first_affected_block.get_or_insert(index);
+                    num_records_removed += retained.num_removed;
+                    if retained.num_records == 0 {
+                        num_slices_removed += 1;
+                    }
+                }
+
+                Some(retained)
+            } else {
+                None
+            };
+            retained_slices.push(retained);
+        }
+
+        let Some(first_affected_block) = first_affected_block else {
+            return Ok(RetentionResult::NothingToDo);
+        };
+
+        if options.dry_run {
+            return Ok(RetentionResult::DryRun {
+                head: chain_info.head,
+                num_records_removed,
+                num_slices_removed,
+            });
+        }
+
+        let retention_dir_path = self.create_run_retention_dir()?;
+
+        // Blocks preceding the first affected slice are kept as is, while all
+        // subsequent blocks are re-committed on top of them, as removing records
+        // shifts offsets of all later slices
+        let retained_head = chain_info.blocks[first_affected_block - 1].0.clone();
+        let mut current_head = retained_head.clone();
+        let mut prev_offset = chain_info.blocks[..first_affected_block]
+            .iter()
+            .rev()
+            .find_map(|(_, block)| match &block.event {
+                MetadataEvent::AddData(e) => e.last_offset(),
+                _ => None,
+            });
+
+        // Data files of all re-committed slices are superseded by the rewritten ones
+        let replaced_slices: Vec<_> = chain_info.blocks[first_affected_block..]
+            .iter()
+            .filter_map(|(_, block)| match &block.event {
+                MetadataEvent::AddData(e) => e.new_data.as_ref().map(|s| s.physical_hash.clone()),
+                _ => None,
+            })
+            .collect();
+
+        for (index, ((_, block), retained)) in chain_info
+            .blocks
+            .into_iter()
+            .zip(retained_slices)
+            .enumerate()
+            .skip(first_affected_block)
+        {
+            let commit_opts = CommitOpts {
+                block_ref: &BlockRef::Head,
+                system_time: Some(self.time_source.now()),
+                prev_block_hash: Some(Some(&current_head)),
+                check_object_refs: false,
+                update_block_ref: false,
+            };
+
+            let commit_result = match block.event {
+                MetadataEvent::AddData(add_data) => {
+                    let start_offset = prev_offset.map_or(0, |offset| offset + 1);
+
+                    let new_data = match retained {
+                        Some(retained) if retained.num_records != 0 => {
+                            let new_file_path =
+                                retention_dir_path.join(format!("retained-slice-{index}"));
+                            Self::write_retained_records(
+                                retained.data_frame,
+                                &chain_info.vocab.offset_column,
+                                start_offset,
+                                &new_file_path,
+                            )
+                            .await?;
+
+                            Some((
+                                OwnedFile::new(new_file_path),
+                                OffsetInterval {
+                                    start: start_offset,
+                                    end: start_offset + retained.num_records - 1,
+                                },
+                            ))
+                        }
+                        _ => None,
+                    };
+
+                    // Slice expired entirely and the block carries nothing else
+                    if new_data.is_none()
+                        && add_data.new_checkpoint.is_none()
+                        && add_data.new_watermark.is_none()
+                        && add_data.new_source_state.is_none()
+                    {
+                        continue;
+                    }
+
+                    let (data_file, new_offset_interval) = new_data.unzip();
+                    let new_prev_offset =
+                        new_offset_interval.as_ref().map(|i| i.end).or(prev_offset);
+
+                    let commit_result = dataset
+                        .commit_add_data(
+                            AddDataParams {
+                                prev_checkpoint: add_data.prev_checkpoint,
+                                prev_offset,
+                                new_offset_interval,
+                                new_watermark: add_data.new_watermark,
+                                new_source_state: add_data.new_source_state,
+                            },
+                            data_file,
+                            add_data
+                                .new_checkpoint
+                                .map(|c| CheckpointRef::Existed(c.physical_hash)),
+                            commit_opts,
+                        )
+                        .await
+                        .int_err()?;
+
+                    prev_offset = new_prev_offset;
+                    commit_result
+                }
+                event => dataset.commit_event(event, commit_opts).await.int_err()?,
+            };
+
+            current_head = commit_result.new_head;
+        }
+
+        dataset
+            .as_metadata_chain()
+            .set_ref(
+                &BlockRef::Head,
+                &current_head,
+                SetRefOpts {
+                    validate_block_present: true,
+                    check_ref_is: Some(Some(&chain_info.head)),
+                },
+            )
+            .await?;
+
+        build_slice_indexes(dataset.as_ref(), Some(&retained_head), &current_head).await;
+
+        let new_slices: HashSet<_> =
+            collect_slice_hashes(dataset.as_ref(), &current_head, Some(&retained_head))
+                .await?
+                .into_iter()
+                .collect();
+
+        for physical_hash in replaced_slices {
+            if new_slices.contains(&physical_hash) {
+                continue;
+            }
+
+            data_repo.delete(&physical_hash).await.int_err()?;
+
+            delete_slice_indexes(dataset.as_ref(), &[physical_hash]).await?;
+        }
+
+        // Merged files may contain the records removed from the rewritten slices
+        delete_compacted_slices(dataset.as_ref()).await;
+
+        Ok(RetentionResult::Success {
+            old_head: chain_info.head,
+            new_head: current_head,
+            num_records_removed,
+            num_slices_removed,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RetentionService for RetentionServiceImpl {
+    #[tracing::instrument(level = "info", skip_all, fields(%dataset_handle, ?options))]
+    async fn apply_retention(
+        &self,
+        dataset_handle: &DatasetHandle,
+        options: RetentionOptions,
+    ) -> Result<RetentionResult, RetentionError> {
+        self.dataset_authorizer
+            .check_action_allowed(dataset_handle, auth::DatasetAction::Write)
+            .await?;
+
+        let dataset = self.dataset_repo.get_dataset_by_handle(dataset_handle);
+
+        let dataset_kind = dataset
+            .get_summary(GetSummaryOpts::default())
+            .await
+            .int_err()?
+            .kind;
+
+        // Removing records of a derivative dataset would break reproducibility
+        // of its transformation
+        if dataset_kind != DatasetKind::Root {
+            return Err(RetentionError::InvalidDatasetKind(
+                InvalidDatasetKindError {
+                    dataset_name: dataset_handle.alias.dataset_name.clone(),
+                },
+            ));
+        }
+
+        let result = self.apply_retention_impl(dataset, options).await?;
+
+        if let RetentionResult::Success {
+            old_head, new_head, ..
+        } = &result
+        {
+            self.outbox
+                .post_message(
+                    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                    DatasetReferenceMessage::updated(
+                        dataset_handle.id.clone(),
+                        Some(old_head.clone()),
+                        new_head.clone(),
+                    ),
+                )
+                .await?;
+        }
+
+        Ok(result)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
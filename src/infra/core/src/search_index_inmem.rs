@@ -0,0 +1,590 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use dill::*;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::*;
+use messaging_outbox::{
+    MessageConsumer,
+    MessageConsumerMeta,
+    MessageConsumerT,
+    MessageConsumptionDurability,
+};
+use opendatafabric::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// In-memory index of the dataset metadata used for local search.
+///
+/// The index is populated lazily by scanning all datasets upon the first
+/// search. Afterwards it is kept in sync with dataset lifecycle and reference
+/// messages: only the datasets that were created or whose head has moved are
+/// re-indexed upon the next search.
+pub struct SearchIndexInMemory {
+    state: Arc<tokio::sync::RwLock<State>>,
+}
+
+#[derive(Default)]
+struct State {
+    entries: HashMap<DatasetID, SearchIndexEntry>,
+    /// Datasets to re-index along with the generation they were last marked at
+    unindexed_dataset_ids: HashMap<DatasetID, u64>,
+    /// Incremented on every change reported by messages, lets a refresh detect
+    /// changes that arrived while it was reading datasets without the lock
+    generation: u64,
+    initial_scan_started: bool,
+    initially_scanned: bool,
+}
+
+#[derive(Debug, Clone)]
+struct SearchIndexEntry {
+    handle: DatasetHandle,
+    head: Multihash,
+    document: SearchDocument,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn MessageConsumer)]
+#[interface(dyn MessageConsumerT<DatasetLifecycleMessage>)]
+#[interface(dyn MessageConsumerT<DatasetReferenceMessage>)]
+#[meta(MessageConsumerMeta {
+    consumer_name: MESSAGE_CONSUMER_KAMU_CORE_SEARCH_INDEX,
+    feeding_producers: &[
+        MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
+        MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+    ],
+    durability: MessageConsumptionDurability::BestEffort,
+})]
+#[scope(Singleton)]
+impl SearchIndexInMemory {
+    pub fn new() -> Self {
+        Self {
+            state: Default::default(),
+        }
+    }
+
+    /// Returns datasets matching the query and filters ordered by relevance
+    /// along with their scores
+    #[tracing::instrument(level = "debug", skip_all, fields(?query, ?filters))]
+    pub async fn query(
+        &self,
+        dataset_repo: &dyn DatasetRepository,
+        query: Option<&str>,
+        filters: &SearchFilters,
+    ) -> Result<Vec<(DatasetHandle, SearchDocument, u32)>, InternalError> {
+        self.refresh(dataset_repo).await?;
+
+        let query = SearchQuery::parse(query.unwrap_or_default());
+
+        let state = self.state.read().await;
+
+        let mut hits: Vec<_> = state
+            .entries
+            .values()
+            .filter(|entry| entry.matches_filters(filters))
+            .filter_map(|entry| {
+                query
+                    .score(&entry.handle.alias, &entry.document)
+                    .map(|score| (entry.handle.clone(), entry.document.clone(), score))
+            })
+            .collect();
+
+        // Most relevant first, ties are broken by alias to keep pages stable
+        hits.sort_by(|(a_hdl, _, a_score), (b_hdl, _, b_score)| {
+            b_score
+                .cmp(a_score)
+                .then_with(|| a_hdl.alias.cmp(&b_hdl.alias))
+        });
+
+        Ok(hits)
+    }
+
+    /// Brings the index up to date with the current state of datasets
+    async fn refresh(&self, dataset_repo: &dyn DatasetRepository) -> Result<(), InternalError> {
+        use futures::TryStreamExt;
+
+        // Determine which datasets need to be (re-)indexed
+        let (is_initial_scan, since_generation, candidates): (
+            bool,
+            u64,
+            Vec<(DatasetID, Option<DatasetHandle>)>,
+        ) = {
+            let mut state = self.state.write().await;
+            if !state.initially_scanned {
+                state.initial_scan_started = true;
+                (true, state.generation, Vec::new())
+            } else {
+                let candidates: Vec<_> = state
+                    .unindexed_dataset_ids
+                    .keys()
+                    .map(|id| (id.clone(), None))
+                    .collect();
+                (false, state.generation, candidates)
+            }
+        };
+
+        if !is_initial_scan && candidates.is_empty() {
+            return Ok(());
+        }
+
+        let candidates = if is_initial_scan {
+            dataset_repo
+                .get_all_datasets()
+                .map_ok(|hdl| (hdl.id.clone(), Some(hdl)))
+                .try_collect()
+                .await?
+        } else {
+            candidates
+        };
+
+        let mut updated_entries = Vec::new();
+        let mut up_to_date_dataset_ids = Vec::new();
+        let mut removed_dataset_ids = Vec::new();
+
+        for (dataset_id, maybe_handle) in candidates {
+            let handle = match maybe_handle {
+                Some(handle) => handle,
+                None => match dataset_repo
+                    .try_resolve_dataset_ref(&dataset_id.as_local_ref())
+                    .await?
+                {
+                    Some(handle) => handle,
+                    None => {
+                        removed_dataset_ids.push(dataset_id);
+                        continue;
+                    }
+                },
+            };
+
+            let dataset = dataset_repo.get_dataset_by_handle(&handle);
+            let head = match dataset
+                .as_metadata_chain()
+                .resolve_ref(&BlockRef::Head)
+                .await
+            {
+                Ok(head) => head,
+                Err(GetRefError::NotFound(_)) => {
+                    removed_dataset_ids.push(dataset_id);
+                    continue;
+                }
+                Err(e) => return Err(e.int_err()),
+            };
+
+            let is_up_to_date = {
+                let state = self.state.read().await;
+                state
+                    .entries
+                    .get(&dataset_id)
+                    .is_some_and(|e| e.head == head)
+            };
+            if is_up_to_date {
+                up_to_date_dataset_ids.push(dataset_id);
+                continue;
+            }
+
+            tracing::debug!(%handle, %head, "Indexing dataset metadata");
+
+            let document = SearchDocument::build(dataset.as_ref(), &head).await?;
+            updated_entries.push(SearchIndexEntry {
+                handle,
+                head,
+                document,
+            });
+        }
+
+        let mut state = self.state.write().await;
+
+        // Results for datasets that changed since the candidates were picked may
+        // already be stale, so they are left for the next refresh
+        for dataset_id in up_to_date_dataset_ids {
+            if !state.is_changed_since(&dataset_id, since_generation) {
+                state.unindexed_dataset_ids.remove(&dataset_id);
+            }
+        }
+        for dataset_id in removed_dataset_ids {
+            if !state.is_changed_since(&dataset_id, since_generation) {
+                state.entries.remove(&dataset_id);
+                state.unindexed_dataset_ids.remove(&dataset_id);
+            }
+        }
+        for entry in updated_entries {
+            if !state.is_changed_since(&entry.handle.id, since_generation) {
+                state.unindexed_dataset_ids.remove(&entry.handle.id);
+                state.entries.insert(entry.handle.id.clone(), entry);
+            }
+        }
+        if is_initial_scan {
+            // Changes preceding the scan are covered by it
+            state
+                .unindexed_dataset_ids
+                .retain(|_, generation| *generation > since_generation);
+        }
+        state.initially_scanned = true;
+
+        tracing::debug!(num_entries = state.entries.len(), "Search index refreshed");
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl State {
+    fn mark_unindexed(&mut self, dataset_id: &DatasetID) {
+        self.generation += 1;
+        self.unindexed_dataset_ids
+            .insert(dataset_id.clone(), self.generation);
+    }
+
+    fn is_changed_since(&self, dataset_id: &DatasetID, generation: u64) -> bool {
+        self.unindexed_dataset_ids
+            .get(dataset_id)
+            .is_some_and(|marked_at| *marked_at > generation)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl SearchIndexEntry {
+    fn matches_filters(&self, filters: &SearchFilters) -> bool {
+        if !filters.kinds.is_empty() && !filters.kinds.contains(&self.document.kind) {
+            return false;
+        }
+
+        if let Some(owner) = &filters.owner
+            && self.handle.alias.account_name.as_ref() != Some(owner)
+        {
+            return false;
+        }
+
+        filters.keywords.iter().all(|keyword| {
+            self.document
+                .keywords
+                .iter()
+                .any(|k| k.eq_ignore_ascii_case(keyword))
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl MessageConsumer for SearchIndexInMemory {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<DatasetLifecycleMessage> for SearchIndexInMemory {
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        name = "SearchIndexInMemory[DatasetLifecycleMessage]"
+    )]
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &DatasetLifecycleMessage,
+    ) -> Result<(), InternalError> {
+        tracing::debug!(received_message = ?message, "Received dataset lifecycle message");
+
+        let mut state = self.state.write().await;
+
+        // Initial scan will pick up the latest state of all datasets
+        if !state.initial_scan_started {
+            return Ok(());
+        }
+
+        match message {
+            DatasetLifecycleMessage::Created(message) => {
+                state.mark_unindexed(&message.dataset_id);
+            }
+
+            // Marking deleted and renamed datasets prevents a concurrent refresh
+            // from storing an entry it has read before the change, the next
+            // refresh drops the entry or finds it up to date
+            DatasetLifecycleMessage::Deleted(message) => {
+                state.entries.remove(&message.dataset_id);
+                state.mark_unindexed(&message.dataset_id);
+            }
+
+            DatasetLifecycleMessage::Renamed(message) => {
+                if let Some(entry) = state.entries.get_mut(&message.dataset_id) {
+                    entry.handle.alias.dataset_name = message.new_dataset_name.clone();
+                }
+                state.mark_unindexed(&message.dataset_id);
+            }
+
+            DatasetLifecycleMessage::DependenciesUpdated(_) => {
+                // No action required: head change is reported separately
+            }
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl MessageConsumerT<DatasetReferenceMessage> for SearchIndexInMemory {
+    #[tracing::instrument(
+        level = "debug",
+        skip_all,
+        name = "SearchIndexInMemory[DatasetReferenceMessage]"
+    )]
+    async fn consume_message(
+        &self,
+        _: &Catalog,
+        message: &DatasetReferenceMessage,
+    ) -> Result<(), InternalError> {
+        tracing::debug!(received_message = ?message, "Received dataset reference message");
+
+        let mut state = self.state.write().await;
+
+        // Initial scan will pick up the latest state of all datasets
+        if !state.initial_scan_started {
+            return Ok(());
+        }
+
+        match message {
+            DatasetReferenceMessage::Updated(message) => {
+                let is_up_to_date = state
+                    .entries
+                    .get(&message.dataset_id)
+                    .is_some_and(|e| e.head == message.new_block_hash);
+
+                if !is_up_to_date {
+                    state.mark_unindexed(&message.dataset_id);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Document
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Searchable representation of the dataset metadata
+#[derive(Debug, Clone)]
+pub struct SearchDocument {
+    pub kind: DatasetKind,
+    pub description: Option<String>,
+    pub keywords: Vec<String>,
+    pub num_blocks: u64,
+    pub num_records: u64,
+    pub estimated_size: u64,
+    terms: Vec<(SearchField, HashSet<String>)>,
+}
+
+impl SearchDocument {
+    /// Extracts searchable metadata from the chain starting at the specified
+    /// head block
+    pub async fn build(dataset: &dyn Dataset, head: &Multihash) -> Result<Self, InternalError> {
+        let chain = dataset.as_metadata_chain();
+
+        let head_block = chain.get_block(head).await.int_err()?;
+
+        let mut set_info_visitor = SearchSetInfoVisitor::new();
+        let mut set_vocab_visitor = SearchSetVocabVisitor::new();
+        let mut set_data_schema_visitor = SearchSetDataSchemaVisitor::new();
+        let mut set_license_visitor = SearchSetLicenseVisitor::new();
+        let mut set_attachments_visitor = SearchSetAttachmentsVisitor::new();
+
+        chain
+            .accept_by_hash(
+                &mut [
+                    &mut set_info_visitor,
+                    &mut set_vocab_visitor,
+                    &mut set_data_schema_visitor,
+                    &mut set_license_visitor,
+                    &mut set_attachments_visitor,
+                ],
+                head,
+            )
+            .await
+            .int_err()?;
+
+        let summary = dataset
+            .get_summary(GetSummaryOpts::default())
+            .await
+            .int_err()?;
+
+        let set_info = set_info_visitor.into_event();
+        let description = set_info.as_ref().and_then(|e| e.description.clone());
+        let keywords = set_info.and_then(|e| e.keywords).unwrap_or_default();
+
+        let mut column_names = Vec::new();
+        if let Some(set_data_schema) = set_data_schema_visitor.into_event() {
+            let schema = set_data_schema.schema_as_arrow().int_err()?;
+            column_names.extend(schema.fields().iter().map(|f| f.name().clone()));
+        }
+        if let Some(set_vocab) = set_vocab_visitor.into_event() {
+            column_names.extend(
+                [
+                    set_vocab.offset_column,
+                    set_vocab.operation_type_column,
+                    set_vocab.system_time_column,
+                    set_vocab.event_time_column,
+                ]
+                .into_iter()
+                .flatten(),
+            );
+        }
+
+        let license = set_license_visitor
+            .into_event()
+            .map(|e| [Some(e.short_name), Some(e.name), e.spdx_id])
+            .into_iter()
+            .flatten()
+            .flatten();
+
+        let attachments = set_attachments_visitor
+            .into_event()
+            .map(|e| {
+                let Attachments::Embedded(embedded) = e.attachments;
+                embedded.items
+            })
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|item| [item.path, item.content]);
+
+        let terms = vec![
+            (SearchField::Keywords, tokenize_all(keywords.iter())),
+            (SearchField::Description, tokenize_all(description.iter())),
+            (SearchField::Columns, tokenize_all(column_names.iter())),
+            (SearchField::License, tokenize_all(license)),
+            (SearchField::Attachments, tokenize_all(attachments)),
+        ];
+
+        Ok(Self {
+            kind: summary.kind,
+            description,
+            keywords,
+            num_blocks: head_block.sequence_number + 1,
+            num_records: summary.num_records,
+            estimated_size: summary.data_size,
+            terms,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Metadata fields that participate in ranking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchField {
+    Name,
+    Keywords,
+    Description,
+    Columns,
+    License,
+    Attachments,
+}
+
+impl SearchField {
+    fn weight(self) -> u32 {
+        match self {
+            SearchField::Name => 16,
+            SearchField::Keywords => 8,
+            SearchField::Description => 4,
+            SearchField::Columns => 4,
+            SearchField::License | SearchField::Attachments => 2,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct SearchQuery {
+    phrase: String,
+    terms: Vec<String>,
+}
+
+impl SearchQuery {
+    fn parse(query: &str) -> Self {
+        Self {
+            phrase: query.trim().to_lowercase(),
+            terms: tokenize(query).collect(),
+        }
+    }
+
+    /// Returns the relevance score of a document or `None` if the document
+    /// does not match the query. Every term of the query has to be found in
+    /// at least one of the fields. Exact token matches weigh twice as much as
+    /// prefix matches.
+    fn score(&self, alias: &DatasetAlias, document: &SearchDocument) -> Option<u32> {
+        if self.phrase.is_empty() {
+            return Some(0);
+        }
+
+        let name = alias.dataset_name.as_str().to_lowercase();
+
+        // Whole query matching the name preserves the behavior of the former
+        // substring search, so such datasets are always included
+        let name_matches_phrase = name.contains(&self.phrase);
+
+        let mut score = if name_matches_phrase {
+            SearchField::Name.weight() * 2
+        } else {
+            0
+        };
+
+        for term in &self.terms {
+            let mut term_score = 0;
+
+            if name.contains(term.as_str()) {
+                term_score += SearchField::Name.weight();
+            }
+
+            for (field, tokens) in &document.terms {
+                if tokens.contains(term) {
+                    term_score += field.weight();
+                } else if tokens.iter().any(|t| t.starts_with(term.as_str())) {
+                    term_score += field.weight() / 2;
+                }
+            }
+
+            if term_score == 0 && !name_matches_phrase {
+                return None;
+            }
+
+            score += term_score;
+        }
+
+        Some(score)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+}
+
+fn tokenize_all<I, S>(texts: I) -> HashSet<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    texts
+        .into_iter()
+        .flat_map(|t| tokenize(t.as_ref()).collect::<Vec<_>>())
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
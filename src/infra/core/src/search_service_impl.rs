@@ -17,16 +17,30 @@ use serde_json::json;
 use url::Url;
 
 use crate::utils::s3_context::S3Context;
+use crate::SearchIndexInMemory;
 
 pub struct SearchServiceImpl {
     remote_repo_reg: Arc<dyn RemoteRepositoryRegistry>,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+    search_index: Arc<SearchIndexInMemory>,
 }
 
 #[component(pub)]
 #[interface(dyn SearchService)]
 impl SearchServiceImpl {
-    pub fn new(remote_repo_reg: Arc<dyn RemoteRepositoryRegistry>) -> Self {
-        Self { remote_repo_reg }
+    pub fn new(
+        remote_repo_reg: Arc<dyn RemoteRepositoryRegistry>,
+        dataset_repo: Arc<dyn DatasetRepository>,
+        dataset_action_authorizer: Arc<dyn auth::DatasetActionAuthorizer>,
+        search_index: Arc<SearchIndexInMemory>,
+    ) -> Self {
+        Self {
+            remote_repo_reg,
+            dataset_repo,
+            dataset_action_authorizer,
+            search_index,
+        }
     }
 
     fn search_in_repo_localfs(
@@ -53,6 +67,7 @@ impl SearchServiceImpl {
                             DatasetName::try_from(file_name).int_err()?,
                         ),
                         kind: None,
+                        description: None,
                         num_blocks: None,
                         num_records: None,
                         estimated_size: None,
@@ -90,6 +105,7 @@ impl SearchServiceImpl {
                     id: None,
                     alias: DatasetAliasRemote::new(repo_name.clone(), None, name),
                     kind: None,
+                    description: None,
                     num_blocks: None,
                     num_records: None,
                     estimated_size: None,
@@ -105,12 +121,15 @@ impl SearchServiceImpl {
         &self,
         url: &Url,
         query: Option<&str>,
+        args: &RemoteSearchArgs<'_>,
         repo_name: &RepoName,
     ) -> Result<Vec<SearchResultDataset>, SearchError> {
+        // Optional arguments and fields are only requested when the caller uses them
+        // as older nodes don't recognize them
         let gql_query = r#"
-            {
+            query Search($query: String!) {
               search {
-                query(query: "{query}", perPage: 100) {
+                query(query: $query{filters}, perPage: 100) {
                   nodes {
                     ... on Dataset {
                       id
@@ -120,6 +139,7 @@ impl SearchServiceImpl {
                       }
                       kind
                       metadata {
+                        {current_info}
                         chain {
                           blocks(page: 0, perPage: 1) {
                             totalCount
@@ -136,7 +156,15 @@ impl SearchServiceImpl {
               }
             }
             "#
-        .replace("{query}", query.unwrap_or_default());
+        .replace("{filters}", &Self::odf_search_filters_args(args.filters))
+        .replace(
+            "{current_info}",
+            if args.include_description {
+                "currentInfo { description }"
+            } else {
+                ""
+            },
+        );
 
         let mut gql_url = Url::parse(url.as_str().strip_prefix("odf+").unwrap()).unwrap();
         gql_url.path_segments_mut().unwrap().push("graphql");
@@ -145,7 +173,12 @@ impl SearchServiceImpl {
         let cl = reqwest::Client::new();
         let response = cl
             .post(gql_url)
-            .json(&json!({"query": gql_query}))
+            .json(&json!({
+                "query": gql_query,
+                "variables": {
+                    "query": query.unwrap_or_default(),
+                },
+            }))
             .send()
             .await
             .int_err()?
@@ -172,6 +205,7 @@ impl SearchServiceImpl {
                 id: Some(ds.id),
                 alias: DatasetAliasRemote::new(repo_name.clone(), ds.owner.account_name, ds.name),
                 kind: Some(ds.kind),
+                description: ds.metadata.current_info.and_then(|i| i.description),
                 num_blocks: Some(ds.metadata.chain.blocks.total_count),
                 num_records: Some(ds.data.num_records_total),
                 estimated_size: Some(ds.data.estimated_size),
@@ -181,18 +215,50 @@ impl SearchServiceImpl {
         Ok(datasets)
     }
 
+    fn odf_search_filters_args(filters: &SearchFilters) -> String {
+        if filters.is_empty() {
+            return String::new();
+        }
+
+        let mut args = Vec::new();
+
+        if !filters.kinds.is_empty() {
+            let kinds: Vec<_> = filters
+                .kinds
+                .iter()
+                .map(|kind| match kind {
+                    DatasetKind::Root => "ROOT",
+                    DatasetKind::Derivative => "DERIVATIVE",
+                })
+                .collect();
+            args.push(format!("byKinds: [{}]", kinds.join(", ")));
+        }
+
+        if let Some(owner) = &filters.owner {
+            args.push(format!("byOwner: {}", json!(owner.as_str())));
+        }
+
+        if !filters.keywords.is_empty() {
+            args.push(format!("byKeywords: {}", json!(filters.keywords)));
+        }
+
+        format!(", filters: {{ {} }}", args.join(", "))
+    }
+
     // TODO: This is crude temporary implementation until ODF specifies registry
     // interface
     async fn search_in_resource(
         &self,
         url: &Url,
         query: Option<&str>,
+        args: &RemoteSearchArgs<'_>,
         repo_name: &RepoName,
     ) -> Result<Vec<SearchResultDataset>, SearchError> {
         match url.scheme() {
+            // Simple repositories don't carry any metadata we could filter by other than names
             "file" => self.search_in_repo_localfs(url, query, repo_name),
             "s3" | "s3+http" | "s3+https" => self.search_in_repo_s3(url, query, repo_name).await,
-            "odf+http" | "odf+https" => self.search_in_repo_odf(url, query, repo_name).await,
+            "odf+http" | "odf+https" => self.search_in_repo_odf(url, query, args, repo_name).await,
             _ => Err(UnsupportedProtocolError {
                 message: None,
                 url: url.clone(),
@@ -204,13 +270,16 @@ impl SearchServiceImpl {
     async fn search_in_repo(
         &self,
         query: Option<&str>,
+        args: &RemoteSearchArgs<'_>,
         repo_name: &RepoName,
     ) -> Result<SearchResult, SearchError> {
         let repo = self.remote_repo_reg.get_repository(repo_name)?;
 
         tracing::info!(repo_id = repo_name.as_str(), repo_url = ?repo.url, query = ?query, "Searching remote repository");
 
-        let datasets = self.search_in_resource(&repo.url, query, repo_name).await?;
+        let datasets = self
+            .search_in_resource(&repo.url, query, args, repo_name)
+            .await?;
 
         Ok(SearchResult { datasets })
     }
@@ -229,14 +298,69 @@ impl SearchService for SearchServiceImpl {
             self.remote_repo_reg.get_all_repositories().collect()
         };
 
+        let args = RemoteSearchArgs {
+            filters: &options.filters,
+            include_description: options.include_description,
+        };
+
         let mut result = SearchResult::default();
         for repo in &repo_names {
-            let mut repo_result = self.search_in_repo(query, repo).await?;
+            let mut repo_result = self.search_in_repo(query, &args, repo).await?;
             result.datasets.append(&mut repo_result.datasets);
         }
 
         Ok(result)
     }
+
+    async fn search_local(
+        &self,
+        query: Option<&str>,
+        options: SearchLocalOptions,
+    ) -> Result<SearchLocalResult, SearchError> {
+        tracing::info!(query = ?query, filters = ?options.filters, "Searching local datasets");
+
+        let hits = self
+            .search_index
+            .query(self.dataset_repo.as_ref(), query, &options.filters)
+            .await?;
+
+        let mut result = SearchLocalResult::default();
+
+        for (handle, document, score) in hits {
+            if !self
+                .dataset_action_authorizer
+                .is_action_allowed(&handle, auth::DatasetAction::Read)
+                .await?
+            {
+                continue;
+            }
+
+            result.total_count += 1;
+            if result.total_count <= options.offset || result.datasets.len() >= options.limit {
+                continue;
+            }
+
+            result.datasets.push(SearchLocalResultDataset {
+                handle,
+                kind: document.kind,
+                description: document.description,
+                keywords: document.keywords,
+                num_blocks: document.num_blocks,
+                num_records: document.num_records,
+                estimated_size: document.estimated_size,
+                score,
+            });
+        }
+
+        Ok(result)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct RemoteSearchArgs<'a> {
+    filters: &'a SearchFilters,
+    include_description: bool,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -264,9 +388,17 @@ struct GqlAccount {
 #[derive(::serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct GqlMetadata {
+    #[serde(default)]
+    current_info: Option<GqlSetInfo>,
     chain: GqlMetadataChain,
 }
 
+#[derive(::serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GqlSetInfo {
+    description: Option<String>,
+}
+
 #[derive(::serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct GqlMetadataChain {
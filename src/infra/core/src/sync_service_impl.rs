@@ -15,12 +15,14 @@ use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
 use kamu_core::services::sync_service::DatasetNotFoundError;
 use kamu_core::utils::metadata_chain_comparator::*;
 use kamu_core::*;
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::*;
 use url::Url;
 
 use super::utils::smart_transfer_protocol::SmartTransferProtocolClient;
 use crate::utils::ipfs_wrapper::*;
 use crate::utils::simple_transfer_protocol::{DatasetFactoryFn, SimpleTransferProtocol};
+use crate::utils::slice_indexes;
 use crate::utils::smart_transfer_protocol::TransferOptions;
 use crate::DatasetRepositoryWriter;
 
@@ -34,6 +36,7 @@ pub struct SyncServiceImpl {
     dataset_factory: Arc<dyn DatasetFactory>,
     smart_transfer_protocol: Arc<dyn SmartTransferProtocolClient>,
     ipfs_client: Arc<IpfsClient>,
+    outbox: Arc<dyn Outbox>,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -49,6 +52,7 @@ impl SyncServiceImpl {
         dataset_factory: Arc<dyn DatasetFactory>,
         smart_transfer_protocol: Arc<dyn SmartTransferProtocolClient>,
         ipfs_client: Arc<IpfsClient>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             remote_repo_reg,
@@ -58,6 +62,7 @@ impl SyncServiceImpl {
             dataset_factory,
             smart_transfer_protocol,
             ipfs_client,
+            outbox,
         }
     }
 
@@ -218,10 +223,10 @@ impl SyncServiceImpl {
 
         tracing::info!("Starting sync using Simple Transfer Protocol");
 
-        SimpleTransferProtocol
+        let result = SimpleTransferProtocol
             .sync(
                 &src_ref.as_any_ref(),
-                src_dataset,
+                src_dataset.clone(),
                 dst_dataset,
                 dst_factory,
                 validation,
@@ -229,7 +234,33 @@ impl SyncServiceImpl {
                 opts.force,
                 listener,
             )
-            .await
+            .await?;
+
+        self.sync_slice_indexes(Some(src_dataset.as_ref()), dst_ref, &result)
+            .await;
+
+        // Smart protocol notifies about the local head changes via the metadata append
+        // use case, while simple protocol writes to the local dataset directly
+        if let SyncResult::Updated {
+            old_head, new_head, ..
+        } = &result
+            && let SyncRef::Local(dst_local_ref) = dst_ref
+        {
+            let dst_handle = self.dataset_repo.resolve_dataset_ref(dst_local_ref).await?;
+
+            self.outbox
+                .post_message(
+                    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                    DatasetReferenceMessage::updated(
+                        dst_handle.id,
+                        old_head.clone(),
+                        new_head.clone(),
+                    ),
+                )
+                .await?;
+        }
+
+        Ok(result)
     }
 
     async fn sync_smart_pull_transfer_protocol(
@@ -247,7 +278,8 @@ impl SyncServiceImpl {
 
         tracing::info!("Starting sync using Smart Transfer Protocol (Pull flow)");
 
-        self.smart_transfer_protocol
+        let result = self
+            .smart_transfer_protocol
             .pull_protocol_client_flow(
                 &http_src_url,
                 dst_dataset,
@@ -258,7 +290,54 @@ impl SyncServiceImpl {
                     ..Default::default()
                 },
             )
-            .await
+            .await?;
+
+        self.sync_slice_indexes(None, dst_ref, &result).await;
+
+        Ok(result)
+    }
+
+    /// Carries the slice indexes over to the destination, as the transfer
+    /// protocols only transfer the metadata chain and the data files. Indexes
+    /// are copied from the source when it is accessible directly, otherwise
+    /// they are rebuilt from the data files of a local destination.
+    async fn sync_slice_indexes(
+        &self,
+        src_dataset: Option<&dyn Dataset>,
+        dst_ref: &SyncRef,
+        result: &SyncResult,
+    ) {
+        let SyncResult::Updated {
+            old_head, new_head, ..
+        } = result
+        else {
+            return;
+        };
+
+        if src_dataset.is_none() && !dst_ref.is_local() {
+            return;
+        }
+
+        let dst_dataset = match self.get_dataset_reader(dst_ref).await {
+            Ok(dst_dataset) => dst_dataset,
+            Err(err) => {
+                tracing::warn!(error = ?err, error_msg = %err, "Failed to sync slice indexes");
+                return;
+            }
+        };
+
+        if let Some(src_dataset) = src_dataset {
+            slice_indexes::copy_slice_indexes(
+                src_dataset,
+                dst_dataset.as_ref(),
+                old_head.as_ref(),
+                new_head,
+            )
+            .await;
+        } else {
+            slice_indexes::build_slice_indexes(dst_dataset.as_ref(), old_head.as_ref(), new_head)
+                .await;
+        }
     }
 
     async fn sync_smart_push_transfer_protocol<'a>(
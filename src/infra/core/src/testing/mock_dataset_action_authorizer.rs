@@ -97,6 +97,36 @@ impl MockDatasetActionAuthorizer {
         )
     }
 
+    pub fn expect_check_delete_dataset(
+        self,
+        dataset_alias: &DatasetAlias,
+        times: usize,
+        success: bool,
+    ) -> Self {
+        let dataset_alias = dataset_alias.clone();
+        self.expect_check_action_allowed_internal(
+            function(move |dh: &DatasetHandle| dh.alias == dataset_alias),
+            DatasetAction::Delete,
+            times,
+            success,
+        )
+    }
+
+    pub fn expect_check_rename_dataset(
+        self,
+        dataset_alias: &DatasetAlias,
+        times: usize,
+        success: bool,
+    ) -> Self {
+        let dataset_alias = dataset_alias.clone();
+        self.expect_check_action_allowed_internal(
+            function(move |dh: &DatasetHandle| dh.alias == dataset_alias),
+            DatasetAction::Rename,
+            times,
+            success,
+        )
+    }
+
     pub fn expect_check_read_a_dataset(self, times: usize, success: bool) -> Self {
         self.expect_check_action_allowed_internal(always(), DatasetAction::Read, times, success)
     }
@@ -18,6 +18,7 @@ use itertools::Itertools;
 use kamu_core::engine::*;
 use kamu_core::*;
 use kamu_ingest_datafusion::DataWriterDataFusion;
+use messaging_outbox::{Outbox, OutboxExt};
 use opendatafabric::*;
 use random_names::get_random_name;
 use time_source::SystemTimeSource;
@@ -28,6 +29,7 @@ pub struct TransformServiceImpl {
     engine_provisioner: Arc<dyn EngineProvisioner>,
     time_source: Arc<dyn SystemTimeSource>,
     compaction_svc: Arc<dyn CompactionService>,
+    outbox: Arc<dyn Outbox>,
 }
 
 #[component(pub)]
@@ -39,6 +41,7 @@ impl TransformServiceImpl {
         engine_provisioner: Arc<dyn EngineProvisioner>,
         time_source: Arc<dyn SystemTimeSource>,
         compaction_svc: Arc<dyn CompactionService>,
+        outbox: Arc<dyn Outbox>,
     ) -> Self {
         Self {
             dataset_repo,
@@ -46,6 +49,7 @@ impl TransformServiceImpl {
             engine_provisioner,
             time_source,
             compaction_svc,
+            outbox,
         }
     }
 
@@ -151,6 +155,32 @@ impl TransformServiceImpl {
             }
         }
 
+        // Index the partitions of the output if dataset has a partitioning spec
+        let ctx = datafusion::prelude::SessionContext::new();
+        let partitions = if let Some(data_file) = &response.new_data {
+            kamu_ingest_datafusion::partitioning::stage_slice_partitions(
+                &ctx,
+                dataset.as_ref(),
+                &request.vocab,
+                data_file.as_path(),
+            )
+            .await?
+        } else {
+            None
+        };
+
+        // Collect column statistics if dataset has the slice statistics enabled
+        let statistics = if let Some(data_file) = &response.new_data {
+            kamu_ingest_datafusion::statistics::stage_slice_statistics(
+                &ctx,
+                dataset.as_ref(),
+                data_file.as_path(),
+            )
+            .await?
+        } else {
+            None
+        };
+
         let params = ExecuteTransformParams {
             query_inputs: request.inputs.iter().map(|i| i.clone().into()).collect(),
             prev_checkpoint: request.prev_checkpoint,
@@ -176,6 +206,37 @@ impl TransformServiceImpl {
         {
             Ok(res) => {
                 new_head = res.new_head;
+
+                if partitions.is_some() || statistics.is_some() {
+                    let new_block = dataset
+                        .as_metadata_chain()
+                        .get_block(&new_head)
+                        .await
+                        .int_err()?
+                        .into_typed::<ExecuteTransform>()
+                        .unwrap();
+
+                    if let Some(new_data) = &new_block.event.new_data {
+                        if let Some(partitions) = partitions {
+                            kamu_ingest_datafusion::partitioning::commit_slice_partitions(
+                                dataset.as_ref(),
+                                &new_data.physical_hash,
+                                partitions,
+                            )
+                            .await;
+                        }
+
+                        if let Some(statistics) = statistics {
+                            kamu_ingest_datafusion::statistics::commit_slice_statistics(
+                                dataset.as_ref(),
+                                &new_data.physical_hash,
+                                statistics,
+                            )
+                            .await;
+                        }
+                    }
+                }
+
                 Ok(())
             }
             Err(CommitError::MetadataAppendError(AppendError::InvalidBlock(
@@ -684,7 +745,7 @@ impl TransformServiceImpl {
         {
             Ok(Some(operation)) => {
                 let dataset_repo = self.dataset_repo.clone();
-                Self::do_transform(
+                let result = Self::do_transform(
                     self.engine_provisioner.clone(),
                     operation,
                     |request, response| async move {
@@ -692,7 +753,22 @@ impl TransformServiceImpl {
                     },
                     listener,
                 )
-                .await
+                .await?;
+
+                if let TransformResult::Updated { old_head, new_head } = &result {
+                    self.outbox
+                        .post_message(
+                            MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                            DatasetReferenceMessage::updated(
+                                dataset_handle.id.clone(),
+                                Some(old_head.clone()),
+                                new_head.clone(),
+                            ),
+                        )
+                        .await?;
+                }
+
+                Ok(result)
             }
             Ok(None) => {
                 listener.begin();
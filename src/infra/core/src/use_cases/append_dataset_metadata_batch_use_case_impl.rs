@@ -19,9 +19,11 @@ use kamu_core::{
     BlockRef,
     Dataset,
     DatasetLifecycleMessage,
+    DatasetReferenceMessage,
     GetSummaryOpts,
     HashedMetadataBlock,
     SetRefOpts,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
 };
 use messaging_outbox::{Outbox, OutboxExt};
@@ -103,12 +105,19 @@ impl AppendDatasetMetadataBatchUseCase for AppendDatasetMetadataBatchUseCaseImpl
             )
             .await?;
 
-        if !new_upstream_ids.is_empty() {
-            let summary = dataset
-                .get_summary(GetSummaryOpts::default())
-                .await
-                .int_err()?;
+        let summary = dataset
+            .get_summary(GetSummaryOpts::default())
+            .await
+            .int_err()?;
 
+        self.outbox
+            .post_message(
+                MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                DatasetReferenceMessage::updated(summary.id.clone(), old_head, new_head),
+            )
+            .await?;
+
+        if !new_upstream_ids.is_empty() {
             self.outbox
                 .post_message(
                     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
@@ -17,7 +17,9 @@ use kamu_core::{
     CommitOpts,
     CommitResult,
     DatasetLifecycleMessage,
+    DatasetReferenceMessage,
     DatasetRepository,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
 };
 use messaging_outbox::{Outbox, OutboxExt};
@@ -63,6 +65,17 @@ impl CommitDatasetEventUseCase for CommitDatasetEventUseCaseImpl {
 
         let commit_result = dataset.commit_event(event, opts).await?;
 
+        self.outbox
+            .post_message(
+                MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
+                DatasetReferenceMessage::updated(
+                    dataset_handle.id.clone(),
+                    commit_result.old_head.clone(),
+                    commit_result.new_head.clone(),
+                ),
+            )
+            .await?;
+
         if !commit_result.new_upstream_ids.is_empty() {
             self.outbox
                 .post_message(
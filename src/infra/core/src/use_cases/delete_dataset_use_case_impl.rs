@@ -11,6 +11,8 @@ use std::sync::Arc;
 
 use dill::{component, interface};
 use internal_error::ResultIntoInternal;
+use kamu_accounts::CurrentAccountSubject;
+use kamu_audit_log::{AuditAction, AuditLogService};
 use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer};
 use kamu_core::{
     DanglingReferenceError,
@@ -37,6 +39,8 @@ pub struct DeleteDatasetUseCaseImpl {
     dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
     dependency_graph_service: Arc<dyn DependencyGraphService>,
     outbox: Arc<dyn Outbox>,
+    audit_log_service: Arc<dyn AuditLogService>,
+    current_account_subject: Arc<CurrentAccountSubject>,
 }
 
 impl DeleteDatasetUseCaseImpl {
@@ -46,6 +50,8 @@ impl DeleteDatasetUseCaseImpl {
         dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
         dependency_graph_service: Arc<dyn DependencyGraphService>,
         outbox: Arc<dyn Outbox>,
+        audit_log_service: Arc<dyn AuditLogService>,
+        current_account_subject: Arc<CurrentAccountSubject>,
     ) -> Self {
         Self {
             dataset_repo,
@@ -53,6 +59,8 @@ impl DeleteDatasetUseCaseImpl {
             dataset_action_authorizer,
             dependency_graph_service,
             outbox,
+            audit_log_service,
+            current_account_subject,
         }
     }
 
@@ -109,9 +117,16 @@ impl DeleteDatasetUseCase for DeleteDatasetUseCaseImpl {
     ) -> Result<(), DeleteDatasetError> {
         // Permission check
         self.dataset_action_authorizer
-            .check_action_allowed(dataset_handle, DatasetAction::Write)
+            .check_action_allowed(dataset_handle, DatasetAction::Delete)
             .await?;
 
+        let owner_account_id = match self.current_account_subject.as_ref() {
+            CurrentAccountSubject::Anonymous(_) => {
+                panic!("Anonymous account cannot delete dataset");
+            }
+            CurrentAccountSubject::Logged(l) => l.account_id.clone(),
+        };
+
         // Validate against dangling ref
         self.ensure_no_dangling_references(dataset_handle).await?;
 
@@ -124,7 +139,16 @@ impl DeleteDatasetUseCase for DeleteDatasetUseCaseImpl {
         self.outbox
             .post_message(
                 MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
-                DatasetLifecycleMessage::deleted(dataset_handle.id.clone()),
+                DatasetLifecycleMessage::deleted(dataset_handle.id.clone(), owner_account_id),
+            )
+            .await?;
+
+        self.audit_log_service
+            .record(
+                self.current_account_subject.maybe_account_id(),
+                AuditAction::DatasetDeleted,
+                Some(&dataset_handle.id),
+                Some(format!("Deleted dataset '{}'", dataset_handle.alias)),
             )
             .await?;
 
@@ -11,6 +11,7 @@ use std::sync::Arc;
 
 use dill::{component, interface};
 use kamu_accounts::CurrentAccountSubject;
+use kamu_audit_log::{AuditAction, AuditLogService};
 use kamu_core::auth::{DatasetAction, DatasetActionAuthorizer};
 use kamu_core::{
     DatasetLifecycleMessage,
@@ -32,6 +33,7 @@ pub struct RenameDatasetUseCaseImpl {
     dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
     dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
     outbox: Arc<dyn Outbox>,
+    audit_log_service: Arc<dyn AuditLogService>,
     current_account_subject: Arc<CurrentAccountSubject>,
 }
 
@@ -43,6 +45,7 @@ impl RenameDatasetUseCaseImpl {
         dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
         dataset_action_authorizer: Arc<dyn DatasetActionAuthorizer>,
         outbox: Arc<dyn Outbox>,
+        audit_log_service: Arc<dyn AuditLogService>,
         current_account_subject: Arc<CurrentAccountSubject>,
     ) -> Self {
         Self {
@@ -50,6 +53,7 @@ impl RenameDatasetUseCaseImpl {
             dataset_repo_writer,
             dataset_action_authorizer,
             outbox,
+            audit_log_service,
             current_account_subject,
         }
     }
@@ -75,7 +79,7 @@ impl RenameDatasetUseCase for RenameDatasetUseCaseImpl {
         }?;
 
         self.dataset_action_authorizer
-            .check_action_allowed(&dataset_handle, DatasetAction::Write)
+            .check_action_allowed(&dataset_handle, DatasetAction::Rename)
             .await?;
 
         let old_name = dataset_handle.alias.dataset_name.clone();
@@ -89,13 +93,22 @@ impl RenameDatasetUseCase for RenameDatasetUseCaseImpl {
                 MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
                 DatasetLifecycleMessage::renamed(
                     dataset_handle.id.clone(),
-                    owner_account_id,
-                    old_name,
+                    owner_account_id.clone(),
+                    old_name.clone(),
                     new_name.clone(),
                 ),
             )
             .await?;
 
+        self.audit_log_service
+            .record(
+                Some(&owner_account_id),
+                AuditAction::DatasetRenamed,
+                Some(&dataset_handle.id),
+                Some(format!("Renamed dataset '{old_name}' to '{new_name}'")),
+            )
+            .await?;
+
         Ok(())
     }
 }
@@ -0,0 +1,177 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{BTreeSet, HashMap};
+
+use datafusion::common::{Column, DFSchema};
+use datafusion::logical_expr::{Expr, LogicalPlan};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Column of one of the registered input relations
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ColumnOrigin {
+    pub relation: String,
+    pub column: String,
+}
+
+type Lineage = Vec<BTreeSet<ColumnOrigin>>;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Traces output columns of a logical plan back to the columns of the input
+/// relations they are derived from.
+///
+/// Analysis is static and conservative: a column is considered derived from all
+/// columns referenced by its expression, while columns that are only used in
+/// filters, join conditions, or sorting are not treated as sources.
+#[derive(Debug, Default)]
+pub struct ColumnLineageAnalyzer {
+    relations: HashMap<String, HashMap<String, BTreeSet<ColumnOrigin>>>,
+}
+
+impl ColumnLineageAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an input relation whose columns are origins of themselves
+    pub fn add_input<'a>(&mut self, name: &str, columns: impl IntoIterator<Item = &'a str>) {
+        let columns = columns
+            .into_iter()
+            .map(|c| {
+                (
+                    c.to_string(),
+                    BTreeSet::from([ColumnOrigin {
+                        relation: name.to_string(),
+                        column: c.to_string(),
+                    }]),
+                )
+            })
+            .collect();
+
+        self.relations.insert(name.to_string(), columns);
+    }
+
+    /// Registers an intermediate relation (e.g. a named query step) using
+    /// lineage previously returned by [`Self::analyze`]
+    pub fn add_view(&mut self, name: &str, lineage: Vec<(String, BTreeSet<ColumnOrigin>)>) {
+        self.relations
+            .insert(name.to_string(), lineage.into_iter().collect());
+    }
+
+    /// Returns origins of every column in the output schema of the plan
+    pub fn analyze(&self, plan: &LogicalPlan) -> Vec<(String, BTreeSet<ColumnOrigin>)> {
+        let lineage = self.plan_lineage(plan);
+
+        plan.schema()
+            .fields()
+            .iter()
+            .zip(lineage)
+            .map(|(f, origins)| (f.name().clone(), origins))
+            .collect()
+    }
+
+    fn plan_lineage(&self, plan: &LogicalPlan) -> Lineage {
+        let lineage = match plan {
+            LogicalPlan::TableScan(scan) => {
+                let relation = self.relations.get(scan.table_name.table());
+                scan.projected_schema
+                    .fields()
+                    .iter()
+                    .map(|f| {
+                        relation
+                            .and_then(|r| r.get(f.name()))
+                            .cloned()
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            }
+            LogicalPlan::Projection(proj) => {
+                let input = self.plan_lineage(&proj.input);
+                proj.expr
+                    .iter()
+                    .map(|e| Self::expr_lineage(e, proj.input.schema(), &input))
+                    .collect()
+            }
+            LogicalPlan::Aggregate(agg) => {
+                let input = self.plan_lineage(&agg.input);
+                agg.group_expr
+                    .iter()
+                    .chain(agg.aggr_expr.iter())
+                    .map(|e| Self::expr_lineage(e, agg.input.schema(), &input))
+                    .collect()
+            }
+            LogicalPlan::Window(window) => {
+                let input = self.plan_lineage(&window.input);
+                let window_lineage: Lineage = window
+                    .window_expr
+                    .iter()
+                    .map(|e| Self::expr_lineage(e, window.input.schema(), &input))
+                    .collect();
+                input.into_iter().chain(window_lineage).collect()
+            }
+            LogicalPlan::SubqueryAlias(alias) => self.plan_lineage(&alias.input),
+            LogicalPlan::Union(union) => {
+                let mut lineage: Lineage = vec![BTreeSet::new(); plan.schema().fields().len()];
+                for input in &union.inputs {
+                    for (acc, origins) in lineage.iter_mut().zip(self.plan_lineage(input)) {
+                        acc.extend(origins);
+                    }
+                }
+                lineage
+            }
+            _ => {
+                // Nodes like filters, joins, sorts, and limits pass the columns of their
+                // inputs through - match them by qualified name
+                let inputs: Vec<_> = plan
+                    .inputs()
+                    .into_iter()
+                    .map(|i| (i.schema(), self.plan_lineage(i)))
+                    .collect();
+
+                plan.schema()
+                    .iter()
+                    .map(|(qualifier, field)| {
+                        let column = Column::new(qualifier.cloned(), field.name());
+                        inputs
+                            .iter()
+                            .find_map(|(schema, lineage)| {
+                                schema
+                                    .maybe_index_of_column(&column)
+                                    .map(|i| lineage[i].clone())
+                            })
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            }
+        };
+
+        Self::fit(lineage, plan.schema().fields().len())
+    }
+
+    fn expr_lineage(
+        expr: &Expr,
+        input_schema: &DFSchema,
+        input: &Lineage,
+    ) -> BTreeSet<ColumnOrigin> {
+        expr.column_refs()
+            .into_iter()
+            .filter_map(|c| input_schema.maybe_index_of_column(c))
+            .flat_map(|i| input[i].iter().cloned())
+            .collect()
+    }
+
+    // Guards against the plan nodes that add implicit columns (e.g. grouping
+    // sets) so that lineage always aligns with the schema
+    fn fit(mut lineage: Lineage, len: usize) -> Lineage {
+        lineage.resize(len, BTreeSet::new());
+        lineage
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use internal_error::{ErrorIntoInternal, InternalError, ResultIntoInternal};
+use kamu_core::*;
+use opendatafabric::serde::yaml::Manifest;
+
+use crate::utils::slice_indexes::delete_slice_indexes;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Name of the object in the dataset info repository that holds the soft
+/// compaction index
+pub const COMPACTED_SLICES_KEY: &str = "compacted-slices";
+
+const COMPACTED_SLICES_KIND: &str = "CompactedSlices";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn read_compacted_slices(
+    info_repo: &dyn NamedObjectRepository,
+) -> Result<Option<CompactedSlices>, InternalError> {
+    let data = match info_repo.get(COMPACTED_SLICES_KEY).await {
+        Ok(data) => data,
+        Err(GetNamedError::NotFound(_)) => return Ok(None),
+        Err(e) => return Err(e.int_err()),
+    };
+
+    let manifest: Manifest<CompactedSlices> = serde_yaml::from_slice(&data[..]).int_err()?;
+
+    if manifest.kind != COMPACTED_SLICES_KIND {
+        return Err(InvalidObjectKind {
+            expected: COMPACTED_SLICES_KIND.to_owned(),
+            actual: manifest.kind,
+        }
+        .int_err());
+    }
+
+    Ok(Some(manifest.content))
+}
+
+pub async fn write_compacted_slices(
+    info_repo: &dyn NamedObjectRepository,
+    compacted_slices: &CompactedSlices,
+) -> Result<(), InternalError> {
+    let manifest = Manifest {
+        kind: COMPACTED_SLICES_KIND.to_owned(),
+        version: 1,
+        content: compacted_slices.clone(),
+    };
+
+    let data = serde_yaml::to_string(&manifest).int_err()?.into_bytes();
+
+    info_repo.set(COMPACTED_SLICES_KEY, &data).await.int_err()
+}
+
+/// Deletes the soft compaction index along with the merged files and their
+/// index entries, e.g. after the chain was rewritten by a reset, a hard
+/// compaction or a retention
+#[tracing::instrument(level = "debug", skip_all)]
+pub async fn delete_compacted_slices(dataset: &dyn Dataset) {
+    let res: Result<(), InternalError> = async {
+        let Some(compacted_slices) = read_compacted_slices(dataset.as_info_repo()).await? else {
+            return Ok(());
+        };
+
+        // The index goes first, so that queries never refer to deleted files
+        dataset
+            .as_info_repo()
+            .delete(COMPACTED_SLICES_KEY)
+            .await
+            .int_err()?;
+
+        tracing::debug!(
+            num_slices = compacted_slices.slices.len(),
+            "Deleting compacted slices"
+        );
+
+        for slice in compacted_slices.slices {
+            dataset
+                .as_data_repo()
+                .delete(&slice.physical_hash)
+                .await
+                .int_err()?;
+
+            delete_slice_indexes(dataset, &[slice.physical_hash]).await?;
+        }
+
+        Ok(())
+    }
+    .await;
+
+    if let Err(err) = res {
+        tracing::warn!(error = ?err, error_msg = %err, "Failed to delete compacted slices");
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
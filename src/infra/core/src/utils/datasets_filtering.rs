@@ -123,6 +123,7 @@ fn get_remote_datasets_stream(
 
             let search_options = SearchOptions {
                 repository_names: vec![repo_name],
+                ..Default::default()
             };
 
             let remote_datasets: Vec<_> = match search_svc.search(None, search_options).await {
@@ -20,6 +20,9 @@ pub const HTTPD: &str = "docker.io/httpd:2.4";
 pub const MINIO: &str = "docker.io/minio/minio:RELEASE.2021-08-31T05-46-54Z";
 pub const BUSYBOX: &str = "docker.io/busybox:latest";
 
+#[cfg(feature = "ingest-kafka")]
+pub const REDPANDA: &str = "docker.redpanda.com/redpandadata/redpanda:v24.2.7";
+
 #[cfg(feature = "ingest-mqtt")]
 pub const RUMQTTD: &str = "docker.io/bytebeamio/rumqttd:0.19.0";
 
@@ -8,9 +8,13 @@
 // by the Apache License, Version 2.0.
 
 pub mod cached_object;
+pub mod column_lineage;
+pub mod compacted_slices;
 pub mod datasets_filtering;
 pub mod docker_images;
 pub mod ipfs_wrapper;
 pub mod s3_context;
 pub mod simple_transfer_protocol;
+pub mod size_bounded_lru;
+pub mod slice_indexes;
 pub mod smart_transfer_protocol;
@@ -0,0 +1,109 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Least-recently-used index that evicts entries once their total size
+/// exceeds the limit
+pub struct SizeBoundedLru<K, V> {
+    max_total_size: usize,
+    total_size: usize,
+    clock: u64,
+    entries: HashMap<K, LruEntry<V>>,
+    recency: BTreeMap<u64, K>,
+}
+
+struct LruEntry<V> {
+    value: V,
+    size: usize,
+    last_used: u64,
+}
+
+impl<K, V> SizeBoundedLru<K, V>
+where
+    K: Clone + Eq + Hash,
+{
+    pub fn new(max_total_size: usize) -> Self {
+        Self {
+            max_total_size,
+            total_size: 0,
+            clock: 0,
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn total_size(&self) -> usize {
+        self.total_size
+    }
+
+    /// Returns the value and marks it as most recently used
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let entry = self.entries.get_mut(key)?;
+
+        self.clock += 1;
+        self.recency.remove(&entry.last_used);
+        self.recency.insert(self.clock, key.clone());
+        entry.last_used = self.clock;
+
+        Some(&entry.value)
+    }
+
+    /// Inserts or replaces the value, returning the entries that had to be
+    /// evicted to stay within the size limit (possibly including the inserted
+    /// one if it alone exceeds the limit)
+    pub fn insert(&mut self, key: K, value: V, size: usize) -> Vec<(K, V)> {
+        let mut evicted = Vec::new();
+
+        self.remove(&key);
+
+        self.clock += 1;
+        self.total_size += size;
+        self.recency.insert(self.clock, key.clone());
+        self.entries.insert(
+            key,
+            LruEntry {
+                value,
+                size,
+                last_used: self.clock,
+            },
+        );
+
+        while self.total_size > self.max_total_size {
+            let Some((_, oldest)) = self.recency.pop_first() else {
+                break;
+            };
+            let entry = self.entries.remove(&oldest).unwrap();
+            self.total_size -= entry.size;
+            evicted.push((oldest, entry.value));
+        }
+
+        evicted
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.entries.remove(key)?;
+        self.recency.remove(&entry.last_used);
+        self.total_size -= entry.size;
+        Some(entry.value)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
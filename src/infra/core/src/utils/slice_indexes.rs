@@ -0,0 +1,305 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use datafusion::prelude::SessionContext;
+use futures::TryStreamExt;
+use internal_error::{InternalError, ResultIntoInternal};
+use kamu_core::utils::partitioned_slices::*;
+use kamu_core::utils::slice_statistics::*;
+use kamu_core::*;
+use opendatafabric::*;
+
+use crate::utils::compacted_slices::read_compacted_slices;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Side indexes of the data files (partitions and column statistics) are stored
+// in the info repository of the dataset next to the metadata chain. They only
+// speed up the queries, so their maintenance after the chain changes is
+// best-effort: failures are logged and the files that lost their index entries
+// are simply read in full.
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Deletes the index entries of the data slices that are referenced by the
+/// chain at `old_head`, but not at `new_head`, e.g. after a reset or a hard
+/// compaction
+#[tracing::instrument(level = "debug", skip_all, fields(%old_head, %new_head))]
+pub async fn delete_orphaned_slice_indexes(
+    dataset: &dyn Dataset,
+    old_head: &Multihash,
+    new_head: &Multihash,
+) {
+    let res: Result<(), InternalError> = async {
+        let retained: HashSet<_> = collect_slice_hashes(dataset, new_head, None)
+            .await?
+            .into_iter()
+            .collect();
+
+        let orphaned: Vec<_> = collect_slice_hashes(dataset, old_head, None)
+            .await?
+            .into_iter()
+            .filter(|h| !retained.contains(h))
+            .collect();
+
+        tracing::debug!(
+            num_slices = orphaned.len(),
+            "Deleting orphaned slice indexes"
+        );
+
+        delete_slice_indexes(dataset, &orphaned).await
+    }
+    .await;
+
+    if let Err(err) = res {
+        tracing::warn!(error = ?err, error_msg = %err, "Failed to delete orphaned slice indexes");
+    }
+}
+
+/// Deletes the partitioning spec along with the partitions of all data files
+/// of the dataset
+pub async fn delete_partitioning(dataset: &dyn Dataset) -> Result<(), InternalError> {
+    delete_partitioning_spec(dataset.as_info_repo()).await?;
+
+    for physical_hash in collect_data_files(dataset).await? {
+        delete_slice_partitions(dataset.as_info_repo(), &physical_hash).await?;
+    }
+
+    Ok(())
+}
+
+/// Deletes the statistics configuration along with the statistics of all data
+/// files of the dataset
+pub async fn delete_statistics(dataset: &dyn Dataset) -> Result<(), InternalError> {
+    delete_slice_statistics_config(dataset.as_info_repo()).await?;
+
+    for physical_hash in collect_data_files(dataset).await? {
+        delete_slice_statistics(dataset.as_info_repo(), &physical_hash).await?;
+    }
+
+    Ok(())
+}
+
+/// Computes the statistics of the data files of the dataset that have none,
+/// returning the number of files processed
+pub async fn build_statistics(dataset: &dyn Dataset) -> Result<usize, InternalError> {
+    use kamu_ingest_datafusion::statistics::compute_slice_statistics;
+
+    let Some(config) = read_slice_statistics_config(dataset.as_info_repo()).await? else {
+        return Ok(0);
+    };
+
+    let ctx = SessionContext::new();
+    let mut num_files = 0;
+
+    for physical_hash in collect_data_files(dataset).await? {
+        if read_slice_statistics(dataset.as_info_repo(), &physical_hash)
+            .await?
+            .is_some()
+        {
+            continue;
+        }
+
+        let data_path = get_data_file_path(dataset, &physical_hash).await?;
+        let statistics = compute_slice_statistics(&ctx, &config, &data_path).await?;
+        write_slice_statistics(dataset.as_info_repo(), &physical_hash, &statistics).await?;
+
+        num_files += 1;
+    }
+
+    Ok(num_files)
+}
+
+/// Copies the index entries of the data slices added between `old_head` and
+/// `new_head` from the source dataset, adopting the partitioning spec and the
+/// statistics configuration of the source when the destination has none
+#[tracing::instrument(level = "debug", skip_all, fields(?old_head, %new_head))]
+pub async fn copy_slice_indexes(
+    src: &dyn Dataset,
+    dst: &dyn Dataset,
+    old_head: Option<&Multihash>,
+    new_head: &Multihash,
+) {
+    let res: Result<(), InternalError> = async {
+        let (src_info, dst_info) = (src.as_info_repo(), dst.as_info_repo());
+
+        let spec = match read_partitioning_spec(dst_info).await? {
+            Some(spec) => Some(spec),
+            None => {
+                let spec = read_partitioning_spec(src_info).await?;
+                if let Some(spec) = &spec {
+                    write_partitioning_spec(dst_info, spec).await?;
+                }
+                spec
+            }
+        };
+
+        let statistics_config = match read_slice_statistics_config(dst_info).await? {
+            Some(config) => Some(config),
+            None => {
+                let config = read_slice_statistics_config(src_info).await?;
+                if let Some(config) = &config {
+                    write_slice_statistics_config(dst_info, config).await?;
+                }
+                config
+            }
+        };
+
+        if spec.is_none() && statistics_config.is_none() {
+            return Ok(());
+        }
+
+        for physical_hash in collect_slice_hashes(dst, new_head, old_head).await? {
+            if let Some(spec) = &spec
+                && let Some(partitions) = read_slice_partitions(src_info, &physical_hash).await?
+                && partitions.spec == *spec
+            {
+                write_slice_partitions(dst_info, &physical_hash, &partitions).await?;
+            }
+
+            if statistics_config.is_some()
+                && let Some(statistics) = read_slice_statistics(src_info, &physical_hash).await?
+            {
+                write_slice_statistics(dst_info, &physical_hash, &statistics).await?;
+            }
+        }
+
+        Ok(())
+    }
+    .await;
+
+    if let Err(err) = res {
+        tracing::warn!(error = ?err, error_msg = %err, "Failed to copy slice indexes");
+    }
+}
+
+/// Computes the index entries of the data slices added between `old_head` and
+/// `new_head` from the local data files, e.g. after a pull via a protocol that
+/// does not transfer the indexes
+#[tracing::instrument(level = "debug", skip_all, fields(?old_head, %new_head))]
+pub async fn build_slice_indexes(
+    dataset: &dyn Dataset,
+    old_head: Option<&Multihash>,
+    new_head: &Multihash,
+) {
+    use kamu_ingest_datafusion::partitioning::compute_slice_partitions;
+    use kamu_ingest_datafusion::statistics::compute_slice_statistics;
+
+    let res: Result<(), InternalError> = async {
+        let info_repo = dataset.as_info_repo();
+
+        let spec = read_partitioning_spec(info_repo).await?;
+        let statistics_config = read_slice_statistics_config(info_repo).await?;
+
+        if spec.is_none() && statistics_config.is_none() {
+            return Ok(());
+        }
+
+        let vocab: DatasetVocabulary = dataset
+            .as_metadata_chain()
+            .accept_one(SearchSetVocabVisitor::new())
+            .await
+            .int_err()?
+            .into_event()
+            .unwrap_or_default()
+            .into();
+
+        let ctx = SessionContext::new();
+
+        for physical_hash in collect_slice_hashes(dataset, new_head, old_head).await? {
+            let data_path = get_data_file_path(dataset, &physical_hash).await?;
+
+            if let Some(spec) = &spec
+                && let Some(partitions) =
+                    compute_slice_partitions(&ctx, spec, &vocab.offset_column, &data_path).await?
+            {
+                write_slice_partitions(info_repo, &physical_hash, &partitions).await?;
+            }
+
+            if let Some(config) = &statistics_config {
+                let statistics = compute_slice_statistics(&ctx, config, &data_path).await?;
+                write_slice_statistics(info_repo, &physical_hash, &statistics).await?;
+            }
+        }
+
+        Ok(())
+    }
+    .await;
+
+    if let Err(err) = res {
+        tracing::warn!(error = ?err, error_msg = %err, "Failed to build slice indexes");
+    }
+}
+
+/// Deletes the index entries of the specified data files
+pub async fn delete_slice_indexes(
+    dataset: &dyn Dataset,
+    physical_hashes: &[Multihash],
+) -> Result<(), InternalError> {
+    for physical_hash in physical_hashes {
+        delete_slice_partitions(dataset.as_info_repo(), physical_hash).await?;
+        delete_slice_statistics(dataset.as_info_repo(), physical_hash).await?;
+    }
+    Ok(())
+}
+
+/// Collects the physical hashes of all data files of the dataset: the slices
+/// referenced by the chain and the files produced by the soft compaction
+async fn collect_data_files(dataset: &dyn Dataset) -> Result<Vec<Multihash>, InternalError> {
+    let mut files = match dataset
+        .as_metadata_chain()
+        .try_get_ref(&BlockRef::Head)
+        .await
+        .int_err()?
+    {
+        Some(head) => collect_slice_hashes(dataset, &head, None).await?,
+        None => Vec::new(),
+    };
+
+    if let Some(compacted_slices) = read_compacted_slices(dataset.as_info_repo()).await? {
+        files.extend(compacted_slices.slices.into_iter().map(|s| s.physical_hash));
+    }
+
+    Ok(files)
+}
+
+/// Collects the physical hashes of the data slices added by the blocks in the
+/// `(tail, head]` interval
+pub async fn collect_slice_hashes(
+    dataset: &dyn Dataset,
+    head: &Multihash,
+    tail: Option<&Multihash>,
+) -> Result<Vec<Multihash>, InternalError> {
+    dataset
+        .as_metadata_chain()
+        .iter_blocks_interval(head, tail, false)
+        .try_filter_map(|(_, block)| async move {
+            Ok(match block.event {
+                MetadataEvent::AddData(e) => e.new_data.map(|s| s.physical_hash),
+                MetadataEvent::ExecuteTransform(e) => e.new_data.map(|s| s.physical_hash),
+                _ => None,
+            })
+        })
+        .try_collect()
+        .await
+        .int_err()
+}
+
+async fn get_data_file_path(
+    dataset: &dyn Dataset,
+    physical_hash: &Multihash,
+) -> Result<PathBuf, InternalError> {
+    let url = dataset.as_data_repo().get_internal_url(physical_hash).await;
+    kamu_data_utils::data::local_url::into_local_path(url).int_err()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
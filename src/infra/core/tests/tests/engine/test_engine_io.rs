@@ -19,6 +19,7 @@ use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
 use kamu_datasets_services::DatasetKeyValueServiceSysEnv;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::SystemTimeSourceDefault;
 
@@ -45,6 +46,7 @@ async fn test_engine_io_common<
     let object_store_registry = Arc::new(ObjectStoreRegistryImpl::new(object_stores));
     let time_source = Arc::new(SystemTimeSourceDefault);
     let dataset_env_var_sys_env = Arc::new(DatasetKeyValueServiceSysEnv::new());
+    let outbox = Arc::new(DummyOutboxImpl {});
 
     let ingest_svc = PollingIngestServiceImpl::new(
         dataset_repo.clone(),
@@ -55,6 +57,7 @@ async fn test_engine_io_common<
             None,
             None,
             None,
+            None,
             dataset_env_var_sys_env,
             run_info_dir.clone(),
         )),
@@ -64,6 +67,7 @@ async fn test_engine_io_common<
         run_info_dir.clone(),
         cache_dir,
         time_source.clone(),
+        outbox.clone(),
     );
 
     let transform_svc = TransformServiceImpl::new(
@@ -77,7 +81,9 @@ async fn test_engine_io_common<
             object_store_registry.clone(),
             time_source.clone(),
             run_info_dir.clone(),
+            outbox.clone(),
         )),
+        outbox,
     );
 
     ///////////////////////////////////////////////////////////////////////////
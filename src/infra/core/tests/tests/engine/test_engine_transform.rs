@@ -23,6 +23,7 @@ use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
 use kamu_datasets_services::DatasetKeyValueServiceSysEnv;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::{SystemTimeSource, SystemTimeSourceStub};
 
@@ -257,6 +258,7 @@ impl TestHarness {
             .add::<PushIngestServiceImpl>()
             .add::<TransformServiceImpl>()
             .add::<CompactionServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .add::<DatasetKeyValueServiceSysEnv>()
             .add_value(SystemTimeSourceStub::new_set(
                 Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap(),
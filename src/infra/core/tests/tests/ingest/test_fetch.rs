@@ -772,6 +772,864 @@ async fn test_fetch_mqtt_one_record() {
     assert_eq!(std::fs::read(target_path).unwrap(), data);
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Kafka
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-kafka")]
+async fn kafka_create_topic_with_records(
+    broker: &crate::KafkaBroker,
+    topic: &str,
+    values: &[&str],
+) {
+    use rskafka::client::partition::{Compression, UnknownTopicHandling};
+    use rskafka::record::Record;
+
+    let client = rskafka::client::ClientBuilder::new(vec![broker.broker_addr()])
+        .build()
+        .await
+        .unwrap();
+
+    client
+        .controller_client()
+        .unwrap()
+        .create_topic(topic, 1, 1, 5_000)
+        .await
+        .unwrap();
+
+    if values.is_empty() {
+        return;
+    }
+
+    let partition_client = client
+        .partition_client(topic.to_string(), 0, UnknownTopicHandling::Retry)
+        .await
+        .unwrap();
+
+    let records = values
+        .iter()
+        .map(|v| Record {
+            key: None,
+            value: Some(v.as_bytes().to_vec()),
+            headers: std::collections::BTreeMap::new(),
+            timestamp: Utc::now(),
+        })
+        .collect();
+
+    partition_client
+        .produce(records, Compression::NoCompression)
+        .await
+        .unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-kafka")]
+#[test_group::group(containerized)]
+#[test_log::test(tokio::test)]
+async fn test_fetch_kafka_empty() {
+    let harness = FetchTestHarness::new();
+
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let broker = crate::KafkaBroker::new().await;
+    let topic = "test-topic";
+    kafka_create_topic_with_records(&broker, topic, &[]).await;
+
+    let fetch_step = FetchStep::Kafka(FetchStepKafka {
+        brokers: vec![broker.broker_addr()],
+        topic: topic.to_string(),
+        partitions: None,
+    });
+
+    let listener = Arc::new(TestListener::new());
+
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            Some(listener.clone()),
+        )
+        .await
+        .unwrap();
+
+    assert_matches!(res, FetchResult::UpToDate);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-kafka")]
+#[test_group::group(containerized)]
+#[test_log::test(tokio::test)]
+async fn test_fetch_kafka_resumes_from_offsets() {
+    let harness = FetchTestHarness::new();
+
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let broker = crate::KafkaBroker::new().await;
+    let topic = "test-topic";
+    kafka_create_topic_with_records(&broker, topic, &[r#"{"data": 1}"#, r#"{"data": 2}"#]).await;
+
+    let fetch_step = FetchStep::Kafka(FetchStepKafka {
+        brokers: vec![broker.broker_addr()],
+        topic: topic.to_string(),
+        partitions: Some(vec![0]),
+    });
+
+    let listener = Arc::new(TestListener::new());
+
+    // Consume existing records
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            Some(listener.clone()),
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag("0:2".to_string()))
+    );
+    assert_eq!(update.source_event_time, None);
+    assert!(!update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        "{\"data\": 1}\n{\"data\": 2}"
+    );
+
+    // No new records
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            Some(listener.clone()),
+        )
+        .await
+        .unwrap();
+
+    assert_matches!(res, FetchResult::UpToDate);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-kafka")]
+#[test_group::group(containerized)]
+#[test_log::test(tokio::test)]
+async fn test_fetch_kafka_resets_out_of_range_offsets() {
+    let harness = FetchTestHarness::new();
+
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let broker = crate::KafkaBroker::new().await;
+    let topic = "test-topic";
+    kafka_create_topic_with_records(&broker, topic, &[r#"{"data": 1}"#, r#"{"data": 2}"#]).await;
+
+    let fetch_step = FetchStep::Kafka(FetchStepKafka {
+        brokers: vec![broker.broker_addr()],
+        topic: topic.to_string(),
+        partitions: Some(vec![0]),
+    });
+
+    // Stored offset is not available in the partition, e.g. after the topic was
+    // re-created
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            Some(&PollingSourceState::ETag("0:100".to_string())),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag("0:2".to_string()))
+    );
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        "{\"data\": 1}\n{\"data\": 2}"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-kafka")]
+#[test_group::group(containerized)]
+#[test_log::test(tokio::test)]
+async fn test_fetch_kafka_has_more() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let fetch_svc = FetchService::new(
+        Arc::new(ContainerRuntime::default()),
+        Some(Arc::new(SourceConfig {
+            target_records_per_slice: 2,
+        })),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(DatasetKeyValueServiceSysEnv::new()),
+        Arc::new(RunInfoDir::new(temp_dir.path().join("run"))),
+    );
+
+    let target_path = temp_dir.path().join("fetched.bin");
+
+    let broker = crate::KafkaBroker::new().await;
+    let topic = "test-topic";
+    kafka_create_topic_with_records(
+        &broker,
+        topic,
+        &[r#"{"data": 1}"#, r#"{"data": 2}"#, r#"{"data": 3}"#],
+    )
+    .await;
+
+    let fetch_step = FetchStep::Kafka(FetchStepKafka {
+        brokers: vec![broker.broker_addr()],
+        topic: topic.to_string(),
+        partitions: None,
+    });
+
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag("0:2".to_string()))
+    );
+    assert!(update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        "{\"data\": 1}\n{\"data\": 2}"
+    );
+
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag("0:3".to_string()))
+    );
+    assert!(!update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        r#"{"data": 3}"#
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-kafka")]
+#[test_log::test(tokio::test)]
+async fn test_fetch_kafka_malformed_source_state() {
+    let harness = FetchTestHarness::new();
+
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    // State is decoded before connecting, so the broker does not need to exist
+    let fetch_step = FetchStep::Kafka(FetchStepKafka {
+        brokers: vec!["localhost:1".to_string()],
+        topic: "test-topic".to_string(),
+        partitions: Some(vec![0]),
+    });
+
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            Some(&PollingSourceState::ETag("0:abc".to_string())),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await;
+
+    assert_matches!(res, Err(PollingIngestError::InvalidSourceState(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SQL
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-sql")]
+async fn sqlite_create_table_with_rows(db_path: &std::path::Path, rows: &[(i64, &str)]) {
+    use sqlx::Connection as _;
+
+    let mut connection = sqlx::SqliteConnection::connect_with(
+        &sqlx::sqlite::SqliteConnectOptions::new()
+            .filename(db_path)
+            .create_if_missing(true),
+    )
+    .await
+    .unwrap();
+
+    sqlx::query("CREATE TABLE IF NOT EXISTS cities (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .execute(&mut connection)
+        .await
+        .unwrap();
+
+    for (id, name) in rows {
+        sqlx::query("INSERT INTO cities (id, name) VALUES (?, ?)")
+            .bind(id)
+            .bind(name)
+            .execute(&mut connection)
+            .await
+            .unwrap();
+    }
+
+    connection.close().await.unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-sql")]
+#[test_log::test(tokio::test)]
+async fn test_fetch_sql_sqlite_without_cursor() {
+    let harness = FetchTestHarness::new();
+
+    let db_path = harness.temp_dir.path().join("db.sqlite");
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    sqlite_create_table_with_rows(&db_path, &[(1, "A"), (2, "B")]).await;
+
+    let fetch_step = FetchStep::Sql(FetchStepSql {
+        connection_url: format!("sqlite://{}", db_path.display()),
+        query: "SELECT id, name FROM cities;".to_string(),
+        cursor_column: None,
+    });
+
+    let listener = Arc::new(TestListener::new());
+
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            Some(listener.clone()),
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(update.source_state, None);
+    assert!(!update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        indoc!(
+            r#"
+            {"id":1,"name":"A"}
+            {"id":2,"name":"B"}
+            "#
+        )
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-sql")]
+#[test_log::test(tokio::test)]
+async fn test_fetch_sql_sqlite_incremental_by_cursor() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let fetch_svc = FetchService::new(
+        Arc::new(ContainerRuntime::default()),
+        Some(Arc::new(SourceConfig {
+            target_records_per_slice: 2,
+        })),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(DatasetKeyValueServiceSysEnv::new()),
+        Arc::new(RunInfoDir::new(temp_dir.path().join("run"))),
+    );
+
+    let db_path = temp_dir.path().join("db.sqlite");
+    let target_path = temp_dir.path().join("fetched.bin");
+
+    sqlite_create_table_with_rows(&db_path, &[(1, "A"), (2, "B"), (3, "C")]).await;
+
+    let fetch_step = FetchStep::Sql(FetchStepSql {
+        connection_url: format!("sqlite://{}", db_path.display()),
+        query: "SELECT id, name FROM cities".to_string(),
+        cursor_column: Some("id".to_string()),
+    });
+
+    // First slice is limited by the target number of records
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag("2".to_string()))
+    );
+    assert!(update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        indoc!(
+            r#"
+            {"id":1,"name":"A"}
+            {"id":2,"name":"B"}
+            "#
+        )
+    );
+
+    // Remaining rows
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag("3".to_string()))
+    );
+    assert!(!update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        "{\"id\":3,\"name\":\"C\"}\n"
+    );
+
+    // Nothing new
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_matches!(res, FetchResult::UpToDate);
+
+    // New rows are picked up
+    sqlite_create_table_with_rows(&db_path, &[(4, "D")]).await;
+
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag("4".to_string()))
+    );
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        "{\"id\":4,\"name\":\"D\"}\n"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-sql")]
+#[test_log::test(tokio::test)]
+async fn test_fetch_sql_sqlite_non_unique_cursor() {
+    use sqlx::Connection as _;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let fetch_svc = FetchService::new(
+        Arc::new(ContainerRuntime::default()),
+        Some(Arc::new(SourceConfig {
+            target_records_per_slice: 2,
+        })),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(DatasetKeyValueServiceSysEnv::new()),
+        Arc::new(RunInfoDir::new(temp_dir.path().join("run"))),
+    );
+
+    let db_path = temp_dir.path().join("db.sqlite");
+    let target_path = temp_dir.path().join("fetched.bin");
+
+    let mut connection = sqlx::SqliteConnection::connect_with(
+        &sqlx::sqlite::SqliteConnectOptions::new()
+            .filename(&db_path)
+            .create_if_missing(true),
+    )
+    .await
+    .unwrap();
+
+    sqlx::query("CREATE TABLE events (id INTEGER NOT NULL, name TEXT NOT NULL)")
+        .execute(&mut connection)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO events VALUES (1, 'A'), (2, 'B'), (2, 'C'), (3, 'D')")
+        .execute(&mut connection)
+        .await
+        .unwrap();
+
+    connection.close().await.unwrap();
+
+    let fetch_step = FetchStep::Sql(FetchStepSql {
+        connection_url: format!("sqlite://{}", db_path.display()),
+        query: "SELECT id, name FROM events".to_string(),
+        cursor_column: Some("id".to_string()),
+    });
+
+    // Rows sharing the cursor value at the slice boundary are fetched together
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag("2".to_string()))
+    );
+    assert!(update.has_more);
+
+    // Order of rows within the same cursor value is not defined
+    let data = std::fs::read_to_string(&target_path).unwrap();
+    let mut lines: Vec<_> = data.lines().collect();
+    lines.sort_unstable();
+    assert_eq!(
+        lines,
+        [
+            r#"{"id":1,"name":"A"}"#,
+            r#"{"id":2,"name":"B"}"#,
+            r#"{"id":2,"name":"C"}"#,
+        ]
+    );
+
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag("3".to_string()))
+    );
+    assert!(!update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        "{\"id\":3,\"name\":\"D\"}\n"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-sql")]
+#[test_log::test(tokio::test)]
+async fn test_fetch_sql_sqlite_timestamp_cursor() {
+    use sqlx::Connection as _;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let fetch_svc = FetchService::new(
+        Arc::new(ContainerRuntime::default()),
+        Some(Arc::new(SourceConfig {
+            target_records_per_slice: 2,
+        })),
+        None,
+        None,
+        None,
+        None,
+        Arc::new(DatasetKeyValueServiceSysEnv::new()),
+        Arc::new(RunInfoDir::new(temp_dir.path().join("run"))),
+    );
+
+    let db_path = temp_dir.path().join("db.sqlite");
+    let target_path = temp_dir.path().join("fetched.bin");
+
+    let mut connection = sqlx::SqliteConnection::connect_with(
+        &sqlx::sqlite::SqliteConnectOptions::new()
+            .filename(&db_path)
+            .create_if_missing(true),
+    )
+    .await
+    .unwrap();
+
+    sqlx::query("CREATE TABLE events (name TEXT NOT NULL, ts DATETIME NOT NULL)")
+        .execute(&mut connection)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO events VALUES ('A', '2020-01-01 00:00:00'), ('B', '2020-01-02 00:00:00'), \
+         ('C', '2020-01-03 00:00:00')",
+    )
+    .execute(&mut connection)
+    .await
+    .unwrap();
+
+    connection.close().await.unwrap();
+
+    let fetch_step = FetchStep::Sql(FetchStepSql {
+        connection_url: format!("sqlite://{}", db_path.display()),
+        query: "SELECT name, ts FROM events".to_string(),
+        cursor_column: Some("ts".to_string()),
+    });
+
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            None,
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag(
+            r#""2020-01-02 00:00:00""#.to_string()
+        ))
+    );
+    assert!(update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        indoc!(
+            r#"
+            {"name":"A","ts":"2020-01-01 00:00:00"}
+            {"name":"B","ts":"2020-01-02 00:00:00"}
+            "#
+        )
+    );
+
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            update.source_state.as_ref(),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag(
+            r#""2020-01-03 00:00:00""#.to_string()
+        ))
+    );
+    assert!(!update.has_more);
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        "{\"name\":\"C\",\"ts\":\"2020-01-03 00:00:00\"}\n"
+    );
+
+    // Cursor values read from the databases with temporal types are persisted with
+    // their type and bound as native values
+    let res = fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            Some(&PollingSourceState::ETag(
+                r#"{"timestamp":"2020-01-01T00:00:00"}"#.to_string(),
+            )),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let FetchResult::Updated(update) = res else {
+        panic!("Unexpected result: {res:#?}");
+    };
+    assert_eq!(
+        update.source_state,
+        Some(PollingSourceState::ETag(
+            r#""2020-01-03 00:00:00""#.to_string()
+        ))
+    );
+    assert_eq!(
+        std::fs::read_to_string(&target_path).unwrap(),
+        indoc!(
+            r#"
+            {"name":"B","ts":"2020-01-02 00:00:00"}
+            {"name":"C","ts":"2020-01-03 00:00:00"}
+            "#
+        )
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "ingest-sql")]
+#[test_log::test(tokio::test)]
+async fn test_fetch_sql_malformed_source_state() {
+    let harness = FetchTestHarness::new();
+
+    let db_path = harness.temp_dir.path().join("db.sqlite");
+    let target_path = harness.temp_dir.path().join("fetched.bin");
+
+    let fetch_step = FetchStep::Sql(FetchStepSql {
+        connection_url: format!("sqlite://{}", db_path.display()),
+        query: "SELECT id, name FROM cities".to_string(),
+        cursor_column: Some("id".to_string()),
+    });
+
+    let res = harness
+        .fetch_svc
+        .fetch(
+            &mock_dataset_handle(),
+            &generate_unique_operation_id(),
+            &fetch_step,
+            Some(&PollingSourceState::ETag("[1, 2]".to_string())),
+            &target_path,
+            &Utc::now(),
+            &HashMap::new(),
+            None,
+        )
+        .await;
+
+    assert_matches!(res, Err(PollingIngestError::InvalidSourceState(_)));
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Container
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1394,6 +2252,7 @@ impl FetchTestHarness {
             None,
             None,
             None,
+            None,
             Arc::new(DatasetKeyValueServiceSysEnv::new()),
             Arc::new(RunInfoDir::new(temp_dir.path().join("run"))),
         );
@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use std::assert_matches::assert_matches;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use chrono::{DateTime, TimeZone, Utc};
@@ -419,6 +419,220 @@ async fn test_data_writer_rejects_incompatible_schema() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_evolves_schema_additively() {
+    let mut harness = Harness::new(vec![]).await;
+
+    // Round 1
+    harness
+        .write(
+            indoc!(
+                r#"
+                city,population
+                A,1000
+                B,2000
+                "#
+            ),
+            "city STRING, population INT",
+        )
+        .await
+        .unwrap();
+
+    let (schema_block_hash, _) = harness.get_last_schema_block().await;
+
+    // Round 2 (new column and wider type)
+    harness
+        .set_schema_evolution(SchemaEvolutionRules::all())
+        .await;
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 2, 12, 0, 0).unwrap());
+    harness.set_source_event_time(Utc.with_ymd_and_hms(2000, 1, 2, 12, 0, 0).unwrap());
+
+    harness
+        .write(
+            indoc!(
+                r#"
+                city,state,population
+                C,X,3000
+                "#
+            ),
+            "city STRING, state STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    let df = harness.get_last_data().await;
+
+    assert_schema_eq(
+        df.schema(),
+        indoc!(
+            r#"
+            message arrow_schema {
+              REQUIRED INT64 offset;
+              REQUIRED INT32 op;
+              REQUIRED INT64 system_time (TIMESTAMP(MILLIS,true));
+              OPTIONAL INT64 event_time (TIMESTAMP(MILLIS,true));
+              OPTIONAL BYTE_ARRAY city (STRING);
+              OPTIONAL INT64 population;
+              OPTIONAL BYTE_ARRAY state (STRING);
+            }
+            "#
+        ),
+    );
+
+    assert_data_eq(
+        df.clone(),
+        indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+-------+
+            | offset | op | system_time          | event_time           | city | population | state |
+            +--------+----+----------------------+----------------------+------+------------+-------+
+            | 2      | 0  | 2010-01-02T12:00:00Z | 2000-01-02T12:00:00Z | C    | 3000       | X     |
+            +--------+----+----------------------+----------------------+------+------------+-------+
+            "#
+        ),
+    )
+    .await;
+
+    // New schema was committed
+    let (evolved_schema_block_hash, schema_block) = harness.get_last_schema_block().await;
+    assert_ne!(schema_block_hash, evolved_schema_block_hash);
+
+    let schema_in_block = schema_block.event.schema_as_arrow().unwrap();
+    let schema_in_data = SchemaRef::new(df.schema().into());
+    assert_eq!(schema_in_block, schema_in_data);
+
+    // Round 3 (not ok - column was removed)
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 3, 12, 0, 0).unwrap());
+    harness.set_source_event_time(Utc.with_ymd_and_hms(2000, 1, 3, 12, 0, 0).unwrap());
+
+    let res = harness
+        .write(
+            indoc!(
+                r#"
+                city,population
+                D,4000
+                "#
+            ),
+            "city STRING, population BIGINT",
+        )
+        .await;
+
+    assert_matches!(res, Err(WriteDataError::IncompatibleSchema(_)));
+
+    // Round 4 (narrower type is cast to the declared one)
+    harness
+        .write(
+            indoc!(
+                r#"
+                city,state,population
+                D,Y,4000
+                "#
+            ),
+            "city STRING, state STRING, population INT",
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+-------+
+            | offset | op | system_time          | event_time           | city | population | state |
+            +--------+----+----------------------+----------------------+------+------------+-------+
+            | 3      | 0  | 2010-01-03T12:00:00Z | 2000-01-03T12:00:00Z | D    | 4000       | Y     |
+            +--------+----+----------------------+----------------------+------+------------+-------+
+            "#
+        ),
+    )
+    .await;
+
+    assert_eq!(
+        evolved_schema_block_hash,
+        harness.get_last_schema_block().await.0
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_snapshot_evolves_schema() {
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategySnapshot {
+            primary_key: vec!["city".to_string()],
+            compare_columns: None,
+        })
+        .build()
+        .into()])
+    .await;
+
+    // Round 1
+    harness
+        .write(
+            indoc!(
+                r#"
+                city,population
+                A,1000
+                B,2000
+                "#
+            ),
+            "city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    // Round 2 (without evolution new column is rejected)
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 2, 12, 0, 0).unwrap());
+    harness.set_source_event_time(Utc.with_ymd_and_hms(2000, 1, 2, 12, 0, 0).unwrap());
+
+    let data = indoc!(
+        r#"
+        city,population,state
+        A,1000,X
+        B,2000,Y
+        "#
+    );
+    let schema = "city STRING, population BIGINT, state STRING";
+
+    let res = harness.write(data, schema).await;
+    assert_matches!(res, Err(WriteDataError::IncompatibleSchema(_)));
+
+    // Round 3 (new column populates previous state)
+    harness
+        .set_schema_evolution(SchemaEvolutionRules::all())
+        .await;
+
+    harness.write(data, schema).await.unwrap();
+
+    assert_data_eq(
+        harness.get_last_data().await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+-------+
+            | offset | op | system_time          | event_time           | city | population | state |
+            +--------+----+----------------------+----------------------+------+------------+-------+
+            | 2      | 2  | 2010-01-02T12:00:00Z | 2000-01-01T12:00:00Z | A    | 1000       |       |
+            | 3      | 3  | 2010-01-02T12:00:00Z | 2000-01-02T12:00:00Z | A    | 1000       | X     |
+            | 4      | 2  | 2010-01-02T12:00:00Z | 2000-01-01T12:00:00Z | B    | 2000       |       |
+            | 5      | 3  | 2010-01-02T12:00:00Z | 2000-01-02T12:00:00Z | B    | 2000       | Y     |
+            +--------+----+----------------------+----------------------+------+------------+-------+
+            "#
+        ),
+    )
+    .await;
+
+    // Round 4 (slices written before and after evolution are merged correctly)
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 3, 12, 0, 0).unwrap());
+    harness.set_source_event_time(Utc.with_ymd_and_hms(2000, 1, 3, 12, 0, 0).unwrap());
+
+    let res = harness.write(data, schema).await;
+    assert_matches!(res, Err(WriteDataError::EmptyCommit(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[test_group::group(engine, ingest, datafusion)]
 #[test_log::test(tokio::test)]
 async fn test_data_writer_ledger_orders_by_event_time() {
@@ -762,6 +976,210 @@ async fn test_data_writer_optimal_parquet_encoding() {
     assert_data_encoding(2, Encoding::RLE_DICTIONARY);
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_partitions_slices() {
+    use kamu_core::utils::partitioned_slices::{read_slice_partitions, write_partitioning_spec};
+
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyAppend {})
+        .build()
+        .into()])
+    .await;
+
+    let spec = PartitioningSpec {
+        column: "event_time".to_string(),
+        transform: PartitionTransform::Day,
+    };
+
+    write_partitioning_spec(harness.dataset.as_info_repo(), &spec)
+        .await
+        .unwrap();
+
+    // Round 1: Partition values of records of different days are indexed
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,city,population
+                2020-01-01T10:00:00Z,A,1000
+                2020-01-02T10:00:00Z,B,2000
+                2020-01-01T20:00:00Z,C,3000
+                "#
+            ),
+            "event_time TIMESTAMP, city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    let slice_1 = harness
+        .get_last_data_block()
+        .await
+        .event
+        .new_data
+        .unwrap()
+        .physical_hash;
+
+    let partitions_1 = read_slice_partitions(harness.dataset.as_info_repo(), &slice_1)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        partitions_1,
+        SlicePartitions {
+            spec: spec.clone(),
+            row_groups: vec![RowGroupPartitions {
+                offset_start: 0,
+                offset_end: 2,
+                values: vec![
+                    Some("2020-01-01".to_string()),
+                    Some("2020-01-02".to_string())
+                ],
+            }],
+        }
+    );
+
+    // The data slice itself is stored intact
+    assert_data_eq(
+        harness.get_data(&slice_1).await,
+        indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+
+            | offset | op | system_time          | event_time           | city | population |
+            +--------+----+----------------------+----------------------+------+------------+
+            | 0      | 0  | 2010-01-01T12:00:00Z | 2020-01-01T10:00:00Z | A    | 1000       |
+            | 1      | 0  | 2010-01-01T12:00:00Z | 2020-01-02T10:00:00Z | B    | 2000       |
+            | 2      | 0  | 2010-01-01T12:00:00Z | 2020-01-01T20:00:00Z | C    | 3000       |
+            +--------+----+----------------------+----------------------+------+------------+
+            "#
+        ),
+    )
+    .await;
+
+    // Round 2: New slice gets its own index entry
+    harness.set_system_time(Utc.with_ymd_and_hms(2010, 1, 2, 12, 0, 0).unwrap());
+
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,city,population
+                2020-01-03T10:00:00Z,D,4000
+                2020-01-03T11:00:00Z,E,5000
+                "#
+            ),
+            "event_time TIMESTAMP, city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    let slice_2 = harness
+        .get_last_data_block()
+        .await
+        .event
+        .new_data
+        .unwrap()
+        .physical_hash;
+
+    assert_eq!(
+        read_slice_partitions(harness.dataset.as_info_repo(), &slice_2)
+            .await
+            .unwrap()
+            .unwrap()
+            .row_groups,
+        vec![RowGroupPartitions {
+            offset_start: 3,
+            offset_end: 4,
+            values: vec![Some("2020-01-03".to_string())],
+        }]
+    );
+
+    assert_eq!(
+        read_slice_partitions(harness.dataset.as_info_repo(), &slice_1)
+            .await
+            .unwrap(),
+        Some(partitions_1)
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_data_writer_collects_slice_statistics() {
+    use kamu_core::utils::slice_statistics::{
+        read_slice_statistics,
+        write_slice_statistics_config,
+    };
+
+    let mut harness = Harness::new(vec![MetadataFactory::set_polling_source()
+        .merge(odf::MergeStrategyAppend {})
+        .build()
+        .into()])
+    .await;
+
+    write_slice_statistics_config(
+        harness.dataset.as_info_repo(),
+        &SliceStatisticsConfig {
+            bloom_filter_columns: vec!["city".to_string()],
+        },
+    )
+    .await
+    .unwrap();
+
+    harness
+        .write(
+            indoc!(
+                r#"
+                event_time,city,population
+                2020-01-01,B,2000
+                2020-01-01,A,
+                2020-01-01,C,3000
+                "#
+            ),
+            "event_time TIMESTAMP, city STRING, population BIGINT",
+        )
+        .await
+        .unwrap();
+
+    let source_slice = harness
+        .get_last_data_block()
+        .await
+        .event
+        .new_data
+        .unwrap()
+        .physical_hash;
+
+    let stats = read_slice_statistics(harness.dataset.as_info_repo(), &source_slice)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let offset = stats.column("offset").unwrap();
+    assert_eq!(offset.min.as_deref(), Some("0"));
+    assert_eq!(offset.max.as_deref(), Some("2"));
+    assert_eq!(offset.null_count, 0);
+    assert_eq!(offset.bloom_filter, None);
+
+    let population = stats.column("population").unwrap();
+    assert_eq!(population.min.as_deref(), Some("2000"));
+    assert_eq!(population.max.as_deref(), Some("3000"));
+    assert_eq!(population.null_count, 1);
+
+    let city = stats.column("city").unwrap();
+    assert_eq!(city.min.as_deref(), Some("A"));
+    assert_eq!(city.max.as_deref(), Some("C"));
+    assert_eq!(city.null_count, 0);
+
+    let bloom_filter = city.bloom_filter.as_ref().unwrap();
+    assert!(bloom_filter.may_contain("A"));
+    assert!(bloom_filter.may_contain("B"));
+    assert!(bloom_filter.may_contain("C"));
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Builder
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -936,6 +1354,7 @@ struct Harness {
     dataset: Arc<dyn Dataset>,
     writer: DataWriterDataFusion,
     ctx: SessionContext,
+    schema_evolution: SchemaEvolutionRules,
 
     system_time: DateTime<Utc>,
     source_event_time: DateTime<Utc>,
@@ -1001,6 +1420,7 @@ impl Harness {
             dataset,
             writer,
             ctx,
+            schema_evolution: SchemaEvolutionRules::default(),
             system_time,
             source_event_time: Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap(),
         }
@@ -1014,8 +1434,14 @@ impl Harness {
         self.source_event_time = t;
     }
 
+    async fn set_schema_evolution(&mut self, schema_evolution: SchemaEvolutionRules) {
+        self.schema_evolution = schema_evolution;
+        self.reset_writer().await;
+    }
+
     async fn reset_writer(&mut self) {
         self.writer = DataWriterDataFusion::builder(self.dataset.clone(), self.ctx.clone())
+            .with_schema_evolution(self.schema_evolution)
             .with_metadata_state_scanned(None)
             .await
             .unwrap()
@@ -1104,10 +1530,15 @@ impl Harness {
     async fn get_last_data_file(&self) -> PathBuf {
         let block = self.get_last_data_block().await;
 
+        self.get_data_file(&block.event.new_data.unwrap().physical_hash)
+            .await
+    }
+
+    async fn get_data_file(&self, physical_hash: &odf::Multihash) -> PathBuf {
         kamu_data_utils::data::local_url::into_local_path(
             self.dataset
                 .as_data_repo()
-                .get_internal_url(&block.event.new_data.unwrap().physical_hash)
+                .get_internal_url(physical_hash)
                 .await,
         )
         .unwrap()
@@ -1115,6 +1546,15 @@ impl Harness {
 
     async fn get_last_data(&self) -> DataFrame {
         let part_file = self.get_last_data_file().await;
+        self.read_data_file(&part_file).await
+    }
+
+    async fn get_data(&self, physical_hash: &odf::Multihash) -> DataFrame {
+        let part_file = self.get_data_file(physical_hash).await;
+        self.read_data_file(&part_file).await
+    }
+
+    async fn read_data_file(&self, part_file: &Path) -> DataFrame {
         self.ctx
             .read_parquet(
                 part_file.to_string_lossy().as_ref(),
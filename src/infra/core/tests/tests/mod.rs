@@ -11,15 +11,20 @@ mod engine;
 mod ingest;
 mod repos;
 mod test_compact_service_impl;
+mod test_data_export_service_impl;
+mod test_dataset_archive_service_impl;
 mod test_dataset_changes_service_impl;
+mod test_dataset_diff_service_impl;
 mod test_dataset_ownership_service_inmem;
 mod test_datasets_filtering;
 mod test_dependency_graph_inmem;
 mod test_metadata_chain_comparator;
+mod test_provenance_service_impl;
 mod test_pull_service_impl;
 mod test_query_service_impl;
 mod test_reset_service_impl;
 mod test_resource_loader_impl;
+mod test_retention_service_impl;
 mod test_schema_utils;
 mod test_search_service_impl;
 mod test_serde_yaml;
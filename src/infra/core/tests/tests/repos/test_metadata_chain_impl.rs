@@ -722,6 +722,60 @@ async fn test_append_add_data_must_be_preseeded_by_schema() {
         .unwrap();
 }
 
+#[test_log::test(tokio::test)]
+async fn test_append_set_data_schema_is_not_restricted_to_additive_evolution() {
+    use datafusion::arrow::datatypes::{DataType, Field, Schema};
+
+    let tmp_dir = tempfile::tempdir().unwrap();
+    let chain = init_chain(tmp_dir.path());
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(MetadataFactory::seed(DatasetKind::Root).build())
+                .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let head = chain
+        .append(
+            MetadataFactory::metadata_block(
+                MetadataFactory::set_data_schema()
+                    .schema(&Schema::new(vec![
+                        Field::new("city", DataType::Utf8, false),
+                        Field::new("population", DataType::Int32, false),
+                    ]))
+                    .build(),
+            )
+            .prev(&head, 0)
+            .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    // Evolution rules apply to schemas produced by ingest only, chains received
+    // from other nodes may replace the schema
+    chain
+        .append(
+            MetadataFactory::metadata_block(
+                MetadataFactory::set_data_schema()
+                    .schema(&Schema::new(vec![Field::new(
+                        "city",
+                        DataType::Utf8,
+                        false,
+                    )]))
+                    .build(),
+            )
+            .prev(&head, 1)
+            .build(),
+            AppendOpts::default(),
+        )
+        .await
+        .unwrap();
+}
+
 #[test_log::test(tokio::test)]
 async fn test_append_execute_transform_must_be_preseeded_by_schema() {
     let tmp_dir = tempfile::tempdir().unwrap();
@@ -11,10 +11,18 @@ use std::assert_matches::assert_matches;
 use std::sync::{Arc, Mutex};
 
 use chrono::{DateTime, NaiveDate, TimeDelta, TimeZone, Utc};
+use datafusion::dataframe::DataFrame;
 use datafusion::execution::config::SessionConfig;
 use datafusion::execution::context::SessionContext;
 use dill::Component;
-use domain::{CompactionError, CompactionOptions, CompactionResult, CompactionService};
+use domain::{
+    CompactedSlices,
+    CompactionError,
+    CompactionMode,
+    CompactionOptions,
+    CompactionResult,
+    CompactionService,
+};
 use futures::TryStreamExt;
 use indoc::{formatdoc, indoc};
 use kamu::domain::*;
@@ -23,6 +31,7 @@ use kamu::utils::s3_context::S3Context;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
 use kamu_core::auth;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use time_source::{SystemTimeSource, SystemTimeSourceStub};
 
@@ -1054,6 +1063,247 @@ async fn test_dataset_keep_metadata_only_compact() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test_group::group(ingest, datafusion, compact)]
+#[tokio::test]
+async fn test_dataset_soft_compact() {
+    let harness = CompactTestHarness::new();
+
+    let created = harness.create_test_root_dataset().await;
+    let dataset_ref = created.dataset_handle.as_local_ref();
+
+    // Round 1: Three small slices are merged into one file, chain is left intact
+    harness.ingest_multiple_blocks(&dataset_ref, 3).await;
+
+    let old_head = harness.get_dataset_head(&dataset_ref).await;
+    let old_blocks = harness.get_dataset_blocks(&dataset_ref).await;
+
+    assert_matches!(
+        harness
+            .compaction_svc
+            .compact_dataset(
+                &created.dataset_handle,
+                CompactionOptions {
+                    mode: CompactionMode::Soft,
+                    ..CompactionOptions::default()
+                },
+                Some(Arc::new(NullCompactionListener {}))
+            )
+            .await,
+        Ok(CompactionResult::SoftSuccess {
+            head,
+            old_num_slices: 3,
+            new_num_slices: 1,
+        }) if head == old_head,
+    );
+
+    assert_eq!(old_head, harness.get_dataset_head(&dataset_ref).await);
+    assert_eq!(old_blocks, harness.get_dataset_blocks(&dataset_ref).await);
+    assert!(harness.verify_dataset(&dataset_ref).await);
+
+    let compacted_slices = harness.get_compacted_slices(&dataset_ref).await.unwrap();
+    assert_eq!(compacted_slices.slices.len(), 1);
+
+    let compacted_slice = compacted_slices.slices.first().unwrap();
+    assert_eq!(
+        compacted_slice.offset_interval,
+        OffsetInterval { start: 0, end: 5 }
+    );
+    assert_eq!(compacted_slice.source_slices.len(), 3);
+    assert_eq!(
+        harness
+            .count_data_file_records(&dataset_ref, &compacted_slice.physical_hash)
+            .await,
+        6
+    );
+
+    // Round 2: Nothing new to merge
+    assert_matches!(
+        harness
+            .compaction_svc
+            .compact_dataset(
+                &created.dataset_handle,
+                CompactionOptions {
+                    mode: CompactionMode::Soft,
+                    ..CompactionOptions::default()
+                },
+                Some(Arc::new(NullCompactionListener {}))
+            )
+            .await,
+        Ok(CompactionResult::NothingToDo)
+    );
+
+    // Round 3: Only the newly added slices are merged
+    harness.ingest_multiple_blocks(&dataset_ref, 2).await;
+
+    assert_matches!(
+        harness
+            .compaction_svc
+            .compact_dataset(
+                &created.dataset_handle,
+                CompactionOptions {
+                    mode: CompactionMode::Soft,
+                    ..CompactionOptions::default()
+                },
+                Some(Arc::new(NullCompactionListener {}))
+            )
+            .await,
+        Ok(CompactionResult::SoftSuccess {
+            old_num_slices: 3,
+            new_num_slices: 2,
+            ..
+        })
+    );
+
+    let compacted_slices = harness.get_compacted_slices(&dataset_ref).await.unwrap();
+    assert_eq!(
+        compacted_slices
+            .slices
+            .iter()
+            .map(|s| s.offset_interval.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            OffsetInterval { start: 0, end: 5 },
+            OffsetInterval { start: 6, end: 9 }
+        ]
+    );
+    assert!(harness.verify_dataset(&dataset_ref).await);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(ingest, datafusion, compact)]
+#[tokio::test]
+async fn test_dataset_soft_compact_evolved_schema() {
+    let harness = CompactTestHarness::new();
+
+    let created = harness.create_test_root_dataset().await;
+    let dataset_ref = created.dataset_handle.as_local_ref();
+
+    harness.ingest_multiple_blocks(&dataset_ref, 2).await;
+
+    // New nullable column is added by another push source
+    let dataset = harness
+        .dataset_repo
+        .find_dataset_by_ref(&dataset_ref)
+        .await
+        .unwrap();
+    dataset
+        .commit_event(
+            MetadataFactory::add_push_source()
+                .source_name("evolved")
+                .read(ReadStepCsv {
+                    header: Some(true),
+                    schema: Some(
+                        [
+                            "date TIMESTAMP",
+                            "city STRING",
+                            "population BIGINT",
+                            "state STRING",
+                        ]
+                        .iter()
+                        .map(|s| (*s).to_string())
+                        .collect(),
+                    ),
+                    ..ReadStepCsv::default()
+                })
+                .merge(MergeStrategyLedger {
+                    primary_key: vec!["date".to_string(), "city".to_string()],
+                })
+                .build()
+                .into(),
+            CommitOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    harness
+        .push_ingest_svc
+        .ingest_from_file_stream(
+            &dataset_ref,
+            Some("evolved"),
+            Box::new(std::io::Cursor::new(indoc!(
+                "
+                date,city,population,state
+                2020-01-10,C,3000,X
+                2020-01-11,D,4000,Y
+                "
+            ))),
+            PushIngestOpts {
+                schema_evolution: SchemaEvolutionRules {
+                    add_nullable_columns: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_matches!(
+        harness
+            .compaction_svc
+            .compact_dataset(
+                &created.dataset_handle,
+                CompactionOptions {
+                    mode: CompactionMode::Soft,
+                    ..CompactionOptions::default()
+                },
+                Some(Arc::new(NullCompactionListener {}))
+            )
+            .await,
+        Ok(CompactionResult::SoftSuccess {
+            old_num_slices: 3,
+            new_num_slices: 1,
+            ..
+        })
+    );
+
+    // Slices written before the evolution are merged according to the latest schema
+    let compacted_slices = harness.get_compacted_slices(&dataset_ref).await.unwrap();
+    assert_eq!(compacted_slices.slices.len(), 1);
+
+    let compacted_slice = compacted_slices.slices.first().unwrap();
+    assert_eq!(compacted_slice.source_slices.len(), 3);
+
+    let df = harness
+        .read_data_file(&dataset_ref, &compacted_slice.physical_hash)
+        .await;
+    assert!(df.schema().has_column_with_unqualified_name("state"));
+    assert_eq!(df.clone().count().await.unwrap(), 6);
+    assert_eq!(
+        df.filter(datafusion::prelude::col("state").is_null())
+            .unwrap()
+            .count()
+            .await
+            .unwrap(),
+        4
+    );
+
+    // Hard compaction rewrites the chain and clears the side index
+    assert_matches!(
+        harness
+            .compaction_svc
+            .compact_dataset(
+                &created.dataset_handle,
+                CompactionOptions::default(),
+                Some(Arc::new(NullCompactionListener {}))
+            )
+            .await,
+        Ok(CompactionResult::Success { .. })
+    );
+
+    assert_eq!(harness.get_compacted_slices(&dataset_ref).await, None);
+    assert!(!dataset
+        .as_data_repo()
+        .contains(&compacted_slice.physical_hash)
+        .await
+        .unwrap());
+    assert!(harness.verify_dataset(&dataset_ref).await);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 struct CompactTestHarness {
     _temp_dir: tempfile::TempDir,
     dataset_repo: Arc<dyn DatasetRepository>,
@@ -1103,6 +1353,7 @@ impl CompactTestHarness {
             .bind::<dyn EngineProvisioner, mock_engine_provisioner::MockEngineProvisioner>()
             .add::<TransformServiceImpl>()
             .add::<VerificationServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .build();
 
         let dataset_repo = catalog.get_one::<dyn DatasetRepository>().unwrap();
@@ -1152,6 +1403,7 @@ impl CompactTestHarness {
             .add_value(TestTransformService::new(Arc::new(Mutex::new(Vec::new()))))
             .bind::<dyn TransformService, TestTransformService>()
             .add::<VerificationServiceImpl>()
+            .add::<DummyOutboxImpl>()
             .add::<PushIngestServiceImpl>()
             .add::<DataFormatRegistryImpl>()
             .add::<CompactionServiceImpl>()
@@ -1204,6 +1456,46 @@ impl CompactTestHarness {
             .unwrap()
     }
 
+    async fn get_compacted_slices(&self, dataset_ref: &DatasetRef) -> Option<CompactedSlices> {
+        let dataset = self
+            .dataset_repo
+            .find_dataset_by_ref(dataset_ref)
+            .await
+            .unwrap();
+
+        kamu::utils::compacted_slices::read_compacted_slices(dataset.as_info_repo())
+            .await
+            .unwrap()
+    }
+
+    async fn read_data_file(&self, dataset_ref: &DatasetRef, hash: &Multihash) -> DataFrame {
+        let dataset = self
+            .dataset_repo
+            .find_dataset_by_ref(dataset_ref)
+            .await
+            .unwrap();
+        let url = dataset.as_data_repo().get_internal_url(hash).await;
+
+        self.ctx
+            .read_parquet(
+                url.to_string(),
+                datafusion::execution::options::ParquetReadOptions {
+                    file_extension: "",
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn count_data_file_records(&self, dataset_ref: &DatasetRef, hash: &Multihash) -> usize {
+        self.read_data_file(dataset_ref, hash)
+            .await
+            .count()
+            .await
+            .unwrap()
+    }
+
     async fn create_dataset(&self, dataset_snapshot: DatasetSnapshot) -> CreateDatasetResult {
         self.dataset_repo_writer
             .create_dataset_from_snapshot(dataset_snapshot)
@@ -0,0 +1,467 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use datafusion::prelude::*;
+use indoc::indoc;
+use kamu::domain::*;
+use kamu::testing::*;
+use kamu::*;
+use kamu_accounts::CurrentAccountSubject;
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::*;
+use tempfile::TempDir;
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+use url::Url;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_export_dataset_incrementally() {
+    let harness = DataExportTestHarness::new();
+    let hdl = harness.new_root("foo").await;
+
+    harness
+        .ingest(
+            &hdl,
+            indoc!(
+                "
+                city,population
+                A,1000
+                B,2000
+                "
+            ),
+        )
+        .await;
+
+    let dest = harness.tempdir.path().join("export");
+    let options = DataExportOptions {
+        destination: Url::from_directory_path(&dest).unwrap(),
+        format: DataExportFormat::Csv,
+        compression: None,
+        partition_by: Vec::new(),
+        incremental: true,
+    };
+
+    let head = harness.get_head(&hdl).await;
+    assert_eq!(
+        harness
+            .export_svc
+            .export_data(
+                DataExportSource::Dataset(hdl.as_local_ref()),
+                options.clone()
+            )
+            .await
+            .unwrap(),
+        DataExportResult::Exported {
+            num_records: 2,
+            head: Some(head),
+        }
+    );
+
+    harness
+        .ingest(
+            &hdl,
+            indoc!(
+                "
+                city,population
+                C,3000
+                "
+            ),
+        )
+        .await;
+
+    let head = harness.get_head(&hdl).await;
+    assert_eq!(
+        harness
+            .export_svc
+            .export_data(
+                DataExportSource::Dataset(hdl.as_local_ref()),
+                options.clone()
+            )
+            .await
+            .unwrap(),
+        DataExportResult::Exported {
+            num_records: 1,
+            head: Some(head),
+        }
+    );
+
+    assert_eq!(
+        harness
+            .export_svc
+            .export_data(
+                DataExportSource::Dataset(hdl.as_local_ref()),
+                options.clone()
+            )
+            .await
+            .unwrap(),
+        DataExportResult::UpToDate
+    );
+
+    // Previous exports are not overwritten
+    let ctx = SessionContext::new();
+    let df = ctx
+        .read_csv(dest.to_str().unwrap(), CsvReadOptions::new())
+        .await
+        .unwrap();
+    assert_eq!(df.count().await.unwrap(), 3);
+
+    // Full export ignores the position
+    assert_matches!(
+        harness
+            .export_svc
+            .export_data(
+                DataExportSource::Dataset(hdl.as_local_ref()),
+                DataExportOptions {
+                    destination: Url::from_directory_path(harness.tempdir.path().join("full"))
+                        .unwrap(),
+                    incremental: false,
+                    ..options
+                },
+            )
+            .await
+            .unwrap(),
+        DataExportResult::Exported { num_records: 3, .. }
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_export_dataset_partitioned() {
+    let harness = DataExportTestHarness::new();
+    let hdl = harness.new_root("foo").await;
+
+    harness
+        .ingest(
+            &hdl,
+            indoc!(
+                "
+                city,population
+                A,1000
+                B,2000
+                A,3000
+                "
+            ),
+        )
+        .await;
+
+    let dest = harness.tempdir.path().join("export");
+    let res = harness
+        .export_svc
+        .export_data(
+            DataExportSource::Dataset(hdl.as_local_ref()),
+            DataExportOptions {
+                destination: Url::from_directory_path(&dest).unwrap(),
+                format: DataExportFormat::Parquet,
+                compression: Some(DataExportCompression::Zstd),
+                partition_by: vec!["city".to_string()],
+                incremental: false,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_matches!(res, DataExportResult::Exported { num_records: 3, .. });
+    assert!(dest.join("city=A").is_dir());
+    assert!(dest.join("city=B").is_dir());
+    assert_eq!(count_parquet_records(&dest.join("city=A")).await, 2);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_export_dataset_with_evolved_schema() {
+    let harness = DataExportTestHarness::new();
+    let hdl = harness.new_root("foo").await;
+
+    harness
+        .ingest(
+            &hdl,
+            indoc!(
+                "
+                city,population
+                A,1000
+                B,2000
+                "
+            ),
+        )
+        .await;
+
+    // New nullable column is added after the data was written
+    let dataset = harness.dataset_repo.get_dataset_by_handle(&hdl);
+    let schema = dataset
+        .as_metadata_chain()
+        .accept_one(SearchSetDataSchemaVisitor::new())
+        .await
+        .unwrap()
+        .into_event()
+        .unwrap()
+        .schema_as_arrow()
+        .unwrap();
+    let mut fields: Vec<_> = schema.fields().iter().cloned().collect();
+    fields.push(Arc::new(datafusion::arrow::datatypes::Field::new(
+        "state",
+        datafusion::arrow::datatypes::DataType::Utf8,
+        true,
+    )));
+    dataset
+        .commit_event(
+            MetadataFactory::set_data_schema()
+                .schema(&datafusion::arrow::datatypes::Schema::new(fields))
+                .build()
+                .into(),
+            CommitOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    let dest = harness.tempdir.path().join("export");
+    let res = harness
+        .export_svc
+        .export_data(
+            DataExportSource::Dataset(hdl.as_local_ref()),
+            DataExportOptions {
+                destination: Url::from_directory_path(&dest).unwrap(),
+                format: DataExportFormat::Parquet,
+                compression: None,
+                partition_by: Vec::new(),
+                incremental: false,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_matches!(res, DataExportResult::Exported { num_records: 2, .. });
+
+    // Older slices are exported according to the latest schema
+    let ctx = SessionContext::new();
+    let df = ctx
+        .read_parquet(dest.to_str().unwrap(), ParquetReadOptions::default())
+        .await
+        .unwrap();
+    assert!(df.schema().has_column_with_unqualified_name("state"));
+    assert_eq!(df.count().await.unwrap(), 2);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_export_sql_result() {
+    let harness = DataExportTestHarness::new();
+    let hdl = harness.new_root("foo").await;
+
+    harness
+        .ingest(
+            &hdl,
+            indoc!(
+                "
+                city,population
+                A,1000
+                B,2000
+                C,3000
+                "
+            ),
+        )
+        .await;
+
+    let dest = harness.tempdir.path().join("export");
+    let res = harness
+        .export_svc
+        .export_data(
+            DataExportSource::Sql("select city from foo where population > 1500".to_string()),
+            DataExportOptions {
+                destination: Url::from_directory_path(&dest).unwrap(),
+                format: DataExportFormat::NdJson,
+                compression: None,
+                partition_by: Vec::new(),
+                incremental: false,
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        res,
+        DataExportResult::Exported {
+            num_records: 2,
+            head: None,
+        }
+    );
+
+    let ctx = SessionContext::new();
+    let df = ctx
+        .read_json(dest.to_str().unwrap(), NdJsonReadOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(df.count().await.unwrap(), 2);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_export_rejects_invalid_options() {
+    let harness = DataExportTestHarness::new();
+    let hdl = harness.new_root("foo").await;
+
+    let options = DataExportOptions {
+        destination: Url::from_directory_path(harness.tempdir.path().join("export")).unwrap(),
+        format: DataExportFormat::Arrow,
+        compression: Some(DataExportCompression::Gzip),
+        partition_by: Vec::new(),
+        incremental: false,
+    };
+
+    assert_matches!(
+        harness
+            .export_svc
+            .export_data(
+                DataExportSource::Dataset(hdl.as_local_ref()),
+                options.clone()
+            )
+            .await,
+        Err(DataExportError::InvalidOptions(_))
+    );
+
+    assert_matches!(
+        harness
+            .export_svc
+            .export_data(
+                DataExportSource::Sql("select * from foo".to_string()),
+                DataExportOptions {
+                    format: DataExportFormat::Parquet,
+                    compression: None,
+                    incremental: true,
+                    ..options
+                },
+            )
+            .await,
+        Err(DataExportError::InvalidOptions(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn count_parquet_records(dir: &Path) -> usize {
+    let ctx = SessionContext::new();
+    ctx.read_parquet(dir.to_str().unwrap(), ParquetReadOptions::default())
+        .await
+        .unwrap()
+        .count()
+        .await
+        .unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DataExportTestHarness {
+    tempdir: TempDir,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
+    push_ingest_svc: Arc<dyn PushIngestService>,
+    export_svc: Arc<dyn DataExportService>,
+}
+
+impl DataExportTestHarness {
+    fn new() -> Self {
+        let tempdir = tempfile::tempdir().unwrap();
+        let datasets_dir = tempdir.path().join("datasets");
+        let run_info_dir = tempdir.path().join("run");
+        std::fs::create_dir(&datasets_dir).unwrap();
+        std::fs::create_dir(&run_info_dir).unwrap();
+
+        let catalog = dill::CatalogBuilder::new()
+            .add_value(RunInfoDir::new(run_info_dir))
+            .add_value(CurrentAccountSubject::new_test())
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+            .add_value(SystemTimeSourceStub::new_set(
+                Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap(),
+            ))
+            .bind::<dyn SystemTimeSource, SystemTimeSourceStub>()
+            .add::<ObjectStoreRegistryImpl>()
+            .add::<ObjectStoreBuilderLocalFs>()
+            .add::<DataFormatRegistryImpl>()
+            .add_value(EngineProvisionerNull)
+            .bind::<dyn EngineProvisioner, EngineProvisionerNull>()
+            .add::<PushIngestServiceImpl>()
+            .add::<DummyOutboxImpl>()
+            .add::<QueryServiceImpl>()
+            .add::<DataExportServiceImpl>()
+            .build();
+
+        Self {
+            tempdir,
+            dataset_repo: catalog.get_one().unwrap(),
+            dataset_repo_writer: catalog.get_one().unwrap(),
+            push_ingest_svc: catalog.get_one().unwrap(),
+            export_svc: catalog.get_one().unwrap(),
+        }
+    }
+
+    async fn new_root(&self, name: &str) -> DatasetHandle {
+        let snap = MetadataFactory::dataset_snapshot()
+            .name(name)
+            .kind(DatasetKind::Root)
+            .push_event(
+                MetadataFactory::add_push_source()
+                    .read(ReadStepCsv {
+                        header: Some(true),
+                        schema: Some(vec![
+                            "city STRING".to_string(),
+                            "population BIGINT".to_string(),
+                        ]),
+                        ..ReadStepCsv::default()
+                    })
+                    .merge(MergeStrategyAppend {})
+                    .build(),
+            )
+            .build();
+
+        self.dataset_repo_writer
+            .create_dataset_from_snapshot(snap)
+            .await
+            .unwrap()
+            .create_dataset_result
+            .dataset_handle
+    }
+
+    async fn ingest(&self, hdl: &DatasetHandle, data: &'static str) {
+        self.push_ingest_svc
+            .ingest_from_file_stream(
+                &hdl.as_local_ref(),
+                None,
+                Box::new(std::io::Cursor::new(data)),
+                PushIngestOpts::default(),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn get_head(&self, hdl: &DatasetHandle) -> Multihash {
+        self.dataset_repo
+            .get_dataset_by_handle(hdl)
+            .as_metadata_chain()
+            .resolve_ref(&BlockRef::Head)
+            .await
+            .unwrap()
+    }
+}
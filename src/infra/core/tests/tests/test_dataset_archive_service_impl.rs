@@ -0,0 +1,423 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::io::Read;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use datafusion::arrow::array::{Array, Int32Array, StringArray};
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use datafusion::arrow::record_batch::RecordBatch;
+use dill::Component;
+use kamu::domain::*;
+use kamu::testing::{MetadataFactory, MockDatasetActionAuthorizer, ParquetWriterHelper};
+use kamu::utils::ipfs_wrapper::IpfsClient;
+use kamu::*;
+use kamu_accounts::CurrentAccountSubject;
+use kamu_audit_log::testing::DummyAuditLogService;
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::serde::yaml::Manifest;
+use opendatafabric::*;
+use tempfile::TempDir;
+use time_source::SystemTimeSourceDefault;
+
+use super::test_pull_service_impl::TestTransformService;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_export_import_round_trip() {
+    let src = DatasetArchiveTestHarness::new();
+    let dst = DatasetArchiveTestHarness::new();
+
+    let dataset_alias = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    let head = src.create_dataset_with_data(&dataset_alias).await;
+
+    let archive_path = src.temp_dir.path().join("foo.tar.zst");
+    let export_result = src
+        .dataset_archive_svc
+        .export_archive(&dataset_alias.as_local_ref(), &archive_path)
+        .await
+        .unwrap();
+
+    assert_eq!(export_result.manifest.head, head);
+    assert!(archive_path.is_file());
+    assert!(export_result
+        .manifest
+        .entries
+        .iter()
+        .any(|e| e.path.starts_with("data/")));
+    assert!(export_result
+        .manifest
+        .entries
+        .iter()
+        .any(|e| e.path == "refs/head"));
+
+    let import_result = dst
+        .dataset_archive_svc
+        .import_archive(&archive_path, None)
+        .await
+        .unwrap();
+
+    assert_eq!(import_result.dataset_handle.alias, dataset_alias);
+    assert_eq!(
+        import_result.dataset_handle.id,
+        export_result.dataset_handle.id
+    );
+    assert_matches!(
+        import_result.sync_result,
+        SyncResult::Updated { new_head, .. } if new_head == head
+    );
+    assert_eq!(dst.get_head(&dataset_alias).await, head);
+
+    // Importing the same archive again is a no-op
+    let import_result = dst
+        .dataset_archive_svc
+        .import_archive(&archive_path, None)
+        .await
+        .unwrap();
+    assert_matches!(import_result.sync_result, SyncResult::UpToDate);
+
+    // Import under a different name
+    let other = DatasetArchiveTestHarness::new();
+    let other_alias = DatasetAlias::new(None, DatasetName::new_unchecked("bar"));
+    let import_result = other
+        .dataset_archive_svc
+        .import_archive(&archive_path, Some(other_alias.clone()))
+        .await
+        .unwrap();
+    assert_eq!(import_result.dataset_handle.alias, other_alias);
+    assert_eq!(other.get_head(&other_alias).await, head);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_import_detects_corruption_and_resumes() {
+    let src = DatasetArchiveTestHarness::new();
+    let dst = DatasetArchiveTestHarness::new();
+
+    let dataset_alias = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    let head = src.create_dataset_with_data(&dataset_alias).await;
+
+    let archive_path = src.temp_dir.path().join("foo.tar.zst");
+    src.dataset_archive_svc
+        .export_archive(&dataset_alias.as_local_ref(), &archive_path)
+        .await
+        .unwrap();
+
+    let corrupted_path = src.temp_dir.path().join("foo-corrupted.tar.zst");
+    corrupt_archive(&archive_path, &corrupted_path, Path::new("dataset/data"));
+
+    assert_matches!(
+        dst.dataset_archive_svc
+            .import_archive(&corrupted_path, None)
+            .await,
+        Err(ImportArchiveError::IntegrityCheckFailed(e)) if e.path.starts_with("data/")
+    );
+    assert_matches!(
+        dst.dataset_repo
+            .resolve_dataset_ref(&dataset_alias.as_local_ref())
+            .await,
+        Err(GetDatasetError::NotFound(_))
+    );
+
+    // Files unpacked by the failed attempt are reused
+    dst.dataset_archive_svc
+        .import_archive(&archive_path, None)
+        .await
+        .unwrap();
+    assert_eq!(dst.get_head(&dataset_alias).await, head);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_import_rejects_archive_without_manifest() {
+    let harness = DatasetArchiveTestHarness::new();
+
+    let archive_path = harness.temp_dir.path().join("empty.tar.zst");
+    let file = std::fs::File::create(&archive_path).unwrap();
+    let mut builder = tar::Builder::new(zstd::Encoder::new(file, 0).unwrap());
+    let mut header = tar::Header::new_gnu();
+    header.set_size(3);
+    header.set_cksum();
+    builder
+        .append_data(&mut header, "dataset/refs/head", &b"foo"[..])
+        .unwrap();
+    builder.into_inner().unwrap().finish().unwrap();
+
+    assert_matches!(
+        harness
+            .dataset_archive_svc
+            .import_archive(&archive_path, None)
+            .await,
+        Err(ImportArchiveError::InvalidArchive(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_import_rejects_links() {
+    let src = DatasetArchiveTestHarness::new();
+    let dst = DatasetArchiveTestHarness::new();
+
+    let dataset_alias = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    src.create_dataset_with_data(&dataset_alias).await;
+
+    let archive_path = src.temp_dir.path().join("foo.tar.zst");
+    src.dataset_archive_svc
+        .export_archive(&dataset_alias.as_local_ref(), &archive_path)
+        .await
+        .unwrap();
+
+    for entry_type in [tar::EntryType::Symlink, tar::EntryType::Link] {
+        let rewritten_path = src.temp_dir.path().join("foo-link.tar.zst");
+        rewrite_archive(&archive_path, &rewritten_path, |path, header, data| {
+            if path == Path::new("dataset/refs/head") {
+                header.set_entry_type(entry_type);
+                header.set_link_name("../../outside").unwrap();
+                data.clear();
+            }
+        });
+
+        assert_matches!(
+            dst.dataset_archive_svc
+                .import_archive(&rewritten_path, None)
+                .await,
+            Err(ImportArchiveError::InvalidArchive(_))
+        );
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_import_rejects_head_not_matching_manifest() {
+    let src = DatasetArchiveTestHarness::new();
+    let dst = DatasetArchiveTestHarness::new();
+
+    let dataset_alias = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+    let head = src.create_dataset_with_data(&dataset_alias).await;
+
+    let archive_path = src.temp_dir.path().join("foo.tar.zst");
+    src.dataset_archive_svc
+        .export_archive(&dataset_alias.as_local_ref(), &archive_path)
+        .await
+        .unwrap();
+
+    let wrong_head = Multihash::from_digest_sha3_256(b"foo");
+
+    let rewritten_path = src.temp_dir.path().join("foo-head.tar.zst");
+    rewrite_archive(&archive_path, &rewritten_path, |path, _, data| {
+        if path == Path::new(DATASET_ARCHIVE_MANIFEST_PATH) {
+            let mut manifest: Manifest<DatasetArchiveManifest> =
+                serde_yaml::from_slice(data).unwrap();
+            manifest.content.head = wrong_head.clone();
+            *data = serde_yaml::to_string(&manifest).unwrap().into_bytes();
+        }
+    });
+
+    assert_matches!(
+        dst.dataset_archive_svc
+            .import_archive(&rewritten_path, None)
+            .await,
+        Err(ImportArchiveError::HeadMismatch(e)) if e.expected == wrong_head && e.actual == head
+    );
+
+    // Nothing is imported into the workspace
+    assert_matches!(
+        dst.dataset_repo
+            .try_resolve_dataset_ref(&dataset_alias.as_local_ref())
+            .await,
+        Ok(None)
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_export_requires_read_access() {
+    let dataset_alias = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
+
+    let harness = DatasetArchiveTestHarness::new_with_authorizer(
+        MockDatasetActionAuthorizer::new().expect_check_read_dataset(&dataset_alias, 1, false),
+    );
+    harness.create_dataset_with_data(&dataset_alias).await;
+
+    let archive_path = harness.temp_dir.path().join("foo.tar.zst");
+    assert_matches!(
+        harness
+            .dataset_archive_svc
+            .export_archive(&dataset_alias.as_local_ref(), &archive_path)
+            .await,
+        Err(ExportArchiveError::Access(_))
+    );
+    assert!(!archive_path.exists());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Rewrites the archive replacing contents of the files under the specified
+/// prefix while keeping the original manifest
+fn corrupt_archive(src: &Path, dst: &Path, prefix: &Path) {
+    rewrite_archive(src, dst, |path, _, data| {
+        if path.starts_with(prefix) {
+            *data = b"corrupted".to_vec();
+        }
+    });
+}
+
+/// Copies the archive entry by entry letting the callback modify them
+fn rewrite_archive(
+    src: &Path,
+    dst: &Path,
+    mut rewrite: impl FnMut(&Path, &mut tar::Header, &mut Vec<u8>),
+) {
+    let mut archive =
+        tar::Archive::new(zstd::Decoder::new(std::fs::File::open(src).unwrap()).unwrap());
+    let mut builder =
+        tar::Builder::new(zstd::Encoder::new(std::fs::File::create(dst).unwrap(), 0).unwrap());
+
+    for entry in archive.entries().unwrap() {
+        let mut entry = entry.unwrap();
+        let path = entry.path().unwrap().into_owned();
+        let mut header = entry.header().clone();
+
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data).unwrap();
+
+        rewrite(&path, &mut header, &mut data);
+
+        header.set_size(data.len() as u64);
+        header.set_cksum();
+        builder.append_data(&mut header, &path, &data[..]).unwrap();
+    }
+
+    builder.into_inner().unwrap().finish().unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DatasetArchiveTestHarness {
+    temp_dir: TempDir,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
+    dataset_archive_svc: Arc<dyn DatasetArchiveService>,
+}
+
+impl DatasetArchiveTestHarness {
+    fn new() -> Self {
+        Self::new_with_authorizer(MockDatasetActionAuthorizer::allowing())
+    }
+
+    fn new_with_authorizer(dataset_action_authorizer: MockDatasetActionAuthorizer) -> Self {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let datasets_dir = temp_dir.path().join("datasets");
+        let run_info_dir = temp_dir.path().join("run");
+        std::fs::create_dir(&datasets_dir).unwrap();
+        std::fs::create_dir(&run_info_dir).unwrap();
+
+        let catalog = dill::CatalogBuilder::new()
+            .add::<SystemTimeSourceDefault>()
+            .add_value(RunInfoDir::new(run_info_dir))
+            .add_value(IpfsGateway::default())
+            .add_value(IpfsClient::default())
+            .add_value(CurrentAccountSubject::new_test())
+            .add_value(dataset_action_authorizer)
+            .bind::<dyn auth::DatasetActionAuthorizer, MockDatasetActionAuthorizer>()
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .add_value(RemoteReposDir::new(temp_dir.path().join("repos")))
+            .add::<RemoteRepositoryRegistryImpl>()
+            .add::<auth::DummyOdfServerAccessTokenResolver>()
+            .add::<DatasetFactoryImpl>()
+            .add::<SyncServiceImpl>()
+            .add::<DummySmartTransferProtocolClient>()
+            .add::<CreateDatasetUseCaseImpl>()
+            .add::<DummyOutboxImpl>()
+            .add_value(TestTransformService::new(Arc::new(Mutex::new(Vec::new()))))
+            .bind::<dyn TransformService, TestTransformService>()
+            .add::<VerificationServiceImpl>()
+            .add::<DeleteDatasetUseCaseImpl>()
+            .add::<DependencyGraphServiceInMemory>()
+            .add::<DummyAuditLogService>()
+            .add::<DatasetArchiveServiceImpl>()
+            .build();
+
+        Self {
+            temp_dir,
+            dataset_repo: catalog.get_one().unwrap(),
+            dataset_repo_writer: catalog.get_one().unwrap(),
+            dataset_archive_svc: catalog.get_one().unwrap(),
+        }
+    }
+
+    async fn create_dataset_with_data(&self, dataset_alias: &DatasetAlias) -> Multihash {
+        let created = self
+            .dataset_repo_writer
+            .create_dataset_from_snapshot(
+                MetadataFactory::dataset_snapshot()
+                    .name(dataset_alias.clone())
+                    .kind(DatasetKind::Root)
+                    .push_event(MetadataFactory::set_polling_source().build())
+                    .build(),
+            )
+            .await
+            .unwrap();
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]));
+        let a: Arc<dyn Array> = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let b: Arc<dyn Array> = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let record_batch = RecordBatch::try_new(schema, vec![a, b]).unwrap();
+
+        let data_path = self.temp_dir.path().join("data.parquet");
+        ParquetWriterHelper::from_record_batch(&data_path, &record_batch).unwrap();
+
+        created
+            .create_dataset_result
+            .dataset
+            .commit_add_data(
+                AddDataParams {
+                    prev_checkpoint: None,
+                    prev_offset: None,
+                    new_offset_interval: Some(OffsetInterval { start: 0, end: 2 }),
+                    new_watermark: None,
+                    new_source_state: None,
+                },
+                Some(OwnedFile::new(data_path)),
+                None,
+                CommitOpts::default(),
+            )
+            .await
+            .unwrap()
+            .new_head
+    }
+
+    async fn get_head(&self, dataset_alias: &DatasetAlias) -> Multihash {
+        self.dataset_repo
+            .find_dataset_by_ref(&dataset_alias.as_local_ref())
+            .await
+            .unwrap()
+            .as_metadata_chain()
+            .resolve_ref(&BlockRef::Head)
+            .await
+            .unwrap()
+    }
+}
@@ -0,0 +1,504 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use indoc::indoc;
+use kamu::domain::*;
+use kamu::testing::*;
+use kamu::*;
+use kamu_accounts::CurrentAccountSubject;
+use kamu_data_utils::testing::assert_data_eq;
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::*;
+use tempfile::TempDir;
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_diff_blocks_by_primary_key() {
+    let harness = DiffTestHarness::new();
+    let hdl = harness
+        .new_root(
+            "foo",
+            ["city STRING", "population BIGINT"],
+            MergeStrategySnapshot {
+                primary_key: vec!["city".to_string()],
+                compare_columns: None,
+            },
+        )
+        .await;
+
+    harness
+        .ingest(&hdl, "city,population\nA,1000\nB,2000\nC,3000\n")
+        .await;
+    let old_head = harness.get_head(&hdl).await;
+
+    harness
+        .ingest(&hdl, "city,population\nA,1000\nB,2500\nD,4000\n")
+        .await;
+    let new_head = harness.get_head(&hdl).await;
+
+    let res = harness
+        .diff_svc
+        .diff_blocks(
+            &hdl.as_local_ref(),
+            Some(&old_head),
+            Some(&new_head),
+            DiffOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.key, DiffKey::PrimaryKey(vec!["city".to_string()]));
+    assert_data_eq(
+        res.df,
+        indoc!(
+            r#"
+            +----+----------------------+------+------------+
+            | op | event_time           | city | population |
+            +----+----------------------+------+------------+
+            | 2  | 2050-01-01T12:00:00Z | B    | 2000       |
+            | 3  | 2050-01-01T12:00:00Z | B    | 2500       |
+            | 1  | 2050-01-01T12:00:00Z | C    | 3000       |
+            | 0  | 2050-01-01T12:00:00Z | D    | 4000       |
+            +----+----------------------+------+------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_diff_blocks_by_offset() {
+    let harness = DiffTestHarness::new();
+    let hdl = harness
+        .new_root(
+            "foo",
+            ["city STRING", "population BIGINT"],
+            MergeStrategyAppend {},
+        )
+        .await;
+
+    harness
+        .ingest(&hdl, "city,population\nA,1000\nB,2000\n")
+        .await;
+    let old_head = harness.get_head(&hdl).await;
+
+    harness.ingest(&hdl, "city,population\nC,3000\n").await;
+
+    // New head defaults to the current one
+    let res = harness
+        .diff_svc
+        .diff_blocks(
+            &hdl.as_local_ref(),
+            Some(&old_head),
+            None,
+            DiffOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.key, DiffKey::Offset);
+    assert_data_eq(
+        res.df,
+        indoc!(
+            r#"
+            +----+--------+----------------------+------+------------+
+            | op | offset | event_time           | city | population |
+            +----+--------+----------------------+------+------------+
+            | 0  | 2      | 2050-01-01T12:00:00Z | C    | 3000       |
+            +----+--------+----------------------+------+------------+
+            "#
+        ),
+    )
+    .await;
+
+    // Old head defaults to an empty state
+    let res = harness
+        .diff_svc
+        .diff_blocks(&hdl.as_local_ref(), None, None, DiffOptions::default())
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        res.df,
+        indoc!(
+            r#"
+            +----+--------+----------------------+------+------------+
+            | op | offset | event_time           | city | population |
+            +----+--------+----------------------+------+------------+
+            | 0  | 0      | 2050-01-01T12:00:00Z | A    | 1000       |
+            | 0  | 1      | 2050-01-01T12:00:00Z | B    | 2000       |
+            | 0  | 2      | 2050-01-01T12:00:00Z | C    | 3000       |
+            +----+--------+----------------------+------+------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_diff_blocks_evolved_schema() {
+    let harness = DiffTestHarness::new();
+    let hdl = harness
+        .new_root(
+            "foo",
+            ["city STRING", "population BIGINT"],
+            MergeStrategyAppend {},
+        )
+        .await;
+
+    harness
+        .ingest(&hdl, "city,population\nA,1000\nB,2000\n")
+        .await;
+    let old_head = harness.get_head(&hdl).await;
+
+    harness
+        .dataset_repo
+        .get_dataset_by_handle(&hdl)
+        .commit_event(
+            MetadataFactory::add_push_source()
+                .source_name("evolved")
+                .read(ReadStepCsv {
+                    header: Some(true),
+                    schema: Some(
+                        ["city STRING", "population BIGINT", "state STRING"]
+                            .iter()
+                            .map(|s| (*s).to_string())
+                            .collect(),
+                    ),
+                    ..ReadStepCsv::default()
+                })
+                .merge(MergeStrategyAppend {})
+                .build()
+                .into(),
+            CommitOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    harness
+        .push_ingest_svc
+        .ingest_from_file_stream(
+            &hdl.as_local_ref(),
+            Some("evolved"),
+            Box::new(std::io::Cursor::new("city,population,state\nC,3000,X\n")),
+            PushIngestOpts {
+                schema_evolution: SchemaEvolutionRules {
+                    add_nullable_columns: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Slices written before the schema evolved are read with the latest schema
+    let res = harness
+        .diff_svc
+        .diff_blocks(
+            &hdl.as_local_ref(),
+            Some(&old_head),
+            None,
+            DiffOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_data_eq(
+        res.df,
+        indoc!(
+            r#"
+            +----+--------+----------------------+------+------------+-------+
+            | op | offset | event_time           | city | population | state |
+            +----+--------+----------------------+------+------------+-------+
+            | 0  | 2      | 2050-01-01T12:00:00Z | C    | 3000       | X     |
+            +----+--------+----------------------+------+------------+-------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_diff_blocks_not_ancestor() {
+    let harness = DiffTestHarness::new();
+    let hdl = harness
+        .new_root(
+            "foo",
+            ["city STRING", "population BIGINT"],
+            MergeStrategyAppend {},
+        )
+        .await;
+
+    harness.ingest(&hdl, "city,population\nA,1000\n").await;
+    let old_head = harness.get_head(&hdl).await;
+
+    harness.ingest(&hdl, "city,population\nB,2000\n").await;
+    let new_head = harness.get_head(&hdl).await;
+
+    // Heads are swapped
+    assert_matches!(
+        harness
+            .diff_svc
+            .diff_blocks(
+                &hdl.as_local_ref(),
+                Some(&new_head),
+                Some(&old_head),
+                DiffOptions::default(),
+            )
+            .await,
+        Err(DiffError::InvalidInterval(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_diff_blocks_not_found() {
+    let harness = DiffTestHarness::new();
+    let hdl = harness
+        .new_root(
+            "foo",
+            ["city STRING", "population BIGINT"],
+            MergeStrategyAppend {},
+        )
+        .await;
+
+    assert_matches!(
+        harness
+            .diff_svc
+            .diff_blocks(
+                &hdl.as_local_ref(),
+                Some(&Multihash::from_digest_sha3_256(b"foo")),
+                None,
+                DiffOptions::default(),
+            )
+            .await,
+        Err(DiffError::BlockNotFound(_))
+    );
+
+    assert_matches!(
+        harness
+            .diff_svc
+            .diff_blocks(&hdl.as_local_ref(), None, None, DiffOptions::default())
+            .await,
+        Err(DiffError::DatasetSchemaNotAvailable(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_diff_datasets() {
+    let harness = DiffTestHarness::new();
+    let foo = harness
+        .new_root(
+            "foo",
+            ["city STRING", "population BIGINT"],
+            MergeStrategyAppend {},
+        )
+        .await;
+    let bar = harness
+        .new_root(
+            "bar",
+            ["city STRING", "population BIGINT"],
+            MergeStrategyAppend {},
+        )
+        .await;
+
+    harness
+        .ingest(&foo, "city,population\nA,1000\nB,2000\nC,3000\n")
+        .await;
+    harness
+        .ingest(&bar, "city,population\nA,1000\nB,2500\nD,4000\n")
+        .await;
+
+    let res = harness
+        .diff_svc
+        .diff_datasets(
+            &foo.as_local_ref(),
+            &bar.as_local_ref(),
+            DiffOptions {
+                primary_key: Some(vec!["city".to_string()]),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.key, DiffKey::PrimaryKey(vec!["city".to_string()]));
+    assert_data_eq(
+        res.df,
+        indoc!(
+            r#"
+            +----+----------------------+------+------------+
+            | op | event_time           | city | population |
+            +----+----------------------+------+------------+
+            | 2  | 2050-01-01T12:00:00Z | B    | 2000       |
+            | 3  | 2050-01-01T12:00:00Z | B    | 2500       |
+            | 1  | 2050-01-01T12:00:00Z | C    | 3000       |
+            | 0  | 2050-01-01T12:00:00Z | D    | 4000       |
+            +----+----------------------+------+------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_diff_datasets_schema_mismatch() {
+    let harness = DiffTestHarness::new();
+    let foo = harness
+        .new_root(
+            "foo",
+            ["city STRING", "population BIGINT"],
+            MergeStrategyAppend {},
+        )
+        .await;
+    let bar = harness
+        .new_root(
+            "bar",
+            ["city STRING", "population STRING"],
+            MergeStrategyAppend {},
+        )
+        .await;
+
+    harness.ingest(&foo, "city,population\nA,1000\n").await;
+    harness.ingest(&bar, "city,population\nA,1000\n").await;
+
+    assert_matches!(
+        harness
+            .diff_svc
+            .diff_datasets(
+                &foo.as_local_ref(),
+                &bar.as_local_ref(),
+                DiffOptions::default(),
+            )
+            .await,
+        Err(DiffError::SchemaMismatch(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct DiffTestHarness {
+    _tempdir: TempDir,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
+    push_ingest_svc: Arc<dyn PushIngestService>,
+    diff_svc: Arc<dyn DatasetDiffService>,
+}
+
+impl DiffTestHarness {
+    fn new() -> Self {
+        let tempdir = tempfile::tempdir().unwrap();
+        let datasets_dir = tempdir.path().join("datasets");
+        let run_info_dir = tempdir.path().join("run");
+        std::fs::create_dir(&datasets_dir).unwrap();
+        std::fs::create_dir(&run_info_dir).unwrap();
+
+        let catalog = dill::CatalogBuilder::new()
+            .add_value(RunInfoDir::new(run_info_dir))
+            .add_value(CurrentAccountSubject::new_test())
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+            .add_value(SystemTimeSourceStub::new_set(
+                Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap(),
+            ))
+            .bind::<dyn SystemTimeSource, SystemTimeSourceStub>()
+            .add::<ObjectStoreRegistryImpl>()
+            .add::<ObjectStoreBuilderLocalFs>()
+            .add::<DataFormatRegistryImpl>()
+            .add_value(EngineProvisionerNull)
+            .bind::<dyn EngineProvisioner, EngineProvisionerNull>()
+            .add::<PushIngestServiceImpl>()
+            .add::<DummyOutboxImpl>()
+            .add::<DatasetDiffServiceImpl>()
+            .build();
+
+        Self {
+            _tempdir: tempdir,
+            dataset_repo: catalog.get_one().unwrap(),
+            dataset_repo_writer: catalog.get_one().unwrap(),
+            push_ingest_svc: catalog.get_one().unwrap(),
+            diff_svc: catalog.get_one().unwrap(),
+        }
+    }
+
+    async fn new_root<const N: usize>(
+        &self,
+        name: &str,
+        schema: [&str; N],
+        merge: impl Into<opendatafabric::MergeStrategy>,
+    ) -> DatasetHandle {
+        let snap = MetadataFactory::dataset_snapshot()
+            .name(name)
+            .kind(DatasetKind::Root)
+            .push_event(
+                MetadataFactory::add_push_source()
+                    .read(ReadStepCsv {
+                        header: Some(true),
+                        schema: Some(schema.iter().map(|s| (*s).to_string()).collect()),
+                        ..ReadStepCsv::default()
+                    })
+                    .merge(merge)
+                    .build(),
+            )
+            .build();
+
+        self.dataset_repo_writer
+            .create_dataset_from_snapshot(snap)
+            .await
+            .unwrap()
+            .create_dataset_result
+            .dataset_handle
+    }
+
+    async fn ingest(&self, hdl: &DatasetHandle, data: &'static str) {
+        self.push_ingest_svc
+            .ingest_from_file_stream(
+                &hdl.as_local_ref(),
+                None,
+                Box::new(std::io::Cursor::new(data)),
+                PushIngestOpts::default(),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn get_head(&self, hdl: &DatasetHandle) -> Multihash {
+        self.dataset_repo
+            .get_dataset_by_handle(hdl)
+            .as_metadata_chain()
+            .resolve_ref(&BlockRef::Head)
+            .await
+            .unwrap()
+    }
+}
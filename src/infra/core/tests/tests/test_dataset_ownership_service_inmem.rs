@@ -34,6 +34,7 @@ use kamu_accounts_services::{
     LoginPasswordAuthProvider,
     PredefinedAccountsRegistrator,
 };
+use kamu_audit_log::testing::DummyAuditLogService;
 use kamu_core::{DatasetOwnershipService, DatasetRepository};
 use opendatafabric::{AccountID, AccountName, DatasetAlias, DatasetID, DatasetKind, DatasetName};
 use tempfile::TempDir;
@@ -123,6 +124,7 @@ impl DatasetOwnershipHarness {
                 .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
                 .add_value(CurrentAccountSubject::new_test())
                 .add::<AccessTokenServiceImpl>()
+                .add::<DummyAuditLogService>()
                 .add::<AuthenticationServiceImpl>()
                 .add_value(predefined_accounts_config.clone())
                 .add_value(JwtAuthenticationConfig::default())
@@ -16,6 +16,7 @@ use internal_error::ResultIntoInternal;
 use kamu::testing::MetadataFactory;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
+use kamu_audit_log::testing::DummyAuditLogService;
 use kamu_core::*;
 use messaging_outbox::{register_message_dispatcher, Outbox, OutboxImmediateImpl};
 use opendatafabric::*;
@@ -637,7 +638,8 @@ impl DependencyGraphHarness {
             .add::<DependencyGraphServiceInMemory>()
             .add::<CreateDatasetFromSnapshotUseCaseImpl>()
             .add::<CommitDatasetEventUseCaseImpl>()
-            .add::<DeleteDatasetUseCaseImpl>();
+            .add::<DeleteDatasetUseCaseImpl>()
+            .add::<DummyAuditLogService>();
 
         register_message_dispatcher::<DatasetLifecycleMessage>(
             &mut b,
@@ -0,0 +1,507 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use datafusion::prelude::*;
+use indoc::indoc;
+use kamu::domain::*;
+use kamu::testing::*;
+use kamu::*;
+use kamu_accounts::CurrentAccountSubject;
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::*;
+use tempfile::TempDir;
+use time_source::SystemTimeSourceDefault;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_column_lineage_of_root_dataset() {
+    let harness = ProvenanceTestHarness::new();
+    let root = harness.new_root("foo").await;
+
+    let lineage = harness
+        .provenance_svc
+        .get_column_lineage(&root.as_local_ref())
+        .await
+        .unwrap();
+
+    assert_eq!(lineage, None);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_column_lineage_of_projection() {
+    let harness = ProvenanceTestHarness::new();
+    let foo = harness.new_root("foo").await;
+
+    let deriv = harness
+        .new_deriv(
+            "bar",
+            MetadataFactory::set_transform()
+                .inputs_from_refs([&foo.alias])
+                .transform(
+                    MetadataFactory::transform()
+                        .query(
+                            "select city, population * 2 as double_population, 'x' as tag from \
+                             foo where population > 0",
+                        )
+                        .build(),
+                )
+                .build(),
+        )
+        .await;
+
+    let lineage = harness
+        .provenance_svc
+        .get_column_lineage(&deriv.as_local_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        harness.flatten(&lineage),
+        vec![
+            ("city", vec![("foo", "city")]),
+            ("double_population", vec![("foo", "population")]),
+            ("tag", vec![]),
+        ]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_column_lineage_of_multi_step_join() {
+    let harness = ProvenanceTestHarness::new();
+    let foo = harness.new_root("foo").await;
+    let bar = harness.new_root("bar").await;
+
+    let deriv = harness
+        .new_deriv(
+            "baz",
+            MetadataFactory::set_transform()
+                .inputs_from_refs_and_aliases([(&foo.alias, "a"), (&bar.alias, "b")])
+                .transform(Transform::Sql(TransformSql {
+                    engine: "datafusion".to_string(),
+                    version: None,
+                    query: None,
+                    queries: Some(vec![
+                        SqlQueryStep {
+                            alias: Some("totals".to_string()),
+                            query: "select city, sum(population) as total from a group by city"
+                                .to_string(),
+                        },
+                        SqlQueryStep {
+                            alias: None,
+                            query: "select t.city, t.total + b.population as combined from totals \
+                                    as t join b on t.city = b.city"
+                                .to_string(),
+                        },
+                    ]),
+                    temporal_tables: None,
+                }))
+                .build(),
+        )
+        .await;
+
+    let lineage = harness
+        .provenance_svc
+        .get_column_lineage(&deriv.as_local_ref())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        harness.flatten(&lineage),
+        vec![
+            ("city", vec![("foo", "city")]),
+            (
+                "combined",
+                vec![("foo", "population"), ("bar", "population")]
+            ),
+        ]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_column_lineage_unsupported_query() {
+    let harness = ProvenanceTestHarness::new();
+    let foo = harness.new_root("foo").await;
+
+    let deriv = harness
+        .new_deriv(
+            "bar",
+            MetadataFactory::set_transform()
+                .inputs_from_refs([&foo.alias])
+                .transform(
+                    MetadataFactory::transform()
+                        .query("select unknown_column from foo")
+                        .build(),
+                )
+                .build(),
+        )
+        .await;
+
+    let res = harness
+        .provenance_svc
+        .get_column_lineage(&deriv.as_local_ref())
+        .await;
+
+    assert_matches!(res, Err(GetColumnLineageError::UnsupportedQuery(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_record_provenance_of_root_dataset() {
+    let harness = ProvenanceTestHarness::new();
+    let foo = harness.new_root_with_data("foo").await;
+
+    let head = harness.get_head(&foo).await;
+
+    let provenance = harness
+        .provenance_svc
+        .get_record_provenance(&foo.as_local_ref(), 4)
+        .await
+        .unwrap();
+
+    assert_eq!(provenance.block_hash, head);
+    assert_eq!(
+        provenance.offset_interval,
+        OffsetInterval { start: 3, end: 5 }
+    );
+    assert!(provenance.inputs.is_empty());
+
+    assert_matches!(
+        harness
+            .provenance_svc
+            .get_record_provenance(&foo.as_local_ref(), 6)
+            .await,
+        Err(GetRecordProvenanceError::RecordNotFound(
+            RecordNotFoundError { offset: 6, .. }
+        ))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_record_provenance_of_filter() {
+    let harness = ProvenanceTestHarness::new();
+    let foo = harness.new_root_with_data("foo").await;
+    let foo_head = harness.get_head(&foo).await;
+
+    let bar = harness
+        .new_deriv(
+            "bar",
+            MetadataFactory::set_transform()
+                .inputs_from_refs([&foo.alias])
+                .transform(
+                    MetadataFactory::transform()
+                        .query("select city, population from foo where population > 2500")
+                        .build(),
+                )
+                .build(),
+        )
+        .await;
+
+    let bar_head = harness
+        .append_event(
+            &bar,
+            MetadataFactory::execute_transform()
+                .push_query_input(ExecuteTransformInput {
+                    dataset_id: foo.id.clone(),
+                    prev_block_hash: None,
+                    new_block_hash: Some(foo_head.clone()),
+                    prev_offset: None,
+                    new_offset: Some(5),
+                })
+                .some_new_data_with_offset(0, 3)
+                .build(),
+        )
+        .await;
+
+    let provenance = harness
+        .provenance_svc
+        .get_record_provenance(&bar.as_local_ref(), 1)
+        .await
+        .unwrap();
+
+    assert_eq!(provenance.block_hash, bar_head);
+    assert_eq!(
+        provenance.offset_interval,
+        OffsetInterval { start: 0, end: 3 }
+    );
+    assert_eq!(provenance.inputs.len(), 1);
+
+    let input = provenance.inputs.into_iter().next().unwrap();
+    assert_eq!(input.dataset, foo);
+    assert_eq!(input.prev_block_hash, None);
+    assert_eq!(input.new_block_hash, Some(foo_head));
+    assert_eq!(
+        input.offset_interval,
+        Some(OffsetInterval { start: 0, end: 5 })
+    );
+
+    let records = input
+        .possible_source_records
+        .unwrap()
+        .select(vec![col("offset"), col("city"), col("population")])
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        records,
+        indoc!(
+            r#"
+            +--------+------+------------+
+            | offset | city | population |
+            +--------+------+------------+
+            | 2      | C    | 3000       |
+            | 3      | A    | 4000       |
+            | 4      | B    | 5000       |
+            | 5      | C    | 6000       |
+            +--------+------+------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_record_provenance_of_aggregation_has_no_possible_sources() {
+    let harness = ProvenanceTestHarness::new();
+    let foo = harness.new_root_with_data("foo").await;
+    let foo_head = harness.get_head(&foo).await;
+
+    let bar = harness
+        .new_deriv(
+            "bar",
+            MetadataFactory::set_transform()
+                .inputs_from_refs([&foo.alias])
+                .transform(
+                    MetadataFactory::transform()
+                        .query("select city, sum(population) as population from foo group by city")
+                        .build(),
+                )
+                .build(),
+        )
+        .await;
+
+    harness
+        .append_event(
+            &bar,
+            MetadataFactory::execute_transform()
+                .push_query_input(ExecuteTransformInput {
+                    dataset_id: foo.id.clone(),
+                    prev_block_hash: None,
+                    new_block_hash: Some(foo_head),
+                    prev_offset: None,
+                    new_offset: Some(5),
+                })
+                .some_new_data_with_offset(0, 2)
+                .build(),
+        )
+        .await;
+
+    let provenance = harness
+        .provenance_svc
+        .get_record_provenance(&bar.as_local_ref(), 0)
+        .await
+        .unwrap();
+
+    assert_eq!(provenance.inputs.len(), 1);
+    assert_eq!(
+        provenance.inputs[0].offset_interval,
+        Some(OffsetInterval { start: 0, end: 5 })
+    );
+    assert!(provenance.inputs[0].possible_source_records.is_none());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct ProvenanceTestHarness {
+    _tempdir: TempDir,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
+    push_ingest_svc: Arc<dyn PushIngestService>,
+    provenance_svc: Arc<dyn ProvenanceService>,
+}
+
+impl ProvenanceTestHarness {
+    fn new() -> Self {
+        let tempdir = tempfile::tempdir().unwrap();
+        let datasets_dir = tempdir.path().join("datasets");
+        let run_info_dir = tempdir.path().join("run");
+        std::fs::create_dir(&datasets_dir).unwrap();
+        std::fs::create_dir(&run_info_dir).unwrap();
+
+        let catalog = dill::CatalogBuilder::new()
+            .add_value(RunInfoDir::new(run_info_dir))
+            .add_value(CurrentAccountSubject::new_test())
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+            .add::<SystemTimeSourceDefault>()
+            .add::<ObjectStoreRegistryImpl>()
+            .add::<ObjectStoreBuilderLocalFs>()
+            .add::<DataFormatRegistryImpl>()
+            .add_value(EngineProvisionerNull)
+            .bind::<dyn EngineProvisioner, EngineProvisionerNull>()
+            .add::<PushIngestServiceImpl>()
+            .add::<DummyOutboxImpl>()
+            .add::<ProvenanceServiceImpl>()
+            .build();
+
+        Self {
+            _tempdir: tempdir,
+            dataset_repo: catalog.get_one().unwrap(),
+            dataset_repo_writer: catalog.get_one().unwrap(),
+            push_ingest_svc: catalog.get_one().unwrap(),
+            provenance_svc: catalog.get_one().unwrap(),
+        }
+    }
+
+    async fn new_root_with_data(&self, name: &str) -> DatasetHandle {
+        let snap = MetadataFactory::dataset_snapshot()
+            .name(name)
+            .kind(DatasetKind::Root)
+            .push_event(
+                MetadataFactory::add_push_source()
+                    .read(ReadStepCsv {
+                        header: Some(true),
+                        schema: Some(
+                            ["city STRING", "population BIGINT"]
+                                .iter()
+                                .map(|s| (*s).to_string())
+                                .collect(),
+                        ),
+                        ..ReadStepCsv::default()
+                    })
+                    .build(),
+            )
+            .build();
+
+        let hdl = self
+            .dataset_repo_writer
+            .create_dataset_from_snapshot(snap)
+            .await
+            .unwrap()
+            .create_dataset_result
+            .dataset_handle;
+
+        for data in [
+            "city,population\nA,1000\nB,2000\nC,3000\n",
+            "city,population\nA,4000\nB,5000\nC,6000\n",
+        ] {
+            self.push_ingest_svc
+                .ingest_from_file_stream(
+                    &hdl.as_local_ref(),
+                    None,
+                    Box::new(std::io::Cursor::new(data)),
+                    PushIngestOpts::default(),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        hdl
+    }
+
+    async fn get_head(&self, hdl: &DatasetHandle) -> Multihash {
+        self.dataset_repo
+            .get_dataset_by_handle(hdl)
+            .as_metadata_chain()
+            .resolve_ref(&BlockRef::Head)
+            .await
+            .unwrap()
+    }
+
+    async fn append_event(
+        &self,
+        hdl: &DatasetHandle,
+        event: impl Into<MetadataEvent>,
+    ) -> Multihash {
+        let dataset = self.dataset_repo.get_dataset_by_handle(hdl);
+        let chain = dataset.as_metadata_chain();
+
+        let prev_head = chain.resolve_ref(&BlockRef::Head).await.unwrap();
+        let prev_block = chain.get_block(&prev_head).await.unwrap();
+
+        chain
+            .append(
+                MetadataFactory::metadata_block(event.into())
+                    .prev(&prev_head, prev_block.sequence_number)
+                    .build(),
+                AppendOpts::default(),
+            )
+            .await
+            .unwrap()
+    }
+
+    async fn new_root(&self, name: &str) -> DatasetHandle {
+        let snap = MetadataFactory::dataset_snapshot()
+            .name(name)
+            .kind(DatasetKind::Root)
+            .push_event(MetadataFactory::set_data_schema().build())
+            .build();
+
+        self.dataset_repo_writer
+            .create_dataset_from_snapshot(snap)
+            .await
+            .unwrap()
+            .create_dataset_result
+            .dataset_handle
+    }
+
+    async fn new_deriv(&self, name: &str, transform: SetTransform) -> DatasetHandle {
+        let snap = MetadataFactory::dataset_snapshot()
+            .name(name)
+            .kind(DatasetKind::Derivative)
+            .push_event(transform)
+            .push_event(MetadataFactory::set_data_schema().build())
+            .build();
+
+        self.dataset_repo_writer
+            .create_dataset_from_snapshot(snap)
+            .await
+            .unwrap()
+            .create_dataset_result
+            .dataset_handle
+    }
+
+    fn flatten<'a>(&self, lineage: &'a ColumnLineage) -> Vec<(&'a str, Vec<(&'a str, &'a str)>)> {
+        lineage
+            .columns
+            .iter()
+            .map(|c| {
+                (
+                    c.name.as_str(),
+                    c.sources
+                        .iter()
+                        .map(|s| (s.dataset.alias.dataset_name.as_str(), s.column.as_str()))
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+}
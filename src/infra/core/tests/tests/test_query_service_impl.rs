@@ -17,6 +17,7 @@ use datafusion::arrow::array::*;
 use datafusion::arrow::datatypes::{DataType, Field, Schema};
 use datafusion::arrow::record_batch::RecordBatch;
 use dill::{Catalog, Component};
+use futures::TryStreamExt;
 use kamu::domain::*;
 use kamu::testing::{
     LocalS3Server,
@@ -1313,3 +1314,863 @@ async fn test_sql_statement_with_state_cte() {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_rewrite_time_travel() {
+    let hash = Multihash::from_digest_sha3_256(b"foo");
+
+    assert_eq!(
+        rewrite_time_travel("select * from foo").unwrap(),
+        "select * from foo"
+    );
+
+    assert_eq!(
+        rewrite_time_travel(&format!(
+            "select * from \"my.foo\" as of block '{}' where x = 'it''s'",
+            hash.as_multibase()
+        ))
+        .unwrap(),
+        format!(
+            "select * from \"my.foo@block:{}\" AS \"my.foo\" where x = 'it''s'",
+            hash.as_multibase()
+        )
+    );
+
+    assert_eq!(
+        rewrite_time_travel(
+            "select * from kamu.foo AS OF SYSTEM TIME '2024-01-01' f join bar on f.id = bar.id"
+        )
+        .unwrap(),
+        "select * from kamu.\"foo@time:2024-01-01T00:00:00Z\" f join bar on f.id = bar.id"
+    );
+
+    assert_matches!(
+        rewrite_time_travel("select * from foo as of block 'zzz'"),
+        Err(datafusion::common::DataFusionError::Plan(_))
+    );
+
+    assert_eq!(
+        TimeTravel::from_table_name(&format!("my.foo@block:{}", hash.as_multibase())),
+        Some(("my.foo", TimeTravel::Block(hash)))
+    );
+    assert_eq!(TimeTravel::from_table_name("my.foo"), None);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_time_travel_by_block() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let create_result = create_test_dataset(&catalog, tempdir.path()).await;
+
+    // Hash of the block that added the first data slice
+    let blocks: Vec<_> = create_result
+        .dataset
+        .as_metadata_chain()
+        .iter_blocks()
+        .try_collect()
+        .await
+        .unwrap();
+    let first_data_block_hash = blocks[1].0.clone();
+
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+    let res = query_svc
+        .sql_statement(
+            &format!("select blah from foo as of block '{first_data_block_hash}' order by blah"),
+            QueryOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        res.state.input_datasets,
+        BTreeMap::from([(
+            create_result.dataset_handle.id.clone(),
+            QueryStateDataset {
+                alias: "foo".to_string(),
+                block_hash: first_data_block_hash.clone(),
+            }
+        )])
+    );
+
+    kamu_data_utils::testing::assert_data_eq(
+        res.df,
+        indoc::indoc!(
+            r#"
+            +------+
+            | blah |
+            +------+
+            | a    |
+            | b    |
+            +------+
+            "#
+        ),
+    )
+    .await;
+
+    // Explicit state in options has to agree with the time travel clause
+    let res = query_svc
+        .sql_statement(
+            &format!(
+                "select count(*) as num_records from foo as of block '{first_data_block_hash}'"
+            ),
+            QueryOptions {
+                input_datasets: BTreeMap::from([(
+                    create_result.dataset_handle.id.clone(),
+                    QueryOptionsDataset {
+                        alias: "foo".to_string(),
+                        block_hash: Some(first_data_block_hash.clone()),
+                        ..Default::default()
+                    },
+                )]),
+            },
+        )
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        res.df,
+        indoc::indoc!(
+            r#"
+            +-------------+
+            | num_records |
+            +-------------+
+            | 2           |
+            +-------------+
+            "#
+        ),
+    )
+    .await;
+
+    let block_hash = Multihash::from_digest_sha3_256(b"does-not-exist");
+    let res = query_svc
+        .sql_statement(
+            &format!("select * from foo as of block '{block_hash}'"),
+            QueryOptions::default(),
+        )
+        .await;
+
+    assert_matches!(res, Err(QueryError::DatasetBlockNotFound(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_time_travel_by_system_time() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let create_result = create_test_dataset(&catalog, tempdir.path()).await;
+    let head = create_result
+        .dataset
+        .as_metadata_chain()
+        .resolve_ref(&BlockRef::Head)
+        .await
+        .unwrap();
+
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+    let res = query_svc
+        .sql_statement(
+            "select count(*) as num_records from foo as of system time '2100-01-01'",
+            QueryOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        res.state
+            .input_datasets
+            .get(&create_result.dataset_handle.id)
+            .unwrap()
+            .block_hash,
+        head
+    );
+
+    kamu_data_utils::testing::assert_data_eq(
+        res.df,
+        indoc::indoc!(
+            r#"
+            +-------------+
+            | num_records |
+            +-------------+
+            | 4           |
+            +-------------+
+            "#
+        ),
+    )
+    .await;
+
+    let res = query_svc
+        .sql_statement(
+            "select count(*) from foo as of system time '2000-01-01'",
+            QueryOptions::default(),
+        )
+        .await;
+
+    assert_matches!(
+        res,
+        Err(QueryError::DataFusionError(DataFusionError {
+            source: datafusion::common::DataFusionError::Plan(s),
+            ..
+        })) if s.contains("has no state as of")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_time_travel_conflicting_states_with_options() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let create_result = create_test_dataset(&catalog, tempdir.path()).await;
+
+    let blocks: Vec<_> = create_result
+        .dataset
+        .as_metadata_chain()
+        .iter_blocks()
+        .try_collect()
+        .await
+        .unwrap();
+    let head = blocks[0].0.clone();
+    let first_data_block_hash = blocks[1].0.clone();
+
+    let options = QueryOptions {
+        input_datasets: BTreeMap::from([(
+            create_result.dataset_handle.id.clone(),
+            QueryOptionsDataset {
+                alias: "foo".to_string(),
+                ..Default::default()
+            },
+        )]),
+    };
+
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+
+    // Two time travel clauses resolving to different blocks
+    let res = query_svc
+        .sql_statement(
+            &format!(
+                "select blah from foo as of block '{first_data_block_hash}' union all select blah \
+                 from foo as of block '{head}'"
+            ),
+            options.clone(),
+        )
+        .await;
+
+    assert_matches!(
+        res,
+        Err(QueryError::DataFusionError(DataFusionError {
+            source: datafusion::common::DataFusionError::Plan(s),
+            ..
+        })) if s.contains("referenced at different states")
+    );
+
+    // Plain reference reads the head, while the time travel clause points to the
+    // past
+    let res = query_svc
+        .sql_statement(
+            &format!(
+                "select blah from foo union all select blah from foo as of block \
+                 '{first_data_block_hash}'"
+            ),
+            options,
+        )
+        .await;
+
+    assert_matches!(
+        res,
+        Err(QueryError::DataFusionError(DataFusionError {
+            source: datafusion::common::DataFusionError::Plan(s),
+            ..
+        })) if s.contains("referenced at different states")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn create_catalog_with_query_result_cache(tempdir: &Path, local_fs: bool) -> dill::Catalog {
+    let datasets_dir = tempdir.join("datasets");
+    if !datasets_dir.exists() {
+        std::fs::create_dir(&datasets_dir).unwrap();
+    }
+
+    let mut b = dill::CatalogBuilder::new();
+    b.add::<SystemTimeSourceDefault>()
+        .add_builder(
+            DatasetRepositoryLocalFs::builder()
+                .with_root(datasets_dir)
+                .with_multi_tenant(false),
+        )
+        .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+        .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+        .add::<QueryServiceImpl>()
+        .add::<ObjectStoreRegistryImpl>()
+        .add::<ObjectStoreBuilderLocalFs>()
+        .add_value(QueryResultCacheConfig::default())
+        .add::<QueryResultCacheMetrics>()
+        .add_value(CacheDir::new(tempdir.join("cache")))
+        .add_value(CurrentAccountSubject::new_test())
+        .add_value(MockDatasetActionAuthorizer::allowing())
+        .bind::<dyn auth::DatasetActionAuthorizer, MockDatasetActionAuthorizer>();
+
+    if local_fs {
+        b.add::<QueryResultCacheLocalFs>();
+    } else {
+        b.add::<QueryResultCacheInMemory>();
+    }
+
+    b.build()
+}
+
+async fn query_num_records(catalog: &Catalog, statement: &str) {
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+    let res = query_svc
+        .sql_statement(statement, QueryOptions::default())
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        res.df,
+        indoc::indoc!(
+            r#"
+            +-------------+
+            | num_records |
+            +-------------+
+            | 4           |
+            +-------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+fn cache_hits_and_misses(catalog: &Catalog) -> (u64, u64) {
+    let metrics = catalog.get_one::<QueryResultCacheMetrics>().unwrap();
+    (metrics.hits_total.get(), metrics.misses_total.get())
+}
+
+#[test]
+fn test_query_result_cache_key_normalization() {
+    let state = QueryState {
+        input_datasets: BTreeMap::from([(
+            DatasetID::new_seeded_ed25519(b"foo"),
+            QueryStateDataset {
+                alias: "foo".to_string(),
+                block_hash: Multihash::from_digest_sha3_256(b"head"),
+            },
+        )]),
+    };
+
+    assert_eq!(
+        QueryResultCacheKey::new("select  *\n  from foo;", &state),
+        QueryResultCacheKey::new(" select * from foo ", &state),
+    );
+    assert_ne!(
+        QueryResultCacheKey::new("select * from foo where a = 'x  y'", &state),
+        QueryResultCacheKey::new("select * from foo where a = 'x y'", &state),
+    );
+
+    let moved_state = QueryState {
+        input_datasets: state
+            .input_datasets
+            .iter()
+            .map(|(id, ds)| {
+                (
+                    id.clone(),
+                    QueryStateDataset {
+                        alias: ds.alias.clone(),
+                        block_hash: Multihash::from_digest_sha3_256(b"new-head"),
+                    },
+                )
+            })
+            .collect(),
+    };
+
+    assert_ne!(
+        QueryResultCacheKey::new("select * from foo", &state),
+        QueryResultCacheKey::new("select * from foo", &moved_state),
+    );
+}
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_result_cache_invalidated_on_head_change() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_query_result_cache(tempdir.path(), false);
+
+    let create_result = create_test_dataset(&catalog, tempdir.path()).await;
+
+    query_num_records(&catalog, "select count(*) as num_records from foo").await;
+    assert_eq!(cache_hits_and_misses(&catalog), (0, 1));
+
+    // Cosmetic differences in the statement still hit the cache
+    query_num_records(&catalog, "select count(*) as num_records\n  from foo;").await;
+    assert_eq!(cache_hits_and_misses(&catalog), (1, 1));
+
+    // Moving the head invalidates the result
+    create_result
+        .dataset
+        .commit_event(
+            MetadataFactory::set_info()
+                .description("Updated")
+                .build()
+                .into(),
+            CommitOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    query_num_records(&catalog, "select count(*) as num_records from foo").await;
+    assert_eq!(cache_hits_and_misses(&catalog), (1, 2));
+
+    query_num_records(&catalog, "select count(*) as num_records from foo").await;
+    assert_eq!(cache_hits_and_misses(&catalog), (2, 2));
+}
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_result_cache_skips_volatile_queries() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_query_result_cache(tempdir.path(), false);
+
+    create_test_dataset(&catalog, tempdir.path()).await;
+
+    let statement = "select count(*) as num_records from foo where random() >= 0";
+
+    query_num_records(&catalog, statement).await;
+    assert_eq!(cache_hits_and_misses(&catalog), (0, 1));
+
+    query_num_records(&catalog, statement).await;
+    assert_eq!(cache_hits_and_misses(&catalog), (0, 2));
+}
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_result_cache_local_fs_survives_restart() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_query_result_cache(tempdir.path(), true);
+
+    create_test_dataset(&catalog, tempdir.path()).await;
+
+    query_num_records(&catalog, "select count(*) as num_records from foo").await;
+    assert_eq!(cache_hits_and_misses(&catalog), (0, 1));
+    assert_eq!(
+        std::fs::read_dir(tempdir.path().join("cache").join("query-results"))
+            .unwrap()
+            .count(),
+        1
+    );
+
+    // New cache instance picks up the entries stored previously
+    let catalog = create_catalog_with_query_result_cache(tempdir.path(), true);
+
+    query_num_records(&catalog, "select count(*) as num_records from foo").await;
+    assert_eq!(cache_hits_and_misses(&catalog), (1, 0));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Partitioning
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn partition_existing_slices(dataset: &dyn Dataset, spec: PartitioningSpec) {
+    use kamu::domain::utils::partitioned_slices::{
+        write_partitioning_spec,
+        write_slice_partitions,
+    };
+    use kamu_ingest_datafusion::partitioning::compute_slice_partitions;
+
+    write_partitioning_spec(dataset.as_info_repo(), &spec)
+        .await
+        .unwrap();
+
+    let ctx = datafusion::prelude::SessionContext::new();
+
+    for slice in get_data_slices(dataset).await {
+        let partitions = compute_slice_partitions(
+            &ctx,
+            &spec,
+            "offset",
+            &get_data_slice_path(dataset, &slice).await,
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        write_slice_partitions(dataset.as_info_repo(), &slice.physical_hash, &partitions)
+            .await
+            .unwrap();
+    }
+}
+
+/// Returns data slices of the dataset starting with the newest
+async fn get_data_slices(dataset: &dyn Dataset) -> Vec<DataSlice> {
+    dataset
+        .as_metadata_chain()
+        .iter_blocks()
+        .try_filter_map(|(_, b)| async move {
+            Ok(b.into_typed::<AddData>().and_then(|b| b.event.new_data))
+        })
+        .try_collect()
+        .await
+        .unwrap()
+}
+
+async fn get_data_slice_path(dataset: &dyn Dataset, slice: &DataSlice) -> std::path::PathBuf {
+    kamu_data_utils::data::local_url::into_local_path(
+        dataset
+            .as_data_repo()
+            .get_internal_url(&slice.physical_hash)
+            .await,
+    )
+    .unwrap()
+}
+
+/// Rewrites the data file with one record per row group and without the
+/// statistics of the `blah` column, so that its row groups can only be pruned
+/// by the filters on the offset column
+fn rewrite_with_row_group_per_record(path: &Path) {
+    use datafusion::parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use datafusion::parquet::arrow::ArrowWriter;
+    use datafusion::parquet::file::properties::{EnabledStatistics, WriterProperties};
+    use datafusion::parquet::schema::types::ColumnPath;
+
+    let reader = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(path).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let schema = reader.schema();
+    let batches: Vec<RecordBatch> = reader.map(Result::unwrap).collect();
+
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(1)
+        .set_column_statistics_enabled(ColumnPath::from("blah"), EnabledStatistics::None)
+        .build();
+
+    let mut writer =
+        ArrowWriter::try_new(std::fs::File::create(path).unwrap(), schema, Some(props)).unwrap();
+    for batch in &batches {
+        writer.write(batch).unwrap();
+    }
+    writer.close().unwrap();
+}
+
+fn sum_plan_metric(plan: &dyn datafusion::physical_plan::ExecutionPlan, name: &str) -> usize {
+    plan.metrics()
+        .and_then(|m| m.sum_by_name(name))
+        .map_or(0, |v| v.as_usize())
+        + plan
+            .children()
+            .into_iter()
+            .map(|c| sum_plan_metric(c.as_ref(), name))
+            .sum::<usize>()
+}
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_prunes_partitions() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let create_result = create_test_dataset(&catalog, tempdir.path()).await;
+    let dataset = create_result.dataset.as_ref();
+
+    let slices = get_data_slices(dataset).await;
+
+    // Slice with `c` and `d` records gets a row group per record
+    rewrite_with_row_group_per_record(&get_data_slice_path(dataset, &slices[0]).await);
+
+    partition_existing_slices(
+        dataset,
+        PartitioningSpec {
+            column: "blah".to_string(),
+            transform: PartitionTransform::Identity,
+        },
+    )
+    .await;
+
+    let partitions = kamu::domain::utils::partitioned_slices::read_slice_partitions(
+        dataset.as_info_repo(),
+        &slices[0].physical_hash,
+    )
+    .await
+    .unwrap()
+    .unwrap();
+
+    assert_eq!(
+        partitions.row_groups,
+        vec![
+            RowGroupPartitions {
+                offset_start: 2,
+                offset_end: 2,
+                values: vec![Some("c".to_string())],
+            },
+            RowGroupPartitions {
+                offset_start: 3,
+                offset_end: 3,
+                values: vec![Some("d".to_string())],
+            },
+        ]
+    );
+
+    // Corrupt the slice with `a` and `b` records - queries that prune it should
+    // never attempt to read it
+    std::fs::write(get_data_slice_path(dataset, &slices[1]).await, b"corrupted").unwrap();
+
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+
+    let res = query_svc
+        .sql_statement(
+            "select blah from foo where blah = 'c' or blah = 'x'",
+            QueryOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    let task_ctx = res.df.task_ctx();
+    let plan = res.df.create_physical_plan().await.unwrap();
+    let batches = datafusion::physical_plan::collect(plan.clone(), Arc::new(task_ctx))
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        datafusion::prelude::SessionContext::new()
+            .read_batches(batches)
+            .unwrap(),
+        indoc::indoc!(
+            r#"
+            +------+
+            | blah |
+            +------+
+            | c    |
+            +------+
+            "#
+        ),
+    )
+    .await;
+
+    // Row group with `d` record is skipped using the offset filter
+    assert_eq!(
+        sum_plan_metric(plan.as_ref(), "row_groups_pruned_statistics"),
+        1
+    );
+
+    // Reading all partitions hits the corrupted file
+    let res = query_svc
+        .sql_statement("select * from foo", QueryOptions::default())
+        .await
+        .unwrap();
+
+    assert!(res.df.collect().await.is_err());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Slice statistics
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_skips_slices_using_statistics() {
+    use kamu::domain::utils::slice_statistics::{
+        read_slice_statistics,
+        write_slice_statistics_config,
+    };
+    use kamu::utils::slice_indexes::build_statistics;
+
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let create_result = create_test_dataset(&catalog, tempdir.path()).await;
+    let dataset = create_result.dataset.as_ref();
+
+    write_slice_statistics_config(
+        dataset.as_info_repo(),
+        &SliceStatisticsConfig {
+            bloom_filter_columns: vec!["blah".to_string()],
+        },
+    )
+    .await
+    .unwrap();
+
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+    let slices = get_data_slices(dataset).await;
+
+    // Slices without statistics are read in full and queries never write them
+    let res = query_svc
+        .sql_statement(
+            "select blah from foo where blah = 'c'",
+            QueryOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        res.df,
+        indoc::indoc!(
+            r#"
+            +------+
+            | blah |
+            +------+
+            | c    |
+            +------+
+            "#
+        ),
+    )
+    .await;
+
+    for slice in &slices {
+        assert_eq!(
+            read_slice_statistics(dataset.as_info_repo(), &slice.physical_hash)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    assert_eq!(build_statistics(dataset).await.unwrap(), 2);
+    assert_eq!(build_statistics(dataset).await.unwrap(), 0);
+
+    // Corrupt the second slice with `c` and `d` records - queries that skip it
+    // should never attempt to read it
+    std::fs::write(get_data_slice_path(dataset, &slices[0]).await, b"corrupted").unwrap();
+
+    // Skipped by min / max
+    let res = query_svc
+        .sql_statement(
+            "select blah from foo where blah < 'c' order by blah",
+            QueryOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        res.df,
+        indoc::indoc!(
+            r#"
+            +------+
+            | blah |
+            +------+
+            | a    |
+            | b    |
+            +------+
+            "#
+        ),
+    )
+    .await;
+
+    // Both slices are skipped by min / max and bloom filter
+    let res = query_svc
+        .sql_statement(
+            "select blah from foo where blah = 'ab'",
+            QueryOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(res.df.count().await.unwrap(), 0);
+
+    // Reading all slices hits the corrupted file
+    let res = query_svc
+        .sql_statement("select * from foo", QueryOptions::default())
+        .await
+        .unwrap();
+
+    assert!(res.df.collect().await.is_err());
+}
+
+#[test_group::group(engine, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_sql_statement_ignores_invalid_slice_statistics() {
+    use kamu::domain::utils::slice_statistics::{
+        read_slice_statistics,
+        slice_statistics_key,
+        write_slice_statistics_config,
+    };
+
+    let tempdir = tempfile::tempdir().unwrap();
+    let catalog = create_catalog_with_local_workspace(
+        tempdir.path(),
+        MockDatasetActionAuthorizer::allowing(),
+    );
+
+    let create_result = create_test_dataset(&catalog, tempdir.path()).await;
+    let dataset = create_result.dataset.as_ref();
+
+    write_slice_statistics_config(
+        dataset.as_info_repo(),
+        &SliceStatisticsConfig {
+            bloom_filter_columns: vec!["blah".to_string()],
+        },
+    )
+    .await
+    .unwrap();
+
+    // Statistics of the slice with `a` and `b` records claiming an empty bloom
+    // filter
+    let header = br#"{"columns":[{"name":"blah","min":"a","max":"b","nullCount":0,"bloomFilter":{"numHashes":3,"numBytes":0}}]}"#;
+    let mut data = Vec::new();
+    data.extend_from_slice(b"KSST");
+    data.extend_from_slice(&1u16.to_le_bytes());
+    data.extend_from_slice(&u32::try_from(header.len()).unwrap().to_le_bytes());
+    data.extend_from_slice(header);
+
+    let slices = get_data_slices(dataset).await;
+    let key = slice_statistics_key(&slices[1].physical_hash);
+    dataset.as_info_repo().set(&key, &data).await.unwrap();
+
+    assert!(
+        read_slice_statistics(dataset.as_info_repo(), &slices[1].physical_hash)
+            .await
+            .is_err()
+    );
+
+    // Slice with invalid statistics is read in full
+    let query_svc = catalog.get_one::<dyn QueryService>().unwrap();
+    let res = query_svc
+        .sql_statement(
+            "select blah from foo where blah = 'a'",
+            QueryOptions::default(),
+        )
+        .await
+        .unwrap();
+
+    kamu_data_utils::testing::assert_data_eq(
+        res.df,
+        indoc::indoc!(
+            r#"
+            +------+
+            | blah |
+            +------+
+            | a    |
+            +------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -15,6 +15,8 @@ use kamu::domain::*;
 use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
+use kamu_audit_log::testing::DummyAuditLogService;
+use messaging_outbox::DummyOutboxImpl;
 use opendatafabric::*;
 use tempfile::TempDir;
 use time_source::SystemTimeSourceDefault;
@@ -185,6 +187,8 @@ impl ResetTestHarness {
             .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
             .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
             .add::<ResetServiceImpl>()
+            .add::<DummyAuditLogService>()
+            .add::<DummyOutboxImpl>()
             .build();
 
         let dataset_repo = catalog.get_one::<dyn DatasetRepository>().unwrap();
@@ -0,0 +1,602 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeZone, Utc};
+use datafusion::execution::config::SessionConfig;
+use datafusion::execution::context::SessionContext;
+use datafusion::prelude::{cast, col, lit, when};
+use datafusion::scalar::ScalarValue;
+use futures::TryStreamExt;
+use indoc::indoc;
+use kamu::domain::*;
+use kamu::testing::{DatasetDataHelper, MetadataFactory, ParquetWriterHelper};
+use kamu::*;
+use kamu_accounts::CurrentAccountSubject;
+use kamu_core::auth;
+use messaging_outbox::DummyOutboxImpl;
+use opendatafabric::*;
+use time_source::{SystemTimeSource, SystemTimeSourceStub};
+
+use crate::mock_engine_provisioner;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(ingest, datafusion, retention)]
+#[tokio::test]
+async fn test_dataset_retention() {
+    let harness = RetentionTestHarness::new();
+
+    let created = harness.create_test_root_dataset().await;
+    let dataset_ref = created.dataset_handle.as_local_ref();
+
+    for data_str in [
+        indoc!(
+            "
+            date,city,population
+            2020-01-01,A,1000
+            2020-01-02,B,2000
+            2020-01-03,C,3000
+            "
+        ),
+        indoc!(
+            "
+            date,city,population
+            2020-01-04,A,4000
+            2020-01-05,B,5000
+            2020-01-06,C,6000
+            "
+        ),
+        indoc!(
+            "
+            date,city,population
+            2020-01-07,A,7000
+            2020-01-08,B,8000
+            2020-01-09,C,9000
+            "
+        ),
+    ] {
+        harness.ingest_data(data_str, &dataset_ref).await;
+    }
+
+    let cutoff = Utc.with_ymd_and_hms(2020, 1, 5, 0, 0, 0).unwrap();
+
+    // Round 1: Dry run only reports what would be removed
+    let old_head = harness.get_dataset_head(&dataset_ref).await;
+
+    assert_matches!(
+        harness
+            .retention_svc
+            .apply_retention(
+                &created.dataset_handle,
+                RetentionOptions {
+                    cutoff,
+                    dry_run: true,
+                },
+            )
+            .await,
+        Ok(RetentionResult::DryRun {
+            head,
+            num_records_removed: 4,
+            num_slices_removed: 1,
+        }) if head == old_head
+    );
+    assert_eq!(old_head, harness.get_dataset_head(&dataset_ref).await);
+
+    // Round 2: Expired records are removed and offsets of the remaining
+    // records are shifted
+    //
+    // Before: seed <- add_push_source <- set_vocab <- set_data_schema <-
+    // add_data(3 records) <- add_data(3 records) <- add_data(3 records)
+    //
+    // After: seed <- add_push_source <- set_vocab <- set_data_schema <-
+    // add_data(2 records) <- add_data(3 records)
+    assert_matches!(
+        harness
+            .retention_svc
+            .apply_retention(
+                &created.dataset_handle,
+                RetentionOptions {
+                    cutoff,
+                    dry_run: false,
+                },
+            )
+            .await,
+        Ok(RetentionResult::Success {
+            old_head: res_old_head,
+            new_head,
+            num_records_removed: 4,
+            num_slices_removed: 1,
+        }) if res_old_head == old_head && new_head != old_head
+    );
+
+    assert!(harness.verify_dataset(&dataset_ref).await);
+
+    let offset_intervals: Vec<_> = harness
+        .get_dataset_blocks(&dataset_ref)
+        .await
+        .into_iter()
+        .filter_map(|block| match block.event {
+            MetadataEvent::AddData(add_data) => Some((
+                add_data.prev_offset,
+                add_data.new_data.map(|d| d.offset_interval),
+            )),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        offset_intervals,
+        vec![
+            (Some(1), Some(OffsetInterval { start: 2, end: 4 })),
+            (None, Some(OffsetInterval { start: 0, end: 1 })),
+        ]
+    );
+
+    harness
+        .dataset_data_helper(&dataset_ref)
+        .await
+        .assert_last_data_records_eq(indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+
+            | offset | op | system_time          | date                 | city | population |
+            +--------+----+----------------------+----------------------+------+------------+
+            | 2      | 0  | 2050-01-01T12:00:00Z | 2020-01-07T00:00:00Z | A    | 7000       |
+            | 3      | 0  | 2050-01-01T12:00:00Z | 2020-01-08T00:00:00Z | B    | 8000       |
+            | 4      | 0  | 2050-01-01T12:00:00Z | 2020-01-09T00:00:00Z | C    | 9000       |
+            +--------+----+----------------------+----------------------+------+------------+
+            "#
+        ))
+        .await;
+
+    // Round 3: Nothing left to remove
+    assert_matches!(
+        harness
+            .retention_svc
+            .apply_retention(
+                &created.dataset_handle,
+                RetentionOptions {
+                    cutoff,
+                    dry_run: false,
+                },
+            )
+            .await,
+        Ok(RetentionResult::NothingToDo)
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(ingest, datafusion, retention)]
+#[tokio::test]
+async fn test_dataset_retention_keeps_records_without_event_time() {
+    let harness = RetentionTestHarness::new();
+
+    let created = harness.create_test_root_dataset().await;
+    let dataset_ref = created.dataset_handle.as_local_ref();
+
+    for data_str in [
+        indoc!(
+            "
+            date,city,population
+            2020-01-01,A,1000
+            2020-01-02,B,2000
+            "
+        ),
+        indoc!(
+            "
+            date,city,population
+            2020-01-06,A,6000
+            2020-01-07,B,7000
+            "
+        ),
+    ] {
+        harness.ingest_data(data_str, &dataset_ref).await;
+    }
+
+    // Ingest always populates the event time, so the slice with a missing one is
+    // derived from the last slice and committed directly
+    let last_data = harness
+        .dataset_data_helper(&dataset_ref)
+        .await
+        .get_last_data()
+        .await;
+    let event_time_type = last_data
+        .schema()
+        .field_with_unqualified_name("date")
+        .unwrap()
+        .data_type()
+        .clone();
+    let record_batches = last_data
+        .with_column("offset", col("offset") + lit(2_i64))
+        .unwrap()
+        .with_column(
+            "date",
+            when(
+                col("city").eq(lit("A")),
+                cast(lit(ScalarValue::Null), event_time_type),
+            )
+            .otherwise(col("date"))
+            .unwrap(),
+        )
+        .unwrap()
+        .collect()
+        .await
+        .unwrap();
+
+    let data_path = harness.temp_dir.path().join("null-event-time.parquet");
+    ParquetWriterHelper::from_record_batch(&data_path, &record_batches[0]).unwrap();
+
+    let dataset = harness
+        .dataset_repo
+        .find_dataset_by_ref(&dataset_ref)
+        .await
+        .unwrap();
+    dataset
+        .commit_add_data(
+            AddDataParams {
+                prev_checkpoint: None,
+                prev_offset: Some(3),
+                new_offset_interval: Some(OffsetInterval { start: 4, end: 5 }),
+                new_watermark: None,
+                new_source_state: None,
+            },
+            Some(OwnedFile::new(data_path)),
+            None,
+            CommitOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    // The first slice expires entirely, while the record without an event time
+    // is retained
+    assert_matches!(
+        harness
+            .retention_svc
+            .apply_retention(
+                &created.dataset_handle,
+                RetentionOptions {
+                    cutoff: Utc.with_ymd_and_hms(2020, 1, 5, 0, 0, 0).unwrap(),
+                    dry_run: false,
+                },
+            )
+            .await,
+        Ok(RetentionResult::Success {
+            num_records_removed: 2,
+            num_slices_removed: 1,
+            ..
+        })
+    );
+
+    assert!(harness.verify_dataset(&dataset_ref).await);
+
+    harness
+        .dataset_data_helper(&dataset_ref)
+        .await
+        .assert_last_data_records_eq(indoc!(
+            r#"
+            +--------+----+----------------------+----------------------+------+------------+
+            | offset | op | system_time          | date                 | city | population |
+            +--------+----+----------------------+----------------------+------+------------+
+            | 2      | 0  | 2050-01-01T12:00:00Z |                      | A    | 6000       |
+            | 3      | 0  | 2050-01-01T12:00:00Z | 2020-01-07T00:00:00Z | B    | 7000       |
+            +--------+----+----------------------+----------------------+------+------------+
+            "#
+        ))
+        .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(ingest, datafusion, retention)]
+#[tokio::test]
+async fn test_dataset_retention_deletes_replaced_slices() {
+    use kamu::domain::utils::slice_statistics::{
+        read_slice_statistics,
+        write_slice_statistics_config,
+    };
+
+    let harness = RetentionTestHarness::new();
+
+    let created = harness.create_test_root_dataset().await;
+    let dataset_ref = created.dataset_handle.as_local_ref();
+
+    write_slice_statistics_config(
+        created.dataset.as_info_repo(),
+        &SliceStatisticsConfig {
+            bloom_filter_columns: vec!["city".to_string()],
+        },
+    )
+    .await
+    .unwrap();
+
+    for data_str in [
+        indoc!(
+            "
+            date,city,population
+            2020-01-01,A,1000
+            2020-01-02,B,2000
+            "
+        ),
+        indoc!(
+            "
+            date,city,population
+            2020-01-03,C,3000
+            2020-01-04,D,4000
+            "
+        ),
+    ] {
+        harness.ingest_data(data_str, &dataset_ref).await;
+    }
+
+    let old_slices = harness.get_data_slice_hashes(&dataset_ref).await;
+    assert_eq!(old_slices.len(), 2);
+
+    assert_matches!(
+        harness
+            .retention_svc
+            .apply_retention(
+                &created.dataset_handle,
+                RetentionOptions {
+                    cutoff: Utc.with_ymd_and_hms(2020, 1, 2, 0, 0, 0).unwrap(),
+                    dry_run: false,
+                },
+            )
+            .await,
+        Ok(RetentionResult::Success {
+            num_records_removed: 1,
+            num_slices_removed: 0,
+            ..
+        })
+    );
+
+    let new_slices = harness.get_data_slice_hashes(&dataset_ref).await;
+    assert_eq!(new_slices.len(), 2);
+
+    let data_repo = created.dataset.as_data_repo();
+    let info_repo = created.dataset.as_info_repo();
+
+    // Superseded data files are removed along with their indexes
+    for physical_hash in &old_slices {
+        assert!(!new_slices.contains(physical_hash));
+        assert!(!data_repo.contains(physical_hash).await.unwrap());
+        assert_eq!(
+            read_slice_statistics(info_repo, physical_hash)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    // Rewritten data files are indexed
+    for physical_hash in &new_slices {
+        assert!(data_repo.contains(physical_hash).await.unwrap());
+        assert!(read_slice_statistics(info_repo, physical_hash)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    assert!(harness.verify_dataset(&dataset_ref).await);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(retention)]
+#[tokio::test]
+async fn test_dataset_retention_derive_error() {
+    let harness = RetentionTestHarness::new();
+
+    let created = harness
+        .create_dataset(
+            MetadataFactory::dataset_snapshot()
+                .name("derive.foo")
+                .kind(DatasetKind::Derivative)
+                .push_event(MetadataFactory::set_data_schema().build())
+                .build(),
+        )
+        .await;
+
+    assert_matches!(
+        harness
+            .retention_svc
+            .apply_retention(
+                &created.dataset_handle,
+                RetentionOptions {
+                    cutoff: Utc::now(),
+                    dry_run: false,
+                },
+            )
+            .await,
+        Err(RetentionError::InvalidDatasetKind(_)),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct RetentionTestHarness {
+    temp_dir: tempfile::TempDir,
+    dataset_repo: Arc<dyn DatasetRepository>,
+    dataset_repo_writer: Arc<dyn DatasetRepositoryWriter>,
+    retention_svc: Arc<dyn RetentionService>,
+    push_ingest_svc: Arc<PushIngestServiceImpl>,
+    verification_svc: Arc<dyn VerificationService>,
+    ctx: SessionContext,
+}
+
+impl RetentionTestHarness {
+    fn new() -> Self {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let run_info_dir = temp_dir.path().join("run");
+        let datasets_dir = temp_dir.path().join("datasets");
+        std::fs::create_dir(&run_info_dir).unwrap();
+        std::fs::create_dir(&datasets_dir).unwrap();
+        let current_date_time: DateTime<Utc> = Utc.with_ymd_and_hms(2050, 1, 1, 12, 0, 0).unwrap();
+
+        let catalog = dill::CatalogBuilder::new()
+            .add_value(RunInfoDir::new(run_info_dir))
+            .add_value(CurrentAccountSubject::new_test())
+            .add_builder(
+                DatasetRepositoryLocalFs::builder()
+                    .with_root(datasets_dir)
+                    .with_multi_tenant(false),
+            )
+            .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+            .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+            .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+            .add_value(SystemTimeSourceStub::new_set(current_date_time))
+            .bind::<dyn SystemTimeSource, SystemTimeSourceStub>()
+            .add::<ObjectStoreRegistryImpl>()
+            .add::<ObjectStoreBuilderLocalFs>()
+            .add::<DataFormatRegistryImpl>()
+            .add::<RetentionServiceImpl>()
+            .add::<PushIngestServiceImpl>()
+            .add_value(
+                mock_engine_provisioner::MockEngineProvisioner::new().stub_provision_engine(),
+            )
+            .bind::<dyn EngineProvisioner, mock_engine_provisioner::MockEngineProvisioner>()
+            .add::<TransformServiceImpl>()
+            .add::<VerificationServiceImpl>()
+            .add::<DummyOutboxImpl>()
+            .build();
+
+        Self {
+            temp_dir,
+            dataset_repo: catalog.get_one().unwrap(),
+            dataset_repo_writer: catalog.get_one().unwrap(),
+            retention_svc: catalog.get_one().unwrap(),
+            push_ingest_svc: catalog.get_one().unwrap(),
+            verification_svc: catalog.get_one().unwrap(),
+            ctx: SessionContext::new_with_config(SessionConfig::new().with_target_partitions(1)),
+        }
+    }
+
+    async fn get_dataset_head(&self, dataset_ref: &DatasetRef) -> Multihash {
+        let dataset = self
+            .dataset_repo
+            .find_dataset_by_ref(dataset_ref)
+            .await
+            .unwrap();
+
+        dataset
+            .as_metadata_chain()
+            .resolve_ref(&BlockRef::Head)
+            .await
+            .unwrap()
+    }
+
+    async fn get_dataset_blocks(&self, dataset_ref: &DatasetRef) -> Vec<MetadataBlock> {
+        let dataset = self
+            .dataset_repo
+            .find_dataset_by_ref(dataset_ref)
+            .await
+            .unwrap();
+        let head = self.get_dataset_head(dataset_ref).await;
+
+        dataset
+            .as_metadata_chain()
+            .iter_blocks_interval(&head, None, false)
+            .map_ok(|(_, b)| b)
+            .try_collect()
+            .await
+            .unwrap()
+    }
+
+    async fn get_data_slice_hashes(&self, dataset_ref: &DatasetRef) -> Vec<Multihash> {
+        self.get_dataset_blocks(dataset_ref)
+            .await
+            .into_iter()
+            .filter_map(|block| match block.event {
+                MetadataEvent::AddData(add_data) => add_data.new_data.map(|d| d.physical_hash),
+                _ => None,
+            })
+            .collect()
+    }
+
+    async fn create_dataset(&self, dataset_snapshot: DatasetSnapshot) -> CreateDatasetResult {
+        self.dataset_repo_writer
+            .create_dataset_from_snapshot(dataset_snapshot)
+            .await
+            .unwrap()
+            .create_dataset_result
+    }
+
+    async fn create_test_root_dataset(&self) -> CreateDatasetResult {
+        self.create_dataset(
+            MetadataFactory::dataset_snapshot()
+                .name("foo")
+                .kind(DatasetKind::Root)
+                .push_event(
+                    MetadataFactory::add_push_source()
+                        .read(ReadStepCsv {
+                            header: Some(true),
+                            schema: Some(
+                                ["date TIMESTAMP", "city STRING", "population BIGINT"]
+                                    .iter()
+                                    .map(|s| (*s).to_string())
+                                    .collect(),
+                            ),
+                            ..ReadStepCsv::default()
+                        })
+                        .merge(MergeStrategyLedger {
+                            primary_key: vec!["date".to_string(), "city".to_string()],
+                        })
+                        .build(),
+                )
+                .push_event(SetVocab {
+                    event_time_column: Some("date".to_string()),
+                    ..Default::default()
+                })
+                .build(),
+        )
+        .await
+    }
+
+    async fn dataset_data_helper(&self, dataset_ref: &DatasetRef) -> DatasetDataHelper {
+        let dataset = self
+            .dataset_repo
+            .find_dataset_by_ref(dataset_ref)
+            .await
+            .unwrap();
+
+        DatasetDataHelper::new_with_context(dataset, self.ctx.clone())
+    }
+
+    async fn ingest_data(&self, data_str: &str, dataset_ref: &DatasetRef) {
+        let data = std::io::Cursor::new(data_str.to_string());
+
+        self.push_ingest_svc
+            .ingest_from_file_stream(
+                dataset_ref,
+                None,
+                Box::new(data),
+                PushIngestOpts::default(),
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    async fn verify_dataset(&self, dataset_ref: &DatasetRef) -> bool {
+        let result = self
+            .verification_svc
+            .verify(
+                dataset_ref,
+                (None, None),
+                VerificationOptions::default(),
+                None,
+            )
+            .await;
+
+        result.outcome.is_ok()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -14,7 +14,7 @@ use kamu::domain::*;
 use kamu::testing::*;
 use kamu::*;
 use kamu_accounts::CurrentAccountSubject;
-use messaging_outbox::DummyOutboxImpl;
+use messaging_outbox::{DummyOutboxImpl, MessageConsumerT};
 use opendatafabric::*;
 use time_source::SystemTimeSourceDefault;
 use url::Url;
@@ -49,6 +49,7 @@ async fn do_test_search(tmp_workspace_dir: &Path, repo_url: Url) {
         .add::<SyncServiceImpl>()
         .add::<DummySmartTransferProtocolClient>()
         .add::<SearchServiceImpl>()
+        .add::<SearchIndexInMemory>()
         .add::<CreateDatasetUseCaseImpl>()
         .add::<DummyOutboxImpl>()
         .build();
@@ -96,6 +97,7 @@ async fn do_test_search(tmp_workspace_dir: &Path, repo_url: Url) {
                 id: None,
                 alias: dataset_remote_alias.clone(),
                 kind: None,
+                description: None,
                 num_blocks: None,
                 num_records: None,
                 estimated_size: None,
@@ -113,6 +115,7 @@ async fn do_test_search(tmp_workspace_dir: &Path, repo_url: Url) {
                 id: None,
                 alias: dataset_remote_alias.clone(),
                 kind: None,
+                description: None,
                 num_blocks: None,
                 num_records: None,
                 estimated_size: None,
@@ -152,3 +155,257 @@ async fn test_search_s3() {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_search_local_ranking_and_filters() {
+    let tmp_workspace_dir = tempfile::tempdir().unwrap();
+    let datasets_dir = tmp_workspace_dir.path().join("datasets");
+    std::fs::create_dir(&datasets_dir).unwrap();
+
+    let catalog = dill::CatalogBuilder::new()
+        .add::<SystemTimeSourceDefault>()
+        .add_value(CurrentAccountSubject::new_test())
+        .add_builder(
+            DatasetRepositoryLocalFs::builder()
+                .with_root(datasets_dir)
+                .with_multi_tenant(false),
+        )
+        .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+        .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+        .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+        .add_value(RemoteRepositoryRegistryNull)
+        .bind::<dyn RemoteRepositoryRegistry, RemoteRepositoryRegistryNull>()
+        .add::<SearchServiceImpl>()
+        .add::<SearchIndexInMemory>()
+        .build();
+
+    let dataset_repo_writer = catalog.get_one::<dyn DatasetRepositoryWriter>().unwrap();
+    let search_svc = catalog.get_one::<dyn SearchService>().unwrap();
+
+    create_dataset_with_info(
+        dataset_repo_writer.as_ref(),
+        "weather.stations",
+        DatasetKind::Root,
+        "Locations of meteorological stations",
+        &["weather", "geo"],
+    )
+    .await;
+    create_dataset_with_info(
+        dataset_repo_writer.as_ref(),
+        "city.temperature",
+        DatasetKind::Derivative,
+        "Hourly temperature aggregated from weather stations",
+        &["weather"],
+    )
+    .await;
+    create_dataset_with_info(
+        dataset_repo_writer.as_ref(),
+        "population",
+        DatasetKind::Root,
+        "Population census by city",
+        &["demographics"],
+    )
+    .await;
+
+    let aliases = |result: SearchLocalResult| -> Vec<String> {
+        result
+            .datasets
+            .into_iter()
+            .map(|ds| ds.handle.alias.to_string())
+            .collect()
+    };
+
+    // Name match ranks above description match
+    let result = search_svc
+        .search_local(Some("weather"), SearchLocalOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(result.total_count, 2);
+    assert_eq!(
+        aliases(result),
+        vec![
+            "weather.stations".to_string(),
+            "city.temperature".to_string()
+        ]
+    );
+
+    // Every term has to match
+    let result = search_svc
+        .search_local(Some("city census"), SearchLocalOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(aliases(result), vec!["population".to_string()]);
+
+    // Filter by kind
+    let result = search_svc
+        .search_local(
+            Some("weather"),
+            SearchLocalOptions {
+                filters: SearchFilters {
+                    kinds: vec![DatasetKind::Derivative],
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(aliases(result), vec!["city.temperature".to_string()]);
+
+    // Filter by keyword without a query
+    let result = search_svc
+        .search_local(
+            None,
+            SearchLocalOptions {
+                filters: SearchFilters {
+                    keywords: vec!["geo".to_string()],
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(aliases(result), vec!["weather.stations".to_string()]);
+
+    // Pagination
+    let result = search_svc
+        .search_local(
+            None,
+            SearchLocalOptions {
+                offset: 1,
+                limit: 1,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.total_count, 3);
+    assert_eq!(aliases(result), vec!["population".to_string()]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_log::test(tokio::test)]
+async fn test_search_local_reindexes_on_reference_message() {
+    let tmp_workspace_dir = tempfile::tempdir().unwrap();
+    let datasets_dir = tmp_workspace_dir.path().join("datasets");
+    std::fs::create_dir(&datasets_dir).unwrap();
+
+    let catalog = dill::CatalogBuilder::new()
+        .add::<SystemTimeSourceDefault>()
+        .add_value(CurrentAccountSubject::new_test())
+        .add_builder(
+            DatasetRepositoryLocalFs::builder()
+                .with_root(datasets_dir)
+                .with_multi_tenant(false),
+        )
+        .bind::<dyn DatasetRepository, DatasetRepositoryLocalFs>()
+        .bind::<dyn DatasetRepositoryWriter, DatasetRepositoryLocalFs>()
+        .add::<auth::AlwaysHappyDatasetActionAuthorizer>()
+        .add_value(RemoteRepositoryRegistryNull)
+        .bind::<dyn RemoteRepositoryRegistry, RemoteRepositoryRegistryNull>()
+        .add::<SearchServiceImpl>()
+        .add::<SearchIndexInMemory>()
+        .build();
+
+    let dataset_repo_writer = catalog.get_one::<dyn DatasetRepositoryWriter>().unwrap();
+    let search_svc = catalog.get_one::<dyn SearchService>().unwrap();
+    let search_index = catalog.get_one::<SearchIndexInMemory>().unwrap();
+
+    let create_result = dataset_repo_writer
+        .create_dataset_from_snapshot(
+            MetadataFactory::dataset_snapshot()
+                .name("foo")
+                .kind(DatasetKind::Root)
+                .push_event(MetadataFactory::set_polling_source().build())
+                .build(),
+        )
+        .await
+        .unwrap()
+        .create_dataset_result;
+
+    let result = search_svc
+        .search_local(Some("weather"), SearchLocalOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(result.total_count, 0);
+
+    let commit_result = create_result
+        .dataset
+        .commit_event(
+            MetadataEvent::SetInfo(
+                MetadataFactory::set_info()
+                    .description("Weather observations")
+                    .build(),
+            ),
+            CommitOpts::default(),
+        )
+        .await
+        .unwrap();
+
+    // The index does not poll the heads of datasets on every search
+    let result = search_svc
+        .search_local(Some("weather"), SearchLocalOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(result.total_count, 0);
+
+    MessageConsumerT::<DatasetReferenceMessage>::consume_message(
+        search_index.as_ref(),
+        &catalog,
+        &DatasetReferenceMessage::updated(
+            create_result.dataset_handle.id.clone(),
+            commit_result.old_head,
+            commit_result.new_head,
+        ),
+    )
+    .await
+    .unwrap();
+
+    let result = search_svc
+        .search_local(Some("weather"), SearchLocalOptions::default())
+        .await
+        .unwrap();
+    assert_eq!(result.total_count, 1);
+    assert_eq!(
+        result.datasets[0].description.as_deref(),
+        Some("Weather observations")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+async fn create_dataset_with_info(
+    dataset_repo_writer: &dyn DatasetRepositoryWriter,
+    name: &str,
+    kind: DatasetKind,
+    description: &str,
+    keywords: &[&str],
+) {
+    let mut set_info = MetadataFactory::set_info().description(description);
+    for keyword in keywords {
+        set_info = set_info.keyword(keyword);
+    }
+
+    let snapshot = MetadataFactory::dataset_snapshot()
+        .name(name)
+        .kind(kind)
+        .push_event(set_info.build());
+
+    let snapshot = match kind {
+        DatasetKind::Root => snapshot.push_event(MetadataFactory::set_polling_source().build()),
+        DatasetKind::Derivative => snapshot.push_event(
+            MetadataFactory::set_transform()
+                .inputs_from_refs(["weather.stations"])
+                .build(),
+        ),
+    };
+
+    dataset_repo_writer
+        .create_dataset_from_snapshot(snapshot.build())
+        .await
+        .unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -45,6 +45,15 @@ async fn test_setup_pull_images() {
         .await
         .unwrap();
 
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "ingest-kafka")] {
+            container_runtime
+                .ensure_image(docker_images::REDPANDA, None)
+                .await
+                .unwrap();
+        }
+    }
+
     cfg_if::cfg_if! {
         if #[cfg(feature = "ingest-mqtt")] {
             container_runtime
@@ -24,7 +24,9 @@ use kamu_core::{
     AppendDatasetMetadataBatchUseCase,
     CreateDatasetResult,
     DatasetLifecycleMessage,
+    DatasetReferenceMessage,
     DatasetRepository,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
 };
 use messaging_outbox::{MockOutbox, Outbox};
@@ -48,7 +50,11 @@ use time_source::SystemTimeSourceDefault;
 async fn test_append_dataset_metadata_batch() {
     let alias_foo = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
 
-    let mock_outbox = MockOutbox::new();
+    let mut mock_outbox = MockOutbox::new();
+    AppendDatasetMetadataBatchUseCaseHarness::add_outbox_dataset_reference_updated_expectation(
+        &mut mock_outbox,
+        1,
+    );
 
     let harness = AppendDatasetMetadataBatchUseCaseHarness::new(mock_outbox);
     let create_result_foo = harness.create_dataset(&alias_foo, DatasetKind::Root).await;
@@ -95,6 +101,10 @@ async fn test_append_dataset_metadata_batch_with_new_dependencies() {
     let alias_bar = DatasetAlias::new(None, DatasetName::new_unchecked("bar"));
 
     let mut mock_outbox = MockOutbox::new();
+    AppendDatasetMetadataBatchUseCaseHarness::add_outbox_dataset_reference_updated_expectation(
+        &mut mock_outbox,
+        1,
+    );
     AppendDatasetMetadataBatchUseCaseHarness::add_outbox_dataset_dependencies_updated_expectation(
         &mut mock_outbox,
         1,
@@ -225,6 +235,25 @@ impl AppendDatasetMetadataBatchUseCaseHarness {
             .times(times)
             .returning(|_, _| Ok(()));
     }
+
+    fn add_outbox_dataset_reference_updated_expectation(
+        mock_outbox: &mut MockOutbox,
+        times: usize,
+    ) {
+        mock_outbox
+            .expect_post_message_as_json()
+            .with(
+                eq(MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE),
+                function(|message_as_json: &serde_json::Value| {
+                    matches!(
+                        serde_json::from_value::<DatasetReferenceMessage>(message_as_json.clone()),
+                        Ok(DatasetReferenceMessage::Updated(_))
+                    )
+                }),
+            )
+            .times(times)
+            .returning(|_, _| Ok(()));
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
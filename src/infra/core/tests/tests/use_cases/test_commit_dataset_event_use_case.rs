@@ -21,7 +21,9 @@ use kamu_core::{
     CommitOpts,
     CreateDatasetResult,
     DatasetLifecycleMessage,
+    DatasetReferenceMessage,
     DatasetRepository,
+    MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE,
     MESSAGE_PRODUCER_KAMU_CORE_DATASET_SERVICE,
 };
 use messaging_outbox::{MockOutbox, Outbox};
@@ -38,7 +40,11 @@ async fn test_commit_dataset_event() {
     let mock_authorizer =
         MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_foo, 1, true);
 
-    let mock_outbox = MockOutbox::new();
+    let mut mock_outbox = MockOutbox::new();
+    CommitDatasetEventUseCaseHarness::add_outbox_dataset_reference_updated_expectation(
+        &mut mock_outbox,
+        1,
+    );
 
     let harness = CommitDatasetEventUseCaseHarness::new(mock_authorizer, mock_outbox);
     let create_result_foo = harness.create_dataset(&alias_foo, DatasetKind::Root).await;
@@ -90,6 +96,10 @@ async fn test_commit_event_with_new_dependencies() {
         MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_bar, 1, true);
 
     let mut mock_outbox = MockOutbox::new();
+    CommitDatasetEventUseCaseHarness::add_outbox_dataset_reference_updated_expectation(
+        &mut mock_outbox,
+        1,
+    );
     CommitDatasetEventUseCaseHarness::add_outbox_dataset_dependencies_updated_expectation(
         &mut mock_outbox,
         1,
@@ -200,6 +210,25 @@ impl CommitDatasetEventUseCaseHarness {
             .times(times)
             .returning(|_, _| Ok(()));
     }
+
+    fn add_outbox_dataset_reference_updated_expectation(
+        mock_outbox: &mut MockOutbox,
+        times: usize,
+    ) {
+        mock_outbox
+            .expect_post_message_as_json()
+            .with(
+                eq(MESSAGE_PRODUCER_KAMU_CORE_DATASET_REFERENCE_SERVICE),
+                function(|message_as_json: &serde_json::Value| {
+                    matches!(
+                        serde_json::from_value::<DatasetReferenceMessage>(message_as_json.clone()),
+                        Ok(DatasetReferenceMessage::Updated(_))
+                    )
+                }),
+            )
+            .times(times)
+            .returning(|_, _| Ok(()));
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -10,6 +10,7 @@
 use std::assert_matches::assert_matches;
 use std::sync::Arc;
 
+use database_common::PaginationOpts;
 use dill::{Catalog, Component};
 use kamu::testing::{MetadataFactory, MockDatasetActionAuthorizer};
 use kamu::{
@@ -19,7 +20,10 @@ use kamu::{
     DependencyGraphRepositoryInMemory,
     DependencyGraphServiceInMemory,
 };
-use kamu_accounts::CurrentAccountSubject;
+use kamu_accounts::{CurrentAccountSubject, DEFAULT_ACCOUNT_ID};
+use kamu_audit_log::{AuditAction, AuditEventFilter, AuditLogService};
+use kamu_audit_log_inmem::InMemoryAuditEventRepository;
+use kamu_audit_log_services::AuditLogServiceImpl;
 use kamu_core::auth::DatasetActionAuthorizer;
 use kamu_core::{
     CreateDatasetResult,
@@ -46,7 +50,7 @@ async fn test_delete_dataset_success_via_ref() {
     DeleteUseCaseHarness::add_outbox_dataset_deleted_expectation(&mut mock_outbox, 1);
 
     let mock_authorizer =
-        MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_foo, 1, true);
+        MockDatasetActionAuthorizer::new().expect_check_delete_dataset(&alias_foo, 1, true);
 
     let harness = DeleteUseCaseHarness::new(mock_authorizer, mock_outbox);
 
@@ -63,6 +67,11 @@ async fn test_delete_dataset_success_via_ref() {
         harness.check_dataset_exists(&alias_foo).await,
         Err(GetDatasetError::NotFound(_))
     );
+
+    assert_eq!(
+        harness.recorded_audit_actions().await,
+        vec![AuditAction::DatasetDeleted]
+    );
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -75,7 +84,7 @@ async fn test_delete_dataset_success_via_handle() {
     DeleteUseCaseHarness::add_outbox_dataset_deleted_expectation(&mut mock_outbox, 1);
 
     let mock_authorizer =
-        MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_foo, 1, true);
+        MockDatasetActionAuthorizer::new().expect_check_delete_dataset(&alias_foo, 1, true);
 
     let harness = DeleteUseCaseHarness::new(mock_authorizer, mock_outbox);
 
@@ -117,7 +126,7 @@ async fn test_delete_unauthorized() {
     let alias_foo = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
 
     let harness = DeleteUseCaseHarness::new(
-        MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_foo, 1, false),
+        MockDatasetActionAuthorizer::new().expect_check_delete_dataset(&alias_foo, 1, false),
         MockOutbox::new(),
     );
 
@@ -133,6 +142,7 @@ async fn test_delete_unauthorized() {
     );
 
     assert_matches!(harness.check_dataset_exists(&alias_foo).await, Ok(_));
+    assert!(harness.recorded_audit_actions().await.is_empty());
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -170,6 +180,7 @@ async fn test_delete_dataset_respects_dangling_refs() {
     harness
         .consume_message(DatasetLifecycleMessage::deleted(
             create_result_derived.dataset_handle.id,
+            DEFAULT_ACCOUNT_ID.clone(),
         ))
         .await;
 
@@ -188,6 +199,7 @@ async fn test_delete_dataset_respects_dangling_refs() {
     harness
         .consume_message(DatasetLifecycleMessage::deleted(
             create_result_root.dataset_handle.id,
+            DEFAULT_ACCOUNT_ID.clone(),
         ))
         .await;
 
@@ -233,6 +245,8 @@ impl DeleteUseCaseHarness {
             .add_value(mock_dataset_action_authorizer)
             .bind::<dyn DatasetActionAuthorizer, MockDatasetActionAuthorizer>()
             .add::<SystemTimeSourceDefault>()
+            .add::<AuditLogServiceImpl>()
+            .add::<InMemoryAuditEventRepository>()
             .add_value(mock_outbox)
             .bind::<dyn Outbox, MockOutbox>()
             .build();
@@ -301,6 +315,24 @@ impl DeleteUseCaseHarness {
         Ok(())
     }
 
+    async fn recorded_audit_actions(&self) -> Vec<AuditAction> {
+        let audit_log_service = self.catalog.get_one::<dyn AuditLogService>().unwrap();
+        audit_log_service
+            .list_events(
+                &AuditEventFilter::default(),
+                PaginationOpts {
+                    limit: 100,
+                    offset: 0,
+                },
+            )
+            .await
+            .unwrap()
+            .list
+            .into_iter()
+            .map(|event| event.action)
+            .collect()
+    }
+
     async fn dependencies_eager_initialization(&self) {
         let dependency_graph_service = self
             .catalog
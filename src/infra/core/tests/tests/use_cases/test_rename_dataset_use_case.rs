@@ -10,10 +10,14 @@
 use std::assert_matches::assert_matches;
 use std::sync::Arc;
 
+use database_common::PaginationOpts;
 use dill::{Catalog, Component};
 use kamu::testing::{MetadataFactory, MockDatasetActionAuthorizer};
 use kamu::{DatasetRepositoryLocalFs, DatasetRepositoryWriter, RenameDatasetUseCaseImpl};
 use kamu_accounts::CurrentAccountSubject;
+use kamu_audit_log::{AuditAction, AuditEvent, AuditEventFilter, AuditLogService};
+use kamu_audit_log_inmem::InMemoryAuditEventRepository;
+use kamu_audit_log_services::AuditLogServiceImpl;
 use kamu_core::auth::DatasetActionAuthorizer;
 use kamu_core::{
     CreateDatasetResult,
@@ -37,7 +41,7 @@ async fn test_rename_dataset_success_via_ref() {
     let alias_bar = DatasetAlias::new(None, DatasetName::new_unchecked("bar"));
 
     let mock_authorizer =
-        MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_foo, 1, true);
+        MockDatasetActionAuthorizer::new().expect_check_rename_dataset(&alias_foo, 1, true);
     let mut mock_outbox = MockOutbox::new();
     RenameUseCaseHarness::add_outbox_dataset_renamed_expectation(&mut mock_outbox, 1);
 
@@ -61,6 +65,15 @@ async fn test_rename_dataset_success_via_ref() {
         Err(GetDatasetError::NotFound(_))
     );
     assert_matches!(harness.check_dataset_exists(&alias_bar).await, Ok(_));
+
+    assert_matches!(
+        harness.recorded_audit_events().await.as_slice(),
+        [AuditEvent {
+            action: AuditAction::DatasetRenamed,
+            details: Some(details),
+            ..
+        }] if details == "Renamed dataset 'foo' to 'bar'"
+    );
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -89,7 +102,7 @@ async fn test_rename_dataset_unauthorized() {
     let alias_foo = DatasetAlias::new(None, DatasetName::new_unchecked("foo"));
 
     let harness = RenameUseCaseHarness::new(
-        MockDatasetActionAuthorizer::new().expect_check_write_dataset(&alias_foo, 1, false),
+        MockDatasetActionAuthorizer::new().expect_check_rename_dataset(&alias_foo, 1, false),
         MockOutbox::new(),
     );
 
@@ -107,6 +120,7 @@ async fn test_rename_dataset_unauthorized() {
     );
 
     assert_matches!(harness.check_dataset_exists(&alias_foo).await, Ok(_));
+    assert!(harness.recorded_audit_events().await.is_empty());
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -140,6 +154,8 @@ impl RenameUseCaseHarness {
             .add_value(mock_dataset_action_authorizer)
             .bind::<dyn DatasetActionAuthorizer, MockDatasetActionAuthorizer>()
             .add::<SystemTimeSourceDefault>()
+            .add::<AuditLogServiceImpl>()
+            .add::<InMemoryAuditEventRepository>()
             .add_value(mock_outbox)
             .bind::<dyn Outbox, MockOutbox>()
             .build();
@@ -181,6 +197,21 @@ impl RenameUseCaseHarness {
         Ok(())
     }
 
+    async fn recorded_audit_events(&self) -> Vec<AuditEvent> {
+        let audit_log_service = self.catalog.get_one::<dyn AuditLogService>().unwrap();
+        audit_log_service
+            .list_events(
+                &AuditEventFilter::default(),
+                PaginationOpts {
+                    limit: 100,
+                    offset: 0,
+                },
+            )
+            .await
+            .unwrap()
+            .list
+    }
+
     fn add_outbox_dataset_renamed_expectation(mock_outbox: &mut MockOutbox, times: usize) {
         mock_outbox
             .expect_post_message_as_json()
@@ -0,0 +1,82 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use container_runtime::*;
+use kamu::utils::docker_images;
+
+pub struct KafkaBroker {
+    pub container_name: String,
+    pub address: String,
+    pub host_port: u16,
+    #[allow(dead_code)]
+    container: ContainerProcess,
+}
+
+impl KafkaBroker {
+    pub const IMAGE: &'static str = docker_images::REDPANDA;
+
+    pub async fn new() -> Self {
+        let container_runtime = ContainerRuntime::default();
+        container_runtime
+            .ensure_image(Self::IMAGE, None)
+            .await
+            .unwrap();
+
+        let server_port = 9092;
+
+        // Kafka clients connect to the address advertised by the broker, so host port
+        // has to be known before the container starts
+        let host_port = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            listener.local_addr().unwrap().port()
+        };
+
+        let address = container_runtime.get_runtime_host_addr();
+
+        let container = container_runtime
+            .run_attached(Self::IMAGE)
+            .random_container_name_with_prefix("kamu-test-kafka-")
+            .map_port(host_port, server_port)
+            .args([
+                "redpanda".to_string(),
+                "start".to_string(),
+                "--mode".to_string(),
+                "dev-container".to_string(),
+                "--smp".to_string(),
+                "1".to_string(),
+                "--kafka-addr".to_string(),
+                format!("PLAINTEXT://0.0.0.0:{server_port}"),
+                "--advertise-kafka-addr".to_string(),
+                format!("PLAINTEXT://{address}:{host_port}"),
+            ])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap();
+
+        container
+            .wait_for_host_socket(server_port, Duration::from_secs(30))
+            .await
+            .unwrap();
+
+        Self {
+            container_name: container.container_name().to_string(),
+            container,
+            address,
+            host_port,
+        }
+    }
+
+    pub fn broker_addr(&self) -> String {
+        format!("{}:{}", self.address, self.host_port)
+    }
+}
@@ -11,6 +11,8 @@
 mod ftp_server;
 mod http_server;
 mod ipfs_daemon;
+#[cfg(feature = "ingest-kafka")]
+mod kafka_broker;
 #[cfg(feature = "ingest-mqtt")]
 mod mqtt_broker;
 
@@ -20,5 +22,7 @@ pub mod mock_engine_provisioner;
 pub use ftp_server::*;
 pub use http_server::*;
 pub use ipfs_daemon::*;
+#[cfg(feature = "ingest-kafka")]
+pub use kafka_broker::*;
 #[cfg(feature = "ingest-mqtt")]
 pub use mqtt_broker::*;
@@ -11,7 +11,9 @@
 #![feature(let_chains)]
 
 pub mod merge_strategies;
+pub mod partitioning;
 pub mod readers;
+pub mod statistics;
 mod visitor;
 mod writer;
 
@@ -57,8 +57,9 @@ impl MergeStrategy for MergeStrategyLedger {
             new
         } else {
             let cols: Vec<_> = self.primary_key.iter().map(String::as_str).collect();
+            let prev = super::align_prev_with_new(prev.unwrap(), &new)?;
 
-            new.join(prev.unwrap(), JoinType::LeftAnti, &cols, &cols, None)
+            new.join(prev, JoinType::LeftAnti, &cols, &cols, None)
                 .int_err()?
         };
 
@@ -7,6 +7,11 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use datafusion::prelude::*;
+use datafusion::scalar::ScalarValue;
+use internal_error::*;
+use kamu_data_utils::schema::evolution::is_numeric_widening;
+
 mod append;
 mod ledger;
 mod snapshot;
@@ -14,3 +19,37 @@ mod snapshot;
 pub use append::*;
 pub use ledger::*;
 pub use snapshot::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Aligns previously written data with the schema of the new data to account
+/// for additive schema evolution: columns missing in the previous data are
+/// populated with nulls and columns of narrower types are cast to the types of
+/// the new data.
+pub(crate) fn align_prev_with_new(
+    mut prev: DataFrame,
+    new: &DataFrame,
+) -> Result<DataFrame, InternalError> {
+    for new_field in new.schema().fields() {
+        let expr = match prev.schema().field_with_unqualified_name(new_field.name()) {
+            Err(_) => cast(
+                Expr::Literal(ScalarValue::Null),
+                new_field.data_type().clone(),
+            ),
+            Ok(prev_field)
+                if prev_field.data_type() != new_field.data_type()
+                    && is_numeric_widening(prev_field.data_type(), new_field.data_type()) =>
+            {
+                cast(
+                    col(Column::from_name(new_field.name())),
+                    new_field.data_type().clone(),
+                )
+            }
+            Ok(_) => continue,
+        };
+
+        prev = prev.with_column(new_field.name(), expr).int_err()?;
+    }
+
+    Ok(prev)
+}
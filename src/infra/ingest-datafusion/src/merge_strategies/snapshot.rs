@@ -120,6 +120,22 @@ impl MergeStrategySnapshot {
         Ok(state)
     }
 
+    /// Computes changes that turn the `old` state into the `new` state. States
+    /// are expected to contain only data columns, i.e. no offset, operation
+    /// type, or system time.
+    pub fn diff(&self, old: DataFrame, new: DataFrame) -> Result<DataFrame, InternalError> {
+        // Account for columns that were added or widened by schema evolution
+        let old = super::align_prev_with_new(old, &new)?;
+
+        let res = self
+            .cdc_diff(old, new)
+            .map_err(|e| e.0)?
+            .sort(self.sort_order())
+            .int_err()?;
+
+        Ok(res)
+    }
+
     /// Returns a filter like:
     ///
     /// ```text
@@ -254,7 +270,6 @@ impl MergeStrategySnapshot {
         old: DataFrame,
         new: DataFrame,
     ) -> Result<DataFrame, DataFusionErrorWrapped> {
-        // TODO: Schema evolution
         let a_old = TableReference::bare("old");
         let a_new = TableReference::bare("new");
         let old_col = |name: &str| -> Expr { Expr::Column(Column::new(Some(a_old.clone()), name)) };
@@ -370,6 +385,9 @@ impl MergeStrategy for MergeStrategySnapshot {
             .without_columns(&[&self.vocab.offset_column, &self.vocab.operation_type_column])
             .int_err()?;
 
+        // Account for columns that were added or widened by schema evolution
+        let proj = super::align_prev_with_new(proj, &new)?;
+
         // Diff state with new data
         let res = self.cdc_diff(proj, new)?;
 
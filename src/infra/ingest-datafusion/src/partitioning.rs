@@ -0,0 +1,216 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+
+use datafusion::arrow::array::{Array, StringArray, UInt64Array};
+use datafusion::arrow::datatypes::DataType;
+use datafusion::functions_aggregate::min_max::min;
+use datafusion::parquet::file::reader::{FileReader, SerializedFileReader};
+use datafusion::prelude::*;
+use internal_error::*;
+use kamu_core::utils::partitioned_slices::{read_partitioning_spec, write_slice_partitions};
+use kamu_core::*;
+use opendatafabric as odf;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Slices with more distinct partition values than this are left
+/// unpartitioned, as an index of this size would not outweigh its overhead
+pub const MAX_PARTITIONS_PER_SLICE: usize = 1000;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the partition values of every row group of a data slice file.
+///
+/// Relies on records of the slice being stored in the offset order without
+/// gaps, which holds for all files produced by the writers and the compaction.
+/// Returns `None` when the slice cannot be partitioned according to the spec.
+#[tracing::instrument(level = "debug", skip_all, fields(?data_path))]
+pub async fn compute_slice_partitions(
+    ctx: &SessionContext,
+    spec: &PartitioningSpec,
+    offset_column: &str,
+    data_path: &Path,
+) -> Result<Option<SlicePartitions>, InternalError> {
+    let df = ctx
+        .read_parquet(
+            data_path.to_str().unwrap(),
+            ParquetReadOptions {
+                file_extension: data_path.extension().unwrap_or_default().to_str().unwrap(),
+                ..Default::default()
+            },
+        )
+        .await
+        .int_err()?;
+
+    if !df.schema().has_column_with_unqualified_name(&spec.column) {
+        tracing::warn!(
+            column = %spec.column,
+            "Partitioning column is missing in the data slice, leaving it unpartitioned",
+        );
+        return Ok(None);
+    }
+
+    let row_group_sizes: Vec<u64> = {
+        let reader =
+            SerializedFileReader::new(std::fs::File::open(data_path).int_err()?).int_err()?;
+        reader
+            .metadata()
+            .row_groups()
+            .iter()
+            .map(|rg| u64::try_from(rg.num_rows()).unwrap())
+            .collect()
+    };
+
+    // Offset column is `Int64` in the datasets written by some engines
+    let offset_col = || cast(col(Column::from_name(offset_column)), DataType::UInt64);
+
+    let Some(first_offset) = df
+        .clone()
+        .aggregate(vec![], vec![min(offset_col()).alias("offset")])
+        .int_err()?
+        .collect()
+        .await
+        .int_err()?
+        .first()
+        .and_then(|batch| {
+            let offsets = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .unwrap();
+            (!offsets.is_null(0)).then(|| offsets.value(0))
+        })
+    else {
+        return Ok(None);
+    };
+
+    let key = spec.partition_value_expr();
+    let mut row_groups = Vec::with_capacity(row_group_sizes.len());
+    let mut num_values = 0;
+    let mut offset_start = first_offset;
+
+    for num_rows in row_group_sizes {
+        if num_rows == 0 {
+            continue;
+        }
+        let offset_end = offset_start + num_rows - 1;
+
+        let mut values: Vec<Option<String>> = df
+            .clone()
+            .filter(
+                offset_col()
+                    .gt_eq(lit(offset_start))
+                    .and(offset_col().lt_eq(lit(offset_end))),
+            )
+            .int_err()?
+            .select(vec![key.clone().alias("partition")])
+            .int_err()?
+            .distinct()
+            .int_err()?
+            .collect()
+            .await
+            .int_err()?
+            .iter()
+            .flat_map(|batch| {
+                let values = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap();
+                (0..values.len())
+                    .map(|i| (!values.is_null(i)).then(|| values.value(i).to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        values.sort();
+
+        num_values += values.len();
+        if num_values > MAX_PARTITIONS_PER_SLICE {
+            tracing::warn!(
+                num_values,
+                "Too many partitions in the data slice, leaving it unpartitioned",
+            );
+            return Ok(None);
+        }
+
+        row_groups.push(RowGroupPartitions {
+            offset_start,
+            offset_end,
+            values,
+        });
+
+        offset_start = offset_end + 1;
+    }
+
+    tracing::debug!(
+        num_row_groups = row_groups.len(),
+        "Computed slice partitions"
+    );
+
+    Ok(Some(SlicePartitions {
+        spec: spec.clone(),
+        row_groups,
+    }))
+}
+
+/// Computes the partitions of a data slice file if the dataset has a
+/// partitioning spec
+pub async fn stage_slice_partitions(
+    ctx: &SessionContext,
+    dataset: &dyn Dataset,
+    vocab: &odf::DatasetVocabulary,
+    data_path: &Path,
+) -> Result<Option<SlicePartitions>, InternalError> {
+    let Some(spec) = read_partitioning_spec(dataset.as_info_repo()).await? else {
+        return Ok(None);
+    };
+
+    compute_slice_partitions(ctx, &spec, &vocab.offset_column, data_path).await
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Records the partitions of a slice that was committed into the metadata
+/// chain.
+///
+/// The index only speeds up the queries, so failures are logged instead of
+/// failing the commit that already happened.
+#[tracing::instrument(level = "debug", skip_all, fields(%physical_hash))]
+pub async fn commit_slice_partitions(
+    dataset: &dyn Dataset,
+    physical_hash: &odf::Multihash,
+    partitions: SlicePartitions,
+) {
+    if let Err(err) = commit_slice_partitions_impl(dataset, physical_hash, &partitions).await {
+        tracing::warn!(error = ?err, error_msg = %err, "Failed to record slice partitions");
+    }
+}
+
+async fn commit_slice_partitions_impl(
+    dataset: &dyn Dataset,
+    physical_hash: &odf::Multihash,
+    partitions: &SlicePartitions,
+) -> Result<(), InternalError> {
+    // Spec could've been changed while the data was being written
+    if read_partitioning_spec(dataset.as_info_repo())
+        .await?
+        .as_ref()
+        != Some(&partitions.spec)
+    {
+        tracing::warn!("Partitioning spec has changed, discarding partitions");
+        return Ok(());
+    }
+
+    write_slice_partitions(dataset.as_info_repo(), physical_hash, partitions).await
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
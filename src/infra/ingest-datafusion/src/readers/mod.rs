@@ -7,19 +7,23 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+mod avro;
 mod csv;
 mod geojson;
 mod json;
 mod ndgeojson;
 mod ndjson;
+mod orc;
 mod parquet;
 mod shapefile;
 
+pub use avro::*;
 pub use csv::*;
 pub use geojson::*;
 pub use json::*;
 pub use ndgeojson::*;
 pub use ndjson::*;
+pub use orc::*;
 pub use parquet::*;
 pub use shapefile::*;
 
@@ -57,3 +61,41 @@ macro_rules! bad_input {
 }
 
 pub(crate) use {bad_input, unsupported};
+
+/// Projects and casts columns of the data frame to match the specified schema.
+/// Used by readers of self-describing formats where the underlying reader
+/// cannot apply the user-provided schema on its own.
+pub(crate) fn coerce_to_schema(
+    df: datafusion::prelude::DataFrame,
+    schema: &datafusion::arrow::datatypes::Schema,
+) -> Result<datafusion::prelude::DataFrame, kamu_core::ingest::ReadError> {
+    use datafusion::common::Column;
+    use datafusion::prelude::*;
+    use internal_error::*;
+
+    let mut select = Vec::new();
+
+    for field in schema.fields() {
+        if df
+            .schema()
+            .field_with_unqualified_name(field.name())
+            .is_err()
+        {
+            return Err(bad_input!(
+                "Column '{}' specified in the schema is not present in the input",
+                field.name()
+            )
+            .into());
+        }
+
+        select.push(
+            cast(
+                col(Column::from_name(field.name())),
+                field.data_type().clone(),
+            )
+            .alias(field.name()),
+        );
+    }
+
+    Ok(df.select(select).int_err()?)
+}
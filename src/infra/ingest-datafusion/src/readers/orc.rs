@@ -0,0 +1,109 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::SchemaRef;
+use datafusion::parquet::arrow::ArrowWriter;
+use datafusion::prelude::*;
+use internal_error::*;
+use kamu_core::ingest::ReadError;
+use opendatafabric::*;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct ReaderOrc {
+    ctx: SessionContext,
+    schema: Option<SchemaRef>,
+    temp_path: PathBuf,
+}
+
+impl ReaderOrc {
+    pub async fn new(
+        ctx: SessionContext,
+        conf: ReadStepOrc,
+        temp_path: impl Into<PathBuf>,
+    ) -> Result<Self, ReadError> {
+        Ok(Self {
+            schema: super::from_ddl_schema(&ctx, &conf.schema)
+                .await?
+                .map(Arc::new),
+            ctx,
+            temp_path: temp_path.into(),
+        })
+    }
+
+    /// Re-encodes the file into Parquet one batch at a time, so that the file
+    /// is never fully loaded into memory and decoding errors are reported
+    /// before the data is ingested
+    fn convert_to_parquet_blocking(in_path: &Path, out_path: &Path) -> Result<(), ReadError> {
+        use datafusion::arrow::record_batch::RecordBatchReader as _;
+
+        let file = std::fs::File::open(in_path).int_err()?;
+
+        let reader = orc_rust::ArrowReaderBuilder::try_new(file)
+            .map_err(|e| bad_input!("Invalid ORC file: {e}"))?
+            .build();
+
+        let out_file = std::fs::File::create(out_path).int_err()?;
+        let mut writer = ArrowWriter::try_new(out_file, reader.schema(), None).int_err()?;
+
+        for batch in reader {
+            let batch = batch.map_err(|e| bad_input!("Invalid ORC file: {e}"))?;
+            writer.write(&batch).int_err()?;
+        }
+
+        writer.close().int_err()?;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl Reader for ReaderOrc {
+    async fn input_schema(&self) -> Option<SchemaRef> {
+        self.schema.clone()
+    }
+
+    async fn read(&self, path: &Path) -> Result<DataFrame, ReadError> {
+        // TODO: PERF: Consider registering a streaming table provider instead of
+        // re-encoding the data into Parquet which DataFusion can read natively
+        let in_path = path.to_path_buf();
+        let out_path = self.temp_path.clone();
+        tokio::task::spawn_blocking(move || Self::convert_to_parquet_blocking(&in_path, &out_path))
+            .await
+            .int_err()??;
+
+        let df = self
+            .ctx
+            .read_parquet(
+                self.temp_path.to_str().unwrap(),
+                ParquetReadOptions {
+                    file_extension: self
+                        .temp_path
+                        .extension()
+                        .and_then(|s| s.to_str())
+                        .unwrap_or(""),
+                    ..Default::default()
+                },
+            )
+            .await
+            .int_err()?;
+
+        if let Some(schema) = &self.schema {
+            super::coerce_to_schema(df, schema)
+        } else {
+            Ok(df)
+        }
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::path::Path;
+
+use datafusion::arrow::array::{Array, AsArray, Int64Array};
+use datafusion::arrow::datatypes::DataType;
+use datafusion::functions_aggregate::count::count;
+use datafusion::functions_aggregate::min_max::{max, min};
+use datafusion::prelude::*;
+use internal_error::*;
+use kamu_core::utils::slice_statistics::{read_slice_statistics_config, write_slice_statistics};
+use kamu_core::*;
+use opendatafabric as odf;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Desired probability of a bloom filter reporting a value that is absent
+pub const BLOOM_FILTER_FALSE_POSITIVE_RATE: f64 = 0.01;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the column statistics of a local data file if the dataset has the
+/// slice statistics enabled
+pub async fn stage_slice_statistics(
+    ctx: &SessionContext,
+    dataset: &dyn Dataset,
+    data_path: &Path,
+) -> Result<Option<SliceStatistics>, InternalError> {
+    let Some(config) = read_slice_statistics_config(dataset.as_info_repo()).await? else {
+        return Ok(None);
+    };
+
+    compute_slice_statistics(ctx, &config, data_path)
+        .await
+        .map(Some)
+}
+
+/// Computes the column statistics of a local data file
+#[tracing::instrument(level = "debug", skip_all, fields(?data_path))]
+pub async fn compute_slice_statistics(
+    ctx: &SessionContext,
+    config: &SliceStatisticsConfig,
+    data_path: &Path,
+) -> Result<SliceStatistics, InternalError> {
+    let df = ctx
+        .read_parquet(
+            data_path.to_str().unwrap(),
+            ParquetReadOptions {
+                file_extension: data_path.extension().unwrap_or_default().to_str().unwrap(),
+                ..Default::default()
+            },
+        )
+        .await
+        .int_err()?;
+
+    let columns = compute_column_statistics(df, &config.bloom_filter_columns).await?;
+    Ok(SliceStatistics { columns })
+}
+
+/// Computes min/max values and null counts of all columns with a supported
+/// type, and bloom filters of the values for the specified columns
+pub async fn compute_column_statistics(
+    df: DataFrame,
+    bloom_filter_columns: &[String],
+) -> Result<Vec<ColumnStatistics>, InternalError> {
+    let columns: Vec<String> = df
+        .schema()
+        .fields()
+        .iter()
+        .filter(|f| is_supported_type(f.data_type()))
+        .map(|f| f.name().clone())
+        .collect();
+
+    if columns.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut aggr = Vec::new();
+    for (i, name) in columns.iter().enumerate() {
+        let c = col(Column::from_name(name));
+        aggr.push(cast(min(c.clone()), DataType::Utf8).alias(format!("min_{i}")));
+        aggr.push(cast(max(c.clone()), DataType::Utf8).alias(format!("max_{i}")));
+        aggr.push(count(c).alias(format!("count_{i}")));
+    }
+    aggr.push(count(lit(1)).alias("num_records"));
+
+    let batches = df
+        .clone()
+        .aggregate(Vec::new(), aggr)
+        .int_err()?
+        .collect()
+        .await
+        .int_err()?;
+
+    assert_eq!(batches.len(), 1);
+    let batch = &batches[0];
+
+    let get_count = |name: &str| {
+        batch
+            .column_by_name(name)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .value(0)
+    };
+    let get_str = |name: &str| {
+        let array = batch.column_by_name(name).unwrap().as_string::<i32>();
+        (!array.is_null(0)).then(|| array.value(0).to_string())
+    };
+
+    let num_records = get_count("num_records");
+
+    let mut statistics = Vec::new();
+    for (i, name) in columns.into_iter().enumerate() {
+        let bloom_filter = if bloom_filter_columns.contains(&name) {
+            Some(build_bloom_filter(df.clone(), &name).await?)
+        } else {
+            None
+        };
+
+        statistics.push(ColumnStatistics {
+            min: get_str(&format!("min_{i}")),
+            max: get_str(&format!("max_{i}")),
+            null_count: u64::try_from(num_records - get_count(&format!("count_{i}"))).unwrap(),
+            bloom_filter,
+            name,
+        });
+    }
+
+    Ok(statistics)
+}
+
+async fn build_bloom_filter(df: DataFrame, column: &str) -> Result<BloomFilter, InternalError> {
+    let c = col(Column::from_name(column));
+
+    let batches = df
+        .filter(c.clone().is_not_null())
+        .int_err()?
+        .select(vec![cast(c, DataType::Utf8).alias("value")])
+        .int_err()?
+        .distinct()
+        .int_err()?
+        .collect()
+        .await
+        .int_err()?;
+
+    let num_values = batches.iter().map(|b| b.num_rows()).sum();
+    let mut bloom_filter = BloomFilter::with_capacity(num_values, BLOOM_FILTER_FALSE_POSITIVE_RATE);
+
+    for batch in &batches {
+        for value in batch.column(0).as_string::<i32>().iter().flatten() {
+            bloom_filter.insert(value);
+        }
+    }
+
+    Ok(bloom_filter)
+}
+
+/// Types whose values can be ordered and round-trip via a string representation
+pub fn is_supported_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type,
+        DataType::Boolean
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Decimal128(_, _)
+            | DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Date32
+            | DataType::Date64
+            | DataType::Timestamp(_, _)
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Records the statistics of a data file that was added to the dataset.
+///
+/// Statistics only speed up the queries, so failures are logged instead of
+/// failing the commit that already happened.
+#[tracing::instrument(level = "debug", skip_all, fields(%physical_hash))]
+pub async fn commit_slice_statistics(
+    dataset: &dyn Dataset,
+    physical_hash: &odf::Multihash,
+    statistics: SliceStatistics,
+) {
+    if let Err(err) = commit_slice_statistics_impl(dataset, physical_hash, &statistics).await {
+        tracing::warn!(error = ?err, error_msg = %err, "Failed to record slice statistics");
+    }
+}
+
+async fn commit_slice_statistics_impl(
+    dataset: &dyn Dataset,
+    physical_hash: &odf::Multihash,
+    statistics: &SliceStatistics,
+) -> Result<(), InternalError> {
+    // Statistics could've been disabled while the data was being written
+    if read_slice_statistics_config(dataset.as_info_repo())
+        .await?
+        .is_none()
+    {
+        tracing::warn!("Slice statistics were disabled, discarding statistics");
+        return Ok(());
+    }
+
+    write_slice_statistics(dataset.as_info_repo(), physical_hash, statistics).await
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -23,6 +23,11 @@ use datafusion::prelude::*;
 use internal_error::*;
 use kamu_core::ingest::*;
 use kamu_core::*;
+use kamu_data_utils::schema::evolution::{
+    evolve_schema,
+    is_numeric_widening,
+    validate_schema_evolution,
+};
 use odf::{AsTypedBlock, DatasetVocabulary, MetadataEvent};
 use opendatafabric as odf;
 
@@ -36,6 +41,7 @@ pub struct DataWriterDataFusion {
     dataset: Arc<dyn Dataset>,
     merge_strategy: Arc<dyn MergeStrategy>,
     block_ref: BlockRef,
+    schema_evolution: SchemaEvolutionRules,
 
     // Mutable
     meta: DataWriterMetadataState,
@@ -72,6 +78,7 @@ impl DataWriterDataFusion {
         dataset: Arc<dyn Dataset>,
         merge_strategy: Arc<dyn MergeStrategy>,
         block_ref: BlockRef,
+        schema_evolution: SchemaEvolutionRules,
         metadata_state: DataWriterMetadataState,
     ) -> Self {
         Self {
@@ -79,6 +86,7 @@ impl DataWriterDataFusion {
             dataset,
             merge_strategy,
             block_ref,
+            schema_evolution,
             meta: metadata_state,
         }
     }
@@ -217,6 +225,40 @@ impl DataWriterDataFusion {
         }
     }
 
+    /// Prepares new data for additive schema evolution by casting columns with
+    /// narrower types to the types declared in the dataset schema and moving
+    /// all newly added columns to the end
+    fn align_with_prev_schema(
+        &self,
+        df: DataFrame,
+        prev_schema: &SchemaRef,
+    ) -> Result<DataFrame, InternalError> {
+        let mut select = Vec::new();
+
+        for prev_field in prev_schema.fields() {
+            let Ok(field) = df.schema().field_with_unqualified_name(prev_field.name()) else {
+                continue;
+            };
+
+            let expr = col(Column::from_name(prev_field.name()));
+            if field.data_type() != prev_field.data_type()
+                && is_numeric_widening(field.data_type(), prev_field.data_type())
+            {
+                select.push(cast(expr, prev_field.data_type().clone()).alias(prev_field.name()));
+            } else {
+                select.push(expr);
+            }
+        }
+
+        for field in df.schema().fields() {
+            if prev_schema.field_with_name(field.name()).is_err() {
+                select.push(col(Column::from_name(field.name())));
+            }
+        }
+
+        df.select(select).int_err()
+    }
+
     // TODO: PERF: This will not scale well as number of blocks grows
     async fn get_all_previous_data(
         &self,
@@ -240,8 +282,13 @@ impl DataWriterDataFusion {
             .read_parquet(
                 prev_data_paths,
                 ParquetReadOptions {
-                    // TODO: Specify schema
-                    schema: None,
+                    // Reading with the declared schema makes slices written before the schema
+                    // evolved readable along with the newer ones
+                    schema: if self.schema_evolution.is_enabled() {
+                        self.meta.schema.as_deref()
+                    } else {
+                        None
+                    },
                     file_extension: "",
                     // TODO: PERF: Possibly speed up by specifying `offset`
                     file_sort_order: Vec::new(),
@@ -379,6 +426,32 @@ impl DataWriterDataFusion {
         }
     }
 
+    /// Validates the schema of the new slice against the declared one,
+    /// returning the evolved schema if it needs to be committed
+    fn validate_output_schema(
+        &self,
+        prev_schema: &SchemaRef,
+        new_schema: &SchemaRef,
+    ) -> Result<Option<SchemaRef>, IncompatibleSchemaError> {
+        if !self.schema_evolution.is_enabled() {
+            Self::validate_output_schema_equivalence(prev_schema, new_schema)?;
+            return Ok(None);
+        }
+
+        match evolve_schema(prev_schema, new_schema, &self.schema_evolution) {
+            Ok(None) => Ok(None),
+            Ok(Some(evolved_schema)) => {
+                tracing::info!(schema = ?evolved_schema, "Evolving dataset schema");
+                Ok(Some(SchemaRef::new(evolved_schema)))
+            }
+            Err(err) => Err(IncompatibleSchemaError::new(
+                format!("Schema of the new slice cannot be evolved from the previous one: {err}"),
+                prev_schema.clone(),
+                new_schema.clone(),
+            )),
+        }
+    }
+
     fn is_schema_equivalent(lhs: &SchemaRef, rhs: &SchemaRef) -> bool {
         lhs.fields().len() == rhs.fields().len()
             && lhs
@@ -609,6 +682,7 @@ impl DataWriter for DataWriterDataFusion {
             add_data: Some(add_data),
             new_schema: None,
             data_file: None,
+            partitions: None,
         };
 
         let commit = self.commit(staged).await?;
@@ -621,7 +695,7 @@ impl DataWriter for DataWriterDataFusion {
         new_data: Option<DataFrame>,
         opts: WriteDataOpts,
     ) -> Result<StageDataResult, StageDataError> {
-        let (add_data, new_schema, data_file) = if let Some(new_data) = new_data {
+        let (add_data, new_schema, data_file, partitions) = if let Some(new_data) = new_data {
             self.validate_input(&new_data)?;
 
             // Normalize timestamps
@@ -634,6 +708,13 @@ impl DataWriter for DataWriterDataFusion {
             // Populate event time with nulls if missing, using matching type to prev data
             let df = self.ensure_event_time_column(df, prev.as_ref().map(DataFrame::schema))?;
 
+            let df = match &self.meta.schema {
+                Some(prev_schema) if self.schema_evolution.is_enabled() => {
+                    self.align_with_prev_schema(df, prev_schema)?
+                }
+                _ => df,
+            };
+
             let df = self.merge_strategy.merge(prev, df)?;
 
             tracing::debug!(
@@ -650,17 +731,33 @@ impl DataWriter for DataWriterDataFusion {
                 self.meta.prev_offset.map_or(0, |e| e + 1),
             )?;
 
-            // Validate schema matches the declared one
+            // Validate schema matches the declared one or can be evolved from it, and
+            // determine whether we need to commit `SetDataSchema` event
             let new_schema = SchemaRef::new(df.schema().into());
             tracing::info!(schema = ?new_schema, "Final output schema");
 
-            if let Some(prev_schema) = &self.meta.schema {
-                Self::validate_output_schema_equivalence(prev_schema, &new_schema)?;
-            }
+            let new_schema = if let Some(prev_schema) = &self.meta.schema {
+                self.validate_output_schema(prev_schema, &new_schema)?
+            } else {
+                Some(new_schema)
+            };
 
             // Write output
             let data_file = self.write_output(opts.data_staging_path, df).await?;
 
+            // Index the partitions of the output if dataset has a partitioning spec
+            let partitions = if let Some(data_file) = &data_file {
+                crate::partitioning::stage_slice_partitions(
+                    &self.ctx,
+                    self.dataset.as_ref(),
+                    &self.meta.vocab,
+                    data_file.as_path(),
+                )
+                .await?
+            } else {
+                None
+            };
+
             // Prepare commit info
             let prev_offset = self.meta.prev_offset;
             let prev_checkpoint = self.meta.prev_checkpoint.clone();
@@ -677,7 +774,8 @@ impl DataWriter for DataWriterDataFusion {
                         new_watermark: opts.new_watermark.or(prev_watermark),
                         new_source_state,
                     },
-                    Some(new_schema),
+                    new_schema,
+                    None,
                     None,
                 )
             } else {
@@ -696,8 +794,9 @@ impl DataWriter for DataWriterDataFusion {
                         new_watermark: opts.new_watermark.or(new_watermark_from_data),
                         new_source_state,
                     },
-                    Some(new_schema),
+                    new_schema,
                     data_file,
+                    partitions,
                 )
             }
         } else {
@@ -710,14 +809,7 @@ impl DataWriter for DataWriterDataFusion {
                 new_source_state: opts.new_source_state,
             };
 
-            (add_data, None, None)
-        };
-
-        // Do we need to commit `SetDataSchema` event?
-        let new_schema = if self.meta.schema.is_none() {
-            new_schema
-        } else {
-            None
+            (add_data, None, None, None)
         };
 
         // Do we have anything to commit in `AddData` event?
@@ -736,6 +828,7 @@ impl DataWriter for DataWriterDataFusion {
                 add_data,
                 new_schema,
                 data_file,
+                partitions,
             })
         } else {
             Err(EmptyCommitError {}.into())
@@ -750,6 +843,12 @@ impl DataWriter for DataWriterDataFusion {
 
         // Commit `SetDataSchema` event
         if let Some(new_schema) = staged.new_schema {
+            // Additive evolution is only enforced for schemas produced by ingest, chains
+            // received from other nodes are not subject to it
+            if let Some(prev_schema) = &self.meta.schema {
+                validate_schema_evolution(prev_schema, &new_schema).int_err()?;
+            }
+
             // TODO: Make commit of schema and data atomic
             let commit_schema_result = self
                 .dataset
@@ -770,6 +869,18 @@ impl DataWriter for DataWriterDataFusion {
             self.meta.schema = Some(new_schema);
         }
 
+        // Collect column statistics before the data file is moved into the repository
+        let statistics = if let Some(data_file) = &staged.data_file {
+            crate::statistics::stage_slice_statistics(
+                &self.ctx,
+                self.dataset.as_ref(),
+                data_file.as_path(),
+            )
+            .await?
+        } else {
+            None
+        };
+
         // Commit `AddData` event
         let add_data_block = if let Some(add_data) = staged.add_data {
             let commit_data_result = self
@@ -803,6 +914,24 @@ impl DataWriter for DataWriterDataFusion {
             if let Some(new_data) = &new_block.event.new_data {
                 self.meta.prev_offset = Some(new_data.offset_interval.end);
                 self.meta.data_slices.push(new_data.physical_hash.clone());
+
+                if let Some(partitions) = staged.partitions {
+                    crate::partitioning::commit_slice_partitions(
+                        self.dataset.as_ref(),
+                        &new_data.physical_hash,
+                        partitions,
+                    )
+                    .await;
+                }
+
+                if let Some(statistics) = statistics {
+                    crate::statistics::commit_slice_statistics(
+                        self.dataset.as_ref(),
+                        &new_data.physical_hash,
+                        statistics,
+                    )
+                    .await;
+                }
             }
 
             self.meta.prev_checkpoint = new_block
@@ -837,6 +966,7 @@ pub struct DataWriterDataFusionBuilder {
     dataset: Arc<dyn Dataset>,
     ctx: SessionContext,
     block_ref: BlockRef,
+    schema_evolution: SchemaEvolutionRules,
     metadata_state: Option<DataWriterMetadataState>,
 }
 
@@ -846,6 +976,7 @@ impl DataWriterDataFusionBuilder {
             dataset,
             ctx,
             block_ref: BlockRef::Head,
+            schema_evolution: SchemaEvolutionRules::default(),
             metadata_state: None,
         }
     }
@@ -854,6 +985,15 @@ impl DataWriterDataFusionBuilder {
         Self { block_ref, ..self }
     }
 
+    /// Allows schema of the dataset to evolve according to specified rules
+    /// when new data does not match the declared schema
+    pub fn with_schema_evolution(self, schema_evolution: SchemaEvolutionRules) -> Self {
+        Self {
+            schema_evolution,
+            ..self
+        }
+    }
+
     pub fn metadata_state(&self) -> Option<&DataWriterMetadataState> {
         self.metadata_state.as_ref()
     }
@@ -991,6 +1131,7 @@ impl DataWriterDataFusionBuilder {
             self.dataset,
             merge_strategy,
             self.block_ref,
+            self.schema_evolution,
             metadata_state,
         )
     }
@@ -10,11 +10,13 @@
 mod test_merge_strategy_append;
 mod test_merge_strategy_ledger;
 mod test_merge_strategy_snapshot;
+mod test_reader_avro;
 mod test_reader_common;
 mod test_reader_csv;
 mod test_reader_geojson;
 mod test_reader_json;
 mod test_reader_ndgeojson;
 mod test_reader_ndjson;
+mod test_reader_orc;
 mod test_reader_parquet;
 mod test_reader_shapefile;
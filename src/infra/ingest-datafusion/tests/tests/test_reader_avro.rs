@@ -0,0 +1,174 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+use std::path::Path;
+
+use datafusion::prelude::SessionContext;
+use indoc::indoc;
+use kamu_ingest_datafusion::*;
+use opendatafabric::*;
+
+use super::test_reader_common;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_test_data(path: impl AsRef<Path>) {
+    use apache_avro::types::Record;
+
+    let schema = apache_avro::Schema::parse_str(indoc!(
+        r#"
+        {
+            "type": "record",
+            "name": "city",
+            "fields": [
+                {"name": "city", "type": "string"},
+                {"name": "population", "type": "long"}
+            ]
+        }
+        "#
+    ))
+    .unwrap();
+
+    let mut writer = apache_avro::Writer::new(&schema, std::fs::File::create(path).unwrap());
+
+    for (city, population) in [
+        ("vancouver", 675_000i64),
+        ("seattle", 733_000),
+        ("kyiv", 2_884_000),
+    ] {
+        let mut record = Record::new(writer.schema()).unwrap();
+        record.put("city", city);
+        record.put("population", population);
+        writer.append(record).unwrap();
+    }
+
+    writer.flush().unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_avro() {
+    test_reader_common::test_reader_success(
+        ReaderAvro::new(SessionContext::new(), ReadStepAvro { schema: None })
+            .await
+            .unwrap(),
+        |path| async {
+            write_test_data(path);
+        },
+        indoc!(
+            r#"
+            message arrow_schema {
+              REQUIRED BYTE_ARRAY city (STRING);
+              REQUIRED INT64 population;
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            +-----------+------------+
+            | city      | population |
+            +-----------+------------+
+            | vancouver | 675000     |
+            | seattle   | 733000     |
+            | kyiv      | 2884000    |
+            +-----------+------------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_avro_schema_coercion() {
+    test_reader_common::test_reader_success(
+        ReaderAvro::new(
+            SessionContext::new(),
+            ReadStepAvro {
+                schema: Some(vec![
+                    "population string not null".to_string(),
+                    "city string not null".to_string(),
+                ]),
+            },
+        )
+        .await
+        .unwrap(),
+        |path| async {
+            write_test_data(path);
+        },
+        indoc!(
+            r#"
+            message arrow_schema {
+              REQUIRED BYTE_ARRAY population (STRING);
+              REQUIRED BYTE_ARRAY city (STRING);
+            }
+            "#
+        ),
+        indoc!(
+            r#"
+            +------------+-----------+
+            | population | city      |
+            +------------+-----------+
+            | 675000     | vancouver |
+            | 733000     | seattle   |
+            | 2884000    | kyiv      |
+            +------------+-----------+
+            "#
+        ),
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_avro_schema_missing_column() {
+    test_reader_common::test_reader(
+        ReaderAvro::new(
+            SessionContext::new(),
+            ReadStepAvro {
+                schema: Some(vec!["country string".to_string()]),
+            },
+        )
+        .await
+        .unwrap(),
+        |path| async {
+            write_test_data(path);
+        },
+        |res| async {
+            assert_matches!(res, Err(ReadError::BadInput(_)));
+        },
+    )
+    .await;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test_group::group(engine, ingest, datafusion)]
+#[test_log::test(tokio::test)]
+async fn test_read_avro_malformed() {
+    test_reader_common::test_reader(
+        ReaderAvro::new(SessionContext::new(), ReadStepAvro { schema: None })
+            .await
+            .unwrap(),
+        |path| async {
+            std::fs::write(path, "not an avro file").unwrap();
+        },
+        |res| async {
+            assert_matches!(res, Err(ReadError::BadInput(_)));
+        },
+    )
+    .await;
+}
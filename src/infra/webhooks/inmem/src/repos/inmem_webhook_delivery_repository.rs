@@ -0,0 +1,151 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use database_common::PaginationOpts;
+use dill::*;
+use internal_error::InternalError;
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InMemoryWebhookDeliveryRepository {
+    state: Arc<Mutex<State>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct State {
+    deliveries_by_subscription_ids: HashMap<Uuid, Vec<WebhookDelivery>>,
+    retries_by_ids: HashMap<Uuid, WebhookDeliveryRetry>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn WebhookDeliveryRepository)]
+#[scope(Singleton)]
+impl InMemoryWebhookDeliveryRepository {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl WebhookDeliveryRepository for InMemoryWebhookDeliveryRepository {
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), InternalError> {
+        let mut guard = self.state.lock().unwrap();
+
+        guard
+            .deliveries_by_subscription_ids
+            .entry(delivery.subscription_id)
+            .or_default()
+            .push(delivery.clone());
+
+        Ok(())
+    }
+
+    async fn get_deliveries_count_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<usize, InternalError> {
+        let guard = self.state.lock().unwrap();
+
+        Ok(guard
+            .deliveries_by_subscription_ids
+            .get(subscription_id)
+            .map_or(0, Vec::len))
+    }
+
+    async fn get_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        pagination: &PaginationOpts,
+    ) -> Result<Vec<WebhookDelivery>, InternalError> {
+        let guard = self.state.lock().unwrap();
+
+        let Some(deliveries) = guard.deliveries_by_subscription_ids.get(subscription_id) else {
+            return Ok(vec![]);
+        };
+
+        let mut deliveries = deliveries.clone();
+        deliveries.sort_by(|a, b| {
+            b.requested_at
+                .cmp(&a.requested_at)
+                .then(b.attempt.cmp(&a.attempt))
+        });
+
+        Ok(deliveries
+            .into_iter()
+            .skip(pagination.offset)
+            .take(pagination.limit)
+            .collect())
+    }
+
+    async fn delete_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), InternalError> {
+        let mut guard = self.state.lock().unwrap();
+
+        guard.deliveries_by_subscription_ids.remove(subscription_id);
+        guard
+            .retries_by_ids
+            .retain(|_, retry| retry.subscription_id != *subscription_id);
+
+        Ok(())
+    }
+
+    async fn schedule_retry(&self, retry: &WebhookDeliveryRetry) -> Result<(), InternalError> {
+        let mut guard = self.state.lock().unwrap();
+
+        guard.retries_by_ids.insert(retry.id, retry.clone());
+
+        Ok(())
+    }
+
+    async fn get_due_retries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDeliveryRetry>, InternalError> {
+        let guard = self.state.lock().unwrap();
+
+        let mut due_retries: Vec<_> = guard
+            .retries_by_ids
+            .values()
+            .filter(|retry| retry.due_at <= now)
+            .cloned()
+            .collect();
+        due_retries.sort_by_key(|retry| retry.due_at);
+        due_retries.truncate(limit);
+
+        Ok(due_retries)
+    }
+
+    async fn delete_retry(&self, retry_id: &Uuid) -> Result<(), InternalError> {
+        let mut guard = self.state.lock().unwrap();
+
+        guard.retries_by_ids.remove(retry_id);
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,164 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use dill::*;
+use internal_error::InternalError;
+use opendatafabric::{AccountID, DatasetID};
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct InMemoryWebhookSubscriptionRepository {
+    state: Arc<Mutex<State>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct State {
+    subscription_ids_in_order: Vec<Uuid>,
+    subscriptions_by_ids: HashMap<Uuid, WebhookSubscription>,
+}
+
+impl State {
+    fn subscriptions(&self) -> impl Iterator<Item = &WebhookSubscription> {
+        self.subscription_ids_in_order
+            .iter()
+            .map(|id| self.subscriptions_by_ids.get(id).unwrap())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component(pub)]
+#[interface(dyn WebhookSubscriptionRepository)]
+#[scope(Singleton)]
+impl InMemoryWebhookSubscriptionRepository {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionRepository for InMemoryWebhookSubscriptionRepository {
+    async fn save_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), InternalError> {
+        let mut guard = self.state.lock().unwrap();
+
+        if guard
+            .subscriptions_by_ids
+            .insert(subscription.id, subscription.clone())
+            .is_none()
+        {
+            guard.subscription_ids_in_order.push(subscription.id);
+        }
+
+        Ok(())
+    }
+
+    async fn get_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError> {
+        let guard = self.state.lock().unwrap();
+
+        guard
+            .subscriptions_by_ids
+            .get(subscription_id)
+            .cloned()
+            .ok_or_else(|| {
+                GetWebhookSubscriptionError::NotFound(WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                })
+            })
+    }
+
+    async fn get_subscriptions_by_account(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<WebhookSubscription>, InternalError> {
+        let guard = self.state.lock().unwrap();
+
+        Ok(guard
+            .subscriptions()
+            .filter(|s| s.account_id == *account_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_enabled_subscriptions_for_dataset(
+        &self,
+        dataset_id: &DatasetID,
+        owner_account_id: Option<&AccountID>,
+    ) -> Result<Vec<WebhookSubscription>, InternalError> {
+        let guard = self.state.lock().unwrap();
+
+        Ok(guard
+            .subscriptions()
+            .filter(|s| s.enabled)
+            .filter(|s| match &s.dataset_id {
+                Some(subscription_dataset_id) => subscription_dataset_id == dataset_id,
+                None => owner_account_id == Some(&s.account_id),
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn set_subscription_enabled(
+        &self,
+        subscription_id: &Uuid,
+        enabled: bool,
+    ) -> Result<(), UpdateWebhookSubscriptionError> {
+        let mut guard = self.state.lock().unwrap();
+
+        let Some(subscription) = guard.subscriptions_by_ids.get_mut(subscription_id) else {
+            return Err(UpdateWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                },
+            ));
+        };
+        subscription.enabled = enabled;
+
+        Ok(())
+    }
+
+    async fn delete_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError> {
+        let mut guard = self.state.lock().unwrap();
+
+        if guard.subscriptions_by_ids.remove(subscription_id).is_none() {
+            return Err(DeleteWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                },
+            ));
+        }
+        guard
+            .subscription_ids_in_order
+            .retain(|id| id != subscription_id);
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,273 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::{PaginationOpts, TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct PostgresWebhookDeliveryRepository {
+    transaction: TransactionRefT<sqlx::Postgres>,
+}
+
+#[component(pub)]
+#[interface(dyn WebhookDeliveryRepository)]
+impl PostgresWebhookDeliveryRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryRepository for PostgresWebhookDeliveryRepository {
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_deliveries (id, subscription_id, event_id, event_type, attempt, requested_at, response_status, error)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            delivery.id,
+            delivery.subscription_id,
+            delivery.event_id,
+            delivery.event_type.as_str(),
+            i32::try_from(delivery.attempt).unwrap(),
+            delivery.requested_at,
+            delivery.response_status.map(i32::from),
+            delivery.error,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn get_deliveries_count_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!" FROM webhook_deliveries WHERE subscription_id = $1
+            "#,
+            subscription_id,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(usize::try_from(count).unwrap())
+    }
+
+    async fn get_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        pagination: &PaginationOpts,
+    ) -> Result<Vec<WebhookDelivery>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let rows = sqlx::query_as!(
+            WebhookDeliveryRowModel,
+            r#"
+            SELECT id, subscription_id, event_id, event_type, attempt, requested_at, response_status, error
+                FROM webhook_deliveries
+                WHERE subscription_id = $1
+                ORDER BY requested_at DESC, attempt DESC
+                LIMIT $2 OFFSET $3
+            "#,
+            subscription_id,
+            i64::try_from(pagination.limit).unwrap(),
+            i64::try_from(pagination.offset).unwrap(),
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn delete_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM webhook_delivery_retries WHERE subscription_id = $1
+            "#,
+            subscription_id,
+        )
+        .execute(&mut *connection_mut)
+        .await
+        .int_err()?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM webhook_deliveries WHERE subscription_id = $1
+            "#,
+            subscription_id,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn schedule_retry(&self, retry: &WebhookDeliveryRetry) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let event = serde_json::to_string(&retry.event).int_err()?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_delivery_retries (id, subscription_id, event, attempt, due_at)
+                VALUES ($1, $2, $3, $4, $5)
+            "#,
+            retry.id,
+            retry.subscription_id,
+            event,
+            i32::try_from(retry.attempt).unwrap(),
+            retry.due_at,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn get_due_retries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDeliveryRetry>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let rows = sqlx::query_as!(
+            WebhookDeliveryRetryRowModel,
+            r#"
+            SELECT id, subscription_id, event, attempt, due_at
+                FROM webhook_delivery_retries
+                WHERE due_at <= $1
+                ORDER BY due_at
+                LIMIT $2
+            "#,
+            now,
+            i64::try_from(limit).unwrap(),
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn delete_retry(&self, retry_id: &Uuid) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM webhook_delivery_retries WHERE id = $1
+            "#,
+            retry_id,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct WebhookDeliveryRowModel {
+    id: Uuid,
+    subscription_id: Uuid,
+    event_id: Uuid,
+    event_type: String,
+    attempt: i32,
+    requested_at: DateTime<Utc>,
+    response_status: Option<i32>,
+    error: Option<String>,
+}
+
+impl TryFrom<WebhookDeliveryRowModel> for WebhookDelivery {
+    type Error = InternalError;
+
+    fn try_from(row: WebhookDeliveryRowModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            subscription_id: row.subscription_id,
+            event_id: row.event_id,
+            event_type: row.event_type.parse::<WebhookEventType>().int_err()?,
+            attempt: u32::try_from(row.attempt).int_err()?,
+            requested_at: row.requested_at,
+            response_status: row
+                .response_status
+                .map(u16::try_from)
+                .transpose()
+                .int_err()?,
+            error: row.error,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct WebhookDeliveryRetryRowModel {
+    id: Uuid,
+    subscription_id: Uuid,
+    event: String,
+    attempt: i32,
+    due_at: DateTime<Utc>,
+}
+
+impl TryFrom<WebhookDeliveryRetryRowModel> for WebhookDeliveryRetry {
+    type Error = InternalError;
+
+    fn try_from(row: WebhookDeliveryRetryRowModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            subscription_id: row.subscription_id,
+            event: serde_json::from_str(&row.event).int_err()?,
+            attempt: u32::try_from(row.attempt).int_err()?,
+            due_at: row.due_at,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
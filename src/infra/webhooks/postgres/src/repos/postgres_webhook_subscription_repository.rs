@@ -0,0 +1,262 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::{TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use opendatafabric::{AccountID, DatasetID};
+use url::Url;
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct PostgresWebhookSubscriptionRepository {
+    transaction: TransactionRefT<sqlx::Postgres>,
+}
+
+#[component(pub)]
+#[interface(dyn WebhookSubscriptionRepository)]
+impl PostgresWebhookSubscriptionRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookSubscriptionRepository for PostgresWebhookSubscriptionRepository {
+    async fn save_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_subscriptions (id, account_id, target_url, event_types, dataset_id, secret, enabled, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                ON CONFLICT (id) DO UPDATE SET
+                    target_url = excluded.target_url,
+                    event_types = excluded.event_types,
+                    enabled = excluded.enabled
+            "#,
+            subscription.id,
+            subscription.account_id.to_string(),
+            subscription.target_url.as_str(),
+            format_event_types(&subscription.event_types),
+            subscription.dataset_id.as_ref().map(ToString::to_string),
+            subscription.secret,
+            subscription.enabled,
+            subscription.created_at,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn get_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<WebhookSubscription, GetWebhookSubscriptionError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let maybe_row = sqlx::query_as!(
+            WebhookSubscriptionRowModel,
+            r#"
+            SELECT id, account_id AS "account_id: _", target_url, event_types, dataset_id AS "dataset_id: _", secret, enabled, created_at
+                FROM webhook_subscriptions
+                WHERE id = $1
+            "#,
+            subscription_id,
+        )
+        .fetch_optional(connection_mut)
+        .await
+        .int_err()?;
+
+        match maybe_row {
+            Some(row) => Ok(row.try_into()?),
+            None => Err(GetWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                },
+            )),
+        }
+    }
+
+    async fn get_subscriptions_by_account(
+        &self,
+        account_id: &AccountID,
+    ) -> Result<Vec<WebhookSubscription>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let rows = sqlx::query_as!(
+            WebhookSubscriptionRowModel,
+            r#"
+            SELECT id, account_id AS "account_id: _", target_url, event_types, dataset_id AS "dataset_id: _", secret, enabled, created_at
+                FROM webhook_subscriptions
+                WHERE account_id = $1
+                ORDER BY created_at
+            "#,
+            account_id.to_string(),
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn get_enabled_subscriptions_for_dataset(
+        &self,
+        dataset_id: &DatasetID,
+        owner_account_id: Option<&AccountID>,
+    ) -> Result<Vec<WebhookSubscription>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let rows = sqlx::query_as!(
+            WebhookSubscriptionRowModel,
+            r#"
+            SELECT id, account_id AS "account_id: _", target_url, event_types, dataset_id AS "dataset_id: _", secret, enabled, created_at
+                FROM webhook_subscriptions
+                WHERE enabled = TRUE AND (
+                    dataset_id = $1 OR (dataset_id IS NULL AND account_id = $2)
+                )
+                ORDER BY created_at
+            "#,
+            dataset_id.to_string(),
+            owner_account_id.map(ToString::to_string),
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn set_subscription_enabled(
+        &self,
+        subscription_id: &Uuid,
+        enabled: bool,
+    ) -> Result<(), UpdateWebhookSubscriptionError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let update_result = sqlx::query!(
+            r#"
+            UPDATE webhook_subscriptions SET enabled = $1 WHERE id = $2
+            "#,
+            enabled,
+            subscription_id,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        if update_result.rows_affected() == 0 {
+            return Err(UpdateWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn delete_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), DeleteWebhookSubscriptionError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let delete_result = sqlx::query!(
+            r#"
+            DELETE FROM webhook_subscriptions WHERE id = $1
+            "#,
+            subscription_id,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        if delete_result.rows_affected() == 0 {
+            return Err(DeleteWebhookSubscriptionError::NotFound(
+                WebhookSubscriptionNotFoundError {
+                    subscription_id: *subscription_id,
+                },
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct WebhookSubscriptionRowModel {
+    id: Uuid,
+    account_id: AccountID,
+    target_url: String,
+    event_types: String,
+    dataset_id: Option<DatasetID>,
+    secret: String,
+    enabled: bool,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<WebhookSubscriptionRowModel> for WebhookSubscription {
+    type Error = InternalError;
+
+    fn try_from(row: WebhookSubscriptionRowModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            account_id: row.account_id,
+            target_url: Url::parse(&row.target_url).int_err()?,
+            event_types: parse_event_types(&row.event_types)?,
+            dataset_id: row.dataset_id,
+            secret: row.secret,
+            enabled: row.enabled,
+            created_at: row.created_at,
+        })
+    }
+}
+
+fn format_event_types(event_types: &[WebhookEventType]) -> String {
+    event_types
+        .iter()
+        .map(WebhookEventType::as_str)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_event_types(event_types: &str) -> Result<Vec<WebhookEventType>, InternalError> {
+    event_types
+        .split(',')
+        .map(|event_type| event_type.parse::<WebhookEventType>().int_err())
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
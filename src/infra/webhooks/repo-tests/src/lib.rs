@@ -0,0 +1,20 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+#![feature(assert_matches)]
+
+mod webhook_delivery_repository_test_suite;
+mod webhook_subscription_repository_test_suite;
+
+pub mod webhook_delivery_repo {
+    pub use crate::webhook_delivery_repository_test_suite::*;
+}
+pub mod webhook_subscription_repo {
+    pub use crate::webhook_subscription_repository_test_suite::*;
+}
@@ -0,0 +1,284 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use database_common::PaginationOpts;
+use dill::Catalog;
+use kamu_webhooks::{
+    WebhookDelivery,
+    WebhookDeliveryRepository,
+    WebhookDeliveryRetry,
+    WebhookEvent,
+    WebhookEventPayload,
+    WebhookEventType,
+    WebhookSubscription,
+    WebhookSubscriptionRepository,
+};
+use opendatafabric::{AccountID, DatasetID};
+use uuid::Uuid;
+
+use crate::webhook_subscription_repository_test_suite::new_subscription;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_no_deliveries(catalog: &Catalog) {
+    let delivery_repo = catalog.get_one::<dyn WebhookDeliveryRepository>().unwrap();
+
+    let subscription = save_subscription(catalog).await;
+
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_count_by_subscription(&subscription.id)
+            .await
+            .unwrap(),
+        0
+    );
+    assert!(delivery_repo
+        .get_deliveries_by_subscription(
+            &subscription.id,
+            &PaginationOpts {
+                offset: 0,
+                limit: 10,
+            }
+        )
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_save_and_get_deliveries(catalog: &Catalog) {
+    let delivery_repo = catalog.get_one::<dyn WebhookDeliveryRepository>().unwrap();
+
+    let subscription = save_subscription(catalog).await;
+    let other_subscription = save_subscription(catalog).await;
+
+    let event_id = Uuid::new_v4();
+    let start = Utc::now().round_subsecs(6);
+
+    let failed_delivery = WebhookDelivery {
+        id: Uuid::new_v4(),
+        subscription_id: subscription.id,
+        event_id,
+        event_type: WebhookEventType::FlowFailed,
+        attempt: 1,
+        requested_at: start,
+        response_status: Some(503),
+        error: Some("Endpoint responded with status 503".to_string()),
+    };
+    let unreachable_delivery = WebhookDelivery {
+        id: Uuid::new_v4(),
+        attempt: 2,
+        requested_at: start + Duration::seconds(1),
+        response_status: None,
+        error: Some("Connection refused".to_string()),
+        ..failed_delivery.clone()
+    };
+    let successful_delivery = WebhookDelivery {
+        id: Uuid::new_v4(),
+        attempt: 3,
+        requested_at: start + Duration::seconds(3),
+        response_status: Some(200),
+        error: None,
+        ..failed_delivery.clone()
+    };
+    let other_delivery = WebhookDelivery {
+        id: Uuid::new_v4(),
+        subscription_id: other_subscription.id,
+        ..successful_delivery.clone()
+    };
+
+    for delivery in [
+        &failed_delivery,
+        &unreachable_delivery,
+        &successful_delivery,
+        &other_delivery,
+    ] {
+        delivery_repo.save_delivery(delivery).await.unwrap();
+    }
+
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_count_by_subscription(&subscription.id)
+            .await
+            .unwrap(),
+        3
+    );
+
+    // The most recent deliveries go first
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_by_subscription(
+                &subscription.id,
+                &PaginationOpts {
+                    offset: 0,
+                    limit: 10,
+                }
+            )
+            .await
+            .unwrap(),
+        vec![
+            successful_delivery.clone(),
+            unreachable_delivery.clone(),
+            failed_delivery.clone()
+        ]
+    );
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_by_subscription(
+                &subscription.id,
+                &PaginationOpts {
+                    offset: 1,
+                    limit: 1,
+                }
+            )
+            .await
+            .unwrap(),
+        vec![unreachable_delivery]
+    );
+
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_by_subscription(
+                &other_subscription.id,
+                &PaginationOpts {
+                    offset: 0,
+                    limit: 10,
+                }
+            )
+            .await
+            .unwrap(),
+        vec![other_delivery]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_delete_deliveries(catalog: &Catalog) {
+    let delivery_repo = catalog.get_one::<dyn WebhookDeliveryRepository>().unwrap();
+
+    let subscription = save_subscription(catalog).await;
+
+    let delivery = WebhookDelivery {
+        id: Uuid::new_v4(),
+        subscription_id: subscription.id,
+        event_id: Uuid::new_v4(),
+        event_type: WebhookEventType::DatasetCreated,
+        attempt: 1,
+        requested_at: Utc::now().round_subsecs(6),
+        response_status: Some(204),
+        error: None,
+    };
+    delivery_repo.save_delivery(&delivery).await.unwrap();
+
+    let now = Utc::now().round_subsecs(6);
+    delivery_repo
+        .schedule_retry(&new_retry(&subscription, 2, now))
+        .await
+        .unwrap();
+
+    delivery_repo
+        .delete_deliveries_by_subscription(&subscription.id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        delivery_repo
+            .get_deliveries_count_by_subscription(&subscription.id)
+            .await
+            .unwrap(),
+        0
+    );
+    assert!(delivery_repo
+        .get_due_retries(now, 10)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_schedule_and_get_due_retries(catalog: &Catalog) {
+    let delivery_repo = catalog.get_one::<dyn WebhookDeliveryRepository>().unwrap();
+
+    let subscription = save_subscription(catalog).await;
+
+    let now = Utc::now().round_subsecs(6);
+
+    let later_retry = new_retry(&subscription, 3, now - Duration::seconds(1));
+    let earlier_retry = new_retry(&subscription, 2, now - Duration::seconds(2));
+    let future_retry = new_retry(&subscription, 2, now + Duration::seconds(1));
+
+    for retry in [&later_retry, &earlier_retry, &future_retry] {
+        delivery_repo.schedule_retry(retry).await.unwrap();
+    }
+
+    // Only retries due by now are returned, the oldest ones first
+    assert_eq!(
+        delivery_repo.get_due_retries(now, 10).await.unwrap(),
+        vec![earlier_retry.clone(), later_retry.clone()]
+    );
+    assert_eq!(
+        delivery_repo.get_due_retries(now, 1).await.unwrap(),
+        vec![earlier_retry.clone()]
+    );
+
+    delivery_repo.delete_retry(&earlier_retry.id).await.unwrap();
+
+    assert_eq!(
+        delivery_repo.get_due_retries(now, 10).await.unwrap(),
+        vec![later_retry]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn new_retry(
+    subscription: &WebhookSubscription,
+    attempt: u32,
+    due_at: DateTime<Utc>,
+) -> WebhookDeliveryRetry {
+    WebhookDeliveryRetry {
+        id: Uuid::new_v4(),
+        subscription_id: subscription.id,
+        event: WebhookEvent::new(
+            Utc::now().round_subsecs(6),
+            DatasetID::new_seeded_ed25519(b"foo"),
+            WebhookEventPayload::DatasetDeleted,
+        ),
+        attempt,
+        due_at,
+    }
+}
+
+async fn save_subscription(catalog: &Catalog) -> WebhookSubscription {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let subscription = new_subscription(
+        &AccountID::new_seeded_ed25519(b"alice"),
+        vec![
+            WebhookEventType::FlowFailed,
+            WebhookEventType::DatasetCreated,
+        ],
+        None,
+    );
+    subscription_repo
+        .save_subscription(&subscription)
+        .await
+        .unwrap();
+
+    subscription
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
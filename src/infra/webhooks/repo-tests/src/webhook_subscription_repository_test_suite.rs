@@ -0,0 +1,351 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::assert_matches::assert_matches;
+
+use chrono::{SubsecRound, Utc};
+use dill::Catalog;
+use kamu_webhooks::{
+    DeleteWebhookSubscriptionError,
+    GetWebhookSubscriptionError,
+    UpdateWebhookSubscriptionError,
+    WebhookEventType,
+    WebhookSubscription,
+    WebhookSubscriptionRepository,
+};
+use opendatafabric::{AccountID, DatasetID};
+use url::Url;
+use uuid::Uuid;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_missing_subscription_not_found(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let subscription_id = Uuid::new_v4();
+
+    assert_matches!(
+        subscription_repo.get_subscription(&subscription_id).await,
+        Err(GetWebhookSubscriptionError::NotFound(e)) if e.subscription_id == subscription_id
+    );
+    assert_matches!(
+        subscription_repo
+            .set_subscription_enabled(&subscription_id, false)
+            .await,
+        Err(UpdateWebhookSubscriptionError::NotFound(_))
+    );
+    assert_matches!(
+        subscription_repo
+            .delete_subscription(&subscription_id)
+            .await,
+        Err(DeleteWebhookSubscriptionError::NotFound(_))
+    );
+
+    let subscriptions = subscription_repo
+        .get_subscriptions_by_account(&AccountID::new_seeded_ed25519(b"alice"))
+        .await
+        .unwrap();
+    assert!(subscriptions.is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_save_and_get_subscription(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let account_id = AccountID::new_seeded_ed25519(b"alice");
+
+    let subscription = new_subscription(
+        &account_id,
+        vec![
+            WebhookEventType::FlowSucceeded,
+            WebhookEventType::FlowFailed,
+        ],
+        Some(DatasetID::new_seeded_ed25519(b"foo")),
+    );
+    subscription_repo
+        .save_subscription(&subscription)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        subscription_repo
+            .get_subscription(&subscription.id)
+            .await
+            .unwrap(),
+        subscription
+    );
+    assert_eq!(
+        subscription_repo
+            .get_subscriptions_by_account(&account_id)
+            .await
+            .unwrap(),
+        vec![subscription]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_get_subscriptions_by_account(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let alice_id = AccountID::new_seeded_ed25519(b"alice");
+    let bob_id = AccountID::new_seeded_ed25519(b"bob");
+
+    let alice_subscription_1 =
+        new_subscription(&alice_id, vec![WebhookEventType::DatasetCreated], None);
+    let bob_subscription = new_subscription(&bob_id, vec![WebhookEventType::DatasetCreated], None);
+    let alice_subscription_2 = new_subscription(
+        &alice_id,
+        vec![WebhookEventType::DatasetDeleted],
+        Some(DatasetID::new_seeded_ed25519(b"foo")),
+    );
+
+    for subscription in [
+        &alice_subscription_1,
+        &bob_subscription,
+        &alice_subscription_2,
+    ] {
+        subscription_repo
+            .save_subscription(subscription)
+            .await
+            .unwrap();
+    }
+
+    let mut alice_subscriptions = subscription_repo
+        .get_subscriptions_by_account(&alice_id)
+        .await
+        .unwrap();
+    alice_subscriptions.sort_by_key(|s| s.id);
+    let mut expected_alice_subscriptions = vec![alice_subscription_1, alice_subscription_2];
+    expected_alice_subscriptions.sort_by_key(|s| s.id);
+    assert_eq!(alice_subscriptions, expected_alice_subscriptions);
+
+    assert_eq!(
+        subscription_repo
+            .get_subscriptions_by_account(&bob_id)
+            .await
+            .unwrap(),
+        vec![bob_subscription]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_get_enabled_subscriptions_for_dataset(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let alice_id = AccountID::new_seeded_ed25519(b"alice");
+    let bob_id = AccountID::new_seeded_ed25519(b"bob");
+    let foo_id = DatasetID::new_seeded_ed25519(b"foo");
+    let bar_id = DatasetID::new_seeded_ed25519(b"bar");
+
+    let alice_all_datasets = new_subscription(&alice_id, vec![WebhookEventType::FlowFailed], None);
+    let alice_foo = new_subscription(
+        &alice_id,
+        vec![WebhookEventType::FlowFailed],
+        Some(foo_id.clone()),
+    );
+    let alice_bar = new_subscription(
+        &alice_id,
+        vec![WebhookEventType::FlowFailed],
+        Some(bar_id.clone()),
+    );
+    let bob_all_datasets = new_subscription(&bob_id, vec![WebhookEventType::FlowFailed], None);
+    let bob_foo = new_subscription(
+        &bob_id,
+        vec![WebhookEventType::FlowFailed],
+        Some(foo_id.clone()),
+    );
+
+    for subscription in [
+        &alice_all_datasets,
+        &alice_foo,
+        &alice_bar,
+        &bob_all_datasets,
+        &bob_foo,
+    ] {
+        subscription_repo
+            .save_subscription(subscription)
+            .await
+            .unwrap();
+    }
+
+    // Dataset-scoped subscriptions and account-wide subscriptions of the owner
+    assert_eq!(
+        sorted_ids(
+            subscription_repo
+                .get_enabled_subscriptions_for_dataset(&foo_id, Some(&alice_id))
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|s| s.id)
+        ),
+        sorted_ids([alice_all_datasets.id, alice_foo.id, bob_foo.id])
+    );
+
+    // Owner is unknown: only dataset-scoped subscriptions
+    assert_eq!(
+        sorted_ids(
+            subscription_repo
+                .get_enabled_subscriptions_for_dataset(&foo_id, None)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|s| s.id)
+        ),
+        sorted_ids([alice_foo.id, bob_foo.id])
+    );
+
+    // Disabled subscriptions are excluded
+    subscription_repo
+        .set_subscription_enabled(&alice_all_datasets.id, false)
+        .await
+        .unwrap();
+    subscription_repo
+        .set_subscription_enabled(&bob_foo.id, false)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        sorted_ids(
+            subscription_repo
+                .get_enabled_subscriptions_for_dataset(&foo_id, Some(&alice_id))
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|s| s.id)
+        ),
+        sorted_ids([alice_foo.id])
+    );
+    assert_eq!(
+        sorted_ids(
+            subscription_repo
+                .get_enabled_subscriptions_for_dataset(&bar_id, Some(&bob_id))
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|s| s.id)
+        ),
+        sorted_ids([alice_bar.id, bob_all_datasets.id])
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_set_subscription_enabled(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let subscription = new_subscription(
+        &AccountID::new_seeded_ed25519(b"alice"),
+        vec![WebhookEventType::DatasetHeadUpdated],
+        None,
+    );
+    subscription_repo
+        .save_subscription(&subscription)
+        .await
+        .unwrap();
+
+    subscription_repo
+        .set_subscription_enabled(&subscription.id, false)
+        .await
+        .unwrap();
+    assert!(
+        !subscription_repo
+            .get_subscription(&subscription.id)
+            .await
+            .unwrap()
+            .enabled
+    );
+
+    subscription_repo
+        .set_subscription_enabled(&subscription.id, true)
+        .await
+        .unwrap();
+    assert!(
+        subscription_repo
+            .get_subscription(&subscription.id)
+            .await
+            .unwrap()
+            .enabled
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub async fn test_delete_subscription(catalog: &Catalog) {
+    let subscription_repo = catalog
+        .get_one::<dyn WebhookSubscriptionRepository>()
+        .unwrap();
+
+    let account_id = AccountID::new_seeded_ed25519(b"alice");
+
+    let subscription_1 = new_subscription(&account_id, vec![WebhookEventType::FlowFailed], None);
+    let subscription_2 = new_subscription(&account_id, vec![WebhookEventType::FlowSucceeded], None);
+
+    for subscription in [&subscription_1, &subscription_2] {
+        subscription_repo
+            .save_subscription(subscription)
+            .await
+            .unwrap();
+    }
+
+    subscription_repo
+        .delete_subscription(&subscription_1.id)
+        .await
+        .unwrap();
+
+    assert_matches!(
+        subscription_repo.get_subscription(&subscription_1.id).await,
+        Err(GetWebhookSubscriptionError::NotFound(_))
+    );
+    assert_eq!(
+        subscription_repo
+            .get_subscriptions_by_account(&account_id)
+            .await
+            .unwrap(),
+        vec![subscription_2]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn sorted_ids(ids: impl IntoIterator<Item = Uuid>) -> Vec<Uuid> {
+    let mut ids: Vec<_> = ids.into_iter().collect();
+    ids.sort();
+    ids
+}
+
+pub(crate) fn new_subscription(
+    account_id: &AccountID,
+    event_types: Vec<WebhookEventType>,
+    dataset_id: Option<DatasetID>,
+) -> WebhookSubscription {
+    WebhookSubscription::new(
+        account_id.clone(),
+        Url::parse("https://example.com/hooks").unwrap(),
+        event_types,
+        dataset_id,
+        Utc::now().round_subsecs(6),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
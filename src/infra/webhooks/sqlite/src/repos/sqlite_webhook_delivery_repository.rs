@@ -0,0 +1,295 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use chrono::{DateTime, Utc};
+use database_common::{PaginationOpts, TransactionRef, TransactionRefT};
+use dill::{component, interface};
+use internal_error::{InternalError, ResultIntoInternal};
+use uuid::Uuid;
+
+use crate::domain::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct SqliteWebhookDeliveryRepository {
+    transaction: TransactionRefT<sqlx::Sqlite>,
+}
+
+#[component(pub)]
+#[interface(dyn WebhookDeliveryRepository)]
+impl SqliteWebhookDeliveryRepository {
+    pub fn new(transaction: TransactionRef) -> Self {
+        Self {
+            transaction: transaction.into(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookDeliveryRepository for SqliteWebhookDeliveryRepository {
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let id = delivery.id.to_string();
+        let subscription_id = delivery.subscription_id.to_string();
+        let event_id = delivery.event_id.to_string();
+        let event_type = delivery.event_type.as_str();
+        let attempt = i64::from(delivery.attempt);
+        let response_status = delivery.response_status.map(i64::from);
+
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_deliveries (id, subscription_id, event_id, event_type, attempt, requested_at, response_status, error)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            id,
+            subscription_id,
+            event_id,
+            event_type,
+            attempt,
+            delivery.requested_at,
+            response_status,
+            delivery.error,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn get_deliveries_count_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<usize, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let subscription_id = subscription_id.to_string();
+
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS count FROM webhook_deliveries WHERE subscription_id = $1
+            "#,
+            subscription_id,
+        )
+        .fetch_one(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(usize::try_from(count).unwrap())
+    }
+
+    async fn get_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+        pagination: &PaginationOpts,
+    ) -> Result<Vec<WebhookDelivery>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let subscription_id = subscription_id.to_string();
+        let limit = i64::try_from(pagination.limit).unwrap();
+        let offset = i64::try_from(pagination.offset).unwrap();
+
+        let rows = sqlx::query_as!(
+            WebhookDeliveryRowModel,
+            r#"
+            SELECT id, subscription_id, event_id, event_type, attempt, requested_at AS "requested_at: _", response_status, error
+                FROM webhook_deliveries
+                WHERE subscription_id = $1
+                ORDER BY requested_at DESC, attempt DESC
+                LIMIT $2 OFFSET $3
+            "#,
+            subscription_id,
+            limit,
+            offset,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn delete_deliveries_by_subscription(
+        &self,
+        subscription_id: &Uuid,
+    ) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let subscription_id = subscription_id.to_string();
+
+        sqlx::query!(
+            r#"
+            DELETE FROM webhook_delivery_retries WHERE subscription_id = $1
+            "#,
+            subscription_id,
+        )
+        .execute(&mut *connection_mut)
+        .await
+        .int_err()?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM webhook_deliveries WHERE subscription_id = $1
+            "#,
+            subscription_id,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn schedule_retry(&self, retry: &WebhookDeliveryRetry) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let id = retry.id.to_string();
+        let subscription_id = retry.subscription_id.to_string();
+        let event = serde_json::to_string(&retry.event).int_err()?;
+        let attempt = i64::from(retry.attempt);
+
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_delivery_retries (id, subscription_id, event, attempt, due_at)
+                VALUES ($1, $2, $3, $4, $5)
+            "#,
+            id,
+            subscription_id,
+            event,
+            attempt,
+            retry.due_at,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+
+    async fn get_due_retries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDeliveryRetry>, InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let limit = i64::try_from(limit).unwrap();
+
+        let rows = sqlx::query_as!(
+            WebhookDeliveryRetryRowModel,
+            r#"
+            SELECT id, subscription_id, event, attempt, due_at AS "due_at: _"
+                FROM webhook_delivery_retries
+                WHERE due_at <= $1
+                ORDER BY due_at
+                LIMIT $2
+            "#,
+            now,
+            limit,
+        )
+        .fetch_all(connection_mut)
+        .await
+        .int_err()?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+
+    async fn delete_retry(&self, retry_id: &Uuid) -> Result<(), InternalError> {
+        let mut tr = self.transaction.lock().await;
+
+        let connection_mut = tr.connection_mut().await?;
+
+        let retry_id = retry_id.to_string();
+
+        sqlx::query!(
+            r#"
+            DELETE FROM webhook_delivery_retries WHERE id = $1
+            "#,
+            retry_id,
+        )
+        .execute(connection_mut)
+        .await
+        .int_err()?;
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct WebhookDeliveryRowModel {
+    id: String,
+    subscription_id: String,
+    event_id: String,
+    event_type: String,
+    attempt: i64,
+    requested_at: DateTime<Utc>,
+    response_status: Option<i64>,
+    error: Option<String>,
+}
+
+impl TryFrom<WebhookDeliveryRowModel> for WebhookDelivery {
+    type Error = InternalError;
+
+    fn try_from(row: WebhookDeliveryRowModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::parse_str(&row.id).int_err()?,
+            subscription_id: Uuid::parse_str(&row.subscription_id).int_err()?,
+            event_id: Uuid::parse_str(&row.event_id).int_err()?,
+            event_type: row.event_type.parse::<WebhookEventType>().int_err()?,
+            attempt: u32::try_from(row.attempt).int_err()?,
+            requested_at: row.requested_at,
+            response_status: row
+                .response_status
+                .map(u16::try_from)
+                .transpose()
+                .int_err()?,
+            error: row.error,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct WebhookDeliveryRetryRowModel {
+    id: String,
+    subscription_id: String,
+    event: String,
+    attempt: i64,
+    due_at: DateTime<Utc>,
+}
+
+impl TryFrom<WebhookDeliveryRetryRowModel> for WebhookDeliveryRetry {
+    type Error = InternalError;
+
+    fn try_from(row: WebhookDeliveryRetryRowModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Uuid::parse_str(&row.id).int_err()?,
+            subscription_id: Uuid::parse_str(&row.subscription_id).int_err()?,
+            event: serde_json::from_str(&row.event).int_err()?,
+            attempt: u32::try_from(row.attempt).int_err()?,
+            due_at: row.due_at,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,240 @@
+// Copyright Kamu Data, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use datafusion::arrow::datatypes::{DataType, Schema};
+use thiserror::Error;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Set of additive schema evolution rules that a dataset writer may apply when
+/// the schema of a new data slice differs from the previously declared one.
+///
+/// All rules are disabled by default which preserves the strict behavior of
+/// requiring exact schema equivalence.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaEvolutionRules {
+    /// Allow new nullable columns to be appended to the end of the schema
+    pub add_nullable_columns: bool,
+    /// Allow numeric types to be widened (e.g. `Int32` -> `Int64`)
+    pub widen_numeric_types: bool,
+    /// Allow previously non-nullable columns to become nullable
+    pub relax_non_null: bool,
+}
+
+impl SchemaEvolutionRules {
+    pub fn all() -> Self {
+        Self {
+            add_nullable_columns: true,
+            widen_numeric_types: true,
+            relax_non_null: true,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.add_nullable_columns || self.widen_numeric_types || self.relax_non_null
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SchemaEvolutionError {
+    #[error("Column '{column}' is missing or was reordered")]
+    ColumnMissing { column: String },
+
+    #[error("Column '{column}' changed type from {prev_type} to {new_type}")]
+    TypeChanged {
+        column: String,
+        prev_type: DataType,
+        new_type: DataType,
+    },
+
+    #[error("Column '{column}' changed its metadata")]
+    MetadataChanged { column: String },
+
+    #[error("Column '{column}' became non-nullable")]
+    NullabilityTightened { column: String },
+
+    #[error("Column '{column}' was added")]
+    ColumnAdded { column: String },
+
+    #[error("Column '{column}' was added as non-nullable")]
+    NonNullableColumnAdded { column: String },
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returns `true` if values of type `from` can be losslessly represented by
+/// type `to`
+pub fn is_numeric_widening(from: &DataType, to: &DataType) -> bool {
+    use DataType::*;
+
+    match (from, to) {
+        (Int8, Int16 | Int32 | Int64 | Float32 | Float64)
+        | (Int16, Int32 | Int64 | Float32 | Float64)
+        | (Int32, Int64 | Float64)
+        | (UInt8, UInt16 | UInt32 | UInt64 | Int16 | Int32 | Int64 | Float32 | Float64)
+        | (UInt16, UInt32 | UInt64 | Int32 | Int64 | Float32 | Float64)
+        | (UInt32, UInt64 | Int64 | Float64)
+        | (Float16, Float32 | Float64)
+        | (Float32, Float64) => true,
+        (Decimal128(p1, s1), Decimal128(p2, s2) | Decimal256(p2, s2))
+        | (Decimal256(p1, s1), Decimal256(p2, s2)) => {
+            s2 >= s1 && (i16::from(*p2) - i16::from(*s2)) >= (i16::from(*p1) - i16::from(*s1))
+        }
+        _ => false,
+    }
+}
+
+/// Computes the schema that results from applying the evolution `rules` to
+/// `prev` schema in order to accommodate the `new` one.
+///
+/// Returns `None` when `new` schema does not require `prev` schema to change.
+/// Note that a `new` schema with narrower types or stricter nullability is
+/// considered compatible and does not result in a change.
+pub fn evolve_schema(
+    prev: &Schema,
+    new: &Schema,
+    rules: &SchemaEvolutionRules,
+) -> Result<Option<Schema>, SchemaEvolutionError> {
+    let mut changed = false;
+    let mut fields = Vec::with_capacity(new.fields().len());
+
+    for (i, prev_field) in prev.fields().iter().enumerate() {
+        let Some(new_field) = new
+            .fields()
+            .get(i)
+            .filter(|f| f.name() == prev_field.name())
+        else {
+            return Err(SchemaEvolutionError::ColumnMissing {
+                column: prev_field.name().clone(),
+            });
+        };
+
+        if new_field.metadata() != prev_field.metadata() {
+            return Err(SchemaEvolutionError::MetadataChanged {
+                column: prev_field.name().clone(),
+            });
+        }
+
+        let data_type = if new_field.data_type() == prev_field.data_type()
+            || is_numeric_widening(new_field.data_type(), prev_field.data_type())
+        {
+            prev_field.data_type().clone()
+        } else if rules.widen_numeric_types
+            && is_numeric_widening(prev_field.data_type(), new_field.data_type())
+        {
+            changed = true;
+            new_field.data_type().clone()
+        } else {
+            return Err(SchemaEvolutionError::TypeChanged {
+                column: prev_field.name().clone(),
+                prev_type: prev_field.data_type().clone(),
+                new_type: new_field.data_type().clone(),
+            });
+        };
+
+        // Note: Without the relaxation rule nullability differences are ignored
+        let nullable =
+            if !prev_field.is_nullable() && new_field.is_nullable() && rules.relax_non_null {
+                changed = true;
+                true
+            } else {
+                prev_field.is_nullable()
+            };
+
+        fields.push(Arc::new(
+            prev_field
+                .as_ref()
+                .clone()
+                .with_data_type(data_type)
+                .with_nullable(nullable),
+        ));
+    }
+
+    for new_field in new.fields().iter().skip(prev.fields().len()) {
+        if !rules.add_nullable_columns {
+            return Err(SchemaEvolutionError::ColumnAdded {
+                column: new_field.name().clone(),
+            });
+        }
+        if !new_field.is_nullable() {
+            return Err(SchemaEvolutionError::NonNullableColumnAdded {
+                column: new_field.name().clone(),
+            });
+        }
+
+        changed = true;
+        fields.push(new_field.clone());
+    }
+
+    if !changed {
+        return Ok(None);
+    }
+
+    Ok(Some(Schema::new_with_metadata(
+        fields,
+        prev.metadata().clone(),
+    )))
+}
+
+/// Validates that `new` schema declared for a dataset is an additive evolution
+/// of the `prev` one, i.e. that all data written under `prev` schema can be
+/// read under the `new` one.
+pub fn validate_schema_evolution(prev: &Schema, new: &Schema) -> Result<(), SchemaEvolutionError> {
+    if new.fields().len() < prev.fields().len() {
+        let column = prev
+            .fields()
+            .iter()
+            .find(|f| new.field_with_name(f.name()).is_err())
+            .unwrap_or(&prev.fields()[new.fields().len()])
+            .name()
+            .clone();
+        return Err(SchemaEvolutionError::ColumnMissing { column });
+    }
+
+    for (prev_field, new_field) in prev.fields().iter().zip(new.fields().iter()) {
+        if prev_field.name() != new_field.name() {
+            return Err(SchemaEvolutionError::ColumnMissing {
+                column: prev_field.name().clone(),
+            });
+        }
+        if prev_field.data_type() != new_field.data_type()
+            && !is_numeric_widening(prev_field.data_type(), new_field.data_type())
+        {
+            return Err(SchemaEvolutionError::TypeChanged {
+                column: prev_field.name().clone(),
+                prev_type: prev_field.data_type().clone(),
+                new_type: new_field.data_type().clone(),
+            });
+        }
+        if prev_field.is_nullable() && !new_field.is_nullable() {
+            return Err(SchemaEvolutionError::NullabilityTightened {
+                column: prev_field.name().clone(),
+            });
+        }
+        if prev_field.metadata() != new_field.metadata() {
+            return Err(SchemaEvolutionError::MetadataChanged {
+                column: prev_field.name().clone(),
+            });
+        }
+    }
+
+    for new_field in new.fields().iter().skip(prev.fields().len()) {
+        if !new_field.is_nullable() {
+            return Err(SchemaEvolutionError::NonNullableColumnAdded {
+                column: new_field.name().clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
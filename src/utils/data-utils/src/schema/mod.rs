@@ -9,5 +9,6 @@
 
 pub mod cmp;
 pub mod convert;
+pub mod evolution;
 pub mod format;
 pub mod parse;
@@ -7,6 +7,11 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::assert_matches::assert_matches;
+
+use datafusion::arrow::datatypes::{DataType, Field, Schema};
+use kamu_data_utils::schema::evolution::*;
+
 #[test_log::test(tokio::test)]
 async fn test_parse_ddl() {
     let ctx = datafusion::prelude::SessionContext::new();
@@ -46,3 +51,102 @@ message arrow_schema {
         "#,
     );
 }
+
+#[test]
+fn test_evolve_schema_disabled() {
+    let prev = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+
+    // Nullability differences are ignored
+    let new = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+    assert_eq!(
+        evolve_schema(&prev, &new, &SchemaEvolutionRules::default()),
+        Ok(None)
+    );
+
+    let new = Schema::new(vec![Field::new("a", DataType::Int64, false)]);
+    assert_matches!(
+        evolve_schema(&prev, &new, &SchemaEvolutionRules::default()),
+        Err(SchemaEvolutionError::TypeChanged { .. })
+    );
+
+    let new = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+    assert_matches!(
+        evolve_schema(&prev, &new, &SchemaEvolutionRules::default()),
+        Err(SchemaEvolutionError::ColumnAdded { .. })
+    );
+}
+
+#[test]
+fn test_evolve_schema_additive() {
+    let prev = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Float32, true),
+        Field::new("c", DataType::Int64, true),
+    ]);
+    let new = Schema::new(vec![
+        Field::new("a", DataType::Int32, true),
+        Field::new("b", DataType::Float64, true),
+        Field::new("c", DataType::Int32, false),
+        Field::new("d", DataType::Utf8, true),
+    ]);
+
+    assert_eq!(
+        evolve_schema(&prev, &new, &SchemaEvolutionRules::all()),
+        Ok(Some(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::Int64, true),
+            Field::new("d", DataType::Utf8, true),
+        ])))
+    );
+
+    assert_eq!(
+        evolve_schema(&prev, &prev, &SchemaEvolutionRules::all()),
+        Ok(None)
+    );
+}
+
+#[test]
+fn test_evolve_schema_incompatible() {
+    let prev = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, true),
+    ]);
+
+    let new = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+    assert_matches!(
+        evolve_schema(&prev, &new, &SchemaEvolutionRules::all()),
+        Err(SchemaEvolutionError::ColumnMissing { .. })
+    );
+
+    let new = Schema::new(vec![
+        Field::new("b", DataType::Utf8, true),
+        Field::new("a", DataType::Int32, false),
+    ]);
+    assert_matches!(
+        evolve_schema(&prev, &new, &SchemaEvolutionRules::all()),
+        Err(SchemaEvolutionError::ColumnMissing { .. })
+    );
+
+    let new = Schema::new(vec![
+        Field::new("a", DataType::Int64, false),
+        Field::new("b", DataType::Int64, true),
+    ]);
+    assert_matches!(
+        evolve_schema(&prev, &new, &SchemaEvolutionRules::all()),
+        Err(SchemaEvolutionError::TypeChanged { .. })
+    );
+
+    let new = Schema::new(vec![
+        Field::new("a", DataType::Int32, false),
+        Field::new("b", DataType::Utf8, true),
+        Field::new("c", DataType::Utf8, false),
+    ]);
+    assert_matches!(
+        evolve_schema(&prev, &new, &SchemaEvolutionRules::all()),
+        Err(SchemaEvolutionError::NonNullableColumnAdded { .. })
+    );
+}
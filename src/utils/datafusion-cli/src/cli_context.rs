@@ -27,6 +27,9 @@ use object_store::ObjectStore;
 
 use crate::object_storage::{AwsOptions, GcpOptions};
 
+/// Function that expands syntax extensions in the SQL text before it is parsed
+pub type SqlRewriter = fn(&str) -> Result<String, DataFusionError>;
+
 #[async_trait::async_trait]
 /// The CLI session context trait provides a way to have a session context that
 /// can be used with datafusion's CLI code.
@@ -49,6 +52,11 @@ pub trait CliSessionContext {
 
     /// Execute a logical plan and return a DataFrame.
     async fn execute_logical_plan(&self, plan: LogicalPlan) -> Result<DataFrame, DataFusionError>;
+
+    /// Get the function to rewrite SQL statements with before parsing them.
+    fn sql_rewriter(&self) -> Option<SqlRewriter> {
+        None
+    }
 }
 
 #[async_trait::async_trait]
@@ -90,3 +98,47 @@ impl CliSessionContext for SessionContext {
         self.execute_logical_plan(plan).await
     }
 }
+
+/// Session context that rewrites SQL statements with the specified function
+/// before parsing them.
+pub struct SessionContextWithSqlRewriter {
+    ctx: SessionContext,
+    sql_rewriter: SqlRewriter,
+}
+
+impl SessionContextWithSqlRewriter {
+    pub fn new(ctx: SessionContext, sql_rewriter: SqlRewriter) -> Self {
+        Self { ctx, sql_rewriter }
+    }
+}
+
+#[async_trait::async_trait]
+impl CliSessionContext for SessionContextWithSqlRewriter {
+    fn task_ctx(&self) -> Arc<TaskContext> {
+        CliSessionContext::task_ctx(&self.ctx)
+    }
+
+    fn session_state(&self) -> SessionState {
+        CliSessionContext::session_state(&self.ctx)
+    }
+
+    fn register_object_store(
+        &self,
+        url: &url::Url,
+        object_store: Arc<dyn ObjectStore>,
+    ) -> Option<Arc<dyn ObjectStore + 'static>> {
+        CliSessionContext::register_object_store(&self.ctx, url, object_store)
+    }
+
+    fn register_table_options_extension_from_scheme(&self, scheme: &str) {
+        CliSessionContext::register_table_options_extension_from_scheme(&self.ctx, scheme);
+    }
+
+    async fn execute_logical_plan(&self, plan: LogicalPlan) -> Result<DataFrame, DataFusionError> {
+        CliSessionContext::execute_logical_plan(&self.ctx, plan).await
+    }
+
+    fn sql_rewriter(&self) -> Option<SqlRewriter> {
+        Some(self.sql_rewriter)
+    }
+}
@@ -127,10 +127,13 @@ pub async fn exec_from_repl(
     print_options: &mut PrintOptions,
 ) -> rustyline::Result<()> {
     let mut rl = Editor::new()?;
-    rl.set_helper(Some(CliHelper::new(
-        &ctx.task_ctx().session_config().options().sql_parser.dialect,
-        print_options.color,
-    )));
+    rl.set_helper(Some(
+        CliHelper::new(
+            &ctx.task_ctx().session_config().options().sql_parser.dialect,
+            print_options.color,
+        )
+        .with_sql_rewriter(ctx.sql_rewriter()),
+    ));
     rl.load_history(".history").ok();
 
     loop {
@@ -208,7 +211,10 @@ pub(super) async fn exec_and_print(
     sql: String,
 ) -> Result<()> {
     let now = Instant::now();
-    let sql = unescape_input(&sql)?;
+    let mut sql = unescape_input(&sql)?;
+    if let Some(rewrite) = ctx.sql_rewriter() {
+        sql = rewrite(&sql)?;
+    }
     let task_ctx = ctx.task_ctx();
     let dialect = &task_ctx.session_config().options().sql_parser.dialect;
     let dialect = dialect_from_str(dialect).ok_or_else(|| {
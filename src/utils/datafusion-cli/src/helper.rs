@@ -33,12 +33,14 @@ use rustyline::hint::Hinter;
 use rustyline::validate::{ValidationContext, ValidationResult, Validator};
 use rustyline::{Context, Helper, Result};
 
+use crate::cli_context::SqlRewriter;
 use crate::highlighter::{NoSyntaxHighlighter, SyntaxHighlighter};
 
 pub struct CliHelper {
     completer: FilenameCompleter,
     dialect: String,
     highlighter: Box<dyn Highlighter>,
+    sql_rewriter: Option<SqlRewriter>,
 }
 
 impl CliHelper {
@@ -52,9 +54,15 @@ impl CliHelper {
             completer: FilenameCompleter::new(),
             dialect: dialect.into(),
             highlighter,
+            sql_rewriter: None,
         }
     }
 
+    pub fn with_sql_rewriter(mut self, sql_rewriter: Option<SqlRewriter>) -> Self {
+        self.sql_rewriter = sql_rewriter;
+        self
+    }
+
     pub fn set_dialect(&mut self, dialect: &str) {
         if dialect != self.dialect {
             self.dialect = dialect.to_string();
@@ -83,6 +91,15 @@ impl CliHelper {
             };
             let lines = split_from_semicolon(sql);
             for line in lines {
+                let line = match self.sql_rewriter.map(|rewrite| rewrite(&line)) {
+                    None => line,
+                    Some(Ok(line)) => line,
+                    Some(Err(err)) => {
+                        return Ok(ValidationResult::Invalid(Some(format!(
+                            "  🤔 Invalid statement: {err}",
+                        ))));
+                    }
+                };
                 match DFParser::parse_sql_with_dialect(&line, dialect.as_ref()) {
                     Ok(statements) if statements.is_empty() => {
                         return Ok(ValidationResult::Invalid(Some(